@@ -33,6 +33,7 @@ use bigbytesdb_common_storage::DataOperator;
 use bigbytesdb_common_tracing::set_panic_hook;
 use bigbytesdb_enterprise_background_service::get_background_service_handler;
 use bigbytesdb_query::clusters::ClusterDiscovery;
+use bigbytesdb_query::history::SystemHistory;
 use bigbytesdb_query::local;
 use bigbytesdb_query::servers::admin::AdminService;
 use bigbytesdb_query::servers::flight::FlightService;
@@ -260,6 +261,9 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
         info!("Listening for FlightSQL API: {}", listening);
     }
 
+    // Flush the query history into the system_history tables if enabled, and the pending audit events.
+    SystemHistory::instance().start();
+
     // Print information to users.
     println!("Bigbytesdb Query");
 
@@ -282,6 +286,9 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
     if conf.log.structlog.on {
         println!("    structlog: {}", conf.log.structlog);
     }
+    if conf.log.history.on {
+        println!("    history: {}", conf.log.history);
+    }
 
     println!();
     println!(
@@ -375,6 +375,9 @@ build_exceptions! {
     // dynamic error codes.
     IllegalDynamicTable(2740),
 
+    // Materialized view error codes.
+    IllegalMaterializedView(2750),
+
     // Variable error codes.
     UnknownVariable(2801),
     OnlySupportAsciiChars(2802),
@@ -404,6 +407,17 @@ build_exceptions! {
     UnknownProcedure(3130),
     ProcedureAlreadyExists(3131),
     IllegalProcedureFormat(3132),
+    // Plan baseline
+    UnknownPlanBaseline(3140),
+    PlanBaselineAlreadyExists(3141),
+    // Snapshot tag
+    UnknownSnapshotTag(3150),
+    SnapshotTagAlreadyExists(3151),
+    // Workload group
+    UnknownWorkloadGroup(3160),
+    WorkloadGroupAlreadyExists(3161),
+    WorkloadGroupQueueFull(3162),
+    WorkloadGroupIsUsed(3163),
 }
 
 // Storage errors [3001, 4000].
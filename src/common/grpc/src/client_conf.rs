@@ -39,6 +39,9 @@ pub struct RpcClientConf {
     /// None disables auto-sync.
     pub auto_sync_interval: Option<Duration>,
     pub unhealthy_endpoint_evict_time: Duration,
+    /// The max staleness of a stale-tolerant read that can be served by a learner.
+    /// None sends all reads to the leader.
+    pub max_staleness: Option<Duration>,
 }
 
 impl RpcClientConf {
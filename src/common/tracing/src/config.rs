@@ -26,6 +26,7 @@ pub struct Config {
     pub profile: ProfileLogConfig,
     pub structlog: StructLogConfig,
     pub tracing: TracingConfig,
+    pub history: HistoryConfig,
 }
 
 impl Config {
@@ -219,6 +220,36 @@ impl Default for StructLogConfig {
     }
 }
 
+/// Config for flushing the query history into the tables of the `system_history` database.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct HistoryConfig {
+    pub on: bool,
+    /// The interval in seconds between two flushes.
+    pub interval: u64,
+    /// The number of days the history is kept, 0 means forever.
+    pub retention: u64,
+}
+
+impl Display for HistoryConfig {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "enabled={}, interval={}s, retention={}d",
+            self.on, self.interval, self.retention
+        )
+    }
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self {
+            on: false,
+            interval: 15,
+            retention: 30,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
 pub struct TracingConfig {
     pub on: bool,
@@ -351,7 +351,8 @@ pub fn init_logging(
         let dispatch = Dispatch::new()
             .filter(EnvFilter::new(
                 EnvFilterBuilder::new()
-                    .filter(Some("bigbytesdb::log::structlog"), LevelFilter::Trace),
+                    .filter(Some("bigbytesdb::log::structlog"), LevelFilter::Trace)
+                    .filter(Some("bigbytesdb::log::audit"), LevelFilter::Trace),
             ))
             .append(structlog_log_file);
         logger = logger.dispatch(dispatch);
@@ -0,0 +1,523 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+
+const MAGIC: &[u8; 4] = b"HNSW";
+const MAX_LEVEL: usize = 16;
+const NO_ENTRY_POINT: u32 = u32::MAX;
+
+/// The distance function a vector index is built for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DistanceType {
+    Cosine,
+    L2,
+}
+
+impl DistanceType {
+    /// Parses the value of the `distance` index option.
+    pub fn from_option(value: &str) -> Option<Self> {
+        match value {
+            "cosine" => Some(DistanceType::Cosine),
+            "l2" => Some(DistanceType::L2),
+            _ => None,
+        }
+    }
+
+    /// Returns the distance type computed by the scalar function, if any.
+    pub fn from_func_name(name: &str) -> Option<Self> {
+        match name {
+            "cosine_distance" => Some(DistanceType::Cosine),
+            "l2_distance" => Some(DistanceType::L2),
+            _ => None,
+        }
+    }
+
+    pub fn option_name(&self) -> &'static str {
+        match self {
+            DistanceType::Cosine => "cosine",
+            DistanceType::L2 => "l2",
+        }
+    }
+
+    /// Same as `cosine_distance` and `l2_distance`, the lengths must be checked by the caller.
+    pub fn distance(&self, from: &[f32], to: &[f32]) -> f32 {
+        match self {
+            DistanceType::Cosine => {
+                let (mut dot, mut aa, mut bb) = (0.0_f32, 0.0_f32, 0.0_f32);
+                for (a, b) in from.iter().zip(to.iter()) {
+                    dot += a * b;
+                    aa += a * a;
+                    bb += b * b;
+                }
+                1.0 - dot / (aa.sqrt() * bb.sqrt())
+            }
+            DistanceType::L2 => from
+                .iter()
+                .zip(to.iter())
+                .map(|(a, b)| (a - b).powi(2))
+                .sum::<f32>()
+                .sqrt(),
+        }
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            DistanceType::Cosine => 0,
+            DistanceType::L2 => 1,
+        }
+    }
+
+    fn from_u8(v: u8) -> Result<Self> {
+        match v {
+            0 => Ok(DistanceType::Cosine),
+            1 => Ok(DistanceType::L2),
+            _ => Err(ErrorCode::StorageOther(format!(
+                "invalid distance type {} of hnsw index",
+                v
+            ))),
+        }
+    }
+}
+
+/// A candidate node and its distance to the query vector.
+#[derive(Clone, Copy, Debug)]
+struct Candidate {
+    distance: f32,
+    node: u32,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.node.cmp(&other.node))
+    }
+}
+
+/// Hierarchical Navigable Small World graph over the vectors of a block.
+///
+/// Nodes are numbered in insertion order, each node keeps the row of the vector in the block,
+/// so that search results can be mapped back to the rows.
+#[derive(Clone, Debug)]
+pub struct HnswIndex {
+    distance_type: DistanceType,
+    dimension: usize,
+    m: usize,
+    ef_construction: usize,
+    rows: Vec<u32>,
+    vectors: Vec<f32>,
+    // neighbors[node][level] are the neighbors of the node at the level.
+    neighbors: Vec<Vec<Vec<u32>>>,
+    entry_point: u32,
+    max_level: usize,
+    rng_state: u64,
+}
+
+impl HnswIndex {
+    pub const VERSION: u8 = 1;
+
+    pub fn new(
+        distance_type: DistanceType,
+        dimension: usize,
+        m: usize,
+        ef_construction: usize,
+    ) -> Self {
+        Self {
+            distance_type,
+            dimension,
+            m: m.max(2),
+            ef_construction: ef_construction.max(1),
+            rows: vec![],
+            vectors: vec![],
+            neighbors: vec![],
+            entry_point: NO_ENTRY_POINT,
+            max_level: 0,
+            rng_state: 0x2545_f491_4f6c_dd1d,
+        }
+    }
+
+    pub fn distance_type(&self) -> DistanceType {
+        self.distance_type
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Adds the vector of the row into the graph.
+    pub fn insert(&mut self, row: u32, vector: &[f32]) -> Result<()> {
+        if vector.len() != self.dimension {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Vector length not equal: {:} != {:}",
+                vector.len(),
+                self.dimension,
+            )));
+        }
+
+        let node = self.rows.len() as u32;
+        let level = self.random_level();
+        self.rows.push(row);
+        self.vectors.extend_from_slice(vector);
+        self.neighbors.push(vec![vec![]; level + 1]);
+
+        if self.entry_point == NO_ENTRY_POINT {
+            self.entry_point = node;
+            self.max_level = level;
+            return Ok(());
+        }
+
+        let mut entry_points = vec![self.candidate(vector, self.entry_point)];
+        for lc in (level + 1..=self.max_level).rev() {
+            entry_points = self.search_layer(vector, entry_points, 1, lc);
+        }
+
+        for lc in (0..=level.min(self.max_level)).rev() {
+            let found = self.search_layer(vector, entry_points, self.ef_construction, lc);
+            let selected = found
+                .iter()
+                .take(self.m)
+                .map(|c| c.node)
+                .collect::<Vec<_>>();
+            for neighbor in &selected {
+                self.connect(*neighbor, node, lc);
+            }
+            self.neighbors[node as usize][lc] = selected;
+            entry_points = found;
+        }
+
+        if level > self.max_level {
+            self.entry_point = node;
+            self.max_level = level;
+        }
+        Ok(())
+    }
+
+    /// Returns the rows of the approximate `k` nearest vectors and their distances,
+    /// ordered by distance. A larger `ef` gives a better recall with a slower search.
+    pub fn search(&self, query: &[f32], k: usize, ef: usize) -> Result<Vec<(u32, f32)>> {
+        if query.len() != self.dimension {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Vector length not equal: {:} != {:}",
+                query.len(),
+                self.dimension,
+            )));
+        }
+        if self.entry_point == NO_ENTRY_POINT || k == 0 {
+            return Ok(vec![]);
+        }
+
+        let mut entry_points = vec![self.candidate(query, self.entry_point)];
+        for lc in (1..=self.max_level).rev() {
+            entry_points = self.search_layer(query, entry_points, 1, lc);
+        }
+        let found = self.search_layer(query, entry_points, ef.max(k), 0);
+        Ok(found
+            .into_iter()
+            .take(k)
+            .map(|c| (self.rows[c.node as usize], c.distance))
+            .collect())
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(32 + self.rows.len() * (self.dimension + self.m) * 4);
+        buf.extend_from_slice(MAGIC);
+        buf.push(Self::VERSION);
+        buf.push(self.distance_type.to_u8());
+        for v in [
+            self.dimension,
+            self.m,
+            self.ef_construction,
+            self.max_level,
+            self.rows.len(),
+        ] {
+            buf.extend_from_slice(&(v as u32).to_le_bytes());
+        }
+        buf.extend_from_slice(&self.entry_point.to_le_bytes());
+        for row in &self.rows {
+            buf.extend_from_slice(&row.to_le_bytes());
+        }
+        for v in &self.vectors {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        for levels in &self.neighbors {
+            buf.extend_from_slice(&(levels.len() as u32).to_le_bytes());
+            for neighbors in levels {
+                buf.extend_from_slice(&(neighbors.len() as u32).to_le_bytes());
+                for neighbor in neighbors {
+                    buf.extend_from_slice(&neighbor.to_le_bytes());
+                }
+            }
+        }
+        buf
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = ByteReader { bytes, pos: 0 };
+        if reader.take(4)? != MAGIC {
+            return Err(ErrorCode::StorageOther("invalid hnsw index file"));
+        }
+        let version = reader.take(1)?[0];
+        if version != Self::VERSION {
+            return Err(ErrorCode::StorageOther(format!(
+                "unsupported hnsw index version {}",
+                version
+            )));
+        }
+        let distance_type = DistanceType::from_u8(reader.take(1)?[0])?;
+        let dimension = reader.read_u32()? as usize;
+        let m = reader.read_u32()? as usize;
+        let ef_construction = reader.read_u32()? as usize;
+        let max_level = reader.read_u32()? as usize;
+        let len = reader.read_u32()? as usize;
+        let entry_point = reader.read_u32()?;
+        if m < 2 || ef_construction == 0 || max_level > MAX_LEVEL {
+            return Err(ErrorCode::StorageOther("invalid hnsw index header"));
+        }
+        let valid_entry_point = if len == 0 {
+            entry_point == NO_ENTRY_POINT
+        } else {
+            (entry_point as usize) < len
+        };
+        if !valid_entry_point {
+            return Err(ErrorCode::StorageOther(format!(
+                "invalid hnsw index entry point {} of {} nodes",
+                entry_point, len
+            )));
+        }
+
+        // The lengths are checked against the remaining bytes before allocating,
+        // so that a corrupted file can not trigger a huge allocation.
+        let num_values = len
+            .checked_mul(dimension + 1)
+            .filter(|n| *n <= reader.remaining_u32s())
+            .ok_or_else(|| ErrorCode::StorageOther("unexpected end of hnsw index file"))?;
+        let mut rows = Vec::with_capacity(len);
+        for _ in 0..len {
+            rows.push(reader.read_u32()?);
+        }
+        let mut vectors = Vec::with_capacity(num_values - len);
+        for _ in 0..num_values - len {
+            vectors.push(f32::from_bits(reader.read_u32()?));
+        }
+        let mut neighbors = Vec::with_capacity(len);
+        for node in 0..len {
+            let num_levels = reader.read_u32()? as usize;
+            if num_levels == 0 || num_levels > max_level + 1 {
+                return Err(ErrorCode::StorageOther(format!(
+                    "invalid number of levels {} of hnsw index node {}",
+                    num_levels, node
+                )));
+            }
+            let mut levels = Vec::with_capacity(num_levels);
+            for _ in 0..num_levels {
+                let num_neighbors = reader.read_u32()? as usize;
+                if num_neighbors > reader.remaining_u32s() {
+                    return Err(ErrorCode::StorageOther("unexpected end of hnsw index file"));
+                }
+                let mut level = Vec::with_capacity(num_neighbors);
+                for _ in 0..num_neighbors {
+                    level.push(reader.read_u32()?);
+                }
+                levels.push(level);
+            }
+            neighbors.push(levels);
+        }
+        if reader.pos != bytes.len() {
+            return Err(ErrorCode::StorageOther(
+                "unexpected trailing bytes in hnsw index file",
+            ));
+        }
+        if len > 0 && neighbors[entry_point as usize].len() != max_level + 1 {
+            return Err(ErrorCode::StorageOther(
+                "the entry point of hnsw index is not on the top level",
+            ));
+        }
+        // A neighbor at a level must be a node that exists on that level.
+        for (node, levels) in neighbors.iter().enumerate() {
+            for (level, links) in levels.iter().enumerate() {
+                for neighbor in links {
+                    if neighbors
+                        .get(*neighbor as usize)
+                        .is_none_or(|levels| levels.len() <= level)
+                    {
+                        return Err(ErrorCode::StorageOther(format!(
+                            "invalid neighbor {} of hnsw index node {} at level {}",
+                            neighbor, node, level
+                        )));
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            distance_type,
+            dimension,
+            m,
+            ef_construction,
+            rows,
+            vectors,
+            neighbors,
+            entry_point,
+            max_level,
+            rng_state: 0x2545_f491_4f6c_dd1d,
+        })
+    }
+
+    fn vector(&self, node: u32) -> &[f32] {
+        let start = node as usize * self.dimension;
+        &self.vectors[start..start + self.dimension]
+    }
+
+    fn candidate(&self, query: &[f32], node: u32) -> Candidate {
+        Candidate {
+            distance: self.distance_type.distance(query, self.vector(node)),
+            node,
+        }
+    }
+
+    // Greedy search of the `ef` nearest nodes at the level, ordered by distance.
+    fn search_layer(
+        &self,
+        query: &[f32],
+        entry_points: Vec<Candidate>,
+        ef: usize,
+        level: usize,
+    ) -> Vec<Candidate> {
+        let mut visited = vec![false; self.rows.len()];
+        let mut candidates = BinaryHeap::with_capacity(ef * 2);
+        let mut results = BinaryHeap::with_capacity(ef + 1);
+        for ep in entry_points {
+            if !visited[ep.node as usize] {
+                visited[ep.node as usize] = true;
+                candidates.push(Reverse(ep));
+                results.push(ep);
+            }
+        }
+        while results.len() > ef {
+            results.pop();
+        }
+
+        while let Some(Reverse(current)) = candidates.pop() {
+            let furthest = results.peek().map_or(f32::INFINITY, |c| c.distance);
+            if current.distance > furthest && results.len() >= ef {
+                break;
+            }
+            let Some(neighbors) = self.neighbors[current.node as usize].get(level) else {
+                continue;
+            };
+            for neighbor in neighbors {
+                if visited[*neighbor as usize] {
+                    continue;
+                }
+                visited[*neighbor as usize] = true;
+                let candidate = self.candidate(query, *neighbor);
+                let furthest = results.peek().map_or(f32::INFINITY, |c| c.distance);
+                if results.len() < ef || candidate.distance < furthest {
+                    candidates.push(Reverse(candidate));
+                    results.push(candidate);
+                    if results.len() > ef {
+                        results.pop();
+                    }
+                }
+            }
+        }
+        results.into_sorted_vec()
+    }
+
+    // Adds a link from the node to the new node, shrinks the links to the closest ones
+    // if the node has too many links.
+    fn connect(&mut self, node: u32, new_node: u32, level: usize) {
+        let max_links = if level == 0 { self.m * 2 } else { self.m };
+        let links = &mut self.neighbors[node as usize][level];
+        links.push(new_node);
+        if links.len() <= max_links {
+            return;
+        }
+
+        let links = std::mem::take(&mut self.neighbors[node as usize][level]);
+        let base = self.vector(node).to_vec();
+        let mut candidates = links
+            .into_iter()
+            .map(|link| self.candidate(&base, link))
+            .collect::<Vec<_>>();
+        candidates.sort();
+        candidates.truncate(max_links);
+        self.neighbors[node as usize][level] = candidates.into_iter().map(|c| c.node).collect();
+    }
+
+    // Draws the level of a new node, from an exponentially decaying distribution.
+    fn random_level(&mut self) -> usize {
+        // xorshift64*, deterministic so that the same block always builds the same graph.
+        self.rng_state ^= self.rng_state >> 12;
+        self.rng_state ^= self.rng_state << 25;
+        self.rng_state ^= self.rng_state >> 27;
+        let v = self.rng_state.wrapping_mul(0x2545_f491_4f6c_dd1d);
+        let uniform = ((v >> 11) as f64 + 1.0) / ((1u64 << 53) as f64 + 1.0);
+        let ml = 1.0 / (self.m as f64).ln();
+        ((-uniform.ln() * ml) as usize).min(MAX_LEVEL)
+    }
+}
+
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if len > self.bytes.len() - self.pos {
+            return Err(ErrorCode::StorageOther("unexpected end of hnsw index file"));
+        }
+        let bytes = &self.bytes[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn remaining_u32s(&self) -> usize {
+        (self.bytes.len() - self.pos) / 4
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
@@ -13,8 +13,11 @@
 // limitations under the License.
 
 mod distance;
+mod hnsw;
 
 pub use distance::cosine_distance;
 pub use distance::cosine_distance_64;
 pub use distance::l2_distance;
 pub use distance::l2_distance_64;
+pub use hnsw::DistanceType;
+pub use hnsw::HnswIndex;
@@ -0,0 +1,130 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigbytesdb_common_vector::l2_distance;
+use bigbytesdb_common_vector::DistanceType;
+use bigbytesdb_common_vector::HnswIndex;
+
+fn gen_vectors(num: usize, dimension: usize) -> Vec<Vec<f32>> {
+    let mut state = 42_u64;
+    (0..num)
+        .map(|_| {
+            (0..dimension)
+                .map(|_| {
+                    state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                    (state >> 40) as f32 / (1u64 << 24) as f32
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_hnsw_search() {
+    let dimension = 8;
+    let vectors = gen_vectors(1000, dimension);
+    let mut index = HnswIndex::new(DistanceType::L2, dimension, 16, 100);
+    for (row, vector) in vectors.iter().enumerate() {
+        index.insert(row as u32, vector).unwrap();
+    }
+    assert_eq!(index.len(), 1000);
+
+    let queries = gen_vectors(20, dimension);
+    let k = 10;
+    let mut hits = 0;
+    for query in &queries {
+        let mut exact = vectors
+            .iter()
+            .enumerate()
+            .map(|(row, v)| (row as u32, l2_distance(query, v).unwrap()))
+            .collect::<Vec<_>>();
+        exact.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let exact = exact[..k].iter().map(|(row, _)| *row).collect::<Vec<_>>();
+
+        let result = index.search(query, k, 64).unwrap();
+        assert_eq!(result.len(), k);
+        // results are ordered by the exact distance of the found rows.
+        for (row, distance) in &result {
+            let expected = l2_distance(query, &vectors[*row as usize]).unwrap();
+            approx::assert_relative_eq!(*distance, expected);
+        }
+        assert!(result.windows(2).all(|w| w[0].1 <= w[1].1));
+        hits += result.iter().filter(|(row, _)| exact.contains(row)).count();
+    }
+    let recall = hits as f64 / (queries.len() * k) as f64;
+    assert!(recall >= 0.9, "recall {} is too low", recall);
+}
+
+#[test]
+fn test_hnsw_serialization() {
+    let dimension = 4;
+    let vectors = gen_vectors(200, dimension);
+    let mut index = HnswIndex::new(DistanceType::Cosine, dimension, 8, 32);
+    for (row, vector) in vectors.iter().enumerate() {
+        // rows of the block may be skipped, e.g. NULL values.
+        index.insert(row as u32 * 2, vector).unwrap();
+    }
+
+    let bytes = index.to_bytes();
+    let loaded = HnswIndex::from_bytes(&bytes).unwrap();
+    assert_eq!(loaded.len(), index.len());
+    assert_eq!(loaded.dimension(), dimension);
+    assert_eq!(loaded.distance_type(), DistanceType::Cosine);
+
+    let query = [0.1, 0.2, 0.3, 0.4];
+    assert_eq!(
+        index.search(&query, 5, 16).unwrap(),
+        loaded.search(&query, 5, 16).unwrap()
+    );
+    assert!(HnswIndex::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+}
+
+#[test]
+fn test_hnsw_corrupted_bytes() {
+    let dimension = 4;
+    let vectors = gen_vectors(50, dimension);
+    let mut index = HnswIndex::new(DistanceType::L2, dimension, 8, 32);
+    for (row, vector) in vectors.iter().enumerate() {
+        index.insert(row as u32, vector).unwrap();
+    }
+    let bytes = index.to_bytes();
+
+    // The number of nodes is at offset 22 and the entry point at offset 26.
+    let mut corrupted = bytes.clone();
+    corrupted[22..26].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert!(HnswIndex::from_bytes(&corrupted).is_err());
+
+    let mut corrupted = bytes.clone();
+    corrupted[26..30].copy_from_slice(&50_u32.to_le_bytes());
+    assert!(HnswIndex::from_bytes(&corrupted).is_err());
+
+    // The first neighbor of the first node follows its number of levels and neighbors.
+    let offset = 30 + 50 * (dimension + 1) * 4 + 8;
+    let mut corrupted = bytes.clone();
+    corrupted[offset..offset + 4].copy_from_slice(&50_u32.to_le_bytes());
+    assert!(HnswIndex::from_bytes(&corrupted).is_err());
+
+    let mut corrupted = bytes.clone();
+    corrupted.extend_from_slice(&[0; 4]);
+    assert!(HnswIndex::from_bytes(&corrupted).is_err());
+}
+
+#[test]
+fn test_hnsw_dimension_mismatch() {
+    let mut index = HnswIndex::new(DistanceType::L2, 3, 16, 100);
+    assert!(index.insert(0, &[1.0, 2.0]).is_err());
+    assert!(index.search(&[1.0, 2.0], 1, 10).is_err());
+    // search on an empty index returns nothing.
+    assert!(index.search(&[1.0, 2.0, 3.0], 1, 10).unwrap().is_empty());
+}
@@ -52,6 +52,7 @@ pub use util::assert_table_exist;
 pub use util::db_has_to_exist;
 pub use util::deserialize_struct;
 pub use util::deserialize_u64;
+pub use util::fetch_add_u64;
 pub use util::fetch_id;
 pub use util::get_u64_value;
 pub use util::list_u64_value;
@@ -2540,12 +2540,15 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 }
             }
 
-            // If the column ids and options do not change,
+            // If the index type, column ids and options do not change,
             // use the old index version, otherwise create a new index version.
             let mut old_version = None;
             let mut mark_delete_op = None;
             if let Some(old_index) = indexes.get(&req.name) {
-                if old_index.column_ids == req.column_ids && old_index.options == req.options {
+                if old_index.index_type == req.index_type
+                    && old_index.column_ids == req.column_ids
+                    && old_index.options == req.options
+                {
                     old_version = Some(old_index.version.clone());
                 } else {
                     let (m_key, m_value) = mark_table_index_as_deleted(
@@ -2560,6 +2563,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
             let version = old_version.unwrap_or(Uuid::new_v4().simple().to_string());
 
             let index = TableIndex {
+                index_type: req.index_type,
                 name: req.name.clone(),
                 column_ids: req.column_ids.clone(),
                 sync_creation: req.sync_creation,
@@ -112,6 +112,7 @@ use bigbytesdb_common_meta_app::schema::TableIdHistoryIdent;
 use bigbytesdb_common_meta_app::schema::TableIdList;
 use bigbytesdb_common_meta_app::schema::TableIdToName;
 use bigbytesdb_common_meta_app::schema::TableIdent;
+use bigbytesdb_common_meta_app::schema::TableIndexType;
 use bigbytesdb_common_meta_app::schema::TableInfo;
 use bigbytesdb_common_meta_app::schema::TableMeta;
 use bigbytesdb_common_meta_app::schema::TableNameIdent;
@@ -5556,6 +5557,7 @@ impl SchemaApiTestSuite {
             let resp = resp.unwrap().data;
             assert_eq!(resp.comment, Some("seq".to_string()));
             assert_eq!(resp.current, 1);
+            assert_eq!(resp.storage_version, 1);
         }
 
         info!("--- get sequence nextval");
@@ -6006,6 +6008,7 @@ impl SchemaApiTestSuite {
             info!("--- create table index 1");
             let req = CreateTableIndexReq {
                 create_option: CreateOption::Create,
+                index_type: TableIndexType::Inverted,
                 tenant: tenant.clone(),
                 table_id,
                 name: index_name_1.clone(),
@@ -6025,6 +6028,7 @@ impl SchemaApiTestSuite {
             info!("--- create table index 2 with duplicate column id");
             let req = CreateTableIndexReq {
                 create_option: CreateOption::Create,
+                index_type: TableIndexType::Inverted,
                 table_id,
                 tenant: tenant.clone(),
                 name: index_name_2.clone(),
@@ -6038,6 +6042,7 @@ impl SchemaApiTestSuite {
             info!("--- create table index 2");
             let req = CreateTableIndexReq {
                 create_option: CreateOption::Create,
+                index_type: TableIndexType::Inverted,
                 table_id,
                 tenant: tenant.clone(),
                 name: index_name_2.clone(),
@@ -6059,6 +6064,7 @@ impl SchemaApiTestSuite {
             info!("--- create table index again with if_not_exists = false");
             let req = CreateTableIndexReq {
                 create_option: CreateOption::Create,
+                index_type: TableIndexType::Inverted,
                 table_id,
                 tenant: tenant.clone(),
                 name: index_name_1.clone(),
@@ -6079,6 +6085,7 @@ impl SchemaApiTestSuite {
             info!("--- create table index again with if_not_exists = true");
             let req = CreateTableIndexReq {
                 create_option: CreateOption::CreateIfNotExists,
+                index_type: TableIndexType::Inverted,
                 table_id,
                 tenant: tenant.clone(),
                 name: index_name_1.clone(),
@@ -6095,6 +6102,7 @@ impl SchemaApiTestSuite {
             info!("--- create table index with invalid column id");
             let req = CreateTableIndexReq {
                 create_option: CreateOption::Create,
+                index_type: TableIndexType::Inverted,
                 table_id,
                 tenant: tenant.clone(),
                 name: index_name_3.clone(),
@@ -6198,6 +6206,7 @@ impl SchemaApiTestSuite {
             info!("--- replace index_2");
             let req = CreateTableIndexReq {
                 create_option: CreateOption::CreateOrReplace,
+                index_type: TableIndexType::Inverted,
                 table_id,
                 tenant: tenant.clone(),
                 name: index_name_2.clone(),
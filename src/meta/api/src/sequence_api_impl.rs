@@ -26,21 +26,23 @@ use bigbytesdb_common_meta_app::schema::GetSequenceNextValueReply;
 use bigbytesdb_common_meta_app::schema::GetSequenceNextValueReq;
 use bigbytesdb_common_meta_app::schema::SequenceIdent;
 use bigbytesdb_common_meta_app::schema::SequenceMeta;
+use bigbytesdb_common_meta_app::schema::SequenceStorageValue;
 use bigbytesdb_common_meta_kvapi::kvapi;
-use bigbytesdb_common_meta_types::MatchSeq;
+use bigbytesdb_common_meta_kvapi::kvapi::Key;
+use bigbytesdb_common_meta_types::txn_op_response;
 use bigbytesdb_common_meta_types::MetaError;
 use bigbytesdb_common_meta_types::SeqV;
+use bigbytesdb_common_meta_types::TxnOp;
 use bigbytesdb_common_meta_types::TxnRequest;
 use fastrace::func_name;
 use log::debug;
 
-use crate::bigbytesdb_common_meta_types::With;
 use crate::kv_app_error::KVAppError;
 use crate::kv_pb_api::KVPbApi;
-use crate::kv_pb_api::UpsertPB;
 use crate::send_txn;
 use crate::txn_backoff::txn_backoff;
 use crate::txn_cond_eq_seq;
+use crate::util::fetch_add_u64;
 use crate::util::txn_op_put_pb;
 use crate::SequenceApi;
 
@@ -54,21 +56,33 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SequenceApi for KV {
         debug!(req :? =(&req); "SchemaApi: {}", func_name!());
 
         let meta: SequenceMeta = req.clone().into();
+        let ident = req.ident.clone();
+        let storage_ident = ident.storage_ident();
 
-        let seq = MatchSeq::from(req.create_option);
-        let key = req.ident.clone();
-        let reply = self
-            .upsert_pb(&UpsertPB::update(key, meta).with(seq))
-            .await?;
+        let condition = match req.create_option {
+            CreateOption::Create | CreateOption::CreateIfNotExists => {
+                vec![txn_cond_eq_seq(&ident, 0)]
+            }
+            CreateOption::CreateOrReplace => vec![],
+        };
+        let if_then = vec![
+            txn_op_put_pb(&ident, &meta, None)?, // name -> meta
+            TxnOp::put(
+                storage_ident.to_string_key(),
+                SequenceStorageValue(meta.current).to_bytes(),
+            ), // name -> current value
+        ];
+
+        let txn_req = TxnRequest::new(condition, if_then);
+        let (succ, _responses) = send_txn(self, txn_req).await?;
 
         debug!(
             ident :?= (req.ident),
-            prev :? = (reply.prev),
-            is_changed = reply.is_changed();
+            succ = succ;
             "create_sequence"
         );
 
-        if !reply.is_changed() {
+        if !succ {
             match req.create_option {
                 CreateOption::Create => Err(KVAppError::AppError(AppError::SequenceError(
                     SequenceError::SequenceAlreadyExists(req.ident.exist_error(func_name!())),
@@ -88,8 +102,18 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SequenceApi for KV {
         name_ident: &SequenceIdent,
     ) -> Result<Option<SeqV<SequenceMeta>>, MetaError> {
         debug!(req :? =name_ident; "SchemaApi: {}", func_name!());
-        let seq_meta = self.get_pb(name_ident).await?;
-        Ok(seq_meta)
+        let Some(mut seq_meta) = self.get_pb(name_ident).await? else {
+            return Ok(None);
+        };
+
+        if seq_meta.data.storage_version > 0 {
+            let storage_key = name_ident.storage_ident().to_string_key();
+            let current = self.get_kv(&storage_key).await?;
+            if let Some(current) = current.and_then(|v| SequenceStorageValue::from_bytes(&v.data)) {
+                seq_meta.data.current = current.0;
+            }
+        }
+        Ok(Some(seq_meta))
     }
 
     async fn get_sequence_next_value(
@@ -106,6 +130,8 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SequenceApi for KV {
         }
 
         let ident = req.ident.clone();
+        let storage_ident = ident.storage_ident();
+        let count = req.count;
         let mut trials = txn_backoff(None, func_name!());
         loop {
             trials.next().unwrap()?.await;
@@ -119,46 +145,82 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SequenceApi for KV {
             let sequence_seq = seq_meta.seq;
             let mut sequence_meta = seq_meta.data;
 
-            let start = sequence_meta.current;
-            let count = req.count;
-            if u64::MAX - sequence_meta.current < count {
-                return Err(KVAppError::AppError(AppError::SequenceError(
-                    SequenceError::OutofSequenceRange(OutofSequenceRange::new(
+            let out_of_range = |current: u64| {
+                KVAppError::AppError(AppError::SequenceError(SequenceError::OutofSequenceRange(
+                    OutofSequenceRange::new(
                         sequence_name,
                         format!(
                             "{:?}: current: {}, count: {}",
-                            sequence_name, sequence_meta.current, count
+                            sequence_name, current, count
                         ),
-                    )),
-                )));
-            }
-
-            // update meta
-            sequence_meta.current += count;
-            sequence_meta.update_on = Utc::now();
+                    ),
+                )))
+            };
 
-            let condition = vec![txn_cond_eq_seq(&ident, sequence_seq)];
-            let if_then = vec![
-                txn_op_put_pb(&ident, &sequence_meta, None)?, // name -> meta
-            ];
+            if sequence_meta.storage_version == 0 {
+                // A sequence created by an older version keeps the current value in the meta,
+                // move it to the storage key, so that the later calls use `FetchAddU64`.
+                let start = sequence_meta.current;
+                if u64::MAX - sequence_meta.current < count {
+                    return Err(out_of_range(sequence_meta.current));
+                }
 
-            let txn_req = TxnRequest::new(condition, if_then);
+                sequence_meta.current += count;
+                sequence_meta.storage_version = 1;
+                sequence_meta.update_on = Utc::now();
+
+                let condition = vec![txn_cond_eq_seq(&ident, sequence_seq)];
+                let if_then = vec![
+                    txn_op_put_pb(&ident, &sequence_meta, None)?, // name -> meta
+                    TxnOp::put(
+                        storage_ident.to_string_key(),
+                        SequenceStorageValue(sequence_meta.current).to_bytes(),
+                    ), // name -> current value
+                ];
+
+                let txn_req = TxnRequest::new(condition, if_then);
+                let (succ, _responses) = send_txn(self, txn_req).await?;
+
+                debug!(
+                    current :? =(&sequence_meta.current),
+                    ident :?= (req.ident),
+                    succ = succ;
+                    "get_sequence_next_values: migrated to storage_version 1"
+                );
+                if succ {
+                    return Ok(GetSequenceNextValueReply {
+                        start,
+                        step: sequence_meta.step,
+                        end: sequence_meta.current - 1,
+                    });
+                }
+                continue;
+            }
 
-            let (succ, _responses) = send_txn(self, txn_req).await?;
+            // The meta is not changed by nextval, the condition only fails
+            // if the sequence is replaced or dropped concurrently.
+            let condition = vec![txn_cond_eq_seq(&ident, sequence_seq)];
+            let Some(fetch_add) =
+                fetch_add_u64(self, condition, &storage_ident.to_string_key(), count).await?
+            else {
+                continue;
+            };
 
             debug!(
-                current :? =(&sequence_meta.current),
-                ident :?= (req.ident),
-                succ = succ;
+                fetch_add :% =(fetch_add),
+                ident :?= (req.ident);
                 "get_sequence_next_values"
             );
-            if succ {
-                return Ok(GetSequenceNextValueReply {
-                    start,
-                    step: sequence_meta.step,
-                    end: sequence_meta.current - 1,
-                });
+
+            if fetch_add.delta() != count {
+                return Err(out_of_range(fetch_add.before));
             }
+
+            return Ok(GetSequenceNextValueReply {
+                start: fetch_add.before,
+                step: sequence_meta.step,
+                end: fetch_add.after - 1,
+            });
         }
     }
 
@@ -166,18 +228,27 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SequenceApi for KV {
         debug!(req :? =(&req); "SchemaApi: {}", func_name!());
 
         let key = req.ident.clone();
-        let reply = self.upsert_pb(&UpsertPB::delete(key)).await?;
+        let storage_key = key.storage_ident();
+        let txn_req = TxnRequest::unconditional(vec![
+            TxnOp::delete(key.to_string_key()),
+            TxnOp::delete(storage_key.to_string_key()),
+        ]);
+        let (_succ, responses) = send_txn(self, txn_req).await?;
+
+        // return prev if drop success
+        let prev = match responses.first().and_then(|r| r.response.as_ref()) {
+            Some(txn_op_response::Response::Delete(resp)) => {
+                resp.prev_value.as_ref().map(|prev| prev.seq)
+            }
+            _ => None,
+        };
 
         debug!(
             ident :?= (req.ident),
-            prev :? = (reply.prev),
-            is_changed = reply.is_changed();
+            prev :? = (prev);
             "drop_sequence"
         );
 
-        // return prev if drop success
-        let prev = reply.prev.map(|prev| prev.seq);
-
         Ok(DropSequenceReply { prev })
     }
 }
@@ -28,6 +28,7 @@ use bigbytesdb_common_meta_kvapi::kvapi;
 use bigbytesdb_common_meta_types::seq_value::SeqV;
 use bigbytesdb_common_meta_types::txn_condition::Target;
 use bigbytesdb_common_meta_types::ConditionResult;
+use bigbytesdb_common_meta_types::FetchAddU64Response;
 use bigbytesdb_common_meta_types::InvalidArgument;
 use bigbytesdb_common_meta_types::InvalidReply;
 use bigbytesdb_common_meta_types::MatchSeq;
@@ -252,6 +253,79 @@ pub async fn send_txn(
     Ok((succ, responses))
 }
 
+/// Atomically add `delta` to the JSON encoded u64 value of `key` if `conditions` are met,
+/// returns the values before and after the update.
+///
+/// A meta-service that does not support `TxnOp::FetchAddU64`, or whose cluster has a node
+/// not supporting it, skips the op without a response; then the value is updated by a
+/// compare-and-swap instead.
+///
+/// Returns `None` if the conditions are not met or the value is updated concurrently
+/// during the compare-and-swap, the caller should retry.
+pub async fn fetch_add_u64(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    conditions: Vec<TxnCondition>,
+    key: &str,
+    delta: u64,
+) -> Result<Option<FetchAddU64Response>, MetaError> {
+    let txn_req = TxnRequest::new(conditions.clone(), vec![TxnOp::fetch_add_u64(key, delta)]);
+    let (succ, responses) = send_txn(kv_api, txn_req).await?;
+    if !succ {
+        return Ok(None);
+    }
+    if let Some(resp) = responses.first().and_then(|r| r.try_as_fetch_add_u64()) {
+        return Ok(Some(resp.clone()));
+    }
+
+    debug!(
+        "FetchAddU64 of {} is skipped by meta-service, fallback to compare-and-swap",
+        key
+    );
+    let got = kv_api.get_kv(key).await?;
+    let (before_seq, before) = match &got {
+        None => (0, Some(0)),
+        Some(seq_v) => (seq_v.seq, serde_json::from_slice::<u64>(&seq_v.data).ok()),
+    };
+    let unchanged = |before: u64| FetchAddU64Response {
+        key: key.to_string(),
+        before_seq,
+        before,
+        after_seq: before_seq,
+        after: before,
+    };
+    // Same as `FetchAddU64`, an invalid value or an overflow leaves the value unchanged.
+    let Some(before) = before else {
+        return Ok(Some(unchanged(0)));
+    };
+    let Some(after) = before.checked_add(delta).filter(|_| delta > 0) else {
+        return Ok(Some(unchanged(before)));
+    };
+
+    let mut conditions = conditions;
+    conditions.push(TxnCondition::eq_seq(key, before_seq));
+    let if_then = vec![
+        TxnOp::put(key, after.to_string().into_bytes()),
+        TxnOp::get(key),
+    ];
+    let (succ, responses) = send_txn(kv_api, TxnRequest::new(conditions, if_then)).await?;
+    if !succ {
+        return Ok(None);
+    }
+    let after_seq = responses
+        .get(1)
+        .and_then(|r| r.try_as_get())
+        .and_then(|r| r.value.as_ref())
+        .map(|v| v.seq)
+        .unwrap_or_default();
+    Ok(Some(FetchAddU64Response {
+        key: key.to_string(),
+        before_seq,
+        before,
+        after_seq,
+        after,
+    }))
+}
+
 /// Add a delete operation by key and exact seq to [`TxnRequest`].
 pub fn txn_delete_exact(txn: &mut TxnRequest, key: &impl kvapi::Key, seq: u64) {
     txn.condition.push(txn_cond_eq_seq(key, seq));
@@ -19,6 +19,7 @@ mod file_format;
 mod network_policy;
 mod ownership_info;
 mod password_policy;
+mod plan_baseline;
 mod principal_identity;
 pub mod role_ident;
 mod role_info;
@@ -34,6 +35,7 @@ mod user_privilege;
 mod user_quota;
 mod user_setting;
 mod user_stage;
+mod workload_group;
 
 mod ownership_object;
 
@@ -42,6 +44,7 @@ pub mod client_session_ident;
 pub mod connection_ident;
 pub mod network_policy_ident;
 pub mod password_policy_ident;
+pub mod plan_baseline_ident;
 pub mod procedure;
 pub mod procedure_id_ident;
 pub mod procedure_id_to_name;
@@ -55,6 +58,7 @@ pub mod user_setting_ident;
 pub mod user_stage_ident;
 pub mod user_token;
 pub mod user_token_ident;
+pub mod workload_group_ident;
 
 pub use connection::*;
 pub use file_format::*;
@@ -64,6 +68,10 @@ pub use ownership_info::OwnershipInfo;
 pub use ownership_object::OwnershipObject;
 pub use password_policy::PasswordPolicy;
 pub use password_policy_ident::PasswordPolicyIdent;
+pub use plan_baseline::PlanBaseline;
+pub use plan_baseline_ident::PlanBaselineFingerprint;
+pub use plan_baseline_ident::PlanBaselineIdent;
+pub use plan_baseline_ident::PlanBaselineNameIdent;
 pub use principal_identity::PrincipalIdentity;
 pub use procedure::CreateProcedureReply;
 pub use procedure::CreateProcedureReq;
@@ -116,3 +124,6 @@ pub use user_setting::UserSettingValue;
 pub use user_setting_ident::SettingIdent;
 pub use user_stage::*;
 pub use user_stage_ident::StageIdent;
+pub use workload_group::WorkloadGroup;
+pub use workload_group::DEFAULT_WORKLOAD_GROUP_CPU_WEIGHT;
+pub use workload_group_ident::WorkloadGroupIdent;
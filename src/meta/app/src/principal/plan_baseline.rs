@@ -0,0 +1,30 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// A plan baseline pins the plan of the queries with the same fingerprint.
+///
+/// The plan is kept as the optimizer hints that reproduce its join order
+/// and distribution, e.g. `LEADING(t1 t2 (t3 t4)) BROADCAST(t4)`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct PlanBaseline {
+    pub name: String,
+    /// Fingerprint of the query with literals and hints removed.
+    pub fingerprint: String,
+    pub query: String,
+    pub plan_hints: String,
+    pub created_on: DateTime<Utc>,
+}
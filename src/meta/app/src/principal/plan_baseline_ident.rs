@@ -0,0 +1,132 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tenant_key::ident::TIdent;
+
+/// Defines the meta-service key for plan baseline, keyed by the query fingerprint,
+/// so that the planner finds the plan baseline of a query with a single get.
+pub type PlanBaselineIdent = TIdent<Resource>;
+
+/// Defines the meta-service key for the name of a plan baseline,
+/// the value is the fingerprint of the query pinned by it.
+pub type PlanBaselineNameIdent = TIdent<NameResource>;
+
+pub use kvapi_impl::NameResource;
+pub use kvapi_impl::Resource;
+
+/// The query fingerprint stored in [`PlanBaselineNameIdent`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlanBaselineFingerprint(pub String);
+
+impl PlanBaselineFingerprint {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.clone().into_bytes()
+    }
+
+    pub fn from_bytes(buf: &[u8]) -> Self {
+        PlanBaselineFingerprint(String::from_utf8_lossy(buf).to_string())
+    }
+}
+
+mod kvapi_impl {
+
+    use bigbytesdb_common_exception::ErrorCode;
+    use bigbytesdb_common_meta_kvapi::kvapi;
+
+    use super::PlanBaselineFingerprint;
+    use super::PlanBaselineNameIdent;
+    use crate::principal::PlanBaseline;
+    use crate::principal::PlanBaselineIdent;
+    use crate::tenant_key::errors::ExistError;
+    use crate::tenant_key::errors::UnknownError;
+    use crate::tenant_key::resource::TenantResource;
+
+    pub struct Resource;
+
+    impl TenantResource for Resource {
+        const PREFIX: &'static str = "__fd_plan_baselines";
+        const TYPE: &'static str = "PlanBaselineIdent";
+        const HAS_TENANT: bool = true;
+        type ValueType = PlanBaseline;
+    }
+
+    impl kvapi::Value for PlanBaseline {
+        type KeyType = PlanBaselineIdent;
+        fn dependency_keys(&self, _key: &Self::KeyType) -> impl IntoIterator<Item = String> {
+            []
+        }
+    }
+
+    pub struct NameResource;
+
+    impl TenantResource for NameResource {
+        const PREFIX: &'static str = "__fd_plan_baseline_names";
+        const TYPE: &'static str = "PlanBaselineNameIdent";
+        const HAS_TENANT: bool = true;
+        type ValueType = PlanBaselineFingerprint;
+    }
+
+    impl kvapi::Value for PlanBaselineFingerprint {
+        type KeyType = PlanBaselineNameIdent;
+        fn dependency_keys(&self, _key: &Self::KeyType) -> impl IntoIterator<Item = String> {
+            []
+        }
+    }
+
+    impl kvapi::ValueWithName for PlanBaseline {
+        fn name(&self) -> &str {
+            &self.name
+        }
+    }
+
+    impl From<ExistError<Resource>> for ErrorCode {
+        fn from(err: ExistError<Resource>) -> Self {
+            ErrorCode::PlanBaselineAlreadyExists(err.to_string())
+        }
+    }
+
+    impl From<UnknownError<Resource>> for ErrorCode {
+        fn from(err: UnknownError<Resource>) -> Self {
+            ErrorCode::UnknownPlanBaseline(err.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bigbytesdb_common_meta_kvapi::kvapi::Key;
+
+    use crate::principal::plan_baseline_ident::PlanBaselineIdent;
+    use crate::principal::plan_baseline_ident::PlanBaselineNameIdent;
+    use crate::tenant::Tenant;
+    #[test]
+    fn test_plan_baseline_ident() {
+        let tenant = Tenant::new_literal("test");
+        let ident = PlanBaselineIdent::new(tenant.clone(), "test2");
+
+        assert_eq!(ident.to_string_key(), "__fd_plan_baselines/test/test2");
+        assert_eq!(
+            ident,
+            PlanBaselineIdent::from_str_key("__fd_plan_baselines/test/test2").unwrap()
+        );
+
+        let ident = PlanBaselineNameIdent::new(tenant.clone(), "b1");
+
+        assert_eq!(ident.to_string_key(), "__fd_plan_baseline_names/test/b1");
+        assert_eq!(
+            ident,
+            PlanBaselineNameIdent::from_str_key("__fd_plan_baseline_names/test/b1").unwrap()
+        );
+    }
+}
@@ -193,6 +193,7 @@ pub struct UserOption {
     password_policy: Option<String>,
     disabled: Option<bool>,
     must_change_password: Option<bool>,
+    workload_group: Option<String>,
 }
 
 impl UserOption {
@@ -204,6 +205,7 @@ impl UserOption {
             password_policy: None,
             disabled: None,
             must_change_password: None,
+            workload_group: None,
         }
     }
 
@@ -241,6 +243,11 @@ impl UserOption {
         self
     }
 
+    pub fn with_workload_group(mut self, workload_group: Option<String>) -> Self {
+        self.workload_group = workload_group;
+        self
+    }
+
     pub fn with_set_flag(mut self, flag: UserOptionFlag) -> Self {
         self.flags.insert(flag);
         self
@@ -270,6 +277,10 @@ impl UserOption {
         self.must_change_password.as_ref()
     }
 
+    pub fn workload_group(&self) -> Option<&String> {
+        self.workload_group.as_ref()
+    }
+
     pub fn set_default_role(&mut self, default_role: Option<String>) {
         self.default_role = default_role;
     }
@@ -290,6 +301,10 @@ impl UserOption {
         self.must_change_password = must_change_password;
     }
 
+    pub fn set_workload_group(&mut self, workload_group: Option<String>) {
+        self.workload_group = workload_group;
+    }
+
     pub fn set_all_flag(&mut self) {
         self.flags = BitFlags::all();
     }
@@ -330,6 +345,8 @@ impl UserOption {
             UserOptionItem::UnsetPasswordPolicy => self.password_policy = None,
             UserOptionItem::Disabled(v) => self.disabled = Some(*v),
             UserOptionItem::MustChangePassword(v) => self.must_change_password = Some(*v),
+            UserOptionItem::SetWorkloadGroup(v) => self.workload_group = Some(v.clone()),
+            UserOptionItem::UnsetWorkloadGroup => self.workload_group = None,
         }
     }
 }
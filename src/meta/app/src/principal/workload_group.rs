@@ -0,0 +1,55 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+pub const DEFAULT_WORKLOAD_GROUP_CPU_WEIGHT: u64 = 100;
+
+/// A workload group limits the resources used by the queries of the users and roles mapped to it.
+///
+/// A limit of `0` means unlimited.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct WorkloadGroup {
+    pub name: String,
+    /// Max number of queries of the group running at the same time.
+    pub max_concurrency: u64,
+    /// Max number of queries of the group waiting for a running slot,
+    /// the queries exceeding it are rejected.
+    pub max_queued: u64,
+    /// Max seconds a query waits for a running slot,
+    /// `0` means using the setting `statement_queued_timeout_in_seconds`.
+    pub queued_timeout_secs: u64,
+    /// Max bytes of memory used by all the running queries of the group.
+    pub max_memory_usage: u64,
+    /// Relative share of the executor worker threads among the busy groups.
+    pub cpu_weight: u64,
+    pub created_on: DateTime<Utc>,
+    pub updated_on: DateTime<Utc>,
+}
+
+impl Default for WorkloadGroup {
+    fn default() -> Self {
+        WorkloadGroup {
+            name: String::new(),
+            max_concurrency: 0,
+            max_queued: 0,
+            queued_timeout_secs: 0,
+            max_memory_usage: 0,
+            cpu_weight: DEFAULT_WORKLOAD_GROUP_CPU_WEIGHT,
+            created_on: DateTime::<Utc>::default(),
+            updated_on: DateTime::<Utc>::default(),
+        }
+    }
+}
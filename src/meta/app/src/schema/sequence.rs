@@ -15,6 +15,7 @@
 use chrono::DateTime;
 use chrono::Utc;
 pub use kvapi_impl::SequenceRsc;
+pub use kvapi_impl::SequenceStorageRsc;
 
 use super::CreateOption;
 use crate::tenant_key::ident::TIdent;
@@ -22,6 +23,32 @@ use crate::tenant_key::ident::TIdent;
 /// Defines the meta-service key for sequence.
 pub type SequenceIdent = TIdent<SequenceRsc>;
 
+/// Defines the meta-service key for the current value of a sequence.
+///
+/// The value is a JSON encoded u64, updated with `FetchAddU64` without touching the [`SequenceMeta`].
+pub type SequenceStorageIdent = TIdent<SequenceStorageRsc>;
+
+impl SequenceIdent {
+    pub fn storage_ident(&self) -> SequenceStorageIdent {
+        SequenceStorageIdent::new_from(self.clone())
+    }
+}
+
+/// The current value of a sequence stored in [`SequenceStorageIdent`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SequenceStorageValue(pub u64);
+
+impl SequenceStorageValue {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        // A JSON encoded u64 is its decimal string.
+        self.0.to_string().into_bytes()
+    }
+
+    pub fn from_bytes(buf: &[u8]) -> Option<Self> {
+        serde_json::from_slice(buf).ok().map(SequenceStorageValue)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SequenceMeta {
     pub create_on: DateTime<Utc>,
@@ -29,7 +56,14 @@ pub struct SequenceMeta {
     pub comment: Option<String>,
     pub start: u64,
     pub step: i64,
+    /// The next value of the sequence.
+    ///
+    /// If `storage_version` is 1, the value is stored in [`SequenceStorageIdent`],
+    /// and this field is only filled when the sequence is read.
     pub current: u64,
+    /// 0: the current value is stored in `current`, updated by a read-modify-write of the meta.
+    /// 1: the current value is stored in [`SequenceStorageIdent`], updated by `FetchAddU64`.
+    pub storage_version: u64,
 }
 
 impl From<CreateSequenceReq> for SequenceMeta {
@@ -41,6 +75,7 @@ impl From<CreateSequenceReq> for SequenceMeta {
             start: 1,
             step: 1,
             current: 1,
+            storage_version: 1,
         }
     }
 }
@@ -97,6 +132,7 @@ mod kvapi_impl {
     use bigbytesdb_common_meta_kvapi::kvapi;
 
     use super::SequenceMeta;
+    use super::SequenceStorageValue;
     use crate::tenant_key::resource::TenantResource;
 
     pub struct SequenceRsc;
@@ -112,6 +148,20 @@ mod kvapi_impl {
             []
         }
     }
+
+    pub struct SequenceStorageRsc;
+    impl TenantResource for SequenceStorageRsc {
+        const PREFIX: &'static str = "__fd_sequence_storage";
+        const HAS_TENANT: bool = true;
+        type ValueType = SequenceStorageValue;
+    }
+
+    impl kvapi::Value for SequenceStorageValue {
+        type KeyType = super::SequenceStorageIdent;
+        fn dependency_keys(&self, _key: &Self::KeyType) -> impl IntoIterator<Item = String> {
+            []
+        }
+    }
 }
 
 #[cfg(test)]
@@ -130,6 +180,12 @@ mod tests {
         assert_eq!(key, "__fd_sequence/dummy/3");
 
         assert_eq!(ident, SequenceIdent::from_str_key(&key).unwrap());
+
+        let storage_ident = ident.storage_ident();
+        assert_eq!(
+            storage_ident.to_string_key(),
+            "__fd_sequence_storage/dummy/3"
+        );
     }
 
     #[test]
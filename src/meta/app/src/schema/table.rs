@@ -281,8 +281,35 @@ pub struct TableMeta {
     pub indexes: BTreeMap<String, TableIndex>,
 }
 
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum TableIndexType {
+    #[default]
+    Inverted = 0,
+    Vector = 1,
+}
+
+impl Display for TableIndexType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TableIndexType::Inverted => write!(f, "INVERTED"),
+            TableIndexType::Vector => write!(f, "VECTOR"),
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct TableIndex {
+    pub index_type: TableIndexType,
     pub name: String,
     pub column_ids: Vec<u32>,
     // if true, index will create after data written to bigbytesdb,
@@ -841,6 +868,7 @@ pub struct UpdateTableMetaReply {}
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateTableIndexReq {
     pub create_option: CreateOption,
+    pub index_type: TableIndexType,
     pub tenant: Tenant,
     pub table_id: u64,
     pub name: String,
@@ -859,8 +887,8 @@ impl Display for CreateTableIndexReq {
 
         write!(
             f,
-            "{}: {} ColumnIds: {:?}, SyncCreation: {:?}, Options: {:?}",
-            typ, self.name, self.column_ids, self.sync_creation, self.options,
+            "{}: {} IndexType: {}, ColumnIds: {:?}, SyncCreation: {:?}, Options: {:?}",
+            typ, self.name, self.index_type, self.column_ids, self.sync_creation, self.options,
         )
     }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod tenant_quota_ident;
+pub mod tenant_usage_ident;
 
 mod quota;
 #[allow(clippy::module_inception)]
@@ -23,4 +24,8 @@ pub use quota::TenantQuota;
 pub use tenant::Tenant;
 pub use tenant::ToTenant;
 pub use tenant_quota_ident::TenantQuotaIdent;
+pub use tenant_usage_ident::TenantRunningQuery;
+pub use tenant_usage_ident::TenantRunningQueryIdent;
+pub use tenant_usage_ident::TenantScannedBytes;
+pub use tenant_usage_ident::TenantScannedBytesIdent;
 pub use uninit_tenant::UninitTenant;
@@ -29,4 +29,17 @@ pub struct TenantQuota {
 
     // The max number of users can be created in the tenant.
     pub max_users: u32,
+
+    // The max bytes of the table data and indexes stored in the tenant,
+    // summed up from the statistics of the table snapshots.
+    pub max_storage_bytes: u64,
+
+    // The max number of queries can run concurrently in the tenant.
+    pub max_concurrent_queries: u32,
+
+    // The max warehouses can be created in the tenant.
+    pub max_warehouses: u32,
+
+    // The max bytes can be scanned by the queries of the tenant in a calendar month (UTC).
+    pub max_scanned_bytes_per_month: u64,
 }
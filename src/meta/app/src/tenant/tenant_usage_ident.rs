@@ -0,0 +1,133 @@
+// Copyright 2021 Digitrans Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub use kvapi_impl::TenantRunningQueryRsc;
+pub use kvapi_impl::TenantScannedBytesRsc;
+
+use crate::tenant_key::ident::TIdent;
+
+/// Defines the meta-service key for the bytes scanned by a tenant in a month, such as `2025-03`.
+///
+/// The value is a JSON encoded u64, accumulated with `FetchAddU64`.
+pub type TenantScannedBytesIdent = TIdent<TenantScannedBytesRsc, String>;
+
+/// Defines the meta-service key for a query running in a tenant, keyed by the query id.
+///
+/// The key is upserted with a ttl and refreshed while the query is running,
+/// so that the queries of a crashed node do not stay counted.
+pub type TenantRunningQueryIdent = TIdent<TenantRunningQueryRsc, String>;
+
+/// The bytes scanned by a tenant stored in [`TenantScannedBytesIdent`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TenantScannedBytes(pub u64);
+
+impl TenantScannedBytes {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        // A JSON encoded u64 is its decimal string.
+        self.0.to_string().into_bytes()
+    }
+
+    pub fn from_bytes(buf: &[u8]) -> Option<Self> {
+        serde_json::from_slice(buf).ok().map(TenantScannedBytes)
+    }
+}
+
+/// The node a query is running on, stored in [`TenantRunningQueryIdent`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TenantRunningQuery {
+    pub node_id: String,
+}
+
+impl TenantRunningQuery {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.node_id.clone().into_bytes()
+    }
+
+    pub fn from_bytes(buf: &[u8]) -> Self {
+        TenantRunningQuery {
+            node_id: String::from_utf8_lossy(buf).to_string(),
+        }
+    }
+}
+
+mod kvapi_impl {
+
+    use bigbytesdb_common_meta_kvapi::kvapi;
+
+    use super::TenantRunningQuery;
+    use super::TenantScannedBytes;
+    use crate::tenant_key::resource::TenantResource;
+
+    pub struct TenantScannedBytesRsc;
+    impl TenantResource for TenantScannedBytesRsc {
+        const PREFIX: &'static str = "__fd_tenant_scanned_bytes";
+        const TYPE: &'static str = "TenantScannedBytesIdent";
+        const HAS_TENANT: bool = true;
+        type ValueType = TenantScannedBytes;
+    }
+
+    impl kvapi::Value for TenantScannedBytes {
+        type KeyType = super::TenantScannedBytesIdent;
+        fn dependency_keys(&self, _key: &Self::KeyType) -> impl IntoIterator<Item = String> {
+            []
+        }
+    }
+
+    pub struct TenantRunningQueryRsc;
+    impl TenantResource for TenantRunningQueryRsc {
+        const PREFIX: &'static str = "__fd_tenant_running_queries";
+        const TYPE: &'static str = "TenantRunningQueryIdent";
+        const HAS_TENANT: bool = true;
+        type ValueType = TenantRunningQuery;
+    }
+
+    impl kvapi::Value for TenantRunningQuery {
+        type KeyType = super::TenantRunningQueryIdent;
+        fn dependency_keys(&self, _key: &Self::KeyType) -> impl IntoIterator<Item = String> {
+            []
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bigbytesdb_common_meta_kvapi::kvapi::Key;
+
+    use super::TenantRunningQueryIdent;
+    use super::TenantScannedBytes;
+    use super::TenantScannedBytesIdent;
+    use crate::tenant::Tenant;
+
+    #[test]
+    fn test_tenant_usage_ident() {
+        let tenant = Tenant::new_literal("test");
+
+        let ident = TenantScannedBytesIdent::new(tenant.clone(), "2025-03");
+        let key = ident.to_string_key();
+        assert_eq!(key, "__fd_tenant_scanned_bytes/test/2025%2d03");
+        assert_eq!(ident, TenantScannedBytesIdent::from_str_key(&key).unwrap());
+
+        let ident = TenantRunningQueryIdent::new(tenant, "q1");
+        let key = ident.to_string_key();
+        assert_eq!(key, "__fd_tenant_running_queries/test/q1");
+        assert_eq!(ident, TenantRunningQueryIdent::from_str_key(&key).unwrap());
+    }
+
+    #[test]
+    fn test_tenant_scanned_bytes_value() {
+        let v = TenantScannedBytes(1 << 40);
+        assert_eq!(v.to_bytes(), b"1099511627776".to_vec());
+        assert_eq!(TenantScannedBytes::from_bytes(&v.to_bytes()), Some(v));
+    }
+}
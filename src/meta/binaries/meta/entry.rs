@@ -34,6 +34,7 @@ use bigbytesdb_common_tracing::init_logging;
 use bigbytesdb_common_tracing::set_panic_hook;
 use bigbytesdb_meta::api::GrpcServer;
 use bigbytesdb_meta::api::HttpService;
+use bigbytesdb_meta::backup::MetaBackup;
 use bigbytesdb_meta::configs::Config;
 use bigbytesdb_meta::meta_service::MetaNode;
 use bigbytesdb_meta::metrics::server_metrics;
@@ -188,6 +189,12 @@ pub async fn entry(conf: Config) -> anyhow::Result<()> {
 
     register_node(&meta_node, &conf).await?;
 
+    // Backup the meta data periodically if a backup storage is configured.
+    if let Some(backup) = MetaBackup::create(meta_node.clone(), &conf.backup)? {
+        info!("Meta backup is enabled: {:?}", conf.backup);
+        backup.spawn();
+    }
+
     println!("Bigbytesdb Metasrv started");
 
     stop_handler.wait_to_terminate(stop_tx).await;
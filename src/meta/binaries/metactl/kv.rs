@@ -0,0 +1,259 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Inspect and edit the key-values in a running meta-service.
+
+use std::fmt::Debug;
+use std::io::Read;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use bigbytesdb_common_meta_api::deserialize_struct;
+use bigbytesdb_common_meta_app::principal::TenantUserIdent;
+use bigbytesdb_common_meta_app::principal::UserInfo;
+use bigbytesdb_common_meta_app::schema::DatabaseId;
+use bigbytesdb_common_meta_app::schema::DatabaseMeta;
+use bigbytesdb_common_meta_app::schema::TableId;
+use bigbytesdb_common_meta_app::schema::TableMeta;
+use bigbytesdb_common_meta_client::ClientHandle;
+use bigbytesdb_common_meta_client::MetaGrpcClient;
+use bigbytesdb_common_meta_kvapi::kvapi;
+use bigbytesdb_common_meta_kvapi::kvapi::KVApi;
+use bigbytesdb_common_meta_types::protobuf::WatchRequest;
+use bigbytesdb_common_meta_types::MatchSeq;
+use bigbytesdb_common_meta_types::SeqV;
+use bigbytesdb_common_meta_types::TxnRequest;
+use bigbytesdb_common_meta_types::UpsertKV;
+use bigbytesdb_common_meta_types::With;
+use bigbytesdb_common_proto_conv::FromToProto;
+use bigbytesdb_meta::version::FEATURE_WATCH_SINCE_SEQ;
+use futures::StreamExt;
+
+use crate::DeleteArgs;
+use crate::GetArgs;
+use crate::ListArgs;
+use crate::MGetArgs;
+use crate::TxnArgs;
+use crate::UpsertArgs;
+use crate::WatchArgs;
+
+pub async fn get(args: &GetArgs) -> anyhow::Result<()> {
+    let client = new_client(&args.grpc_api_address)?;
+
+    let res = client.get_kv(&args.key).await?;
+    print_kv(&args.key, res, args.decode);
+    Ok(())
+}
+
+pub async fn mget(args: &MGetArgs) -> anyhow::Result<()> {
+    let client = new_client(&args.grpc_api_address)?;
+
+    let res = client.mget_kv(&args.keys).await?;
+    for (key, seq_v) in args.keys.iter().zip(res) {
+        print_kv(key, seq_v, args.decode);
+    }
+    Ok(())
+}
+
+pub async fn list(args: &ListArgs) -> anyhow::Result<()> {
+    let client = new_client(&args.grpc_api_address)?;
+
+    let mut strm = client.list_kv(&args.prefix).await?;
+    let mut n = 0;
+    while let Some(item) = strm.next().await {
+        if args.limit.is_some_and(|limit| n >= limit) {
+            break;
+        }
+        let item = item?;
+        print_kv(&item.key, item.value.map(SeqV::from), args.decode);
+        n += 1;
+    }
+    Ok(())
+}
+
+pub async fn upsert(args: &UpsertArgs) -> anyhow::Result<()> {
+    let client = new_client(&args.grpc_api_address)?;
+
+    let mut upsert = UpsertKV::update(&args.key, args.value.as_bytes());
+    if let Some(seq) = args.seq {
+        upsert = upsert.with(MatchSeq::Exact(seq));
+    }
+    if let Some(secs) = args.expire_after {
+        upsert = upsert.with_ttl(Duration::from_secs(secs));
+    }
+
+    let res = client.upsert_kv(upsert).await?;
+    if res.is_changed() {
+        println!("upserted:");
+    } else {
+        println!("not changed, the seq does not match:");
+    }
+    print_kv(&args.key, res.result, false);
+    Ok(())
+}
+
+pub async fn delete(args: &DeleteArgs) -> anyhow::Result<()> {
+    let client = new_client(&args.grpc_api_address)?;
+
+    let mut upsert = UpsertKV::delete(&args.key);
+    if let Some(seq) = args.seq {
+        upsert = upsert.with(MatchSeq::Exact(seq));
+    }
+
+    let res = client.upsert_kv(upsert).await?;
+    if res.is_changed() {
+        println!("deleted:");
+    } else {
+        println!("not deleted, the key does not exist or the seq does not match:");
+    }
+    print_kv(&args.key, res.prev, false);
+    Ok(())
+}
+
+pub async fn watch(args: &WatchArgs) -> anyhow::Result<()> {
+    let client = new_client(&args.grpc_api_address)?;
+
+    let (key, key_end) = match &args.prefix {
+        Some(prefix) => {
+            let (start, end) = kvapi::prefix_to_range(prefix)?;
+            (start, Some(end))
+        }
+        None => {
+            let key = args
+                .key
+                .clone()
+                .ok_or_else(|| anyhow!("one of --key and --prefix is required"))?;
+            (key, args.key_end.clone())
+        }
+    };
+
+    let mut req = WatchRequest::new(key, key_end);
+    req.initial_flush = args.initial_flush;
+    if let Some(seq) = args.since_seq {
+        // An older server ignores `since_seq` and silently skips the missed changes.
+        let status = client.get_cluster_status().await?;
+        if !status.features.iter().any(|f| f == FEATURE_WATCH_SINCE_SEQ) {
+            return Err(anyhow!(
+                "--since-seq is not supported by metasrv {} at {}",
+                status.binary_version,
+                args.grpc_api_address
+            ));
+        }
+        req = req.with_since_seq(seq);
+    }
+
+    let mut strm = client.request(req).await?;
+    while let Some(resp) = strm.message().await? {
+        let Some(event) = resp.event else {
+            continue;
+        };
+        match event.current {
+            Some(current) => {
+                println!("update:");
+                print_kv(&event.key, Some(SeqV::from(current)), args.decode);
+            }
+            None => {
+                println!("delete:");
+                print_kv(&event.key, event.prev.map(SeqV::from), args.decode);
+            }
+        }
+    }
+    Ok(())
+}
+
+pub async fn txn(args: &TxnArgs) -> anyhow::Result<()> {
+    let client = new_client(&args.grpc_api_address)?;
+
+    let json = match &args.txn {
+        Some(json) => json.clone(),
+        None => {
+            let mut json = String::new();
+            std::io::stdin().read_to_string(&mut json)?;
+            json
+        }
+    };
+    let txn: TxnRequest = serde_json::from_str(&json)?;
+
+    let reply = client.transaction(txn).await?;
+    println!("{}", reply);
+    Ok(())
+}
+
+fn new_client(addr: &str) -> anyhow::Result<Arc<ClientHandle>> {
+    let client =
+        MetaGrpcClient::try_create(vec![addr.to_string()], "root", "xxx", None, None, None)?;
+    Ok(client)
+}
+
+fn print_kv(key: &str, seq_v: Option<SeqV>, decode: bool) {
+    let Some(seq_v) = seq_v else {
+        println!("{}: <not found>", key);
+        return;
+    };
+
+    let expire_at = seq_v
+        .meta
+        .as_ref()
+        .and_then(|meta| meta.get_expire_at_ms())
+        .map(|ms| format!(" expire_at={}", ms / 1000))
+        .unwrap_or_default();
+
+    println!(
+        "{}: seq={}{} value={}",
+        key,
+        seq_v.seq,
+        expire_at,
+        format_value(key, &seq_v.data, decode)
+    );
+}
+
+/// Format a value as a readable struct if `decode` is true and the key is of a known type,
+/// otherwise as a string, or as bytes if it is not valid utf8.
+fn format_value(key: &str, value: &[u8], decode: bool) -> String {
+    if decode {
+        if let Some(decoded) = decode_value(key, value) {
+            return decoded;
+        }
+    }
+
+    match std::str::from_utf8(value) {
+        Ok(s) => s.to_string(),
+        Err(_) => format!("{:?}", value),
+    }
+}
+
+fn decode_value(key: &str, value: &[u8]) -> Option<String> {
+    fn decode<T: FromToProto + Debug>(value: &[u8]) -> String {
+        match deserialize_struct::<T>(value) {
+            Ok(v) => format!("{:#?}", v),
+            Err(e) => format!("<can not decode as {}: {}>", std::any::type_name::<T>(), e),
+        }
+    }
+
+    fn has_prefix<K: kvapi::Key>(key: &str) -> bool {
+        key.strip_prefix(K::PREFIX)
+            .is_some_and(|rest| rest.starts_with('/'))
+    }
+
+    if has_prefix::<TableId>(key) {
+        Some(decode::<TableMeta>(value))
+    } else if has_prefix::<DatabaseId>(key) {
+        Some(decode::<DatabaseMeta>(value))
+    } else if has_prefix::<TenantUserIdent>(key) {
+        Some(decode::<UserInfo>(value))
+    } else {
+        None
+    }
+}
@@ -20,7 +20,9 @@ pub mod admin;
 pub mod export_from_disk;
 pub mod import;
 pub mod import_v004;
+pub mod kv;
 pub(crate) mod reading;
+pub mod restore;
 pub mod upgrade;
 
 use std::collections::BTreeMap;
@@ -212,6 +214,148 @@ pub struct BenchArgs {
     pub grpc_api_address: String,
 }
 
+#[derive(Debug, Clone, Deserialize, Args)]
+pub struct GetArgs {
+    #[clap(long, default_value = "127.0.0.1:9191")]
+    pub grpc_api_address: String,
+
+    #[clap(long)]
+    pub key: String,
+
+    /// Decode the value into a readable struct if the type of the key is known.
+    #[clap(long)]
+    pub decode: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Args)]
+pub struct MGetArgs {
+    #[clap(long, default_value = "127.0.0.1:9191")]
+    pub grpc_api_address: String,
+
+    #[clap(long, required = true, num_args = 1..)]
+    pub keys: Vec<String>,
+
+    /// Decode the values into readable structs if the types of the keys are known.
+    #[clap(long)]
+    pub decode: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Args)]
+pub struct ListArgs {
+    #[clap(long, default_value = "127.0.0.1:9191")]
+    pub grpc_api_address: String,
+
+    #[clap(long)]
+    pub prefix: String,
+
+    /// The max number of key-values to output.
+    #[clap(long)]
+    pub limit: Option<u64>,
+
+    /// Decode the values into readable structs if the types of the keys are known.
+    #[clap(long)]
+    pub decode: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Args)]
+pub struct UpsertArgs {
+    #[clap(long, default_value = "127.0.0.1:9191")]
+    pub grpc_api_address: String,
+
+    #[clap(long)]
+    pub key: String,
+
+    #[clap(long)]
+    pub value: String,
+
+    /// Only update the key if its current seq is exactly this; 0 means the key must not exist.
+    #[clap(long)]
+    pub seq: Option<u64>,
+
+    /// Delete the key after this many seconds.
+    #[clap(long)]
+    pub expire_after: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Args)]
+pub struct DeleteArgs {
+    #[clap(long, default_value = "127.0.0.1:9191")]
+    pub grpc_api_address: String,
+
+    #[clap(long)]
+    pub key: String,
+
+    /// Only delete the key if its current seq is exactly this.
+    #[clap(long)]
+    pub seq: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Args)]
+pub struct WatchArgs {
+    #[clap(long, default_value = "127.0.0.1:9191")]
+    pub grpc_api_address: String,
+
+    /// Watch a single key, or the range `[key, key_end)` if `key_end` is specified.
+    #[clap(long, conflicts_with = "prefix")]
+    pub key: Option<String>,
+
+    #[clap(long, requires = "key")]
+    pub key_end: Option<String>,
+
+    /// Watch all the keys with this prefix.
+    #[clap(long)]
+    pub prefix: Option<String>,
+
+    /// Output the current values of the watched keys before the changes.
+    #[clap(long, conflicts_with = "since_seq")]
+    pub initial_flush: bool,
+
+    /// Resume watching from this seq, the changes since it that are still held
+    /// by the server are output before the new changes.
+    #[clap(long)]
+    pub since_seq: Option<u64>,
+
+    /// Decode the values into readable structs if the types of the keys are known.
+    #[clap(long)]
+    pub decode: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Args)]
+pub struct TxnArgs {
+    #[clap(long, default_value = "127.0.0.1:9191")]
+    pub grpc_api_address: String,
+
+    /// The `TxnRequest` in json.
+    /// If `txn` is not specified, the json is read from stdin instead.
+    #[clap(long)]
+    pub txn: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Args)]
+pub struct RestoreArgs {
+    /// The dir to store the restored meta state.
+    #[clap(long)]
+    pub raft_dir: Option<String>,
+
+    /// The backup storage in json, the same as `[backup.storage]` in the meta-service config,
+    /// e.g.: `{"type":"Fs","root":"/path/to/backup"}`
+    #[clap(long)]
+    pub storage: String,
+
+    /// The point to restore to: a log index, or a time in RFC3339 or in UTC `%Y-%m-%d %H:%M:%S`.
+    /// If `to` is not specified, all the backed up logs are restored.
+    #[clap(long)]
+    pub to: Option<String>,
+
+    /// initial_cluster format: node_id=endpoint,grpc_api_addr
+    #[clap(long)]
+    pub initial_cluster: Vec<String>,
+
+    /// The node id of the restored node.
+    #[clap(long, default_value = "0")]
+    pub id: u64,
+}
+
 #[derive(Debug, Deserialize, Parser)]
 #[clap(name = "bigbytesdb-metactl", about, version = &**METASRV_COMMIT_VERSION, author)]
 struct App {
@@ -270,6 +414,9 @@ impl App {
         if let Some(purged) = res.purged {
             println!("Purged: {}", purged);
         }
+        if let Some(staleness_ms) = res.staleness_ms {
+            println!("Staleness: {} ms", staleness_ms);
+        }
         if !res.replication.is_empty() {
             println!("Replication:");
             for (k, v) in res.replication {
@@ -349,6 +496,15 @@ enum CtlCommand {
     Import(ImportArgs),
     TransferLeader(TransferLeaderArgs),
     BenchClientNumConn(BenchArgs),
+    Get(GetArgs),
+    #[clap(name = "mget")]
+    MGet(MGetArgs),
+    List(ListArgs),
+    Upsert(UpsertArgs),
+    Delete(DeleteArgs),
+    Watch(WatchArgs),
+    Txn(TxnArgs),
+    Restore(RestoreArgs),
 }
 
 /// Usage:
@@ -394,6 +550,30 @@ async fn main() -> anyhow::Result<()> {
             CtlCommand::Import(args) => {
                 app.import(args).await?;
             }
+            CtlCommand::Get(args) => {
+                kv::get(args).await?;
+            }
+            CtlCommand::MGet(args) => {
+                kv::mget(args).await?;
+            }
+            CtlCommand::List(args) => {
+                kv::list(args).await?;
+            }
+            CtlCommand::Upsert(args) => {
+                kv::upsert(args).await?;
+            }
+            CtlCommand::Delete(args) => {
+                kv::delete(args).await?;
+            }
+            CtlCommand::Watch(args) => {
+                kv::watch(args).await?;
+            }
+            CtlCommand::Txn(args) => {
+                kv::txn(args).await?;
+            }
+            CtlCommand::Restore(args) => {
+                restore::restore(args).await?;
+            }
         },
         // for backward compatibility
         None => {
@@ -0,0 +1,266 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Restore a meta-service node from the backups written by the meta-service,
+//! to the latest state or to a point in time.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::process;
+
+use anyhow::anyhow;
+use chrono::DateTime;
+use chrono::NaiveDateTime;
+use bigbytesdb_common_meta_app::storage::StorageParams;
+use bigbytesdb_common_meta_raft_store::key_spaces::RaftStoreEntry;
+use bigbytesdb_common_meta_raft_store::state_machine::StateMachineMetaKey;
+use bigbytesdb_common_meta_raft_store::state_machine::StateMachineMetaValue;
+use bigbytesdb_common_meta_types::raft_types::Entry;
+use bigbytesdb_common_meta_types::raft_types::EntryPayload;
+use bigbytesdb_common_meta_types::raft_types::LogId;
+use bigbytesdb_meta::backup::list_log_segments;
+use bigbytesdb_meta::backup::list_snapshots;
+use bigbytesdb_meta::backup::new_backup_operator;
+use bigbytesdb_meta::backup::SnapshotFile;
+use opendal::Operator;
+
+use crate::import;
+use crate::ImportArgs;
+use crate::RestoreArgs;
+
+/// The point to restore to.
+#[derive(Debug, Clone, Copy)]
+enum Target {
+    /// Restore all the backed up logs.
+    Latest,
+    /// Restore the logs up to and including this index.
+    Index(u64),
+    /// Restore the logs proposed no later than this time in milliseconds.
+    TimeMs(u64),
+}
+
+impl Target {
+    fn parse(s: Option<&str>) -> anyhow::Result<Self> {
+        let Some(s) = s else {
+            return Ok(Target::Latest);
+        };
+
+        if !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()) {
+            return Ok(Target::Index(s.parse()?));
+        }
+
+        let ms = if let Ok(t) = DateTime::parse_from_rfc3339(s) {
+            t.timestamp_millis()
+        } else if let Ok(t) = NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S") {
+            t.and_utc().timestamp_millis()
+        } else {
+            return Err(anyhow!(
+                "invalid --to: '{}', expect a log index, a RFC3339 time or a UTC time in '%Y-%m-%d %H:%M:%S'",
+                s
+            ));
+        };
+
+        Ok(Target::TimeMs(ms.max(0) as u64))
+    }
+}
+
+pub async fn restore(args: &RestoreArgs) -> anyhow::Result<()> {
+    let target = Target::parse(args.to.as_deref())?;
+
+    let params: StorageParams = serde_json::from_str(&args.storage)?;
+    let op = new_backup_operator(&params)?;
+
+    let snapshot = choose_snapshot(&op, target).await?;
+
+    eprintln!();
+    eprintln!("Restore:");
+    eprintln!("    From: {:?}", params);
+    eprintln!("    To: {:?}", target);
+    eprintln!(
+        "    Snapshot: {} (last applied: {})",
+        snapshot.path(),
+        snapshot.last_applied
+    );
+
+    let lines = build_restore_lines(&op, &snapshot, target).await?;
+
+    let path = env::temp_dir().join(format!("metactl-restore-{}.json", process::id()));
+    fs::write(&path, lines.join("\n") + "\n")?;
+
+    let import_args = ImportArgs {
+        raft_dir: args.raft_dir.clone(),
+        db: path.display().to_string(),
+        initial_cluster: args.initial_cluster.clone(),
+        id: args.id,
+    };
+
+    let res = import::import_data(&import_args).await;
+    fs::remove_file(&path)?;
+    res
+}
+
+/// Choose the latest snapshot that does not go beyond the target.
+async fn choose_snapshot(op: &Operator, target: Target) -> anyhow::Result<SnapshotFile> {
+    let snapshots = list_snapshots(op).await?;
+
+    let chosen = snapshots.into_iter().rev().find(|s| match target {
+        Target::Latest => true,
+        Target::Index(index) => s.last_applied <= index,
+        Target::TimeMs(ms) => s.time_ms <= ms,
+    });
+
+    chosen.ok_or_else(|| anyhow!("no backed up snapshot before {:?}", target))
+}
+
+/// Build the lines in the format of `metactl export`,
+/// with the logs from the snapshot and the log segments that are committed up to the target.
+async fn build_restore_lines(
+    op: &Operator,
+    snapshot: &SnapshotFile,
+    target: Target,
+) -> anyhow::Result<Vec<String>> {
+    let mut head = vec![];
+    let mut sm_lines = vec![];
+    let mut vote = None;
+    let mut purged: Option<LogId> = None;
+    let mut last_applied: Option<LogId> = None;
+    let mut snapshot_committed: Option<LogId> = None;
+    let mut logs = BTreeMap::new();
+
+    for line in read_lines(op, &snapshot.path()).await? {
+        let (tree, entry): (String, RaftStoreEntry) = serde_json::from_str(&line)?;
+
+        match entry {
+            RaftStoreEntry::Vote(v) => vote = v,
+            RaftStoreEntry::Purged(p) => purged = p,
+            RaftStoreEntry::Committed(c) => snapshot_committed = c,
+            RaftStoreEntry::LogEntry(ent) => {
+                logs.insert(ent.log_id.index, ent);
+            }
+            RaftStoreEntry::StateMachineMeta {
+                key: StateMachineMetaKey::LastApplied,
+                value: StateMachineMetaValue::LogId(log_id),
+            } => {
+                last_applied = Some(log_id);
+                sm_lines.push(line);
+            }
+            _ if tree.starts_with("state_machine/") => sm_lines.push(line),
+            _ => head.push(line),
+        }
+    }
+
+    // The logs in a snapshot after its committed log may be overridden by a new leader,
+    // only the committed ones are restored.
+    let snapshot_committed_index = snapshot_committed.map(|c| c.index);
+    logs.retain(|index, _| Some(*index) <= snapshot_committed_index);
+
+    for seg in list_log_segments(op).await? {
+        if seg.last <= snapshot.last_applied {
+            continue;
+        }
+
+        for line in read_lines(op, &seg.path()).await? {
+            let (_tree, entry): (String, RaftStoreEntry) = serde_json::from_str(&line)?;
+            match entry {
+                RaftStoreEntry::Vote(v) => vote = v.or(vote),
+                RaftStoreEntry::LogEntry(ent) => {
+                    logs.insert(ent.log_id.index, ent);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Purged logs are already included in the snapshot.
+    let purged_index = purged.map(|p| p.index);
+    logs.retain(|index, _| Some(*index) > purged_index);
+
+    let committed = find_committed(&logs, last_applied, target)?;
+
+    eprintln!("    Restore Logs Upto: {:?}", committed);
+
+    let mut lines = head;
+    let enc = |entry: &RaftStoreEntry| serde_json::to_string(&("raft_log", entry));
+
+    lines.push(enc(&RaftStoreEntry::Vote(vote))?);
+    lines.push(enc(&RaftStoreEntry::Committed(committed))?);
+    lines.push(enc(&RaftStoreEntry::Purged(purged))?);
+
+    let upto = committed.map(|c| c.index);
+    for (index, ent) in logs {
+        if Some(index) > upto {
+            break;
+        }
+        lines.push(enc(&RaftStoreEntry::LogEntry(ent))?);
+    }
+
+    lines.extend(sm_lines);
+    Ok(lines)
+}
+
+/// Find the last log to restore, which will be marked as committed.
+///
+/// The logs after the snapshot must be contiguous up to the target.
+fn find_committed(
+    logs: &BTreeMap<u64, Entry>,
+    last_applied: Option<LogId>,
+    target: Target,
+) -> anyhow::Result<Option<LogId>> {
+    let mut committed = last_applied;
+    let mut next = last_applied.map(|l| l.index + 1).unwrap_or_default();
+
+    for (index, ent) in logs.range(next..) {
+        if let Target::Index(t) = target {
+            if *index > t {
+                break;
+            }
+        }
+
+        if let (Target::TimeMs(t), EntryPayload::Normal(log_entry)) = (target, &ent.payload) {
+            if log_entry.time_ms.is_some_and(|ms| ms > t) {
+                break;
+            }
+        }
+
+        if *index != next {
+            return Err(anyhow!(
+                "backed up logs are not contiguous: expect log index {}, but got {}",
+                next,
+                index
+            ));
+        }
+
+        committed = Some(ent.log_id);
+        next = index + 1;
+    }
+
+    if let Target::Index(t) = target {
+        if committed.map(|c| c.index) != Some(t) {
+            return Err(anyhow!(
+                "log index {} is not backed up, the last restorable log is {:?}",
+                t,
+                committed
+            ));
+        }
+    }
+
+    Ok(committed)
+}
+
+async fn read_lines(op: &Operator, path: &str) -> anyhow::Result<Vec<String>> {
+    let buf = op.read(path).await?;
+    let s = String::from_utf8(buf.to_vec())?;
+    Ok(s.lines().map(|l| l.to_string()).collect())
+}
@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 use std::fmt;
+use std::time::Instant;
 
 use itertools::Itertools;
 
@@ -29,6 +30,15 @@ pub struct Endpoints {
 
     /// Nodes of the meta-service cluster.
     nodes: BTreeMap<String, Status>,
+
+    /// Learners of the meta-service cluster, which can serve stale reads.
+    learners: Vec<String>,
+
+    /// The index of the learner to send the next stale read to.
+    next_learner: usize,
+
+    /// Learners that failed to serve a read, they are not chosen until the time it maps to.
+    unhealthy_learners: BTreeMap<String, Instant>,
 }
 
 #[allow(clippy::len_without_is_empty)]
@@ -41,6 +51,9 @@ impl Endpoints {
                 .into_iter()
                 .map(|x| (x.to_string(), Status::default()))
                 .collect(),
+            learners: vec![],
+            next_learner: 0,
+            unhealthy_learners: BTreeMap::new(),
         }
     }
 
@@ -117,17 +130,66 @@ impl Endpoints {
     pub fn len(&self) -> usize {
         self.nodes.len()
     }
+
+    pub fn learners(&self) -> impl Iterator<Item = &'_ String> + '_ {
+        self.learners.iter()
+    }
+
+    /// Replace the learners of the meta-service cluster.
+    pub fn replace_learners(&mut self, learners: impl IntoIterator<Item = impl ToString>) {
+        self.learners = learners
+            .into_iter()
+            .map(|x| x.to_string())
+            .filter(|x| !x.is_empty())
+            .collect();
+
+        let learners = &self.learners;
+        self.unhealthy_learners.retain(|x, _| learners.contains(x));
+    }
+
+    /// Choose a healthy learner to send a stale read to, in a round-robin manner.
+    ///
+    /// It returns `None` if there is no healthy learner.
+    pub fn choose_learner(&mut self) -> Option<&str> {
+        let now = Instant::now();
+        self.unhealthy_learners.retain(|_, until| *until > now);
+
+        for _ in 0..self.learners.len() {
+            let index = self.next_learner % self.learners.len();
+            self.next_learner = index + 1;
+
+            if !self.unhealthy_learners.contains_key(&self.learners[index]) {
+                return Some(self.learners[index].as_str());
+            }
+        }
+        None
+    }
+
+    /// Do not choose the learner for stale reads until `until`.
+    pub fn evict_learner(&mut self, learner: &str, until: Instant) {
+        if self.learners.iter().any(|x| x == learner) {
+            self.unhealthy_learners.insert(learner.to_string(), until);
+        }
+    }
 }
 
 impl fmt::Display for Endpoints {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let keys = self.nodes.keys().map(|x| x.as_str()).join(", ");
-        write!(f, "current:{:?}, all:[{}]", self.current, keys)
+        write!(f, "current:{:?}, all:[{}]", self.current, keys)?;
+
+        if !self.learners.is_empty() {
+            write!(f, ", learners:[{}]", self.learners.join(", "))?;
+        }
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+    use std::time::Instant;
+
     use crate::endpoints::Endpoints;
 
     #[test]
@@ -209,4 +271,56 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_endpoints_choose_learner() -> anyhow::Result<()> {
+        let mut es = Endpoints::new(["a", "b"]);
+        assert_eq!(None, es.choose_learner());
+
+        es.replace_learners(["c", "", "d"]);
+        assert_eq!("current:None, all:[a, b], learners:[c, d]", es.to_string());
+        assert_eq!(Some("c"), es.choose_learner());
+        assert_eq!(Some("d"), es.choose_learner());
+        assert_eq!(Some("c"), es.choose_learner());
+
+        // Reduce learners list size is ok.
+        es.replace_learners(["e"]);
+        assert_eq!(Some("e"), es.choose_learner());
+
+        es.replace_learners(Vec::<String>::new());
+        assert_eq!(None, es.choose_learner());
+        assert_eq!("current:None, all:[a, b]", es.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_endpoints_evict_learner() -> anyhow::Result<()> {
+        let mut es = Endpoints::new(["a"]);
+        es.replace_learners(["c", "d"]);
+
+        let now = Instant::now();
+        es.evict_learner("c", now + Duration::from_secs(3600));
+        assert_eq!(Some("d"), es.choose_learner());
+        assert_eq!(Some("d"), es.choose_learner());
+
+        es.evict_learner("d", now + Duration::from_secs(3600));
+        assert_eq!(None, es.choose_learner());
+
+        // An expired eviction makes the learner available again.
+        es.evict_learner("d", now);
+        assert_eq!(Some("d"), es.choose_learner());
+
+        // A learner that is not in the list can not be evicted.
+        es.evict_learner("x", now + Duration::from_secs(3600));
+        assert!(!es.unhealthy_learners.contains_key("x"));
+
+        // Replacing the learners keeps the evictions of the remaining learners.
+        es.replace_learners(["c", "e"]);
+        assert_eq!(Some("e"), es.choose_learner());
+        assert_eq!(Some("e"), es.choose_learner());
+        assert!(!es.unhealthy_learners.contains_key("d"));
+
+        Ok(())
+    }
 }
@@ -43,6 +43,7 @@ use crate::message::GetClientInfo;
 use crate::message::GetClusterStatus;
 use crate::message::GetEndpoints;
 use crate::message::MakeEstablishedClient;
+use crate::message::StaleRead;
 use crate::message::Streamed;
 
 /// Bind a request type to its corresponding response type.
@@ -177,6 +178,14 @@ impl RequestFor for Streamed<ListKVReq> {
     type Reply = BoxStream<StreamItem>;
 }
 
+impl RequestFor for StaleRead<MGetKVReq> {
+    type Reply = BoxStream<StreamItem>;
+}
+
+impl RequestFor for StaleRead<ListKVReq> {
+    type Reply = BoxStream<StreamItem>;
+}
+
 impl RequestFor for UpsertKV {
     type Reply = UpsertKVReply;
 }
@@ -58,6 +58,7 @@ use bigbytesdb_common_meta_types::protobuf::WatchRequest;
 use bigbytesdb_common_meta_types::protobuf::WatchResponse;
 use bigbytesdb_common_meta_types::ConnectionError;
 use bigbytesdb_common_meta_types::GrpcConfig;
+use bigbytesdb_common_meta_types::GrpcHelper;
 use bigbytesdb_common_meta_types::MetaClientError;
 use bigbytesdb_common_meta_types::MetaError;
 use bigbytesdb_common_meta_types::MetaHandshakeError;
@@ -434,6 +435,14 @@ pub struct MetaGrpcClient {
     endpoints: Arc<Mutex<Endpoints>>,
     endpoints_str: Vec<String>,
     auto_sync_interval: Option<Duration>,
+
+    /// The max staleness of a `StaleRead` that can be served by a learner.
+    ///
+    /// If it is None, all reads are sent to the leader.
+    max_staleness: Option<Duration>,
+
+    /// How long a learner that failed to serve a read is not chosen again.
+    unhealthy_endpoint_evict_time: Duration,
 }
 
 impl Debug for MetaGrpcClient {
@@ -441,6 +450,7 @@ impl Debug for MetaGrpcClient {
         let mut de = f.debug_struct("MetaGrpcClient");
         de.field("endpoints", &*self.endpoints.lock());
         de.field("auto_sync_interval", &self.auto_sync_interval);
+        de.field("max_staleness", &self.max_staleness);
         de.finish()
     }
 }
@@ -463,17 +473,18 @@ impl MetaGrpcClient {
     /// The worker is a singleton and the returned handle is cheap to clone.
     /// When all handles are dropped the worker will quit, then the runtime will be destroyed.
     pub fn try_new(conf: &RpcClientConf) -> Result<Arc<ClientHandle>, MetaClientError> {
-        Self::try_create(
+        Self::create(
             conf.get_endpoints(),
             &conf.username,
             &conf.password,
             conf.timeout,
             conf.auto_sync_interval,
             conf.tls_conf.clone(),
+            conf.max_staleness,
+            conf.unhealthy_endpoint_evict_time,
         )
     }
 
-    #[fastrace::trace]
     pub fn try_create(
         endpoints_str: Vec<String>,
         username: &str,
@@ -481,6 +492,29 @@ impl MetaGrpcClient {
         timeout: Option<Duration>,
         auto_sync_interval: Option<Duration>,
         tls_config: Option<RpcClientTlsConfig>,
+    ) -> Result<Arc<ClientHandle>, MetaClientError> {
+        Self::create(
+            endpoints_str,
+            username,
+            password,
+            timeout,
+            auto_sync_interval,
+            tls_config,
+            None,
+            Duration::ZERO,
+        )
+    }
+
+    #[fastrace::trace]
+    fn create(
+        endpoints_str: Vec<String>,
+        username: &str,
+        password: &str,
+        timeout: Option<Duration>,
+        auto_sync_interval: Option<Duration>,
+        tls_config: Option<RpcClientTlsConfig>,
+        max_staleness: Option<Duration>,
+        unhealthy_endpoint_evict_time: Duration,
     ) -> Result<Arc<ClientHandle>, MetaClientError> {
         Self::endpoints_non_empty(&endpoints_str)?;
 
@@ -517,6 +551,8 @@ impl MetaGrpcClient {
             endpoints,
             endpoints_str,
             auto_sync_interval,
+            max_staleness,
+            unhealthy_endpoint_evict_time,
         });
 
         let worker_name = worker.to_string();
@@ -602,7 +638,7 @@ impl MetaGrpcClient {
         let resp = match req {
             message::Request::StreamMGet(r) => {
                 let strm = self
-                    .kv_read_v1(MetaGrpcReadReq::MGetKV(r.into_inner()))
+                    .kv_read_v1(MetaGrpcReadReq::MGetKV(r.into_inner()), false)
                     .with_timing_threshold(
                         threshold(),
                         info_spent("MetaGrpcClient::kv_read_v1(MGetKV)"),
@@ -612,7 +648,7 @@ impl MetaGrpcClient {
             }
             message::Request::StreamList(r) => {
                 let strm = self
-                    .kv_read_v1(MetaGrpcReadReq::ListKV(r.into_inner()))
+                    .kv_read_v1(MetaGrpcReadReq::ListKV(r.into_inner()), false)
                     .with_timing_threshold(
                         threshold(),
                         info_spent("MetaGrpcClient::kv_read_v1(ListKV)"),
@@ -620,6 +656,26 @@ impl MetaGrpcClient {
                     .await;
                 Response::StreamMGet(strm)
             }
+            message::Request::StaleMGet(r) => {
+                let strm = self
+                    .kv_read_v1(MetaGrpcReadReq::MGetKV(r.into_inner()), true)
+                    .with_timing_threshold(
+                        threshold(),
+                        info_spent("MetaGrpcClient::kv_read_v1(stale MGetKV)"),
+                    )
+                    .await;
+                Response::StreamMGet(strm)
+            }
+            message::Request::StaleList(r) => {
+                let strm = self
+                    .kv_read_v1(MetaGrpcReadReq::ListKV(r.into_inner()), true)
+                    .with_timing_threshold(
+                        threshold(),
+                        info_spent("MetaGrpcClient::kv_read_v1(stale ListKV)"),
+                    )
+                    .await;
+                Response::StreamMGet(strm)
+            }
             message::Request::Upsert(r) => {
                 let resp = self
                     .kv_api(r)
@@ -830,10 +886,18 @@ impl MetaGrpcClient {
                 }
             }
         };
-        let result: Vec<String> = endpoints?.data;
-        debug!("received meta endpoints: {:?}", result);
+        let reply = endpoints?;
+        debug!(
+            "received meta endpoints: {:?}, learners: {:?}",
+            reply.data, reply.learners
+        );
+
+        self.set_endpoints(reply.data).await?;
 
-        self.set_endpoints(result).await?;
+        {
+            let mut eps = self.endpoints.lock();
+            eps.replace_learners(reply.learners);
+        }
         Ok(())
     }
 
@@ -1112,8 +1176,20 @@ impl MetaGrpcClient {
     pub(crate) async fn kv_read_v1(
         &self,
         grpc_req: MetaGrpcReadReq,
+        stale: bool,
     ) -> Result<BoxStream<pb::StreamItem>, MetaError> {
-        debug!("{}::kv_read_v1 request: {:?}", self, grpc_req);
+        debug!(
+            "{}::kv_read_v1 request: {:?}, stale: {}",
+            self, grpc_req, stale
+        );
+
+        // Only reads explicitly marked as `StaleRead` go to learners,
+        // e.g. a read before a CAS transaction must observe the latest state.
+        if let Some(max_staleness) = self.max_staleness.filter(|_| stale) {
+            if let Some(strm) = self.kv_read_v1_from_learner(&grpc_req, max_staleness).await {
+                return Ok(strm);
+            }
+        }
 
         let mut failures = vec![];
 
@@ -1172,6 +1248,55 @@ impl MetaGrpcClient {
         Err(net_err.into())
     }
 
+    /// Send a read request to a learner, allowing it to serve with a state no staler than `max_staleness`.
+    ///
+    /// It returns `None` if there is no healthy learner or the learner fails,
+    /// in which case the caller should fall back to read from the leader.
+    /// A failed learner is not chosen again for `unhealthy_endpoint_evict_time`.
+    #[fastrace::trace]
+    #[async_backtrace::framed]
+    async fn kv_read_v1_from_learner(
+        &self,
+        grpc_req: &MetaGrpcReadReq,
+        max_staleness: Duration,
+    ) -> Option<BoxStream<pb::StreamItem>> {
+        let addr = {
+            let mut es = self.endpoints.lock();
+            es.choose_learner()?.to_string()
+        };
+
+        let mut established_client = match self.conn_pool.get(&addr).await {
+            Ok(c) => c,
+            Err(e) => {
+                warn!("{} failed to connect to learner {}: {:?}", self, addr, e);
+                grpc_metrics::incr_meta_grpc_make_client_fail(&addr);
+                self.evict_learner(&addr);
+                return None;
+            }
+        };
+
+        let raft_req: RaftRequest = grpc_req.clone().into();
+        let mut req = traced_req(raft_req);
+        GrpcHelper::add_request_max_staleness(&mut req, max_staleness);
+
+        let result = established_client
+            .kv_read_v1(req)
+            .with_timing_threshold(threshold(), info_spent("client::kv_read_v1 from learner"))
+            .await;
+
+        match result {
+            Ok(resp) => Some(resp.into_inner().boxed()),
+            Err(e) => {
+                warn!(
+                    "{}::kv_read_v1 from learner {} error: {:?}; request: {:?}",
+                    self, addr, e, grpc_req
+                );
+                self.evict_learner(&addr);
+                None
+            }
+        }
+    }
+
     #[fastrace::trace]
     #[async_backtrace::framed]
     pub(crate) async fn transaction(&self, req: TxnRequest) -> Result<TxnReply, MetaError> {
@@ -1211,6 +1336,16 @@ impl MetaGrpcClient {
         es.current().map(|x| x.to_string())
     }
 
+    fn evict_learner(&self, addr: &str) {
+        let until = Instant::now() + self.unhealthy_endpoint_evict_time;
+        self.endpoints.lock().evict_learner(addr, until);
+
+        info!(
+            "{} evict learner {} for {:?}",
+            self, addr, self.unhealthy_endpoint_evict_time
+        );
+    }
+
     fn choose_next_endpoint(&self) {
         let next = {
             let mut es = self.endpoints.lock();
@@ -25,6 +25,7 @@ use futures::StreamExt;
 use futures::TryStreamExt;
 
 use crate::ClientHandle;
+use crate::StaleRead;
 use crate::Streamed;
 
 #[tonic::async_trait]
@@ -63,3 +64,36 @@ impl kvapi::KVApi for ClientHandle {
         Ok(reply)
     }
 }
+
+impl ClientHandle {
+    /// Same as `get_kv_stream()`, but the read may be served by a learner
+    /// if `max_staleness` is configured.
+    ///
+    /// Do not use it for a read that a conditional update is built upon.
+    #[fastrace::trace]
+    pub async fn get_kv_stream_stale(
+        &self,
+        keys: &[String],
+    ) -> Result<KVStream<MetaError>, MetaError> {
+        let keys = keys.to_vec();
+        let strm = self.request(StaleRead(MGetKVReq { keys })).await?;
+        let strm = strm.map_err(MetaError::from);
+        Ok(strm.boxed())
+    }
+
+    /// Same as `list_kv()`, but the read may be served by a learner
+    /// if `max_staleness` is configured.
+    ///
+    /// Do not use it for a read that a conditional update is built upon.
+    #[fastrace::trace]
+    pub async fn list_kv_stale(&self, prefix: &str) -> Result<KVStream<MetaError>, MetaError> {
+        let strm = self
+            .request(StaleRead(ListKVReq {
+                prefix: prefix.to_string(),
+            }))
+            .await?;
+
+        let strm = strm.map_err(MetaError::from);
+        Ok(strm.boxed())
+    }
+}
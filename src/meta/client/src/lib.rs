@@ -34,6 +34,7 @@ pub use grpc_client::ClientHandle;
 pub use grpc_client::MetaChannelManager;
 pub use grpc_client::MetaGrpcClient;
 pub use message::ClientWorkerRequest;
+pub use message::StaleRead;
 pub use message::Streamed;
 use semver::Version;
 
@@ -121,6 +122,29 @@ pub static METACLI_COMMIT_SEMVER: LazyLock<Version> = LazyLock::new(|| {
 ///   🖥 server: add `TxnRequest::condition_tree`,
 ///              to specify a complex bool expression.
 ///
+/// - 2025-02-18: since 1.2.*
+///   🖥 server: add `TxnOp::FetchAddU64`,
+///              to atomically add to a u64 value and return the value before and after.
+///              The leader skips it unless every node reports the `fetch_add_u64` state machine feature.
+///   👥 client: fall back to a compare-and-swap if `FetchAddU64` is skipped without a response.
+///
+/// - 2025-02-20: since 1.2.*
+///   🖥 server: add `WatchRequest::since_seq`,
+///              to resume a watch stream from a seq without re-syncing all values.
+///   🖥 server: add `ClusterStatus::features`, which includes `watch_since_seq`.
+///   👥 client: `metactl watch --since-seq` fails if the server does not report `watch_since_seq`,
+///              an older server ignores `since_seq` and sends only new changes.
+///
+/// - 2025-03-01: since 1.2.*
+///   🖥 server: add `MemberListReply::learners` and `ClusterStatus::staleness_ms`.
+///   🖥 server: serve `KvReadV1` on a non-leader node with the local state machine,
+///              if the request specifies a max staleness and the node is fresh enough.
+///   👥 client: route `StaleRead` requests to learners if `max_staleness` is configured,
+///              a failing learner is evicted for `unhealthy_endpoint_evict_time`.
+///              An older server does not fill `MemberListReply::learners`,
+///              and an older learner forwards the read to the leader,
+///              thus the read falls back to the leader.
+///
 ///
 /// Server feature set:
 /// ```yaml
@@ -71,6 +71,20 @@ impl<T> Streamed<T> {
     }
 }
 
+/// Mark a streamed read that tolerates a stale result.
+///
+/// If `max_staleness` is configured, it may be served by a learner with a state
+/// no staler than that. It must not be used for a read that a conditional update,
+/// such as a transaction with a seq condition, is built upon.
+#[derive(Debug, Clone)]
+pub struct StaleRead<T>(pub T);
+
+impl<T> StaleRead<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
 /// Meta-client handle-to-worker request body
 #[derive(Debug, Clone, derive_more::From)]
 pub enum Request {
@@ -80,6 +94,12 @@ pub enum Request {
     /// List KVs by key prefix, returning a stream.
     StreamList(Streamed<ListKVReq>),
 
+    /// Get multiple KV that may be served by a learner, returning a stream.
+    StaleMGet(StaleRead<MGetKVReq>),
+
+    /// List KVs by key prefix that may be served by a learner, returning a stream.
+    StaleList(StaleRead<ListKVReq>),
+
     /// Update or insert KV
     Upsert(UpsertKV),
 
@@ -110,6 +130,8 @@ impl Request {
         match self {
             Request::StreamMGet(_) => "StreamMGet",
             Request::StreamList(_) => "StreamList",
+            Request::StaleMGet(_) => "StaleMGet",
+            Request::StaleList(_) => "StaleList",
             Request::Upsert(_) => "Upsert",
             Request::Txn(_) => "Txn",
             Request::Watch(_) => "Watch",
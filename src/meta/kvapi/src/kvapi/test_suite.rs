@@ -105,6 +105,8 @@ impl kvapi::TestSuite {
             .await?;
         self.kv_delete_by_prefix_transaction(&builder.build().await)
             .await?;
+        self.kv_transaction_fetch_add_u64(&builder.build().await)
+            .await?;
 
         Ok(())
     }
@@ -1343,6 +1345,53 @@ impl kvapi::TestSuite {
 
         Ok(())
     }
+
+    /// `FetchAddU64` operations are applied in order in a transaction.
+    /// An absent key is treated as 0, and the value is unchanged
+    /// if the seq does not match or the result exceeds `max_value`.
+    pub async fn kv_transaction_fetch_add_u64<KV: kvapi::KVApi>(
+        &self,
+        kv: &KV,
+    ) -> anyhow::Result<()> {
+        info!("--- {}", func_name!());
+        let key = || "txn_fetch_add_k1".to_string();
+
+        let txn = TxnRequest::unconditional(vec![
+            TxnOp::fetch_add_u64(key(), 3),
+            TxnOp::fetch_add_u64(key(), 2),
+            TxnOp::fetch_add_u64(key(), 1).with_match_seq(Some(1)),
+            TxnOp::fetch_add_u64(key(), 1).with_match_seq(Some(2)),
+            TxnOp::fetch_add_u64(key(), 5).with_max_value(Some(10)),
+            TxnOp::fetch_add_u64(key(), 4).with_max_value(Some(10)),
+        ]);
+
+        let resp = kv.transaction(txn).await?;
+
+        let expected = vec![
+            TxnOpResponse::fetch_add_u64(key(), 0, 0, 1, 3),
+            TxnOpResponse::fetch_add_u64(key(), 1, 3, 2, 5),
+            TxnOpResponse::fetch_add_u64(key(), 2, 5, 2, 5),
+            TxnOpResponse::fetch_add_u64(key(), 2, 5, 3, 6),
+            TxnOpResponse::fetch_add_u64(key(), 3, 6, 3, 6),
+            TxnOpResponse::fetch_add_u64(key(), 3, 6, 4, 10),
+        ];
+
+        self.check_transaction_responses(&resp, &expected, true);
+
+        let got = kv.get_kv(&key()).await?;
+        assert_eq!(Some(SeqV::new(4, b(10))), got);
+
+        // A value that is not a u64 is never changed.
+        kv.upsert_kv(UpsertKV::update(key(), &b("foo"))).await?;
+
+        let txn = TxnRequest::unconditional(vec![TxnOp::fetch_add_u64(key(), 1)]);
+        let resp = kv.transaction(txn).await?;
+
+        let expected = vec![TxnOpResponse::fetch_add_u64(key(), 5, 0, 5, 0)];
+        self.check_transaction_responses(&resp, &expected, true);
+
+        Ok(())
+    }
 }
 
 /// Test that write and read should be forwarded to leader
@@ -212,6 +212,7 @@ where F: Fn(&str, Vec<u8>) -> Result<Vec<u8>, anyhow::Error>
             }
             Request::Delete(_) => {}
             Request::DeleteByPrefix(_) => {}
+            Request::FetchAddU64(_) => {}
         }
 
         Ok(TxnOp { request: Some(req) })
@@ -0,0 +1,58 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This mod is the key point about compatibility.
+//! Everytime update anything in this file, update the `VER` and let the tests pass.
+
+use chrono::DateTime;
+use chrono::Utc;
+use bigbytesdb_common_meta_app::principal as mt;
+use bigbytesdb_common_protos::pb;
+
+use crate::reader_check_msg;
+use crate::FromToProto;
+use crate::Incompatible;
+use crate::MIN_READER_VER;
+use crate::VER;
+
+impl FromToProto for mt::PlanBaseline {
+    type PB = pb::PlanBaseline;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::PlanBaseline) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        Ok(mt::PlanBaseline {
+            name: p.name,
+            fingerprint: p.fingerprint,
+            query: p.query,
+            plan_hints: p.plan_hints,
+            created_on: DateTime::<Utc>::from_pb(p.created_on)?,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::PlanBaseline, Incompatible> {
+        Ok(pb::PlanBaseline {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            fingerprint: self.fingerprint.clone(),
+            query: self.query.clone(),
+            plan_hints: self.plan_hints.clone(),
+            created_on: self.created_on.to_pb()?,
+        })
+    }
+}
@@ -41,6 +41,7 @@ impl FromToProto for SequenceMeta {
             start: p.start,
             current: p.current,
             step: p.step,
+            storage_version: p.storage_version,
         };
         Ok(v)
     }
@@ -55,6 +56,7 @@ impl FromToProto for SequenceMeta {
             start: self.start,
             current: self.current,
             step: self.step,
+            storage_version: self.storage_version,
         };
         Ok(p)
     }
@@ -27,6 +27,7 @@ use bigbytesdb_common_meta_app::storage::StorageParams;
 use bigbytesdb_common_meta_app::tenant::Tenant;
 use bigbytesdb_common_meta_types::NonEmptyString;
 use bigbytesdb_common_protos::pb;
+use num::FromPrimitive;
 
 use crate::reader_check_msg;
 use crate::FromToProto;
@@ -344,6 +345,9 @@ impl FromToProto for mt::TableIndex {
         reader_check_msg(p.ver, p.min_reader_ver)?;
 
         let v = Self {
+            index_type: FromPrimitive::from_i32(p.index_type).ok_or_else(|| {
+                Incompatible::new(format!("invalid TableIndexType: {}", p.index_type))
+            })?,
             name: p.name,
             column_ids: p.column_ids,
             sync_creation: p.sync_creation,
@@ -362,6 +366,7 @@ impl FromToProto for mt::TableIndex {
             sync_creation: self.sync_creation,
             version: self.version.clone(),
             options: self.options.clone(),
+            index_type: self.index_type as i32,
         };
         Ok(p)
     }
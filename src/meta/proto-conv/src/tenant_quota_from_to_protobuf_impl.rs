@@ -38,6 +38,10 @@ impl FromToProto for tenant::TenantQuota {
             max_stages: p.max_stages,
             max_files_per_stage: p.max_files_per_stage,
             max_users: p.max_users,
+            max_storage_bytes: p.max_storage_bytes,
+            max_concurrent_queries: p.max_concurrent_queries,
+            max_warehouses: p.max_warehouses,
+            max_scanned_bytes_per_month: p.max_scanned_bytes_per_month,
         };
         Ok(v)
     }
@@ -51,6 +55,10 @@ impl FromToProto for tenant::TenantQuota {
             max_stages: self.max_stages,
             max_files_per_stage: self.max_files_per_stage,
             max_users: self.max_users,
+            max_storage_bytes: self.max_storage_bytes,
+            max_concurrent_queries: self.max_concurrent_queries,
+            max_warehouses: self.max_warehouses,
+            max_scanned_bytes_per_month: self.max_scanned_bytes_per_month,
         };
         Ok(p)
     }
@@ -104,7 +104,8 @@ impl FromToProto for mt::principal::UserOption {
             .with_network_policy(p.network_policy)
             .with_password_policy(p.password_policy)
             .with_disabled(p.disabled)
-            .with_must_change_password(p.must_change_password))
+            .with_must_change_password(p.must_change_password)
+            .with_workload_group(p.workload_group))
     }
 
     fn to_pb(&self) -> Result<pb::UserOption, Incompatible> {
@@ -117,6 +118,7 @@ impl FromToProto for mt::principal::UserOption {
             password_policy: self.password_policy().cloned(),
             disabled: self.disabled().cloned(),
             must_change_password: self.must_change_password().cloned(),
+            workload_group: self.workload_group().cloned(),
         })
     }
 }
@@ -149,6 +149,11 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (117, "2025-01-21: Add: config.proto: add disable_list_batch in WebhdfsConfig"),
     (118, "2025-01-22: Add: config.proto: add user_name in WebhdfsConfig"),
     (119, "2025-01-25: Add: virtual_column add alias_names and auto_generated field"),
+    (120, "2025-02-06: Add: plan_baseline.proto: PlanBaseline"),
+    (121, "2025-02-10: Add: table.proto: add TableIndex.index_type"),
+    (122, "2025-02-14: Add: workload_group.proto: WorkloadGroup, user.proto: UserOption.workload_group, role.proto: RoleInfo.workload_group"),
+    (123, "2025-02-18: Add: sequence.proto: SequenceMeta.storage_version"),
+    (124, "2025-03-08: Add: tenant.proto: TenantQuota add max_storage_bytes, max_concurrent_queries, max_warehouses and max_scanned_bytes_per_month"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -117,3 +117,8 @@ mod v116_marked_deleted_index_meta;
 mod v117_webhdfs_add_disable_list_batch;
 mod v118_webhdfs_add_user_name;
 mod v119_virtual_column;
+mod v120_plan_baseline;
+mod v121_table_index_type;
+mod v122_workload_group;
+mod v123_sequence_storage_version;
+mod v124_tenant_quota;
@@ -43,6 +43,7 @@ fn test_decode_v90_role() -> anyhow::Result<()> {
         grants: UserGrantSet::new(vec![], HashSet::new()),
         created_on: DateTime::<Utc>::default(),
         update_on: DateTime::<Utc>::default(),
+        workload_group: None,
     };
     common::test_pb_from_to(func_name!(), want())?;
     common::test_load_old(func_name!(), role_info_v90.as_slice(), 90, want())?;
@@ -36,6 +36,10 @@ fn test_decode_v100_tenant_quota() -> anyhow::Result<()> {
         max_stages: 3,
         max_files_per_stage: 4,
         max_users: 5,
+        max_storage_bytes: 0,
+        max_concurrent_queries: 0,
+        max_warehouses: 0,
+        max_scanned_bytes_per_month: 0,
     };
     common::test_load_old(func_name!(), tenant_quota_v100.as_slice(), 100, want())?;
     common::test_pb_from_to(func_name!(), want())?;
@@ -0,0 +1,58 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use bigbytesdb_common_meta_app::principal::PlanBaseline;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `proto_conv::test_build_pb_buf()`
+#[test]
+fn test_decode_v120_plan_baseline() -> anyhow::Result<()> {
+    let plan_baseline_v120 = vec![
+        10, 3, 112, 98, 49, 18, 64, 52, 56, 100, 57, 97, 54, 99, 55, 97, 49, 98, 99, 49, 101, 50,
+        57, 100, 48, 101, 52, 99, 51, 98, 57, 102, 56, 98, 50, 55, 97, 53, 100, 48, 97, 52, 102,
+        54, 101, 49, 99, 50, 98, 51, 100, 52, 101, 53, 102, 54, 48, 55, 49, 56, 50, 57, 51, 97, 52,
+        98, 53, 99, 54, 100, 55, 26, 51, 83, 69, 76, 69, 67, 84, 32, 42, 32, 70, 82, 79, 77, 32,
+        116, 49, 44, 32, 116, 50, 32, 87, 72, 69, 82, 69, 32, 116, 49, 46, 97, 32, 61, 32, 116, 50,
+        46, 97, 32, 65, 78, 68, 32, 116, 50, 46, 98, 32, 62, 32, 49, 34, 28, 76, 69, 65, 68, 73,
+        78, 71, 40, 116, 50, 32, 116, 49, 41, 32, 66, 82, 79, 65, 68, 67, 65, 83, 84, 40, 116, 49,
+        41, 42, 23, 50, 48, 50, 53, 45, 48, 50, 45, 48, 54, 32, 49, 48, 58, 48, 48, 58, 48, 48, 32,
+        85, 84, 67, 160, 6, 120, 168, 6, 24,
+    ];
+
+    let want = || PlanBaseline {
+        name: "pb1".to_string(),
+        fingerprint: "48d9a6c7a1bc1e29d0e4c3b9f8b27a5d0a4f6e1c2b3d4e5f60718293a4b5c6d7".to_string(),
+        query: "SELECT * FROM t1, t2 WHERE t1.a = t2.a AND t2.b > 1".to_string(),
+        plan_hints: "LEADING(t2 t1) BROADCAST(t1)".to_string(),
+        created_on: Utc.with_ymd_and_hms(2025, 2, 6, 10, 0, 0).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), plan_baseline_v120.as_slice(), 120, want())?;
+
+    Ok(())
+}
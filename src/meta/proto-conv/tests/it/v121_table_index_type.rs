@@ -0,0 +1,53 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigbytesdb_common_meta_app::schema as mt;
+use fastrace::func_name;
+use maplit::btreemap;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `proto_conv::test_build_pb_buf()`
+#[test]
+fn test_decode_v121_table_index_type() -> anyhow::Result<()> {
+    let table_index_v121 = vec![
+        10, 4, 105, 100, 120, 49, 18, 1, 1, 24, 1, 34, 32, 102, 49, 48, 98, 50, 51, 48, 49, 53, 51,
+        101, 49, 52, 102, 50, 99, 56, 52, 54, 48, 51, 57, 53, 56, 100, 55, 102, 56, 54, 52, 102,
+        56, 42, 18, 10, 8, 100, 105, 115, 116, 97, 110, 99, 101, 18, 6, 99, 111, 115, 105, 110,
+        101, 48, 1, 160, 6, 121, 168, 6, 24,
+    ];
+
+    let want = || mt::TableIndex {
+        index_type: mt::TableIndexType::Vector,
+        name: "idx1".to_string(),
+        column_ids: vec![1],
+        sync_creation: true,
+        version: "f10b230153e14f2c84603958d7f864f8".to_string(),
+        options: btreemap! {"distance".to_string() => "cosine".to_string()},
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), table_index_v121.as_slice(), 121, want())?;
+
+    Ok(())
+}
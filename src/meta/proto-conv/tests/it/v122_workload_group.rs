@@ -0,0 +1,102 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use bigbytesdb_common_meta_app::principal::RoleInfo;
+use bigbytesdb_common_meta_app::principal::UserGrantSet;
+use bigbytesdb_common_meta_app::principal::UserOption;
+use bigbytesdb_common_meta_app::principal::WorkloadGroup;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `proto_conv::test_build_pb_buf()`
+#[test]
+fn test_decode_v122_workload_group() -> anyhow::Result<()> {
+    let workload_group_v122 = vec![
+        10, 3, 101, 116, 108, 16, 4, 24, 10, 32, 60, 40, 128, 128, 128, 128, 4, 48, 200, 1, 58, 23,
+        50, 48, 50, 53, 45, 48, 50, 45, 49, 52, 32, 49, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67,
+        66, 23, 50, 48, 50, 53, 45, 48, 50, 45, 49, 52, 32, 49, 49, 58, 48, 48, 58, 48, 48, 32, 85,
+        84, 67, 160, 6, 122, 168, 6, 24,
+    ];
+
+    let want = || WorkloadGroup {
+        name: "etl".to_string(),
+        max_concurrency: 4,
+        max_queued: 10,
+        queued_timeout_secs: 60,
+        max_memory_usage: 1024 * 1024 * 1024,
+        cpu_weight: 200,
+        created_on: Utc.with_ymd_and_hms(2025, 2, 14, 10, 0, 0).unwrap(),
+        updated_on: Utc.with_ymd_and_hms(2025, 2, 14, 11, 0, 0).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), workload_group_v122.as_slice(), 122, want())?;
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_v122_role_workload_group() -> anyhow::Result<()> {
+    let role_info_v122 = vec![
+        10, 2, 114, 49, 18, 6, 160, 6, 122, 168, 6, 24, 26, 23, 50, 48, 50, 53, 45, 48, 50, 45, 49,
+        52, 32, 49, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 34, 23, 50, 48, 50, 53, 45, 48, 50,
+        45, 49, 52, 32, 49, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 42, 3, 101, 116, 108, 160,
+        6, 122, 168, 6, 24,
+    ];
+
+    let want = || RoleInfo {
+        name: "r1".to_string(),
+        grants: UserGrantSet::new(vec![], HashSet::new()),
+        created_on: Utc.with_ymd_and_hms(2025, 2, 14, 10, 0, 0).unwrap(),
+        update_on: Utc.with_ymd_and_hms(2025, 2, 14, 10, 0, 0).unwrap(),
+        workload_group: Some("etl".to_string()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), role_info_v122.as_slice(), 122, want())?;
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_v122_user_option_workload_group() -> anyhow::Result<()> {
+    let user_option_v122 = vec![
+        18, 5, 114, 111, 108, 101, 49, 58, 3, 101, 116, 108, 160, 6, 122, 168, 6, 24,
+    ];
+
+    let want = || {
+        UserOption::default()
+            .with_default_role(Some("role1".to_string()))
+            .with_workload_group(Some("etl".to_string()))
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), user_option_v122.as_slice(), 122, want())?;
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use bigbytesdb_common_meta_app::schema as mt;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `proto_conv::test_build_pb_buf()`
+#[test]
+fn test_decode_v123_sequence_meta() -> anyhow::Result<()> {
+    let sequence_meta_v123 = vec![
+        10, 23, 49, 57, 55, 48, 45, 48, 49, 45, 48, 49, 32, 48, 50, 58, 53, 49, 58, 48, 55, 32, 85,
+        84, 67, 18, 23, 49, 57, 55, 48, 45, 48, 49, 45, 48, 49, 32, 48, 50, 58, 53, 49, 58, 48, 55,
+        32, 85, 84, 67, 26, 3, 115, 101, 113, 32, 1, 40, 1, 48, 10, 56, 1, 160, 6, 123, 168, 6, 24,
+    ];
+
+    let want = || mt::SequenceMeta {
+        create_on: DateTime::<Utc>::from_timestamp(10267, 0).unwrap(),
+        update_on: DateTime::<Utc>::from_timestamp(10267, 0).unwrap(),
+        comment: Some("seq".to_string()),
+        start: 1,
+        step: 1,
+        current: 10,
+        storage_version: 1,
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), sequence_meta_v123.as_slice(), 123, want())?;
+
+    Ok(())
+}
@@ -33,6 +33,7 @@ use bigbytesdb_common_meta_types::Change;
 use bigbytesdb_common_meta_types::Cmd;
 use bigbytesdb_common_meta_types::CmdContext;
 use bigbytesdb_common_meta_types::ConditionResult;
+use bigbytesdb_common_meta_types::FetchAddU64;
 use bigbytesdb_common_meta_types::Interval;
 use bigbytesdb_common_meta_types::MatchSeq;
 use bigbytesdb_common_meta_types::MetaSpec;
@@ -436,6 +437,9 @@ where SM: StateMachineApi + 'static
                 self.txn_execute_delete_by_prefix(delete_by_prefix, resp)
                     .await?;
             }
+            Some(txn_op::Request::FetchAddU64(fetch_add)) => {
+                self.txn_execute_fetch_add_u64(fetch_add, resp).await?;
+            }
             None => {}
         }
         Ok(())
@@ -532,6 +536,49 @@ where SM: StateMachineApi + 'static
         Ok(())
     }
 
+    /// Add `delta` to the u64 value of a key in place, without a read-modify-write round trip.
+    ///
+    /// The value is left unchanged if the `match_seq` does not match,
+    /// the result is out of range, or the stored value is not a JSON encoded u64.
+    async fn txn_execute_fetch_add_u64(
+        &mut self,
+        fetch_add: &FetchAddU64,
+        resp: &mut TxnReply,
+    ) -> Result<(), io::Error> {
+        let sv = self.sm.get_maybe_expired_kv(&fetch_add.key).await?;
+        let before_seq = sv.as_ref().map(|x| x.seq).unwrap_or_default();
+        let before = match &sv {
+            None => Some(0),
+            Some(x) => serde_json::from_slice::<u64>(&x.data).ok(),
+        };
+
+        let seq_matched = fetch_add.match_seq.is_none_or(|seq| seq == before_seq);
+        let after = before
+            .and_then(|before| before.checked_add(fetch_add.delta))
+            .filter(|after| fetch_add.max_value.is_none_or(|max| *after <= max));
+
+        let before = before.unwrap_or_default();
+        let (after_seq, after) = match after {
+            Some(after) if seq_matched && after != before => {
+                let value = serde_json::to_vec(&after).map_err(io::Error::other)?;
+                let (_prev, result) = self
+                    .upsert_kv(&UpsertKV::update(&fetch_add.key, &value))
+                    .await?;
+                (result.map(|x| x.seq).unwrap_or_default(), after)
+            }
+            _ => (before_seq, before),
+        };
+
+        resp.responses.push(TxnOpResponse::fetch_add_u64(
+            &fetch_add.key,
+            before_seq,
+            before,
+            after_seq,
+            after,
+        ));
+        Ok(())
+    }
+
     /// Before applying, list expired keys to clean.
     ///
     /// All expired keys will be removed before applying a log.
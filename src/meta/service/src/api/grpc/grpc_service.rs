@@ -76,6 +76,7 @@ use crate::metrics::network_metrics;
 use crate::metrics::RequestInFlight;
 use crate::version::from_digit_ver;
 use crate::version::to_digit_ver;
+use crate::version::API_FEATURES;
 use crate::version::METASRV_SEMVER;
 use crate::version::MIN_METACLI_SEMVER;
 use crate::watcher::WatchStream;
@@ -132,9 +133,37 @@ impl MetaServiceImpl {
         &self,
         request: Request<RaftRequest>,
     ) -> Result<(Option<Endpoint>, BoxStream<StreamItem>), Status> {
+        let max_staleness = GrpcHelper::get_request_max_staleness(&request);
         let req: MetaGrpcReadReq = GrpcHelper::parse_req(request)?;
 
-        debug!("{}: Received ReadRequest: {:?}", func_name!(), req);
+        debug!(
+            "{}: Received ReadRequest: {:?}, max_staleness: {:?}",
+            func_name!(),
+            req,
+            max_staleness
+        );
+
+        if let Some(max_staleness) = max_staleness {
+            let res = self
+                .meta_node
+                .try_stale_read(req.clone(), max_staleness)
+                .await
+                .map_err(GrpcHelper::internal_err);
+
+            match res {
+                Ok(Some(strm)) => {
+                    network_metrics::incr_request_result(true);
+                    return Ok((None, strm));
+                }
+                Ok(None) => {
+                    // Too stale, forward to leader.
+                }
+                Err(e) => {
+                    network_metrics::incr_request_result(false);
+                    return Err(e);
+                }
+            }
+        }
 
         let req = ForwardRequest::new(1, req);
 
@@ -389,15 +418,19 @@ impl MetaService for MetaServiceImpl {
         let watch = request.into_inner();
 
         let key_range = watch.key_range().map_err(Status::invalid_argument)?;
-        let flush = watch.initial_flush;
+        // A resumed watch replays the missed changes instead of flushing all values.
+        let flush = watch.initial_flush && watch.since_seq.is_none();
 
         let (tx, rx) = mpsc::channel(4);
 
         let mn = &self.meta_node;
 
-        let sender = mn.add_watcher(watch, tx.clone()).await?;
+        let (sender, replay) = mn.add_watcher(watch, tx.clone()).await?;
         let stream = WatchStream::new(rx, sender, mn.subscriber_handle.clone());
 
+        // The replayed changes precede the ones dispatched after the watcher is added.
+        let stream = futures::stream::iter(replay.into_iter().map(Ok)).chain(stream);
+
         if flush {
             let sm = mn.raft_store.state_machine.clone();
             {
@@ -419,8 +452,12 @@ impl MetaService for MetaServiceImpl {
 
         let meta_node = &self.meta_node;
         let members = meta_node.get_grpc_advertise_addrs().await;
+        let learners = meta_node.get_learner_grpc_advertise_addrs().await;
 
-        let resp = MemberListReply { data: members };
+        let resp = MemberListReply {
+            data: members,
+            learners,
+        };
         network_metrics::incr_sent_bytes(resp.encoded_len() as u64);
 
         Ok(Response::new(resp))
@@ -474,6 +511,8 @@ impl MetaService for MetaServiceImpl {
             voters: status.voters.iter().map(|n| n.to_string()).collect(),
             non_voters: status.non_voters.iter().map(|n| n.to_string()).collect(),
             last_seq: status.last_seq,
+            staleness_ms: status.staleness_ms,
+            features: API_FEATURES.iter().map(|f| f.to_string()).collect(),
         };
         Ok(Response::new(resp))
     }
@@ -0,0 +1,110 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The layout of the files in a backup storage:
+//!
+//! ```text
+//! snapshot/<time_ms>-<last_applied>.json
+//! snapshot/<time_ms>.json.partial
+//! log/<first_index>-<last_index>.json
+//! ```
+//!
+//! Both kinds of files are lines of JSON strings in the format of `metactl export`.
+
+use std::io;
+
+use opendal::Operator;
+
+pub const SNAPSHOT_DIR: &str = "snapshot/";
+pub const LOG_DIR: &str = "log/";
+
+/// A full export of a meta-service node taken at `time_ms`,
+/// whose state machine has applied logs up to index `last_applied`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SnapshotFile {
+    pub time_ms: u64,
+    pub last_applied: u64,
+}
+
+impl SnapshotFile {
+    pub fn path(&self) -> String {
+        format!(
+            "{}{:020}-{:020}.json",
+            SNAPSHOT_DIR, self.time_ms, self.last_applied
+        )
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        let (time_ms, last_applied) = parse_pair(name)?;
+        Some(Self {
+            time_ms,
+            last_applied,
+        })
+    }
+}
+
+/// The path a snapshot is written to before its `last_applied` is known.
+///
+/// It is not listed as a snapshot.
+pub fn partial_snapshot_path(time_ms: u64) -> String {
+    format!("{}{:020}.json.partial", SNAPSHOT_DIR, time_ms)
+}
+
+/// A series of committed raft-logs in the index range `[first, last]`,
+/// preceded by the vote when they are backed up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LogSegmentFile {
+    pub first: u64,
+    pub last: u64,
+}
+
+impl LogSegmentFile {
+    pub fn path(&self) -> String {
+        format!("{}{:020}-{:020}.json", LOG_DIR, self.first, self.last)
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        let (first, last) = parse_pair(name)?;
+        Some(Self { first, last })
+    }
+}
+
+fn parse_pair(name: &str) -> Option<(u64, u64)> {
+    let (a, b) = name.strip_suffix(".json")?.split_once('-')?;
+    Some((a.parse().ok()?, b.parse().ok()?))
+}
+
+/// List the snapshots in the backup storage, in time order.
+pub async fn list_snapshots(op: &Operator) -> Result<Vec<SnapshotFile>, io::Error> {
+    let mut files = op
+        .list(SNAPSHOT_DIR)
+        .await?
+        .iter()
+        .filter_map(|entry| SnapshotFile::parse(entry.name()))
+        .collect::<Vec<_>>();
+    files.sort();
+    Ok(files)
+}
+
+/// List the raft-log segments in the backup storage, in index order.
+pub async fn list_log_segments(op: &Operator) -> Result<Vec<LogSegmentFile>, io::Error> {
+    let mut files = op
+        .list(LOG_DIR)
+        .await?
+        .iter()
+        .filter_map(|entry| LogSegmentFile::parse(entry.name()))
+        .collect::<Vec<_>>();
+    files.sort();
+    Ok(files)
+}
@@ -0,0 +1,318 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io;
+use std::io::ErrorKind;
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use bigbytesdb_common_meta_raft_store::key_spaces::RaftStoreEntry;
+use bigbytesdb_common_meta_raft_store::state_machine::StateMachineMetaKey;
+use bigbytesdb_common_meta_raft_store::state_machine::StateMachineMetaValue;
+use bigbytesdb_common_meta_types::MetaStartupError;
+use futures::TryStreamExt;
+use log::info;
+use log::warn;
+use opendal::Operator;
+use tokio::time::sleep;
+use tokio::time::Instant;
+
+use crate::backup::layout::list_log_segments;
+use crate::backup::layout::list_snapshots;
+use crate::backup::layout::partial_snapshot_path;
+use crate::backup::layout::LogSegmentFile;
+use crate::backup::layout::SnapshotFile;
+use crate::backup::operator::new_backup_operator;
+use crate::configs::BackupConfig;
+use crate::meta_service::MetaNode;
+
+/// Flush the exported lines to the backup storage when the buffer reaches this size.
+const WRITE_BUFFER_SIZE: usize = 8 * 1024 * 1024;
+
+/// The result of [`MetaBackup::backup_logs`].
+#[derive(Debug)]
+pub enum LogBackup {
+    /// There is no new committed log.
+    Empty,
+    /// The new committed logs are written to this segment.
+    Segment(LogSegmentFile),
+    /// Logs in `[since, first)` are purged before being backed up.
+    Purged { since: u64, first: u64 },
+}
+
+/// Periodically back up the data of a meta-service node to the configured storage.
+///
+/// Only the leader writes backups, thus there is at most one writer in a cluster.
+pub struct MetaBackup {
+    meta_node: Weak<MetaNode>,
+    config: BackupConfig,
+    operator: Operator,
+}
+
+impl MetaBackup {
+    /// Create a backup task if a backup storage is configured.
+    pub fn create(
+        meta_node: Arc<MetaNode>,
+        config: &BackupConfig,
+    ) -> Result<Option<Self>, MetaStartupError> {
+        let Some(storage) = &config.storage else {
+            return Ok(None);
+        };
+
+        let operator = new_backup_operator(storage).map_err(|e| {
+            MetaStartupError::InvalidConfig(format!("invalid backup storage: {}", e))
+        })?;
+
+        Ok(Some(Self {
+            meta_node: Arc::downgrade(&meta_node),
+            config: config.clone(),
+            operator,
+        }))
+    }
+
+    /// Run the backup loop in the background until the meta node is dropped.
+    pub fn spawn(self) {
+        bigbytesdb_common_base::runtime::spawn(async move {
+            self.main().await;
+        });
+    }
+
+    async fn main(self) {
+        info!("MetaBackup start: {:?}", self.config);
+
+        let interval = Duration::from_secs(self.config.interval_secs.max(1));
+        let snapshot_interval = Duration::from_secs(self.config.snapshot_interval_secs);
+        let mut last_snapshot: Option<Instant> = None;
+
+        loop {
+            sleep(interval).await;
+
+            let Some(meta_node) = self.meta_node.upgrade() else {
+                info!("MetaBackup quit: meta node is dropped");
+                return;
+            };
+
+            let id = meta_node.raft_store.id;
+            let leader = meta_node.get_leader().await;
+            if !matches!(leader, Ok(Some(l)) if l == id) {
+                // Only leader backs up; a follower may become leader later.
+                last_snapshot = None;
+                continue;
+            }
+
+            let snapshot_due = last_snapshot.is_none_or(|t| t.elapsed() >= snapshot_interval);
+
+            if snapshot_due && self.snapshot(&meta_node).await {
+                last_snapshot = Some(Instant::now());
+            }
+
+            match self.backup_logs(&meta_node).await {
+                Ok(LogBackup::Empty) => {}
+                Ok(LogBackup::Segment(file)) => {
+                    info!("MetaBackup: logs are backed up: {}", file.path());
+                }
+                Ok(LogBackup::Purged { since, first }) => {
+                    // The backed up logs can not be replayed across the gap,
+                    // a new snapshot covering it is required.
+                    warn!(
+                        "MetaBackup: logs [{}, {}) are purged before being backed up, take a new snapshot",
+                        since, first
+                    );
+                    if self.snapshot(&meta_node).await {
+                        last_snapshot = Some(Instant::now());
+                    }
+                }
+                Err(e) => {
+                    warn!("MetaBackup: failed to back up logs: {}", e);
+                }
+            }
+        }
+    }
+
+    /// Back up a snapshot and return whether it succeeded.
+    async fn snapshot(&self, meta_node: &MetaNode) -> bool {
+        match self.backup_snapshot(meta_node).await {
+            Ok(file) => {
+                info!("MetaBackup: snapshot is backed up: {}", file.path());
+                true
+            }
+            Err(e) => {
+                warn!("MetaBackup: failed to back up snapshot: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Export a full snapshot of the node and write it to the backup storage,
+    /// then remove the snapshots and log segments that are out of retention.
+    ///
+    /// The export is streamed to a partial file, which is copied to the final path
+    /// once `last_applied` is known.
+    pub async fn backup_snapshot(&self, meta_node: &MetaNode) -> Result<SnapshotFile, io::Error> {
+        let time_ms = now_ms();
+        let partial = partial_snapshot_path(time_ms);
+
+        let res = self.write_snapshot(meta_node, &partial).await;
+        let res = match res {
+            Ok(last_applied) => {
+                let file = SnapshotFile {
+                    time_ms,
+                    last_applied,
+                };
+                self.operator
+                    .copy(&partial, &file.path())
+                    .await
+                    .map(|_| file)
+                    .map_err(io::Error::from)
+            }
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = self.operator.delete(&partial).await {
+            warn!("MetaBackup: failed to remove {}: {}", partial, e);
+        }
+        let file = res?;
+
+        self.remove_expired().await?;
+
+        Ok(file)
+    }
+
+    /// Stream the export of the node to `path` and return the index of the last applied log.
+    async fn write_snapshot(&self, meta_node: &MetaNode, path: &str) -> Result<u64, io::Error> {
+        let mut strm = meta_node.raft_store.inner().export();
+        let mut writer = self.operator.writer(path).await?;
+
+        let mut last_applied = 0;
+        let mut buf = String::new();
+        while let Some(line) = strm.try_next().await? {
+            if line.contains("StateMachineMeta") {
+                let (_tree, entry): (String, RaftStoreEntry) = serde_json::from_str(&line)
+                    .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+
+                if let RaftStoreEntry::StateMachineMeta {
+                    key: StateMachineMetaKey::LastApplied,
+                    value: StateMachineMetaValue::LogId(log_id),
+                } = entry
+                {
+                    last_applied = log_id.index;
+                }
+            }
+
+            buf.push_str(&line);
+            buf.push('\n');
+            if buf.len() >= WRITE_BUFFER_SIZE {
+                writer.write(std::mem::take(&mut buf)).await?;
+            }
+        }
+
+        if !buf.is_empty() {
+            writer.write(buf).await?;
+        }
+        writer.close().await?;
+
+        Ok(last_applied)
+    }
+
+    /// Write the logs committed since the last backed up log segment to the backup storage.
+    ///
+    /// If the logs since the last segment are purged and the latest snapshot does not cover
+    /// them, nothing is written and [`LogBackup::Purged`] is returned.
+    pub async fn backup_logs(&self, meta_node: &MetaNode) -> Result<LogBackup, io::Error> {
+        let segments = list_log_segments(&self.operator).await?;
+        let since = segments.last().map(|s| s.last + 1).unwrap_or_default();
+
+        let (vote, entries) = meta_node
+            .raft_store
+            .inner()
+            .export_committed_logs(since)
+            .await?;
+
+        let (Some(first), Some(last)) = (entries.first(), entries.last()) else {
+            return Ok(LogBackup::Empty);
+        };
+
+        let first_index = first.log_id.index;
+        if first_index > since {
+            let snapshots = list_snapshots(&self.operator).await?;
+            let covered = snapshots
+                .last()
+                .is_some_and(|s| s.last_applied + 1 >= first_index);
+            if !covered {
+                return Ok(LogBackup::Purged {
+                    since,
+                    first: first_index,
+                });
+            }
+        }
+
+        let file = LogSegmentFile {
+            first: first_index,
+            last: last.log_id.index,
+        };
+
+        let mut lines = vec![encode_line(&RaftStoreEntry::Vote(vote))?];
+        for entry in entries {
+            lines.push(encode_line(&RaftStoreEntry::LogEntry(entry))?);
+        }
+
+        let mut data = lines.join("\n");
+        data.push('\n');
+        self.operator.write(&file.path(), data).await?;
+
+        Ok(LogBackup::Segment(file))
+    }
+
+    /// Keep the latest `retention` snapshots and the log segments that are needed to replay them.
+    async fn remove_expired(&self) -> Result<(), io::Error> {
+        let retention = self.config.retention as usize;
+        if retention == 0 {
+            return Ok(());
+        }
+
+        let snapshots = list_snapshots(&self.operator).await?;
+        if snapshots.len() <= retention {
+            return Ok(());
+        }
+
+        let (expired, kept) = snapshots.split_at(snapshots.len() - retention);
+        for s in expired {
+            self.operator.delete(&s.path()).await?;
+        }
+
+        let oldest = kept[0].last_applied;
+        for seg in list_log_segments(&self.operator).await? {
+            if seg.last < oldest {
+                self.operator.delete(&seg.path()).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn encode_line(entry: &RaftStoreEntry) -> Result<String, io::Error> {
+    serde_json::to_string(&("raft_log", entry))
+        .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
@@ -0,0 +1,33 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Periodically back up the meta data to an object storage,
+//! so that a cluster can be restored to a point in time with `metactl restore`.
+//!
+//! A backup consists of full snapshots, which are taken every `snapshot_interval_secs`,
+//! and the raft-log segments committed since the last backup, which are taken every `interval_secs`.
+
+mod layout;
+mod meta_backup;
+mod operator;
+
+pub use layout::list_log_segments;
+pub use layout::list_snapshots;
+pub use layout::LogSegmentFile;
+pub use layout::SnapshotFile;
+pub use layout::LOG_DIR;
+pub use layout::SNAPSHOT_DIR;
+pub use meta_backup::LogBackup;
+pub use meta_backup::MetaBackup;
+pub use operator::new_backup_operator;
@@ -0,0 +1,65 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::env;
+use std::io;
+use std::io::ErrorKind;
+
+use bigbytesdb_common_meta_app::storage::StorageParams;
+use opendal::services;
+use opendal::Operator;
+
+/// Build an operator to access the backup storage.
+///
+/// Only `fs` and `s3` are supported for now.
+pub fn new_backup_operator(params: &StorageParams) -> Result<Operator, io::Error> {
+    let op = match params {
+        StorageParams::Fs(cfg) => {
+            let mut root = cfg.root.clone();
+            if !root.starts_with('/') {
+                root = env::current_dir()?.join(root).display().to_string();
+            }
+            let builder = services::Fs::default().root(&root);
+            Operator::new(builder)?.finish()
+        }
+        StorageParams::S3(cfg) => {
+            let mut builder = services::S3::default()
+                .endpoint(&cfg.endpoint_url)
+                .bucket(&cfg.bucket)
+                .access_key_id(&cfg.access_key_id)
+                .secret_access_key(&cfg.secret_access_key)
+                .session_token(&cfg.security_token)
+                .root(&cfg.root);
+
+            if !cfg.region.is_empty() {
+                builder = builder.region(&cfg.region);
+            } else {
+                builder = builder.region("us-east-1");
+            }
+            if cfg.enable_virtual_host_style {
+                builder = builder.enable_virtual_host_style();
+            }
+
+            Operator::new(builder)?.finish()
+        }
+        other => {
+            return Err(io::Error::new(
+                ErrorKind::Unsupported,
+                format!("unsupported meta backup storage: {:?}", other),
+            ));
+        }
+    };
+
+    Ok(op)
+}
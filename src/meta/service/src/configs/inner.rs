@@ -14,6 +14,7 @@
 
 use std::net::SocketAddr;
 
+use bigbytesdb_common_meta_app::storage::StorageParams;
 use bigbytesdb_common_meta_raft_store::config::RaftConfig;
 use bigbytesdb_common_meta_types::MetaStartupError;
 use bigbytesdb_common_meta_types::Node;
@@ -41,6 +42,7 @@ pub struct Config {
     pub grpc_tls_server_cert: String,
     pub grpc_tls_server_key: String,
     pub raft_config: RaftConfig,
+    pub backup: BackupConfig,
 }
 
 impl Default for Config {
@@ -63,6 +65,7 @@ impl Default for Config {
             grpc_tls_server_cert: "".to_string(),
             grpc_tls_server_key: "".to_string(),
             raft_config: Default::default(),
+            backup: Default::default(),
         }
     }
 }
@@ -133,3 +136,32 @@ impl Config {
         !self.grpc_tls_server_key.is_empty() && !self.grpc_tls_server_cert.is_empty()
     }
 }
+
+/// Config of backing up the meta data to a remote storage periodically.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct BackupConfig {
+    /// The storage to write backups to. Backup is disabled if it is `None`.
+    pub storage: Option<StorageParams>,
+
+    /// The interval in seconds to back up the newly committed raft-logs.
+    pub interval_secs: u64,
+
+    /// The interval in seconds to back up a full snapshot.
+    pub snapshot_interval_secs: u64,
+
+    /// The number of the latest snapshots to keep, 0 means to keep all.
+    ///
+    /// The raft-logs that are not needed by the kept snapshots are removed too.
+    pub retention: u64,
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            storage: None,
+            interval_secs: 60,
+            snapshot_interval_secs: 3600,
+            retention: 24,
+        }
+    }
+}
@@ -15,4 +15,5 @@
 mod inner;
 mod outer_v0;
 
+pub use inner::BackupConfig;
 pub use inner::Config;
@@ -17,11 +17,13 @@ use std::env;
 use clap::ArgAction;
 use clap::Args;
 use clap::Parser;
+use bigbytesdb_common_meta_app::storage::StorageParams;
 use bigbytesdb_common_meta_raft_store::config::get_default_raft_advertise_host;
 use bigbytesdb_common_meta_raft_store::config::RaftConfig as InnerRaftConfig;
 use bigbytesdb_common_meta_types::MetaStartupError;
 use bigbytesdb_common_tracing::Config as InnerLogConfig;
 use bigbytesdb_common_tracing::FileConfig as InnerFileLogConfig;
+use bigbytesdb_common_tracing::HistoryConfig;
 use bigbytesdb_common_tracing::OTLPConfig;
 use bigbytesdb_common_tracing::ProfileLogConfig;
 use bigbytesdb_common_tracing::QueryLogConfig;
@@ -35,6 +37,7 @@ use serfig::collectors::from_file;
 use serfig::collectors::from_self;
 use serfig::parsers::Toml;
 
+use super::inner::BackupConfig as InnerBackupConfig;
 use super::inner::Config as InnerConfig;
 use crate::version::METASRV_COMMIT_VERSION;
 
@@ -130,6 +133,9 @@ pub struct Config {
 
     #[clap(flatten)]
     pub raft_config: RaftConfig,
+
+    #[clap(flatten)]
+    pub backup: BackupConfig,
 }
 
 impl Default for Config {
@@ -166,6 +172,7 @@ impl From<Config> for InnerConfig {
             grpc_tls_server_cert: outer.grpc_tls_server_cert,
             grpc_tls_server_key: outer.grpc_tls_server_key,
             raft_config: outer.raft_config.into(),
+            backup: outer.backup.into(),
         }
     }
 }
@@ -192,6 +199,7 @@ impl From<InnerConfig> for Config {
             grpc_tls_server_cert: inner.grpc_tls_server_cert,
             grpc_tls_server_key: inner.grpc_tls_server_key,
             raft_config: inner.raft_config.into(),
+            backup: inner.backup.into(),
         }
     }
 }
@@ -306,6 +314,10 @@ pub struct ConfigViaEnv {
     pub metasrv_join: Vec<String>,
     pub kvsrv_id: u64,
     pub cluster_name: String,
+
+    pub metasrv_backup_interval_secs: u64,
+    pub metasrv_backup_snapshot_interval_secs: u64,
+    pub metasrv_backup_retention: u64,
 }
 
 impl Default for ConfigViaEnv {
@@ -362,6 +374,10 @@ impl From<Config> for ConfigViaEnv {
             metasrv_join: cfg.raft_config.join,
             kvsrv_id: cfg.raft_config.id,
             cluster_name: cfg.raft_config.cluster_name,
+
+            metasrv_backup_interval_secs: cfg.backup.backup_interval_secs,
+            metasrv_backup_snapshot_interval_secs: cfg.backup.backup_snapshot_interval_secs,
+            metasrv_backup_retention: cfg.backup.backup_retention,
         }
     }
 }
@@ -440,6 +456,13 @@ impl Into<Config> for ConfigViaEnv {
             grpc_tls_server_cert: self.grpc_tls_server_cert,
             grpc_tls_server_key: self.grpc_tls_server_key,
             raft_config,
+            backup: BackupConfig {
+                // Backup storage can only be configured in config file.
+                backup_storage: None,
+                backup_interval_secs: self.metasrv_backup_interval_secs,
+                backup_snapshot_interval_secs: self.metasrv_backup_snapshot_interval_secs,
+                backup_retention: self.metasrv_backup_retention,
+            },
         }
     }
 }
@@ -685,6 +708,7 @@ impl Into<InnerLogConfig> for LogConfig {
             profile: ProfileLogConfig::default(),
             structlog: StructLogConfig::default(),
             tracing: TracingConfig::default(),
+            history: HistoryConfig::default(),
         }
     }
 }
@@ -811,3 +835,61 @@ impl From<InnerStderrLogConfig> for StderrLogConfig {
         }
     }
 }
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct BackupConfig {
+    /// The storage to back up the meta data to, only configurable in config file, e.g.:
+    ///
+    /// ```toml
+    /// [backup.storage]
+    /// type = "Fs"
+    /// root = "/path/to/backup"
+    /// ```
+    #[clap(skip)]
+    #[serde(rename = "storage")]
+    pub backup_storage: Option<StorageParams>,
+
+    /// The interval in seconds to back up the newly committed raft-logs.
+    #[clap(long = "backup-interval-secs", default_value = "60")]
+    #[serde(rename = "interval_secs")]
+    pub backup_interval_secs: u64,
+
+    /// The interval in seconds to back up a full snapshot.
+    #[clap(long = "backup-snapshot-interval-secs", default_value = "3600")]
+    #[serde(rename = "snapshot_interval_secs")]
+    pub backup_snapshot_interval_secs: u64,
+
+    /// The number of the latest snapshots to keep, 0 means to keep all.
+    #[clap(long = "backup-retention", default_value = "24")]
+    #[serde(rename = "retention")]
+    pub backup_retention: u64,
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        InnerBackupConfig::default().into()
+    }
+}
+
+impl From<BackupConfig> for InnerBackupConfig {
+    fn from(x: BackupConfig) -> Self {
+        InnerBackupConfig {
+            storage: x.backup_storage,
+            interval_secs: x.backup_interval_secs,
+            snapshot_interval_secs: x.backup_snapshot_interval_secs,
+            retention: x.backup_retention,
+        }
+    }
+}
+
+impl From<InnerBackupConfig> for BackupConfig {
+    fn from(inner: InnerBackupConfig) -> Self {
+        Self {
+            backup_storage: inner.storage,
+            backup_interval_secs: inner.interval_secs,
+            backup_snapshot_interval_secs: inner.snapshot_interval_secs,
+            backup_retention: inner.retention,
+        }
+    }
+}
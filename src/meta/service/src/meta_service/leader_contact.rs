@@ -0,0 +1,60 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Tracks how stale the state machine of a non-leader node is.
+///
+/// Every successful append-entries request from the leader, including heartbeat, carries the leader's committed log index.
+/// Once the local state machine has applied up to that index,
+/// it is at least as fresh as the leader was when the request was received.
+#[derive(Debug, Default)]
+pub struct LeaderContact {
+    inner: Mutex<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    /// The time when the latest append-entries request is received and the leader committed index in it.
+    latest: Option<(Instant, Option<u64>)>,
+
+    /// The latest time at which the local state machine is known to be up to date with the leader.
+    fresh_at: Option<Instant>,
+}
+
+impl LeaderContact {
+    /// Record an append-entries request received at `at`, with the leader committed index in it.
+    pub fn update(&self, at: Instant, leader_commit: Option<u64>) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.latest = Some((at, leader_commit));
+    }
+
+    /// Return how stale the local state machine is, given the index of the last applied log.
+    ///
+    /// It returns `None` if it is unknown,
+    /// i.e., the state machine has never caught up with a committed index received from the leader.
+    pub fn staleness(&self, last_applied: Option<u64>) -> Option<Duration> {
+        let mut inner = self.inner.lock().unwrap();
+
+        if let Some((at, leader_commit)) = inner.latest {
+            if last_applied >= leader_commit {
+                inner.fresh_at = Some(at);
+            }
+        }
+
+        inner.fresh_at.map(|t| t.elapsed())
+    }
+}
@@ -13,14 +13,18 @@
 // limitations under the License.
 
 use std::collections::BTreeSet;
+use std::time::Duration;
 
 use anyerror::AnyError;
 use bigbytesdb_common_base::base::tokio::sync::RwLockReadGuard;
+use bigbytesdb_common_base::base::tokio::time::timeout;
 use bigbytesdb_common_meta_client::MetaGrpcReadReq;
 use bigbytesdb_common_meta_kvapi::kvapi::KVApi;
 use bigbytesdb_common_meta_raft_store::sm_v003::SMV003;
 use bigbytesdb_common_meta_sled_store::openraft::ChangeMembers;
 use bigbytesdb_common_meta_stoerr::MetaStorageError;
+use bigbytesdb_common_meta_types::protobuf::raft_service_client::RaftServiceClient;
+use bigbytesdb_common_meta_types::protobuf::Empty;
 use bigbytesdb_common_meta_types::protobuf::StreamItem;
 use bigbytesdb_common_meta_types::raft_types::ClientWriteError;
 use bigbytesdb_common_meta_types::raft_types::MembershipNode;
@@ -29,9 +33,11 @@ use bigbytesdb_common_meta_types::raft_types::RaftError;
 use bigbytesdb_common_meta_types::seq_value::SeqV;
 use bigbytesdb_common_meta_types::AppliedState;
 use bigbytesdb_common_meta_types::Cmd;
+use bigbytesdb_common_meta_types::ConnectionError;
 use bigbytesdb_common_meta_types::LogEntry;
 use bigbytesdb_common_meta_types::MetaDataError;
 use bigbytesdb_common_meta_types::MetaDataReadError;
+use bigbytesdb_common_meta_types::MetaNetworkError;
 use bigbytesdb_common_meta_types::MetaOperationError;
 use bigbytesdb_common_meta_types::Node;
 use bigbytesdb_common_metrics::count::Count;
@@ -39,6 +45,7 @@ use futures::StreamExt;
 use futures::TryStreamExt;
 use log::debug;
 use log::info;
+use log::warn;
 use maplit::btreemap;
 use maplit::btreeset;
 use tonic::codegen::BoxStream;
@@ -55,6 +62,9 @@ use crate::metrics::server_metrics;
 use crate::metrics::ProposalPending;
 use crate::request_handling::Handler;
 use crate::store::RaftStore;
+use crate::version::FEATURE_FETCH_ADD_U64;
+
+const GET_FEATURES_TIMEOUT: Duration = Duration::from_secs(3);
 
 /// The container of APIs of the leader in a meta service cluster.
 ///
@@ -63,6 +73,7 @@ use crate::store::RaftStore;
 pub struct MetaLeader<'a> {
     sto: &'a RaftStore,
     raft: &'a MetaRaft,
+    fetch_add_u64_nodes: &'a std::sync::Mutex<BTreeSet<NodeId>>,
 }
 
 #[async_trait::async_trait]
@@ -85,8 +96,16 @@ impl Handler<ForwardRequestBody> for MetaLeader<'_> {
                 self.leave(leave_req).await?;
                 Ok(ForwardResponse::Leave(()))
             }
-            ForwardRequestBody::Write(entry) => {
-                let res = self.write(entry.clone()).await?;
+            ForwardRequestBody::Write(mut entry) => {
+                if let Cmd::Transaction(txn) = &mut entry.cmd {
+                    if txn.has_fetch_add_u64() && !self.cluster_supports_fetch_add_u64().await {
+                        // A node that does not support it skips it when applying the log and
+                        // diverges, remove it so that all the nodes apply the same changes.
+                        warn!("not all nodes support FetchAddU64, skip it in: {}", txn);
+                        txn.remove_fetch_add_u64();
+                    }
+                }
+                let res = self.write(entry).await?;
                 Ok(ForwardResponse::AppliedState(res))
             }
 
@@ -169,6 +188,7 @@ impl<'a> MetaLeader<'a> {
         MetaLeader {
             sto: &meta_node.raft_store,
             raft: &meta_node.raft,
+            fetch_add_u64_nodes: &meta_node.fetch_add_u64_nodes,
         }
     }
 
@@ -292,6 +312,73 @@ impl<'a> MetaLeader<'a> {
         }
     }
 
+    /// Returns true if every node in the cluster, including the learners, supports `TxnOp::FetchAddU64`.
+    async fn cluster_supports_fetch_add_u64(&self) -> bool {
+        let node_ids = {
+            let metrics = self.raft.metrics().borrow().clone();
+            let membership = metrics.membership_config.membership();
+            membership.nodes().map(|(id, _)| *id).collect::<Vec<_>>()
+        };
+
+        for node_id in node_ids {
+            if node_id == self.sto.id || self.fetch_add_u64_nodes.lock().unwrap().contains(&node_id)
+            {
+                continue;
+            }
+            match self.get_state_machine_features(node_id).await {
+                Ok(features) if features.iter().any(|f| f == FEATURE_FETCH_ADD_U64) => {
+                    self.fetch_add_u64_nodes.lock().unwrap().insert(node_id);
+                }
+                Ok(features) => {
+                    info!(
+                        "node {} does not support FetchAddU64: {:?}",
+                        node_id, features
+                    );
+                    return false;
+                }
+                Err(e) => {
+                    warn!(
+                        "fail to get state machine features of node {}: {}",
+                        node_id, e
+                    );
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    async fn get_state_machine_features(
+        &self,
+        node_id: NodeId,
+    ) -> Result<Vec<String>, MetaNetworkError> {
+        let endpoint = self
+            .sto
+            .get_node_raft_endpoint(&node_id)
+            .await
+            .map_err(|e| MetaNetworkError::GetNodeAddrError(e.to_string()))?;
+
+        let fu = async {
+            let mut client = RaftServiceClient::connect(format!("http://{}", endpoint))
+                .await
+                .map_err(|e| {
+                    let conn_err = ConnectionError::new(e, format!("address: {}", endpoint));
+                    MetaNetworkError::ConnectionError(conn_err)
+                })?;
+            // A node that does not implement the RPC supports none of the features.
+            let features = client
+                .get_state_machine_features(Empty {})
+                .await
+                .map_err(MetaNetworkError::from)?;
+            Ok::<_, MetaNetworkError>(features.into_inner().features)
+        };
+
+        timeout(GET_FEATURES_TIMEOUT, fu).await.map_err(|e| {
+            let conn_err = ConnectionError::new(e, format!("address: {}", endpoint));
+            MetaNetworkError::ConnectionError(conn_err)
+        })?
+    }
+
     /// Check if a node is allowed to leave the cluster.
     ///
     /// A cluster must have at least one node in it.
@@ -29,6 +29,7 @@ use bigbytesdb_common_base::base::tokio::time::Instant;
 use bigbytesdb_common_grpc::ConnectionFactory;
 use bigbytesdb_common_grpc::DNSResolver;
 use bigbytesdb_common_meta_client::reply_to_api_result;
+use bigbytesdb_common_meta_client::MetaGrpcReadReq;
 use bigbytesdb_common_meta_client::RequestFor;
 use bigbytesdb_common_meta_raft_store::config::RaftConfig;
 use bigbytesdb_common_meta_raft_store::ondisk::DATA_VERSION;
@@ -39,6 +40,7 @@ use bigbytesdb_common_meta_sled_store::openraft::ChangeMembers;
 use bigbytesdb_common_meta_stoerr::MetaStorageError;
 use bigbytesdb_common_meta_types::protobuf::raft_service_client::RaftServiceClient;
 use bigbytesdb_common_meta_types::protobuf::raft_service_server::RaftServiceServer;
+use bigbytesdb_common_meta_types::protobuf::StreamItem;
 use bigbytesdb_common_meta_types::protobuf::WatchRequest;
 use bigbytesdb_common_meta_types::protobuf::WatchResponse;
 use bigbytesdb_common_meta_types::raft_types::CommittedLeaderId;
@@ -75,6 +77,7 @@ use openraft::Raft;
 use openraft::ServerState;
 use openraft::SnapshotPolicy;
 use tokio::sync::mpsc;
+use tonic::codegen::BoxStream;
 use tonic::Status;
 
 use crate::configs::Config as MetaConfig;
@@ -85,6 +88,7 @@ use crate::message::JoinRequest;
 use crate::message::LeaveRequest;
 use crate::meta_service::errors::grpc_error_to_network_err;
 use crate::meta_service::forwarder::MetaForwarder;
+use crate::meta_service::leader_contact::LeaderContact;
 use crate::meta_service::meta_leader::MetaLeader;
 use crate::meta_service::meta_node_status::MetaNodeStatus;
 use crate::meta_service::RaftServiceImpl;
@@ -113,6 +117,9 @@ pub struct MetaNode {
     pub running_rx: watch::Receiver<()>,
     pub join_handles: Mutex<Vec<JoinHandle<Result<(), AnyError>>>>,
     pub joined_tasks: AtomicI32,
+    pub leader_contact: LeaderContact,
+    /// The nodes known to support `TxnOp::FetchAddU64`, a node is not expected to be downgraded.
+    pub fetch_add_u64_nodes: std::sync::Mutex<BTreeSet<NodeId>>,
 }
 
 impl Drop for MetaNode {
@@ -173,6 +180,8 @@ impl MetaNodeBuilder {
             running_rx: rx,
             join_handles: Mutex::new(Vec::new()),
             joined_tasks: AtomicI32::new(1),
+            leader_contact: LeaderContact::default(),
+            fetch_add_u64_nodes: std::sync::Mutex::new(BTreeSet::new()),
         });
 
         MetaNode::subscribe_metrics(meta_node.clone(), raft.metrics()).await;
@@ -912,14 +921,78 @@ impl MetaNode {
             voters,
             non_voters: learners,
             last_seq,
+            staleness_ms: self.get_staleness().map(|d| d.as_millis() as u64),
         })
     }
 
+    /// Return how stale the local state machine is compared with the leader.
+    ///
+    /// A leader is never stale. It returns `None` if it is unknown.
+    pub fn get_staleness(&self) -> Option<Duration> {
+        let (is_leader, last_applied) = {
+            let metrics = self.raft.metrics();
+            let m = metrics.borrow();
+            (
+                m.state == ServerState::Leader,
+                m.last_applied.map(|log_id| log_id.index),
+            )
+        };
+
+        if is_leader {
+            return Some(Duration::ZERO);
+        }
+
+        self.leader_contact.staleness(last_applied)
+    }
+
+    /// Serve a read request with the local state machine, if it is not staler than `max_staleness`.
+    ///
+    /// It returns `None` if the local state machine is too stale,
+    /// in which case the request should be forwarded to the leader.
+    pub async fn try_stale_read(
+        &self,
+        req: MetaGrpcReadReq,
+        max_staleness: Duration,
+    ) -> Result<Option<BoxStream<StreamItem>>, MetaAPIError> {
+        let Some(staleness) = self.get_staleness() else {
+            return Ok(None);
+        };
+
+        if staleness > max_staleness {
+            debug!(
+                "too stale to serve read locally: staleness: {:?} > max_staleness: {:?}",
+                staleness, max_staleness
+            );
+            return Ok(None);
+        }
+
+        // MetaLeader reads from the local state machine, no matter whether this node is the leader.
+        let reader = MetaLeader::new(self);
+        let strm = reader.handle(ForwardRequest::new(0, req)).await?;
+
+        server_metrics::incr_stale_reads();
+        Ok(Some(strm))
+    }
+
     pub(crate) async fn get_last_seq(&self) -> u64 {
         let sm = self.raft_store.state_machine.read().await;
         sm.sys_data_ref().curr_seq()
     }
 
+    /// Get the grpc endpoints of the learners, which do not vote but can serve stale reads.
+    #[fastrace::trace]
+    pub async fn get_learner_grpc_advertise_addrs(&self) -> Vec<String> {
+        let learners = self
+            .raft_store
+            .get_nodes(|ms| ms.learner_ids().collect::<Vec<_>>())
+            .await;
+
+        learners
+            .into_iter()
+            .filter_map(|n| n.grpc_api_advertise_address)
+            .collect()
+    }
+
     #[fastrace::trace]
     pub async fn get_grpc_advertise_addrs(&self) -> Vec<String> {
         // Maybe stale get: from local state machine
@@ -1148,14 +1221,10 @@ impl MetaNode {
         &self,
         request: WatchRequest,
         tx: mpsc::Sender<Result<WatchResponse, Status>>,
-    ) -> Result<Arc<StreamSender>, Status> {
-        let stream_sender = self
-            .subscriber_handle
+    ) -> Result<(Arc<StreamSender>, Vec<WatchResponse>), Status> {
+        self.subscriber_handle
             .request_blocking(|d: &mut EventSubscriber| d.add_watcher(request, tx))
             .await
             .map_err(|_e| Status::internal("EventSubscriber closed"))?
-            .map_err(Status::invalid_argument)?;
-
-        Ok(stream_sender)
     }
 }
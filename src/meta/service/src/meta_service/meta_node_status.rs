@@ -78,6 +78,11 @@ pub struct MetaNodeStatus {
     ///
     /// `seq` is a monotonically incremental integer for every value that is inserted or updated.
     pub last_seq: u64,
+
+    /// How stale the local state machine is compared with the leader, in milliseconds.
+    ///
+    /// A leader is never stale. It is `None` if it is unknown.
+    pub staleness_ms: Option<u64>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
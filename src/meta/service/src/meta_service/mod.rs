@@ -14,6 +14,7 @@
 
 mod errors;
 mod forwarder;
+mod leader_contact;
 mod meta_node_kv_api_impl;
 
 pub(crate) mod snapshot_receiver_v1;
@@ -24,6 +25,7 @@ pub mod meta_node_status;
 pub mod raft_service_impl;
 
 pub use forwarder::MetaForwarder;
+pub use leader_contact::LeaderContact;
 pub use meta_node::MetaNode;
 pub use raft_service_impl::RaftServiceImpl;
 
@@ -18,6 +18,7 @@
 use std::io;
 use std::sync::Arc;
 use std::time::Duration;
+use std::time::Instant;
 
 use bigbytesdb_common_base::base::tokio::sync::Mutex;
 use bigbytesdb_common_base::future::TimedFutureExt;
@@ -36,6 +37,7 @@ use bigbytesdb_common_meta_types::protobuf::SnapshotChunkRequestV003;
 use bigbytesdb_common_meta_types::protobuf::SnapshotResponseV003;
 use bigbytesdb_common_meta_types::protobuf::StreamItem;
 use bigbytesdb_common_meta_types::raft_types::AppendEntriesRequest;
+use bigbytesdb_common_meta_types::raft_types::AppendEntriesResponse;
 use bigbytesdb_common_meta_types::raft_types::InstallSnapshotError;
 use bigbytesdb_common_meta_types::raft_types::InstallSnapshotRequest;
 use bigbytesdb_common_meta_types::raft_types::InstallSnapshotResponse;
@@ -68,6 +70,7 @@ use crate::meta_service::snapshot_receiver_v1;
 use crate::meta_service::snapshot_receiver_v1::ReceiverV1;
 use crate::meta_service::MetaNode;
 use crate::metrics::raft_metrics;
+use crate::version::STATE_MACHINE_FEATURES;
 
 pub struct RaftServiceImpl {
     pub meta_node: Arc<MetaNode>,
@@ -329,8 +332,10 @@ impl RaftService for RaftServiceImpl {
         async {
             self.incr_meta_metrics_recv_bytes_from_peer(&request);
 
+            let received_at = Instant::now();
             let ae_req: AppendEntriesRequest = GrpcHelper::parse_req(request)?;
             let req_summary = ae_req.summary();
+            let leader_commit = ae_req.leader_commit.map(|log_id| log_id.index);
             let raft = &self.meta_node.raft;
 
             info!(
@@ -343,6 +348,12 @@ impl RaftService for RaftServiceImpl {
                 .await
                 .map_err(GrpcHelper::internal_err)?;
 
+            if matches!(resp, AppendEntriesResponse::Success) {
+                self.meta_node
+                    .leader_contact
+                    .update(received_at, leader_commit);
+            }
+
             info!(
                 "RaftServiceImpl::append_entries: from:{remote_addr} done: {}",
                 req_summary
@@ -436,6 +447,17 @@ impl RaftService for RaftServiceImpl {
         };
         fu.in_span(root).await
     }
+
+    async fn get_state_machine_features(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<pb::StateMachineFeatures>, Status> {
+        let features = STATE_MACHINE_FEATURES
+            .iter()
+            .map(|feature| feature.to_string())
+            .collect();
+        Ok(Response::new(pb::StateMachineFeatures { features }))
+    }
 }
 
 /// Get remote address from tonic request.
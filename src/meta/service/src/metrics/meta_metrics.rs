@@ -70,6 +70,7 @@ pub mod server_metrics {
         proposals_pending: Gauge,
         proposals_failed: Counter,
         read_failed: Counter,
+        stale_reads: Counter,
         watchers: Gauge,
         version: Family<Vec<(String, String)>, Gauge>,
     }
@@ -97,6 +98,7 @@ pub mod server_metrics {
                 proposals_pending: Gauge::default(),
                 proposals_failed: Counter::default(),
                 read_failed: Counter::default(),
+                stale_reads: Counter::default(),
                 watchers: Gauge::default(),
                 version: Family::default(),
             };
@@ -196,6 +198,11 @@ pub mod server_metrics {
                 "read failed",
                 metrics.read_failed.clone(),
             );
+            registry.register(
+                key!("stale_reads"),
+                "number of reads served by a non-leader node with its local state machine",
+                metrics.stale_reads.clone(),
+            );
             registry.register(key!("watchers"), "watchers", metrics.watchers.clone());
             registry.register(key!("version"), "version", metrics.version.clone());
             metrics
@@ -293,6 +300,10 @@ pub mod server_metrics {
         SERVER_METRICS.read_failed.inc();
     }
 
+    pub fn incr_stale_reads() {
+        SERVER_METRICS.stale_reads.inc();
+    }
+
     pub fn incr_watchers(cnt: i64) {
         SERVER_METRICS.watchers.inc_by(cnt);
     }
@@ -42,6 +42,7 @@ use bigbytesdb_common_meta_types::raft_types::NodeId;
 use bigbytesdb_common_meta_types::raft_types::Snapshot;
 use bigbytesdb_common_meta_types::raft_types::SnapshotMeta;
 use bigbytesdb_common_meta_types::raft_types::StorageError;
+use bigbytesdb_common_meta_types::raft_types::Vote;
 use bigbytesdb_common_meta_types::snapshot_db::DB;
 use bigbytesdb_common_meta_types::Endpoint;
 use bigbytesdb_common_meta_types::MetaNetworkError;
@@ -296,6 +297,47 @@ impl RaftStoreInner {
         Ok(())
     }
 
+    /// Export the current vote and the committed logs whose index is at least `since`.
+    ///
+    /// Logs that are already purged are not included,
+    /// only the range to export is read from the raft-log.
+    pub async fn export_committed_logs(
+        &self,
+        since: u64,
+    ) -> Result<(Option<Vote>, Vec<Entry>), io::Error> {
+        let log = self.log.read().await;
+
+        let state = log.log_state();
+        let vote = state.vote().map(Cw::to_inner);
+        let committed = state.committed().map(Cw::to_inner).map(|c| c.index);
+        let purged = state.purged().map(Cw::to_inner).map(|p| p.index);
+
+        let Some(committed) = committed else {
+            return Ok((vote, vec![]));
+        };
+
+        let start = match purged {
+            Some(purged) => since.max(purged + 1),
+            None => since,
+        };
+        if start > committed {
+            return Ok((vote, vec![]));
+        }
+
+        let entries = log
+            .read(start, committed + 1)
+            .map(|res| {
+                let (log_id, payload) = res?;
+                Ok(Entry {
+                    log_id: log_id.0,
+                    payload: payload.0,
+                })
+            })
+            .collect::<Result<Vec<_>, io::Error>>()?;
+
+        Ok((vote, entries))
+    }
+
     /// Export all the data that can be used to restore a meta-service node.
     ///
     /// Returns a `BoxStream<'a, Result<String, io::Error>>` that yields a series of JSON strings.
@@ -70,6 +70,27 @@ pub static MIN_METACLI_SEMVER: Version = Version::new(1, 2, 288);
 ///   Add install_snapshot_v1
 pub static MIN_META_SEMVER: Version = Version::new(0, 9, 41);
 
+/// The features of the state machine this node supports when applying the raft log.
+///
+/// A node skips an operation it does not know and diverges from the others,
+/// thus the leader proposes such an operation only if every node in the cluster
+/// reports the feature, see `RaftService::get_state_machine_features`.
+///
+/// - `fetch_add_u64`: `TxnOp::FetchAddU64`.
+pub const STATE_MACHINE_FEATURES: &[&str] = &[FEATURE_FETCH_ADD_U64];
+
+pub const FEATURE_FETCH_ADD_U64: &str = "fetch_add_u64";
+
+/// The features of the gRPC API this node supports, reported in `ClusterStatus::features`.
+///
+/// An older server ignores an unknown request field instead of rejecting it,
+/// thus a client that depends on such a field checks the feature first.
+///
+/// - `watch_since_seq`: `WatchRequest::since_seq`.
+pub const API_FEATURES: &[&str] = &[FEATURE_WATCH_SINCE_SEQ];
+
+pub const FEATURE_WATCH_SINCE_SEQ: &str = "watch_since_seq";
+
 /// Defines the feature set provided and required by raft server and client.
 ///
 /// - The server depends on a sub set of the features provided by the client.
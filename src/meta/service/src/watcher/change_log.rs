@@ -0,0 +1,98 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+
+use bigbytesdb_common_meta_types::Change;
+
+/// The max number of recent changes kept for resuming watch streams.
+pub(crate) const CHANGE_LOG_CAPACITY: usize = 10_000;
+
+/// A bounded log of the most recent kv changes, for replaying to a resumed watch stream.
+///
+/// Every change is tagged with a seq:
+/// an update is tagged with the seq of the new value,
+/// a deletion does not produce a new seq and is tagged with the last seq seen before it.
+#[derive(Debug)]
+pub(crate) struct ChangeLog {
+    capacity: usize,
+
+    changes: VecDeque<(u64, Change<Vec<u8>, String>)>,
+
+    /// The last seq seen in an update.
+    last_seq: Option<u64>,
+
+    /// The smallest seq since which all changes are held in this log.
+    ///
+    /// `None` if nothing has been logged yet.
+    available_since: Option<u64>,
+}
+
+impl ChangeLog {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            changes: VecDeque::new(),
+            last_seq: None,
+            available_since: None,
+        }
+    }
+
+    pub(crate) fn append(&mut self, change: &Change<Vec<u8>, String>) {
+        let tag = match &change.result {
+            Some(seq_v) => {
+                self.last_seq = Some(seq_v.seq);
+                seq_v.seq
+            }
+            // A deletion before any update can not be tagged: it is not logged,
+            // and it will be before `available_since`.
+            None => match self.last_seq {
+                Some(seq) => seq,
+                None => return,
+            },
+        };
+
+        if self.available_since.is_none() {
+            self.available_since = Some(tag);
+        }
+
+        self.changes.push_back((tag, change.clone()));
+
+        while self.changes.len() > self.capacity {
+            let (evicted, _) = self.changes.pop_front().unwrap();
+            self.available_since = Some(evicted + 1);
+        }
+    }
+
+    /// Returns the changes tagged with a seq at or after `since`.
+    ///
+    /// Returns `None` if some of these changes have been evicted or were never logged.
+    pub(crate) fn changes_since(
+        &self,
+        since: u64,
+    ) -> Option<impl Iterator<Item = &Change<Vec<u8>, String>>> {
+        let available_since = self.available_since?;
+        if since < available_since {
+            return None;
+        }
+
+        let iter = self
+            .changes
+            .iter()
+            .filter(move |(tag, _)| *tag >= since)
+            .map(|(_, change)| change);
+
+        Some(iter)
+    }
+}
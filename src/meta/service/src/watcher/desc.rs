@@ -37,4 +37,17 @@ impl WatchDesc {
             key_range,
         }
     }
+
+    /// Whether this watcher is interested in a change to `key`.
+    pub(crate) fn is_interested(&self, key: &String, is_delete: bool) -> bool {
+        if !self.key_range.contains(key) {
+            return false;
+        }
+
+        match self.interested {
+            FilterType::All => true,
+            FilterType::Update => !is_delete,
+            FilterType::Delete => is_delete,
+        }
+    }
 }
@@ -33,6 +33,8 @@ use tonic::Status;
 
 use crate::metrics::network_metrics;
 use crate::metrics::server_metrics;
+use crate::watcher::change_log::ChangeLog;
+use crate::watcher::change_log::CHANGE_LOG_CAPACITY;
 use crate::watcher::command::Command;
 use crate::watcher::id::WatcherId;
 use crate::watcher::subscriber_handle::SubscriberHandle;
@@ -47,6 +49,9 @@ pub struct EventSubscriber {
     watchers: SpanMap<String, Arc<StreamSender>>,
 
     current_watcher_id: WatcherId,
+
+    /// Recent changes for resuming a watch stream with `since_seq`.
+    change_log: ChangeLog,
 }
 
 impl EventSubscriber {
@@ -58,6 +63,7 @@ impl EventSubscriber {
             rx,
             watchers: SpanMap::new(),
             current_watcher_id: 1,
+            change_log: ChangeLog::new(CHANGE_LOG_CAPACITY),
         };
 
         let _h = bigbytesdb_common_base::runtime::spawn(subscriber.main());
@@ -120,26 +126,16 @@ impl EventSubscriber {
 
         let is_delete = change.result.is_none();
 
+        self.change_log.append(&change);
+
         let resp = WatchResponse::new(&change).unwrap();
         let resp_size = resp.encoded_len() as u64;
 
         let mut removed = vec![];
 
         for sender in self.watchers.get(&key) {
-            let interested = sender.desc.interested;
-
-            match interested {
-                FilterType::All => {}
-                FilterType::Update => {
-                    if is_delete {
-                        continue;
-                    }
-                }
-                FilterType::Delete => {
-                    if !is_delete {
-                        continue;
-                    }
-                }
+            if !sender.desc.is_interested(&key, is_delete) {
+                continue;
             }
 
             if let Err(_err) = sender.send(resp.clone()).await {
@@ -158,16 +154,27 @@ impl EventSubscriber {
         }
     }
 
+    /// Add a watcher and return the changes to replay to it if `since_seq` is specified.
+    ///
+    /// The replayed changes are collected in the dispatcher loop,
+    /// thus no change is lost or duplicated between the replayed ones and the dispatched ones.
     #[fastrace::trace]
     pub fn add_watcher(
         &mut self,
         req: WatchRequest,
         tx: mpsc::Sender<Result<WatchResponse, Status>>,
-    ) -> Result<Arc<StreamSender>, &'static str> {
+    ) -> Result<(Arc<StreamSender>, Vec<WatchResponse>), Status> {
         info!("EventSubscriber::add_watcher: {:?}", req);
 
         let interested = req.filter_type();
-        let desc = self.new_watch_desc(req.key, req.key_end, interested)?;
+        let desc = self
+            .new_watch_desc(req.key, req.key_end, interested)
+            .map_err(Status::invalid_argument)?;
+
+        let replay = match req.since_seq {
+            None => vec![],
+            Some(since_seq) => self.replay_changes(&desc, since_seq)?,
+        };
 
         let stream_sender = Arc::new(StreamSender::new(desc, tx));
 
@@ -176,7 +183,39 @@ impl EventSubscriber {
 
         server_metrics::incr_watchers(1);
 
-        Ok(stream_sender)
+        Ok((stream_sender, replay))
+    }
+
+    fn replay_changes(
+        &self,
+        desc: &WatchDesc,
+        since_seq: u64,
+    ) -> Result<Vec<WatchResponse>, Status> {
+        let changes = self.change_log.changes_since(since_seq).ok_or_else(|| {
+            Status::out_of_range(format!(
+                "changes since seq {} are compacted, re-sync with initial_flush",
+                since_seq
+            ))
+        })?;
+
+        let replay = changes
+            .filter(|change| {
+                let Some(key) = &change.ident else {
+                    return false;
+                };
+                desc.is_interested(key, change.result.is_none())
+            })
+            .filter_map(WatchResponse::new)
+            .collect::<Vec<_>>();
+
+        info!(
+            "EventSubscriber: replay {} changes since seq {} to watcher {}",
+            replay.len(),
+            since_seq,
+            desc.watcher_id
+        );
+
+        Ok(replay)
     }
 
     fn new_watch_desc(
@@ -15,6 +15,8 @@
 use std::fs::File;
 use std::io::Write;
 
+use bigbytesdb_common_meta_app::storage::StorageFsConfig;
+use bigbytesdb_common_meta_app::storage::StorageParams;
 use bigbytesdb_meta::configs::Config;
 use tempfile::tempdir;
 
@@ -65,6 +67,15 @@ join = ["j1", "j2"]
 id = 20
 sled_tree_prefix = "sled_foo"
 cluster_name = "foo_cluster"
+
+[backup]
+interval_secs = 30
+snapshot_interval_secs = 600
+retention = 3
+
+[backup.storage]
+type = "Fs"
+root = "backup root"
              "#
     )?;
 
@@ -90,6 +101,15 @@ cluster_name = "foo_cluster"
         assert_eq!(cfg.raft_config.join, vec!["j1", "j2"]);
         assert_eq!(cfg.raft_config.id, 20);
         assert_eq!(cfg.raft_config.cluster_name, "foo_cluster");
+        assert_eq!(cfg.backup.interval_secs, 30);
+        assert_eq!(cfg.backup.snapshot_interval_secs, 600);
+        assert_eq!(cfg.backup.retention, 3);
+        assert_eq!(
+            cfg.backup.storage,
+            Some(StorageParams::Fs(StorageFsConfig {
+                root: "backup root".to_string()
+            }))
+        );
     });
 
     temp_env::with_vars(
@@ -128,6 +128,7 @@ async fn test_watch_single_key() -> anyhow::Result<()> {
         key_end: None,
         filter_type: FilterType::All.into(),
         initial_flush: false,
+        since_seq: None,
     };
 
     let key_a = s("a");
@@ -172,6 +173,7 @@ async fn test_watch() -> anyhow::Result<()> {
             key_end: Some("z".to_string()),
             filter_type: FilterType::All.into(),
             initial_flush: false,
+            since_seq: None,
         };
 
         let key_a = s("a");
@@ -231,6 +233,7 @@ async fn test_watch() -> anyhow::Result<()> {
             // filter only delete events
             filter_type: FilterType::Delete.into(),
             initial_flush: false,
+            since_seq: None,
         };
 
         let key = s(key_str);
@@ -295,6 +298,7 @@ async fn test_watch() -> anyhow::Result<()> {
             key_end: Some(end),
             filter_type: FilterType::All.into(),
             initial_flush: false,
+            since_seq: None,
         };
 
         let conditions = vec![TxnCondition {
@@ -364,6 +368,7 @@ async fn test_watch_initial_flush() -> anyhow::Result<()> {
             key_end: Some(s("e")),
             filter_type: FilterType::All.into(),
             initial_flush: true,
+            since_seq: None,
         };
         client.request(watch).await?
     };
@@ -411,6 +416,58 @@ async fn test_watch_initial_flush() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_watch_since_seq() -> anyhow::Result<()> {
+    let (tc, _addr) = crate::tests::start_metasrv().await?;
+
+    let client = tc.grpc_client().await?;
+
+    let seq_a = client.upsert_kv(UpsertKV::update("a", b"a")).await?;
+    let seq_a = seq_a.result.unwrap().seq;
+    let seq_b = client.upsert_kv(UpsertKV::update("b", b"b")).await?;
+    let seq_b = seq_b.result.unwrap().seq;
+    let seq_c = client.upsert_kv(UpsertKV::update("c", b"c")).await?;
+    let seq_c = seq_c.result.unwrap().seq;
+    client.upsert_kv(UpsertKV::update("z", b"z")).await?;
+    client.upsert_kv(UpsertKV::delete("a")).await?;
+
+    info!("--- resume from seq_b, replay the missed changes then the new ones");
+    {
+        let watch = WatchRequest::new(s("a"), Some(s("e"))).with_since_seq(seq_b);
+        let mut strm = client.request(watch).await?;
+
+        client.upsert_kv(UpsertKV::update("d", b"d")).await?;
+
+        let want = vec![
+            add_event("b", seq_b, "b", None),
+            add_event("c", seq_c, "c", None),
+            del_event("a", seq_a, "a", None),
+            add_event("d", seq_c + 2, "d", None),
+        ];
+
+        for want in want {
+            let resp = strm.message().await?.unwrap();
+            assert_eq!(want, resp.event.unwrap());
+        }
+    }
+
+    info!("--- resume from a seq that is not in the change log");
+    {
+        let watch = WatchRequest::new(s("a"), Some(s("e"))).with_since_seq(0);
+        let res = client.request(watch).await;
+
+        let err = res.unwrap_err();
+        assert!(
+            err.to_string().contains("re-sync with initial_flush"),
+            "{}",
+            err
+        );
+    }
+
+    Ok(())
+}
+
 #[test(harness = meta_service_test_harness)]
 #[fastrace::trace]
 async fn test_watch_expired_events() -> anyhow::Result<()> {
@@ -463,6 +520,7 @@ async fn test_watch_expired_events() -> anyhow::Result<()> {
             key_end: Some(end),
             filter_type: FilterType::All.into(),
             initial_flush: false,
+            since_seq: None,
         };
         watch_client.request(watch).await?
     };
@@ -560,6 +618,7 @@ async fn test_watch_stream_count() -> anyhow::Result<()> {
         key_end: Some("z".to_string()),
         filter_type: FilterType::All.into(),
         initial_flush: false,
+        since_seq: None,
     };
 
     let client1 = make_client(&addr)?;
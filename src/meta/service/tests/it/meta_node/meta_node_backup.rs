@@ -0,0 +1,116 @@
+// Copyright 2022 Digitrans Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use bigbytesdb_common_meta_app::storage::StorageFsConfig;
+use bigbytesdb_common_meta_app::storage::StorageParams;
+use bigbytesdb_common_meta_types::Cmd;
+use bigbytesdb_common_meta_types::LogEntry;
+use bigbytesdb_common_meta_types::UpsertKV;
+use bigbytesdb_meta::backup::list_log_segments;
+use bigbytesdb_meta::backup::list_snapshots;
+use bigbytesdb_meta::backup::new_backup_operator;
+use bigbytesdb_meta::backup::LogBackup;
+use bigbytesdb_meta::backup::LogSegmentFile;
+use bigbytesdb_meta::backup::MetaBackup;
+use bigbytesdb_meta::configs::BackupConfig;
+use log::info;
+use test_harness::test;
+
+use crate::testing::meta_service_test_harness;
+use crate::tests::meta_node::start_meta_node_leader;
+
+/// A leader backs up a snapshot and the committed logs since the last backup.
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_meta_node_backup() -> anyhow::Result<()> {
+    let mut log_index = 0;
+
+    info!("--- bring up leader");
+    let (_id, tc0) = start_meta_node_leader().await?;
+    // initialization log, leader blank log, writing node log
+    log_index += 3;
+
+    let leader = tc0.meta_node();
+    leader
+        .raft
+        .wait(timeout())
+        .applied_index(Some(log_index), "leader log index")
+        .await?;
+
+    leader
+        .write(LogEntry::new(Cmd::UpsertKV(UpsertKV::update("a", b"a"))))
+        .await?;
+    log_index += 1;
+
+    let dir = tempfile::tempdir()?;
+    let storage = StorageParams::Fs(StorageFsConfig {
+        root: dir.path().display().to_string(),
+    });
+    let config = BackupConfig {
+        storage: Some(storage.clone()),
+        ..Default::default()
+    };
+    let backup = MetaBackup::create(leader.clone(), &config)?.unwrap();
+
+    info!("--- back up a snapshot");
+    {
+        let snapshot = backup.backup_snapshot(&leader).await?;
+        assert_eq!(log_index, snapshot.last_applied);
+    }
+
+    info!("--- back up logs");
+    {
+        let LogBackup::Segment(seg) = backup.backup_logs(&leader).await? else {
+            panic!("expect a log segment");
+        };
+        assert_eq!(log_index, seg.last);
+
+        let res = backup.backup_logs(&leader).await?;
+        assert!(matches!(res, LogBackup::Empty), "no new logs to back up");
+    }
+
+    info!("--- back up new logs since the last backup");
+    {
+        leader
+            .write(LogEntry::new(Cmd::UpsertKV(UpsertKV::update("b", b"b"))))
+            .await?;
+        log_index += 1;
+
+        let LogBackup::Segment(seg) = backup.backup_logs(&leader).await? else {
+            panic!("expect a log segment");
+        };
+        assert_eq!(
+            LogSegmentFile {
+                first: log_index,
+                last: log_index
+            },
+            seg
+        );
+    }
+
+    info!("--- list backups");
+    {
+        let op = new_backup_operator(&storage)?;
+        assert_eq!(1, list_snapshots(&op).await?.len());
+        assert_eq!(2, list_log_segments(&op).await?.len());
+    }
+
+    Ok(())
+}
+
+fn timeout() -> Option<Duration> {
+    Some(Duration::from_millis(5_000))
+}
@@ -0,0 +1,103 @@
+// Copyright 2022 Digitrans Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use bigbytesdb_common_base::base::tokio::time::sleep;
+use bigbytesdb_common_meta_client::MetaGrpcReadReq;
+use bigbytesdb_common_meta_kvapi::kvapi::GetKVReq;
+use bigbytesdb_common_meta_types::Cmd;
+use bigbytesdb_common_meta_types::LogEntry;
+use bigbytesdb_common_meta_types::UpsertKV;
+use futures::TryStreamExt;
+use log::info;
+use test_harness::test;
+
+use crate::testing::meta_service_test_harness;
+use crate::tests::meta_node::start_meta_node_leader;
+use crate::tests::meta_node::start_meta_node_non_voter;
+use crate::tests::meta_node::timeout;
+
+/// A learner serves a read with its local state machine if it is fresh enough.
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_meta_node_stale_read_on_learner() -> anyhow::Result<()> {
+    info!("--- bring up leader and learner");
+    let (_id, tc0) = start_meta_node_leader().await?;
+    let leader = tc0.meta_node();
+
+    let (_id, tc1) = start_meta_node_non_voter(leader.clone(), 1).await?;
+    let learner = tc1.meta_node();
+
+    info!("--- learners are listed by the leader");
+    {
+        let learners = leader.get_learner_grpc_advertise_addrs().await;
+        let want = tc1
+            .config
+            .grpc_api_advertise_address()
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(want, learners);
+    }
+
+    info!("--- write a kv and wait for it to be replicated to the learner");
+    {
+        leader
+            .write(LogEntry::new(Cmd::UpsertKV(UpsertKV::update("a", b"a"))))
+            .await?;
+
+        let index = leader.raft.metrics().borrow().last_applied.map(|l| l.index);
+        learner
+            .raft
+            .wait(timeout())
+            .applied_index(index, "learner applied the kv")
+            .await?;
+
+        // Wait for a heartbeat to carry the committed index to the learner.
+        sleep(Duration::from_millis(1_000)).await;
+    }
+
+    info!("--- leader is never stale");
+    assert_eq!(Some(Duration::ZERO), leader.get_staleness());
+
+    info!("--- read from learner with a large max staleness");
+    {
+        let staleness = learner.get_staleness();
+        assert!(staleness.is_some(), "learner staleness is known");
+
+        let req = MetaGrpcReadReq::GetKV(GetKVReq {
+            key: "a".to_string(),
+        });
+        let strm = learner
+            .try_stale_read(req, Duration::from_secs(60))
+            .await?
+            .expect("learner is fresh enough");
+
+        let items: Vec<_> = strm.try_collect().await?;
+        assert_eq!(1, items.len());
+        assert_eq!("a", items[0].key);
+        assert_eq!(b"a".to_vec(), items[0].value.as_ref().unwrap().data);
+    }
+
+    info!("--- read from learner with a tiny max staleness falls back to leader");
+    {
+        let req = MetaGrpcReadReq::GetKV(GetKVReq {
+            key: "a".to_string(),
+        });
+        let res = learner.try_stale_read(req, Duration::ZERO).await?;
+        assert!(res.is_none(), "learner is too stale");
+    }
+
+    Ok(())
+}
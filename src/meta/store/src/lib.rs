@@ -70,6 +70,15 @@ impl MetaStore {
         }
     }
 
+    /// Returns a view of this store whose reads may be served by a meta learner,
+    /// with a state no staler than `max_staleness` of the client config.
+    ///
+    /// Use it only for reads that tolerate a stale result, such as caches and listings.
+    /// Do not use it for a read that a conditional update is built upon.
+    pub fn stale(&self) -> StaleMetaStore<'_> {
+        StaleMetaStore { inner: self }
+    }
+
     pub async fn watch(&self, request: WatchRequest) -> Result<WatchStream, MetaError> {
         match self {
             MetaStore::L(_) => unreachable!(),
@@ -114,6 +123,40 @@ impl kvapi::KVApi for MetaStore {
     }
 }
 
+/// A [`MetaStore`] whose reads tolerate a stale result, see [`MetaStore::stale`].
+///
+/// Writes are always sent to the leader.
+pub struct StaleMetaStore<'a> {
+    inner: &'a MetaStore,
+}
+
+#[async_trait::async_trait]
+impl kvapi::KVApi for StaleMetaStore<'_> {
+    type Error = MetaError;
+
+    async fn upsert_kv(&self, act: UpsertKV) -> Result<UpsertKVReply, Self::Error> {
+        self.inner.upsert_kv(act).await
+    }
+
+    async fn get_kv_stream(&self, keys: &[String]) -> Result<KVStream<Self::Error>, Self::Error> {
+        match self.inner {
+            MetaStore::L(x) => x.get_kv_stream(keys).await,
+            MetaStore::R(x) => x.get_kv_stream_stale(keys).await,
+        }
+    }
+
+    async fn list_kv(&self, prefix: &str) -> Result<KVStream<Self::Error>, Self::Error> {
+        match self.inner {
+            MetaStore::L(x) => x.list_kv(prefix).await,
+            MetaStore::R(x) => x.list_kv_stale(prefix).await,
+        }
+    }
+
+    async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error> {
+        self.inner.transaction(txn).await
+    }
+}
+
 impl MetaStoreProvider {
     pub fn new(rpc_conf: RpcClientConf) -> Self {
         MetaStoreProvider { rpc_conf }
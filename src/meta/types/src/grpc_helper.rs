@@ -16,6 +16,7 @@
 
 use std::error::Error;
 use std::str::FromStr;
+use std::time::Duration;
 
 use log::error;
 use tonic::metadata::MetadataValue;
@@ -27,6 +28,7 @@ use crate::Endpoint;
 
 const HEADER_LEADER: &str = "x-bigbytesdb-meta-leader-grpc-endpoint";
 // const HEADER_LEADER_BIN: &str = "x-bigbytesdb-meta-leader-grpc-endpoint-bin";
+const HEADER_MAX_STALENESS: &str = "x-bigbytesdb-meta-max-staleness-ms";
 
 pub struct GrpcHelper;
 
@@ -113,6 +115,28 @@ impl GrpcHelper {
         }
     }
 
+    /// Add the max staleness a client accepts to a read request,
+    /// to allow a non-leader node to serve the read with its local state machine.
+    pub fn add_request_max_staleness<T>(req: &mut tonic::Request<T>, max_staleness: Duration) {
+        let ms = max_staleness.as_millis() as u64;
+        req.metadata_mut()
+            .insert(HEADER_MAX_STALENESS, MetadataValue::from(ms));
+    }
+
+    /// Retrieve the max staleness a client accepts from a read request.
+    pub fn get_request_max_staleness<T>(req: &tonic::Request<T>) -> Option<Duration> {
+        let v = req.metadata().get(HEADER_MAX_STALENESS)?;
+
+        let ms = v.to_str().ok().and_then(|s| s.parse::<u64>().ok());
+        match ms {
+            Some(ms) => Some(Duration::from_millis(ms)),
+            None => {
+                error!("invalid request max staleness: {:?}", v);
+                None
+            }
+        }
+    }
+
     pub fn encode_raft_request<T>(v: &T) -> Result<RaftRequest, serde_json::Error>
     where T: serde::Serialize + 'static {
         let data = serde_json::to_string(&v)?;
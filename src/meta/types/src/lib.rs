@@ -92,6 +92,8 @@ pub use protobuf::txn_condition;
 pub use protobuf::txn_condition::ConditionResult;
 pub use protobuf::txn_op;
 pub use protobuf::txn_op_response;
+pub use protobuf::FetchAddU64;
+pub use protobuf::FetchAddU64Response;
 pub use protobuf::TxnCondition;
 pub use protobuf::TxnDeleteByPrefixRequest;
 pub use protobuf::TxnDeleteByPrefixResponse;
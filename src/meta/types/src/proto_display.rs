@@ -29,6 +29,8 @@ use crate::txn_op;
 use crate::txn_op::Request;
 use crate::txn_op_response::Response;
 use crate::ConditionResult;
+use crate::FetchAddU64;
+use crate::FetchAddU64Response;
 use crate::TxnCondition;
 use crate::TxnDeleteByPrefixRequest;
 use crate::TxnDeleteByPrefixResponse;
@@ -162,6 +164,9 @@ impl Display for txn_op::Request {
             Request::DeleteByPrefix(r) => {
                 write!(f, "DeleteByPrefix({})", r)
             }
+            Request::FetchAddU64(r) => {
+                write!(f, "FetchAddU64({})", r)
+            }
         }
     }
 }
@@ -201,6 +206,19 @@ impl Display for TxnDeleteByPrefixRequest {
     }
 }
 
+impl Display for FetchAddU64 {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "FetchAddU64 key={} delta={}", self.key, self.delta)?;
+        if let Some(match_seq) = self.match_seq {
+            write!(f, " match_seq: {}", match_seq)?;
+        }
+        if let Some(max_value) = self.max_value {
+            write!(f, " max_value: {}", max_value)?;
+        }
+        Ok(())
+    }
+}
+
 impl Display for Target {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
@@ -250,6 +268,9 @@ impl Display for Response {
             Response::DeleteByPrefix(r) => {
                 write!(f, "DeleteByPrefix: {}", r)
             }
+            Response::FetchAddU64(r) => {
+                write!(f, "FetchAddU64: {}", r)
+            }
         }
     }
 }
@@ -296,6 +317,16 @@ impl Display for TxnDeleteByPrefixResponse {
     }
 }
 
+impl Display for FetchAddU64Response {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "FetchAddU64-resp: key={}, before=(seq={} {}), after=(seq={} {})",
+            self.key, self.before_seq, self.before, self.after_seq, self.after
+        )
+    }
+}
+
 impl Display for BooleanExpression {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let op = self.operator();
@@ -55,6 +55,28 @@ impl pb::TxnRequest {
         }
     }
 
+    /// Returns true if any operation of the transaction is a `FetchAddU64`.
+    pub fn has_fetch_add_u64(&self) -> bool {
+        self.operations
+            .iter()
+            .flat_map(|branch| &branch.operations)
+            .chain(&self.if_then)
+            .chain(&self.else_then)
+            .any(|op| matches!(op.request, Some(pb::txn_op::Request::FetchAddU64(_))))
+    }
+
+    /// Removes the `FetchAddU64` operations,
+    /// the same as a meta-service that does not support them skips them.
+    pub fn remove_fetch_add_u64(&mut self) {
+        let is_kept =
+            |op: &pb::TxnOp| !matches!(op.request, Some(pb::txn_op::Request::FetchAddU64(_)));
+        for branch in &mut self.operations {
+            branch.operations.retain(is_kept);
+        }
+        self.if_then.retain(is_kept);
+        self.else_then.retain(is_kept);
+    }
+
     /// Adds operations to execute when the conditions are not met.
     pub fn with_else(mut self, ops: Vec<pb::TxnOp>) -> Self {
         self.else_then = ops;
@@ -309,6 +331,34 @@ impl pb::TxnOp {
             })),
         }
     }
+
+    /// Create a new `TxnOp` that atomically adds `delta` to the u64 value of `key`.
+    pub fn fetch_add_u64(key: impl ToString, delta: u64) -> Self {
+        pb::TxnOp {
+            request: Some(pb::txn_op::Request::FetchAddU64(pb::FetchAddU64 {
+                key: key.to_string(),
+                match_seq: None,
+                delta,
+                max_value: None,
+            })),
+        }
+    }
+
+    /// Apply the `FetchAddU64` operation only when the seq of the key matches.
+    pub fn with_match_seq(mut self, seq: Option<u64>) -> Self {
+        if let Some(pb::txn_op::Request::FetchAddU64(p)) = &mut self.request {
+            p.match_seq = seq;
+        }
+        self
+    }
+
+    /// Apply the `FetchAddU64` operation only when the result does not exceed `max_value`.
+    pub fn with_max_value(mut self, max_value: Option<u64>) -> Self {
+        if let Some(pb::txn_op::Request::FetchAddU64(p)) = &mut self.request {
+            p.max_value = max_value;
+        }
+        self
+    }
 }
 
 impl pb::TxnOpResponse {
@@ -344,6 +394,39 @@ impl pb::TxnOpResponse {
         }
     }
 
+    /// Create a new `TxnOpResponse` of a `FetchAddU64` operation.
+    pub fn fetch_add_u64(
+        key: impl ToString,
+        before_seq: u64,
+        before: u64,
+        after_seq: u64,
+        after: u64,
+    ) -> Self {
+        pb::TxnOpResponse {
+            response: Some(pb::txn_op_response::Response::FetchAddU64(
+                pb::FetchAddU64Response {
+                    key: key.to_string(),
+                    before_seq,
+                    before,
+                    after_seq,
+                    after,
+                },
+            )),
+        }
+    }
+
+    pub fn as_fetch_add_u64(&self) -> &pb::FetchAddU64Response {
+        self.try_as_fetch_add_u64().unwrap()
+    }
+
+    /// Returns the response as a `FetchAddU64` response if it is one.
+    pub fn try_as_fetch_add_u64(&self) -> Option<&pb::FetchAddU64Response> {
+        match &self.response {
+            Some(pb::txn_op_response::Response::FetchAddU64(resp)) => Some(resp),
+            _ => None,
+        }
+    }
+
     /// Consumes and returns the response as a `Get` response if it is one.
     pub fn into_get(self) -> Option<pb::TxnGetResponse> {
         match self.response {
@@ -374,6 +457,13 @@ impl pb::TxnGetResponse {
     }
 }
 
+impl pb::FetchAddU64Response {
+    /// The value actually added, 0 if the operation is not applied.
+    pub fn delta(&self) -> u64 {
+        self.after.saturating_sub(self.before)
+    }
+}
+
 impl pb::ConditionalOperation {
     pub fn new(
         expr: Option<pb::BooleanExpression>,
@@ -390,6 +480,25 @@ impl pb::ConditionalOperation {
 mod tests {
     use crate::protobuf::BooleanExpression;
     use crate::TxnCondition;
+    use crate::TxnOp;
+    use crate::TxnRequest;
+
+    #[test]
+    fn test_remove_fetch_add_u64() {
+        let mut txn = TxnRequest::new(vec![], vec![
+            TxnOp::put("a", b"1".to_vec()),
+            TxnOp::fetch_add_u64("b", 1),
+        ])
+        .with_else(vec![TxnOp::fetch_add_u64("c", 1)])
+        .push_branch(None, [TxnOp::fetch_add_u64("d", 1), TxnOp::get("d")]);
+        assert!(txn.has_fetch_add_u64());
+
+        txn.remove_fetch_add_u64();
+        assert!(!txn.has_fetch_add_u64());
+        assert_eq!(txn.if_then, vec![TxnOp::put("a", b"1".to_vec())]);
+        assert!(txn.else_then.is_empty());
+        assert_eq!(txn.operations[0].operations, vec![TxnOp::get("d")]);
+    }
 
     #[test]
     fn test_bool_expression() {
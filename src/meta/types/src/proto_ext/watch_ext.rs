@@ -45,6 +45,7 @@ impl WatchRequest {
             key_end,
             filter_type: FilterType::All as _,
             initial_flush: false,
+            since_seq: None,
         }
     }
 
@@ -53,6 +54,12 @@ impl WatchRequest {
         self
     }
 
+    /// Resume the watch from `seq`, see [`WatchRequest::since_seq`].
+    pub fn with_since_seq(mut self, seq: u64) -> Self {
+        self.since_seq = Some(seq);
+        self
+    }
+
     pub fn key_range(&self) -> Result<(Bound<String>, Bound<String>), &'static str> {
         Self::build_key_range(&self.key, &self.key_end)
     }
@@ -1805,8 +1805,18 @@ impl ExprReplacer {
                 }
             }
             TableReference::Join { join, .. } => {
-                if let JoinCondition::On(expr) = &mut join.condition {
-                    self.replace_expr(expr);
+                match &mut join.condition {
+                    JoinCondition::On(expr) => self.replace_expr(expr),
+                    JoinCondition::Match {
+                        match_condition,
+                        on,
+                    } => {
+                        self.replace_expr(match_condition);
+                        if let Some(on) = on {
+                            self.replace_expr(on);
+                        }
+                    }
+                    _ => {}
                 }
                 self.replace_table_table_reference(&mut join.left);
                 self.replace_table_table_reference(&mut join.right);
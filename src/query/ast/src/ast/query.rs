@@ -550,6 +550,7 @@ pub enum TimeTravelPoint {
     Snapshot(String),
     Timestamp(Box<Expr>),
     Offset(Box<Expr>),
+    Tag(Identifier),
     Stream {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
@@ -569,6 +570,9 @@ impl Display for TimeTravelPoint {
             TimeTravelPoint::Offset(num) => {
                 write!(f, "(OFFSET => {num})")?;
             }
+            TimeTravelPoint::Tag(name) => {
+                write!(f, "(TAG => {name})")?;
+            }
             TimeTravelPoint::Stream {
                 catalog,
                 database,
@@ -1007,6 +1011,12 @@ impl Display for TableReference {
                     JoinOperator::CrossJoin => {
                         write!(f, " CROSS JOIN")?;
                     }
+                    JoinOperator::Asof => {
+                        write!(f, " ASOF JOIN")?;
+                    }
+                    JoinOperator::LeftAsof => {
+                        write!(f, " ASOF LEFT JOIN")?;
+                    }
                 }
                 write!(f, " {}", join.right)?;
                 match &join.condition {
@@ -1018,6 +1028,15 @@ impl Display for TableReference {
                         write_comma_separated_list(f, idents)?;
                         write!(f, ")")?;
                     }
+                    JoinCondition::Match {
+                        match_condition,
+                        on,
+                    } => {
+                        write!(f, " MATCH_CONDITION ({match_condition})")?;
+                        if let Some(on) = on {
+                            write!(f, " ON {on}")?;
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -1079,6 +1098,10 @@ pub enum JoinOperator {
     RightAnti,
     // CrossJoin can only work with `JoinCondition::None`
     CrossJoin,
+    // Asof joins match each left row with at most one right row,
+    // the closest one that satisfies the match condition.
+    Asof,
+    LeftAsof,
 }
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
@@ -1087,6 +1110,11 @@ pub enum JoinCondition {
     Using(Vec<Identifier>),
     Natural,
     None,
+    // MATCH_CONDITION (expr) [ON expr], used by ASOF joins
+    Match {
+        match_condition: Box<Expr>,
+        on: Option<Box<Expr>>,
+    },
 }
 
 impl SetExpr {
@@ -90,6 +90,7 @@ pub struct CreateDatabaseStmt {
     pub database: DatabaseRef,
     pub engine: Option<DatabaseEngine>,
     pub options: Vec<SQLProperty>,
+    pub clone_from: Option<DatabaseRef>,
 }
 
 impl Display for CreateDatabaseStmt {
@@ -109,6 +110,10 @@ impl Display for CreateDatabaseStmt {
             write!(f, " ENGINE = {engine}")?;
         }
 
+        if let Some(clone_from) = &self.clone_from {
+            write!(f, " CLONE {clone_from}")?;
+        }
+
         // TODO(leiysky): display rest information
         Ok(())
     }
@@ -24,6 +24,7 @@ use crate::ast::Identifier;
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct Hint {
     pub hints_list: Vec<HintItem>,
+    pub plan_hints: Vec<PlanHint>,
 }
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
@@ -32,6 +33,59 @@ pub struct HintItem {
     pub expr: Expr,
 }
 
+/// Hints that control the plan chosen by the optimizer.
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub enum PlanHint {
+    /// Join the tables first, in the given order.
+    Leading(Vec<LeadingItem>),
+    /// Broadcast the table to all nodes when it is the build side of a join.
+    Broadcast(Identifier),
+    /// Shuffle the table by the join keys when it is joined.
+    Shuffle(Identifier),
+    HashJoin,
+    MergeJoin,
+    NoDecorrelate,
+}
+
+/// An operand of the `LEADING` hint, a parenthesized pair such as `(t1 t2)`
+/// is joined before the other operands.
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub enum LeadingItem {
+    Table(Identifier),
+    Join(Box<LeadingItem>, Box<LeadingItem>),
+}
+
+impl Display for LeadingItem {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            LeadingItem::Table(table) => write!(f, "{table}"),
+            LeadingItem::Join(left, right) => write!(f, "({left} {right})"),
+        }
+    }
+}
+
+impl Display for PlanHint {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            PlanHint::Leading(tables) => {
+                write!(f, "LEADING(")?;
+                for (i, table) in tables.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{table}")?;
+                }
+                write!(f, ")")
+            }
+            PlanHint::Broadcast(table) => write!(f, "BROADCAST({table})"),
+            PlanHint::Shuffle(table) => write!(f, "SHUFFLE({table})"),
+            PlanHint::HashJoin => write!(f, "HASH_JOIN"),
+            PlanHint::MergeJoin => write!(f, "MERGE_JOIN"),
+            PlanHint::NoDecorrelate => write!(f, "NO_DECORRELATE"),
+        }
+    }
+}
+
 impl Display for Hint {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "/*+ ")?;
@@ -42,6 +96,9 @@ impl Display for Hint {
             write!(f, "{}", hint.expr)?;
             write!(f, ")")?;
         }
+        for hint in &self.plan_hints {
+            write!(f, "{hint} ")?;
+        }
         write!(f, "*/")
     }
 }
@@ -42,6 +42,7 @@ pub enum TableIndexType {
     Aggregating,
     // Join
     Inverted,
+    Vector,
 }
 
 impl Display for TableIndexType {
@@ -53,6 +54,9 @@ impl Display for TableIndexType {
             TableIndexType::Inverted => {
                 write!(f, "INVERTED")
             }
+            TableIndexType::Vector => {
+                write!(f, "VECTOR")
+            }
         }
     }
 }
@@ -111,7 +115,8 @@ impl Display for RefreshIndexStmt {
 }
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
-pub struct CreateInvertedIndexStmt {
+pub struct CreateTableIndexStmt {
+    pub index_type: TableIndexType,
     pub create_option: CreateOption,
 
     pub index_name: Identifier,
@@ -125,7 +130,7 @@ pub struct CreateInvertedIndexStmt {
     pub index_options: BTreeMap<String, String>,
 }
 
-impl Display for CreateInvertedIndexStmt {
+impl Display for CreateTableIndexStmt {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "CREATE ")?;
         if let CreateOption::CreateOrReplace = self.create_option {
@@ -134,7 +139,7 @@ impl Display for CreateInvertedIndexStmt {
         if !self.sync_creation {
             write!(f, "ASYNC ")?;
         }
-        write!(f, "INVERTED INDEX")?;
+        write!(f, "{} INDEX", self.index_type)?;
         if let CreateOption::CreateIfNotExists = self.create_option {
             write!(f, " IF NOT EXISTS")?;
         }
@@ -162,7 +167,8 @@ impl Display for CreateInvertedIndexStmt {
 }
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
-pub struct DropInvertedIndexStmt {
+pub struct DropTableIndexStmt {
+    pub index_type: TableIndexType,
     pub if_exists: bool,
     pub index_name: Identifier,
     pub catalog: Option<Identifier>,
@@ -170,9 +176,9 @@ pub struct DropInvertedIndexStmt {
     pub table: Identifier,
 }
 
-impl Display for DropInvertedIndexStmt {
+impl Display for DropTableIndexStmt {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        write!(f, "DROP INVERTED INDEX")?;
+        write!(f, "DROP {} INDEX", self.index_type)?;
         if self.if_exists {
             write!(f, " IF EXISTS")?;
         }
@@ -191,7 +197,8 @@ impl Display for DropInvertedIndexStmt {
 }
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
-pub struct RefreshInvertedIndexStmt {
+pub struct RefreshTableIndexStmt {
+    pub index_type: TableIndexType,
     pub index_name: Identifier,
     pub catalog: Option<Identifier>,
     pub database: Option<Identifier>,
@@ -199,9 +206,9 @@ pub struct RefreshInvertedIndexStmt {
     pub limit: Option<u64>,
 }
 
-impl Display for RefreshInvertedIndexStmt {
+impl Display for RefreshTableIndexStmt {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        write!(f, "REFRESH INVERTED INDEX")?;
+        write!(f, "REFRESH {} INDEX", self.index_type)?;
         write!(f, " {}", self.index_name)?;
         write!(f, " ON ")?;
         write_dot_separated_list(
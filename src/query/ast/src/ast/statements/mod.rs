@@ -29,11 +29,13 @@ mod insert;
 mod insert_multi_table;
 mod kill;
 mod lock;
+mod materialized_view;
 mod merge_into;
 mod network_policy;
 mod notification;
 mod password_policy;
 mod pipe;
+mod plan_baseline;
 mod presign;
 mod principal;
 mod priority;
@@ -56,6 +58,7 @@ mod user;
 mod view;
 mod virtual_column;
 mod warehouse;
+mod workload_group;
 
 pub use call::*;
 pub use catalog::*;
@@ -74,11 +77,13 @@ pub use insert::*;
 pub use insert_multi_table::*;
 pub use kill::*;
 pub use lock::*;
+pub use materialized_view::*;
 pub use merge_into::*;
 pub use network_policy::*;
 pub use notification::*;
 pub use password_policy::*;
 pub use pipe::*;
+pub use plan_baseline::*;
 pub use presign::*;
 pub use principal::*;
 pub use priority::*;
@@ -101,3 +106,4 @@ pub use user::*;
 pub use view::*;
 pub use virtual_column::*;
 pub use warehouse::*;
+pub use workload_group::*;
@@ -204,9 +204,9 @@ pub enum Statement {
     CreateIndex(CreateIndexStmt),
     DropIndex(DropIndexStmt),
     RefreshIndex(RefreshIndexStmt),
-    CreateInvertedIndex(CreateInvertedIndexStmt),
-    DropInvertedIndex(DropInvertedIndexStmt),
-    RefreshInvertedIndex(RefreshInvertedIndexStmt),
+    CreateTableIndex(CreateTableIndexStmt),
+    DropTableIndex(DropTableIndexStmt),
+    RefreshTableIndex(RefreshTableIndexStmt),
 
     // VirtualColumns
     CreateVirtualColumn(CreateVirtualColumnStmt),
@@ -235,6 +235,7 @@ pub enum Statement {
         if_exists: bool,
         role_name: String,
     },
+    AlterRole(AlterRoleStmt),
     Grant(GrantStmt),
     ShowGrants {
         principal: Option<PrincipalIdentity>,
@@ -309,6 +310,16 @@ pub enum Statement {
         show_options: Option<ShowOptions>,
     },
 
+    // plan baseline
+    CreatePlanBaseline(CreatePlanBaselineStmt),
+    DropPlanBaseline(DropPlanBaselineStmt),
+
+    // workload group
+    CreateWorkloadGroup(CreateWorkloadGroupStmt),
+    AlterWorkloadGroup(AlterWorkloadGroupStmt),
+    DropWorkloadGroup(DropWorkloadGroupStmt),
+    ShowWorkloadGroups,
+
     // tasks
     CreateTask(CreateTaskStmt),
     AlterTask(AlterTaskStmt),
@@ -319,6 +330,11 @@ pub enum Statement {
 
     CreateDynamicTable(CreateDynamicTableStmt),
 
+    // materialized views
+    CreateMaterializedView(CreateMaterializedViewStmt),
+    RefreshMaterializedView(RefreshMaterializedViewStmt),
+    DropMaterializedView(DropMaterializedViewStmt),
+
     // pipes
     CreatePipe(CreatePipeStmt),
     DescribePipe(DescribePipeStmt),
@@ -454,7 +470,7 @@ impl Statement {
             | Statement::ShowStreams(..)
             | Statement::DescribeStream(..)
             | Statement::RefreshIndex(..)
-            | Statement::RefreshInvertedIndex(..)
+            | Statement::RefreshTableIndex(..)
             | Statement::RefreshVirtualColumn(..)
             | Statement::ShowVirtualColumns(..)
             | Statement::ShowUsers
@@ -473,6 +489,7 @@ impl Statement {
             | Statement::DescDatamaskPolicy(..)
             | Statement::DescNetworkPolicy(..)
             | Statement::ShowNetworkPolicies
+            | Statement::ShowWorkloadGroups
             | Statement::DescPasswordPolicy(..)
             | Statement::ShowPasswordPolicies { .. }
             | Statement::ExecuteTask(..)
@@ -520,8 +537,8 @@ impl Statement {
             | Statement::RenameDictionary(..)
             | Statement::CreateStream(..)
             | Statement::DropStream(..)
-            | Statement::CreateInvertedIndex(..)
-            | Statement::DropInvertedIndex(..)
+            | Statement::CreateTableIndex(..)
+            | Statement::DropTableIndex(..)
             | Statement::CreateVirtualColumn(..)
             | Statement::AlterVirtualColumn(..)
             | Statement::DropVirtualColumn(..)
@@ -529,6 +546,7 @@ impl Statement {
             | Statement::DropUser { .. }
             | Statement::CreateRole { .. }
             | Statement::DropRole { .. }
+            | Statement::AlterRole(..)
             | Statement::Grant(..)
             | Statement::Revoke(..)
             | Statement::CreateUDF(..)
@@ -546,10 +564,18 @@ impl Statement {
             | Statement::CreatePasswordPolicy(..)
             | Statement::AlterPasswordPolicy(..)
             | Statement::DropPasswordPolicy(..)
+            | Statement::CreatePlanBaseline(..)
+            | Statement::DropPlanBaseline(..)
+            | Statement::CreateWorkloadGroup(..)
+            | Statement::AlterWorkloadGroup(..)
+            | Statement::DropWorkloadGroup(..)
             | Statement::CreateTask(..)
             | Statement::AlterTask(..)
             | Statement::DropTask(..)
             | Statement::CreateDynamicTable(..)
+            | Statement::CreateMaterializedView(..)
+            | Statement::RefreshMaterializedView(..)
+            | Statement::DropMaterializedView(..)
             | Statement::DropPipe(..)
             | Statement::AlterPipe(..)
             | Statement::CreateNotification(..)
@@ -797,9 +823,9 @@ impl Display for Statement {
             Statement::CreateIndex(stmt) => write!(f, "{stmt}")?,
             Statement::DropIndex(stmt) => write!(f, "{stmt}")?,
             Statement::RefreshIndex(stmt) => write!(f, "{stmt}")?,
-            Statement::CreateInvertedIndex(stmt) => write!(f, "{stmt}")?,
-            Statement::DropInvertedIndex(stmt) => write!(f, "{stmt}")?,
-            Statement::RefreshInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateTableIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropTableIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshTableIndex(stmt) => write!(f, "{stmt}")?,
             Statement::CreateVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::AlterVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::DropVirtualColumn(stmt) => write!(f, "{stmt}")?,
@@ -837,6 +863,7 @@ impl Display for Statement {
                 }
                 write!(f, " '{role}'")?;
             }
+            Statement::AlterRole(stmt) => write!(f, "{stmt}")?,
             Statement::Grant(stmt) => write!(f, "{stmt}")?,
             Statement::ShowGrants {
                 principal,
@@ -928,6 +955,12 @@ impl Display for Statement {
             Statement::AlterPasswordPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropPasswordPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DescPasswordPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreatePlanBaseline(stmt) => write!(f, "{stmt}")?,
+            Statement::DropPlanBaseline(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::DropWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowWorkloadGroups => write!(f, "SHOW WORKLOAD GROUPS")?,
             Statement::ShowPasswordPolicies { show_options } => {
                 write!(f, "SHOW PASSWORD POLICIES")?;
                 if let Some(show_options) = show_options {
@@ -968,6 +1001,9 @@ impl Display for Statement {
             Statement::CreateSequence(stmt) => write!(f, "{stmt}")?,
             Statement::DropSequence(stmt) => write!(f, "{stmt}")?,
             Statement::CreateDynamicTable(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::DropMaterializedView(stmt) => write!(f, "{stmt}")?,
             Statement::SetPriority {
                 priority,
                 object_id,
@@ -29,6 +29,7 @@ use crate::ast::CreateOption;
 use crate::ast::Expr;
 use crate::ast::Identifier;
 use crate::ast::Query;
+use crate::ast::TableIndexType;
 use crate::ast::TableReference;
 use crate::ast::TimeTravelPoint;
 use crate::ast::TypeName;
@@ -271,12 +272,18 @@ impl Display for AttachTableStmt {
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum CreateTableSource {
-    Columns(Vec<ColumnDefinition>, Option<Vec<InvertedIndexDefinition>>),
+    Columns(Vec<ColumnDefinition>, Option<Vec<TableIndexDefinition>>),
     Like {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
         table: Identifier,
     },
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
 }
 
 impl Display for CreateTableSource {
@@ -299,6 +306,19 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT {travel_point}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -430,6 +450,20 @@ pub enum AlterTableAction {
     UnsetOptions {
         targets: Vec<Identifier>,
     },
+    CreateTag {
+        name: Identifier,
+        point: Option<TimeTravelPoint>,
+        retain_days: Option<u64>,
+    },
+    DropTag {
+        name: Identifier,
+    },
+    DropPartition {
+        partition: Expr,
+    },
+    TruncatePartition {
+        partition: Expr,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -487,6 +521,28 @@ impl Display for AlterTableAction {
             AlterTableAction::FlashbackTo { point } => {
                 write!(f, "FLASHBACK TO {}", point)?;
             }
+            AlterTableAction::CreateTag {
+                name,
+                point,
+                retain_days,
+            } => {
+                write!(f, "CREATE TAG {name}")?;
+                if let Some(point) = point {
+                    write!(f, " AT {point}")?;
+                }
+                if let Some(days) = retain_days {
+                    write!(f, " RETAIN {days} DAYS")?;
+                }
+            }
+            AlterTableAction::DropTag { name } => {
+                write!(f, "DROP TAG {name}")?;
+            }
+            AlterTableAction::DropPartition { partition } => {
+                write!(f, "DROP PARTITION ({partition})")?;
+            }
+            AlterTableAction::TruncatePartition { partition } => {
+                write!(f, "TRUNCATE PARTITION ({partition})")?;
+            }
             AlterTableAction::UnsetOptions {
                 targets: unset_targets,
             } => {
@@ -812,6 +868,8 @@ pub enum OptimizeTableAction {
     All,
     Purge { before: Option<TimeTravelPoint> },
     Compact { target: CompactTarget },
+    Expire,
+    RefreshNgramIndex,
 }
 
 impl Display for OptimizeTableAction {
@@ -836,6 +894,8 @@ impl Display for OptimizeTableAction {
                 }
                 Ok(())
             }
+            OptimizeTableAction::Expire => write!(f, "EXPIRE"),
+            OptimizeTableAction::RefreshNgramIndex => write!(f, "REFRESH NGRAM INDEX"),
         }
     }
 }
@@ -892,19 +952,20 @@ impl Display for ColumnDefinition {
 }
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
-pub struct InvertedIndexDefinition {
+pub struct TableIndexDefinition {
+    pub index_type: TableIndexType,
     pub index_name: Identifier,
     pub columns: Vec<Identifier>,
     pub sync_creation: bool,
     pub index_options: BTreeMap<String, String>,
 }
 
-impl Display for InvertedIndexDefinition {
+impl Display for TableIndexDefinition {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         if !self.sync_creation {
             write!(f, "ASYNC ")?;
         }
-        write!(f, "INVERTED INDEX")?;
+        write!(f, "{} INDEX", self.index_type)?;
         write!(f, " {}", self.index_name)?;
         write!(f, " (")?;
         write_comma_separated_list(f, &self.columns)?;
@@ -922,7 +983,7 @@ impl Display for InvertedIndexDefinition {
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum CreateDefinition {
     Column(ColumnDefinition),
-    InvertedIndex(InvertedIndexDefinition),
+    TableIndex(TableIndexDefinition),
 }
 
 impl Display for CreateDefinition {
@@ -931,8 +992,8 @@ impl Display for CreateDefinition {
             CreateDefinition::Column(column_def) => {
                 write!(f, "{}", column_def)?;
             }
-            CreateDefinition::InvertedIndex(inverted_index_def) => {
-                write!(f, "{}", inverted_index_def)?;
+            CreateDefinition::TableIndex(table_index_def) => {
+                write!(f, "{}", table_index_def)?;
             }
         }
         Ok(())
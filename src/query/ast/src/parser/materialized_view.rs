@@ -0,0 +1,138 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use nom::branch::alt;
+use nom::combinator::map;
+use nom::combinator::value;
+use nom_rule::rule;
+
+use crate::ast::CreateMaterializedViewStmt;
+use crate::ast::DropMaterializedViewStmt;
+use crate::ast::RefreshMaterializedViewStmt;
+use crate::ast::RefreshMode;
+use crate::ast::Statement;
+use crate::parser::common::dot_separated_idents_1_to_3;
+use crate::parser::common::map_res;
+use crate::parser::common::IResult;
+use crate::parser::common::*;
+use crate::parser::expr::literal_u64;
+use crate::parser::query::query;
+use crate::parser::statement::parse_create_option;
+use crate::parser::token::TokenKind::*;
+use crate::parser::Input;
+
+pub fn materialized_view(i: Input) -> IResult<Statement> {
+    rule!(
+        #create_materialized_view : "`CREATE [OR REPLACE] MATERIALIZED VIEW [IF NOT EXISTS] [<database>.]<view>
+  [ MAX_STALENESS = <num> { SECOND | MINUTE | HOUR | DAY } ]
+  [ REFRESH_MODE = { AUTO | FULL | INCREMENTAL } ]
+AS
+  <query>`"
+        | #refresh_materialized_view : "`REFRESH MATERIALIZED VIEW [<database>.]<view>`"
+        | #drop_materialized_view : "`DROP MATERIALIZED VIEW [IF EXISTS] [<database>.]<view>`"
+    )(i)
+}
+
+fn create_materialized_view(i: Input) -> IResult<Statement> {
+    map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ MATERIALIZED ~ VIEW ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #dot_separated_idents_1_to_3
+            ~ ( MAX_STALENESS ~ ^"=" ~ ^#interval_secs )?
+            ~ ( REFRESH_MODE ~ ^"=" ~ ^#refresh_mode )?
+            ~ AS ~ ^#query
+        },
+        |(
+            _,
+            opt_or_replace,
+            _,
+            _,
+            opt_if_not_exists,
+            (catalog, database, view),
+            opt_max_staleness,
+            opt_refresh_mode,
+            _,
+            query,
+        )| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateMaterializedView(
+                CreateMaterializedViewStmt {
+                    create_option,
+                    catalog,
+                    database,
+                    view,
+                    max_staleness: opt_max_staleness.map(|(_, _, secs)| secs),
+                    refresh_mode: opt_refresh_mode
+                        .map(|(_, _, mode)| mode)
+                        .unwrap_or(RefreshMode::Auto),
+                    query: Box::new(query),
+                },
+            ))
+        },
+    )(i)
+}
+
+fn refresh_materialized_view(i: Input) -> IResult<Statement> {
+    map(
+        rule! {
+            REFRESH ~ MATERIALIZED ~ ^VIEW ~ ^#dot_separated_idents_1_to_3
+        },
+        |(_, _, _, (catalog, database, view))| {
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStmt {
+                catalog,
+                database,
+                view,
+            })
+        },
+    )(i)
+}
+
+fn drop_materialized_view(i: Input) -> IResult<Statement> {
+    map(
+        rule! {
+            DROP ~ MATERIALIZED ~ ^VIEW ~ ( IF ~ ^EXISTS )? ~ ^#dot_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, (catalog, database, view))| {
+            Statement::DropMaterializedView(DropMaterializedViewStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                view,
+            })
+        },
+    )(i)
+}
+
+fn interval_secs(i: Input) -> IResult<u64> {
+    map(
+        rule! {
+            #literal_u64 ~ ( SECOND | MINUTE | HOUR | DAY )
+        },
+        |(num, unit)| match unit.kind {
+            MINUTE => num * 60,
+            HOUR => num * 60 * 60,
+            DAY => num * 60 * 60 * 24,
+            _ => num,
+        },
+    )(i)
+}
+
+fn refresh_mode(i: Input) -> IResult<RefreshMode> {
+    alt((
+        value(RefreshMode::Auto, rule! { AUTO }),
+        value(RefreshMode::Full, rule! { FULL }),
+        value(RefreshMode::Incremental, rule! { INCREMENTAL }),
+    ))(i)
+}
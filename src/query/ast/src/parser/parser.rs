@@ -20,6 +20,7 @@ use crate::ast::ExplainKind;
 use crate::ast::Expr;
 use crate::ast::Identifier;
 use crate::ast::Literal;
+use crate::ast::PlanHint;
 use crate::ast::SelectTarget;
 use crate::ast::Statement;
 use crate::ast::StatementWithFormat;
@@ -35,6 +36,7 @@ use crate::parser::input::Dialect;
 use crate::parser::input::Input;
 use crate::parser::input::ParseMode;
 use crate::parser::statement::insert_stmt;
+use crate::parser::statement::plan_hint;
 use crate::parser::statement::replace_stmt;
 use crate::parser::statement::statement;
 use crate::parser::token::Token;
@@ -77,6 +79,13 @@ pub fn parse_comma_separated_idents(tokens: &[Token], dialect: Dialect) -> Resul
     })
 }
 
+/// Parse optimizer hints without the `/*+ */` delimiters, e.g. `LEADING(t1 t2) BROADCAST(t2)`.
+pub fn parse_plan_hints(tokens: &[Token], dialect: Dialect) -> Result<Vec<PlanHint>> {
+    run_parser(tokens, dialect, ParseMode::Default, false, |i| {
+        nom::multi::many0(plan_hint)(i)
+    })
+}
+
 pub fn parse_values_with_placeholder(
     tokens: &[Token],
     dialect: Dialect,
@@ -574,9 +574,13 @@ pub fn at_snapshot_or_ts(i: Input) -> IResult<TimeTravelPoint> {
         rule! { "(" ~ OFFSET ~ "=>" ~ #expr ~ ")" },
         |(_, _, _, e, _)| TimeTravelPoint::Offset(Box::new(e)),
     );
+    let at_tag = map(
+        rule! { "(" ~ TAG ~ "=>" ~ #ident ~ ")" },
+        |(_, _, _, name, _)| TimeTravelPoint::Tag(name),
+    );
 
     rule!(
-        #at_snapshot | #at_timestamp | #at_offset
+        #at_snapshot | #at_timestamp | #at_offset | #at_tag
     )(i)
 }
 
@@ -669,6 +673,8 @@ pub fn table_alias_without_as(i: Input) -> IResult<TableAlias> {
 
 pub fn join_operator(i: Input) -> IResult<JoinOperator> {
     alt((
+        value(JoinOperator::LeftAsof, rule! { ASOF ~ LEFT ~ OUTER? }),
+        value(JoinOperator::Asof, rule! { ASOF }),
         value(JoinOperator::Inner, rule! { INNER }),
         value(JoinOperator::LeftSemi, rule! { LEFT? ~ SEMI }),
         value(JoinOperator::RightSemi, rule! { RIGHT ~ SEMI }),
@@ -817,6 +823,17 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
         },
         |(_, expr)| TableReferenceElement::JoinCondition(JoinCondition::On(Box::new(expr))),
     );
+    let join_condition_match = map(
+        rule! {
+            MATCH_CONDITION ~ "(" ~ ^#expr ~ ^")" ~ (ON ~ ^#expr)?
+        },
+        |(_, _, match_condition, _, opt_on)| {
+            TableReferenceElement::JoinCondition(JoinCondition::Match {
+                match_condition: Box::new(match_condition),
+                on: opt_on.map(|(_, on)| Box::new(on)),
+            })
+        },
+    );
     let join_condition_using = map(
         rule! {
             USING ~ "(" ~ #comma_separated_list1(ident) ~ ")"
@@ -882,6 +899,7 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
         | #join
         | #join_condition_on
         | #join_condition_using
+        | #join_condition_match
     })(i)?;
     Ok((rest, WithSpan { span, elem }))
 }
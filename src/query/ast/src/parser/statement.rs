@@ -33,6 +33,7 @@ use crate::parser::dynamic_table::dynamic_table;
 use crate::parser::expr::subexpr;
 use crate::parser::expr::*;
 use crate::parser::input::Input;
+use crate::parser::materialized_view::materialized_view;
 use crate::parser::query::*;
 use crate::parser::stage::*;
 use crate::parser::stream::stream_table;
@@ -51,6 +52,7 @@ pub type ShareDatabaseParams = (ShareNameIdent, Identifier);
 #[derive(Clone)]
 pub enum CreateDatabaseOption {
     DatabaseEngine(DatabaseEngine),
+    Clone(DatabaseRef),
 }
 
 pub fn statement_body(i: Input) -> IResult<Statement> {
@@ -708,6 +710,16 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                         database,
                         engine: Some(engine),
                         options: vec![],
+                        clone_from: None,
+                    })
+                }
+                Some(CreateDatabaseOption::Clone(source)) => {
+                    Statement::CreateDatabase(CreateDatabaseStmt {
+                        create_option,
+                        database,
+                        engine: None,
+                        options: vec![],
+                        clone_from: Some(source),
                     })
                 }
                 None => Statement::CreateDatabase(CreateDatabaseStmt {
@@ -715,6 +727,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     database,
                     engine: None,
                     options: vec![],
+                    clone_from: None,
                 }),
             };
 
@@ -888,6 +901,8 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             ~ ( #engine )?
             ~ ( #uri_location )?
             ~ ( CLUSTER ~ ^BY ~ ( #cluster_type )? ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
+            ~ ( PARTITION ~ ^BY ~ ^"(" ~ ^#expr ~ ^")" )?
+            ~ ( TTL ~ ^"=" ~ ^#expr )?
             ~ ( #table_option )?
             ~ ( AS ~ ^#query )?
         },
@@ -902,6 +917,8 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             engine,
             uri_location,
             opt_cluster_by,
+            opt_partition_by,
+            opt_ttl,
             opt_table_options,
             opt_as_query,
         )| {
@@ -913,6 +930,21 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                 Some(TEMP) | Some(TEMPORARY) => TableType::Temporary,
                 _ => unreachable!(),
             };
+            let mut table_options = opt_table_options.unwrap_or_default();
+            if let Some((_, _, _, partition_by, _)) = opt_partition_by {
+                table_options.insert("partition_by".to_string(), partition_by.to_string());
+            }
+            if let Some((_, _, ttl)) = opt_ttl {
+                // kept as a table option, so that `SET OPTIONS (ttl = '...')` shares the same path
+                let ttl = match ttl {
+                    Expr::Literal {
+                        value: Literal::String(ttl),
+                        ..
+                    } => ttl,
+                    ttl => ttl.to_string(),
+                };
+                table_options.insert("ttl".to_string(), ttl);
+            }
             Ok(Statement::CreateTable(CreateTableStmt {
                 create_option,
                 catalog,
@@ -925,7 +957,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     cluster_type: typ.unwrap_or(ClusterType::Linear),
                     cluster_exprs: exprs,
                 }),
-                table_options: opt_table_options.unwrap_or_default(),
+                table_options,
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
                 table_type,
             }))
@@ -1334,12 +1366,12 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
-    let create_inverted_index = map_res(
+    let create_table_index = map_res(
         rule! {
             CREATE
             ~ ( OR ~ ^REPLACE )?
             ~ ASYNC?
-            ~ INVERTED ~ INDEX
+            ~ #table_index_type ~ INDEX
             ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #ident
             ~ ON ~ #dot_separated_idents_1_to_3
@@ -1350,7 +1382,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             _,
             opt_or_replace,
             opt_async,
-            _,
+            index_type,
             _,
             opt_if_not_exists,
             index_name,
@@ -1363,7 +1395,8 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         )| {
             let create_option =
                 parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
-            Ok(Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+            Ok(Statement::CreateTableIndex(CreateTableIndexStmt {
+                index_type,
                 create_option,
                 index_name,
                 catalog,
@@ -1376,13 +1409,14 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
-    let drop_inverted_index = map(
+    let drop_table_index = map(
         rule! {
-            DROP ~ INVERTED ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident
+            DROP ~ #table_index_type ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident
             ~ ON ~ #dot_separated_idents_1_to_3
         },
-        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
-            Statement::DropInvertedIndex(DropInvertedIndexStmt {
+        |(_, index_type, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropTableIndex(DropTableIndexStmt {
+                index_type,
                 if_exists: opt_if_exists.is_some(),
                 index_name,
                 catalog,
@@ -1392,12 +1426,13 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
-    let refresh_inverted_index = map(
+    let refresh_table_index = map(
         rule! {
-            REFRESH ~ INVERTED ~ INDEX ~ #ident ~ ON ~ #dot_separated_idents_1_to_3 ~ ( LIMIT ~ #literal_u64 )?
+            REFRESH ~ #table_index_type ~ INDEX ~ #ident ~ ON ~ #dot_separated_idents_1_to_3 ~ ( LIMIT ~ #literal_u64 )?
         },
-        |(_, _, _, index_name, _, (catalog, database, table), opt_limit)| {
-            Statement::RefreshInvertedIndex(RefreshInvertedIndexStmt {
+        |(_, index_type, _, index_name, _, (catalog, database, table), opt_limit)| {
+            Statement::RefreshTableIndex(RefreshTableIndexStmt {
+                index_type,
                 index_name,
                 catalog,
                 database,
@@ -1589,6 +1624,26 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             role_name,
         },
     );
+    let set_role_workload_group = map(
+        rule! {
+            SET ~ ^WORKLOAD ~ ^GROUP ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, _, workload_group)| Some(workload_group),
+    );
+    let unset_role_workload_group = value(None, rule! { UNSET ~ ^WORKLOAD ~ ^GROUP });
+    let alter_role = map(
+        rule! {
+            ALTER ~ ROLE ~ ( IF ~ ^EXISTS )? ~ #role_name
+            ~ ( #set_role_workload_group | #unset_role_workload_group )
+        },
+        |(_, _, opt_if_exists, role_name, workload_group)| {
+            Statement::AlterRole(AlterRoleStmt {
+                if_exists: opt_if_exists.is_some(),
+                role_name,
+                workload_group,
+            })
+        },
+    );
     let grant = map(
         rule! {
             GRANT ~ #grant_source ~ TO ~ #grant_option
@@ -2076,6 +2131,74 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         |(_, _, _, show_options)| Statement::ShowPasswordPolicies { show_options },
     );
 
+    let create_plan_baseline = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ PLAN ~ ^BASELINE ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ ^#ident ~ ^AS ~ ^#query
+        },
+        |(_, opt_or_replace, _, _, opt_if_not_exists, name, _, query)| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreatePlanBaseline(CreatePlanBaselineStmt {
+                create_option,
+                name,
+                query: Box::new(query),
+            }))
+        },
+    );
+    let drop_plan_baseline = map(
+        rule! {
+            DROP ~ PLAN ~ ^BASELINE ~ ( IF ~ ^EXISTS )? ~ ^#ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropPlanBaseline(DropPlanBaselineStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+    let create_workload_group = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ ^#ident ~ ( WITH ~ ^#set_table_option )?
+        },
+        |(_, opt_or_replace, _, _, opt_if_not_exists, name, opt_options)| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateWorkloadGroup(CreateWorkloadGroupStmt {
+                create_option,
+                name: name.to_string(),
+                options: opt_options.map(|(_, options)| options).unwrap_or_default(),
+            }))
+        },
+    );
+    let alter_workload_group = map(
+        rule! {
+            ALTER ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^EXISTS )? ~ ^#ident ~ ^SET ~ ^#set_table_option
+        },
+        |(_, _, _, opt_if_exists, name, _, options)| {
+            Statement::AlterWorkloadGroup(AlterWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                options,
+            })
+        },
+    );
+    let drop_workload_group = map(
+        rule! {
+            DROP ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^EXISTS )? ~ ^#ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropWorkloadGroup(DropWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+    let show_workload_groups = value(
+        Statement::ShowWorkloadGroups,
+        rule! { SHOW ~ WORKLOAD ~ ^GROUPS },
+    );
     let create_pipe = map(
         rule! {
             CREATE ~ PIPE ~ ( IF ~ ^NOT ~ ^EXISTS )?
@@ -2459,7 +2582,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #drop_database : "`DROP DATABASE [IF EXISTS] <database>`"
             | #alter_database : "`ALTER DATABASE [IF EXISTS] <action>`"
         ),
-        // network policy / password policy
+        // network policy / password policy / plan baseline
         rule!(
             #create_network_policy: "`CREATE NETWORK POLICY [IF NOT EXISTS] name ALLOWED_IP_LIST = ('ip1' [, 'ip2']) [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
             | #alter_network_policy: "`ALTER NETWORK POLICY [IF EXISTS] name SET [ALLOWED_IP_LIST = ('ip1' [, 'ip2'])] [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
@@ -2471,6 +2594,12 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #drop_password_policy: "`DROP PASSWORD POLICY [IF EXISTS] name`"
             | #describe_password_policy: "`DESC PASSWORD POLICY name`"
             | #show_password_policies: "`SHOW PASSWORD POLICIES [<show_options>]`"
+            | #create_plan_baseline: "`CREATE [OR REPLACE] PLAN BASELINE [IF NOT EXISTS] name AS <query>`"
+            | #drop_plan_baseline: "`DROP PLAN BASELINE [IF EXISTS] name`"
+            | #create_workload_group: "`CREATE [OR REPLACE] WORKLOAD GROUP [IF NOT EXISTS] name [WITH option = value, ...]`"
+            | #alter_workload_group: "`ALTER WORKLOAD GROUP [IF EXISTS] name SET option = value, ...`"
+            | #drop_workload_group: "`DROP WORKLOAD GROUP [IF EXISTS] name`"
+            | #show_workload_groups: "`SHOW WORKLOAD GROUPS`"
         ),
         rule!(
             #conditional_multi_table_insert() : "`INSERT [OVERWRITE] {FIRST|ALL} { WHEN <condition> THEN intoClause [ ... ] } [ ... ] [ ELSE intoClause ] <subquery>`"
@@ -2493,6 +2622,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #show_roles : "`SHOW ROLES`"
             | #create_role : "`CREATE ROLE [IF NOT EXISTS] <role_name>`"
             | #drop_role : "`DROP ROLE [IF EXISTS] <role_name>`"
+            | #alter_role : "`ALTER ROLE [IF EXISTS] <role_name> (SET WORKLOAD GROUP = '<group>' | UNSET WORKLOAD GROUP)`"
             | #create_udf : "`CREATE [OR REPLACE] FUNCTION [IF NOT EXISTS] <udf_name> <udf_definition> [DESC = <description>]`"
             | #drop_udf : "`DROP FUNCTION [IF EXISTS] <udf_name>`"
             | #alter_udf : "`ALTER FUNCTION <udf_name> <udf_definition> [DESC = <description>]`"
@@ -2540,9 +2670,9 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #create_index: "`CREATE [OR REPLACE] AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP <index_type> INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH <index_type> INDEX <index> [LIMIT <limit>]`"
-            | #create_inverted_index: "`CREATE [OR REPLACE] INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table>(<column>, ...)`"
-            | #drop_inverted_index: "`DROP INVERTED INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
-            | #refresh_inverted_index: "`REFRESH INVERTED INDEX <index> ON [<database>.]<table> [LIMIT <limit>]`"
+            | #create_table_index: "`CREATE [OR REPLACE] [ASYNC] {INVERTED | VECTOR} INDEX [IF NOT EXISTS] <index> ON [<database>.]<table>(<column>, ...)`"
+            | #drop_table_index: "`DROP {INVERTED | VECTOR} INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
+            | #refresh_table_index: "`REFRESH {INVERTED | VECTOR} INDEX <index> ON [<database>.]<table> [LIMIT <limit>]`"
         ),
         rule!(
             #create_virtual_column: "`CREATE VIRTUAL COLUMN (expr, ...) FOR [<database>.]<table>`"
@@ -2607,10 +2737,11 @@ AS
          | #desc_task : "`DESC | DESCRIBE TASK <name>`"
          | #execute_task: "`EXECUTE TASK <name>`"
         ),
-        // stream, dynamic tables.
+        // stream, dynamic tables, materialized views.
         rule!(
             #stream_table
             | #dynamic_table
+            | #materialized_view
         ),
         rule!(
             #create_pipe : "`CREATE PIPE [ IF NOT EXISTS ] <name>
@@ -2682,6 +2813,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
             rule! {
                 #with? ~ INSERT ~ #hint? ~ ( INTO | OVERWRITE ) ~ TABLE?
                 ~ #dot_separated_idents_1_to_3
+                ~ ( PARTITION ~ ^"(" ~ ^#expr ~ ^")" )?
                 ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
                 ~ #insert_source_parser
             },
@@ -2692,6 +2824,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
                 overwrite,
                 _,
                 (catalog, database, table),
+                opt_partition,
                 opt_columns,
                 source,
             )| {
@@ -2706,6 +2839,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
                         .unwrap_or_default(),
                     source,
                     overwrite: overwrite.kind == OVERWRITE,
+                    partition: opt_partition.map(|(_, _, partition, _)| partition),
                 })
             },
         )(i)
@@ -2973,18 +3107,84 @@ pub fn set_var_hints(i: Input) -> IResult<HintItem> {
     )(i)
 }
 
+pub fn leading_item(i: Input) -> IResult<LeadingItem> {
+    let join = map(
+        rule! {
+            "(" ~ #leading_item ~ ^#leading_item ~ ^")"
+        },
+        |(_, left, right, _)| LeadingItem::Join(Box::new(left), Box::new(right)),
+    );
+    rule!(
+        #map(ident, LeadingItem::Table)
+        | #join
+    )(i)
+}
+
+pub fn plan_hint(i: Input) -> IResult<PlanHint> {
+    let leading = map(
+        rule! {
+            LEADING ~ ^"(" ~ ^#leading_item+ ~ ^")"
+        },
+        |(_, _, items, _)| PlanHint::Leading(items),
+    );
+    let broadcast = map(
+        rule! {
+            BROADCAST ~ ^"(" ~ ^#ident ~ ^")"
+        },
+        |(_, _, table, _)| PlanHint::Broadcast(table),
+    );
+    let shuffle = map(
+        rule! {
+            SHUFFLE ~ ^"(" ~ ^#ident ~ ^")"
+        },
+        |(_, _, table, _)| PlanHint::Shuffle(table),
+    );
+    rule!(
+        #leading
+        | #broadcast
+        | #shuffle
+        | #value(PlanHint::HashJoin, rule! { HASH_JOIN })
+        | #value(PlanHint::MergeJoin, rule! { MERGE_JOIN })
+        | #value(PlanHint::NoDecorrelate, rule! { NO_DECORRELATE })
+    )(i)
+}
+
 pub fn hint(i: Input) -> IResult<Hint> {
+    enum HintOrPlanHint {
+        Hint(HintItem),
+        PlanHint(PlanHint),
+    }
+    let item = rule!(
+        #map(set_var_hints, HintOrPlanHint::Hint)
+        | #map(plan_hint, HintOrPlanHint::PlanHint)
+    );
     let hint = map(
         rule! {
-            "/*+" ~ #set_var_hints+ ~ "*/"
+            "/*+" ~ #item+ ~ "*/"
+        },
+        |(_, items, _)| {
+            let mut hints_list = vec![];
+            let mut plan_hints = vec![];
+            for item in items {
+                match item {
+                    HintOrPlanHint::Hint(item) => hints_list.push(item),
+                    HintOrPlanHint::PlanHint(item) => plan_hints.push(item),
+                }
+            }
+            Hint {
+                hints_list,
+                plan_hints,
+            }
         },
-        |(_, hints_list, _)| Hint { hints_list },
     );
     let invalid_hint = map(
         rule! {
             "/*+" ~ (!"*/" ~ #any_token)* ~ "*/"
         },
-        |_| Hint { hints_list: vec![] },
+        |_| Hint {
+            hints_list: vec![],
+            plan_hints: vec![],
+        },
     );
     rule!(#hint|#invalid_hint)(i)
 }
@@ -3140,17 +3340,25 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
     Ok((i, def))
 }
 
-pub fn inverted_index_def(i: Input) -> IResult<InvertedIndexDefinition> {
+pub fn table_index_type(i: Input) -> IResult<TableIndexType> {
+    alt((
+        value(TableIndexType::Inverted, rule! { INVERTED }),
+        value(TableIndexType::Vector, rule! { VECTOR }),
+    ))(i)
+}
+
+pub fn table_index_def(i: Input) -> IResult<TableIndexDefinition> {
     map_res(
         rule! {
             ASYNC?
-            ~ INVERTED ~ ^INDEX
+            ~ #table_index_type ~ ^INDEX
             ~ #ident
             ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
             ~ ( #table_option )?
         },
-        |(opt_async, _, _, index_name, _, columns, _, opt_index_options)| {
-            Ok(InvertedIndexDefinition {
+        |(opt_async, index_type, _, index_name, _, columns, _, opt_index_options)| {
+            Ok(TableIndexDefinition {
+                index_type,
                 index_name,
                 columns,
                 sync_creation: opt_async.is_none(),
@@ -3163,10 +3371,7 @@ pub fn inverted_index_def(i: Input) -> IResult<InvertedIndexDefinition> {
 pub fn create_def(i: Input) -> IResult<CreateDefinition> {
     alt((
         map(rule! { #column_def }, CreateDefinition::Column),
-        map(
-            rule! { #inverted_index_def },
-            CreateDefinition::InvertedIndex,
-        ),
+        map(rule! { #table_index_def }, CreateDefinition::TableIndex),
     ))(i)
 }
 
@@ -3516,23 +3721,23 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
         },
         |(_, create_defs, _)| {
             let mut columns = Vec::with_capacity(create_defs.len());
-            let mut inverted_indexes = Vec::new();
+            let mut table_indexes = Vec::new();
             for create_def in create_defs {
                 match create_def {
                     CreateDefinition::Column(column) => {
                         columns.push(column);
                     }
-                    CreateDefinition::InvertedIndex(inverted_index) => {
-                        inverted_indexes.push(inverted_index);
+                    CreateDefinition::TableIndex(table_index) => {
+                        table_indexes.push(table_index);
                     }
                 }
             }
-            let opt_inverted_indexes = if !inverted_indexes.is_empty() {
-                Some(inverted_indexes)
+            let opt_table_indexes = if !table_indexes.is_empty() {
+                Some(table_indexes)
             } else {
                 None
             };
-            CreateTableSource::Columns(columns, opt_inverted_indexes)
+            CreateTableSource::Columns(columns, opt_table_indexes)
         },
     );
     let like = map(
@@ -3546,9 +3751,22 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
         },
     );
 
+    let clone = map(
+        rule! {
+            CLONE ~ #dot_separated_idents_1_to_3 ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, (catalog, database, table), opt_travel_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: opt_travel_point.map(|(_, p)| p),
+        },
+    );
+
     rule!(
         #columns
         | #like
+        | #clone
     )(i)
 }
 
@@ -3772,9 +3990,45 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, targets)| AlterTableAction::UnsetOptions { targets },
     );
 
+    let create_tag = map(
+        rule! {
+            CREATE ~ TAG ~ #ident ~ ( AT ~ ^#at_snapshot_or_ts )? ~ ( RETAIN ~ ^#literal_u64 ~ ^DAYS )?
+        },
+        |(_, _, name, opt_point, opt_retain)| AlterTableAction::CreateTag {
+            name,
+            point: opt_point.map(|(_, point)| point),
+            retain_days: opt_retain.map(|(_, days, _)| days),
+        },
+    );
+
+    let drop_tag = map(
+        rule! {
+            DROP ~ TAG ~ #ident
+        },
+        |(_, _, name)| AlterTableAction::DropTag { name },
+    );
+
+    let drop_partition = map(
+        rule! {
+            DROP ~ PARTITION ~ ^"(" ~ ^#expr ~ ^")"
+        },
+        |(_, _, _, partition, _)| AlterTableAction::DropPartition { partition },
+    );
+
+    let truncate_partition = map(
+        rule! {
+            TRUNCATE ~ ^PARTITION ~ ^"(" ~ ^#expr ~ ^")"
+        },
+        |(_, _, _, partition, _)| AlterTableAction::TruncatePartition { partition },
+    );
+
     rule!(
         #alter_table_cluster_key
         | #drop_table_cluster_key
+        | #create_tag
+        | #drop_tag
+        | #drop_partition
+        | #truncate_partition
         | #rename_table
         | #rename_column
         | #modify_table_comment
@@ -3907,6 +4161,11 @@ pub fn optimize_table_action(i: Input) -> IResult<OptimizeTableAction> {
                 target: opt_segment.map_or(CompactTarget::Block, |_| CompactTarget::Segment),
             }
         }),
+        value(OptimizeTableAction::Expire, rule! { EXPIRE }),
+        value(
+            OptimizeTableAction::RefreshNgramIndex,
+            rule! { REFRESH ~ NGRAM ~ INDEX },
+        ),
     ))(i)
 }
 
@@ -4342,15 +4601,23 @@ pub fn database_engine(i: Input) -> IResult<DatabaseEngine> {
 }
 
 pub fn create_database_option(i: Input) -> IResult<CreateDatabaseOption> {
-    let mut create_db_engine = map(
+    let create_db_engine = map(
         rule! {
             ENGINE ~  ^"=" ~ ^#database_engine
         },
         |(_, _, option)| CreateDatabaseOption::DatabaseEngine(option),
     );
 
+    let create_db_clone = map(
+        rule! {
+            CLONE ~ ^#database_ref
+        },
+        |(_, source)| CreateDatabaseOption::Clone(source),
+    );
+
     rule!(
         #create_db_engine
+        | #create_db_clone
     )(i)
 }
 
@@ -4410,6 +4677,18 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         },
         |(_, _, val)| UserOptionItem::MustChangePassword(val),
     );
+    let set_workload_group = map(
+        rule! {
+            SET ~ WORKLOAD ~ ^GROUP ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, _, workload_group)| UserOptionItem::SetWorkloadGroup(workload_group),
+    );
+    let unset_workload_group = map(
+        rule! {
+            UNSET ~ WORKLOAD ~ ^GROUP
+        },
+        |(_, _, _)| UserOptionItem::UnsetWorkloadGroup,
+    );
 
     rule!(
         #tenant_setting
@@ -4421,6 +4700,8 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         | #unset_password_policy
         | #set_disabled_option
         | #must_change_password
+        | #set_workload_group
+        | #unset_workload_group
     )(i)
 }
 
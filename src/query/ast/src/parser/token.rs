@@ -377,10 +377,14 @@ pub enum TokenKind {
     ASC,
     #[token("ANTI", ignore(ascii_case))]
     ANTI,
+    #[token("ASOF", ignore(ascii_case))]
+    ASOF,
     #[token("ASYNC", ignore(ascii_case))]
     ASYNC,
     #[token("ATTACH", ignore(ascii_case))]
     ATTACH,
+    #[token("BASELINE", ignore(ascii_case))]
+    BASELINE,
     #[token("BEFORE", ignore(ascii_case))]
     BEFORE,
     #[token("BETWEEN", ignore(ascii_case))]
@@ -435,6 +439,8 @@ pub enum TokenKind {
     CENTURY,
     #[token("CHANGES", ignore(ascii_case))]
     CHANGES,
+    #[token("CLONE", ignore(ascii_case))]
+    CLONE,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
     #[token("COMMENT", ignore(ascii_case))]
@@ -655,6 +661,16 @@ pub enum TokenKind {
     TABLE_FUNCTIONS,
     #[token("SET_VAR", ignore(ascii_case))]
     SET_VAR,
+    #[token("BROADCAST", ignore(ascii_case))]
+    BROADCAST,
+    #[token("SHUFFLE", ignore(ascii_case))]
+    SHUFFLE,
+    #[token("HASH_JOIN", ignore(ascii_case))]
+    HASH_JOIN,
+    #[token("MERGE_JOIN", ignore(ascii_case))]
+    MERGE_JOIN,
+    #[token("NO_DECORRELATE", ignore(ascii_case))]
+    NO_DECORRELATE,
     #[token("FUSE", ignore(ascii_case))]
     FUSE,
     #[token("GET", ignore(ascii_case))]
@@ -671,6 +687,8 @@ pub enum TokenKind {
     GRAPH,
     #[token("GROUP", ignore(ascii_case))]
     GROUP,
+    #[token("GROUPS", ignore(ascii_case))]
+    GROUPS,
     #[token("GZIP", ignore(ascii_case))]
     GZIP,
     #[token("HAVING", ignore(ascii_case))]
@@ -800,6 +818,8 @@ pub enum TokenKind {
     MAP,
     #[token("MAX_FILE_SIZE", ignore(ascii_case))]
     MAX_FILE_SIZE,
+    #[token("MAX_STALENESS", ignore(ascii_case))]
+    MAX_STALENESS,
     #[token("MASTER_KEY", ignore(ascii_case))]
     MASTER_KEY,
     #[token("MEDIUM", ignore(ascii_case))]
@@ -834,6 +854,8 @@ pub enum TokenKind {
     NATURAL,
     #[token("NETWORK", ignore(ascii_case))]
     NETWORK,
+    #[token("NGRAM", ignore(ascii_case))]
+    NGRAM,
     #[token("DISABLED", ignore(ascii_case))]
     DISABLED,
     #[token("NDJSON", ignore(ascii_case))]
@@ -916,6 +938,8 @@ pub enum TokenKind {
     PATTERN,
     #[token("PIPELINE", ignore(ascii_case))]
     PIPELINE,
+    #[token("PLAN", ignore(ascii_case))]
+    PLAN,
     #[token("PLAINTEXT_PASSWORD", ignore(ascii_case))]
     PLAINTEXT_PASSWORD,
     #[token("POLICIES", ignore(ascii_case))]
@@ -970,6 +994,8 @@ pub enum TokenKind {
     MERGE,
     #[token("MATCHED", ignore(ascii_case))]
     MATCHED,
+    #[token("MATCH_CONDITION", ignore(ascii_case))]
+    MATCH_CONDITION,
     #[token("MISSING_FIELD_AS", ignore(ascii_case))]
     MISSING_FIELD_AS,
     #[token("NULL_FIELD_AS", ignore(ascii_case))]
@@ -1136,6 +1162,8 @@ pub enum TokenKind {
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TAG", ignore(ascii_case))]
+    TAG,
     #[token("TARGET_LAG", ignore(ascii_case))]
     TARGET_LAG,
     #[token("TEXT", ignore(ascii_case))]
@@ -1184,6 +1212,8 @@ pub enum TokenKind {
     TRY_CAST,
     #[token("TSV", ignore(ascii_case))]
     TSV,
+    #[token("TTL", ignore(ascii_case))]
+    TTL,
     #[token("TUESDAY", ignore(ascii_case))]
     TUESDAY,
     #[token("TUPLE", ignore(ascii_case))]
@@ -1232,6 +1262,8 @@ pub enum TokenKind {
     VARIANT,
     #[token("VARIABLE", ignore(ascii_case))]
     VARIABLE,
+    #[token("VECTOR", ignore(ascii_case))]
+    VECTOR,
     #[token("VERBOSE", ignore(ascii_case))]
     VERBOSE,
     #[token("GRAPHICAL", ignore(ascii_case))]
@@ -1252,6 +1284,8 @@ pub enum TokenKind {
     WINDOW,
     #[token("WITH", ignore(ascii_case))]
     WITH,
+    #[token("WORKLOAD", ignore(ascii_case))]
+    WORKLOAD,
     #[token("XML", ignore(ascii_case))]
     XML,
     #[token("XOR", ignore(ascii_case))]
@@ -1647,6 +1681,7 @@ impl TokenKind {
             | TokenKind::PROCEDURE
             | TokenKind::ASC
             | TokenKind::ANTI
+            | TokenKind::ASOF
             // | TokenKind::ASYMMETRIC
             // | TokenKind::AUTHORIZATION
             // | TokenKind::BINARY
@@ -1691,6 +1726,7 @@ impl TokenKind {
             | TokenKind::LEADING
             | TokenKind::LEFT
             | TokenKind::LIKE
+            | TokenKind::MATCH_CONDITION
             // | TokenKind::LOCALTIME
             // | TokenKind::LOCALTIMESTAMP
             | TokenKind::NATURAL
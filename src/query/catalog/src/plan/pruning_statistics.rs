@@ -29,6 +29,14 @@ pub struct PruningStatistics {
     /// Block inverted index filter pruning stats.
     pub blocks_inverted_index_pruning_before: usize,
     pub blocks_inverted_index_pruning_after: usize,
+
+    /// Block vector index pruning stats.
+    pub blocks_vector_index_pruning_before: usize,
+    pub blocks_vector_index_pruning_after: usize,
+
+    /// Block spatial index pruning stats.
+    pub blocks_spatial_pruning_before: usize,
+    pub blocks_spatial_pruning_after: usize,
 }
 
 impl PruningStatistics {
@@ -41,5 +49,9 @@ impl PruningStatistics {
         self.blocks_bloom_pruning_after += other.blocks_bloom_pruning_after;
         self.blocks_inverted_index_pruning_before += other.blocks_inverted_index_pruning_before;
         self.blocks_inverted_index_pruning_after += other.blocks_inverted_index_pruning_after;
+        self.blocks_vector_index_pruning_before += other.blocks_vector_index_pruning_before;
+        self.blocks_vector_index_pruning_after += other.blocks_vector_index_pruning_after;
+        self.blocks_spatial_pruning_before += other.blocks_spatial_pruning_before;
+        self.blocks_spatial_pruning_after += other.blocks_spatial_pruning_after;
     }
 }
@@ -558,6 +558,7 @@ pub enum NavigationPoint {
     SnapshotID(String),
     TimePoint(DateTime<Utc>),
     StreamInfo(TableInfo),
+    Tag(String),
 }
 
 #[derive(Debug, Copy, Clone, Default)]
@@ -115,6 +115,18 @@ impl Display for ProcessInfoState {
     }
 }
 
+/// The runtime stats of a workload group on the local node.
+#[derive(Debug, Clone, Default)]
+pub struct WorkloadGroupStatus {
+    pub name: String,
+    pub running_queries: u64,
+    pub queued_queries: u64,
+    pub total_queries: u64,
+    pub rejected_queries: u64,
+    pub timeout_queries: u64,
+    pub memory_usage: i64,
+}
+
 #[derive(Debug, Clone)]
 pub struct StageAttachment {
     pub location: String,
@@ -238,6 +250,9 @@ pub trait TableContext: Send + Sync {
     async fn get_warehouse_cluster(&self) -> Result<Arc<Cluster>>;
     fn get_processes_info(&self) -> Vec<ProcessInfo>;
     fn get_queued_queries(&self) -> Vec<ProcessInfo>;
+    fn get_workload_groups_status(&self) -> Vec<WorkloadGroupStatus> {
+        unimplemented!()
+    }
     fn get_queries_profile(&self) -> HashMap<String, Vec<PlanProfile>>;
     fn get_stage_attachment(&self) -> Option<StageAttachment>;
     fn get_last_query_id(&self, index: i32) -> String;
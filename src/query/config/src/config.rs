@@ -45,6 +45,7 @@ use bigbytesdb_common_meta_app::tenant::TenantQuota;
 use bigbytesdb_common_storage::StorageConfig as InnerStorageConfig;
 use bigbytesdb_common_tracing::Config as InnerLogConfig;
 use bigbytesdb_common_tracing::FileConfig as InnerFileLogConfig;
+use bigbytesdb_common_tracing::HistoryConfig as InnerHistoryLogConfig;
 use bigbytesdb_common_tracing::OTLPConfig as InnerOTLPLogConfig;
 use bigbytesdb_common_tracing::OTLPEndpointConfig as InnerOTLPEndpointConfig;
 use bigbytesdb_common_tracing::OTLPProtocol;
@@ -1975,6 +1976,9 @@ pub struct LogConfig {
 
     #[clap(flatten)]
     pub tracing: TracingConfig,
+
+    #[clap(flatten)]
+    pub history: HistoryLogConfig,
 }
 
 impl Default for LogConfig {
@@ -2058,6 +2062,13 @@ impl TryInto<InnerLogConfig> for LogConfig {
 
         let tracing: InnerTracingConfig = self.tracing.try_into()?;
 
+        let history: InnerHistoryLogConfig = self.history.try_into()?;
+        if history.on && history.interval == 0 {
+            return Err(ErrorCode::InvalidConfig(
+                "`history.interval` must be greater than 0 when `history.on` is true".to_string(),
+            ));
+        }
+
         Ok(InnerLogConfig {
             file,
             stderr: self.stderr.try_into()?,
@@ -2066,6 +2077,7 @@ impl TryInto<InnerLogConfig> for LogConfig {
             profile,
             structlog,
             tracing,
+            history,
         })
     }
 }
@@ -2082,6 +2094,7 @@ impl From<InnerLogConfig> for LogConfig {
             profile: inner.profile.into(),
             structlog: inner.structlog.into(),
             tracing: inner.tracing.into(),
+            history: inner.history.into(),
 
             // Deprecated fields
             log_dir: None,
@@ -2462,6 +2475,62 @@ impl From<InnerTracingConfig> for TracingConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct HistoryLogConfig {
+    #[clap(
+        long = "log-history-on", value_name = "VALUE", default_value = "false", action = ArgAction::Set, num_args = 0..=1, require_equals = true, default_missing_value = "true"
+    )]
+    #[serde(rename = "on")]
+    pub log_history_on: bool,
+
+    /// The interval in seconds between two flushes of the query history
+    #[clap(
+        long = "log-history-interval",
+        value_name = "VALUE",
+        default_value = "15"
+    )]
+    #[serde(rename = "interval")]
+    pub log_history_interval: u64,
+
+    /// The number of days the query history is kept, 0 means forever
+    #[clap(
+        long = "log-history-retention",
+        value_name = "VALUE",
+        default_value = "30"
+    )]
+    #[serde(rename = "retention")]
+    pub log_history_retention: u64,
+}
+
+impl Default for HistoryLogConfig {
+    fn default() -> Self {
+        InnerHistoryLogConfig::default().into()
+    }
+}
+
+impl TryInto<InnerHistoryLogConfig> for HistoryLogConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerHistoryLogConfig> {
+        Ok(InnerHistoryLogConfig {
+            on: self.log_history_on,
+            interval: self.log_history_interval,
+            retention: self.log_history_retention,
+        })
+    }
+}
+
+impl From<InnerHistoryLogConfig> for HistoryLogConfig {
+    fn from(inner: InnerHistoryLogConfig) -> Self {
+        Self {
+            log_history_on: inner.on,
+            log_history_interval: inner.interval,
+            log_history_retention: inner.retention,
+        }
+    }
+}
+
 with_prefix!(prefix_otlp "otlp_");
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
@@ -2569,6 +2638,17 @@ pub struct MetaConfig {
     )]
     pub unhealth_endpoint_evict_time: u64,
 
+    /// The max staleness in milliseconds of a stale-tolerant read that can be served by a meta learner,
+    /// a learner failing to serve is evicted for `unhealth_endpoint_evict_time`.
+    /// Learners are discovered by auto-sync.
+    /// 0 sends all reads to the meta leader. By default it is 0.
+    #[clap(
+        long = "meta-max-staleness-ms",
+        value_name = "VALUE",
+        default_value = "0"
+    )]
+    pub max_staleness_ms: u64,
+
     /// Certificate for client to identify meta rpc serve
     #[clap(
         long = "meta-rpc-tls-meta-server-root-ca-cert",
@@ -2625,6 +2705,7 @@ impl TryInto<InnerMetaConfig> for MetaConfig {
             client_timeout_in_second: self.client_timeout_in_second,
             auto_sync_interval: self.auto_sync_interval,
             unhealth_endpoint_evict_time: self.unhealth_endpoint_evict_time,
+            max_staleness_ms: self.max_staleness_ms,
             rpc_tls_meta_server_root_ca_cert: self.rpc_tls_meta_server_root_ca_cert,
             rpc_tls_meta_service_domain_name: self.rpc_tls_meta_service_domain_name,
         })
@@ -2641,6 +2722,7 @@ impl From<InnerMetaConfig> for MetaConfig {
             client_timeout_in_second: inner.client_timeout_in_second,
             auto_sync_interval: inner.auto_sync_interval,
             unhealth_endpoint_evict_time: inner.unhealth_endpoint_evict_time,
+            max_staleness_ms: inner.max_staleness_ms,
             rpc_tls_meta_server_root_ca_cert: inner.rpc_tls_meta_server_root_ca_cert,
             rpc_tls_meta_service_domain_name: inner.rpc_tls_meta_service_domain_name,
 
@@ -2666,6 +2748,7 @@ impl Debug for MetaConfig {
                 "unhealth_endpoint_evict_time",
                 &self.unhealth_endpoint_evict_time,
             )
+            .field("max_staleness_ms", &self.max_staleness_ms)
             .field(
                 "rpc_tls_meta_server_root_ca_cert",
                 &self.rpc_tls_meta_server_root_ca_cert,
@@ -3027,6 +3110,25 @@ pub struct ResourcesManagementConfig {
 
     #[clap(long, value_name = "VALUE")]
     pub node_group: Option<String>,
+
+    /// The address of the kubernetes api server, used by kubernetes managed resources.
+    /// Defaults to the in-cluster address exported by kubernetes.
+    #[clap(long, value_name = "VALUE")]
+    pub kubernetes_api_server: Option<String>,
+
+    /// The namespace of the warehouse StatefulSets.
+    /// Defaults to the namespace of the service account.
+    #[clap(long, value_name = "VALUE")]
+    pub kubernetes_namespace: Option<String>,
+
+    /// The bearer token file used to access the kubernetes api server.
+    /// Defaults to the token of the service account.
+    #[clap(long, value_name = "VALUE")]
+    pub kubernetes_token_file: Option<String>,
+
+    /// The StatefulSet copied as the template of each warehouse.
+    #[clap(long, value_name = "VALUE")]
+    pub kubernetes_statefulset_template: Option<String>,
 }
 
 mod cache_config_converters {
@@ -375,6 +375,9 @@ pub struct MetaConfig {
     /// 0 disables auto-sync. By default auto-sync is disabled.
     pub auto_sync_interval: u64,
     pub unhealth_endpoint_evict_time: u64,
+    /// The max staleness in milliseconds of a stale-tolerant read that can be served by a meta learner.
+    /// 0 sends all reads to the meta leader.
+    pub max_staleness_ms: u64,
     /// Certificate for client to identify meta rpc serve
     pub rpc_tls_meta_server_root_ca_cert: String,
     pub rpc_tls_meta_service_domain_name: String,
@@ -390,6 +393,7 @@ impl Default for MetaConfig {
             client_timeout_in_second: 4,
             auto_sync_interval: 0,
             unhealth_endpoint_evict_time: 120,
+            max_staleness_ms: 0,
             rpc_tls_meta_server_root_ca_cert: "".to_string(),
             rpc_tls_meta_service_domain_name: "localhost".to_string(),
         }
@@ -449,6 +453,11 @@ impl MetaConfig {
                 None
             },
             unhealthy_endpoint_evict_time: Duration::from_secs(self.unhealth_endpoint_evict_time),
+            max_staleness: if self.max_staleness_ms > 0 {
+                Some(Duration::from_millis(self.max_staleness_ms))
+            } else {
+                None
+            },
         }
     }
 }
@@ -466,6 +475,7 @@ impl Debug for MetaConfig {
                 "unhealth_endpoint_evict_time",
                 &self.unhealth_endpoint_evict_time,
             )
+            .field("max_staleness_ms", &self.max_staleness_ms)
             .field(
                 "rpc_tls_meta_server_root_ca_cert",
                 &self.rpc_tls_meta_server_root_ca_cert,
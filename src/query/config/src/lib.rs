@@ -39,6 +39,7 @@ pub use config::CacheStorageTypeConfig;
 pub use config::Commands;
 pub use config::Config;
 pub use config::QueryConfig;
+pub use config::ResourcesManagementConfig;
 pub use config::StorageConfig;
 pub use bigbytesdb_common_base::version::BIGBYTESDB_COMMIT_VERSION;
 pub use bigbytesdb_common_base::version::BIGBYTESDB_GIT_SEMVER;
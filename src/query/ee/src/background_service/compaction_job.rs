@@ -21,6 +21,7 @@ use bigbytesdb_common_base::base::tokio::sync::mpsc::Sender;
 use bigbytesdb_common_base::base::tokio::sync::Mutex;
 use bigbytesdb_common_base::base::tokio::time::Instant;
 use bigbytesdb_common_base::base::uuid::Uuid;
+use bigbytesdb_common_catalog::catalog_kind::CATALOG_DEFAULT;
 use bigbytesdb_common_config::InnerConfig;
 use bigbytesdb_common_exception::Result;
 use bigbytesdb_common_expression::types::StringType;
@@ -48,7 +49,9 @@ use bigbytesdb_common_meta_types::SeqV;
 use bigbytesdb_common_users::UserApiProvider;
 use bigbytesdb_query::sessions::QueryContext;
 use bigbytesdb_query::sessions::Session;
+use bigbytesdb_query::sessions::TableContext;
 use bigbytesdb_query::table_functions::SuggestedBackgroundTasksSource;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_TTL;
 use log::debug;
 use log::error;
 use log::info;
@@ -280,6 +283,14 @@ impl CompactionJob {
         tb_id: u64,
         manual: Option<ManualTriggerParams>,
     ) -> Result<()> {
+        // drop expired rows first, so that compaction does not rewrite them.
+        if let Err(e) = self
+            .do_expire(session.clone(), database.clone(), table.clone())
+            .await
+        {
+            error!(job = "compaction", background = true, database = database.clone(), table = table.clone(); "expire failed: {:?}", e);
+        }
+
         let (seg, blk, stats) = Self::do_check_table(
             session.clone(),
             database.clone(),
@@ -664,6 +675,32 @@ impl CompactionJob {
         Ok(())
     }
 
+    // delete the expired rows of a table with a ttl option, blocks which are
+    // entirely expired are dropped by their min/max statistics.
+    async fn do_expire(
+        &self,
+        session: Arc<Session>,
+        database: String,
+        table: String,
+    ) -> Result<()> {
+        let ctx = session.create_query_context().await?;
+        let tbl = ctx.get_table(CATALOG_DEFAULT, &database, &table).await?;
+        if !tbl.options().contains_key(OPT_KEY_TTL) {
+            return Ok(());
+        }
+
+        let sql = Self::get_expire_sql(database, table);
+        debug!(
+            job = "compaction",
+            background = true,
+            sql = sql.as_str();
+            "expire"
+        );
+        let ctx = session.create_query_context().await?;
+        SuggestedBackgroundTasksSource::do_execute_sql(ctx, sql).await?;
+        Ok(())
+    }
+
     pub fn get_compaction_advice_sql(
         database: String,
         table: String,
@@ -708,4 +745,8 @@ impl CompactionJob {
         };
         format!("OPTIMIZE TABLE {}.{} COMPACT{};", database, table, limit)
     }
+
+    pub fn get_expire_sql(database: String, table: String) -> String {
+        format!("OPTIMIZE TABLE {}.{} EXPIRE;", database, table)
+    }
 }
@@ -0,0 +1,187 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigbytesdb_common_config::ResourcesManagementConfig;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use reqwest::Method;
+use reqwest::StatusCode;
+use serde_json::Value;
+
+const SERVICE_ACCOUNT_DIR: &str = "/var/run/secrets/kubernetes.io/serviceaccount";
+
+/// A minimal client of the kubernetes apps/v1 StatefulSet api.
+///
+/// StatefulSets are kept as json values, so that all the fields of the
+/// template StatefulSet are copied to the warehouses as they are.
+pub struct KubernetesClient {
+    client: reqwest::Client,
+    api_server: String,
+    namespace: String,
+    token: Option<String>,
+}
+
+impl KubernetesClient {
+    pub fn try_create(cfg: &ResourcesManagementConfig) -> Result<KubernetesClient> {
+        let api_server = match &cfg.kubernetes_api_server {
+            Some(api_server) => api_server.trim_end_matches('/').to_string(),
+            None => {
+                let host = std::env::var("KUBERNETES_SERVICE_HOST").map_err(|_| {
+                    ErrorCode::InvalidConfig(
+                        "kubernetes_api_server is not set and KUBERNETES_SERVICE_HOST is not found",
+                    )
+                })?;
+                let port =
+                    std::env::var("KUBERNETES_SERVICE_PORT").unwrap_or_else(|_| "443".to_string());
+                format!("https://{}:{}", host, port)
+            }
+        };
+
+        let namespace = match &cfg.kubernetes_namespace {
+            Some(namespace) => namespace.clone(),
+            None => std::fs::read_to_string(format!("{}/namespace", SERVICE_ACCOUNT_DIR))
+                .map(|v| v.trim().to_string())
+                .unwrap_or_else(|_| "default".to_string()),
+        };
+
+        let token = match &cfg.kubernetes_token_file {
+            Some(file) => Some(std::fs::read_to_string(file).map_err(|cause| {
+                ErrorCode::InvalidConfig(format!(
+                    "Cannot read kubernetes token file {}, cause: {}",
+                    file, cause
+                ))
+            })?),
+            None => std::fs::read_to_string(format!("{}/token", SERVICE_ACCOUNT_DIR)).ok(),
+        };
+
+        let mut builder = reqwest::Client::builder();
+        if let Ok(ca) = std::fs::read(format!("{}/ca.crt", SERVICE_ACCOUNT_DIR)) {
+            let certificate = reqwest::Certificate::from_pem(&ca).map_err(|cause| {
+                ErrorCode::InvalidConfig(format!(
+                    "Invalid kubernetes service account certificate, cause: {}",
+                    cause
+                ))
+            })?;
+            builder = builder.add_root_certificate(certificate);
+        }
+        let client = builder.build().map_err(|cause| {
+            ErrorCode::InvalidConfig(format!("Cannot create kubernetes client, cause: {}", cause))
+        })?;
+
+        Ok(KubernetesClient {
+            client,
+            api_server,
+            namespace,
+            token: token.map(|v| v.trim().to_string()),
+        })
+    }
+
+    pub async fn get_statefulset(&self, name: &str) -> Result<Option<Value>> {
+        let path = format!("{}/{}", self.statefulsets_path(), name);
+        self.request(Method::GET, &path, None).await
+    }
+
+    pub async fn list_statefulsets(&self, label_selector: &str) -> Result<Vec<Value>> {
+        let path = format!(
+            "{}?labelSelector={}",
+            self.statefulsets_path(),
+            label_selector
+        );
+        let list = self.request(Method::GET, &path, None).await?;
+        Ok(list
+            .and_then(|mut list| list.get_mut("items").map(Value::take))
+            .and_then(|items| match items {
+                Value::Array(items) => Some(items),
+                _ => None,
+            })
+            .unwrap_or_default())
+    }
+
+    /// Returns None if the StatefulSet already exists.
+    pub async fn create_statefulset(&self, statefulset: Value) -> Result<Option<Value>> {
+        match self
+            .request(Method::POST, &self.statefulsets_path(), Some(statefulset))
+            .await
+        {
+            Err(cause) if cause.code() == ErrorCode::WAREHOUSE_OPERATE_CONFLICT => Ok(None),
+            res => res,
+        }
+    }
+
+    /// Applies a json merge patch to the StatefulSet, returns None if it does not exist.
+    pub async fn patch_statefulset(&self, name: &str, patch: Value) -> Result<Option<Value>> {
+        let path = format!("{}/{}", self.statefulsets_path(), name);
+        self.request(Method::PATCH, &path, Some(patch)).await
+    }
+
+    /// Returns None if the StatefulSet does not exist.
+    pub async fn delete_statefulset(&self, name: &str) -> Result<Option<Value>> {
+        let path = format!("{}/{}", self.statefulsets_path(), name);
+        self.request(Method::DELETE, &path, None).await
+    }
+
+    fn statefulsets_path(&self) -> String {
+        format!("/apis/apps/v1/namespaces/{}/statefulsets", self.namespace)
+    }
+
+    async fn request(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<Value>,
+    ) -> Result<Option<Value>> {
+        let url = format!("{}{}", self.api_server, path);
+        let mut request = self.client.request(method.clone(), &url);
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+        if let Some(body) = body {
+            let content_type = match method {
+                Method::PATCH => "application/merge-patch+json",
+                _ => "application/json",
+            };
+            request = request
+                .header(reqwest::header::CONTENT_TYPE, content_type)
+                .body(body.to_string());
+        }
+
+        let response = request.send().await.map_err(|cause| {
+            ErrorCode::Internal(format!(
+                "Kubernetes request {} {} failed, cause: {}",
+                method, url, cause
+            ))
+        })?;
+
+        let status = response.status();
+        let body = response.text().await.map_err(|cause| {
+            ErrorCode::Internal(format!(
+                "Cannot read kubernetes response of {} {}, cause: {}",
+                method, url, cause
+            ))
+        })?;
+
+        match status {
+            StatusCode::NOT_FOUND => Ok(None),
+            StatusCode::CONFLICT => Err(ErrorCode::WarehouseOperateConflict(format!(
+                "Kubernetes request {} {} conflicted: {}",
+                method, url, body
+            ))),
+            status if status.is_success() => Ok(Some(serde_json::from_str(&body)?)),
+            status => Err(ErrorCode::Internal(format!(
+                "Kubernetes request {} {} failed with status {}: {}",
+                method, url, status, body
+            ))),
+        }
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod kubernetes_client;
 mod resources_management_kubernetes;
 mod resources_management_self_managed;
 mod resources_management_system;
@@ -26,7 +27,12 @@ use bigbytesdb_common_exception::Result;
 use bigbytesdb_common_management::WarehouseMgr;
 use bigbytesdb_common_meta_store::MetaStoreProvider;
 use bigbytesdb_enterprise_resources_management::ResourcesManagement;
+pub use kubernetes_client::KubernetesClient;
 pub use resources_management_kubernetes::KubernetesResourcesManagement;
+pub use resources_management_kubernetes::NODE_GROUP_LABEL;
+pub use resources_management_kubernetes::SUSPENDED_REPLICAS_ANNOTATION;
+pub use resources_management_kubernetes::WAREHOUSE_ENV;
+pub use resources_management_kubernetes::WAREHOUSE_LABEL;
 pub use resources_management_self_managed::SelfManagedResourcesManagement;
 pub use resources_management_system::SystemResourcesManagement;
 
@@ -41,24 +47,13 @@ pub async fn init_resources_management(cfg: &InnerConfig) -> Result<()> {
         Some(resources_management) => {
             match resources_management.typ.to_ascii_lowercase().as_str() {
                 "self_managed" => SelfManagedResourcesManagement::create(cfg),
-                "kubernetes_managed" => KubernetesResourcesManagement::create(),
+                "kubernetes_managed" => {
+                    let warehouse_manager = create_warehouse_manager(cfg).await?;
+                    KubernetesResourcesManagement::create(resources_management, warehouse_manager)
+                }
                 "system_managed" => {
-                    let meta_api_provider =
-                        MetaStoreProvider::new(cfg.meta.to_meta_grpc_client_conf());
-                    match meta_api_provider.create_meta_store().await {
-                        Err(cause) => Err(ErrorCode::from(cause)
-                            .add_message_back("(while create resources management).")),
-                        Ok(metastore) => {
-                            let tenant_id = &cfg.query.tenant_id;
-                            let lift_time = Duration::from_secs(60);
-                            let warehouse_manager = WarehouseMgr::create(
-                                metastore,
-                                tenant_id.tenant_name(),
-                                lift_time,
-                            )?;
-                            SystemResourcesManagement::create(Arc::new(warehouse_manager))
-                        }
-                    }
+                    let warehouse_manager = create_warehouse_manager(cfg).await?;
+                    SystemResourcesManagement::create(warehouse_manager)
                 }
                 _ => Err(ErrorCode::InvalidConfig(format!(
                     "unimplemented resources management {}",
@@ -71,3 +66,19 @@ pub async fn init_resources_management(cfg: &InnerConfig) -> Result<()> {
     GlobalInstance::set(service);
     Ok(())
 }
+
+async fn create_warehouse_manager(cfg: &InnerConfig) -> Result<Arc<WarehouseMgr>> {
+    let meta_api_provider = MetaStoreProvider::new(cfg.meta.to_meta_grpc_client_conf());
+    match meta_api_provider.create_meta_store().await {
+        Err(cause) => {
+            Err(ErrorCode::from(cause).add_message_back("(while create resources management)."))
+        }
+        Ok(metastore) => {
+            let tenant_id = &cfg.query.tenant_id;
+            let lift_time = Duration::from_secs(60);
+            let warehouse_manager =
+                WarehouseMgr::create(metastore, tenant_id.tenant_name(), lift_time)?;
+            Ok(Arc::new(warehouse_manager))
+        }
+    }
+}
@@ -15,73 +15,396 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use bigbytesdb_common_config::GlobalConfig;
+use bigbytesdb_common_config::ResourcesManagementConfig;
 use bigbytesdb_common_exception::ErrorCode;
 use bigbytesdb_common_exception::Result;
 use bigbytesdb_common_management::SelectedNode;
+use bigbytesdb_common_management::SystemManagedCluster;
+use bigbytesdb_common_management::SystemManagedWarehouse;
+use bigbytesdb_common_management::WarehouseApi;
 use bigbytesdb_common_management::WarehouseInfo;
 use bigbytesdb_common_meta_types::NodeInfo;
+use bigbytesdb_common_meta_types::NodeType;
 use bigbytesdb_enterprise_resources_management::ResourcesManagement;
+use log::info;
+use serde_json::json;
+use serde_json::Value;
 
-pub struct KubernetesResourcesManagement {}
+use crate::resource_management::KubernetesClient;
+
+/// The label of the warehouse StatefulSets, its value is the warehouse name.
+pub const WAREHOUSE_LABEL: &str = "bigbytesdb.io/warehouse";
+/// The node selector label of the pods if the warehouse is created in a node group.
+pub const NODE_GROUP_LABEL: &str = "bigbytesdb.io/node-group";
+/// Records the replicas of a suspended warehouse, which are restored on resume.
+pub const SUSPENDED_REPLICAS_ANNOTATION: &str = "bigbytesdb.io/suspended-replicas";
+/// The environment variable injected into the warehouse pods, with the warehouse name.
+pub const WAREHOUSE_ENV: &str = "BIGBYTESDB_WAREHOUSE";
+/// The times to retry an update of a warehouse that conflicted with a concurrent update.
+const MAX_UPDATE_RETRIES: usize = 10;
+
+/// Each warehouse is a StatefulSet copied from the template StatefulSet, with one cluster
+/// named after the warehouse. Warehouses are scaled by the replicas of the StatefulSet, and
+/// the pods register themselves into the warehouse cluster through the meta service.
+pub struct KubernetesResourcesManagement {
+    client: KubernetesClient,
+    template: String,
+    warehouse_manager: Arc<dyn WarehouseApi>,
+}
 
 impl KubernetesResourcesManagement {
-    pub fn create() -> Result<Arc<dyn ResourcesManagement>> {
-        Ok(Arc::new(KubernetesResourcesManagement {}))
+    pub fn create(
+        cfg: &ResourcesManagementConfig,
+        warehouse_manager: Arc<dyn WarehouseApi>,
+    ) -> Result<Arc<dyn ResourcesManagement>> {
+        let Some(template) = cfg.kubernetes_statefulset_template.clone() else {
+            return Err(ErrorCode::InvalidConfig(
+                "kubernetes_statefulset_template is empty with kubernetes resources management",
+            ));
+        };
+
+        Ok(Arc::new(KubernetesResourcesManagement {
+            client: KubernetesClient::try_create(cfg)?,
+            template,
+            warehouse_manager,
+        }))
+    }
+
+    fn statefulset_name(&self, warehouse: &str) -> Result<String> {
+        let valid = !warehouse.is_empty()
+            && warehouse
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+            && !warehouse.starts_with('-')
+            && !warehouse.ends_with('-');
+        if !valid {
+            return Err(ErrorCode::InvalidWarehouse(format!(
+                "Invalid warehouse name {:?}, kubernetes warehouse name must consist of lower case alphanumeric characters or '-'",
+                warehouse
+            )));
+        }
+        Ok(format!("{}-{}", self.template, warehouse))
+    }
+
+    async fn get_warehouse(&self, warehouse: &str) -> Result<(String, Value)> {
+        let name = self.statefulset_name(warehouse)?;
+        match self.client.get_statefulset(&name).await? {
+            Some(statefulset) => Ok((name, statefulset)),
+            None => Err(ErrorCode::UnknownWarehouse(format!(
+                "Unknown warehouse or self managed warehouse {:?}",
+                warehouse
+            ))),
+        }
+    }
+
+    /// Patches the StatefulSet of the warehouse with the patch built from its current state.
+    ///
+    /// The patch carries the `resourceVersion` that was read, so the api server rejects it
+    /// if the StatefulSet was changed in between, then the update is retried on the new state.
+    async fn update_warehouse<F>(&self, warehouse: &str, build_patch: F) -> Result<()>
+    where F: Fn(&Value) -> Result<Value> {
+        let mut retries = 0;
+        loop {
+            let (name, statefulset) = self.get_warehouse(warehouse).await?;
+            let mut patch = build_patch(&statefulset)?;
+            if let Some(version) = statefulset["metadata"]["resourceVersion"].as_str() {
+                patch["metadata"]["resourceVersion"] = json!(version);
+            }
+
+            match self.client.patch_statefulset(&name, patch).await {
+                Ok(Some(_)) => return Ok(()),
+                Ok(None) => {
+                    return Err(ErrorCode::UnknownWarehouse(format!(
+                        "Unknown warehouse or self managed warehouse {:?}",
+                        warehouse
+                    )));
+                }
+                Err(cause)
+                    if cause.code() == ErrorCode::WAREHOUSE_OPERATE_CONFLICT
+                        && retries < MAX_UPDATE_RETRIES =>
+                {
+                    retries += 1;
+                    info!(
+                        "Kubernetes warehouse {:?} was updated concurrently, retry({})",
+                        warehouse, retries
+                    );
+                }
+                Err(cause) => return Err(cause),
+            }
+        }
+    }
+
+    /// Checks the nodes of a warehouse cluster, returns the number of nodes and their node group.
+    fn selected_nodes(nodes: &[SelectedNode]) -> Result<(u64, Option<String>)> {
+        let mut node_group = None;
+        for (idx, node) in nodes.iter().enumerate() {
+            let SelectedNode::Random(group) = node;
+            if idx != 0 && group != &node_group {
+                return Err(ErrorCode::InvalidWarehouse(
+                    "Nodes of kubernetes warehouse must be in the same node group",
+                ));
+            }
+            node_group = group.clone();
+        }
+        Ok((nodes.len() as u64, node_group))
+    }
+
+    /// Sums up the nodes to be assigned to (or unassigned from) the only cluster of the warehouse.
+    fn cluster_nodes(
+        warehouse: &str,
+        statefulset: &Value,
+        nodes: &HashMap<String, Vec<SelectedNode>>,
+    ) -> Result<u64> {
+        let mut num = 0;
+        for (cluster, nodes) in nodes {
+            if cluster != warehouse {
+                return Err(ErrorCode::WarehouseClusterNotExists(format!(
+                    "Warehouse cluster {:?}.{:?} not exists, kubernetes warehouse only has cluster {:?}",
+                    warehouse, cluster, warehouse
+                )));
+            }
+            let (n, node_group) = Self::selected_nodes(nodes)?;
+            if n != 0 && node_group != statefulset_node_group(statefulset) {
+                return Err(ErrorCode::InvalidWarehouse(format!(
+                    "Nodes of warehouse {:?} must be in node group {:?}",
+                    warehouse,
+                    statefulset_node_group(statefulset)
+                )));
+            }
+            num += n;
+        }
+        Ok(num)
+    }
+
+    fn check_running(warehouse: &str, statefulset: &Value) -> Result<()> {
+        if suspended_replicas(statefulset).is_some() {
+            return Err(ErrorCode::InvalidWarehouse(format!(
+                "Cannot change nodes of warehouse {:?}, because warehouse state is not running.",
+                warehouse
+            )));
+        }
+        Ok(())
+    }
+
+    fn build_statefulset(
+        &self,
+        name: &str,
+        warehouse: &str,
+        replicas: u64,
+        node_group: Option<String>,
+        template: &Value,
+    ) -> Result<Value> {
+        let mut spec = template["spec"].clone();
+        if !spec.is_object() {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "Kubernetes statefulset template {} has no spec",
+                self.template
+            )));
+        }
+        spec["replicas"] = json!(replicas);
+        spec["selector"]["matchLabels"][WAREHOUSE_LABEL] = json!(warehouse);
+        spec["template"]["metadata"]["labels"][WAREHOUSE_LABEL] = json!(warehouse);
+        if let Some(node_group) = node_group {
+            spec["template"]["spec"]["nodeSelector"][NODE_GROUP_LABEL] = json!(node_group);
+        }
+
+        let env = json!({"name": WAREHOUSE_ENV, "value": warehouse});
+        if let Some(containers) = spec["template"]["spec"]["containers"].as_array_mut() {
+            for container in containers {
+                match container["env"].as_array_mut() {
+                    Some(envs) => {
+                        envs.retain(|v| v["name"] != WAREHOUSE_ENV);
+                        envs.push(env.clone());
+                    }
+                    None => container["env"] = json!([env.clone()]),
+                }
+            }
+        }
+
+        let mut labels = template["metadata"]["labels"].clone();
+        labels[WAREHOUSE_LABEL] = json!(warehouse);
+        Ok(json!({
+            "apiVersion": "apps/v1",
+            "kind": "StatefulSet",
+            "metadata": {
+                "name": name,
+                "labels": labels,
+            },
+            "spec": spec,
+        }))
     }
 }
 
+fn statefulset_replicas(statefulset: &Value) -> u64 {
+    statefulset["spec"]["replicas"].as_u64().unwrap_or(1)
+}
+
+fn suspended_replicas(statefulset: &Value) -> Option<u64> {
+    statefulset["metadata"]["annotations"][SUSPENDED_REPLICAS_ANNOTATION]
+        .as_str()
+        .and_then(|v| v.parse().ok())
+}
+
+fn statefulset_node_group(statefulset: &Value) -> Option<String> {
+    statefulset["spec"]["template"]["spec"]["nodeSelector"][NODE_GROUP_LABEL]
+        .as_str()
+        .map(|v| v.to_string())
+}
+
+fn warehouse_info(statefulset: &Value) -> Option<WarehouseInfo> {
+    let warehouse = statefulset["metadata"]["labels"][WAREHOUSE_LABEL].as_str()?;
+    let (status, replicas) = match suspended_replicas(statefulset) {
+        Some(replicas) => ("Suspended", replicas),
+        None => ("Running", statefulset_replicas(statefulset)),
+    };
+    let node_group = statefulset_node_group(statefulset);
+    let cluster = SystemManagedCluster {
+        nodes: vec![SelectedNode::Random(node_group); replicas as usize],
+    };
+
+    Some(WarehouseInfo::SystemManaged(SystemManagedWarehouse {
+        id: warehouse.to_string(),
+        role_id: statefulset["metadata"]["uid"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        status: status.to_string(),
+        clusters: HashMap::from([(warehouse.to_string(), cluster)]),
+    }))
+}
+
 #[async_trait::async_trait]
 impl ResourcesManagement for KubernetesResourcesManagement {
     fn support_forward_warehouse_request(&self) -> bool {
         false
     }
 
-    async fn init_node(&self, _: &mut NodeInfo) -> Result<()> {
-        Err(ErrorCode::Unimplemented(
-            "Unimplemented kubernetes resources management",
-        ))
+    async fn init_node(&self, node: &mut NodeInfo) -> Result<()> {
+        // Pods of the warehouse StatefulSets join the cluster named after their warehouse,
+        // other nodes (e.g. of the template StatefulSet) use the configured cluster id.
+        let cluster_id = match std::env::var(WAREHOUSE_ENV) {
+            Ok(warehouse) if !warehouse.is_empty() => warehouse,
+            _ => GlobalConfig::instance().query.cluster_id.clone(),
+        };
+        if cluster_id.is_empty() {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "cluster_id is empty and {} is not set with kubernetes resources management",
+                WAREHOUSE_ENV
+            )));
+        }
+
+        node.cluster_id = cluster_id.clone();
+        node.warehouse_id = cluster_id;
+        node.node_type = NodeType::SelfManaged;
+        Ok(())
     }
 
-    async fn create_warehouse(&self, _: String, _: Vec<SelectedNode>) -> Result<WarehouseInfo> {
-        Err(ErrorCode::Unimplemented(
-            "Unimplemented kubernetes resources management",
-        ))
+    async fn create_warehouse(
+        &self,
+        warehouse: String,
+        nodes: Vec<SelectedNode>,
+    ) -> Result<WarehouseInfo> {
+        let name = self.statefulset_name(&warehouse)?;
+        let (replicas, node_group) = Self::selected_nodes(&nodes)?;
+        if replicas == 0 {
+            return Err(ErrorCode::EmptyNodesForWarehouse(format!(
+                "Cannot create warehouse {:?} with empty nodes.",
+                warehouse
+            )));
+        }
+
+        let Some(template) = self.client.get_statefulset(&self.template).await? else {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "Kubernetes statefulset template {} is not found",
+                self.template
+            )));
+        };
+
+        let statefulset =
+            self.build_statefulset(&name, &warehouse, replicas, node_group, &template)?;
+        let Some(created) = self.client.create_statefulset(statefulset).await? else {
+            return Err(ErrorCode::WarehouseAlreadyExists(format!(
+                "Warehouse {:?} already exists",
+                warehouse
+            )));
+        };
+
+        info!(
+            "Created kubernetes warehouse {:?} with {} replicas",
+            warehouse, replicas
+        );
+        warehouse_info(&created).ok_or_else(|| {
+            ErrorCode::InvalidWarehouse(format!("Invalid warehouse statefulset {}", name))
+        })
     }
 
-    async fn drop_warehouse(&self, _: String) -> Result<WarehouseInfo> {
-        Err(ErrorCode::Unimplemented(
-            "Unimplemented kubernetes resources management",
-        ))
+    async fn drop_warehouse(&self, warehouse: String) -> Result<WarehouseInfo> {
+        let (name, statefulset) = self.get_warehouse(&warehouse).await?;
+        let info = warehouse_info(&statefulset).ok_or_else(|| {
+            ErrorCode::InvalidWarehouse(format!("Invalid warehouse statefulset {}", name))
+        })?;
+
+        if self.client.delete_statefulset(&name).await?.is_none() {
+            return Err(ErrorCode::UnknownWarehouse(format!(
+                "Unknown warehouse or self managed warehouse {:?}",
+                warehouse
+            )));
+        }
+
+        info!("Dropped kubernetes warehouse {:?}", warehouse);
+        Ok(info)
     }
 
-    async fn resume_warehouse(&self, _: String) -> Result<()> {
-        Err(ErrorCode::Unimplemented(
-            "Unimplemented kubernetes resources management",
-        ))
+    async fn resume_warehouse(&self, warehouse: String) -> Result<()> {
+        self.update_warehouse(&warehouse, |statefulset| {
+            let Some(replicas) = suspended_replicas(statefulset) else {
+                return Err(ErrorCode::InvalidWarehouse(format!(
+                    "Cannot resume warehouse {:?}, because warehouse state is not suspend",
+                    warehouse
+                )));
+            };
+
+            Ok(json!({
+                "metadata": {"annotations": {SUSPENDED_REPLICAS_ANNOTATION: null}},
+                "spec": {"replicas": replicas},
+            }))
+        })
+        .await
     }
 
-    async fn suspend_warehouse(&self, _: String) -> Result<()> {
-        Err(ErrorCode::Unimplemented(
-            "Unimplemented kubernetes resources management",
-        ))
+    async fn suspend_warehouse(&self, warehouse: String) -> Result<()> {
+        self.update_warehouse(&warehouse, |statefulset| {
+            if suspended_replicas(statefulset).is_some() {
+                return Err(ErrorCode::InvalidWarehouse(format!(
+                    "Cannot suspend warehouse {:?}, because warehouse state is not running.",
+                    warehouse
+                )));
+            }
+
+            let replicas = statefulset_replicas(statefulset);
+            Ok(json!({
+                "metadata": {"annotations": {SUSPENDED_REPLICAS_ANNOTATION: replicas.to_string()}},
+                "spec": {"replicas": 0},
+            }))
+        })
+        .await
     }
 
     async fn rename_warehouse(&self, _: String, _: String) -> Result<()> {
         Err(ErrorCode::Unimplemented(
-            "Unimplemented kubernetes resources management",
+            "Unimplemented rename warehouse with kubernetes resources management",
         ))
     }
 
-    async fn inspect_warehouse(&self, _: String) -> Result<Vec<NodeInfo>> {
-        Err(ErrorCode::Unimplemented(
-            "Unimplemented kubernetes resources management",
-        ))
+    async fn inspect_warehouse(&self, warehouse: String) -> Result<Vec<NodeInfo>> {
+        self.get_warehouse(&warehouse).await?;
+        self.warehouse_manager.list_warehouse_nodes(warehouse).await
     }
 
     async fn list_warehouses(&self) -> Result<Vec<WarehouseInfo>> {
-        Err(ErrorCode::Unimplemented(
-            "Unimplemented kubernetes resources management",
-        ))
+        let statefulsets = self.client.list_statefulsets(WAREHOUSE_LABEL).await?;
+        Ok(statefulsets.iter().filter_map(warehouse_info).collect())
     }
 
     async fn add_warehouse_cluster(
@@ -91,45 +414,59 @@ impl ResourcesManagement for KubernetesResourcesManagement {
         _: Vec<SelectedNode>,
     ) -> Result<()> {
         Err(ErrorCode::Unimplemented(
-            "Unimplemented kubernetes resources management",
+            "Unimplemented add warehouse cluster with kubernetes resources management",
         ))
     }
 
     async fn rename_warehouse_cluster(&self, _: String, _: String, _: String) -> Result<()> {
         Err(ErrorCode::Unimplemented(
-            "Unimplemented kubernetes resources management",
+            "Unimplemented rename warehouse cluster with kubernetes resources management",
         ))
     }
 
     async fn drop_warehouse_cluster(&self, _: String, _: String) -> Result<()> {
         Err(ErrorCode::Unimplemented(
-            "Unimplemented kubernetes resources management",
+            "Unimplemented drop warehouse cluster with kubernetes resources management",
         ))
     }
 
     async fn assign_warehouse_nodes(
         &self,
-        _: String,
-        _: HashMap<String, Vec<SelectedNode>>,
+        warehouse: String,
+        nodes: HashMap<String, Vec<SelectedNode>>,
     ) -> Result<()> {
-        Err(ErrorCode::Unimplemented(
-            "Unimplemented kubernetes resources management",
-        ))
+        self.update_warehouse(&warehouse, |statefulset| {
+            Self::check_running(&warehouse, statefulset)?;
+            let num = Self::cluster_nodes(&warehouse, statefulset, &nodes)?;
+
+            let replicas = statefulset_replicas(statefulset) + num;
+            Ok(json!({"spec": {"replicas": replicas}}))
+        })
+        .await
     }
 
     async fn unassign_warehouse_nodes(
         &self,
-        _: String,
-        _: HashMap<String, Vec<SelectedNode>>,
+        warehouse: String,
+        nodes: HashMap<String, Vec<SelectedNode>>,
     ) -> Result<()> {
-        Err(ErrorCode::Unimplemented(
-            "Unimplemented kubernetes resources management",
-        ))
+        self.update_warehouse(&warehouse, |statefulset| {
+            Self::check_running(&warehouse, statefulset)?;
+            let num = Self::cluster_nodes(&warehouse, statefulset, &nodes)?;
+
+            let replicas = statefulset_replicas(statefulset);
+            if num >= replicas {
+                return Err(ErrorCode::EmptyNodesForWarehouse(format!(
+                    "Cannot unassign all nodes of warehouse {:?}, please drop the warehouse instead.",
+                    warehouse
+                )));
+            }
+            Ok(json!({"spec": {"replicas": replicas - num}}))
+        })
+        .await
     }
 
     async fn list_online_nodes(&self) -> Result<Vec<NodeInfo>> {
-        Err(ErrorCode::Unimplemented(
-            "Unimplemented kubernetes resources management",
-        ))
+        self.warehouse_manager.list_online_nodes().await
     }
 }
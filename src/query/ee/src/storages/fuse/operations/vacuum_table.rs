@@ -128,10 +128,19 @@ pub async fn get_snapshot_referenced_files(
     segments_vec.into_iter().for_each(|(location, _)| {
         segments.insert(location);
     });
+    let mut blocks = locations_referenced.block_location;
+    let mut blocks_index = locations_referenced.bloom_location;
+
+    // 3. Files shared with the clones of this table or pinned by its tags are referenced as well
+    let retained = fuse_table.retained_locations(ctx).await?;
+    segments.extend(retained.segments);
+    blocks.extend(retained.blocks);
+    blocks_index.extend(retained.blooms);
+
     Ok(Some(SnapshotReferencedFiles {
         segments,
-        blocks: locations_referenced.block_location,
-        blocks_index: locations_referenced.bloom_location,
+        blocks,
+        blocks_index,
     }))
 }
 
@@ -26,6 +26,7 @@ use bigbytesdb_common_expression::DataField;
 use bigbytesdb_common_expression::DataSchema;
 use bigbytesdb_common_meta_app::schema::CreateOption;
 use bigbytesdb_common_meta_app::schema::CreateTableIndexReq;
+use bigbytesdb_common_meta_app::schema::TableIndexType;
 use bigbytesdb_common_sql::plans::RefreshTableIndexPlan;
 use bigbytesdb_common_storages_fuse::io::read::InvertedIndexReader;
 use bigbytesdb_common_storages_fuse::io::MetaReaders;
@@ -77,6 +78,7 @@ async fn test_fuse_do_refresh_inverted_index() -> Result<()> {
 
     let req = CreateTableIndexReq {
         create_option: CreateOption::Create,
+        index_type: TableIndexType::Inverted,
         table_id,
         tenant,
         name: index_name.clone(),
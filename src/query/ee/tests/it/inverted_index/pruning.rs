@@ -35,6 +35,7 @@ use bigbytesdb_common_expression::TableSchemaRef;
 use bigbytesdb_common_expression::TableSchemaRefExt;
 use bigbytesdb_common_meta_app::schema::CreateOption;
 use bigbytesdb_common_meta_app::schema::CreateTableIndexReq;
+use bigbytesdb_common_meta_app::schema::TableIndexType;
 use bigbytesdb_common_sql::plans::CreateTablePlan;
 use bigbytesdb_common_sql::plans::RefreshTableIndexPlan;
 use bigbytesdb_common_sql::BloomIndexColumns;
@@ -76,6 +77,7 @@ async fn apply_block_pruning(
         schema,
         push_down,
         bloom_index_cols,
+        vec![],
         None,
         FuseStorageFormat::Parquet,
     )?
@@ -511,6 +513,7 @@ async fn test_block_pruner() -> Result<()> {
 
     let req = CreateTableIndexReq {
         create_option: CreateOption::Create,
+        index_type: TableIndexType::Inverted,
         table_id,
         tenant,
         name: index_name.clone(),
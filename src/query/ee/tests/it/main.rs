@@ -17,5 +17,6 @@ mod aggregating_index;
 mod background_service;
 mod inverted_index;
 mod license;
+mod resources_management;
 mod storages;
 mod stream;
@@ -0,0 +1,380 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bigbytesdb_common_base::base::tokio;
+use bigbytesdb_common_config::ResourcesManagementConfig;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_management::SelectedNode;
+use bigbytesdb_common_management::WarehouseInfo;
+use bigbytesdb_common_management::WarehouseMgr;
+use bigbytesdb_common_meta_embedded::MemMeta;
+use bigbytesdb_common_meta_store::MetaStore;
+use bigbytesdb_enterprise_query::resource_management::KubernetesResourcesManagement;
+use bigbytesdb_enterprise_query::resource_management::NODE_GROUP_LABEL;
+use bigbytesdb_enterprise_query::resource_management::SUSPENDED_REPLICAS_ANNOTATION;
+use bigbytesdb_enterprise_query::resource_management::WAREHOUSE_ENV;
+use bigbytesdb_enterprise_query::resource_management::WAREHOUSE_LABEL;
+use bigbytesdb_enterprise_resources_management::ResourcesManagement;
+use serde_json::json;
+use serde_json::Value;
+use wiremock::matchers::body_partial_json;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+use wiremock::matchers::query_param;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+
+const STATEFULSETS: &str = "/apis/apps/v1/namespaces/test/statefulsets";
+
+fn create_resources_management(server: &MockServer) -> Result<Arc<dyn ResourcesManagement>> {
+    let cfg = ResourcesManagementConfig {
+        typ: "kubernetes_managed".to_string(),
+        node_group: None,
+        kubernetes_api_server: Some(server.uri()),
+        kubernetes_namespace: Some("test".to_string()),
+        kubernetes_token_file: None,
+        kubernetes_statefulset_template: Some("query".to_string()),
+    };
+    let metastore = MetaStore::L(Arc::new(MemMeta::default()));
+    let warehouse_manager = WarehouseMgr::create(metastore, "test", Duration::from_secs(60))?;
+    KubernetesResourcesManagement::create(&cfg, Arc::new(warehouse_manager))
+}
+
+fn warehouse_statefulset(warehouse: &str, replicas: u64, suspended: Option<u64>) -> Value {
+    let mut statefulset = json!({
+        "metadata": {
+            "name": format!("query-{}", warehouse),
+            "uid": "uid-1",
+            "labels": {WAREHOUSE_LABEL: warehouse},
+        },
+        "spec": {"replicas": replicas},
+    });
+    if let Some(suspended) = suspended {
+        statefulset["metadata"]["annotations"][SUSPENDED_REPLICAS_ANNOTATION] =
+            json!(suspended.to_string());
+    }
+    statefulset
+}
+
+async fn mock_get(server: &MockServer, name: &str, statefulset: Value) {
+    Mock::given(method("GET"))
+        .and(path(format!("{}/{}", STATEFULSETS, name)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(statefulset))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_kubernetes_warehouse() -> Result<()> {
+    let server = MockServer::start().await;
+    let template = json!({
+        "metadata": {"name": "query", "labels": {"app": "query"}},
+        "spec": {
+            "replicas": 1,
+            "serviceName": "query",
+            "selector": {"matchLabels": {"app": "query"}},
+            "template": {
+                "metadata": {"labels": {"app": "query"}},
+                "spec": {"containers": [{"name": "query", "env": [{"name": "A", "value": "1"}]}]},
+            },
+        },
+    });
+    mock_get(&server, "query", template).await;
+
+    Mock::given(method("POST"))
+        .and(path(STATEFULSETS))
+        .and(body_partial_json(json!({
+            "metadata": {"name": "query-wh1", "labels": {"app": "query", WAREHOUSE_LABEL: "wh1"}},
+            "spec": {
+                "replicas": 2,
+                "selector": {"matchLabels": {"app": "query", WAREHOUSE_LABEL: "wh1"}},
+                "template": {"spec": {"nodeSelector": {NODE_GROUP_LABEL: "g1"}}},
+            },
+        })))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(warehouse_statefulset("wh1", 2, None)),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let resources_management = create_resources_management(&server)?;
+    let nodes = vec![SelectedNode::Random(Some("g1".to_string())); 2];
+    let info = resources_management
+        .create_warehouse("wh1".to_string(), nodes)
+        .await?;
+
+    let WarehouseInfo::SystemManaged(warehouse) = info else {
+        unreachable!()
+    };
+    assert_eq!(warehouse.id, "wh1");
+    assert_eq!(warehouse.status, "Running");
+    assert_eq!(warehouse.clusters["wh1"].nodes.len(), 2);
+
+    // the warehouse name is injected into the containers.
+    let requests = server.received_requests().await.unwrap();
+    let created: Value = serde_json::from_slice(&requests.last().unwrap().body)?;
+    let envs = created["spec"]["template"]["spec"]["containers"][0]["env"].clone();
+    assert_eq!(
+        envs,
+        json!([{"name": "A", "value": "1"}, {"name": WAREHOUSE_ENV, "value": "wh1"}])
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_kubernetes_warehouse_failed() -> Result<()> {
+    let server = MockServer::start().await;
+    mock_get(&server, "query", json!({"spec": {"replicas": 1}})).await;
+    Mock::given(method("POST"))
+        .and(path(STATEFULSETS))
+        .respond_with(ResponseTemplate::new(409))
+        .mount(&server)
+        .await;
+
+    let resources_management = create_resources_management(&server)?;
+    let nodes = vec![SelectedNode::Random(None)];
+
+    let res = resources_management
+        .create_warehouse("wh1".to_string(), nodes.clone())
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::WAREHOUSE_ALREADY_EXISTS);
+
+    let res = resources_management
+        .create_warehouse("Invalid_Name".to_string(), nodes.clone())
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::INVALID_WAREHOUSE);
+
+    let res = resources_management
+        .create_warehouse("wh2".to_string(), vec![])
+        .await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::EMPTY_NODES_FOR_WAREHOUSE
+    );
+
+    let mixed_nodes = vec![
+        SelectedNode::Random(None),
+        SelectedNode::Random(Some("g1".to_string())),
+    ];
+    let res = resources_management
+        .create_warehouse("wh2".to_string(), mixed_nodes)
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::INVALID_WAREHOUSE);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_suspend_resume_kubernetes_warehouse() -> Result<()> {
+    let server = MockServer::start().await;
+    mock_get(&server, "query-wh1", warehouse_statefulset("wh1", 3, None)).await;
+    mock_get(
+        &server,
+        "query-wh2",
+        warehouse_statefulset("wh2", 0, Some(2)),
+    )
+    .await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!("{}/query-wh1", STATEFULSETS)))
+        .and(body_partial_json(json!({
+            "metadata": {"annotations": {SUSPENDED_REPLICAS_ANNOTATION: "3"}},
+            "spec": {"replicas": 0},
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("{}/query-wh2", STATEFULSETS)))
+        .and(body_partial_json(json!({
+            "metadata": {"annotations": {SUSPENDED_REPLICAS_ANNOTATION: null}},
+            "spec": {"replicas": 2},
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let resources_management = create_resources_management(&server)?;
+    resources_management
+        .suspend_warehouse("wh1".to_string())
+        .await?;
+    resources_management
+        .resume_warehouse("wh2".to_string())
+        .await?;
+
+    let res = resources_management
+        .resume_warehouse("wh1".to_string())
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::INVALID_WAREHOUSE);
+    let res = resources_management
+        .suspend_warehouse("wh2".to_string())
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::INVALID_WAREHOUSE);
+    let res = resources_management
+        .suspend_warehouse("wh3".to_string())
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::UNKNOWN_WAREHOUSE);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_assign_kubernetes_warehouse_nodes() -> Result<()> {
+    let server = MockServer::start().await;
+    mock_get(&server, "query-wh1", warehouse_statefulset("wh1", 2, None)).await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!("{}/query-wh1", STATEFULSETS)))
+        .and(body_partial_json(json!({"spec": {"replicas": 5}})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("{}/query-wh1", STATEFULSETS)))
+        .and(body_partial_json(json!({"spec": {"replicas": 1}})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let resources_management = create_resources_management(&server)?;
+    let nodes =
+        |n: usize| HashMap::from([("wh1".to_string(), vec![SelectedNode::Random(None); n])]);
+    resources_management
+        .assign_warehouse_nodes("wh1".to_string(), nodes(3))
+        .await?;
+    resources_management
+        .unassign_warehouse_nodes("wh1".to_string(), nodes(1))
+        .await?;
+
+    let res = resources_management
+        .unassign_warehouse_nodes("wh1".to_string(), nodes(2))
+        .await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::EMPTY_NODES_FOR_WAREHOUSE
+    );
+
+    let other_cluster = HashMap::from([("c2".to_string(), vec![SelectedNode::Random(None)])]);
+    let res = resources_management
+        .assign_warehouse_nodes("wh1".to_string(), other_cluster)
+        .await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::WAREHOUSE_CLUSTER_NOT_EXISTS
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_update_kubernetes_warehouse_conflict() -> Result<()> {
+    let server = MockServer::start().await;
+    let mut statefulset = warehouse_statefulset("wh1", 2, None);
+    statefulset["metadata"]["resourceVersion"] = json!("1");
+    Mock::given(method("GET"))
+        .and(path(format!("{}/query-wh1", STATEFULSETS)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(statefulset))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    // the statefulset is scaled concurrently after the first read.
+    let mut statefulset = warehouse_statefulset("wh1", 3, None);
+    statefulset["metadata"]["resourceVersion"] = json!("2");
+    mock_get(&server, "query-wh1", statefulset).await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!("{}/query-wh1", STATEFULSETS)))
+        .and(body_partial_json(json!({
+            "metadata": {"resourceVersion": "1"},
+            "spec": {"replicas": 3},
+        })))
+        .respond_with(ResponseTemplate::new(409))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("{}/query-wh1", STATEFULSETS)))
+        .and(body_partial_json(json!({
+            "metadata": {"resourceVersion": "2"},
+            "spec": {"replicas": 4},
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let resources_management = create_resources_management(&server)?;
+    let nodes = HashMap::from([("wh1".to_string(), vec![SelectedNode::Random(None)])]);
+    resources_management
+        .assign_warehouse_nodes("wh1".to_string(), nodes)
+        .await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_list_and_drop_kubernetes_warehouses() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(STATEFULSETS))
+        .and(query_param("labelSelector", WAREHOUSE_LABEL))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "items": [
+                warehouse_statefulset("wh1", 2, None),
+                warehouse_statefulset("wh2", 0, Some(1)),
+            ],
+        })))
+        .mount(&server)
+        .await;
+    mock_get(&server, "query-wh1", warehouse_statefulset("wh1", 2, None)).await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("{}/query-wh1", STATEFULSETS)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let resources_management = create_resources_management(&server)?;
+    let warehouses = resources_management.list_warehouses().await?;
+    let status = warehouses
+        .iter()
+        .map(|w| match w {
+            WarehouseInfo::SystemManaged(w) => (w.id.clone(), w.status.clone()),
+            WarehouseInfo::SelfManaged(id) => (id.clone(), String::new()),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(status, vec![
+        ("wh1".to_string(), "Running".to_string()),
+        ("wh2".to_string(), "Suspended".to_string()),
+    ]);
+
+    let nodes = resources_management
+        .inspect_warehouse("wh1".to_string())
+        .await?;
+    assert!(nodes.is_empty());
+
+    resources_management
+        .drop_warehouse("wh1".to_string())
+        .await?;
+    let res = resources_management.drop_warehouse("wh3".to_string()).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::UNKNOWN_WAREHOUSE);
+    Ok(())
+}
@@ -0,0 +1,488 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::types::*;
+use bigbytesdb_common_expression::with_number_mapped_type;
+use bigbytesdb_common_expression::AggregateFunctionRef;
+use bigbytesdb_common_expression::Column;
+use bigbytesdb_common_expression::ColumnBuilder;
+use bigbytesdb_common_expression::FromData;
+use bigbytesdb_common_expression::Scalar;
+use bigbytesdb_common_expression::ScalarRef;
+
+use super::FunctionData;
+use super::UnaryState;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::assert_variadic_arguments;
+use crate::aggregates::AggregateUnaryFunction;
+
+const DEFAULT_TOP_K: u64 = 10;
+const DEFAULT_COUNTERS_FACTOR: u64 = 3;
+const MAX_COUNTERS: u64 = 1 << 20;
+
+struct ApproxTopKData {
+    pub k: u64,
+    pub counters: u64,
+    pub data_type: DataType,
+}
+
+impl FunctionData for ApproxTopKData {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, BorshSerialize, BorshDeserialize)]
+struct Counter {
+    /// Estimated frequency, never less than the true frequency.
+    count: u64,
+    /// Upper bound of the over-estimation contained in `count`.
+    error: u64,
+}
+
+/// Space-Saving sketch (Metwally et al.) with the mergeable summary
+/// extension from Agarwal et al., "Mergeable Summaries".
+///
+/// At most `capacity` counters are monitored. When a new value arrives and
+/// the sketch is full, the counter with the smallest count is replaced by
+/// the new value, which inherits that count as its error.
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct ApproxTopKState<T>
+where
+    T: ValueType,
+    T::Scalar: Ord + Hash + BorshSerialize + BorshDeserialize,
+{
+    capacity: u64,
+    counters: HashMap<T::Scalar, Counter>,
+    /// The monitored values ordered by count, to find the counter to evict in `O(log n)`.
+    /// It is rebuilt from `counters` when they are out of sync, e.g. after deserializing.
+    #[borsh(skip)]
+    by_count: BTreeSet<(u64, T::Scalar)>,
+}
+
+impl<T> Default for ApproxTopKState<T>
+where
+    T: ValueType,
+    T::Scalar: Ord + Hash + BorshSerialize + BorshDeserialize,
+{
+    fn default() -> Self {
+        ApproxTopKState::<T> {
+            capacity: 0,
+            counters: HashMap::new(),
+            by_count: BTreeSet::new(),
+        }
+    }
+}
+
+impl<T> ApproxTopKState<T>
+where
+    T: ValueType,
+    T::Scalar: Ord + Hash + BorshSerialize + BorshDeserialize,
+{
+    fn is_full(&self) -> bool {
+        self.capacity > 0 && self.counters.len() as u64 >= self.capacity
+    }
+
+    /// The smallest monitored count, or zero if the sketch still has free counters.
+    ///
+    /// Any value that is not monitored occurred at most this many times.
+    fn min_count(&self) -> u64 {
+        if !self.is_full() {
+            return 0;
+        }
+        if self.by_count.len() == self.counters.len() {
+            return self.by_count.first().map(|(count, _)| *count).unwrap_or(0);
+        }
+        self.counters.values().map(|c| c.count).min().unwrap_or(0)
+    }
+
+    fn sync_by_count(&mut self) {
+        if self.by_count.len() == self.counters.len() {
+            return;
+        }
+        self.by_count = self
+            .counters
+            .iter()
+            .map(|(key, counter)| (counter.count, key.clone()))
+            .collect();
+    }
+
+    fn sorted_counters(&self) -> Vec<(&T::Scalar, &Counter)> {
+        let mut entries = self.counters.iter().collect::<Vec<_>>();
+        entries.sort_by(|(lk, lc), (rk, rc)| match rc.count.cmp(&lc.count) {
+            Ordering::Equal => lk.cmp(rk),
+            ord => ord,
+        });
+        entries
+    }
+
+    fn truncate(&mut self) {
+        if self.counters.len() as u64 <= self.capacity {
+            return;
+        }
+        let capacity = self.capacity as usize;
+        let mut entries = self.counters.drain().collect::<Vec<_>>();
+        entries.sort_by(|(lk, lc), (rk, rc)| match rc.count.cmp(&lc.count) {
+            Ordering::Equal => lk.cmp(rk),
+            ord => ord,
+        });
+        entries.truncate(capacity);
+        self.counters = entries.into_iter().collect();
+        self.by_count.clear();
+    }
+}
+
+impl<T> UnaryState<T, AnyType> for ApproxTopKState<T>
+where
+    T: ValueType + Sync + Send,
+    T::Scalar: Ord + Hash + Sync + Send + BorshSerialize + BorshDeserialize,
+{
+    fn add(
+        &mut self,
+        other: T::ScalarRef<'_>,
+        function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        if self.capacity == 0 {
+            let data = unsafe {
+                function_data
+                    .unwrap()
+                    .as_any()
+                    .downcast_ref_unchecked::<ApproxTopKData>()
+            };
+            self.capacity = data.counters;
+        }
+
+        self.sync_by_count();
+
+        let other = T::to_owned_scalar(other);
+        if let Some(counter) = self.counters.get_mut(&other) {
+            let mut entry = (counter.count, other);
+            self.by_count.remove(&entry);
+            counter.count += 1;
+            entry.0 = counter.count;
+            self.by_count.insert(entry);
+            return Ok(());
+        }
+
+        if !self.is_full() {
+            self.counters
+                .insert(other.clone(), Counter { count: 1, error: 0 });
+            self.by_count.insert((1, other));
+            return Ok(());
+        }
+
+        // Evict the value with the smallest count, the new value takes over its counter.
+        let (min_count, min_key) = self.by_count.pop_first().unwrap();
+        self.counters.remove(&min_key);
+        self.counters.insert(other.clone(), Counter {
+            count: min_count + 1,
+            error: min_count,
+        });
+        self.by_count.insert((min_count + 1, other));
+
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        if rhs.counters.is_empty() {
+            self.capacity = self.capacity.max(rhs.capacity);
+            return Ok(());
+        }
+
+        // A value missing from one side may have been evicted there, so it is
+        // credited with that side's minimum count to keep the estimate an upper bound.
+        let lhs_min = self.min_count();
+        let rhs_min = rhs.min_count();
+
+        for (key, counter) in self.counters.iter_mut() {
+            if !rhs.counters.contains_key(key) {
+                counter.count += rhs_min;
+                counter.error += rhs_min;
+            }
+        }
+
+        for (key, rhs_counter) in rhs.counters.iter() {
+            match self.counters.get_mut(key) {
+                Some(counter) => {
+                    counter.count += rhs_counter.count;
+                    counter.error += rhs_counter.error;
+                }
+                None => {
+                    self.counters.insert(key.clone(), Counter {
+                        count: rhs_counter.count + lhs_min,
+                        error: rhs_counter.error + lhs_min,
+                    });
+                }
+            }
+        }
+
+        self.capacity = self.capacity.max(rhs.capacity);
+        self.by_count.clear();
+        self.truncate();
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut ColumnBuilder,
+        function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        let data = unsafe {
+            function_data
+                .unwrap()
+                .as_any()
+                .downcast_ref_unchecked::<ApproxTopKData>()
+        };
+
+        let entries = self.sorted_counters();
+        let len = entries.len().min(data.k as usize);
+
+        let mut values = ColumnBuilder::with_capacity(&data.data_type, len);
+        let mut counts = Vec::with_capacity(len);
+        for (key, counter) in entries.into_iter().take(len) {
+            values.push(T::upcast_scalar(key.clone()).as_ref());
+            counts.push(counter.count);
+        }
+
+        let column = Column::Tuple(vec![values.build(), UInt64Type::from_data(counts)]);
+        builder.push(ScalarRef::Array(column));
+        Ok(())
+    }
+}
+
+pub fn try_create_aggregate_approx_top_k_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_variadic_arguments(display_name, arguments.len(), (1, 3))?;
+
+    let data_type = arguments[0].clone();
+    let (k, counters) = get_top_k_params(&params, display_name)?;
+    let return_type = DataType::Array(Box::new(DataType::Tuple(vec![
+        data_type.clone(),
+        DataType::Number(NumberDataType::UInt64),
+    ])));
+    let function_data = ApproxTopKData {
+        k,
+        counters,
+        data_type: data_type.clone(),
+    };
+
+    with_number_mapped_type!(|NUM| match &data_type {
+        DataType::Number(NumberDataType::NUM) => {
+            let func = AggregateUnaryFunction::<
+                ApproxTopKState<NumberType<NUM>>,
+                NumberType<NUM>,
+                AnyType,
+            >::try_create(
+                display_name, return_type, params, data_type.clone()
+            )
+            .with_function_data(Box::new(function_data))
+            .with_need_drop(true);
+            Ok(Arc::new(func))
+        }
+        DataType::String => {
+            let func = AggregateUnaryFunction::<
+                ApproxTopKState<StringType>,
+                StringType,
+                AnyType,
+            >::try_create(display_name, return_type, params, data_type.clone())
+            .with_function_data(Box::new(function_data))
+            .with_need_drop(true);
+            Ok(Arc::new(func))
+        }
+        _ => {
+            let func =
+                AggregateUnaryFunction::<ApproxTopKState<AnyType>, AnyType, AnyType>::try_create(
+                    display_name,
+                    return_type,
+                    params,
+                    data_type.clone(),
+                )
+                .with_function_data(Box::new(function_data))
+                .with_need_drop(true);
+            Ok(Arc::new(func))
+        }
+    })
+}
+
+pub fn aggregate_approx_top_k_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_approx_top_k_function))
+}
+
+/// Returns `(k, counters)`. `counters` defaults to `k * 3` and can never be smaller than `k`.
+fn get_top_k_params(params: &[Scalar], display_name: &str) -> Result<(u64, u64)> {
+    if params.len() > 2 {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expect to have [0, 2] params, but got {}",
+            display_name,
+            params.len()
+        )));
+    }
+
+    let get_positive = |scalar: &Scalar, name: &str| -> Result<u64> {
+        if let Scalar::Number(number) = scalar {
+            if let Some(number) = number.integer_to_i128() {
+                if number > 0 {
+                    return Ok(number as u64);
+                }
+            }
+        }
+        Err(ErrorCode::BadDataValueType(format!(
+            "The {} of aggregate function {} must be positive int",
+            name, display_name
+        )))
+    };
+
+    let k = match params.first() {
+        Some(scalar) => get_positive(scalar, "k")?,
+        None => DEFAULT_TOP_K,
+    };
+    let counters = match params.get(1) {
+        Some(scalar) => get_positive(scalar, "counters")?,
+        None => k.saturating_mul(DEFAULT_COUNTERS_FACTOR),
+    };
+
+    if counters < k {
+        return Err(ErrorCode::BadArguments(format!(
+            "The counters of aggregate function {} must not be less than k, but got k = {}, counters = {}",
+            display_name, k, counters
+        )));
+    }
+    if counters > MAX_COUNTERS {
+        return Err(ErrorCode::BadArguments(format!(
+            "The counters of aggregate function {} must not be greater than {}, but got {}",
+            display_name, MAX_COUNTERS, counters
+        )));
+    }
+    Ok((k, counters))
+}
+
+#[cfg(test)]
+mod tests {
+    use bigbytesdb_common_expression::types::AnyType;
+    use bigbytesdb_common_expression::types::DataType;
+    use bigbytesdb_common_expression::types::NumberDataType;
+    use bigbytesdb_common_expression::types::NumberType;
+
+    use super::ApproxTopKData;
+    use super::ApproxTopKState;
+    use crate::aggregates::FunctionData;
+    use crate::aggregates::UnaryState;
+
+    type State = ApproxTopKState<NumberType<u64>>;
+
+    fn data(k: u64, counters: u64) -> ApproxTopKData {
+        ApproxTopKData {
+            k,
+            counters,
+            data_type: DataType::Number(NumberDataType::UInt64),
+        }
+    }
+
+    fn add_all(state: &mut State, values: &[u64], data: &ApproxTopKData) {
+        for v in values {
+            UnaryState::<NumberType<u64>, AnyType>::add(state, *v, Some(data as &dyn FunctionData))
+                .unwrap();
+        }
+    }
+
+    fn top(state: &State) -> Vec<(u64, u64)> {
+        state
+            .sorted_counters()
+            .into_iter()
+            .map(|(k, c)| (*k, c.count))
+            .collect()
+    }
+
+    #[test]
+    fn test_exact_when_not_full() {
+        let data = data(3, 10);
+        let mut state = State::default();
+        add_all(&mut state, &[1, 2, 2, 3, 3, 3], &data);
+        assert_eq!(top(&state), vec![(3, 3), (2, 2), (1, 1)]);
+    }
+
+    #[test]
+    fn test_heavy_hitter_survives_eviction() {
+        let data = data(1, 2);
+        let mut state = State::default();
+        let mut values = vec![];
+        for i in 0..100 {
+            values.push(7);
+            values.push(1000 + i);
+        }
+        add_all(&mut state, &values, &data);
+
+        let result = top(&state);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].0, 7);
+        // Space-Saving never under-estimates.
+        assert!(result[0].1 >= 100);
+        let error = state.counters[&7].error;
+        assert!(result[0].1 - error <= 100);
+    }
+
+    #[test]
+    fn test_merge() {
+        let data = data(2, 3);
+        let mut lhs = State::default();
+        let mut rhs = State::default();
+        add_all(&mut lhs, &[1, 1, 1, 2, 3], &data);
+        add_all(&mut rhs, &[1, 1, 4, 4, 4, 5], &data);
+
+        UnaryState::<NumberType<u64>, AnyType>::merge(&mut lhs, &rhs).unwrap();
+        let result = top(&lhs);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].0, 1);
+        assert_eq!(result[0].1, 5);
+        assert_eq!(result[1].0, 4);
+        assert_eq!(result[1].1, 4);
+    }
+
+    #[test]
+    fn test_evict_after_merge_and_deserialize() {
+        let data = data(2, 2);
+        let mut lhs = State::default();
+        let mut rhs = State::default();
+        add_all(&mut lhs, &[1, 1, 1, 2], &data);
+        add_all(&mut rhs, &[2, 2, 2], &data);
+
+        UnaryState::<NumberType<u64>, AnyType>::merge(&mut lhs, &rhs).unwrap();
+        assert_eq!(top(&lhs), vec![(2, 4), (1, 3)]);
+
+        // Value 1 has the smallest count and is evicted.
+        add_all(&mut lhs, &[3], &data);
+        assert_eq!(top(&lhs), vec![(2, 4), (3, 4)]);
+        assert_eq!(lhs.counters[&3].error, 3);
+
+        let bytes = borsh::to_vec(&lhs).unwrap();
+        let mut state: State = borsh::from_slice(&bytes).unwrap();
+        add_all(&mut state, &[2, 5], &data);
+        assert_eq!(top(&state), vec![(2, 5), (5, 5)]);
+        assert_eq!(state.counters[&5].error, 4);
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::BitAnd;
+use std::ops::BitOr;
+use std::ops::BitXor;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::types::number::Number;
+use bigbytesdb_common_expression::types::BooleanType;
+use bigbytesdb_common_expression::types::DataType;
+use bigbytesdb_common_expression::types::MutableBitmap;
+use bigbytesdb_common_expression::types::NumberDataType;
+use bigbytesdb_common_expression::types::NumberType;
+use bigbytesdb_common_expression::with_integer_mapped_type;
+use bigbytesdb_common_expression::AggregateFunctionRef;
+use bigbytesdb_common_expression::Scalar;
+
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::AggregateUnaryFunction;
+use super::FunctionData;
+use super::UnaryState;
+use crate::aggregates::assert_unary_arguments;
+
+const BIT_AND: u8 = 0;
+const BIT_OR: u8 = 1;
+const BIT_XOR: u8 = 2;
+
+/// State of `bool_and`(`IS_AND = true`) and `bool_or`(`IS_AND = false`).
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct BoolAggState<const IS_AND: bool> {
+    value: bool,
+}
+
+impl<const IS_AND: bool> Default for BoolAggState<IS_AND> {
+    fn default() -> Self {
+        // The identity element of the operation, so an empty state never changes the result.
+        BoolAggState { value: IS_AND }
+    }
+}
+
+impl<const IS_AND: bool> UnaryState<BooleanType, BooleanType> for BoolAggState<IS_AND> {
+    fn add(&mut self, other: bool, _function_data: Option<&dyn FunctionData>) -> Result<()> {
+        if IS_AND {
+            self.value &= other;
+        } else {
+            self.value |= other;
+        }
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.add(rhs.value, None)
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut MutableBitmap,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        builder.push(self.value);
+        Ok(())
+    }
+}
+
+/// State of `bit_and`, `bit_or` and `bit_xor`, `OP` is one of `BIT_AND`, `BIT_OR` and `BIT_XOR`.
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct BitwiseAggState<N, const OP: u8>
+where N: Number + BorshSerialize + BorshDeserialize
+{
+    value: Option<N>,
+}
+
+impl<N, const OP: u8> Default for BitwiseAggState<N, OP>
+where N: Number + BorshSerialize + BorshDeserialize
+{
+    fn default() -> Self {
+        BitwiseAggState { value: None }
+    }
+}
+
+impl<N, const OP: u8> UnaryState<NumberType<N>, NumberType<N>> for BitwiseAggState<N, OP>
+where N: Number
+        + BorshSerialize
+        + BorshDeserialize
+        + BitAnd<Output = N>
+        + BitOr<Output = N>
+        + BitXor<Output = N>
+{
+    fn add(&mut self, other: N, _function_data: Option<&dyn FunctionData>) -> Result<()> {
+        self.value = Some(match self.value {
+            None => other,
+            Some(value) => match OP {
+                BIT_AND => value & other,
+                BIT_OR => value | other,
+                _ => value ^ other,
+            },
+        });
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        if let Some(value) = rhs.value {
+            self.add(value, None)?;
+        }
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut Vec<N>,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        builder.push(self.value.unwrap_or_default());
+        Ok(())
+    }
+}
+
+pub fn try_create_aggregate_bool_function<const IS_AND: bool>(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    match &arguments[0] {
+        DataType::Boolean => AggregateUnaryFunction::<
+            BoolAggState<IS_AND>,
+            BooleanType,
+            BooleanType,
+        >::try_create_unary(
+            display_name, DataType::Boolean, params, arguments[0].clone()
+        ),
+        other => Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, other
+        ))),
+    }
+}
+
+pub fn try_create_aggregate_bitwise_function<const OP: u8>(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    with_integer_mapped_type!(|NUM| match &arguments[0] {
+        DataType::Number(NumberDataType::NUM) => AggregateUnaryFunction::<
+            BitwiseAggState<NUM, OP>,
+            NumberType<NUM>,
+            NumberType<NUM>,
+        >::try_create_unary(
+            display_name,
+            arguments[0].clone(),
+            params,
+            arguments[0].clone(),
+        ),
+        other => Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, other
+        ))),
+    })
+}
+
+pub fn aggregate_bool_and_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_bool_function::<true>))
+}
+
+pub fn aggregate_bool_or_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_bool_function::<false>))
+}
+
+pub fn aggregate_bit_and_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_bitwise_function::<BIT_AND>,
+    ))
+}
+
+pub fn aggregate_bit_or_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_bitwise_function::<BIT_OR>))
+}
+
+pub fn aggregate_bit_xor_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_bitwise_function::<BIT_XOR>,
+    ))
+}
@@ -27,7 +27,7 @@ use crate::aggregates::AggregateFunctionRef;
 use crate::aggregates::Aggregators;
 
 // The NULL value in the those function needs to be handled separately.
-const NEED_NULL_AGGREGATE_FUNCTIONS: [&str; 7] = [
+const NEED_NULL_AGGREGATE_FUNCTIONS: [&str; 8] = [
     "array_agg",
     "list",
     "json_array_agg",
@@ -35,6 +35,7 @@ const NEED_NULL_AGGREGATE_FUNCTIONS: [&str; 7] = [
     "group_array_moving_avg",
     "group_array_moving_sum",
     "st_collect",
+    "st_union_agg",
 ];
 
 const STATE_SUFFIX: &str = "_state";
@@ -0,0 +1,437 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mergeable distinct-count sketches exposed as `Binary` values.
+//!
+//! `hll_sketch`/`theta_sketch` build a sketch from raw values, `hll_merge`/`theta_merge`
+//! union already built sketches, so that per-day sketches can be stored in a table and
+//! combined later into weekly or monthly distinct counts.
+
+use std::collections::BTreeSet;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::types::binary::BinaryColumnBuilder;
+use bigbytesdb_common_expression::types::AnyType;
+use bigbytesdb_common_expression::types::BinaryType;
+use bigbytesdb_common_expression::types::DataType;
+use bigbytesdb_common_expression::types::NumberDataType;
+use bigbytesdb_common_expression::types::NumberType;
+use bigbytesdb_common_expression::types::StringType;
+use bigbytesdb_common_expression::types::ValueType;
+use bigbytesdb_common_expression::with_number_mapped_type;
+use bigbytesdb_common_expression::AggregateFunctionRef;
+use bigbytesdb_common_expression::Scalar;
+use simple_hll::HyperLogLog;
+use siphasher::sip::SipHasher24;
+
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::AggregateUnaryFunction;
+use super::FunctionData;
+use super::UnaryState;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+
+/// Same precision as the default of `approx_count_distinct`, so both return the same estimate.
+pub const HLL_SKETCH_P: usize = 14;
+
+/// Default number of hashes retained by a theta sketch, relative error is about `1 / sqrt(k)`.
+pub const THETA_SKETCH_NOMINAL_ENTRIES: u32 = 4096;
+
+const HLL_SKETCH_TAG: u8 = 1;
+const THETA_SKETCH_TAG: u8 = 2;
+
+fn check_sketch_tag<'a>(buf: &'a [u8], tag: u8, name: &str) -> Result<&'a [u8]> {
+    match buf.split_first() {
+        Some((t, body)) if *t == tag => Ok(body),
+        _ => Err(ErrorCode::BadBytes(format!(
+            "fail to decode {name} from buffer of size {}: not a {name}",
+            buf.len()
+        ))),
+    }
+}
+
+/// A mergeable distinct-count sketch with a self-describing binary encoding.
+pub trait Sketch: Default + BorshSerialize + BorshDeserialize + Send + Sync + 'static {
+    fn add_object<T: Hash>(&mut self, obj: &T);
+
+    fn union(&mut self, other: &Self);
+
+    fn estimate(&self) -> u64;
+
+    fn encode(&self, buf: &mut Vec<u8>) -> Result<()>;
+
+    /// An empty buffer is decoded as an empty sketch.
+    fn decode(buf: &[u8]) -> Result<Self>;
+}
+
+/// HyperLogLog sketch, serialized as a tag byte followed by the borsh encoded registers.
+#[derive(Default, BorshSerialize, BorshDeserialize)]
+pub struct HllSketch {
+    hll: HyperLogLog<HLL_SKETCH_P>,
+}
+
+impl Sketch for HllSketch {
+    fn add_object<T: Hash>(&mut self, obj: &T) {
+        self.hll.add_object(obj);
+    }
+
+    fn union(&mut self, other: &HllSketch) {
+        self.hll.merge(&other.hll);
+    }
+
+    fn estimate(&self) -> u64 {
+        self.hll.count() as u64
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) -> Result<()> {
+        buf.push(HLL_SKETCH_TAG);
+        Ok(borsh::to_writer(buf, self)?)
+    }
+
+    fn decode(buf: &[u8]) -> Result<HllSketch> {
+        if buf.is_empty() {
+            return Ok(HllSketch::default());
+        }
+        let mut body = check_sketch_tag(buf, HLL_SKETCH_TAG, "hll sketch")?;
+        HllSketch::deserialize_reader(&mut body).map_err(|e| {
+            ErrorCode::BadBytes(format!(
+                "fail to decode hll sketch from buffer of size {}: {e}",
+                buf.len()
+            ))
+        })
+    }
+}
+
+/// Theta sketch (K minimum values) over 64-bit hashes.
+///
+/// Only hashes smaller than `theta` are retained, and at most `nominal_entries` of them.
+/// Unlike HyperLogLog, theta sketches support intersection and difference in addition
+/// to union.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ThetaSketch {
+    nominal_entries: u32,
+    theta: u64,
+    hashes: BTreeSet<u64>,
+}
+
+impl Default for ThetaSketch {
+    fn default() -> Self {
+        ThetaSketch::with_nominal_entries(THETA_SKETCH_NOMINAL_ENTRIES)
+    }
+}
+
+impl ThetaSketch {
+    pub fn with_nominal_entries(nominal_entries: u32) -> Self {
+        ThetaSketch {
+            nominal_entries,
+            theta: u64::MAX,
+            hashes: BTreeSet::new(),
+        }
+    }
+
+    fn add_hash(&mut self, hash: u64) {
+        if hash >= self.theta {
+            return;
+        }
+        self.hashes.insert(hash);
+        self.trim();
+    }
+
+    fn trim(&mut self) {
+        while self.hashes.len() > self.nominal_entries as usize {
+            // The evicted hash becomes the new exclusive upper bound.
+            self.theta = self.hashes.pop_last().unwrap();
+        }
+    }
+
+    fn retained_below(hashes: impl Iterator<Item = u64>, theta: u64) -> BTreeSet<u64> {
+        hashes.filter(|h| *h < theta).collect()
+    }
+
+    pub fn intersect(&self, other: &ThetaSketch) -> ThetaSketch {
+        let theta = self.theta.min(other.theta);
+        ThetaSketch {
+            nominal_entries: self.nominal_entries.max(other.nominal_entries),
+            theta,
+            hashes: Self::retained_below(self.hashes.intersection(&other.hashes).copied(), theta),
+        }
+    }
+
+    pub fn a_not_b(&self, other: &ThetaSketch) -> ThetaSketch {
+        let theta = self.theta.min(other.theta);
+        ThetaSketch {
+            nominal_entries: self.nominal_entries,
+            theta,
+            hashes: Self::retained_below(self.hashes.difference(&other.hashes).copied(), theta),
+        }
+    }
+}
+
+impl Sketch for ThetaSketch {
+    fn add_object<T: Hash>(&mut self, obj: &T) {
+        let mut hasher = SipHasher24::new();
+        obj.hash(&mut hasher);
+        self.add_hash(hasher.finish());
+    }
+
+    fn union(&mut self, other: &ThetaSketch) {
+        self.theta = self.theta.min(other.theta);
+        self.nominal_entries = self.nominal_entries.max(other.nominal_entries);
+        let hashes = std::mem::take(&mut self.hashes);
+        self.hashes = Self::retained_below(
+            hashes.into_iter().chain(other.hashes.iter().copied()),
+            self.theta,
+        );
+        self.trim();
+    }
+
+    fn estimate(&self) -> u64 {
+        if self.theta == u64::MAX {
+            return self.hashes.len() as u64;
+        }
+        let fraction = self.theta as f64 / u64::MAX as f64;
+        (self.hashes.len() as f64 / fraction).round() as u64
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) -> Result<()> {
+        buf.push(THETA_SKETCH_TAG);
+        Ok(borsh::to_writer(buf, self)?)
+    }
+
+    fn decode(buf: &[u8]) -> Result<ThetaSketch> {
+        if buf.is_empty() {
+            return Ok(ThetaSketch::default());
+        }
+        let mut body = check_sketch_tag(buf, THETA_SKETCH_TAG, "theta sketch")?;
+        ThetaSketch::deserialize_reader(&mut body).map_err(|e| {
+            ErrorCode::BadBytes(format!(
+                "fail to decode theta sketch from buffer of size {}: {e}",
+                buf.len()
+            ))
+        })
+    }
+}
+
+/// State of `hll_sketch`/`theta_sketch`, the input values are added to the sketch.
+#[derive(Default, BorshSerialize, BorshDeserialize)]
+pub struct SketchState<S: Sketch> {
+    sketch: S,
+}
+
+impl<S, T> UnaryState<T, BinaryType> for SketchState<S>
+where
+    S: Sketch,
+    T: ValueType + Send + Sync,
+    T::Scalar: Hash,
+{
+    fn add(
+        &mut self,
+        other: T::ScalarRef<'_>,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        self.sketch.add_object(&T::to_owned_scalar(other));
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.sketch.union(&rhs.sketch);
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut BinaryColumnBuilder,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        self.sketch.encode(&mut builder.data)?;
+        builder.commit_row();
+        Ok(())
+    }
+}
+
+/// State of `hll_merge`/`theta_merge`, the input values are serialized sketches.
+#[derive(Default, BorshSerialize, BorshDeserialize)]
+pub struct SketchMergeState<S: Sketch> {
+    sketch: S,
+}
+
+impl<S: Sketch> UnaryState<BinaryType, BinaryType> for SketchMergeState<S> {
+    fn add(&mut self, other: &[u8], _function_data: Option<&dyn FunctionData>) -> Result<()> {
+        self.sketch.union(&S::decode(other)?);
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.sketch.union(&rhs.sketch);
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut BinaryColumnBuilder,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        self.sketch.encode(&mut builder.data)?;
+        builder.commit_row();
+        Ok(())
+    }
+}
+
+fn create_sketch_function<S: Sketch>(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let return_type = DataType::Binary;
+    with_number_mapped_type!(|NUM_TYPE| match &arguments[0] {
+        DataType::Number(NumberDataType::NUM_TYPE) => AggregateUnaryFunction::<
+            SketchState<S>,
+            NumberType<NUM_TYPE>,
+            BinaryType,
+        >::try_create_unary(
+            display_name,
+            return_type,
+            params,
+            arguments[0].clone(),
+        ),
+        DataType::String => {
+            AggregateUnaryFunction::<SketchState<S>, StringType, BinaryType>::try_create_unary(
+                display_name,
+                return_type,
+                params,
+                arguments[0].clone(),
+            )
+        }
+        _ => AggregateUnaryFunction::<SketchState<S>, AnyType, BinaryType>::try_create_unary(
+            display_name,
+            return_type,
+            params,
+            arguments[0].clone(),
+        ),
+    })
+}
+
+fn create_merge_function<S: Sketch>(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    if arguments[0] != DataType::Binary {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "{} expects a Binary sketch argument, but got {}",
+            display_name, arguments[0]
+        )));
+    }
+    AggregateUnaryFunction::<SketchMergeState<S>, BinaryType, BinaryType>::try_create_unary(
+        display_name,
+        DataType::Binary,
+        params,
+        arguments[0].clone(),
+    )
+}
+
+pub fn aggregate_hll_sketch_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(create_sketch_function::<HllSketch>))
+}
+
+pub fn aggregate_hll_merge_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(create_merge_function::<HllSketch>))
+}
+
+pub fn aggregate_theta_sketch_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(create_sketch_function::<ThetaSketch>))
+}
+
+pub fn aggregate_theta_merge_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(create_merge_function::<ThetaSketch>))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HllSketch;
+    use super::Sketch;
+    use super::ThetaSketch;
+
+    #[test]
+    fn test_hll_sketch_roundtrip() {
+        let mut sketch = HllSketch::default();
+        for i in 0..1000u64 {
+            sketch.add_object(&i);
+        }
+        let mut buf = vec![];
+        sketch.encode(&mut buf).unwrap();
+        let decoded = HllSketch::decode(&buf).unwrap();
+        assert_eq!(decoded.estimate(), sketch.estimate());
+
+        assert_eq!(HllSketch::decode(&[]).unwrap().estimate(), 0);
+        assert!(HllSketch::decode(&[2, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn test_theta_sketch_exact_below_nominal_entries() {
+        let mut a = ThetaSketch::default();
+        let mut b = ThetaSketch::default();
+        for i in 0..100u64 {
+            a.add_object(&i);
+        }
+        for i in 50..200u64 {
+            b.add_object(&i);
+        }
+        assert_eq!(a.estimate(), 100);
+        assert_eq!(a.intersect(&b).estimate(), 50);
+        assert_eq!(a.a_not_b(&b).estimate(), 50);
+        assert_eq!(b.a_not_b(&a).estimate(), 100);
+
+        let mut u = a.clone();
+        u.union(&b);
+        assert_eq!(u.estimate(), 200);
+
+        let mut buf = vec![];
+        u.encode(&mut buf).unwrap();
+        assert_eq!(ThetaSketch::decode(&buf).unwrap(), u);
+    }
+
+    #[test]
+    fn test_theta_sketch_estimate() {
+        let mut a = ThetaSketch::with_nominal_entries(1024);
+        let mut b = ThetaSketch::with_nominal_entries(1024);
+        for i in 0..100_000u64 {
+            a.add_object(&i);
+        }
+        for i in 50_000..150_000u64 {
+            b.add_object(&i);
+        }
+        assert_eq!(a.hashes.len(), 1024);
+
+        let within = |estimate: u64, expected: u64| {
+            let error = (estimate as f64 - expected as f64).abs() / expected as f64;
+            assert!(error < 0.1, "estimate {estimate}, expected {expected}");
+        };
+        within(a.estimate(), 100_000);
+        within(a.intersect(&b).estimate(), 50_000);
+        within(a.a_not_b(&b).estimate(), 50_000);
+
+        let mut u = a.clone();
+        u.union(&b);
+        within(u.estimate(), 150_000);
+    }
+}
@@ -22,8 +22,10 @@ use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use bigbytesdb_common_exception::ErrorCode;
 use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::types::geography::Geography;
 use bigbytesdb_common_expression::types::Bitmap;
 use bigbytesdb_common_expression::types::DataType;
+use bigbytesdb_common_expression::types::GeographyType;
 use bigbytesdb_common_expression::types::GeometryType;
 use bigbytesdb_common_expression::types::ValueType;
 use bigbytesdb_common_expression::AggrStateRegistry;
@@ -35,6 +37,7 @@ use bigbytesdb_common_expression::Scalar;
 use bigbytesdb_common_expression::ScalarRef;
 use bigbytesdb_common_io::ewkb_to_geo;
 use bigbytesdb_common_io::geo_to_ewkb;
+use bigbytesdb_functions_scalar_geo::constructive::union_all;
 use geo::Geometry;
 use geo::GeometryCollection;
 use geo::LineString;
@@ -191,6 +194,146 @@ where
     }
 }
 
+/// Number of buffered values after which `st_union_agg` unions them into a single
+/// value, so that the state is bounded by the size of the union instead of the rows.
+const ST_UNION_AGG_BUFFER_SIZE: usize = 1024;
+
+/// State of `st_union_agg`, the values are collected like `st_collect`
+/// and unioned together whenever the buffer is full and when the result is produced.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct StUnionAggState<T>
+where
+    T: ValueType,
+    T::Scalar: BorshSerialize + BorshDeserialize,
+{
+    values: StCollectState<T>,
+    /// The error of unioning the buffer in `add`, returned by the next fallible call.
+    #[borsh(skip)]
+    error: Option<ErrorCode>,
+}
+
+impl<T> Default for StUnionAggState<T>
+where
+    T: ValueType,
+    T::Scalar: BorshSerialize + BorshDeserialize,
+{
+    fn default() -> Self {
+        Self {
+            values: StCollectState::default(),
+            error: None,
+        }
+    }
+}
+
+impl<T> StUnionAggState<T>
+where
+    T: ValueType,
+    T::Scalar: BorshSerialize + BorshDeserialize,
+{
+    fn check_error(&mut self) -> Result<()> {
+        match self.error.take() {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    /// Replaces the buffered values with their union once the buffer is full.
+    fn compact(&mut self) -> Result<()> {
+        if self.values.values.len() < ST_UNION_AGG_BUFFER_SIZE {
+            return Ok(());
+        }
+        if let Some(value) = self.union()? {
+            let value = T::try_downcast_scalar(&value.as_ref()).unwrap();
+            self.values.values.push(T::to_owned_scalar(value));
+        }
+        Ok(())
+    }
+
+    /// Takes the buffered values and unions them, returns `None` if there are no values.
+    fn union(&mut self) -> Result<Option<Scalar>> {
+        if self.values.values.is_empty() {
+            return Ok(None);
+        }
+
+        let mut is_geography = false;
+        let mut srid = None;
+        let mut geos = Vec::with_capacity(self.values.values.len());
+        let values = mem::take(&mut self.values.values);
+        for (i, value) in values.into_iter().enumerate() {
+            let val = T::upcast_scalar(value);
+            let v = match &val {
+                Scalar::Geography(v) => {
+                    is_geography = true;
+                    v.0.as_slice()
+                }
+                _ => val.as_geometry().unwrap().as_slice(),
+            };
+            let (geo, geo_srid) = ewkb_to_geo(&mut Ewkb(v))?;
+            if i == 0 {
+                srid = geo_srid;
+            } else if !srid.eq(&geo_srid) {
+                return Err(ErrorCode::GeometryError(format!(
+                    "Incompatible SRID: {} and {}",
+                    srid.unwrap_or_default(),
+                    geo_srid.unwrap_or_default()
+                )));
+            }
+            geos.push(geo);
+        }
+
+        let data = geo_to_ewkb(union_all(geos)?, srid)?;
+        let value = if is_geography {
+            Scalar::Geography(Geography(data))
+        } else {
+            Scalar::Geometry(data)
+        };
+        Ok(Some(value))
+    }
+}
+
+impl<T> ScalarStateFunc<T> for StUnionAggState<T>
+where
+    T: ValueType,
+    T::Scalar: BorshSerialize + BorshDeserialize + Send + Sync,
+{
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn add(&mut self, other: Option<T::ScalarRef<'_>>) {
+        self.values.add(other);
+        if self.error.is_none() {
+            if let Err(e) = self.compact() {
+                self.error = Some(e);
+            }
+        }
+    }
+
+    fn add_batch(&mut self, column: &T::Column, validity: Option<&Bitmap>) -> Result<()> {
+        self.check_error()?;
+        self.values.add_batch(column, validity)?;
+        self.compact()
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.check_error()?;
+        if let Some(e) = &rhs.error {
+            return Err(e.clone());
+        }
+        self.values.merge(&rhs.values)?;
+        self.compact()
+    }
+
+    fn merge_result(&mut self, builder: &mut ColumnBuilder) -> Result<()> {
+        self.check_error()?;
+        match self.union()? {
+            Some(value) => builder.push(value.as_ref()),
+            None => builder.push(ScalarRef::Null),
+        }
+        Ok(())
+    }
+}
+
 #[derive(Clone)]
 pub struct AggregateStCollectFunction<T, State> {
     display_name: String,
@@ -381,3 +524,34 @@ pub fn try_create_aggregate_st_collect_function(
 pub fn aggregate_st_collect_function_desc() -> AggregateFunctionDescription {
     AggregateFunctionDescription::creator(Box::new(try_create_aggregate_st_collect_function))
 }
+
+pub fn try_create_aggregate_st_union_agg_function(
+    display_name: &str,
+    _params: Vec<Scalar>,
+    argument_types: Vec<DataType>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_unary_arguments(display_name, argument_types.len())?;
+    match argument_types[0].remove_nullable() {
+        DataType::Geometry | DataType::Null => {
+            let return_type = DataType::Nullable(Box::new(DataType::Geometry));
+            type State = StUnionAggState<GeometryType>;
+            AggregateStCollectFunction::<GeometryType, State>::try_create(display_name, return_type)
+        }
+        DataType::Geography => {
+            let return_type = DataType::Nullable(Box::new(DataType::Geography));
+            type State = StUnionAggState<GeographyType>;
+            AggregateStCollectFunction::<GeographyType, State>::try_create(
+                display_name,
+                return_type,
+            )
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "The argument of aggregate function {} must be Geometry or Geography",
+            display_name
+        ))),
+    }
+}
+
+pub fn aggregate_st_union_agg_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_st_union_agg_function))
+}
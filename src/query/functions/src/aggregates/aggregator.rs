@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::aggregate_approx_count_distinct::aggregate_approx_count_distinct_function_desc;
+use super::aggregate_approx_top_k::aggregate_approx_top_k_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_max_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_min_function_desc;
 use super::aggregate_avg::aggregate_avg_function_desc;
@@ -41,7 +42,14 @@ use super::AggregateIfCombinator;
 use crate::aggregates::aggregate_array_agg_function_desc;
 use crate::aggregates::aggregate_array_moving_avg_function_desc;
 use crate::aggregates::aggregate_array_moving_sum_function_desc;
+use crate::aggregates::aggregate_bit_and_function_desc;
+use crate::aggregates::aggregate_bit_or_function_desc;
+use crate::aggregates::aggregate_bit_xor_function_desc;
+use crate::aggregates::aggregate_bool_and_function_desc;
+use crate::aggregates::aggregate_bool_or_function_desc;
 use crate::aggregates::aggregate_histogram_function_desc;
+use crate::aggregates::aggregate_hll_merge_function_desc;
+use crate::aggregates::aggregate_hll_sketch_function_desc;
 use crate::aggregates::aggregate_json_array_agg_function_desc;
 use crate::aggregates::aggregate_json_object_agg_function_desc;
 use crate::aggregates::aggregate_kurtosis_function_desc;
@@ -56,8 +64,11 @@ use crate::aggregates::aggregate_range_bound_function_desc;
 use crate::aggregates::aggregate_retention_function_desc;
 use crate::aggregates::aggregate_skewness_function_desc;
 use crate::aggregates::aggregate_st_collect_function_desc;
+use crate::aggregates::aggregate_st_union_agg_function_desc;
 use crate::aggregates::aggregate_string_agg_function_desc;
 use crate::aggregates::aggregate_sum_function_desc;
+use crate::aggregates::aggregate_theta_merge_function_desc;
+use crate::aggregates::aggregate_theta_sketch_function_desc;
 
 pub struct Aggregators;
 
@@ -103,6 +114,11 @@ impl Aggregators {
             "approx_count_distinct",
             aggregate_approx_count_distinct_function_desc(),
         );
+        factory.register("approx_top_k", aggregate_approx_top_k_function_desc());
+        factory.register("hll_sketch", aggregate_hll_sketch_function_desc());
+        factory.register("hll_merge", aggregate_hll_merge_function_desc());
+        factory.register("theta_sketch", aggregate_theta_sketch_function_desc());
+        factory.register("theta_merge", aggregate_theta_merge_function_desc());
         factory.register("retention", aggregate_retention_function_desc());
         factory.register("array_agg", aggregate_array_agg_function_desc());
         factory.register("list", aggregate_array_agg_function_desc());
@@ -147,9 +163,20 @@ impl Aggregators {
 
         factory.register("histogram", aggregate_histogram_function_desc());
 
+        factory.register("bool_and", aggregate_bool_and_function_desc());
+        factory.register("every", aggregate_bool_and_function_desc());
+        factory.register("bool_or", aggregate_bool_or_function_desc());
+        factory.register("bit_and", aggregate_bit_and_function_desc());
+        factory.register("bit_or", aggregate_bit_or_function_desc());
+        factory.register("bit_xor", aggregate_bit_xor_function_desc());
+        factory.register("groupBitAnd", aggregate_bit_and_function_desc());
+        factory.register("groupBitOr", aggregate_bit_or_function_desc());
+        factory.register("groupBitXor", aggregate_bit_xor_function_desc());
+
         factory.register("mode", aggregate_mode_function_desc());
 
         factory.register("st_collect", aggregate_st_collect_function_desc());
+        factory.register("st_union_agg", aggregate_st_union_agg_function_desc());
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
@@ -18,11 +18,13 @@ mod aggregate_function_factory;
 
 mod adaptors;
 mod aggregate_approx_count_distinct;
+mod aggregate_approx_top_k;
 mod aggregate_arg_min_max;
 mod aggregate_array_agg;
 mod aggregate_array_moving;
 mod aggregate_avg;
 mod aggregate_bitmap;
+mod aggregate_bitwise;
 mod aggregate_combinator_distinct;
 mod aggregate_combinator_if;
 mod aggregate_combinator_state;
@@ -43,6 +45,7 @@ mod aggregate_quantile_tdigest_weighted;
 mod aggregate_range_bound;
 mod aggregate_retention;
 mod aggregate_scalar_state;
+mod aggregate_sketch;
 mod aggregate_skewness;
 mod aggregate_st_collect;
 mod aggregate_stddev;
@@ -54,9 +57,11 @@ mod aggregator;
 mod aggregator_common;
 
 pub use adaptors::*;
+pub use aggregate_approx_top_k::*;
 pub use aggregate_arg_min_max::AggregateArgMinMaxFunction;
 pub use aggregate_array_agg::*;
 pub use aggregate_array_moving::*;
+pub use aggregate_bitwise::*;
 pub use aggregate_combinator_distinct::AggregateDistinctCombinator;
 pub use aggregate_combinator_if::AggregateIfCombinator;
 pub use aggregate_count::AggregateCountFunction;
@@ -77,6 +82,7 @@ pub use aggregate_quantile_tdigest::*;
 pub use aggregate_quantile_tdigest_weighted::*;
 pub use aggregate_range_bound::*;
 pub use aggregate_retention::*;
+pub use aggregate_sketch::*;
 pub use aggregate_skewness::*;
 pub use aggregate_st_collect::*;
 pub use aggregate_string_agg::*;
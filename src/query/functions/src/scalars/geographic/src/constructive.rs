@@ -0,0 +1,845 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Constructive operations that build a new geometry from one or two inputs:
+//! overlays (`st_union`, `st_intersection`, `st_difference`, `st_symdifference`),
+//! `st_buffer`, `st_simplify`, `st_centroid` and `st_envelope`.
+//!
+//! Geometry inputs are computed on the plane in the units of their SRID.
+//! Geography inputs are computed on the longitude/latitude plane, except that the
+//! distance of `st_buffer` and the tolerance of `st_simplify` are given in meters
+//! and are applied in a local equirectangular projection around the input.
+
+use std::f64::consts::PI;
+
+use bigbytesdb_common_base::runtime::catch_unwind;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::types::geography::GeographyRef;
+use bigbytesdb_common_expression::types::geometry::GeometryType;
+use bigbytesdb_common_expression::types::GeographyType;
+use bigbytesdb_common_expression::types::NumberType;
+use bigbytesdb_common_expression::types::F64;
+use bigbytesdb_common_expression::vectorize_with_builder_1_arg;
+use bigbytesdb_common_expression::vectorize_with_builder_2_arg;
+use bigbytesdb_common_expression::FunctionDomain;
+use bigbytesdb_common_expression::FunctionRegistry;
+use bigbytesdb_common_io::ewkb_to_geo;
+use bigbytesdb_common_io::geo_to_ewkb;
+use bigbytesdb_common_io::geography::check_point;
+use bigbytesdb_common_io::geometry_type_name;
+use geo::algorithm::line_intersection::line_intersection;
+use geo::algorithm::line_intersection::LineIntersection;
+use geo::BooleanOps;
+use geo::BoundingRect;
+use geo::Centroid;
+use geo::ConvexHull;
+use geo::Coord;
+use geo::CoordsIter;
+use geo::Geometry;
+use geo::GeometryCollection;
+use geo::Intersects;
+use geo::Line;
+use geo::LineString;
+use geo::MapCoords;
+use geo::MultiLineString;
+use geo::MultiPoint;
+use geo::MultiPolygon;
+use geo::Point;
+use geo::Polygon;
+use geo::Simplify;
+use geozero::wkb::Ewkb;
+
+/// Number of segments used to approximate a quarter circle in `st_buffer`.
+const BUFFER_QUAD_SEGMENTS: usize = 8;
+
+/// Mean earth radius in meters, used to convert geography distances.
+const EARTH_RADIUS: f64 = 6_371_008.8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OverlayOp {
+    Union,
+    Intersection,
+    Difference,
+    SymDifference,
+}
+
+impl OverlayOp {
+    fn name(&self) -> &'static str {
+        match self {
+            OverlayOp::Union => "st_union",
+            OverlayOp::Intersection => "st_intersection",
+            OverlayOp::Difference => "st_difference",
+            OverlayOp::SymDifference => "st_symdifference",
+        }
+    }
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // aliases
+    registry.register_aliases("st_symdifference", &["st_sym_difference"]);
+
+    // functions
+    for op in [
+        OverlayOp::Union,
+        OverlayOp::Intersection,
+        OverlayOp::Difference,
+        OverlayOp::SymDifference,
+    ] {
+        register_geometry_overlay(registry, op);
+        register_geography_overlay(registry, op);
+    }
+
+    register_geometry_1_arg(registry, "st_centroid", st_centroid);
+    register_geography_1_arg(registry, "st_centroid", st_centroid);
+    register_geometry_1_arg(registry, "st_envelope", st_envelope);
+    register_geography_1_arg(registry, "st_envelope", st_envelope);
+
+    register_geometry_with_distance(registry, "st_buffer", st_buffer);
+    register_geography_with_distance(registry, "st_buffer", st_buffer);
+    register_geometry_with_distance(registry, "st_simplify", st_simplify);
+    register_geography_with_distance(registry, "st_simplify", st_simplify);
+}
+
+fn register_geometry_overlay(registry: &mut FunctionRegistry, op: OverlayOp) {
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, GeometryType, _, _>(
+        op.name(),
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, GeometryType>(
+            move |l_ewkb, r_ewkb, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.commit_row();
+                        return;
+                    }
+                }
+
+                match eval_overlay(l_ewkb, r_ewkb, op) {
+                    Ok((geo, srid)) => match geo_to_ewkb(geo, srid) {
+                        Ok(data) => builder.put_slice(data.as_slice()),
+                        Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                    },
+                    Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                }
+                builder.commit_row();
+            },
+        ),
+    );
+}
+
+fn register_geography_overlay(registry: &mut FunctionRegistry, op: OverlayOp) {
+    registry
+        .register_passthrough_nullable_2_arg::<GeographyType, GeographyType, GeographyType, _, _>(
+            op.name(),
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<GeographyType, GeographyType, GeographyType>(
+                move |l_geog, r_geog, builder, ctx| {
+                    if let Some(validity) = &ctx.validity {
+                        if !validity.get_bit(builder.len()) {
+                            builder.commit_row();
+                            return;
+                        }
+                    }
+
+                    match eval_overlay(l_geog.0, r_geog.0, op)
+                        .and_then(|(geo, srid)| geography_to_ewkb(geo, srid))
+                    {
+                        Ok(data) => builder.put_slice(data.as_slice()),
+                        Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                    }
+                    builder.commit_row();
+                },
+            ),
+        );
+}
+
+fn register_geometry_1_arg(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    func: fn(Geometry) -> Result<Geometry>,
+) {
+    registry.register_passthrough_nullable_1_arg::<GeometryType, GeometryType, _, _>(
+        name,
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, GeometryType>(move |ewkb, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.commit_row();
+                    return;
+                }
+            }
+
+            match ewkb_to_geo(&mut Ewkb(ewkb)).and_then(|(geo, srid)| geo_to_ewkb(func(geo)?, srid))
+            {
+                Ok(data) => builder.put_slice(data.as_slice()),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+}
+
+fn register_geography_1_arg(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    func: fn(Geometry) -> Result<Geometry>,
+) {
+    registry.register_passthrough_nullable_1_arg::<GeographyType, GeographyType, _, _>(
+        name,
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeographyType, GeographyType>(move |geog, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.commit_row();
+                    return;
+                }
+            }
+
+            match ewkb_to_geo(&mut Ewkb(geog.0))
+                .and_then(|(geo, srid)| geography_to_ewkb(func(geo)?, srid))
+            {
+                Ok(data) => builder.put_slice(data.as_slice()),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+}
+
+fn register_geometry_with_distance(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    func: fn(Geometry, f64) -> Result<Geometry>,
+) {
+    registry
+        .register_passthrough_nullable_2_arg::<GeometryType, NumberType<F64>, GeometryType, _, _>(
+            name,
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<GeometryType, NumberType<F64>, GeometryType>(
+                move |ewkb, distance, builder, ctx| {
+                    if let Some(validity) = &ctx.validity {
+                        if !validity.get_bit(builder.len()) {
+                            builder.commit_row();
+                            return;
+                        }
+                    }
+
+                    match ewkb_to_geo(&mut Ewkb(ewkb))
+                        .and_then(|(geo, srid)| geo_to_ewkb(func(geo, *distance)?, srid))
+                    {
+                        Ok(data) => builder.put_slice(data.as_slice()),
+                        Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                    }
+                    builder.commit_row();
+                },
+            ),
+        );
+}
+
+fn register_geography_with_distance(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    func: fn(Geometry, f64) -> Result<Geometry>,
+) {
+    registry
+        .register_passthrough_nullable_2_arg::<GeographyType, NumberType<F64>, GeographyType, _, _>(
+            name,
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<GeographyType, NumberType<F64>, GeographyType>(
+                move |geog, meters, builder, ctx| {
+                    if let Some(validity) = &ctx.validity {
+                        if !validity.get_bit(builder.len()) {
+                            builder.commit_row();
+                            return;
+                        }
+                    }
+
+                    match eval_geography_in_meters(geog, *meters, func) {
+                        Ok(data) => builder.put_slice(data.as_slice()),
+                        Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                    }
+                    builder.commit_row();
+                },
+            ),
+        );
+}
+
+fn eval_overlay(l_ewkb: &[u8], r_ewkb: &[u8], op: OverlayOp) -> Result<(Geometry, Option<i32>)> {
+    let (l_geo, l_srid) = ewkb_to_geo(&mut Ewkb(l_ewkb))?;
+    let (r_geo, r_srid) = ewkb_to_geo(&mut Ewkb(r_ewkb))?;
+    if l_srid != r_srid {
+        return Err(ErrorCode::GeometryError(format!(
+            "Incompatible SRID: {} and {}",
+            l_srid.unwrap_or_default(),
+            r_srid.unwrap_or_default()
+        )));
+    }
+    Ok((overlay(&l_geo, &r_geo, op)?, l_srid))
+}
+
+/// Runs `func` on a geography whose distance argument is given in meters.
+///
+/// The input is projected to a local equirectangular plane centered on its centroid,
+/// where one unit is one meter, and the result is projected back to longitude/latitude.
+fn eval_geography_in_meters(
+    geog: GeographyRef,
+    meters: f64,
+    func: fn(Geometry, f64) -> Result<Geometry>,
+) -> Result<Vec<u8>> {
+    let (geo, srid) = ewkb_to_geo(&mut Ewkb(geog.0))?;
+    let Some(origin) = geo.centroid() else {
+        return geography_to_ewkb(func(geo, meters)?, srid);
+    };
+    let (lon0, lat0) = (origin.x(), origin.y());
+    let scale = EARTH_RADIUS * PI / 180.0;
+    let cos_lat0 = lat0.to_radians().cos().max(f64::EPSILON);
+
+    let projected = geo.map_coords(|c| Coord {
+        x: (c.x - lon0) * scale * cos_lat0,
+        y: (c.y - lat0) * scale,
+    });
+    let result = func(projected, meters)?.map_coords(|c| Coord {
+        x: c.x / (scale * cos_lat0) + lon0,
+        y: c.y / scale + lat0,
+    });
+    geography_to_ewkb(result, srid)
+}
+
+fn geography_to_ewkb(geo: Geometry, srid: Option<i32>) -> Result<Vec<u8>> {
+    geo.coords_iter().try_for_each(|c| check_point(c.x, c.y))?;
+    geo_to_ewkb(geo, srid)
+}
+
+/// Computes the overlay of two geometries of any type.
+///
+/// The polygonal, linear and puntal parts are overlaid separately, and a part is
+/// dropped if it is covered by a part of a higher dimension of the result,
+/// e.g., the union of a polygon and a line inside it is the polygon.
+/// The result is a `GEOMETRYCOLLECTION` if it has parts of different dimensions.
+pub fn overlay(l_geo: &Geometry, r_geo: &Geometry, op: OverlayOp) -> Result<Geometry> {
+    let l = Parts::from_geometry(l_geo);
+    let r = Parts::from_geometry(r_geo);
+    let polygons = overlay_polygons(&l.polygons, &r.polygons, op)?;
+    let parts = match op {
+        OverlayOp::Union => {
+            let mut parts = Parts::new(vec![], l.lines, polygons);
+            let r_lines = lines_outside(&r.lines, &parts)?;
+            parts.lines = clip_lines(parts.lines, &parts.polygons, true)?;
+            parts.lines.extend(r_lines);
+            let points = dedup_points(l.points.into_iter().chain(r.points));
+            parts.points = points_outside(&points, &parts);
+            parts
+        }
+        OverlayOp::Intersection => {
+            // The overlaps of the lines are taken from the left side only.
+            let mut lines = clip_lines(l.lines.clone(), &r.polygons, false)?;
+            let l_outside = clip_lines(l.lines.clone(), &r.polygons, true)?;
+            lines.extend(line_overlaps(&l_outside, &r.lines));
+            let r_lines = r
+                .lines
+                .iter()
+                .flat_map(|line| subtract_lines(line, &l.lines));
+            lines.extend(clip_lines(r_lines.collect(), &l.polygons, false)?);
+
+            let points = points_inside(&l.points, &r)
+                .into_iter()
+                .chain(points_inside(&r.points, &l))
+                .chain(line_crossings(&l.lines, &r.lines));
+            let mut parts = Parts::new(vec![], lines, polygons);
+            parts.points = points_outside(&dedup_points(points), &parts);
+            parts
+        }
+        OverlayOp::Difference => Parts::new(
+            points_outside(&l.points, &r),
+            lines_outside(&l.lines, &r)?,
+            polygons,
+        ),
+        OverlayOp::SymDifference => {
+            let mut points = points_outside(&l.points, &r);
+            points.extend(points_outside(&r.points, &l));
+            let mut lines = lines_outside(&l.lines, &r)?;
+            lines.extend(lines_outside(&r.lines, &l)?);
+            Parts::new(points, lines, polygons)
+        }
+    };
+    Ok(parts.into_geometry())
+}
+
+/// Unions a set of geometries, merging the polygonal parts pairwise so that
+/// each input takes part in a logarithmic number of overlays.
+pub fn union_all(geos: Vec<Geometry>) -> Result<Geometry> {
+    let mut polygons = Vec::with_capacity(geos.len());
+    let mut lines: Vec<LineString> = Vec::new();
+    let mut points = Vec::new();
+    for geo in geos.iter() {
+        let parts = Parts::from_geometry(geo);
+        if !parts.polygons.0.is_empty() {
+            polygons.push(parts.polygons);
+        }
+        let new_lines = parts
+            .lines
+            .iter()
+            .flat_map(|line| subtract_lines(line, &lines))
+            .collect::<Vec<_>>();
+        lines.extend(new_lines);
+        points.extend(parts.points);
+    }
+
+    while polygons.len() > 1 {
+        let mut merged = Vec::with_capacity(polygons.len().div_ceil(2));
+        let mut iter = polygons.into_iter();
+        while let Some(lhs) = iter.next() {
+            match iter.next() {
+                Some(rhs) => merged.push(boolean_op("st_union", || lhs.union(&rhs))?),
+                None => merged.push(lhs),
+            }
+        }
+        polygons = merged;
+    }
+
+    let polygons = polygons.pop().unwrap_or_else(|| MultiPolygon::new(vec![]));
+    let lines = clip_lines(lines, &polygons, true)?;
+    let mut parts = Parts::new(vec![], lines, polygons);
+    parts.points = points_outside(&dedup_points(points), &parts);
+    Ok(parts.into_geometry())
+}
+
+/// The puntal, linear and polygonal parts of a geometry.
+struct Parts {
+    points: Vec<Point>,
+    lines: Vec<LineString>,
+    polygons: MultiPolygon,
+}
+
+impl Parts {
+    fn new(points: Vec<Point>, lines: Vec<LineString>, polygons: MultiPolygon) -> Parts {
+        Parts {
+            points,
+            lines,
+            polygons,
+        }
+    }
+
+    fn from_geometry(geo: &Geometry) -> Parts {
+        let mut parts = Parts::new(vec![], vec![], MultiPolygon::new(vec![]));
+        parts.add(geo);
+        parts
+    }
+
+    fn add(&mut self, geo: &Geometry) {
+        match geo {
+            Geometry::Point(point) => self.points.push(*point),
+            Geometry::MultiPoint(multi_point) => self.points.extend(multi_point.iter()),
+            Geometry::Line(line) => self.lines.push(LineString::from(*line)),
+            Geometry::LineString(line_string) => self.lines.push(line_string.clone()),
+            Geometry::MultiLineString(multi_line_string) => {
+                self.lines.extend(multi_line_string.iter().cloned())
+            }
+            Geometry::Polygon(polygon) => self.polygons.0.push(polygon.clone()),
+            Geometry::MultiPolygon(multi_polygon) => {
+                self.polygons.0.extend(multi_polygon.iter().cloned())
+            }
+            Geometry::Rect(rect) => self.polygons.0.push(rect.to_polygon()),
+            Geometry::Triangle(triangle) => self.polygons.0.push(triangle.to_polygon()),
+            Geometry::GeometryCollection(collection) => {
+                for geo in collection.iter() {
+                    self.add(geo);
+                }
+            }
+        }
+    }
+
+    fn intersects(&self, point: &Point) -> bool {
+        self.points.contains(point)
+            || self.lines.iter().any(|line| line.intersects(point))
+            || self.polygons.intersects(point)
+    }
+
+    fn into_geometry(self) -> Geometry {
+        let mut geos = Vec::with_capacity(3);
+        if !self.polygons.0.is_empty() {
+            geos.push(from_multi_polygon(self.polygons));
+        }
+        if !self.lines.is_empty() {
+            geos.push(from_multi_line_string(MultiLineString::new(self.lines)));
+        }
+        match self.points.as_slice() {
+            [] => {}
+            [point] => geos.push(Geometry::Point(*point)),
+            _ => geos.push(Geometry::MultiPoint(MultiPoint::new(self.points))),
+        }
+        match geos.len() {
+            0 => empty_geometry(),
+            1 => geos.pop().unwrap(),
+            _ => Geometry::GeometryCollection(GeometryCollection::new_from(geos)),
+        }
+    }
+}
+
+fn overlay_polygons(l: &MultiPolygon, r: &MultiPolygon, op: OverlayOp) -> Result<MultiPolygon> {
+    match (op, l.0.is_empty(), r.0.is_empty()) {
+        (OverlayOp::Intersection, true, _) | (OverlayOp::Intersection, _, true) => {
+            Ok(MultiPolygon::new(vec![]))
+        }
+        (OverlayOp::Difference, true, _) => Ok(MultiPolygon::new(vec![])),
+        (_, _, true) => Ok(l.clone()),
+        (_, true, _) => Ok(r.clone()),
+        _ => boolean_op(op.name(), || match op {
+            OverlayOp::Union => l.union(r),
+            OverlayOp::Intersection => l.intersection(r),
+            OverlayOp::Difference => l.difference(r),
+            OverlayOp::SymDifference => l.xor(r),
+        }),
+    }
+}
+
+/// Keeps the parts of the lines that lie inside (or outside if `invert` is true) of `polygons`.
+fn clip_lines(
+    lines: Vec<LineString>,
+    polygons: &MultiPolygon,
+    invert: bool,
+) -> Result<Vec<LineString>> {
+    if lines.is_empty() || polygons.0.is_empty() {
+        return Ok(if invert { lines } else { vec![] });
+    }
+    let lines = MultiLineString::new(lines);
+    Ok(boolean_op("clip", || polygons.clip(&lines, invert))?.0)
+}
+
+/// Returns the parts of the lines that do not intersect `other`.
+fn lines_outside(lines: &[LineString], other: &Parts) -> Result<Vec<LineString>> {
+    let lines = clip_lines(lines.to_vec(), &other.polygons, true)?;
+    Ok(lines
+        .iter()
+        .flat_map(|line| subtract_lines(line, &other.lines))
+        .collect())
+}
+
+/// Returns the parts of `line_string` that do not overlap any of `others`.
+fn subtract_lines(line_string: &LineString, others: &[LineString]) -> Vec<LineString> {
+    let mut result = Vec::new();
+    let mut coords: Vec<Coord> = Vec::new();
+    for line in line_string.lines() {
+        for piece in subtract_overlaps(line, others) {
+            if coords.last() != Some(&piece.start) {
+                if coords.len() > 1 {
+                    result.push(LineString::new(std::mem::take(&mut coords)));
+                }
+                coords = vec![piece.start];
+            }
+            coords.push(piece.end);
+        }
+    }
+    if coords.len() > 1 {
+        result.push(LineString::new(coords));
+    }
+    result
+}
+
+/// Returns the pieces of `line` that do not overlap any segment of `others`.
+fn subtract_overlaps(line: Line, others: &[LineString]) -> Vec<Line> {
+    let delta = line.delta();
+    let length2 = delta.x * delta.x + delta.y * delta.y;
+    if length2 == 0.0 {
+        return vec![];
+    }
+    let position =
+        |c: Coord| ((c.x - line.start.x) * delta.x + (c.y - line.start.y) * delta.y) / length2;
+    let point_at = |t: f64| match t {
+        t if t <= 0.0 => line.start,
+        t if t >= 1.0 => line.end,
+        t => line.start + delta * t,
+    };
+
+    let mut overlaps = others
+        .iter()
+        .flat_map(|other| other.lines())
+        .filter_map(|other| match line_intersection(line, other) {
+            Some(LineIntersection::Collinear { intersection }) => {
+                let (start, end) = (position(intersection.start), position(intersection.end));
+                Some((start.min(end), start.max(end)))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    overlaps.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut pieces = Vec::new();
+    let mut covered = 0.0;
+    for (start, end) in overlaps {
+        if start > covered {
+            pieces.push(Line::new(point_at(covered), point_at(start)));
+        }
+        covered = f64::max(covered, end);
+    }
+    if covered < 1.0 {
+        pieces.push(Line::new(point_at(covered), point_at(1.0)));
+    }
+    pieces
+}
+
+/// Returns the segments shared by the lines of both sides.
+fn line_overlaps(lines: &[LineString], others: &[LineString]) -> Vec<LineString> {
+    let mut overlaps = Vec::new();
+    for line in lines.iter().flat_map(|line| line.lines()) {
+        for other in others.iter().flat_map(|other| other.lines()) {
+            if let Some(LineIntersection::Collinear { intersection }) =
+                line_intersection(line, other)
+            {
+                overlaps.push(LineString::from(intersection));
+            }
+        }
+    }
+    overlaps
+}
+
+/// Returns the points where the lines of both sides cross or touch.
+fn line_crossings(lines: &[LineString], others: &[LineString]) -> Vec<Point> {
+    let mut crossings = Vec::new();
+    for line in lines.iter().flat_map(|line| line.lines()) {
+        for other in others.iter().flat_map(|other| other.lines()) {
+            if let Some(LineIntersection::SinglePoint { intersection, .. }) =
+                line_intersection(line, other)
+            {
+                crossings.push(Point::from(intersection));
+            }
+        }
+    }
+    crossings
+}
+
+fn points_inside(points: &[Point], other: &Parts) -> Vec<Point> {
+    points
+        .iter()
+        .filter(|point| other.intersects(*point))
+        .cloned()
+        .collect()
+}
+
+fn points_outside(points: &[Point], other: &Parts) -> Vec<Point> {
+    points
+        .iter()
+        .filter(|point| !other.intersects(*point))
+        .cloned()
+        .collect()
+}
+
+fn dedup_points(points: impl IntoIterator<Item = Point>) -> Vec<Point> {
+    let mut result: Vec<Point> = Vec::new();
+    for point in points {
+        if !result.contains(&point) {
+            result.push(point);
+        }
+    }
+    result
+}
+
+/// Runs a boolean operation of `geo`, which may panic on degenerate inputs
+/// such as self-intersecting or collapsed rings, and reports the panic as an error.
+fn boolean_op<T>(name: &str, f: impl FnOnce() -> T) -> Result<T> {
+    catch_unwind(f).map_err(|e| {
+        ErrorCode::GeometryError(format!(
+            "{} failed on invalid or degenerate geometry: {}",
+            name,
+            e.message()
+        ))
+    })
+}
+
+fn to_multi_polygon(geo: &Geometry) -> Option<MultiPolygon> {
+    match geo {
+        Geometry::Polygon(polygon) => Some(MultiPolygon::new(vec![polygon.clone()])),
+        Geometry::MultiPolygon(multi_polygon) => Some(multi_polygon.clone()),
+        Geometry::Rect(rect) => Some(MultiPolygon::new(vec![rect.to_polygon()])),
+        Geometry::Triangle(triangle) => Some(MultiPolygon::new(vec![triangle.to_polygon()])),
+        Geometry::GeometryCollection(collection) if !collection.0.is_empty() => {
+            let mut polygons = Vec::new();
+            for geo in collection.iter() {
+                polygons.extend(to_multi_polygon(geo)?);
+            }
+            Some(MultiPolygon::new(polygons))
+        }
+        _ => None,
+    }
+}
+
+fn from_multi_polygon(mut multi_polygon: MultiPolygon) -> Geometry {
+    match multi_polygon.0.len() {
+        0 => empty_geometry(),
+        1 => Geometry::Polygon(multi_polygon.0.pop().unwrap()),
+        _ => Geometry::MultiPolygon(multi_polygon),
+    }
+}
+
+fn from_multi_line_string(mut multi_line_string: MultiLineString) -> Geometry {
+    match multi_line_string.0.len() {
+        0 => empty_geometry(),
+        1 => Geometry::LineString(multi_line_string.0.pop().unwrap()),
+        _ => Geometry::MultiLineString(multi_line_string),
+    }
+}
+
+fn empty_geometry() -> Geometry {
+    Geometry::GeometryCollection(GeometryCollection::new_from(vec![]))
+}
+
+/// Returns the geometric center of mass, or an empty geometry for an empty input.
+fn st_centroid(geo: Geometry) -> Result<Geometry> {
+    Ok(geo.centroid().map_or_else(empty_geometry, Geometry::Point))
+}
+
+/// Returns the minimum bounding box. Degenerate boxes collapse to a point or a line.
+fn st_envelope(geo: Geometry) -> Result<Geometry> {
+    let Some(rect) = geo.bounding_rect() else {
+        return Ok(empty_geometry());
+    };
+    let (min, max) = (rect.min(), rect.max());
+    let envelope = if min == max {
+        Geometry::Point(Point::from(min))
+    } else if min.x == max.x || min.y == max.y {
+        Geometry::LineString(LineString::from(vec![min, max]))
+    } else {
+        Geometry::Polygon(rect.to_polygon())
+    };
+    Ok(envelope)
+}
+
+/// Simplifies lines and polygons with the Ramer–Douglas–Peucker algorithm.
+fn st_simplify(geo: Geometry, tolerance: f64) -> Result<Geometry> {
+    if !tolerance.is_finite() || tolerance < 0.0 {
+        return Err(ErrorCode::GeometryError(format!(
+            "st_simplify tolerance must be a non-negative number, but got {}",
+            tolerance
+        )));
+    }
+    let geo = match geo {
+        Geometry::Line(line) => Geometry::Line(line),
+        Geometry::LineString(line_string) => Geometry::LineString(line_string.simplify(&tolerance)),
+        Geometry::MultiLineString(multi_line_string) => {
+            Geometry::MultiLineString(multi_line_string.simplify(&tolerance))
+        }
+        Geometry::Polygon(polygon) => Geometry::Polygon(polygon.simplify(&tolerance)),
+        Geometry::MultiPolygon(multi_polygon) => {
+            Geometry::MultiPolygon(multi_polygon.simplify(&tolerance))
+        }
+        Geometry::GeometryCollection(collection) => Geometry::GeometryCollection(
+            collection
+                .into_iter()
+                .map(|geo| st_simplify(geo, tolerance))
+                .collect::<Result<_>>()?,
+        ),
+        other => other,
+    };
+    Ok(geo)
+}
+
+/// Returns the area within `distance` of the geometry. The round parts are
+/// approximated with `BUFFER_QUAD_SEGMENTS` segments per quarter circle.
+///
+/// The buffer is the union of the polygonal input (if any) with a capsule around
+/// every segment and a disc around every point. A negative distance shrinks a
+/// polygonal input by subtracting the capsules around its rings instead.
+fn st_buffer(geo: Geometry, distance: f64) -> Result<Geometry> {
+    if !distance.is_finite() {
+        return Err(ErrorCode::GeometryError(format!(
+            "st_buffer distance must be a finite number, but got {}",
+            distance
+        )));
+    }
+    let polygons = to_multi_polygon(&geo);
+    if distance == 0.0 || (distance < 0.0 && polygons.is_none()) {
+        return Ok(polygons.map_or_else(empty_geometry, from_multi_polygon));
+    }
+
+    let radius = distance.abs();
+    let mut pieces = Vec::new();
+    for line_string in boundary_lines(&geo) {
+        match line_string.0.as_slice() {
+            [] => {}
+            [coord] => pieces.push(disc(*coord, radius)),
+            coords => {
+                for pair in coords.windows(2) {
+                    pieces.push(capsule(pair[0], pair[1], radius));
+                }
+            }
+        }
+    }
+    let rings = match union_all(pieces.into_iter().map(Geometry::Polygon).collect())? {
+        Geometry::Polygon(polygon) => MultiPolygon::new(vec![polygon]),
+        Geometry::MultiPolygon(multi_polygon) => multi_polygon,
+        _ => MultiPolygon::new(vec![]),
+    };
+
+    let result = match polygons {
+        Some(polygons) if distance > 0.0 => boolean_op("st_buffer", || polygons.union(&rings))?,
+        Some(polygons) => boolean_op("st_buffer", || polygons.difference(&rings))?,
+        None => rings,
+    };
+    Ok(from_multi_polygon(result))
+}
+
+/// Collects every linear part of a geometry, including the rings of polygons.
+/// A point is returned as a line string of a single coordinate.
+fn boundary_lines(geo: &Geometry) -> Vec<LineString> {
+    match geo {
+        Geometry::Point(point) => vec![LineString::new(vec![point.0])],
+        Geometry::MultiPoint(multi_point) => multi_point
+            .iter()
+            .map(|point| LineString::new(vec![point.0]))
+            .collect(),
+        Geometry::Line(line) => vec![LineString::from(*line)],
+        Geometry::LineString(line_string) => vec![line_string.clone()],
+        Geometry::MultiLineString(multi_line_string) => multi_line_string.0.clone(),
+        Geometry::Polygon(polygon) => polygon_rings(polygon),
+        Geometry::MultiPolygon(multi_polygon) => {
+            multi_polygon.iter().flat_map(polygon_rings).collect()
+        }
+        Geometry::Rect(rect) => polygon_rings(&rect.to_polygon()),
+        Geometry::Triangle(triangle) => polygon_rings(&triangle.to_polygon()),
+        Geometry::GeometryCollection(collection) => {
+            collection.iter().flat_map(boundary_lines).collect()
+        }
+    }
+}
+
+fn polygon_rings(polygon: &Polygon) -> Vec<LineString> {
+    let mut rings = vec![polygon.exterior().clone()];
+    rings.extend(polygon.interiors().iter().cloned());
+    rings
+}
+
+fn circle_coords(center: Coord, radius: f64) -> Vec<Coord> {
+    let segments = BUFFER_QUAD_SEGMENTS * 4;
+    (0..segments)
+        .map(|i| {
+            let angle = 2.0 * PI * i as f64 / segments as f64;
+            Coord {
+                x: center.x + radius * angle.cos(),
+                y: center.y + radius * angle.sin(),
+            }
+        })
+        .collect()
+}
+
+fn disc(center: Coord, radius: f64) -> Polygon {
+    let mut coords = circle_coords(center, radius);
+    coords.push(coords[0]);
+    Polygon::new(LineString::new(coords), vec![])
+}
+
+fn capsule(start: Coord, end: Coord, radius: f64) -> Polygon {
+    let mut coords = circle_coords(start, radius);
+    coords.extend(circle_coords(end, radius));
+    MultiPoint::from(coords).convex_hull()
+}
@@ -443,6 +443,39 @@ pub fn register(registry: &mut FunctionRegistry) {
             ),
         );
 
+    registry.register_passthrough_nullable_3_arg::<GeometryType, GeometryType, NumberType<F64>, BooleanType, _, _>(
+        "st_dwithin",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<GeometryType, GeometryType, NumberType<F64>, BooleanType>(
+            |l_ewkb, r_ewkb, distance, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.push(false);
+                        return;
+                    }
+                }
+
+                match (
+                    ewkb_to_geo(&mut Ewkb(l_ewkb)),
+                    ewkb_to_geo(&mut Ewkb(r_ewkb)),
+                ) {
+                    (Ok((l_geo, l_srid)), Ok((r_geo, r_srid))) => {
+                        if !check_incompatible_srid(l_srid, r_srid, builder.len(), ctx) {
+                            builder.push(false);
+                            return;
+                        }
+                        let is_dwithin = l_geo.euclidean_distance(&r_geo) <= *distance;
+                        builder.push(is_dwithin);
+                    }
+                    (Err(e), _) | (_, Err(e)) => {
+                        ctx.set_error(builder.len(), e.to_string());
+                        builder.push(false);
+                    }
+                }
+            },
+        ),
+    );
+
     registry.register_passthrough_nullable_1_arg::<GeometryType, NumberType<F64>, _, _>(
         "st_area",
         |_, _| FunctionDomain::MayThrow,
@@ -24,6 +24,7 @@
 #![feature(downcast_unchecked)]
 #![feature(str_internals)]
 
+pub mod constructive;
 pub mod geo;
 pub mod geo_h3;
 pub mod geography;
@@ -38,6 +38,7 @@ pub mod dt_func {
 mod map;
 
 mod other;
+mod sketch;
 mod string;
 mod string_multi_args;
 mod tuple;
@@ -76,8 +77,10 @@ pub fn register(registry: &mut FunctionRegistry) {
     bigbytesdb_functions_scalar_decimal::register_to_decimal(registry);
     vector::register(registry);
     bitmap::register(registry);
+    sketch::register(registry);
     geo_func::geometry::register(registry);
     geo_func::geography::register(registry);
+    geo_func::constructive::register(registry);
     hilbert::register(registry);
     dt_func::interval::register(registry);
 }
@@ -0,0 +1,99 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::types::binary::BinaryColumnBuilder;
+use bigbytesdb_common_expression::types::BinaryType;
+use bigbytesdb_common_expression::types::UInt64Type;
+use bigbytesdb_common_expression::vectorize_with_builder_1_arg;
+use bigbytesdb_common_expression::vectorize_with_builder_2_arg;
+use bigbytesdb_common_expression::FunctionDomain;
+use bigbytesdb_common_expression::FunctionRegistry;
+
+use crate::aggregates::HllSketch;
+use crate::aggregates::Sketch;
+use crate::aggregates::ThetaSketch;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    register_estimate::<HllSketch>(registry, "hll_estimate");
+    register_estimate::<ThetaSketch>(registry, "theta_estimate");
+
+    register_set_operation::<HllSketch>(registry, "hll_union", |mut a, b| {
+        a.union(&b);
+        a
+    });
+    register_set_operation::<ThetaSketch>(registry, "theta_union", |mut a, b| {
+        a.union(&b);
+        a
+    });
+    register_set_operation::<ThetaSketch>(registry, "theta_intersect", |a, b| a.intersect(&b));
+    register_set_operation::<ThetaSketch>(registry, "theta_a_not_b", |a, b| a.a_not_b(&b));
+}
+
+fn register_estimate<S: Sketch>(registry: &mut FunctionRegistry, name: &str) {
+    registry.register_passthrough_nullable_1_arg::<BinaryType, UInt64Type, _, _>(
+        name,
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BinaryType, UInt64Type>(|sketch, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.push(0_u64);
+                    return;
+                }
+            }
+            match S::decode(sketch) {
+                Ok(sketch) => builder.push(sketch.estimate()),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(0_u64);
+                }
+            }
+        }),
+    );
+}
+
+fn register_set_operation<S: Sketch>(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    op: fn(S, S) -> S,
+) {
+    registry.register_passthrough_nullable_2_arg::<BinaryType, BinaryType, BinaryType, _, _>(
+        name,
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<BinaryType, BinaryType, BinaryType>(
+            move |lhs, rhs, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.commit_row();
+                        return;
+                    }
+                }
+                if let Err(e) = eval_set_operation(lhs, rhs, op, builder) {
+                    ctx.set_error(builder.len(), e.to_string());
+                }
+                builder.commit_row();
+            },
+        ),
+    );
+}
+
+fn eval_set_operation<S: Sketch>(
+    lhs: &[u8],
+    rhs: &[u8],
+    op: fn(S, S) -> S,
+    builder: &mut BinaryColumnBuilder,
+) -> Result<()> {
+    let result = op(S::decode(lhs)?, S::decode(rhs)?);
+    result.encode(&mut builder.data)
+}
@@ -18,6 +18,7 @@ mod connection;
 mod file_format;
 mod network_policy;
 mod password_policy;
+mod plan_baseline;
 mod quota;
 mod role;
 mod serde;
@@ -26,6 +27,7 @@ mod stage;
 pub mod udf;
 mod user;
 mod warehouse;
+mod workload_group;
 
 mod client_session;
 pub mod errors;
@@ -36,6 +38,7 @@ pub use connection::ConnectionMgr;
 pub use file_format::FileFormatMgr;
 pub use network_policy::NetworkPolicyMgr;
 pub use password_policy::PasswordPolicyMgr;
+pub use plan_baseline::PlanBaselineMgr;
 pub use procedure::ProcedureMgr;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
@@ -55,3 +58,4 @@ pub use warehouse::SystemManagedWarehouse;
 pub use warehouse::WarehouseApi;
 pub use warehouse::WarehouseInfo;
 pub use warehouse::WarehouseMgr;
+pub use workload_group::WorkloadGroupMgr;
@@ -0,0 +1,205 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_meta_api::kv_pb_api::KVPbApi;
+use bigbytesdb_common_meta_api::reply::txn_reply_to_api_result;
+use bigbytesdb_common_meta_api::txn_cond_eq_seq;
+use bigbytesdb_common_meta_api::txn_op_del;
+use bigbytesdb_common_meta_api::txn_op_put;
+use bigbytesdb_common_meta_api::util::txn_op_put_pb;
+use bigbytesdb_common_meta_app::app_error::TxnRetryMaxTimes;
+use bigbytesdb_common_meta_app::principal::PlanBaseline;
+use bigbytesdb_common_meta_app::principal::PlanBaselineFingerprint;
+use bigbytesdb_common_meta_app::principal::PlanBaselineIdent;
+use bigbytesdb_common_meta_app::principal::PlanBaselineNameIdent;
+use bigbytesdb_common_meta_app::schema::CreateOption;
+use bigbytesdb_common_meta_app::tenant::Tenant;
+use bigbytesdb_common_meta_kvapi::kvapi;
+use bigbytesdb_common_meta_kvapi::kvapi::DirName;
+use bigbytesdb_common_meta_kvapi::kvapi::Key;
+use bigbytesdb_common_meta_types::MetaError;
+use bigbytesdb_common_meta_types::TxnRequest;
+use futures::TryStreamExt;
+
+const TXN_MAX_RETRY_TIMES: u32 = 10;
+
+/// Stores the plan baselines keyed by the query fingerprint, with an index from
+/// the plan baseline name to the fingerprint.
+///
+/// Both keys are always written in one transaction, so a query is pinned by at most
+/// one plan baseline and a name refers to at most one query.
+pub struct PlanBaselineMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    tenant: Tenant,
+}
+
+impl PlanBaselineMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &Tenant) -> Self {
+        PlanBaselineMgr {
+            kv_api,
+            tenant: tenant.clone(),
+        }
+    }
+
+    fn ident(&self, fingerprint: &str) -> PlanBaselineIdent {
+        PlanBaselineIdent::new(self.tenant.clone(), fingerprint)
+    }
+
+    fn name_ident(&self, name: &str) -> PlanBaselineNameIdent {
+        PlanBaselineNameIdent::new(self.tenant.clone(), name)
+    }
+
+    /// Returns the seq of the name key and the fingerprint it refers to, seq is 0 if absent.
+    async fn get_fingerprint(&self, name: &str) -> Result<(u64, Option<String>)> {
+        let key = self.name_ident(name).to_string_key();
+        Ok(match self.kv_api.get_kv(&key).await? {
+            Some(seq_v) => (
+                seq_v.seq,
+                Some(PlanBaselineFingerprint::from_bytes(&seq_v.data).0),
+            ),
+            None => (0, None),
+        })
+    }
+
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn add(
+        &self,
+        plan_baseline: PlanBaseline,
+        create_option: &CreateOption,
+    ) -> Result<()> {
+        let ident = self.ident(&plan_baseline.fingerprint);
+        let name_ident = self.name_ident(&plan_baseline.name);
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let (name_seq, old_fingerprint) = self.get_fingerprint(&plan_baseline.name).await?;
+            if name_seq > 0 {
+                match create_option {
+                    CreateOption::Create => {
+                        return Err(ErrorCode::PlanBaselineAlreadyExists(format!(
+                            "Plan baseline '{}' already exists",
+                            plan_baseline.name
+                        )));
+                    }
+                    CreateOption::CreateIfNotExists => return Ok(()),
+                    CreateOption::CreateOrReplace => {}
+                }
+            }
+
+            // A query can only be pinned by one plan baseline.
+            let seq_v = self.kv_api.get_pb(&ident).await?;
+            if let Some(seq_v) = &seq_v {
+                if seq_v.data.name != plan_baseline.name {
+                    return Err(ErrorCode::PlanBaselineAlreadyExists(format!(
+                        "The query is already pinned by plan baseline '{}'",
+                        seq_v.data.name
+                    )));
+                }
+            }
+            let seq = seq_v.map_or(0, |seq_v| seq_v.seq);
+
+            let mut if_then = vec![
+                txn_op_put_pb(&ident, &plan_baseline, None).map_err(MetaError::from)?,
+                txn_op_put(
+                    &name_ident,
+                    PlanBaselineFingerprint(plan_baseline.fingerprint.clone()).to_bytes(),
+                ),
+            ];
+            // The replaced plan baseline pinned another query.
+            if let Some(old_fingerprint) = old_fingerprint {
+                if old_fingerprint != plan_baseline.fingerprint {
+                    if_then.push(txn_op_del(&self.ident(&old_fingerprint)));
+                }
+            }
+
+            let txn_req = TxnRequest::new(
+                vec![
+                    txn_cond_eq_seq(&name_ident, name_seq),
+                    txn_cond_eq_seq(&ident, seq),
+                ],
+                if_then,
+            );
+            let tx_reply = self.kv_api.transaction(txn_req).await?;
+            let (succ, _) = txn_reply_to_api_result(tx_reply)?;
+
+            if succ {
+                return Ok(());
+            }
+        }
+
+        Err(ErrorCode::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("add_plan_baseline", TXN_MAX_RETRY_TIMES).to_string(),
+        ))
+    }
+
+    /// Returns the plan baseline pinning the query with the fingerprint.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn get_by_fingerprint(&self, fingerprint: &str) -> Result<Option<PlanBaseline>> {
+        let seq_v = self.kv_api.get_pb(&self.ident(fingerprint)).await?;
+        Ok(seq_v.map(|seq_v| seq_v.data))
+    }
+
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn list(&self) -> Result<Vec<PlanBaseline>> {
+        let dir_name = DirName::new(self.ident("dummy"));
+
+        let values = self.kv_api.list_pb_values(&dir_name).await?;
+        let plan_baselines = values.try_collect().await?;
+
+        Ok(plan_baselines)
+    }
+
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn remove(&self, name: &str) -> Result<()> {
+        let name_ident = self.name_ident(name);
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let (name_seq, fingerprint) = self.get_fingerprint(name).await?;
+            let Some(fingerprint) = fingerprint else {
+                return Err(ErrorCode::UnknownPlanBaseline(format!(
+                    "Unknown plan baseline '{}'",
+                    name
+                )));
+            };
+
+            let txn_req = TxnRequest::new(vec![txn_cond_eq_seq(&name_ident, name_seq)], vec![
+                txn_op_del(&name_ident),
+                txn_op_del(&self.ident(&fingerprint)),
+            ]);
+            let tx_reply = self.kv_api.transaction(txn_req).await?;
+            let (succ, _) = txn_reply_to_api_result(tx_reply)?;
+
+            if succ {
+                return Ok(());
+            }
+        }
+
+        Err(ErrorCode::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("drop_plan_baseline", TXN_MAX_RETRY_TIMES).to_string(),
+        ))
+    }
+}
@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+
 use bigbytesdb_common_exception::Result;
 use bigbytesdb_common_meta_app::tenant::TenantQuota;
+use bigbytesdb_common_meta_app::tenant::TenantRunningQuery;
 use bigbytesdb_common_meta_types::seq_value::SeqV;
 use bigbytesdb_common_meta_types::MatchSeq;
 
@@ -22,4 +25,25 @@ pub trait QuotaApi: Sync + Send {
     async fn get_quota(&self, seq: MatchSeq) -> Result<SeqV<TenantQuota>>;
 
     async fn set_quota(&self, quota: &TenantQuota, seq: MatchSeq) -> Result<u64>;
+
+    /// Returns the bytes scanned by the tenant in `month`, such as `2025-03`.
+    async fn get_scanned_bytes(&self, month: &str) -> Result<u64>;
+
+    /// Adds `bytes` to the bytes scanned by the tenant in `month`, and returns the total.
+    async fn add_scanned_bytes(&self, month: &str, bytes: u64) -> Result<u64>;
+
+    /// Registers a running query, or refreshes it.
+    ///
+    /// The registration expires after `ttl` unless refreshed again.
+    async fn upsert_running_query(
+        &self,
+        query_id: &str,
+        node_id: &str,
+        ttl: Duration,
+    ) -> Result<()>;
+
+    async fn remove_running_query(&self, query_id: &str) -> Result<()>;
+
+    /// Returns the query ids and the nodes of the running queries of the tenant.
+    async fn list_running_queries(&self) -> Result<Vec<(String, TenantRunningQuery)>>;
 }
@@ -13,20 +13,29 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use bigbytesdb_common_exception::ErrorCode;
 use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_meta_api::fetch_add_u64;
 use bigbytesdb_common_meta_api::kv_pb_api::KVPbApi;
 use bigbytesdb_common_meta_api::kv_pb_api::UpsertPB;
 use bigbytesdb_common_meta_app::tenant::Tenant;
 use bigbytesdb_common_meta_app::tenant::TenantQuota;
 use bigbytesdb_common_meta_app::tenant::TenantQuotaIdent;
+use bigbytesdb_common_meta_app::tenant::TenantRunningQuery;
+use bigbytesdb_common_meta_app::tenant::TenantRunningQueryIdent;
+use bigbytesdb_common_meta_app::tenant::TenantScannedBytes;
+use bigbytesdb_common_meta_app::tenant::TenantScannedBytesIdent;
+use bigbytesdb_common_meta_app::KeyWithTenant;
 use bigbytesdb_common_meta_kvapi::kvapi;
+use bigbytesdb_common_meta_kvapi::kvapi::DirName;
 use bigbytesdb_common_meta_kvapi::kvapi::Key;
 use bigbytesdb_common_meta_types::seq_value::SeqV;
 use bigbytesdb_common_meta_types::MatchSeq;
 use bigbytesdb_common_meta_types::MatchSeqExt;
 use bigbytesdb_common_meta_types::MetaError;
+use bigbytesdb_common_meta_types::MetaSpec;
 use bigbytesdb_common_meta_types::UpsertKV;
 use bigbytesdb_common_meta_types::With;
 use fastrace::func_name;
@@ -51,6 +60,14 @@ impl<const WRITE_PB: bool> QuotaMgr<WRITE_PB> {
     fn key(&self) -> String {
         self.ident.to_string_key()
     }
+
+    fn scanned_bytes_ident(&self, month: &str) -> TenantScannedBytesIdent {
+        TenantScannedBytesIdent::new(self.ident.tenant(), month)
+    }
+
+    fn running_query_ident(&self, query_id: &str) -> TenantRunningQueryIdent {
+        TenantRunningQueryIdent::new(self.ident.tenant(), query_id)
+    }
 }
 
 #[async_trait::async_trait]
@@ -111,4 +128,76 @@ impl<const WRITE_PB: bool> QuotaApi for QuotaMgr<WRITE_PB> {
             }
         }
     }
+
+    #[async_backtrace::framed]
+    async fn get_scanned_bytes(&self, month: &str) -> Result<u64> {
+        let key = self.scanned_bytes_ident(month).to_string_key();
+        let res = self.kv_api.get_kv(&key).await?;
+        let Some(seq_value) = res else {
+            return Ok(0);
+        };
+
+        let bytes = TenantScannedBytes::from_bytes(&seq_value.data).ok_or_else(|| {
+            ErrorCode::TenantQuotaUnknown(format!("Invalid scanned bytes of {}", key))
+        })?;
+        Ok(bytes.0)
+    }
+
+    #[async_backtrace::framed]
+    async fn add_scanned_bytes(&self, month: &str, bytes: u64) -> Result<u64> {
+        let key = self.scanned_bytes_ident(month).to_string_key();
+        // Only a concurrent update fails the compare-and-swap fallback.
+        let response = loop {
+            if let Some(response) = fetch_add_u64(self.kv_api.as_ref(), vec![], &key, bytes).await?
+            {
+                break response;
+            }
+        };
+        Ok(response.after)
+    }
+
+    #[async_backtrace::framed]
+    async fn upsert_running_query(
+        &self,
+        query_id: &str,
+        node_id: &str,
+        ttl: Duration,
+    ) -> Result<()> {
+        let key = self.running_query_ident(query_id).to_string_key();
+        let value = TenantRunningQuery {
+            node_id: node_id.to_string(),
+        };
+        self.kv_api
+            .upsert_kv(UpsertKV::update(key, &value.to_bytes()).with(MetaSpec::new_ttl(ttl)))
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn remove_running_query(&self, query_id: &str) -> Result<()> {
+        let key = self.running_query_ident(query_id).to_string_key();
+        self.kv_api.upsert_kv(UpsertKV::delete(key)).await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn list_running_queries(&self) -> Result<Vec<(String, TenantRunningQuery)>> {
+        let dir_name = DirName::new(self.running_query_ident("dummy"));
+        let values = self
+            .kv_api
+            .prefix_list_kv(&dir_name.dir_name_with_slash())
+            .await?;
+
+        let mut queries = Vec::with_capacity(values.len());
+        for (key, seq_value) in values {
+            let ident = TenantRunningQueryIdent::from_str_key(&key).map_err(|e| {
+                ErrorCode::TenantQuotaUnknown(format!("Invalid running query key {}: {}", key, e))
+            })?;
+            queries.push((
+                ident.name().clone(),
+                TenantRunningQuery::from_bytes(&seq_value.data),
+            ));
+        }
+        Ok(queries)
+    }
 }
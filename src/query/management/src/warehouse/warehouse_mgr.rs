@@ -1364,6 +1364,7 @@ impl WarehouseApi for WarehouseMgr {
     async fn list_warehouses(&self) -> Result<Vec<WarehouseInfo>> {
         let values = self
             .metastore
+            .stale()
             .prefix_list_kv(&self.warehouse_info_key_prefix)
             .await?;
 
@@ -1491,7 +1492,7 @@ impl WarehouseApi for WarehouseMgr {
             escape_for_key(&warehouse)?
         );
 
-        let values = self.metastore.prefix_list_kv(&nodes_prefix).await?;
+        let values = self.metastore.stale().prefix_list_kv(&nodes_prefix).await?;
 
         let mut nodes_info = Vec::with_capacity(values.len());
         for (node_key, value) in values {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use bigbytesdb_common_base::base::tokio;
 use bigbytesdb_common_exception::Result;
@@ -36,6 +37,10 @@ async fn test_update_quota_from_json_to_pb() -> Result<()> {
         max_stages: 4,
         max_files_per_stage: 5,
         max_users: 6,
+        max_storage_bytes: 7,
+        max_concurrent_queries: 8,
+        max_warehouses: 9,
+        max_scanned_bytes_per_month: 10,
     };
     quota_api_json.set_quota(&quota0, MatchSeq::GE(0)).await?;
 
@@ -43,7 +48,7 @@ async fn test_update_quota_from_json_to_pb() -> Result<()> {
     let s: String = String::from_utf8(value.unwrap().data)?;
     assert_eq!(
         s,
-        "{\"max_databases\":2,\"max_tables_per_database\":3,\"max_stages\":4,\"max_files_per_stage\":5,\"max_users\":6}"
+        "{\"max_databases\":2,\"max_tables_per_database\":3,\"max_stages\":4,\"max_files_per_stage\":5,\"max_users\":6,\"max_storage_bytes\":7,\"max_concurrent_queries\":8,\"max_warehouses\":9,\"max_scanned_bytes_per_month\":10}"
     );
 
     let quota1 = quota_api_json.get_quota(MatchSeq::GE(0)).await?.data;
@@ -53,7 +58,7 @@ async fn test_update_quota_from_json_to_pb() -> Result<()> {
     let s: String = String::from_utf8(value.unwrap().data)?;
     assert_eq!(
         s,
-        "{\"max_databases\":2,\"max_tables_per_database\":3,\"max_stages\":4,\"max_files_per_stage\":5,\"max_users\":6}"
+        "{\"max_databases\":2,\"max_tables_per_database\":3,\"max_stages\":4,\"max_files_per_stage\":5,\"max_users\":6,\"max_storage_bytes\":7,\"max_concurrent_queries\":8,\"max_warehouses\":9,\"max_scanned_bytes_per_month\":10}"
     );
 
     // when enable write pb
@@ -84,6 +89,45 @@ async fn test_update_quota_from_json_to_pb() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_quota_usage() -> Result<()> {
+    let (kv_api, _, quota_api) = new_quota_api().await?;
+
+    assert_eq!(quota_api.get_scanned_bytes("2025-03").await?, 0);
+    assert_eq!(quota_api.add_scanned_bytes("2025-03", 10).await?, 10);
+    assert_eq!(quota_api.add_scanned_bytes("2025-03", 5).await?, 15);
+    assert_eq!(quota_api.get_scanned_bytes("2025-03").await?, 15);
+    assert_eq!(quota_api.get_scanned_bytes("2025-04").await?, 0);
+
+    let value = kv_api
+        .get_kv("__fd_tenant_scanned_bytes/admin/2025%2d03")
+        .await?;
+    assert_eq!(value.unwrap().data, b"15".to_vec());
+
+    let ttl = Duration::from_secs(60);
+    quota_api.upsert_running_query("q1", "n1", ttl).await?;
+    quota_api.upsert_running_query("q2", "n2", ttl).await?;
+    quota_api.upsert_running_query("q1", "n1", ttl).await?;
+
+    let mut queries = quota_api.list_running_queries().await?;
+    queries.sort_by(|a, b| a.0.cmp(&b.0));
+    let queries = queries
+        .into_iter()
+        .map(|(id, q)| (id, q.node_id))
+        .collect::<Vec<_>>();
+    assert_eq!(queries, vec![
+        ("q1".to_string(), "n1".to_string()),
+        ("q2".to_string(), "n2".to_string())
+    ]);
+
+    quota_api.remove_running_query("q1").await?;
+    let queries = quota_api.list_running_queries().await?;
+    assert_eq!(queries.len(), 1);
+    assert_eq!(queries[0].0, "q2");
+
+    Ok(())
+}
+
 async fn new_quota_api() -> Result<(Arc<MemMeta>, QuotaMgr<false>, QuotaMgr<true>)> {
     let test_api = Arc::new(MemMeta::default());
     let mgr_json = QuotaMgr::<false>::create(test_api.clone(), &Tenant::new_literal("admin"));
@@ -27,6 +27,7 @@ use bigbytesdb_common_users::JwtAuthenticator;
 use bigbytesdb_common_users::UserApiProvider;
 use fastrace::func_name;
 
+use crate::history::SystemHistory;
 use crate::servers::http::v1::ClientSessionManager;
 use crate::sessions::Session;
 
@@ -97,6 +98,39 @@ impl AuthMgr {
         session: &mut Session,
         credential: &Credential,
         need_user_info: bool,
+    ) -> Result<(String, Option<String>)> {
+        let res = self.do_auth(session, credential, need_user_info).await;
+
+        // Only the logins with the user credentials are recorded,
+        // the token of a session is verified on every request.
+        let (auth_type, user_name, client_ip) = match credential {
+            Credential::Jwt { client_ip, .. } => ("jwt", "", client_ip),
+            Credential::Password {
+                name, client_ip, ..
+            } => ("password", name.as_str(), client_ip),
+            _ => return res,
+        };
+        let user_name = match &res {
+            Ok((name, _)) => name.as_str(),
+            Err(_) => user_name,
+        };
+        SystemHistory::instance().append_login(
+            &session.get_type(),
+            &session.get_current_tenant(),
+            auth_type,
+            user_name,
+            client_ip.as_deref().unwrap_or_default(),
+            res.as_ref().err(),
+        );
+        res
+    }
+
+    #[async_backtrace::framed]
+    async fn do_auth(
+        &self,
+        session: &mut Session,
+        credential: &Credential,
+        need_user_info: bool,
     ) -> Result<(String, Option<String>)> {
         let user_api = UserApiProvider::instance();
         let global_network_policy = session
@@ -128,6 +128,7 @@ use crate::catalogs::default::catalog_context::CatalogContext;
 use crate::databases::Database;
 use crate::databases::DatabaseContext;
 use crate::databases::DatabaseFactory;
+use crate::sessions::TenantQuotaManager;
 use crate::storages::StorageDescription;
 use crate::storages::StorageFactory;
 use crate::storages::Table;
@@ -706,6 +707,18 @@ impl Catalog for MutableCatalog {
             }
         }
 
+        // The bytes the commit adds to the storage of the tenant.
+        let storage_growth = req
+            .update_table_metas
+            .iter()
+            .map(|(update, table_info)| {
+                TenantQuotaManager::storage_bytes(&update.new_table_meta.statistics) as i64
+                    - TenantQuotaManager::storage_bytes(&table_info.meta.statistics) as i64
+            })
+            .sum::<i64>();
+        let quota_manager = TenantQuotaManager::instance();
+        quota_manager.check_storage(&self.tenant, storage_growth)?;
+
         info!(
             "updating multi table meta. number of tables: {}",
             req.update_table_metas.len()
@@ -716,6 +729,9 @@ impl Catalog for MutableCatalog {
             "update multi table meta done. time used {:?}",
             begin.elapsed()
         );
+        if let Ok(Ok(_)) = &res {
+            quota_manager.add_storage_usage(&self.tenant, storage_growth);
+        }
         Ok(res?)
     }
 
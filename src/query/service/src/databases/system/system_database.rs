@@ -22,6 +22,7 @@ use bigbytesdb_common_meta_app::schema::DatabaseInfo;
 use bigbytesdb_common_meta_app::schema::DatabaseMeta;
 use bigbytesdb_common_meta_app::tenant::Tenant;
 use bigbytesdb_common_meta_types::seq_value::SeqV;
+use bigbytesdb_common_storages_system::AuditLogTable;
 use bigbytesdb_common_storages_system::BackgroundJobTable;
 use bigbytesdb_common_storages_system::BackgroundTaskTable;
 use bigbytesdb_common_storages_system::BacktraceTable;
@@ -46,11 +47,13 @@ use bigbytesdb_common_storages_system::LocksTable;
 use bigbytesdb_common_storages_system::MallocStatsTable;
 #[cfg(feature = "jemalloc")]
 use bigbytesdb_common_storages_system::MallocStatsTotalsTable;
+use bigbytesdb_common_storages_system::MaterializedViewsTable;
 use bigbytesdb_common_storages_system::MetricsTable;
 use bigbytesdb_common_storages_system::NotificationHistoryTable;
 use bigbytesdb_common_storages_system::NotificationsTable;
 use bigbytesdb_common_storages_system::OneTable;
 use bigbytesdb_common_storages_system::PasswordPoliciesTable;
+use bigbytesdb_common_storages_system::PlanBaselinesTable;
 use bigbytesdb_common_storages_system::ProceduresTable;
 use bigbytesdb_common_storages_system::ProcessesTable;
 use bigbytesdb_common_storages_system::QueriesProfilingTable;
@@ -72,6 +75,7 @@ use bigbytesdb_common_storages_system::UsersTable;
 use bigbytesdb_common_storages_system::ViewsTableWithHistory;
 use bigbytesdb_common_storages_system::ViewsTableWithoutHistory;
 use bigbytesdb_common_storages_system::VirtualColumnsTable;
+use bigbytesdb_common_storages_system::WorkloadGroupsTable;
 
 use crate::catalogs::InMemoryMetas;
 use crate::databases::Database;
@@ -125,6 +129,10 @@ impl SystemDatabase {
                 sys_db_meta.next_table_id(),
                 config.query.max_query_log_size,
             )),
+            Arc::new(AuditLogTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
             EnginesTable::create(sys_db_meta.next_table_id()),
             RolesTable::create(sys_db_meta.next_table_id()),
             StagesTable::create(sys_db_meta.next_table_id()),
@@ -144,6 +152,9 @@ impl SystemDatabase {
             LocksTable::create(sys_db_meta.next_table_id()),
             VirtualColumnsTable::create(sys_db_meta.next_table_id()),
             PasswordPoliciesTable::create(sys_db_meta.next_table_id()),
+            PlanBaselinesTable::create(sys_db_meta.next_table_id()),
+            WorkloadGroupsTable::create(sys_db_meta.next_table_id()),
+            MaterializedViewsTable::create(sys_db_meta.next_table_id()),
             UserFunctionsTable::create(sys_db_meta.next_table_id()),
             NotificationsTable::create(sys_db_meta.next_table_id()),
             NotificationHistoryTable::create(sys_db_meta.next_table_id()),
@@ -44,6 +44,7 @@ use crate::builtin::BuiltinUDFs;
 use crate::builtin::BuiltinUsers;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
+use crate::history::SystemHistory;
 use crate::locks::LockManager;
 #[cfg(feature = "enable_queries_executor")]
 use crate::pipelines::executor::GlobalQueriesExecutor;
@@ -52,6 +53,8 @@ use crate::servers::http::v1::ClientSessionManager;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::SessionManager;
+use crate::sessions::TenantQuotaManager;
+use crate::sessions::WorkloadGroupResourceManager;
 
 pub struct GlobalServices;
 
@@ -111,6 +114,7 @@ impl GlobalServices {
         }
 
         QueriesQueueManager::init(config.query.max_running_queries as usize)?;
+        WorkloadGroupResourceManager::init()?;
         HttpQueryManager::init(config).await?;
         ClientSessionManager::init(config).await?;
         DataExchangeManager::init()?;
@@ -139,6 +143,7 @@ impl GlobalServices {
         }
 
         RoleCacheManager::init()?;
+        TenantQuotaManager::init()?;
 
         DataOperator::init(&config.storage, config.spill.storage_params.clone()).await?;
         ShareTableConfig::init(
@@ -158,6 +163,7 @@ impl GlobalServices {
         }
 
         ProfilesLogQueue::init(config.query.max_cached_queries_profiles);
+        SystemHistory::init(config.log.history.clone())?;
 
         #[cfg(feature = "enable_queries_executor")]
         {
@@ -0,0 +1,559 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+use bigbytesdb_common_base::base::tokio::time::sleep;
+use bigbytesdb_common_base::base::GlobalInstance;
+use bigbytesdb_common_base::runtime::GlobalIORuntime;
+use bigbytesdb_common_base::runtime::TrySpawn;
+use bigbytesdb_common_catalog::catalog_kind::CATALOG_DEFAULT;
+use bigbytesdb_common_config::GlobalConfig;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::types::DataType;
+use bigbytesdb_common_expression::ColumnBuilder;
+use bigbytesdb_common_expression::Scalar;
+use bigbytesdb_common_meta_app::principal::UserInfo;
+use bigbytesdb_common_meta_app::tenant::Tenant;
+use bigbytesdb_common_pipeline_core::processors::PlanProfile;
+use bigbytesdb_common_sql::plans::Insert;
+use bigbytesdb_common_sql::plans::InsertInputSource;
+use bigbytesdb_common_sql::plans::InsertValue;
+use bigbytesdb_common_sql::Planner;
+use bigbytesdb_common_storages_system::AuditLogElement;
+use bigbytesdb_common_storages_system::QueryLogElement;
+use bigbytesdb_common_storages_system::SystemLogElement;
+use bigbytesdb_common_tracing::HistoryConfig;
+use bigbytesdb_common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_TTL;
+use futures_util::TryStreamExt;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::history::LoginHistoryElement;
+use crate::history::ProfileHistoryElement;
+use crate::interpreters::InsertInterpreter;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::convert_query_log_timestamp;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+pub const SYSTEM_HISTORY_DATABASE: &str = "system_history";
+
+const SYSTEM_HISTORY_USAGE: &str = "SystemHistory";
+
+// Events are dropped if they can not be flushed in time, instead of using up the memory.
+// The audit events are the exception, they are never dropped.
+const MAX_PENDING_EVENTS: usize = 100_000;
+
+const EXPIRE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+struct HistoryBuffer<Event: SystemLogElement> {
+    table_name: &'static str,
+    // At most `MAX_PENDING_EVENTS` events are kept if it is bounded.
+    bounded: bool,
+    events: Mutex<Vec<Event>>,
+    dropped: AtomicU64,
+}
+
+impl<Event: SystemLogElement> HistoryBuffer<Event> {
+    fn create(table_name: &'static str) -> HistoryBuffer<Event> {
+        HistoryBuffer {
+            table_name,
+            bounded: true,
+            events: Mutex::new(Vec::new()),
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    fn create_unbounded(table_name: &'static str) -> HistoryBuffer<Event> {
+        HistoryBuffer {
+            bounded: false,
+            ..Self::create(table_name)
+        }
+    }
+
+    fn capacity(&self) -> usize {
+        match self.bounded {
+            true => MAX_PENDING_EVENTS,
+            false => usize::MAX,
+        }
+    }
+
+    /// Returns false if the event is dropped because too many events are pending.
+    fn push(&self, event: Event) -> bool {
+        let mut events = self.events.lock();
+        if events.len() >= self.capacity() {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            return false;
+        }
+        events.push(event);
+        true
+    }
+
+    fn take(&self) -> Vec<Event> {
+        std::mem::take(&mut *self.events.lock())
+    }
+
+    // Put the events that failed to flush back, they are retried in the next round.
+    fn restore(&self, mut failed: Vec<Event>) {
+        let mut events = self.events.lock();
+        let capacity = self.capacity().saturating_sub(events.len());
+        if failed.len() > capacity {
+            let dropped = failed.len() - capacity;
+            self.dropped.fetch_add(dropped as u64, Ordering::Relaxed);
+            failed.drain(..dropped);
+        }
+        failed.append(&mut events);
+        *events = failed;
+    }
+
+    fn rows(&self, events: &[Event]) -> Result<(Vec<String>, Vec<Vec<Scalar>>)> {
+        let schema = Event::schema();
+        let mut columns: Vec<ColumnBuilder> = schema
+            .fields()
+            .iter()
+            .map(|f| {
+                let data_type: DataType = f.data_type().into();
+                ColumnBuilder::with_capacity(&data_type, events.len())
+            })
+            .collect();
+        for event in events {
+            event.fill_to_data_block(&mut columns)?;
+        }
+
+        let names = schema.fields().iter().map(|f| f.name().clone()).collect();
+        let columns = columns
+            .into_iter()
+            .map(|builder| builder.build())
+            .collect::<Vec<_>>();
+        let rows = (0..events.len())
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|column| column.index(row).unwrap().to_owned())
+                    .collect()
+            })
+            .collect();
+        Ok((names, rows))
+    }
+}
+
+/// Persists the query logs, the query profiles, the login events and the audit logs
+/// into the Fuse tables of the `system_history` database.
+///
+/// Events are buffered in memory and flushed by a background task every `interval` seconds,
+/// so the queries are never blocked by the history. As the tables are on the shared storage,
+/// the history of all the nodes in the cluster can be queried from any node.
+/// Rows older than `retention` days are hidden by the `ttl` option of the tables,
+/// and removed by `OPTIMIZE TABLE ... EXPIRE` periodically.
+///
+/// The audit events are persisted even if the history is off. They are flushed by the
+/// audited statement itself, see [`SystemHistory::persist_audit`], and never dropped.
+pub struct SystemHistory {
+    config: HistoryConfig,
+    prepared: AtomicBool,
+    audit_prepared: AtomicBool,
+    query_history: HistoryBuffer<QueryLogElement>,
+    profile_history: HistoryBuffer<ProfileHistoryElement>,
+    login_history: HistoryBuffer<LoginHistoryElement>,
+    audit_history: HistoryBuffer<AuditLogElement>,
+}
+
+impl SystemHistory {
+    pub fn init(config: HistoryConfig) -> Result<()> {
+        GlobalInstance::set(Arc::new(SystemHistory {
+            config,
+            prepared: AtomicBool::new(false),
+            audit_prepared: AtomicBool::new(false),
+            query_history: HistoryBuffer::create("query_history"),
+            profile_history: HistoryBuffer::create("profile_history"),
+            login_history: HistoryBuffer::create("login_history"),
+            audit_history: HistoryBuffer::create_unbounded("audit_history"),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<SystemHistory> {
+        GlobalInstance::get()
+    }
+
+    /// The queries issued by the history itself are not recorded,
+    /// otherwise every flush produces new events to flush.
+    pub(crate) fn is_history_session(handler_type: &str) -> bool {
+        handler_type == SessionType::HTTPAPI(SYSTEM_HISTORY_USAGE.to_string()).to_string()
+    }
+
+    pub fn append_query_log(&self, event: &QueryLogElement) {
+        if self.config.on && !Self::is_history_session(&event.handler_type) {
+            self.query_history.push(event.clone());
+        }
+    }
+
+    pub fn append_profiles(&self, ctx: &QueryContext, profiles: &[PlanProfile]) -> Result<()> {
+        let handler_type = ctx.get_current_session().get_type().to_string();
+        if !self.config.on || Self::is_history_session(&handler_type) {
+            return Ok(());
+        }
+
+        let config = GlobalConfig::instance();
+        self.profile_history.push(ProfileHistoryElement {
+            event_time: convert_query_log_timestamp(SystemTime::now()),
+            tenant_id: ctx.get_tenant().tenant_name().to_string(),
+            cluster_id: config.query.cluster_id.clone(),
+            node_id: config.query.node_id.clone(),
+            query_id: ctx.get_id(),
+            profiles: serde_json::to_string(profiles)?,
+        });
+        Ok(())
+    }
+
+    pub fn append_login(
+        &self,
+        session_type: &SessionType,
+        tenant: &Tenant,
+        auth_type: &str,
+        user_name: &str,
+        client_ip: &str,
+        error: Option<&ErrorCode>,
+    ) {
+        let handler_type = session_type.to_string();
+        if !self.config.on || Self::is_history_session(&handler_type) {
+            return;
+        }
+
+        let config = GlobalConfig::instance();
+        self.login_history.push(LoginHistoryElement {
+            event_time: convert_query_log_timestamp(SystemTime::now()),
+            tenant_id: tenant.tenant_name().to_string(),
+            cluster_id: config.query.cluster_id.clone(),
+            node_id: config.query.node_id.clone(),
+            handler_type,
+            auth_type: auth_type.to_string(),
+            user_name: user_name.to_string(),
+            client_ip: client_ip.to_string(),
+            success: error.is_none(),
+            error_code: error.map(|e| e.code() as i32).unwrap_or(0),
+            error_message: error.map(|e| e.message()).unwrap_or_default(),
+        });
+    }
+
+    /// Persists the audit events into `system_history.audit_history` right away.
+    ///
+    /// The events are kept if it fails, they are retried with the ones of the following
+    /// statements and by the background task.
+    #[async_backtrace::framed]
+    pub async fn persist_audit(&self, events: Vec<AuditLogElement>) -> Result<()> {
+        for event in events {
+            self.audit_history.push(event);
+        }
+
+        let session = Self::create_session().await?;
+        self.prepare_audit(&session).await?;
+        self.flush_buffer(&session, &self.audit_history).await
+    }
+
+    /// Start the background task that flushes the events.
+    ///
+    /// If the history is off, it only retries the audit events that failed to be persisted.
+    pub fn start(self: &Arc<Self>) {
+        if !self.config.on {
+            info!("system history is off, only the audit events are persisted");
+        }
+
+        let history = self.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            let interval = Duration::from_secs(history.config.interval);
+            let mut last_expire = Instant::now();
+            loop {
+                sleep(interval).await;
+                let flushed = match history.config.on {
+                    true => history.flush().await,
+                    false => history.flush_audit().await,
+                };
+                if let Err(cause) = flushed {
+                    warn!("system history flush failed: {}", cause);
+                }
+
+                if history.config.retention > 0 && last_expire.elapsed() >= EXPIRE_INTERVAL {
+                    last_expire = Instant::now();
+                    if let Err(cause) = history.expire().await {
+                        warn!("system history expire failed: {}", cause);
+                    }
+                }
+            }
+        });
+        info!(
+            "system history started, interval: {}s, retention: {}d",
+            self.config.interval, self.config.retention
+        );
+    }
+
+    /// Creates the session that runs the statements of one flush or expire round,
+    /// each statement still gets its own query context.
+    #[async_backtrace::framed]
+    async fn create_session() -> Result<Arc<Session>> {
+        let session_manager = SessionManager::instance();
+        let session = session_manager
+            .create_session(SessionType::HTTPAPI(SYSTEM_HISTORY_USAGE.to_string()))
+            .await?;
+        let session = session_manager.register_session(session)?;
+        session
+            .set_authed_user(
+                UserInfo::new_no_auth("system_history", "0.0.0.0"),
+                Some(BUILTIN_ROLE_ACCOUNT_ADMIN.to_string()),
+            )
+            .await?;
+        Ok(session)
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_sql(session: &Arc<Session>, sql: &str) -> Result<()> {
+        let ctx = session.create_query_context().await?;
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner.plan_sql(sql).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(ctx).await?;
+        stream.try_collect::<Vec<_>>().await?;
+        Ok(())
+    }
+
+    fn ttl(&self) -> Option<String> {
+        match self.config.retention {
+            0 => None,
+            days => Some(format!("event_time + INTERVAL {} DAY", days)),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn prepare_table<Event: SystemLogElement>(
+        &self,
+        session: &Arc<Session>,
+        buffer: &HistoryBuffer<Event>,
+    ) -> Result<()> {
+        let columns = Event::schema()
+            .fields()
+            .iter()
+            .map(|f| format!("`{}` {}", f.name(), f.data_type().sql_name_explicit_null()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let ttl = self.ttl();
+        let ttl_clause = match &ttl {
+            Some(ttl) => format!(" TTL = '{}'", ttl),
+            None => "".to_string(),
+        };
+        Self::execute_sql(
+            session,
+            &format!(
+                "CREATE TABLE IF NOT EXISTS {}.{} ({}){}",
+                SYSTEM_HISTORY_DATABASE, buffer.table_name, columns, ttl_clause
+            ),
+        )
+        .await?;
+
+        // The retention may be changed since the table was created.
+        let ctx = session.create_query_context().await?;
+        let table = ctx
+            .get_table(CATALOG_DEFAULT, SYSTEM_HISTORY_DATABASE, buffer.table_name)
+            .await?;
+        if table.options().get(OPT_KEY_TTL) != ttl.as_ref() {
+            let sql = match &ttl {
+                Some(ttl) => format!(
+                    "ALTER TABLE {}.{} SET OPTIONS({} = '{}')",
+                    SYSTEM_HISTORY_DATABASE, buffer.table_name, OPT_KEY_TTL, ttl
+                ),
+                None => format!(
+                    "ALTER TABLE {}.{} UNSET OPTIONS({})",
+                    SYSTEM_HISTORY_DATABASE, buffer.table_name, OPT_KEY_TTL
+                ),
+            };
+            Self::execute_sql(session, &sql).await?;
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn prepare_audit(&self, session: &Arc<Session>) -> Result<()> {
+        if self.audit_prepared.load(Ordering::Acquire) {
+            return Ok(());
+        }
+
+        Self::execute_sql(
+            session,
+            &format!("CREATE DATABASE IF NOT EXISTS {}", SYSTEM_HISTORY_DATABASE),
+        )
+        .await?;
+        self.prepare_table(session, &self.audit_history).await?;
+        self.audit_prepared.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn prepare(&self, session: &Arc<Session>) -> Result<()> {
+        if self.prepared.load(Ordering::Acquire) {
+            return Ok(());
+        }
+
+        self.prepare_audit(session).await?;
+        self.prepare_table(session, &self.query_history).await?;
+        self.prepare_table(session, &self.profile_history).await?;
+        self.prepare_table(session, &self.login_history).await?;
+        self.prepared.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn flush_audit(&self) -> Result<()> {
+        if self.audit_history.events.lock().is_empty() {
+            return Ok(());
+        }
+
+        let session = Self::create_session().await?;
+        self.prepare_audit(&session).await?;
+        self.flush_buffer(&session, &self.audit_history).await
+    }
+
+    #[async_backtrace::framed]
+    async fn flush(&self) -> Result<()> {
+        let session = Self::create_session().await?;
+        self.prepare(&session).await?;
+
+        let mut res = Ok(());
+        for flushed in [
+            self.flush_buffer(&session, &self.query_history).await,
+            self.flush_buffer(&session, &self.profile_history).await,
+            self.flush_buffer(&session, &self.login_history).await,
+            self.flush_buffer(&session, &self.audit_history).await,
+        ] {
+            if flushed.is_err() {
+                res = flushed;
+            }
+        }
+        res
+    }
+
+    #[async_backtrace::framed]
+    async fn flush_buffer<Event: SystemLogElement>(
+        &self,
+        session: &Arc<Session>,
+        buffer: &HistoryBuffer<Event>,
+    ) -> Result<()> {
+        let dropped = buffer.dropped.swap(0, Ordering::Relaxed);
+        if dropped > 0 {
+            warn!(
+                "system history dropped {} events of {} that were not flushed in time",
+                dropped, buffer.table_name
+            );
+        }
+
+        let events = buffer.take();
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let res = self.insert(session, buffer, &events).await;
+        if res.is_err() {
+            buffer.restore(events);
+        }
+        res
+    }
+
+    #[async_backtrace::framed]
+    async fn insert<Event: SystemLogElement>(
+        &self,
+        session: &Arc<Session>,
+        buffer: &HistoryBuffer<Event>,
+        events: &[Event],
+    ) -> Result<()> {
+        let ctx = session.create_query_context().await?;
+        let table = ctx
+            .get_table(CATALOG_DEFAULT, SYSTEM_HISTORY_DATABASE, buffer.table_name)
+            .await?;
+
+        // The columns are matched by name, so that the history tables created by
+        // an older version, which may miss some columns, can still be written.
+        let (names, rows) = buffer.rows(events)?;
+        let positions = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.as_str(), i))
+            .collect::<HashMap<_, _>>();
+        let schema = table.schema();
+        let rows = rows
+            .into_iter()
+            .map(|row| {
+                schema
+                    .fields()
+                    .iter()
+                    .map(|f| match positions.get(f.name().as_str()) {
+                        Some(i) => row[*i].clone(),
+                        None => Scalar::default_value(&f.data_type().into()),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let plan = Insert {
+            catalog: CATALOG_DEFAULT.to_string(),
+            database: SYSTEM_HISTORY_DATABASE.to_string(),
+            table: buffer.table_name.to_string(),
+            schema,
+            overwrite: false,
+            source: InsertInputSource::Values(InsertValue::Values { rows }),
+            table_info: None,
+        };
+        let interpreter = InsertInterpreter::try_create(ctx.clone(), plan)?;
+        let stream = interpreter.execute(ctx).await?;
+        stream.try_collect::<Vec<_>>().await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn expire(&self) -> Result<()> {
+        let session = Self::create_session().await?;
+        let tables = match self.config.on {
+            true => vec![
+                self.query_history.table_name,
+                self.profile_history.table_name,
+                self.login_history.table_name,
+                self.audit_history.table_name,
+            ],
+            false => vec![self.audit_history.table_name],
+        };
+        for table in tables {
+            Self::execute_sql(
+                &session,
+                &format!(
+                    "OPTIMIZE TABLE {}.{} EXPIRE",
+                    SYSTEM_HISTORY_DATABASE, table
+                ),
+            )
+            .await?;
+        }
+        Ok(())
+    }
+}
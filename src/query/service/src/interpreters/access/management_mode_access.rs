@@ -76,10 +76,12 @@ impl AccessChecker for ManagementModeAccess {
 
                 // Database.
                 | Plan::CreateDatabase(_)
+                | Plan::CloneDatabase(_)
                 | Plan::DropDatabase(_)
 
                 // Table.
                 | Plan::CreateTable(_)
+                | Plan::CloneTable(_)
                 | Plan::DropTable(_)
                 | Plan::DropView(_)
                 | Plan::CreateView(_)
@@ -89,6 +91,10 @@ impl AccessChecker for ManagementModeAccess {
                 // Dynamic table.
                 | Plan::CreateDynamicTable(_)
 
+                // Materialized view.
+                | Plan::CreateMaterializedView(_)
+                | Plan::RefreshMaterializedView(_)
+
                 // User.
                 | Plan::AlterUser(_)
                 | Plan::CreateUser(_)
@@ -99,6 +105,7 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::ShowRoles(_)
                 | Plan::CreateRole(_)
                 | Plan::DropRole(_)
+                | Plan::AlterRole(_)
 
                 // Privilege.
                 | Plan::GrantPriv(_)
@@ -116,6 +123,13 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreatePasswordPolicy(_)
                 | Plan::AlterPasswordPolicy(_)
                 | Plan::DropPasswordPolicy(_)
+                // Plan baseline.
+                | Plan::CreatePlanBaseline(_)
+                | Plan::DropPlanBaseline(_)
+                // Workload group.
+                | Plan::CreateWorkloadGroup(_)
+                | Plan::AlterWorkloadGroup(_)
+                | Plan::DropWorkloadGroup(_)
 
                 // UDF
                 | Plan::CreateUDF(_)
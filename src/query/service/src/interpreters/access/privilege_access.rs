@@ -43,6 +43,7 @@ use bigbytesdb_common_sql::plans::RewriteKind;
 use bigbytesdb_common_sql::Planner;
 use bigbytesdb_common_users::RoleCacheManager;
 use bigbytesdb_common_users::UserApiProvider;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 
 use crate::interpreters::access::AccessChecker;
@@ -812,6 +813,11 @@ impl AccessChecker for PrivilegeAccess {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::CreateDatabase, true, false)
                     .await?;
             }
+            Plan::CloneDatabase(plan) => {
+                self.validate_access(&GrantObject::Global, UserPrivilegeType::CreateDatabase, true, false)
+                    .await?;
+                self.validate_db_access(&plan.source_catalog, &plan.source_database, UserPrivilegeType::Select, false).await?
+            }
             Plan::DropDatabase(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Drop, plan.if_exists).await?;
             }
@@ -917,6 +923,10 @@ impl AccessChecker for PrivilegeAccess {
                     self.check(ctx, query).await?;
                 }
             }
+            Plan::CloneTable(plan) => {
+                self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Create, false).await?;
+                self.validate_table_access(&plan.source_catalog, &plan.source_database, &plan.source_table, UserPrivilegeType::Select, false, false).await?
+            }
             Plan::DropTable(plan) => {
                 // For attach table
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Drop, plan.if_exists, true).await?;
@@ -944,6 +954,12 @@ impl AccessChecker for PrivilegeAccess {
             Plan::UnsetOptions(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
             }
+            Plan::CreateSnapshotTag(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
+            Plan::DropSnapshotTag(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
             Plan::AddTableColumn(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
             }
@@ -979,6 +995,9 @@ impl AccessChecker for PrivilegeAccess {
             Plan::OptimizeCompactSegment(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Super, false, false).await?
             },
+            Plan::OptimizeRefreshNgramIndex(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Super, false, false).await?
+            },
             Plan::OptimizeCompactBlock { s_expr, .. } => {
                 let plan: OptimizeCompactBlock = s_expr.plan().clone().try_into()?;
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Super, false, false).await?
@@ -1015,6 +1034,11 @@ impl AccessChecker for PrivilegeAccess {
                 }
                 self.validate_insert_source(ctx, &plan.source).await?;
             }
+            Plan::InsertOverwritePartition(plan) => {
+                for privilege in [UserPrivilegeType::Insert, UserPrivilegeType::Delete] {
+                    self.validate_table_access(&plan.catalog, &plan.database, &plan.table, privilege, false, false).await?;
+                }
+            }
             Plan::InsertMultiTable(plan) => {
                 let target_table_privileges = if plan.overwrite {
                     vec![UserPrivilegeType::Insert, UserPrivilegeType::Delete]
@@ -1110,6 +1134,18 @@ impl AccessChecker for PrivilegeAccess {
             Plan::CreateDynamicTable(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Create, false).await?;
             }
+            Plan::CreateMaterializedView(plan) => {
+                let plan = &plan.create_table;
+                self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Create, false).await?;
+                if let Some(query) = plan.options.get(OPT_KEY_MATERIALIZED_VIEW_QUERY) {
+                    let mut planner = Planner::new(self.ctx.clone());
+                    let (plan, _) = planner.plan_sql(query).await?;
+                    self.check(ctx, &plan).await?
+                }
+            }
+            Plan::RefreshMaterializedView(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.view, UserPrivilegeType::Insert, false, false).await?
+            }
             Plan::CreateUser(_) => {
                 self.validate_access(
                     &GrantObject::Global,
@@ -1191,6 +1227,11 @@ impl AccessChecker for PrivilegeAccess {
                 let from = plan.from.clone();
                 return self.check(ctx, &from).await;
             }
+            Plan::CreatePlanBaseline(plan) => {
+                self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false)
+                    .await?;
+                return self.check(ctx, &plan.plan).await;
+            }
             Plan::RemoveStage(plan) => {
                 self.validate_stage_access(&plan.stage, UserPrivilegeType::Write).await?;
             }
@@ -1210,6 +1251,11 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::AlterPasswordPolicy(_)
             | Plan::DropPasswordPolicy(_)
             | Plan::DescPasswordPolicy(_)
+            | Plan::DropPlanBaseline(_)
+            | Plan::AlterRole(_)
+            | Plan::CreateWorkloadGroup(_)
+            | Plan::AlterWorkloadGroup(_)
+            | Plan::DropWorkloadGroup(_)
             | Plan::CreateConnection(_)
             | Plan::ShowConnections(_)
             | Plan::DescConnection(_)
@@ -0,0 +1,147 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use bigbytesdb_common_ast::ast::Engine;
+use bigbytesdb_common_catalog::table::NavigationPoint;
+use bigbytesdb_common_catalog::table::Table;
+use bigbytesdb_common_catalog::table_context::TableContext;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_meta_app::schema::CreateOption;
+use bigbytesdb_common_sql::plans::CreateTablePlan;
+use bigbytesdb_common_storages_fuse::FuseTable;
+use bigbytesdb_storages_common_table_meta::table::format_clone_table_ids;
+use bigbytesdb_storages_common_table_meta::table::is_reserved_opt_key;
+use bigbytesdb_storages_common_table_meta::table::parse_clone_table_ids;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_CLONE_SOURCES;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_COMMENT;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+
+use crate::interpreters::common::table_option_validation::CREATE_FUSE_OPTIONS;
+use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::sessions::QueryContext;
+
+/// Returns the Fuse table behind `table` if it can be cloned.
+///
+/// Only the tables kept in the default storage share their files with clones, temporary,
+/// attached and external location tables are rejected.
+pub fn cloneable_fuse_table(table: &dyn Table) -> Result<&FuseTable> {
+    let fuse_table = FuseTable::try_from_table(table).map_err(|_| {
+        ErrorCode::Unimplemented(format!(
+            "CLONE is only supported by FUSE tables, but table {} is of engine {}",
+            table.name(),
+            table.engine()
+        ))
+    })?;
+    let table_info = fuse_table.get_table_info();
+    if fuse_table.is_read_only()
+        || table_info.meta.storage_params.is_some()
+        || table_info.options().contains_key(OPT_KEY_TEMP_PREFIX)
+    {
+        return Err(ErrorCode::Unimplemented(format!(
+            "CLONE of table {} is not supported, only tables of the default storage can be cloned",
+            table.name()
+        )));
+    }
+    Ok(fuse_table)
+}
+
+/// Create `catalog.database.table` as a zero-copy clone of `source`.
+///
+/// The new table shares the segments and blocks of the source snapshot (or the snapshot
+/// at `navigation`), and records the lineage that keeps gc from purging them.
+#[async_backtrace::framed]
+pub async fn clone_table(
+    ctx: Arc<QueryContext>,
+    create_option: CreateOption,
+    catalog_name: &str,
+    database: &str,
+    table: &str,
+    source: &FuseTable,
+    navigation: Option<&NavigationPoint>,
+) -> Result<()> {
+    let catalog = ctx.get_catalog(catalog_name).await?;
+    let tenant = ctx.get_tenant();
+    if create_option == CreateOption::CreateIfNotExists
+        && catalog.exists_table(&tenant, database, table).await?
+    {
+        return Ok(());
+    }
+
+    let source_at = match navigation {
+        Some(point) => Some(
+            source
+                .navigate_to_point(point, ctx.clone().get_abort_checker())
+                .await?,
+        ),
+        None => None,
+    };
+    let source = source_at.as_deref().unwrap_or(source);
+    let source_meta = &source.get_table_info().meta;
+
+    let mut options = source_meta
+        .options
+        .iter()
+        .filter(|(key, _)| {
+            CREATE_FUSE_OPTIONS.contains(key.as_str())
+                && !is_reserved_opt_key(key)
+                && key.as_str() != OPT_KEY_TEMP_PREFIX
+        })
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect::<BTreeMap<_, _>>();
+    if !source_meta.comment.is_empty() {
+        options.insert(OPT_KEY_COMMENT.to_string(), source_meta.comment.clone());
+    }
+    let db = catalog.get_database(&tenant, database).await?;
+    options.insert(
+        OPT_KEY_DATABASE_ID.to_string(),
+        db.get_db_info().database_id.db_id.to_string(),
+    );
+    // The clone may reference the files of the source and of all the ancestors of the source.
+    let mut clone_sources = parse_clone_table_ids(source_meta.options.get(OPT_KEY_CLONE_SOURCES));
+    clone_sources.push(source.get_id());
+    options.insert(
+        OPT_KEY_CLONE_SOURCES.to_string(),
+        format_clone_table_ids(&clone_sources),
+    );
+
+    let plan = CreateTablePlan {
+        create_option,
+        tenant: tenant.clone(),
+        catalog: catalog_name.to_string(),
+        database: database.to_string(),
+        table: table.to_string(),
+        schema: source.schema(),
+        engine: Engine::Fuse,
+        engine_options: source_meta.engine_options.clone(),
+        storage_params: None,
+        options,
+        field_comments: source_meta.field_comments.clone(),
+        cluster_key: source_meta.cluster_key.clone(),
+        as_select: None,
+        inverted_indexes: (!source_meta.indexes.is_empty()).then(|| source_meta.indexes.clone()),
+    };
+    CreateTableInterpreter::try_create(ctx.clone(), plan)?
+        .execute2()
+        .await?;
+
+    let cloned = catalog.get_table(&tenant, database, table).await?;
+    let cloned = FuseTable::try_from_table(cloned.as_ref())?;
+    cloned.do_clone_from(ctx.as_ref(), source).await
+}
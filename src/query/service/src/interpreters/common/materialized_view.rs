@@ -0,0 +1,442 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::Utc;
+use bigbytesdb_common_ast::ast::quote::display_ident;
+use bigbytesdb_common_ast::ast::Query;
+use bigbytesdb_common_ast::ast::Statement;
+use bigbytesdb_common_ast::ast::TableReference;
+use bigbytesdb_common_ast::ast::TemporalClause;
+use bigbytesdb_common_ast::ast::TimeTravelPoint;
+use bigbytesdb_common_ast::parser::parse_sql;
+use bigbytesdb_common_ast::parser::tokenize_sql;
+use bigbytesdb_common_ast::parser::Dialect;
+use bigbytesdb_common_catalog::lock::Lock;
+use bigbytesdb_common_catalog::table::Table;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_meta_app::schema::UpsertTableOptionReq;
+use bigbytesdb_common_meta_types::MatchSeq;
+use bigbytesdb_common_sql::incremental_refresh;
+use bigbytesdb_common_sql::materialized_view_refresh_state;
+use bigbytesdb_common_sql::normalize_identifier;
+use bigbytesdb_common_sql::parse_table_reference;
+use bigbytesdb_common_sql::replace_table_references;
+use bigbytesdb_common_sql::table_references;
+use bigbytesdb_common_sql::IncrementalRefresh;
+use bigbytesdb_common_sql::NameResolutionContext;
+use bigbytesdb_common_sql::Planner;
+use bigbytesdb_common_storages_fuse::FuseTable;
+use bigbytesdb_common_storages_fuse::TableContext;
+use bigbytesdb_storages_common_table_meta::table::MaterializedViewRefreshState;
+use bigbytesdb_storages_common_table_meta::table::MaterializedViewSource;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESH_STATE;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use bigbytesdb_storages_common_table_meta::table::REFRESH_MODE_FULL;
+use bigbytesdb_storages_common_table_meta::table::REFRESH_MODE_INCREMENTAL;
+use futures_util::TryStreamExt;
+use log::info;
+use log::warn;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::locks::LockManager;
+use crate::sessions::QueryContext;
+
+/// A table read by the query of a materialized view.
+struct BaseTable {
+    table_ref: TableReference,
+    table: Arc<dyn Table>,
+    snapshot_id: Option<String>,
+    snapshot_location: Option<String>,
+}
+
+/// Refresh the materialized view to the current snapshots of its base tables.
+///
+/// The view is refreshed incrementally by the rows appended to the base tables since
+/// the last refresh if possible, otherwise the query is computed again. Refreshes of
+/// the same view are serialized by the table lock of the view.
+#[async_backtrace::framed]
+pub async fn refresh_materialized_view(
+    ctx: Arc<QueryContext>,
+    catalog_name: &str,
+    database: &str,
+    view: &str,
+) -> Result<()> {
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_catalog(catalog_name).await?;
+    let view_table = catalog.get_table(&tenant, database, view).await?;
+
+    // Concurrent refreshes would apply the same delta twice, the lock is held until the
+    // refresh state is recorded, regardless of `enable_table_lock`.
+    let _guard = LockManager::create_table_lock(view_table.get_table_info().clone())?
+        .try_lock(ctx.clone(), true)
+        .await?;
+    // The view may have been refreshed while waiting for the lock.
+    ctx.evict_table_from_cache(catalog_name, database, view)?;
+    let view_table = catalog.get_table(&tenant, database, view).await?;
+    let view_info = view_table.get_table_info();
+    let Some(view_query) = view_info.options().get(OPT_KEY_MATERIALIZED_VIEW_QUERY) else {
+        return Err(ErrorCode::IllegalMaterializedView(format!(
+            "{database}.{view} is not a materialized view"
+        )));
+    };
+
+    let settings = ctx.get_settings();
+    let dialect = settings.get_sql_dialect()?;
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let query = parse_query(view_query, dialect)?;
+
+    let mut base_tables = vec![];
+    for table_ref in table_references(&query) {
+        let TableReference::Table {
+            catalog,
+            database: table_database,
+            table,
+            ..
+        } = &table_ref
+        else {
+            continue;
+        };
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &name_resolution_ctx).name)
+            .unwrap_or_else(|| catalog_name.to_string());
+        let table_database = table_database
+            .as_ref()
+            .map(|database| normalize_identifier(database, &name_resolution_ctx).name)
+            .unwrap_or_else(|| database.to_string());
+        let table_name = normalize_identifier(table, &name_resolution_ctx).name;
+        let table = ctx
+            .get_catalog(&catalog)
+            .await?
+            .get_table(&tenant, &table_database, &table_name)
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let snapshot_id = fuse_table
+            .read_table_snapshot()
+            .await?
+            .map(|snapshot| snapshot.snapshot_id.simple().to_string());
+        base_tables.push(BaseTable {
+            table_ref: table_ref.clone(),
+            snapshot_location: fuse_table.snapshot_loc(),
+            table,
+            snapshot_id,
+        });
+    }
+
+    let state = materialized_view_refresh_state(view_info)?;
+    let refresh_mode = view_info
+        .options()
+        .get(OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE)
+        .map(String::as_str);
+    let incremental = match refresh_mode {
+        Some(REFRESH_MODE_FULL) => None,
+        _ => incremental_refresh(&query),
+    };
+
+    let view_name = format!(
+        "{}.{}",
+        display_ident(database, true, dialect),
+        display_ident(view, true, dialect)
+    );
+    let mut refreshed_mode = REFRESH_MODE_FULL;
+    if let (Some(incremental), Some(state)) = (incremental, &state) {
+        // The incremental refresh is based on the data of the last refresh.
+        if state.snapshot_location.as_ref() == view_info.options().get(OPT_KEY_SNAPSHOT_LOCATION) {
+            let view_columns = view_table
+                .schema()
+                .fields()
+                .iter()
+                .map(|field| display_ident(field.name(), true, dialect))
+                .collect::<Vec<_>>();
+            let result = match incremental_refresh_sql(
+                &query,
+                &incremental,
+                state,
+                &base_tables,
+                &view_name,
+                &view_columns,
+                dialect,
+            )
+            .await
+            {
+                Ok(Some(Some(sql))) => {
+                    info!("Refresh materialized view {view_name} incrementally: {sql}");
+                    execute_sql(&ctx, &sql).await.map(|_| true)
+                }
+                // No base table is changed.
+                Ok(Some(None)) => Ok(true),
+                Ok(None) => Ok(false),
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(true) => refreshed_mode = REFRESH_MODE_INCREMENTAL,
+                Ok(false) => {}
+                Err(e) => warn!(
+                    "Failed to refresh materialized view {view_name} incrementally, fallback to full refresh: {e}"
+                ),
+            }
+        }
+    }
+    if refreshed_mode == REFRESH_MODE_FULL {
+        let mut full_query = query.clone();
+        let replacements = base_tables
+            .iter()
+            .map(|base_table| match &base_table.snapshot_id {
+                Some(snapshot_id) => at_snapshot(&base_table.table_ref, snapshot_id),
+                None => base_table.table_ref.clone(),
+            })
+            .collect();
+        replace_table_references(&mut full_query, replacements);
+        let sql = format!("INSERT OVERWRITE {view_name} {full_query}");
+        info!("Refresh materialized view {view_name}: {sql}");
+        execute_sql(&ctx, &sql).await?;
+    }
+
+    // Record the snapshots the view is refreshed to.
+    let view_table = catalog.get_table(&tenant, database, view).await?;
+    let view_info = view_table.get_table_info();
+    let mut sources: Vec<MaterializedViewSource> = vec![];
+    for base_table in &base_tables {
+        let table_id = base_table.table.get_id();
+        if sources.iter().all(|source| source.table_id != table_id) {
+            sources.push(MaterializedViewSource {
+                table_id,
+                snapshot_id: base_table.snapshot_id.clone(),
+                snapshot_location: base_table.snapshot_location.clone(),
+            });
+        }
+    }
+    let state = MaterializedViewRefreshState {
+        snapshot_location: view_info.options().get(OPT_KEY_SNAPSHOT_LOCATION).cloned(),
+        refreshed_on: Utc::now(),
+        refresh_mode: refreshed_mode.to_string(),
+        sources,
+    };
+    let state = serde_json::to_string(&state)?;
+    let req = UpsertTableOptionReq {
+        table_id: view_info.ident.table_id,
+        seq: MatchSeq::Exact(view_info.ident.seq),
+        options: HashMap::from([(
+            OPT_KEY_MATERIALIZED_VIEW_REFRESH_STATE.to_string(),
+            Some(state),
+        )]),
+    };
+    catalog.upsert_table_option(&tenant, database, req).await?;
+    Ok(())
+}
+
+/// Build the statement refreshing the materialized view by the rows appended to the
+/// base tables since the last refresh, `Ok(Some(None))` if no base table is changed.
+///
+/// The rows derived from the appended rows are the union of the query over each table
+/// occurrence `i`, reading the appended rows of occurrence `i`, the occurrences before
+/// `i` at the current snapshots and the occurrences after `i` at the last refreshed
+/// snapshots, so each combination of old and new rows is counted once.
+async fn incremental_refresh_sql(
+    query: &Query,
+    incremental: &IncrementalRefresh,
+    state: &MaterializedViewRefreshState,
+    base_tables: &[BaseTable],
+    view_name: &str,
+    view_columns: &[String],
+    dialect: Dialect,
+) -> Result<Option<Option<String>>> {
+    let mut old_snapshots = Vec::with_capacity(base_tables.len());
+    for base_table in base_tables {
+        let Some(source) = state.source(base_table.table.get_id()) else {
+            return Ok(None);
+        };
+        old_snapshots.push(source);
+    }
+
+    let mut checked = HashSet::new();
+    let mut terms = vec![];
+    for (i, base_table) in base_tables.iter().enumerate() {
+        let old = old_snapshots[i];
+        if old.snapshot_location == base_table.snapshot_location {
+            continue;
+        }
+        let Some(new_snapshot_id) = &base_table.snapshot_id else {
+            return Ok(None);
+        };
+        let fuse_table = FuseTable::try_from_table(base_table.table.as_ref())?;
+        if checked.insert(base_table.table.get_id())
+            && !is_append_only(fuse_table, &old.snapshot_location).await?
+        {
+            return Ok(None);
+        }
+
+        let mut replacements = Vec::with_capacity(base_tables.len());
+        for (j, other) in base_tables.iter().enumerate() {
+            let snapshot_id = if j < i {
+                &other.snapshot_id
+            } else {
+                &old_snapshots[j].snapshot_id
+            };
+            let replacement = match (j == i, snapshot_id) {
+                (true, Some(old_snapshot_id)) => {
+                    changes_between(fuse_table, other, old_snapshot_id, new_snapshot_id, dialect)?
+                }
+                (true, None) => at_snapshot(&other.table_ref, new_snapshot_id),
+                (false, Some(snapshot_id)) => at_snapshot(&other.table_ref, snapshot_id),
+                // The table is empty, so is the result of the term.
+                (false, None) => break,
+            };
+            replacements.push(replacement);
+        }
+        if replacements.len() < base_tables.len() {
+            continue;
+        }
+        let mut term = query.clone();
+        replace_table_references(&mut term, replacements);
+        terms.push(term.to_string());
+    }
+
+    if terms.is_empty() {
+        return Ok(Some(None));
+    }
+    let delta = terms.join(" UNION ALL ");
+    let sql = match incremental {
+        IncrementalRefresh::Append => format!("INSERT INTO {view_name} {delta}"),
+        IncrementalRefresh::Merge(merge_functions) => {
+            let keys = view_columns
+                .iter()
+                .zip(merge_functions)
+                .filter(|(_, merge_function)| merge_function.is_none())
+                .map(|(column, _)| column.clone())
+                .collect::<Vec<_>>();
+            let items = view_columns
+                .iter()
+                .zip(merge_functions)
+                .map(|(column, merge_function)| match merge_function {
+                    Some(merge_function) => format!("{merge_function}({column}) AS {column}"),
+                    None => column.clone(),
+                })
+                .collect::<Vec<_>>();
+            let group_by = if keys.is_empty() {
+                String::new()
+            } else {
+                format!(" GROUP BY {}", keys.join(", "))
+            };
+            format!(
+                "INSERT OVERWRITE {view_name} SELECT {} FROM (SELECT * FROM {view_name} UNION ALL {delta}) AS _mv{group_by}",
+                items.join(", ")
+            )
+        }
+    };
+    Ok(Some(Some(sql)))
+}
+
+// The change is append only if the segments of the old snapshot are still in the table.
+async fn is_append_only(fuse_table: &FuseTable, old_location: &Option<String>) -> Result<bool> {
+    if !fuse_table.change_tracking_enabled() {
+        return Ok(false);
+    }
+    let Some(old_location) = old_location else {
+        return Ok(true);
+    };
+    let Some(new_snapshot) = fuse_table.read_table_snapshot().await? else {
+        return Ok(false);
+    };
+    let old_snapshot = fuse_table
+        .changes_read_offset_snapshot(old_location)
+        .await?;
+    let new_segments = new_snapshot.segments.iter().collect::<HashSet<_>>();
+    Ok(old_snapshot
+        .segments
+        .iter()
+        .all(|segment| new_segments.contains(segment)))
+}
+
+fn at_snapshot(table_ref: &TableReference, snapshot_id: &str) -> TableReference {
+    let mut table_ref = table_ref.clone();
+    if let TableReference::Table { temporal, .. } = &mut table_ref {
+        *temporal = Some(TemporalClause::TimeTravel(TimeTravelPoint::Snapshot(
+            snapshot_id.to_string(),
+        )));
+    }
+    table_ref
+}
+
+// The rows appended between the snapshots, with the columns of the table only.
+fn changes_between(
+    fuse_table: &FuseTable,
+    base_table: &BaseTable,
+    from: &str,
+    to: &str,
+    dialect: Dialect,
+) -> Result<TableReference> {
+    let TableReference::Table {
+        catalog,
+        database,
+        table,
+        alias,
+        ..
+    } = &base_table.table_ref
+    else {
+        unreachable!()
+    };
+    let columns = fuse_table
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| display_ident(field.name(), true, dialect))
+        .collect::<Vec<_>>();
+    let name = catalog
+        .iter()
+        .chain(database)
+        .chain(Some(table))
+        .map(|ident| ident.to_string())
+        .collect::<Vec<_>>()
+        .join(".");
+    let alias = match alias {
+        Some(alias) => alias.to_string(),
+        None => table.to_string(),
+    };
+    parse_table_reference(
+        &format!(
+            "(SELECT {} FROM {name} CHANGES (INFORMATION => APPEND_ONLY) AT (SNAPSHOT => '{from}') END (SNAPSHOT => '{to}')) AS {alias}",
+            columns.join(", ")
+        ),
+        dialect,
+    )
+}
+
+fn parse_query(sql: &str, dialect: Dialect) -> Result<Query> {
+    let tokens = tokenize_sql(sql)?;
+    match parse_sql(&tokens, dialect)?.0 {
+        Statement::Query(query) => Ok(*query),
+        _ => Err(ErrorCode::IllegalMaterializedView(format!(
+            "invalid query of materialized view: {sql}"
+        ))),
+    }
+}
+
+async fn execute_sql(ctx: &Arc<QueryContext>, sql: &str) -> Result<()> {
+    let ctx = ctx.get_current_session().create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx.clone()).await?;
+    stream.try_collect::<Vec<_>>().await?;
+    Ok(())
+}
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod clone;
 mod grant;
+mod materialized_view;
 mod metrics;
 mod notification;
 mod query_log;
@@ -23,7 +25,10 @@ mod util;
 
 pub mod table_option_validation;
 
+pub use clone::clone_table;
+pub use clone::cloneable_fuse_table;
 pub use grant::validate_grant_object_exists;
+pub use materialized_view::refresh_materialized_view;
 pub use notification::get_notification_client_config;
 pub use query_log::InterpreterQueryLog;
 pub use stream::dml_build_update_stream_req;
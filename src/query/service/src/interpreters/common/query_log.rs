@@ -27,6 +27,7 @@ use log::error;
 use log::info;
 use serde_json;
 
+use crate::history::SystemHistory;
 use crate::sessions::convert_query_log_timestamp;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -70,6 +71,7 @@ impl InterpreterQueryLog {
         info!(target: "bigbytesdb::log::query", "{}", event_str);
         // log the query event in the system log
         info!("query: {} becomes {:?}", event.query_id, event.log_type);
+        SystemHistory::instance().append_query_log(&event);
         QueryLogQueue::instance()?.append_data(event)
     }
 
@@ -14,15 +14,20 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashSet;
+use std::sync::Arc;
 use std::sync::LazyLock;
 
 use chrono::Duration;
 use bigbytesdb_common_ast::ast::Engine;
+use bigbytesdb_common_catalog::table_context::TableContext;
 use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_expression::types::DataType;
+use bigbytesdb_common_expression::DataSchema;
 use bigbytesdb_common_expression::TableSchemaRef;
 use bigbytesdb_common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use bigbytesdb_common_io::constants::DEFAULT_MIN_TABLE_LEVEL_DATA_RETENTION_PERIOD_IN_HOURS;
 use bigbytesdb_common_settings::Settings;
+use bigbytesdb_common_sql::parse_computed_expr;
 use bigbytesdb_common_sql::BloomIndexColumns;
 use bigbytesdb_common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use bigbytesdb_common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
@@ -30,15 +35,24 @@ use bigbytesdb_common_storages_fuse::FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS
 use bigbytesdb_common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use bigbytesdb_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use bigbytesdb_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
+use bigbytesdb_common_storages_fuse::MAX_NGRAM_SIZE;
 use bigbytesdb_storages_common_index::BloomIndex;
+use bigbytesdb_storages_common_table_meta::meta::supported_stat_type;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_CLONE_SOURCES;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_COMMENT;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_CONNECTION_NAME;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_ENGINE;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_LOCATION;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_MAX_STALENESS;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_NGRAM_SIZE;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_RANDOM_MAX_ARRAY_LEN;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_RANDOM_MAX_STRING_LEN;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_RANDOM_MIN_STRING_LEN;
@@ -46,6 +60,7 @@ use bigbytesdb_storages_common_table_meta::table::OPT_KEY_RANDOM_SEED;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_TTL;
 use log::error;
 
 /// Table option keys that can occur in 'create table statement'.
@@ -72,6 +87,17 @@ pub static CREATE_FUSE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new(
 
     r.insert("transient");
     r.insert(OPT_KEY_TEMP_PREFIX);
+
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MAX_STALENESS);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE);
+
+    r.insert(OPT_KEY_CLONE_SOURCES);
+
+    r.insert(OPT_KEY_TTL);
+    r.insert(OPT_KEY_PARTITION_BY);
+    r.insert(OPT_KEY_NGRAM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_NGRAM_SIZE);
     r
 });
 
@@ -109,6 +135,9 @@ pub static UNSET_TABLE_OPTIONS_WHITE_LIST: LazyLock<HashSet<&'static str>> = Laz
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
     r.insert(FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS);
+    r.insert(OPT_KEY_TTL);
+    r.insert(OPT_KEY_NGRAM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_NGRAM_SIZE);
     r
 });
 
@@ -194,6 +223,31 @@ pub fn is_valid_bloom_index_columns(
     Ok(())
 }
 
+pub fn is_valid_ngram_index_columns(
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> bigbytesdb_common_exception::Result<()> {
+    if let Some(value) = options.get(OPT_KEY_NGRAM_INDEX_COLUMNS) {
+        BloomIndexColumns::verify_definition(value, schema, BloomIndex::supported_ngram_type)
+            .map_err(|e| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "invalid ngram_index_columns {:?}: {}",
+                    value,
+                    e.message()
+                ))
+            })?;
+    }
+    if let Some(value) = options.get(OPT_KEY_NGRAM_SIZE) {
+        if !matches!(value.parse::<usize>(), Ok(1..=MAX_NGRAM_SIZE)) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "invalid ngram_size {:?}, it should be between 1 and {}",
+                value, MAX_NGRAM_SIZE
+            )));
+        }
+    }
+    Ok(())
+}
+
 pub fn is_valid_change_tracking(
     options: &BTreeMap<String, String>,
 ) -> bigbytesdb_common_exception::Result<()> {
@@ -211,3 +265,55 @@ pub fn is_valid_random_seed(
     }
     Ok(())
 }
+
+pub fn is_valid_ttl(
+    ctx: Arc<dyn TableContext>,
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> bigbytesdb_common_exception::Result<()> {
+    if let Some(value) = options.get(OPT_KEY_TTL) {
+        let schema = Arc::new(DataSchema::from(schema));
+        let expr = parse_computed_expr(ctx, schema, value).map_err(|e| {
+            ErrorCode::TableOptionInvalid(format!(
+                "invalid ttl option {:?}: {}",
+                value,
+                e.message()
+            ))
+        })?;
+        match expr.data_type().remove_nullable() {
+            DataType::Timestamp | DataType::Date => {}
+            other => {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "invalid ttl option {:?}, it must be a TIMESTAMP or DATE expression, but got {}",
+                    value, other
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_partition_by(
+    ctx: Arc<dyn TableContext>,
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> bigbytesdb_common_exception::Result<()> {
+    if let Some(value) = options.get(OPT_KEY_PARTITION_BY) {
+        let schema = Arc::new(DataSchema::from(schema));
+        let expr = parse_computed_expr(ctx, schema, value).map_err(|e| {
+            ErrorCode::TableOptionInvalid(format!(
+                "invalid partition by expression {:?}: {}",
+                value,
+                e.message()
+            ))
+        })?;
+        let data_type = expr.data_type();
+        if !supported_stat_type(data_type) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "invalid partition by expression {:?}, the type {} is not supported",
+                value, data_type
+            )));
+        }
+    }
+    Ok(())
+}
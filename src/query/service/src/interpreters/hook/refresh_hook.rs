@@ -92,10 +92,9 @@ async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
         plans.extend_from_slice(&agg_index_plans);
     }
 
-    // Generate sync inverted indexes.
-    let inverted_index_plans =
-        generate_refresh_inverted_index_plan(ctx.clone(), &desc, table).await?;
-    plans.extend_from_slice(&inverted_index_plans);
+    // Generate async table indexes, including inverted and vector indexes.
+    let table_index_plans = generate_refresh_table_index_plan(ctx.clone(), &desc, table).await?;
+    plans.extend_from_slice(&table_index_plans);
 
     // Generate virtual columns.
     if ctx
@@ -267,7 +266,7 @@ async fn build_refresh_index_plan(
         .await
 }
 
-async fn generate_refresh_inverted_index_plan(
+async fn generate_refresh_table_index_plan(
     ctx: Arc<QueryContext>,
     desc: &RefreshDesc,
     table: Arc<dyn Table>,
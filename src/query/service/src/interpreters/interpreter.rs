@@ -54,6 +54,7 @@ use super::hook::vacuum_hook::hook_disk_temp_dir;
 use super::hook::vacuum_hook::hook_vacuum_temp_files;
 use super::InterpreterMetrics;
 use super::InterpreterQueryLog;
+use crate::history::SystemHistory;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::executor::PipelinePullingExecutor;
@@ -64,6 +65,7 @@ use crate::sessions::QueriesQueueManager;
 use crate::sessions::QueryContext;
 use crate::sessions::QueryEntry;
 use crate::sessions::SessionManager;
+use crate::sessions::TenantQuotaManager;
 use crate::stream::DataBlockStream;
 use crate::stream::ProgressStream;
 use crate::stream::PullingExecutorStream;
@@ -187,6 +189,10 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>, has_profiles
     let typ = session.get_type();
     if typ.is_user_session() {
         SessionManager::instance().status.write().query_finish(now);
+        TenantQuotaManager::instance().record_scanned_bytes(
+            &ctx.get_tenant(),
+            ctx.get_scan_progress_value().bytes as u64,
+        );
     }
 
     if let Err(error) = InterpreterQueryLog::log_finish(ctx, now, error, has_profiles) {
@@ -249,19 +255,39 @@ async fn plan_sql(
         // If a lock is required, acquire the queue guard before
         // planning the statement, to avoid potential deadlocks.
         // See PR https://github.com/getbigbytes/bigbytesdb/pull/16632
-        let query_entry = QueryEntry::create_entry(&ctx, &extras, true)?;
-        let guard = QueriesQueueManager::instance().acquire(query_entry).await?;
+        let query_entry = QueryEntry::create_entry(&ctx, &extras, true)?
+            .with_workload_group()
+            .await?;
+        let guard = acquire_queue_guard(&ctx, query_entry).await?;
         let plan = planner.plan_stmt(&extras.statement, true).await?;
         Ok((plan, extras, guard))
     } else {
         // No lock is needed, plan the statement first, then acquire the queue guard.
         let plan = planner.plan_stmt(&extras.statement, true).await?;
-        let query_entry = QueryEntry::create(&ctx, &plan, &extras)?;
-        let guard = QueriesQueueManager::instance().acquire(query_entry).await?;
+        let query_entry = QueryEntry::create(&ctx, &plan, &extras)?
+            .with_workload_group()
+            .await?;
+        let guard = acquire_queue_guard(&ctx, query_entry).await?;
         Ok((plan, extras, guard))
     }
 }
 
+/// Acquire a running slot from the query queue, then admit the query against
+/// the quotas of the tenant if it is a heavy one.
+async fn acquire_queue_guard(
+    ctx: &Arc<QueryContext>,
+    query_entry: QueryEntry,
+) -> Result<AcquireQueueGuard> {
+    let need_acquire_to_queue = query_entry.need_acquire_to_queue;
+    let guard = QueriesQueueManager::instance().acquire(query_entry).await?;
+    if !need_acquire_to_queue {
+        return Ok(guard);
+    }
+
+    let permit = TenantQuotaManager::instance().admit(ctx).await?;
+    Ok(guard.with_tenant_quota_permit(permit))
+}
+
 fn attach_query_hash(ctx: &Arc<QueryContext>, stmt: &mut Option<Statement>, sql: &str) {
     let (query_hash, query_parameterized_hash) = if let Some(stmt) = stmt {
         let query_hash = format!("{:x}", Md5::digest(stmt.to_string()));
@@ -311,6 +337,7 @@ pub fn on_execution_finished(info: &ExecutionInfo, query_ctx: Arc<QueryContext>)
                 statistics_desc: get_statistics_desc(),
             })?
         );
+        SystemHistory::instance().append_profiles(&query_ctx, &query_profiles)?;
         let profiles_queue = ProfilesLogQueue::instance()?;
         profiles_queue.append_data(ProfilesLogElement {
             query_id: query_ctx.get_id(),
@@ -348,7 +375,9 @@ fn need_acquire_lock(ctx: Arc<QueryContext>, stmt: &Statement) -> bool {
         | Statement::TruncateTable(_) => true,
         Statement::OptimizeTable(OptimizeTableStmt { action, .. }) => matches!(
             action,
-            OptimizeTableAction::All | OptimizeTableAction::Compact { .. }
+            OptimizeTableAction::All
+                | OptimizeTableAction::Compact { .. }
+                | OptimizeTableAction::Expire
         ),
         Statement::AlterTable(AlterTableStmt { action, .. }) => matches!(
             action,
@@ -356,6 +385,8 @@ fn need_acquire_lock(ctx: Arc<QueryContext>, stmt: &Statement) -> bool {
                 | AlterTableAction::ModifyColumn {
                     action: ModifyColumnAction::SetDataType(_),
                 }
+                | AlterTableAction::DropPartition { .. }
+                | AlterTableAction::TruncatePartition { .. }
         ),
         _ => false,
     }
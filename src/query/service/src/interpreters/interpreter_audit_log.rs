@@ -0,0 +1,765 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use bigbytesdb_common_base::runtime::GlobalIORuntime;
+use bigbytesdb_common_base::runtime::TrySpawn;
+use bigbytesdb_common_config::GlobalConfig;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::SendableDataBlockStream;
+use bigbytesdb_common_license::license::Feature;
+use bigbytesdb_common_license::license_manager::LicenseManagerSwitch;
+use bigbytesdb_common_meta_app::principal::PrincipalIdentity;
+use bigbytesdb_common_meta_app::principal::UserIdentity;
+use bigbytesdb_common_meta_app::schema::GetIndexReq;
+use bigbytesdb_common_meta_app::schema::IndexNameIdent;
+use bigbytesdb_common_meta_app::schema::ListDroppedTableReq;
+use bigbytesdb_common_pipeline_core::ExecutionInfo;
+use bigbytesdb_common_pipeline_core::SourcePipeBuilder;
+use bigbytesdb_common_sql::plans::Plan;
+use bigbytesdb_common_storages_system::AuditLogElement;
+use bigbytesdb_common_storages_system::AuditLogQueue;
+use bigbytesdb_common_users::UserApiProvider;
+use bigbytesdb_enterprise_data_mask_feature::get_datamask_handler;
+use log::error;
+use log::info;
+use log::warn;
+use serde_json::json;
+use serde_json::Value;
+
+use crate::history::SystemHistory;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::convert_query_log_timestamp;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// The errors meaning that the object does not exist, the image of it is `None` then.
+const NOT_FOUND_CODES: [u16; 10] = [
+    ErrorCode::UNKNOWN_DATABASE,
+    ErrorCode::UNKNOWN_TABLE,
+    ErrorCode::UNKNOWN_USER,
+    ErrorCode::UNKNOWN_ROLE,
+    ErrorCode::UNKNOWN_DATAMASK,
+    ErrorCode::UNKNOWN_INDEX,
+    ErrorCode::UNKNOWN_STAGE,
+    ErrorCode::UNKNOWN_CONNECTION,
+    ErrorCode::UNKNOWN_NETWORK_POLICY,
+    ErrorCode::UNKNOWN_PASSWORD_POLICY,
+];
+
+/// An object that can be changed by an audited statement.
+#[derive(Clone)]
+enum AuditObject {
+    Database {
+        catalog: String,
+        database: String,
+    },
+    Table {
+        // `TABLE`, `VIEW`, `MATERIALIZED VIEW` or `STREAM`.
+        object_type: &'static str,
+        catalog: String,
+        database: String,
+        table: String,
+    },
+    /// The dropped tables of a database, or of all the databases if `database` is empty.
+    DroppedTables {
+        catalog: String,
+        database: String,
+    },
+    Index {
+        catalog: String,
+        index: String,
+    },
+    User(UserIdentity),
+    Role(String),
+    DataMask(String),
+    Udf(String),
+    Stage(String),
+    Connection(String),
+    NetworkPolicy(String),
+    PasswordPolicy(String),
+}
+
+impl AuditObject {
+    fn table_of(
+        object_type: &'static str,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> AuditObject {
+        AuditObject::Table {
+            object_type,
+            catalog: catalog.to_string(),
+            database: database.to_string(),
+            table: table.to_string(),
+        }
+    }
+
+    fn table(catalog: &str, database: &str, table: &str) -> AuditObject {
+        Self::table_of("TABLE", catalog, database, table)
+    }
+
+    fn view(catalog: &str, database: &str, view: &str) -> AuditObject {
+        Self::table_of("VIEW", catalog, database, view)
+    }
+
+    fn materialized_view(catalog: &str, database: &str, view: &str) -> AuditObject {
+        Self::table_of("MATERIALIZED VIEW", catalog, database, view)
+    }
+
+    fn stream(catalog: &str, database: &str, stream: &str) -> AuditObject {
+        Self::table_of("STREAM", catalog, database, stream)
+    }
+
+    fn database(catalog: &str, database: &str) -> AuditObject {
+        AuditObject::Database {
+            catalog: catalog.to_string(),
+            database: database.to_string(),
+        }
+    }
+
+    fn principal(principal: &PrincipalIdentity) -> AuditObject {
+        match principal {
+            PrincipalIdentity::User(user) => AuditObject::User(user.clone()),
+            PrincipalIdentity::Role(role) => AuditObject::Role(role.clone()),
+        }
+    }
+
+    fn object_type(&self) -> &'static str {
+        match self {
+            AuditObject::Database { .. } => "DATABASE",
+            AuditObject::Table { object_type, .. } => object_type,
+            AuditObject::DroppedTables { database, .. } if database.is_empty() => "CATALOG",
+            AuditObject::DroppedTables { .. } => "DATABASE",
+            AuditObject::Index { .. } => "INDEX",
+            AuditObject::User(_) => "USER",
+            AuditObject::Role(_) => "ROLE",
+            AuditObject::DataMask(_) => "DATA MASK",
+            AuditObject::Udf(_) => "FUNCTION",
+            AuditObject::Stage(_) => "STAGE",
+            AuditObject::Connection(_) => "CONNECTION",
+            AuditObject::NetworkPolicy(_) => "NETWORK POLICY",
+            AuditObject::PasswordPolicy(_) => "PASSWORD POLICY",
+        }
+    }
+
+    fn name(&self) -> String {
+        match self {
+            AuditObject::Database { catalog, database } => format!("{}.{}", catalog, database),
+            AuditObject::Table {
+                catalog,
+                database,
+                table,
+                ..
+            } => format!("{}.{}.{}", catalog, database, table),
+            AuditObject::DroppedTables { catalog, database } if database.is_empty() => {
+                catalog.clone()
+            }
+            AuditObject::DroppedTables { catalog, database } => {
+                format!("{}.{}", catalog, database)
+            }
+            AuditObject::Index { index, .. } => index.clone(),
+            AuditObject::User(user) => user.display().to_string(),
+            AuditObject::Role(role) => role.clone(),
+            AuditObject::DataMask(name)
+            | AuditObject::Udf(name)
+            | AuditObject::Stage(name)
+            | AuditObject::Connection(name)
+            | AuditObject::NetworkPolicy(name)
+            | AuditObject::PasswordPolicy(name) => name.clone(),
+        }
+    }
+
+    /// Read the id and the json of the object, `None` if it does not exist.
+    async fn image(&self, ctx: &QueryContext) -> Result<Option<(Option<u64>, Value)>> {
+        let tenant = ctx.get_tenant();
+        match self {
+            AuditObject::Database { catalog, database } => {
+                let catalog = ctx.get_catalog(catalog).await?;
+                let db = catalog.get_database(&tenant, database).await?;
+                let info = db.get_db_info();
+                let meta = &info.meta.data;
+                let value = json!({
+                    "name": db.name(),
+                    "engine": meta.engine,
+                    "engine_options": meta.engine_options,
+                    "options": meta.options,
+                    "comment": meta.comment,
+                    "created_on": meta.created_on,
+                    "updated_on": meta.updated_on,
+                });
+                Ok(Some((Some(info.database_id.db_id), value)))
+            }
+            AuditObject::Table {
+                catalog,
+                database,
+                table,
+                ..
+            } => {
+                let catalog = ctx.get_catalog(catalog).await?;
+                // Bypass the table cache of the query context, which may be stale after the change.
+                let table = catalog.get_table(&tenant, database, table).await?;
+                let info = table.get_table_info();
+                let value = json!({
+                    "name": info.name,
+                    "meta": info.meta,
+                });
+                Ok(Some((Some(table.get_id()), value)))
+            }
+            AuditObject::DroppedTables { catalog, database } => {
+                let catalog = ctx.get_catalog(catalog).await?;
+                let database = (!database.is_empty()).then_some(database);
+                let (tables, _) = catalog
+                    .get_drop_table_infos(ListDroppedTableReq::new4(&tenant, database, None, None))
+                    .await?;
+                let mut tables = tables
+                    .iter()
+                    .map(|table| (table.get_id(), table.get_table_info().desc.clone()))
+                    .collect::<Vec<_>>();
+                tables.sort();
+                let value = tables
+                    .into_iter()
+                    .map(|(id, name)| json!({ "id": id, "name": name }))
+                    .collect::<Vec<_>>();
+                Ok(Some((None, json!(value))))
+            }
+            AuditObject::Index { catalog, index } => {
+                let catalog = ctx.get_catalog(catalog).await?;
+                let reply = catalog
+                    .get_index(GetIndexReq {
+                        name_ident: IndexNameIdent::new(&tenant, index),
+                    })
+                    .await?;
+                let value = serde_json::to_value(&reply.index_meta)?;
+                Ok(Some((Some(reply.index_id), value)))
+            }
+            AuditObject::User(user) => {
+                let user = UserApiProvider::instance()
+                    .get_user(&tenant, user.clone())
+                    .await?;
+                let mut value = serde_json::to_value(&user)?;
+                // The password hashes are never written into the audit log.
+                if let Some(fields) = value.as_object_mut() {
+                    fields.remove("auth_info");
+                    fields.remove("history_auth_infos");
+                    fields.insert(
+                        "auth_type".to_string(),
+                        json!(user.auth_info.get_type().to_str()),
+                    );
+                }
+                Ok(Some((None, value)))
+            }
+            AuditObject::Role(role) => {
+                let role = UserApiProvider::instance()
+                    .get_role(&tenant, role.clone())
+                    .await?;
+                Ok(Some((None, serde_json::to_value(&role)?)))
+            }
+            AuditObject::DataMask(name) => {
+                // The data mask handler is only registered with the enterprise license.
+                if LicenseManagerSwitch::instance()
+                    .check_enterprise_enabled(ctx.get_license_key(), Feature::DataMask)
+                    .is_err()
+                {
+                    return Ok(None);
+                }
+                let meta_api = UserApiProvider::instance().get_meta_store_client();
+                let mask = get_datamask_handler()
+                    .get_data_mask(meta_api, &tenant, name.clone())
+                    .await?;
+                Ok(Some((None, serde_json::to_value(&mask)?)))
+            }
+            AuditObject::Udf(name) => {
+                let Some(udf) = UserApiProvider::instance().get_udf(&tenant, name).await? else {
+                    return Ok(None);
+                };
+                let value = json!({
+                    "name": udf.name,
+                    "description": udf.description,
+                    "definition": udf.definition.to_string(),
+                    "created_on": udf.created_on,
+                });
+                Ok(Some((None, value)))
+            }
+            AuditObject::Stage(name) => {
+                let stage = UserApiProvider::instance().get_stage(&tenant, name).await?;
+                let mut value = serde_json::to_value(&stage)?;
+                // The credentials of the storage are never written into the audit log.
+                if let Some(fields) = value.as_object_mut() {
+                    fields.insert(
+                        "stage_params".to_string(),
+                        json!({ "storage": stage.stage_params.storage.to_string() }),
+                    );
+                }
+                Ok(Some((None, value)))
+            }
+            AuditObject::Connection(name) => {
+                let connection = UserApiProvider::instance()
+                    .get_connection(&tenant, name)
+                    .await?;
+                // The values of the storage params may be credentials, only the keys are kept.
+                let value = json!({
+                    "name": connection.name,
+                    "storage_type": connection.storage_type,
+                    "storage_params": connection.storage_params.keys().collect::<Vec<_>>(),
+                });
+                Ok(Some((None, value)))
+            }
+            AuditObject::NetworkPolicy(name) => {
+                let policy = UserApiProvider::instance()
+                    .get_network_policy(&tenant, name)
+                    .await?;
+                Ok(Some((None, serde_json::to_value(&policy)?)))
+            }
+            AuditObject::PasswordPolicy(name) => {
+                let policy = UserApiProvider::instance()
+                    .get_password_policy(&tenant, name)
+                    .await?;
+                Ok(Some((None, serde_json::to_value(&policy)?)))
+            }
+        }
+    }
+}
+
+/// An object changed by an audited statement, and the new object if the statement renames it.
+struct AuditTarget {
+    object: AuditObject,
+    renamed: Option<AuditObject>,
+}
+
+impl AuditTarget {
+    fn of(object: AuditObject) -> AuditTarget {
+        AuditTarget {
+            object,
+            renamed: None,
+        }
+    }
+
+    fn renamed(object: AuditObject, renamed: AuditObject) -> AuditTarget {
+        AuditTarget {
+            object,
+            renamed: Some(renamed),
+        }
+    }
+}
+
+type Image = Option<(Option<u64>, String)>;
+
+struct Audit {
+    ctx: Arc<QueryContext>,
+    action: &'static str,
+    targets: Vec<AuditTarget>,
+}
+
+impl Audit {
+    /// Returns the action and the changed objects of the DDL and privilege statements,
+    /// `None` if the plan is not audited.
+    fn targets(plan: &Plan, current_catalog: &str) -> Option<(&'static str, Vec<AuditTarget>)> {
+        let one = |action: &'static str, object: AuditObject| {
+            Some((action, vec![AuditTarget::of(object)]))
+        };
+        match plan {
+            Plan::CreateDatabase(p) => one(
+                "CREATE DATABASE",
+                AuditObject::database(&p.catalog, &p.database),
+            ),
+            Plan::DropDatabase(p) => one(
+                "DROP DATABASE",
+                AuditObject::database(&p.catalog, &p.database),
+            ),
+            Plan::UndropDatabase(p) => one(
+                "UNDROP DATABASE",
+                AuditObject::database(&p.catalog, &p.database),
+            ),
+            Plan::RenameDatabase(p) => Some((
+                "RENAME DATABASE",
+                p.entities
+                    .iter()
+                    .map(|e| {
+                        AuditTarget::renamed(
+                            AuditObject::database(&e.catalog, &e.database),
+                            AuditObject::database(&e.catalog, &e.new_database),
+                        )
+                    })
+                    .collect(),
+            )),
+            Plan::CreateTable(p) => one(
+                "CREATE TABLE",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::DropTable(p) => one(
+                "DROP TABLE",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::UndropTable(p) => one(
+                "UNDROP TABLE",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::TruncateTable(p) => one(
+                "TRUNCATE TABLE",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::VacuumDropTable(p) => one("VACUUM DROP TABLE", AuditObject::DroppedTables {
+                catalog: p.catalog.clone(),
+                database: p.database.clone(),
+            }),
+            Plan::CloneTable(p) => one(
+                "CLONE TABLE",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::CloneDatabase(p) => one(
+                "CLONE DATABASE",
+                AuditObject::database(&p.create_database.catalog, &p.create_database.database),
+            ),
+            Plan::CreateSnapshotTag(p) => one(
+                "CREATE TAG",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::DropSnapshotTag(p) => one(
+                "DROP TAG",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::RenameTable(p) => Some(("RENAME TABLE", vec![AuditTarget::renamed(
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+                AuditObject::table(&p.catalog, &p.new_database, &p.new_table),
+            )])),
+            Plan::AddTableColumn(p) => one(
+                "ALTER TABLE",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::ModifyTableColumn(p) => one(
+                "ALTER TABLE",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::RenameTableColumn(p) => one(
+                "ALTER TABLE",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::DropTableColumn(p) => one(
+                "ALTER TABLE",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::AlterTableClusterKey(p) => one(
+                "ALTER TABLE",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::DropTableClusterKey(p) => one(
+                "ALTER TABLE",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::SetOptions(p) => one(
+                "ALTER TABLE",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::UnsetOptions(p) => one(
+                "ALTER TABLE",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::CreateView(p) => one(
+                "CREATE VIEW",
+                AuditObject::view(&p.catalog, &p.database, &p.view_name),
+            ),
+            Plan::AlterView(p) => one(
+                "ALTER VIEW",
+                AuditObject::view(&p.catalog, &p.database, &p.view_name),
+            ),
+            Plan::DropView(p) => one(
+                "DROP VIEW",
+                AuditObject::view(&p.catalog, &p.database, &p.view_name),
+            ),
+            Plan::CreateMaterializedView(p) => one(
+                "CREATE MATERIALIZED VIEW",
+                AuditObject::materialized_view(
+                    &p.create_table.catalog,
+                    &p.create_table.database,
+                    &p.create_table.table,
+                ),
+            ),
+            Plan::CreateStream(p) => one(
+                "CREATE STREAM",
+                AuditObject::stream(&p.catalog, &p.database, &p.stream_name),
+            ),
+            Plan::DropStream(p) => one(
+                "DROP STREAM",
+                AuditObject::stream(&p.catalog, &p.database, &p.stream_name),
+            ),
+            Plan::CreateIndex(p) => one("CREATE INDEX", AuditObject::Index {
+                catalog: current_catalog.to_string(),
+                index: p.index_name.clone(),
+            }),
+            Plan::DropIndex(p) => one("DROP INDEX", AuditObject::Index {
+                catalog: current_catalog.to_string(),
+                index: p.index.clone(),
+            }),
+            Plan::CreateUser(p) => one("CREATE USER", AuditObject::User(p.user.clone())),
+            Plan::AlterUser(p) => one("ALTER USER", AuditObject::User(p.user.clone())),
+            Plan::DropUser(p) => one("DROP USER", AuditObject::User(p.user.clone())),
+            Plan::CreateRole(p) => one("CREATE ROLE", AuditObject::Role(p.role_name.clone())),
+            Plan::AlterRole(p) => one("ALTER ROLE", AuditObject::Role(p.role_name.clone())),
+            Plan::DropRole(p) => one("DROP ROLE", AuditObject::Role(p.role_name.clone())),
+            Plan::GrantPriv(p) => one("GRANT", AuditObject::principal(&p.principal)),
+            Plan::GrantRole(p) => one("GRANT", AuditObject::principal(&p.principal)),
+            Plan::RevokePriv(p) => one("REVOKE", AuditObject::principal(&p.principal)),
+            Plan::RevokeRole(p) => one("REVOKE", AuditObject::principal(&p.principal)),
+            Plan::CreateDatamaskPolicy(p) => one(
+                "CREATE MASKING POLICY",
+                AuditObject::DataMask(p.name.clone()),
+            ),
+            Plan::DropDatamaskPolicy(p) => {
+                one("DROP MASKING POLICY", AuditObject::DataMask(p.name.clone()))
+            }
+            Plan::CreateUDF(p) => one("CREATE FUNCTION", AuditObject::Udf(p.udf.name.clone())),
+            Plan::AlterUDF(p) => one("ALTER FUNCTION", AuditObject::Udf(p.udf.name.clone())),
+            Plan::DropUDF(p) => one("DROP FUNCTION", AuditObject::Udf(p.udf.clone())),
+            Plan::CreateStage(p) => one(
+                "CREATE STAGE",
+                AuditObject::Stage(p.stage_info.stage_name.clone()),
+            ),
+            Plan::DropStage(p) => one("DROP STAGE", AuditObject::Stage(p.name.clone())),
+            Plan::CreateConnection(p) => {
+                one("CREATE CONNECTION", AuditObject::Connection(p.name.clone()))
+            }
+            Plan::DropConnection(p) => {
+                one("DROP CONNECTION", AuditObject::Connection(p.name.clone()))
+            }
+            Plan::CreateNetworkPolicy(p) => one(
+                "CREATE NETWORK POLICY",
+                AuditObject::NetworkPolicy(p.name.clone()),
+            ),
+            Plan::AlterNetworkPolicy(p) => one(
+                "ALTER NETWORK POLICY",
+                AuditObject::NetworkPolicy(p.name.clone()),
+            ),
+            Plan::DropNetworkPolicy(p) => one(
+                "DROP NETWORK POLICY",
+                AuditObject::NetworkPolicy(p.name.clone()),
+            ),
+            Plan::CreatePasswordPolicy(p) => one(
+                "CREATE PASSWORD POLICY",
+                AuditObject::PasswordPolicy(p.name.clone()),
+            ),
+            Plan::AlterPasswordPolicy(p) => one(
+                "ALTER PASSWORD POLICY",
+                AuditObject::PasswordPolicy(p.name.clone()),
+            ),
+            Plan::DropPasswordPolicy(p) => one(
+                "DROP PASSWORD POLICY",
+                AuditObject::PasswordPolicy(p.name.clone()),
+            ),
+            _ => None,
+        }
+    }
+
+    async fn image(&self, object: &AuditObject) -> Image {
+        match object.image(&self.ctx).await {
+            Ok(image) => image.map(|(id, value)| (id, value.to_string())),
+            Err(cause) if NOT_FOUND_CODES.contains(&cause.code()) => None,
+            Err(cause) => {
+                warn!(
+                    "fail to read {} {} for audit_log: {}",
+                    object.object_type(),
+                    object.name(),
+                    cause
+                );
+                None
+            }
+        }
+    }
+
+    async fn images_before(&self) -> Vec<Image> {
+        let mut images = Vec::with_capacity(self.targets.len());
+        for target in &self.targets {
+            images.push(self.image(&target.object).await);
+        }
+        images
+    }
+
+    async fn images_after(&self) -> Vec<Image> {
+        let mut images = Vec::with_capacity(self.targets.len());
+        for target in &self.targets {
+            let object = target.renamed.as_ref().unwrap_or(&target.object);
+            images.push(self.image(object).await);
+        }
+        images
+    }
+
+    async fn write_logs(&self, before: Vec<Image>, after: Vec<Image>) {
+        let events = match self.events(before, after) {
+            Ok(events) => events,
+            Err(cause) => {
+                error!("fail to write audit_log {:?}", cause);
+                return;
+            }
+        };
+        if events.is_empty() {
+            return;
+        }
+
+        for event in &events {
+            if let Err(cause) = Self::write_log(event) {
+                error!("fail to write audit_log {:?}", cause);
+            }
+        }
+
+        // The statement is done, the events are kept and retried until they are persisted.
+        if let Err(cause) = SystemHistory::instance().persist_audit(events).await {
+            error!(
+                "fail to persist audit events of query {} into system_history, will retry: {}",
+                self.ctx.get_id(),
+                cause
+            );
+        }
+    }
+
+    fn events(&self, before: Vec<Image>, after: Vec<Image>) -> Result<Vec<AuditLogElement>> {
+        let ctx = &self.ctx;
+        let event_time = convert_query_log_timestamp(SystemTime::now());
+        let tenant_id = ctx.get_tenant().tenant_name().to_string();
+        let cluster_id = GlobalConfig::instance().query.cluster_id.clone();
+        let node_id = ctx.get_cluster().local_id.clone();
+        let query_id = ctx.get_id();
+        let user_name = ctx.get_current_user()?.name;
+        let client_ip = ctx.get_client_address().unwrap_or_default();
+        let statement = ctx.get_query_str();
+
+        let mut events = Vec::with_capacity(self.targets.len());
+        for ((target, before), after) in self.targets.iter().zip(before).zip(after) {
+            // Such as `DROP ... IF EXISTS` of a missing object, nothing is changed.
+            if before.as_ref().map(|(_, value)| value) == after.as_ref().map(|(_, value)| value) {
+                continue;
+            }
+
+            let object = target.renamed.as_ref().unwrap_or(&target.object);
+            let object_id = after.as_ref().or(before.as_ref()).and_then(|(id, _)| *id);
+            events.push(AuditLogElement {
+                event_time,
+                tenant_id: tenant_id.clone(),
+                cluster_id: cluster_id.clone(),
+                node_id: node_id.clone(),
+                query_id: query_id.clone(),
+                user_name: user_name.clone(),
+                client_ip: client_ip.clone(),
+                action: self.action.to_string(),
+                object_type: object.object_type().to_string(),
+                object_name: object.name(),
+                object_id,
+                statement: statement.clone(),
+                old_value: before.map(|(_, value)| value),
+                new_value: after.map(|(_, value)| value),
+            });
+        }
+        Ok(events)
+    }
+
+    fn write_log(event: &AuditLogElement) -> Result<()> {
+        let event_str = serde_json::to_string(event)?;
+        // log the audit log in JSON format, which goes to the structured logger
+        info!(target: "bigbytesdb::log::audit", "{}", event_str);
+        AuditLogQueue::instance()?.append_data(event.clone())
+    }
+}
+
+/// Records the DDL and privilege statements into `system.audit_log`, along with the
+/// objects before and after the change.
+///
+/// It wraps the interpreter of the statement: the objects are read before the interpreter runs,
+/// and again after the change is done, which is at the end of the pipeline if it has one.
+/// Failed statements, and the statements that leave the objects unchanged, are not recorded.
+///
+/// The records go to the `bigbytesdb::log::audit` log target, and are persisted into
+/// `system_history.audit_history` before the statement returns, whether `log.history.on` is set
+/// or not. The records that fail to be persisted are never dropped, they are retried by
+/// the background task of [`SystemHistory`].
+pub struct AuditLogInterpreter {
+    inner: InterpreterPtr,
+    audit: Arc<Audit>,
+}
+
+impl AuditLogInterpreter {
+    /// Wrap the interpreter if the plan is audited, otherwise return it as is.
+    pub fn wrap(ctx: Arc<QueryContext>, plan: &Plan, inner: InterpreterPtr) -> InterpreterPtr {
+        // The tables of the system history are maintained by itself, not by the users.
+        let handler_type = ctx.get_current_session().get_type().to_string();
+        if SystemHistory::is_history_session(&handler_type) {
+            return inner;
+        }
+
+        match Audit::targets(plan, &ctx.get_current_catalog()) {
+            None => inner,
+            Some((action, targets)) => Arc::new(AuditLogInterpreter {
+                inner,
+                audit: Arc::new(Audit {
+                    ctx,
+                    action,
+                    targets,
+                }),
+            }),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AuditLogInterpreter {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn is_txn_command(&self) -> bool {
+        self.inner.is_txn_command()
+    }
+
+    fn is_ddl(&self) -> bool {
+        self.inner.is_ddl()
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let before = self.audit.images_before().await;
+        let mut build_res = self.inner.execute2().await?;
+
+        if build_res.main_pipeline.is_empty() {
+            let after = self.audit.images_after().await;
+            self.audit.write_logs(before, after).await;
+            return Ok(build_res);
+        }
+
+        // Such as `CREATE TABLE ... AS SELECT`, the change is done when the pipeline finishes.
+        let audit = self.audit.clone();
+        build_res
+            .main_pipeline
+            .set_on_finished(move |info: &ExecutionInfo| {
+                if info.res.is_ok() {
+                    GlobalIORuntime::instance().spawn(async move {
+                        let after = audit.images_after().await;
+                        audit.write_logs(before, after).await;
+                    });
+                }
+                Ok(())
+            });
+        Ok(build_res)
+    }
+
+    fn set_source_pipe_builder(&self, builder: Option<SourcePipeBuilder>) -> Result<()> {
+        self.inner.set_source_pipe_builder(builder)
+    }
+
+    fn inject_result(&self) -> Result<SendableDataBlockStream> {
+        self.inner.inject_result()
+    }
+}
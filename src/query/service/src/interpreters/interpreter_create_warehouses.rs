@@ -28,6 +28,7 @@ use crate::interpreters::util::AuditElement;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
+use crate::sessions::TenantQuotaManager;
 
 pub struct CreateWarehouseInterpreter {
     #[allow(dead_code)]
@@ -39,6 +40,26 @@ impl CreateWarehouseInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: CreateWarehousePlan) -> Result<Self> {
         Ok(CreateWarehouseInterpreter { ctx, plan })
     }
+
+    /// Create the warehouse, then drop it if the warehouses created concurrently
+    /// go beyond the `max_warehouses` quota of the tenant.
+    async fn create_warehouse(&self, selected_nodes: Vec<SelectedNode>) -> Result<()> {
+        let resources_management = GlobalInstance::get::<Arc<dyn ResourcesManagement>>();
+        resources_management
+            .create_warehouse(self.plan.warehouse.clone(), selected_nodes)
+            .await?;
+
+        if let Err(cause) = TenantQuotaManager::instance()
+            .check_warehouses(&self.ctx.get_tenant(), 0)
+            .await
+        {
+            resources_management
+                .drop_warehouse(self.plan.warehouse.clone())
+                .await?;
+            return Err(cause);
+        }
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -56,6 +77,10 @@ impl Interpreter for CreateWarehouseInterpreter {
         LicenseManagerSwitch::instance()
             .check_enterprise_enabled(self.ctx.get_license_key(), Feature::SystemManagement)?;
 
+        TenantQuotaManager::instance()
+            .check_warehouses(&self.ctx.get_tenant(), 1)
+            .await?;
+
         if let Some(warehouse_size) = self.plan.options.get("warehouse_size") {
             if !self.plan.nodes.is_empty() {
                 return Err(ErrorCode::InvalidArgument(
@@ -69,11 +94,7 @@ impl Interpreter for CreateWarehouseInterpreter {
                 ));
             };
 
-            GlobalInstance::get::<Arc<dyn ResourcesManagement>>()
-                .create_warehouse(self.plan.warehouse.clone(), vec![
-                    SelectedNode::Random(None);
-                    warehouse_size
-                ])
+            self.create_warehouse(vec![SelectedNode::Random(None); warehouse_size])
                 .await?;
 
             return Ok(PipelineBuildResult::create());
@@ -90,9 +111,7 @@ impl Interpreter for CreateWarehouseInterpreter {
             }
         }
 
-        GlobalInstance::get::<Arc<dyn ResourcesManagement>>()
-            .create_warehouse(self.plan.warehouse.clone(), selected_nodes)
-            .await?;
+        self.create_warehouse(selected_nodes).await?;
 
         let user_info = self.ctx.get_current_user()?;
         log::info!(
@@ -0,0 +1,158 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bigbytesdb_common_catalog::table::Table;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_meta_app::schema::CreateOption;
+use bigbytesdb_common_meta_app::schema::CreateTableReq;
+use bigbytesdb_common_meta_app::schema::TableMeta;
+use bigbytesdb_common_meta_app::schema::TableNameIdent;
+use bigbytesdb_common_sql::plans::CloneDatabasePlan;
+use bigbytesdb_common_sql::plans::DropDatabasePlan;
+use bigbytesdb_common_storages_fuse::TableContext;
+use bigbytesdb_common_storages_view::view_table::VIEW_ENGINE;
+use log::debug;
+use log::info;
+use log::warn;
+
+use crate::interpreters::common::clone_table;
+use crate::interpreters::common::cloneable_fuse_table;
+use crate::interpreters::CreateDatabaseInterpreter;
+use crate::interpreters::DropDatabaseInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct CloneDatabaseInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CloneDatabasePlan,
+}
+
+impl CloneDatabaseInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CloneDatabasePlan) -> Result<Self> {
+        Ok(CloneDatabaseInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CloneDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "CloneDatabaseInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[fastrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "clone_database_execute");
+
+        let plan = &self.plan.create_database;
+        let catalog = self.ctx.get_catalog(&plan.catalog).await?;
+        if plan.create_option == CreateOption::CreateIfNotExists
+            && catalog
+                .exists_database(&plan.tenant, &plan.database)
+                .await?
+        {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        // Resolve the source before creating anything.
+        let tables = catalog
+            .list_tables(&plan.tenant, &self.plan.source_database)
+            .await?;
+
+        CreateDatabaseInterpreter::try_create(self.ctx.clone(), plan.clone())?
+            .execute2()
+            .await?;
+
+        // A failed clone must not leave a database with only part of the tables behind.
+        if let Err(cause) = self.clone_tables(tables).await {
+            let drop_plan = DropDatabasePlan {
+                if_exists: true,
+                tenant: plan.tenant.clone(),
+                catalog: plan.catalog.clone(),
+                database: plan.database.clone(),
+            };
+            if let Err(e) = DropDatabaseInterpreter::try_create(self.ctx.clone(), drop_plan)?
+                .execute2()
+                .await
+            {
+                warn!(
+                    "clone database {}: failed to drop the partially cloned database, {}",
+                    plan.database, e
+                );
+            }
+            return Err(cause);
+        }
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+impl CloneDatabaseInterpreter {
+    /// Create the views and clone the fuse tables of the source database into the new database.
+    #[async_backtrace::framed]
+    async fn clone_tables(&self, tables: Vec<Arc<dyn Table>>) -> Result<()> {
+        let plan = &self.plan.create_database;
+        let catalog = self.ctx.get_catalog(&plan.catalog).await?;
+        for table in tables {
+            let table_info = table.get_table_info();
+            if table.engine() == VIEW_ENGINE {
+                let req = CreateTableReq {
+                    create_option: CreateOption::Create,
+                    name_ident: TableNameIdent {
+                        tenant: plan.tenant.clone(),
+                        db_name: plan.database.clone(),
+                        table_name: table.name().to_string(),
+                    },
+                    table_meta: TableMeta {
+                        engine: VIEW_ENGINE.to_string(),
+                        options: table_info.meta.options.clone(),
+                        ..Default::default()
+                    },
+                    as_dropped: false,
+                };
+                catalog.create_table(req).await?;
+                continue;
+            }
+
+            match cloneable_fuse_table(table.as_ref()) {
+                Ok(source) => {
+                    clone_table(
+                        self.ctx.clone(),
+                        CreateOption::Create,
+                        &plan.catalog,
+                        &plan.database,
+                        table.name(),
+                        source,
+                        None,
+                    )
+                    .await?
+                }
+                Err(e) => info!(
+                    "clone database {}: skip table {}, {}",
+                    plan.database,
+                    table.name(),
+                    e.message()
+                ),
+            }
+        }
+        Ok(())
+    }
+}
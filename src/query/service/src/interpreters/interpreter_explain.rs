@@ -336,18 +336,32 @@ impl ExplainInterpreter {
         let result = plan
             .format(metadata.clone(), Default::default())?
             .format_pretty()?;
-        let line_split_result: Vec<&str> = result.lines().collect();
+        let line_split_result = Self::with_plan_hints(&result, metadata);
         let formatted_plan = StringType::from_data(line_split_result);
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
     }
 
+    // Append the plan baseline in use and the optimizer hints that are not honored
+    // to the formatted plan.
+    fn with_plan_hints(result: &str, metadata: &MetadataRef) -> Vec<String> {
+        let mut lines: Vec<String> = result.lines().map(|line| line.to_string()).collect();
+        let metadata = metadata.read();
+        if let Some(plan_baseline) = &metadata.plan_hints().plan_baseline {
+            lines.push(format!("Plan baseline: {plan_baseline}"));
+        }
+        for hint in metadata.plan_hints().ignored.iter() {
+            lines.push(format!("Ignored hint: {hint}"));
+        }
+        lines
+    }
+
     pub fn explain_join_order(
         &self,
         plan: &PhysicalPlan,
         metadata: &MetadataRef,
     ) -> Result<Vec<DataBlock>> {
         let result = plan.format_join(metadata)?.format_pretty()?;
-        let line_split_result: Vec<&str> = result.lines().collect();
+        let line_split_result = Self::with_plan_hints(&result, metadata);
         let formatted_plan = StringType::from_data(line_split_result);
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
     }
@@ -87,6 +87,7 @@ use crate::interpreters::interpreter_unassign_warehouse_nodes::UnassignWarehouse
 use crate::interpreters::interpreter_use_warehouse::UseWarehouseInterpreter;
 use crate::interpreters::interpreter_view_describe::DescribeViewInterpreter;
 use crate::interpreters::AlterUserInterpreter;
+use crate::interpreters::AuditLogInterpreter;
 use crate::interpreters::CreateStreamInterpreter;
 use crate::interpreters::DescUserInterpreter;
 use crate::interpreters::DropStreamInterpreter;
@@ -111,7 +112,8 @@ impl InterpreterFactory {
             }
         })?;
 
-        Self::get_warehouses_interpreter(ctx, plan, Self::get_inner)
+        let interpreter = Self::get_warehouses_interpreter(ctx.clone(), plan, Self::get_inner)?;
+        Ok(AuditLogInterpreter::wrap(ctx, plan, interpreter))
     }
 
     pub fn get_warehouses_interpreter(
@@ -274,6 +276,9 @@ impl InterpreterFactory {
             Plan::CreateDatabase(create_database) => Ok(Arc::new(
                 CreateDatabaseInterpreter::try_create(ctx, *create_database.clone())?,
             )),
+            Plan::CloneDatabase(clone_database) => Ok(Arc::new(
+                CloneDatabaseInterpreter::try_create(ctx, *clone_database.clone())?,
+            )),
             Plan::DropDatabase(drop_database) => Ok(Arc::new(DropDatabaseInterpreter::try_create(
                 ctx,
                 *drop_database.clone(),
@@ -298,6 +303,10 @@ impl InterpreterFactory {
                 ctx,
                 *create_table.clone(),
             )?)),
+            Plan::CloneTable(clone_table) => Ok(Arc::new(CloneTableInterpreter::try_create(
+                ctx,
+                *clone_table.clone(),
+            )?)),
             Plan::DropTable(drop_table) => Ok(Arc::new(DropTableInterpreter::try_create(
                 ctx,
                 *drop_table.clone(),
@@ -357,6 +366,12 @@ impl InterpreterFactory {
                 ctx,
                 *purge.clone(),
             )?)),
+            Plan::OptimizeRefreshNgramIndex(refresh_ngram_index) => {
+                Ok(Arc::new(OptimizeRefreshNgramIndexInterpreter::try_create(
+                    ctx,
+                    *refresh_ngram_index.clone(),
+                )?))
+            }
             Plan::OptimizeCompactSegment(compact_segment) => Ok(Arc::new(
                 OptimizeCompactSegmentInterpreter::try_create(ctx, *compact_segment.clone())?,
             )),
@@ -418,6 +433,14 @@ impl InterpreterFactory {
             // dynamic tables
             Plan::CreateDynamicTable(_) => Err(ErrorCode::Unimplemented("todo")),
 
+            // materialized views
+            Plan::CreateMaterializedView(p) => Ok(Arc::new(
+                CreateMaterializedViewInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::RefreshMaterializedView(p) => Ok(Arc::new(
+                RefreshMaterializedViewInterpreter::try_create(ctx, *p.clone())?,
+            )),
+
             // Indexes
             Plan::CreateIndex(index) => Ok(Arc::new(CreateIndexInterpreter::try_create(
                 ctx,
@@ -495,6 +518,10 @@ impl InterpreterFactory {
                 ctx,
                 *drop_role.clone(),
             )?)),
+            Plan::AlterRole(alter_role) => Ok(Arc::new(AlterRoleInterpreter::try_create(
+                ctx,
+                *alter_role.clone(),
+            )?)),
             Plan::SetRole(set_role) => Ok(Arc::new(SetRoleInterpreter::try_create(
                 ctx,
                 *set_role.clone(),
@@ -580,6 +607,14 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateSnapshotTag(p) => Ok(Arc::new(CreateSnapshotTagInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropSnapshotTag(p) => Ok(Arc::new(DropSnapshotTagInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
             Plan::CreateDatamaskPolicy(p) => Ok(Arc::new(CreateDataMaskInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -625,6 +660,25 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreatePlanBaseline(p) => Ok(Arc::new(CreatePlanBaselineInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropPlanBaseline(p) => Ok(Arc::new(DropPlanBaselineInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::CreateWorkloadGroup(p) => Ok(Arc::new(
+                CreateWorkloadGroupInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::AlterWorkloadGroup(p) => Ok(Arc::new(AlterWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropWorkloadGroup(p) => Ok(Arc::new(DropWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
 
             Plan::CreateTask(p) => Ok(Arc::new(CreateTaskInterpreter::try_create(
                 ctx,
@@ -677,6 +731,9 @@ impl InterpreterFactory {
             Plan::InsertMultiTable(p) => {
                 Ok(InsertMultiTableInterpreter::try_create(ctx, *p.clone())?)
             }
+            Plan::InsertOverwritePartition(p) => Ok(Arc::new(
+                InsertOverwritePartitionInterpreter::try_create(ctx, *p.clone())?,
+            )),
             Plan::ExecuteImmediate(p) => Ok(Arc::new(ExecuteImmediateInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -0,0 +1,94 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_sql::execute_commit_statement;
+use bigbytesdb_common_sql::plans::InsertOverwritePartitionPlan;
+use bigbytesdb_common_sql::Planner;
+use futures_util::TryStreamExt;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct InsertOverwritePartitionInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: InsertOverwritePartitionPlan,
+}
+
+impl InsertOverwritePartitionInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: InsertOverwritePartitionPlan) -> Result<Self> {
+        Ok(InsertOverwritePartitionInterpreter { ctx, plan })
+    }
+
+    async fn replace_partition(&self) -> Result<()> {
+        let session = self.ctx.get_current_session();
+        execute_sql(session.create_query_context().await?, &self.plan.delete).await?;
+
+        // The inserted rows are checked against the partition when they are written.
+        let ctx = session.create_query_context().await?;
+        ctx.get_settings()
+            .set_insert_overwrite_partition(self.plan.predicate.clone())?;
+        execute_sql(ctx, &self.plan.insert).await
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for InsertOverwritePartitionInterpreter {
+    fn name(&self) -> &str {
+        "InsertOverwritePartitionInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // the delete and the insert are committed together,
+        // in the enclosing transaction if there is one.
+        let txn_mgr = self.ctx.txn_mgr();
+        let auto_commit = !txn_mgr.lock().is_active();
+        if auto_commit {
+            txn_mgr.lock().begin();
+        }
+
+        let res = self.replace_partition().await;
+        if auto_commit {
+            match res {
+                Ok(_) => execute_commit_statement(self.ctx.clone()).await?,
+                Err(e) => {
+                    txn_mgr.lock().clear();
+                    return Err(e);
+                }
+            }
+        } else {
+            res?;
+        }
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+async fn execute_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<()> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx.clone()).await?;
+    stream.try_collect::<Vec<_>>().await?;
+    Ok(())
+}
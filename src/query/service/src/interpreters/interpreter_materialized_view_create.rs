@@ -0,0 +1,76 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_meta_app::schema::CreateOption;
+use bigbytesdb_common_sql::invalidate_materialized_view_defs;
+use bigbytesdb_common_sql::plans::CreateMaterializedViewPlan;
+use bigbytesdb_common_storages_fuse::TableContext;
+use log::debug;
+
+use crate::interpreters::common::refresh_materialized_view;
+use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct CreateMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateMaterializedViewPlan,
+}
+
+impl CreateMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateMaterializedViewPlan) -> Result<Self> {
+        Ok(CreateMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "CreateMaterializedViewInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[fastrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "create_materialized_view_execute");
+
+        let plan = &self.plan.create_table;
+        let catalog = self.ctx.get_catalog(&plan.catalog).await?;
+        if plan.create_option == CreateOption::CreateIfNotExists
+            && catalog
+                .exists_table(&plan.tenant, &plan.database, &plan.table)
+                .await?
+        {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        CreateTableInterpreter::try_create(self.ctx.clone(), plan.clone())?
+            .execute2()
+            .await?;
+        invalidate_materialized_view_defs(&plan.tenant, &plan.catalog, &plan.database);
+        // Fill the view with the result of its query.
+        refresh_materialized_view(self.ctx.clone(), &plan.catalog, &plan.database, &plan.table)
+            .await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
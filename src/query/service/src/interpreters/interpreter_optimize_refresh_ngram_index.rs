@@ -0,0 +1,79 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bigbytesdb_common_catalog::lock::LockTableOption;
+use bigbytesdb_common_catalog::table::TableExt;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_sql::plans::OptimizeRefreshNgramIndexPlan;
+use bigbytesdb_common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct OptimizeRefreshNgramIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: OptimizeRefreshNgramIndexPlan,
+}
+
+impl OptimizeRefreshNgramIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: OptimizeRefreshNgramIndexPlan) -> Result<Self> {
+        Ok(OptimizeRefreshNgramIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for OptimizeRefreshNgramIndexInterpreter {
+    fn name(&self) -> &str {
+        "OptimizeRefreshNgramIndexInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let lock_guard = self
+            .ctx
+            .clone()
+            .acquire_table_lock(
+                &self.plan.catalog,
+                &self.plan.database,
+                &self.plan.table,
+                &LockTableOption::LockWithRetry,
+            )
+            .await?;
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(
+                &self.ctx.get_tenant(),
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await?;
+        // check mutability
+        table.check_mutable()?;
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        fuse_table.do_refresh_ngram_index(self.ctx.clone()).await?;
+
+        drop(lock_guard);
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_meta_app::principal::PlanBaseline;
+use bigbytesdb_common_sql::capture_plan_hints;
+use bigbytesdb_common_sql::plans::CreatePlanBaselinePlan;
+use bigbytesdb_common_sql::plans::Plan;
+use bigbytesdb_common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct CreatePlanBaselineInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreatePlanBaselinePlan,
+}
+
+impl CreatePlanBaselineInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreatePlanBaselinePlan) -> Result<Self> {
+        Ok(CreatePlanBaselineInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreatePlanBaselineInterpreter {
+    fn name(&self) -> &str {
+        "CreatePlanBaselineInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[fastrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "create_plan_baseline_execute");
+
+        let plan = &self.plan;
+        let Plan::Query {
+            s_expr, metadata, ..
+        } = plan.plan.as_ref()
+        else {
+            return Err(ErrorCode::Internal(
+                "The plan of CREATE PLAN BASELINE must be a query",
+            ));
+        };
+        let plan_hints = capture_plan_hints(s_expr, &metadata.read())?;
+
+        let plan_baseline = PlanBaseline {
+            name: plan.name.clone(),
+            fingerprint: plan.fingerprint.clone(),
+            query: plan.query.clone(),
+            plan_hints,
+            created_on: Utc::now(),
+        };
+        // The uniqueness of the name and of the pinned query is checked in one transaction.
+        UserApiProvider::instance()
+            .add_plan_baseline(&plan.tenant, plan_baseline, &plan.create_option)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
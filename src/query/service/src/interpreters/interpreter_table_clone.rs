@@ -0,0 +1,77 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_sql::plans::CloneTablePlan;
+use bigbytesdb_common_storages_fuse::TableContext;
+use log::debug;
+
+use crate::interpreters::common::clone_table;
+use crate::interpreters::common::cloneable_fuse_table;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct CloneTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CloneTablePlan,
+}
+
+impl CloneTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CloneTablePlan) -> Result<Self> {
+        Ok(CloneTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CloneTableInterpreter {
+    fn name(&self) -> &str {
+        "CloneTableInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[fastrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "clone_table_execute");
+
+        let plan = &self.plan;
+        let source = self
+            .ctx
+            .get_table(
+                &plan.source_catalog,
+                &plan.source_database,
+                &plan.source_table,
+            )
+            .await?;
+        let source = cloneable_fuse_table(source.as_ref())?;
+        clone_table(
+            self.ctx.clone(),
+            plan.create_option,
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+            source,
+            plan.navigation.as_ref(),
+        )
+        .await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
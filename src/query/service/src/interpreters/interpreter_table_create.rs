@@ -32,6 +32,7 @@ use bigbytesdb_common_meta_app::schema::CommitTableMetaReq;
 use bigbytesdb_common_meta_app::schema::CreateOption;
 use bigbytesdb_common_meta_app::schema::CreateTableReq;
 use bigbytesdb_common_meta_app::schema::TableIdent;
+use bigbytesdb_common_meta_app::schema::TableIndexType;
 use bigbytesdb_common_meta_app::schema::TableInfo;
 use bigbytesdb_common_meta_app::schema::TableMeta;
 use bigbytesdb_common_meta_app::schema::TableNameIdent;
@@ -61,8 +62,11 @@ use crate::interpreters::common::table_option_validation::is_valid_bloom_index_c
 use crate::interpreters::common::table_option_validation::is_valid_change_tracking;
 use crate::interpreters::common::table_option_validation::is_valid_create_opt;
 use crate::interpreters::common::table_option_validation::is_valid_data_retention_period;
+use crate::interpreters::common::table_option_validation::is_valid_ngram_index_columns;
+use crate::interpreters::common::table_option_validation::is_valid_partition_by;
 use crate::interpreters::common::table_option_validation::is_valid_random_seed;
 use crate::interpreters::common::table_option_validation::is_valid_row_per_block;
+use crate::interpreters::common::table_option_validation::is_valid_ttl;
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -109,7 +113,12 @@ impl Interpreter for CreateTableInterpreter {
             LicenseManagerSwitch::instance()
                 .check_enterprise_enabled(self.ctx.get_license_key(), ComputedColumn)?;
         }
-        if self.plan.inverted_indexes.is_some() {
+        let has_inverted_index = self.plan.inverted_indexes.as_ref().is_some_and(|indexes| {
+            indexes
+                .values()
+                .any(|index| index.index_type == TableIndexType::Inverted)
+        });
+        if has_inverted_index {
             LicenseManagerSwitch::instance()
                 .check_enterprise_enabled(self.ctx.get_license_key(), InvertedIndex)?;
         }
@@ -392,8 +401,14 @@ impl CreateTableInterpreter {
 
         is_valid_block_per_segment(&table_meta.options)?;
         is_valid_row_per_block(&table_meta.options)?;
+        // check ttl expression.
+        is_valid_ttl(self.ctx.clone(), &table_meta.options, schema.clone())?;
+        // check partition by expression.
+        is_valid_partition_by(self.ctx.clone(), &table_meta.options, schema.clone())?;
         // check bloom_index_columns.
-        is_valid_bloom_index_columns(&table_meta.options, schema)?;
+        is_valid_bloom_index_columns(&table_meta.options, schema.clone())?;
+        // check ngram_index_columns and ngram_size.
+        is_valid_ngram_index_columns(&table_meta.options, schema)?;
         is_valid_change_tracking(&table_meta.options)?;
         // check random seed
         is_valid_random_seed(&table_meta.options)?;
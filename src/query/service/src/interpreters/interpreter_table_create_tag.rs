@@ -0,0 +1,128 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Duration;
+use chrono::Utc;
+use bigbytesdb_common_catalog::table::Table;
+use bigbytesdb_common_catalog::table::TableExt;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_meta_app::schema::UpsertTableOptionReq;
+use bigbytesdb_common_meta_types::MatchSeq;
+use bigbytesdb_common_sql::plans::CreateSnapshotTagPlan;
+use bigbytesdb_common_storages_fuse::FuseTable;
+use bigbytesdb_storages_common_table_meta::table::format_snapshot_tags;
+use bigbytesdb_storages_common_table_meta::table::parse_snapshot_tags;
+use bigbytesdb_storages_common_table_meta::table::SnapshotTag;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_TAGS;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateSnapshotTagInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateSnapshotTagPlan,
+}
+
+impl CreateSnapshotTagInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateSnapshotTagPlan) -> Result<Self> {
+        Ok(CreateSnapshotTagInterpreter { ctx, plan })
+    }
+}
+
+/// Returns the Fuse table behind `table`, tags are only supported by Fuse tables.
+pub(crate) fn taggable_fuse_table(table: &dyn Table) -> Result<&FuseTable> {
+    FuseTable::try_from_table(table).map_err(|_| {
+        ErrorCode::Unimplemented(format!(
+            "TAG is only supported by FUSE tables, but table {} is of engine {}",
+            table.name(),
+            table.engine()
+        ))
+    })
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateSnapshotTagInterpreter {
+    fn name(&self) -> &str {
+        "CreateSnapshotTagInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
+        let table = catalog
+            .get_table(&tenant, &self.plan.database, &self.plan.table)
+            .await?;
+
+        // check mutability
+        table.check_mutable()?;
+        let fuse_table = taggable_fuse_table(table.as_ref())?;
+
+        let snapshot_location = match &self.plan.navigation {
+            Some(point) => fuse_table
+                .navigate_to_point(point, self.ctx.clone().get_abort_checker())
+                .await?
+                .snapshot_loc(),
+            None => fuse_table.snapshot_loc(),
+        };
+        let Some(snapshot_location) = snapshot_location else {
+            return Err(ErrorCode::TableHistoricalDataNotFound(
+                "Empty Table has no snapshot to tag",
+            ));
+        };
+
+        let now = Utc::now();
+        let mut tags = parse_snapshot_tags(table.options().get(OPT_KEY_SNAPSHOT_TAGS))?;
+        // expired tags no longer pin anything, forget them on the way
+        tags.retain(|_, tag| !tag.is_expired(now));
+        if tags.contains_key(&self.plan.tag) {
+            return Err(ErrorCode::SnapshotTagAlreadyExists(format!(
+                "Tag '{}' of table '{}.{}' already exists",
+                self.plan.tag, self.plan.database, self.plan.table
+            )));
+        }
+        tags.insert(self.plan.tag.clone(), SnapshotTag {
+            snapshot_location,
+            created_on: now,
+            expire_at: self
+                .plan
+                .retain_days
+                .map(|days| now + Duration::days(days as i64)),
+        });
+
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+            options: HashMap::from([(
+                OPT_KEY_SNAPSHOT_TAGS.to_string(),
+                Some(format_snapshot_tags(&tags)?),
+            )]),
+        };
+        catalog
+            .upsert_table_option(&tenant, &self.plan.database, req)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
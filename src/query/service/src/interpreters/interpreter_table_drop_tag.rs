@@ -0,0 +1,94 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use bigbytesdb_common_catalog::table::TableExt;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_meta_app::schema::UpsertTableOptionReq;
+use bigbytesdb_common_meta_types::MatchSeq;
+use bigbytesdb_common_sql::plans::DropSnapshotTagPlan;
+use bigbytesdb_storages_common_table_meta::table::format_snapshot_tags;
+use bigbytesdb_storages_common_table_meta::table::parse_snapshot_tags;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_TAGS;
+
+use crate::interpreters::interpreter_table_create_tag::taggable_fuse_table;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropSnapshotTagInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropSnapshotTagPlan,
+}
+
+impl DropSnapshotTagInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropSnapshotTagPlan) -> Result<Self> {
+        Ok(DropSnapshotTagInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropSnapshotTagInterpreter {
+    fn name(&self) -> &str {
+        "DropSnapshotTagInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
+        let table = catalog
+            .get_table(&tenant, &self.plan.database, &self.plan.table)
+            .await?;
+
+        // check mutability
+        table.check_mutable()?;
+        taggable_fuse_table(table.as_ref())?;
+
+        let now = Utc::now();
+        let mut tags = parse_snapshot_tags(table.options().get(OPT_KEY_SNAPSHOT_TAGS))?;
+        let dropped = tags.remove(&self.plan.tag);
+        if dropped.is_none_or(|tag| tag.is_expired(now)) {
+            return Err(ErrorCode::UnknownSnapshotTag(format!(
+                "Unknown tag '{}' of table '{}.{}'",
+                self.plan.tag, self.plan.database, self.plan.table
+            )));
+        }
+        tags.retain(|_, tag| !tag.is_expired(now));
+
+        // the files only kept for the tag are purged by the next gc
+        let value = (!tags.is_empty())
+            .then(|| format_snapshot_tags(&tags))
+            .transpose()?;
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+            options: HashMap::from([(OPT_KEY_SNAPSHOT_TAGS.to_string(), value)]),
+        };
+        catalog
+            .upsert_table_option(&tenant, &self.plan.database, req)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
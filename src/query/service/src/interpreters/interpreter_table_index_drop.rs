@@ -18,6 +18,7 @@ use bigbytesdb_common_exception::Result;
 use bigbytesdb_common_license::license::Feature;
 use bigbytesdb_common_license::license_manager::LicenseManagerSwitch;
 use bigbytesdb_common_meta_app::schema::DropTableIndexReq;
+use bigbytesdb_common_meta_app::schema::TableIndexType;
 use bigbytesdb_common_sql::plans::DropTableIndexPlan;
 use bigbytesdb_common_storages_fuse::TableContext;
 use bigbytesdb_enterprise_inverted_index::get_inverted_index_handler;
@@ -49,8 +50,11 @@ impl Interpreter for DropTableIndexInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        LicenseManagerSwitch::instance()
-            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
+        let index_type = self.plan.index_type;
+        if index_type == TableIndexType::Inverted {
+            LicenseManagerSwitch::instance()
+                .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
+        }
 
         let index_name = self.plan.index_name.clone();
         let table_id = self.plan.table_id;
@@ -63,8 +67,15 @@ impl Interpreter for DropTableIndexInterpreter {
             name: index_name,
         };
 
-        let handler = get_inverted_index_handler();
-        let _ = handler.do_drop_table_index(catalog, drop_index_req).await?;
+        match index_type {
+            TableIndexType::Inverted => {
+                let handler = get_inverted_index_handler();
+                let _ = handler.do_drop_table_index(catalog, drop_index_req).await?;
+            }
+            TableIndexType::Vector => {
+                catalog.drop_table_index(drop_index_req).await?;
+            }
+        }
 
         Ok(PipelineBuildResult::create())
     }
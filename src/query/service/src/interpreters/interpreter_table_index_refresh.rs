@@ -20,6 +20,7 @@ use bigbytesdb_common_exception::Result;
 use bigbytesdb_common_expression::TableSchemaRefExt;
 use bigbytesdb_common_license::license::Feature;
 use bigbytesdb_common_license::license_manager::LicenseManagerSwitch;
+use bigbytesdb_common_meta_app::schema::TableIndexType;
 use bigbytesdb_common_sql::plans::RefreshTableIndexPlan;
 use bigbytesdb_common_storages_fuse::FuseTable;
 use bigbytesdb_common_storages_fuse::TableContext;
@@ -51,9 +52,6 @@ impl Interpreter for RefreshTableIndexInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        LicenseManagerSwitch::instance()
-            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
-
         let table = self
             .ctx
             .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
@@ -66,10 +64,21 @@ impl Interpreter for RefreshTableIndexInterpreter {
         let table_meta = &table.get_table_info().meta;
         let Some(index) = table_meta.indexes.get(&index_name) else {
             return Err(ErrorCode::RefreshIndexError(format!(
-                "Inverted index {} does not exist",
+                "Index {} does not exist",
                 index_name
             )));
         };
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        if index.index_type == TableIndexType::Vector {
+            fuse_table
+                .do_refresh_vector_index(self.ctx.clone(), index, segment_locs)
+                .await?;
+            return Ok(PipelineBuildResult::create());
+        }
+
+        LicenseManagerSwitch::instance()
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
         let mut index_fields = Vec::with_capacity(index.column_ids.len());
         for column_id in &index.column_ids {
             for field in &table_meta.schema.fields {
@@ -89,8 +98,6 @@ impl Interpreter for RefreshTableIndexInterpreter {
         let index_schema = TableSchemaRefExt::create(index_fields);
 
         let mut build_res = PipelineBuildResult::create();
-
-        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
         fuse_table
             .do_refresh_inverted_index(
                 self.ctx.clone(),
@@ -55,6 +55,7 @@ use bigbytesdb_enterprise_data_mask_feature::get_datamask_handler;
 use bigbytesdb_storages_common_index::BloomIndex;
 use bigbytesdb_storages_common_table_meta::meta::SnapshotId;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
 use crate::interpreters::Interpreter;
@@ -200,6 +201,12 @@ impl ModifyTableColumnInterpreter {
                 bloom_index_cols = cols;
             }
         }
+        let mut ngram_index_cols = vec![];
+        if let Some(v) = table_info.options().get(OPT_KEY_NGRAM_INDEX_COLUMNS) {
+            if let BloomIndexColumns::Specify(cols) = v.parse::<BloomIndexColumns>()? {
+                ngram_index_cols = cols;
+            }
+        }
 
         let mut table_info = table.get_table_info().clone();
         table_info.meta.fill_field_comments();
@@ -217,6 +224,14 @@ impl ModifyTableColumnInterpreter {
                             field.data_type
                         )));
                     }
+                    if ngram_index_cols.iter().any(|v| v.as_str() == field.name)
+                        && !BloomIndex::supported_ngram_type(&field.data_type)
+                    {
+                        return Err(ErrorCode::TableOptionInvalid(format!(
+                            "Unsupported data type '{}' for ngram index",
+                            field.data_type
+                        )));
+                    }
                     // If the column is inverted index column, the type can't be changed.
                     if !table_info.meta.indexes.is_empty() {
                         for (index_name, index) in &table_info.meta.indexes {
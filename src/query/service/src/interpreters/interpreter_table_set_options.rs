@@ -23,10 +23,12 @@ use bigbytesdb_common_meta_app::schema::UpsertTableOptionReq;
 use bigbytesdb_common_meta_types::MatchSeq;
 use bigbytesdb_common_sql::plans::SetOptionsPlan;
 use bigbytesdb_common_storages_fuse::TableContext;
+use bigbytesdb_storages_common_table_meta::table::is_reserved_opt_key;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING_BEGIN_VER;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use log::error;
@@ -35,7 +37,9 @@ use crate::interpreters::common::table_option_validation::is_valid_block_per_seg
 use crate::interpreters::common::table_option_validation::is_valid_bloom_index_columns;
 use crate::interpreters::common::table_option_validation::is_valid_create_opt;
 use crate::interpreters::common::table_option_validation::is_valid_data_retention_period;
+use crate::interpreters::common::table_option_validation::is_valid_ngram_index_columns;
 use crate::interpreters::common::table_option_validation::is_valid_row_per_block;
+use crate::interpreters::common::table_option_validation::is_valid_ttl;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -102,6 +106,13 @@ impl Interpreter for SetOptionsInterpreter {
                 OPT_KEY_CLUSTER_TYPE
             )));
         }
+        if self.plan.set_options.contains_key(OPT_KEY_PARTITION_BY) {
+            error!("{}", &error_str);
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "can't change {} for alter table statement",
+                OPT_KEY_PARTITION_BY
+            )));
+        }
         let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
         let database = self.plan.database.as_str();
         let table_name = self.plan.table.as_str();
@@ -111,6 +122,12 @@ impl Interpreter for SetOptionsInterpreter {
 
         for table_option in self.plan.set_options.iter() {
             let key = table_option.0.to_lowercase();
+            if is_reserved_opt_key(&key) {
+                error!("{}", &error_str);
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "can't change {key} for alter table statement",
+                )));
+            }
             let engine = Engine::from(table.engine());
             if !is_valid_create_opt(&key, &engine) {
                 error!("{}", &error_str);
@@ -139,6 +156,10 @@ impl Interpreter for SetOptionsInterpreter {
 
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
+        // check ngram_index_columns and ngram_size.
+        is_valid_ngram_index_columns(&self.plan.set_options, table.schema())?;
+        // check ttl expression.
+        is_valid_ttl(self.ctx.clone(), &self.plan.set_options, table.schema())?;
 
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
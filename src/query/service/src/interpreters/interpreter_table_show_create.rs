@@ -222,8 +222,9 @@ impl ShowCreateTableInterpreter {
                     options.push(option);
                 }
                 let mut index_str = format!(
-                    "  {} INVERTED INDEX {} ({})",
+                    "  {} {} INDEX {} ({})",
                     sync,
+                    index_field.index_type,
                     display_ident(&index_field.name, quoted_ident_case_sensitive, sql_dialect),
                     column_names_str
                 );
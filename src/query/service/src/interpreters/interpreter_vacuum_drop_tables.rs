@@ -31,6 +31,7 @@ use bigbytesdb_common_meta_app::schema::DroppedId;
 use bigbytesdb_common_meta_app::schema::GcDroppedTableReq;
 use bigbytesdb_common_meta_app::schema::ListDroppedTableReq;
 use bigbytesdb_common_sql::plans::VacuumDropTablePlan;
+use bigbytesdb_common_storages_fuse::FuseTable;
 use bigbytesdb_common_storages_view::view_table::VIEW_ENGINE;
 use bigbytesdb_enterprise_vacuum_handler::get_vacuum_handler;
 use log::info;
@@ -182,9 +183,30 @@ impl Interpreter for VacuumDropTablesInterpreter {
             tables.len()
         );
 
+        // Tables sharing files with live clones are kept, along with their meta data,
+        // until the last clone referencing them is vacuumed.
+        let mut shared_tables = HashSet::new();
+        let mut purgeable_tables = Vec::with_capacity(tables.len());
+        for table in tables {
+            if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+                let live_clones = fuse_table.live_clone_ids(self.ctx.as_ref()).await?;
+                if !live_clones.is_empty() {
+                    info!(
+                        "table {} is referenced by clones {:?}, skip purging its data",
+                        table.get_table_info().desc,
+                        live_clones
+                    );
+                    shared_tables.insert(table.get_id());
+                    continue;
+                }
+            }
+            purgeable_tables.push(table);
+        }
+        let tables = purgeable_tables;
+
         let handler = get_vacuum_handler();
         let threads_nums = self.ctx.get_settings().get_max_threads()? as usize;
-        let (files_opt, failed_tables) = handler
+        let (files_opt, mut failed_tables) = handler
             .do_vacuum_drop_tables(
                 threads_nums,
                 tables,
@@ -195,6 +217,7 @@ impl Interpreter for VacuumDropTablesInterpreter {
                 },
             )
             .await?;
+        failed_tables.extend(shared_tables);
 
         let failed_db_ids = failed_tables
             .iter()
@@ -18,6 +18,7 @@ mod hook;
 mod interpreter;
 mod interpreter_add_warehouse_cluster;
 mod interpreter_assign_warehouse_nodes;
+mod interpreter_audit_log;
 mod interpreter_catalog_create;
 mod interpreter_catalog_drop;
 mod interpreter_catalog_show_create;
@@ -35,6 +36,7 @@ mod interpreter_create_warehouses;
 mod interpreter_data_mask_create;
 mod interpreter_data_mask_desc;
 mod interpreter_data_mask_drop;
+mod interpreter_database_clone;
 mod interpreter_database_create;
 mod interpreter_database_drop;
 mod interpreter_database_rename;
@@ -57,8 +59,11 @@ mod interpreter_index_drop;
 mod interpreter_index_refresh;
 mod interpreter_insert;
 mod interpreter_insert_multi_table;
+mod interpreter_insert_partition;
 mod interpreter_inspect_warehouse;
 mod interpreter_kill;
+mod interpreter_materialized_view_create;
+mod interpreter_materialized_view_refresh;
 mod interpreter_metrics;
 mod interpreter_mutation;
 mod interpreter_network_policies_show;
@@ -73,10 +78,13 @@ mod interpreter_notification_drop;
 mod interpreter_optimize_compact_block;
 mod interpreter_optimize_compact_segment;
 mod interpreter_optimize_purge;
+mod interpreter_optimize_refresh_ngram_index;
 mod interpreter_password_policy_alter;
 mod interpreter_password_policy_create;
 mod interpreter_password_policy_desc;
 mod interpreter_password_policy_drop;
+mod interpreter_plan_baseline_create;
+mod interpreter_plan_baseline_drop;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
@@ -88,6 +96,7 @@ mod interpreter_rename_warehouse;
 mod interpreter_rename_warehouse_cluster;
 mod interpreter_replace;
 mod interpreter_resume_warehouse;
+mod interpreter_role_alter;
 mod interpreter_role_create;
 mod interpreter_role_drop;
 mod interpreter_role_grant;
@@ -108,10 +117,13 @@ mod interpreter_suspend_warehouse;
 mod interpreter_system_action;
 mod interpreter_table_add_column;
 mod interpreter_table_analyze;
+mod interpreter_table_clone;
 mod interpreter_table_create;
+mod interpreter_table_create_tag;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
 mod interpreter_table_drop_column;
+mod interpreter_table_drop_tag;
 mod interpreter_table_exists;
 mod interpreter_table_index_create;
 mod interpreter_table_index_drop;
@@ -161,6 +173,9 @@ mod interpreter_virtual_column_alter;
 mod interpreter_virtual_column_create;
 mod interpreter_virtual_column_drop;
 mod interpreter_virtual_column_refresh;
+mod interpreter_workload_group_alter;
+mod interpreter_workload_group_create;
+mod interpreter_workload_group_drop;
 mod util;
 
 pub use access::ManagementModeAccess;
@@ -169,6 +184,7 @@ pub use hook::HookOperator;
 pub use interpreter::interpreter_plan_sql;
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
+pub use interpreter_audit_log::AuditLogInterpreter;
 pub use interpreter_catalog_use::UseCatalogInterpreter;
 pub use interpreter_cluster_key_alter::AlterTableClusterKeyInterpreter;
 pub use interpreter_cluster_key_drop::DropTableClusterKeyInterpreter;
@@ -176,6 +192,7 @@ pub use interpreter_clustering_history::InterpreterClusteringHistory;
 pub use interpreter_data_mask_create::CreateDataMaskInterpreter;
 pub use interpreter_data_mask_desc::DescDataMaskInterpreter;
 pub use interpreter_data_mask_drop::DropDataMaskInterpreter;
+pub use interpreter_database_clone::CloneDatabaseInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
 pub use interpreter_database_rename::RenameDatabaseInterpreter;
@@ -188,7 +205,10 @@ pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_index_refresh::RefreshIndexInterpreter;
 pub use interpreter_insert::InsertInterpreter;
 pub use interpreter_insert_multi_table::InsertMultiTableInterpreter;
+pub use interpreter_insert_partition::InsertOverwritePartitionInterpreter;
 pub use interpreter_kill::KillInterpreter;
+pub use interpreter_materialized_view_create::CreateMaterializedViewInterpreter;
+pub use interpreter_materialized_view_refresh::RefreshMaterializedViewInterpreter;
 pub use interpreter_metrics::InterpreterMetrics;
 pub use interpreter_mutation::MutationInterpreter;
 pub use interpreter_network_policies_show::ShowNetworkPoliciesInterpreter;
@@ -199,14 +219,18 @@ pub use interpreter_network_policy_drop::DropNetworkPolicyInterpreter;
 pub use interpreter_optimize_compact_block::OptimizeCompactBlockInterpreter;
 pub use interpreter_optimize_compact_segment::OptimizeCompactSegmentInterpreter;
 pub use interpreter_optimize_purge::OptimizePurgeInterpreter;
+pub use interpreter_optimize_refresh_ngram_index::OptimizeRefreshNgramIndexInterpreter;
 pub use interpreter_password_policy_alter::AlterPasswordPolicyInterpreter;
 pub use interpreter_password_policy_create::CreatePasswordPolicyInterpreter;
 pub use interpreter_password_policy_desc::DescPasswordPolicyInterpreter;
 pub use interpreter_password_policy_drop::DropPasswordPolicyInterpreter;
+pub use interpreter_plan_baseline_create::CreatePlanBaselineInterpreter;
+pub use interpreter_plan_baseline_drop::DropPlanBaselineInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_procedure_desc::DescProcedureInterpreter;
 pub use interpreter_replace::ReplaceInterpreter;
+pub use interpreter_role_alter::AlterRoleInterpreter;
 pub use interpreter_role_create::CreateRoleInterpreter;
 pub use interpreter_role_drop::DropRoleInterpreter;
 pub use interpreter_role_grant::GrantRoleInterpreter;
@@ -223,10 +247,13 @@ pub use interpreter_stream_drop::DropStreamInterpreter;
 pub use interpreter_system_action::SystemActionInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
+pub use interpreter_table_clone::CloneTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
+pub use interpreter_table_create_tag::CreateSnapshotTagInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
 pub use interpreter_table_drop_column::DropTableColumnInterpreter;
+pub use interpreter_table_drop_tag::DropSnapshotTagInterpreter;
 pub use interpreter_table_exists::ExistsTableInterpreter;
 pub use interpreter_table_index_create::CreateTableIndexInterpreter;
 pub use interpreter_table_index_drop::DropTableIndexInterpreter;
@@ -262,3 +289,6 @@ pub use interpreter_virtual_column_alter::AlterVirtualColumnInterpreter;
 pub use interpreter_virtual_column_create::CreateVirtualColumnInterpreter;
 pub use interpreter_virtual_column_drop::DropVirtualColumnInterpreter;
 pub use interpreter_virtual_column_refresh::RefreshVirtualColumnInterpreter;
+pub use interpreter_workload_group_alter::AlterWorkloadGroupInterpreter;
+pub use interpreter_workload_group_create::CreateWorkloadGroupInterpreter;
+pub use interpreter_workload_group_drop::DropWorkloadGroupInterpreter;
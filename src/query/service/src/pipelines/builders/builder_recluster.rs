@@ -32,6 +32,7 @@ use bigbytesdb_common_sql::executor::physical_plans::Recluster;
 use bigbytesdb_common_sql::StreamContext;
 use bigbytesdb_common_storages_factory::Table;
 use bigbytesdb_common_storages_fuse::operations::TransformSerializeBlock;
+use bigbytesdb_common_storages_fuse::operations::TransformSplitPartition;
 use bigbytesdb_common_storages_fuse::FuseTable;
 use bigbytesdb_common_storages_fuse::TableContext;
 
@@ -168,6 +169,17 @@ impl PipelineBuilder {
                     max_threads,
                 )?;
 
+                // Blocks of a partitioned table never span partitions.
+                let partition_gen = table.partition_gen(
+                    self.ctx.clone(),
+                    &table.schema_with_stream().remove_virtual_computed_fields(),
+                )?;
+                if partition_gen.is_partitioned() {
+                    self.main_pipeline.add_accumulating_transformer(|| {
+                        TransformSplitPartition::create(partition_gen.clone())
+                    });
+                }
+
                 self.main_pipeline
                     .add_transform(|transform_input_port, transform_output_port| {
                         let proc = TransformSerializeBlock::try_create(
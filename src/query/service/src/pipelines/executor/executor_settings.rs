@@ -15,9 +15,12 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use bigbytesdb_common_base::runtime::MemStat;
 use bigbytesdb_common_catalog::table_context::TableContext;
 use bigbytesdb_common_exception::Result;
 
+use crate::sessions::WorkloadGroupResourceManager;
+
 #[derive(Clone)]
 pub struct ExecutorSettings {
     pub query_id: Arc<String>,
@@ -25,6 +28,10 @@ pub struct ExecutorSettings {
     pub enable_queries_executor: bool,
     pub max_execute_time_in_seconds: Duration,
     pub executor_node_id: String,
+    /// Memory stat of the workload group of the query, the parent of the query memory stat.
+    pub workload_group_mem_stat: Option<Arc<MemStat>>,
+    /// Share of the executor threads of the query's workload group, 1.0 without workload group.
+    pub workload_group_cpu_share: f64,
 }
 
 impl ExecutorSettings {
@@ -33,6 +40,8 @@ impl ExecutorSettings {
         let settings = ctx.get_settings();
         let max_threads = settings.get_max_threads()?;
         let max_execute_time_in_seconds = settings.get_max_execute_time_in_seconds()?;
+        let workload_group_mgr = WorkloadGroupResourceManager::instance();
+        let workload_group = workload_group_mgr.get_query_workload_group(&query_id);
 
         Ok(ExecutorSettings {
             enable_queries_executor: settings.get_enable_experimental_queries_executor()?,
@@ -40,6 +49,24 @@ impl ExecutorSettings {
             max_execute_time_in_seconds: Duration::from_secs(max_execute_time_in_seconds),
             max_threads,
             executor_node_id: ctx.get_cluster().local_id.clone(),
+            workload_group_mem_stat: workload_group.as_ref().map(|group| group.mem_stat()),
+            workload_group_cpu_share: match &workload_group {
+                None => 1.0,
+                Some(group) => workload_group_mgr.cpu_share(group),
+            },
         })
     }
+
+    /// Scale the number of executor threads by the cpu share of the workload group.
+    ///
+    /// Only the per-query executor owns its threads. The experimental queries executor
+    /// (`enable_experimental_queries_executor`) runs all queries on a shared pool of
+    /// `max_threads` threads, so the cpu weight of workload groups is not applied there.
+    pub fn weighted_threads(&self, threads_num: usize) -> usize {
+        if self.workload_group_cpu_share >= 1.0 {
+            return threads_num;
+        }
+        let threads = (threads_num as f64 * self.workload_group_cpu_share).ceil() as usize;
+        std::cmp::max(threads, 1)
+    }
 }
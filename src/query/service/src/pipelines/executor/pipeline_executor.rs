@@ -65,6 +65,8 @@ impl PipelineExecutor {
                 QueryPipelineExecutor::create(pipeline, settings)?,
             ))
         } else {
+            // The threads of the global queries executor are shared by all the queries,
+            // `ExecutorSettings::weighted_threads` of the workload group does not apply.
             let on_init_callback = Some(pipeline.take_on_init());
             let on_finished_chain = pipeline.take_on_finished();
 
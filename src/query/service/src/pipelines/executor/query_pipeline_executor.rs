@@ -173,6 +173,7 @@ impl QueryPipelineExecutor {
         settings: ExecutorSettings,
         lock_guards: Vec<Arc<LockGuard>>,
     ) -> Result<Arc<QueryPipelineExecutor>> {
+        let threads_num = settings.weighted_threads(threads_num);
         let workers_condvar = WorkersCondvar::create(threads_num);
         let global_tasks_queue = QueryExecutorTasksQueue::create(threads_num);
 
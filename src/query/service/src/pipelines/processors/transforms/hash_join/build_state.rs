@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use bigbytesdb_common_catalog::runtime_filter_info::RuntimeFilterReady;
 use bigbytesdb_common_expression::types::DataType;
+use bigbytesdb_common_expression::Column;
 use bigbytesdb_common_expression::ColumnVec;
 use bigbytesdb_common_expression::DataBlock;
 
@@ -49,6 +50,8 @@ pub struct BuildBlockGenerationState {
     pub(crate) build_columns_data_type: Vec<DataType>,
     // after projected by build_projection, whether we still have data.
     pub(crate) is_build_projected: bool,
+    // The build key of asof join match condition for every chunk.
+    pub(crate) asof_columns: Vec<Option<Column>>,
 }
 
 impl BuildBlockGenerationState {
@@ -59,6 +62,7 @@ impl BuildBlockGenerationState {
             build_columns: Vec::new(),
             build_columns_data_type: Vec::new(),
             is_build_projected: true,
+            asof_columns: Vec::new(),
         }
     }
 }
@@ -22,6 +22,7 @@ use bigbytesdb_common_sql::executor::physical_plans::HashJoin;
 use bigbytesdb_common_sql::IndexType;
 use parking_lot::RwLock;
 
+use crate::sql::plans::ComparisonOp;
 use crate::sql::plans::JoinType;
 
 pub const MARKER_KIND_TRUE: u8 = 0;
@@ -33,6 +34,13 @@ pub struct MarkJoinDesc {
     pub(crate) has_null: RwLock<bool>,
 }
 
+/// The match condition of asof join: `probe_key op build_key`.
+pub struct AsofJoinDesc {
+    pub(crate) probe_key: Expr,
+    pub(crate) build_key: Expr,
+    pub(crate) op: ComparisonOp,
+}
+
 pub struct HashJoinDesc {
     pub(crate) build_keys: Vec<Expr>,
     pub(crate) probe_keys: Vec<Expr>,
@@ -51,6 +59,7 @@ pub struct HashJoinDesc {
     pub broadcast: bool,
     // If enable bloom runtime filter
     pub enable_bloom_runtime_filter: bool,
+    pub(crate) asof_join_desc: Option<AsofJoinDesc>,
 }
 
 impl HashJoinDesc {
@@ -93,6 +102,11 @@ impl HashJoinDesc {
             broadcast: join.broadcast,
             single_to_inner: join.single_to_inner.clone(),
             enable_bloom_runtime_filter: join.enable_bloom_runtime_filter,
+            asof_join_desc: join.asof_condition.as_ref().map(|condition| AsofJoinDesc {
+                probe_key: condition.probe_key.as_expr(&BUILTIN_FUNCTIONS),
+                build_key: condition.build_key.as_expr(&BUILTIN_FUNCTIONS),
+                op: condition.op,
+            }),
         })
     }
 
@@ -433,6 +433,10 @@ impl HashJoinBuildState {
         if task_num == 0 {
             return Ok(());
         }
+        if self.hash_join_state.hash_join_desc.asof_join_desc.is_some() {
+            let build_state = unsafe { &mut *self.hash_join_state.build_state.get() };
+            build_state.generation_state.asof_columns = vec![None; task_num];
+        }
         let tasks = (0..task_num).collect_vec();
         *self.build_hash_table_tasks.write() = tasks.into();
         Ok(())
@@ -629,7 +633,7 @@ impl HashJoinBuildState {
         let mut _nullable_chunk = None;
         let evaluator = if matches!(
             self.hash_join_state.hash_join_desc.join_type,
-            JoinType::Left | JoinType::LeftSingle | JoinType::Full
+            JoinType::Left | JoinType::LeftSingle | JoinType::Full | JoinType::LeftAsof
         ) {
             let validity = Bitmap::new_constant(true, chunk.num_rows());
             let nullable_columns = chunk
@@ -655,6 +659,13 @@ impl HashJoinBuildState {
                     .convert_to_full_column(expr.data_type(), chunk.num_rows()))
             })
             .collect::<Result<Vec<_>>>()?;
+        if let Some(asof_join_desc) = &self.hash_join_state.hash_join_desc.asof_join_desc {
+            let expr = &asof_join_desc.build_key;
+            let asof_column = evaluator
+                .run(expr)?
+                .convert_to_full_column(expr.data_type(), chunk.num_rows());
+            build_state.generation_state.asof_columns[chunk_index] = Some(asof_column);
+        }
 
         let column_nums = chunk.num_columns();
         let mut block_entries = Vec::with_capacity(self.build_projections.len());
@@ -1123,5 +1134,6 @@ pub fn supported_join_type_for_runtime_filter(join_type: &JoinType) -> bool {
             | JoinType::RightSemi
             | JoinType::RightAnti
             | JoinType::LeftMark
+            | JoinType::Asof
     )
 }
@@ -256,6 +256,18 @@ impl HashJoinProbeState {
             });
         let probe_keys = (&keys_columns).into();
 
+        probe_state.asof_probe_column = match &self.hash_join_state.hash_join_desc.asof_join_desc {
+            Some(asof_join_desc) => {
+                let expr = &asof_join_desc.probe_key;
+                Some(
+                    evaluator
+                        .run(expr)?
+                        .convert_to_full_column(expr.data_type(), input_num_rows),
+                )
+            }
+            None => None,
+        };
+
         if self.hash_join_state.hash_join_desc.join_type != JoinType::LeftMark {
             input = input.project(&self.probe_projections);
         }
@@ -264,7 +276,11 @@ impl HashJoinProbeState {
         if self.hash_join_state.fast_return.load(Ordering::Acquire)
             && matches!(
                 self.hash_join_state.hash_join_desc.join_type,
-                JoinType::Left | JoinType::LeftSingle | JoinType::Full | JoinType::LeftAnti
+                JoinType::Left
+                    | JoinType::LeftSingle
+                    | JoinType::Full
+                    | JoinType::LeftAnti
+                    | JoinType::LeftAsof
             )
         {
             return self.left_fast_return(
@@ -283,8 +299,10 @@ impl HashJoinProbeState {
             input_num_rows as u64
         };
         // We use the information from the probed data to predict the matching state of this probe.
+        // Asof join looks up every probe row, so early filtering is not used.
         let prefer_early_filtering =
-            (probe_state.num_keys_hash_matched as f64) / (probe_state.num_keys as f64) < 0.8;
+            (probe_state.num_keys_hash_matched as f64) / (probe_state.num_keys as f64) < 0.8
+                && !self.hash_join_state.hash_join_desc.join_type.is_asof_join();
 
         // Probe:
         // (1) INNER / RIGHT / RIGHT SINGLE / RIGHT SEMI / RIGHT ANTI / RIGHT MARK / LEFT SEMI / LEFT MARK
@@ -145,7 +145,7 @@ impl HashJoinState {
     ) -> Result<Arc<HashJoinState>> {
         if matches!(
             hash_join_desc.join_type,
-            JoinType::Left | JoinType::LeftSingle | JoinType::Full
+            JoinType::Left | JoinType::LeftSingle | JoinType::Full | JoinType::LeftAsof
         ) {
             build_schema = build_schema_wrap_nullable(&build_schema);
         };
@@ -260,6 +260,7 @@ impl HashJoinState {
         build_state.generation_state.build_num_rows = 0;
         build_state.generation_state.build_columns.clear();
         build_state.generation_state.build_columns_data_type.clear();
+        build_state.generation_state.asof_columns.clear();
         if self.need_outer_scan() {
             build_state.outer_scan_map.clear();
         }
@@ -0,0 +1,245 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering as CmpOrdering;
+use std::collections::hash_map::Entry;
+use std::sync::atomic::Ordering;
+
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::Column;
+use bigbytesdb_common_expression::DataBlock;
+use bigbytesdb_common_expression::KeyAccessor;
+use bigbytesdb_common_expression::ScalarRef;
+use bigbytesdb_common_hashtable::HashJoinHashtableLike;
+use bigbytesdb_common_hashtable::RowPtr;
+
+use crate::pipelines::processors::transforms::hash_join::common::wrap_true_validity;
+use crate::pipelines::processors::transforms::hash_join::HashJoinProbeState;
+use crate::pipelines::processors::transforms::hash_join::ProbeState;
+use crate::sql::plans::ComparisonOp;
+
+impl HashJoinProbeState {
+    /// For every probe row, binary search the rows of the build side with the same keys,
+    /// sorted by the asof build key, for the closest one satisfying `probe_key op build_key`.
+    /// The sorted rows of a key are built once by each probe processor. The output
+    /// has at most one row for each probe row, so the whole input is processed in one round.
+    pub(crate) fn asof_join<'a, H: HashJoinHashtableLike, const LEFT_ASOF: bool>(
+        &self,
+        probe_state: &mut ProbeState,
+        keys: Box<(dyn KeyAccessor<Key = H::Key>)>,
+        hash_table: &H,
+    ) -> Result<Vec<DataBlock>>
+    where
+        H::Key: 'a,
+    {
+        // Safe to unwrap.
+        let op = self
+            .hash_join_state
+            .hash_join_desc
+            .asof_join_desc
+            .as_ref()
+            .unwrap()
+            .op;
+        let probe_column = probe_state.asof_probe_column.take().unwrap();
+        let process_state = probe_state.process_state.as_ref().unwrap();
+        let input = &process_state.input;
+        let num_rows = input.num_rows();
+
+        // Probe states.
+        let max_block_size = probe_state.max_block_size;
+        let build_indexes = &mut probe_state.mutable_indexes.build_indexes;
+        let build_indexes_ptr = build_indexes.as_mut_ptr();
+        let pointers = probe_state.hashes.as_slice();
+        let asof_sorted_rows = &mut probe_state.asof_sorted_rows;
+
+        // Build states.
+        let build_state = unsafe { &*self.hash_join_state.build_state.get() };
+        let asof_columns = &build_state.generation_state.asof_columns;
+
+        let mut matched_probe_indexes = Vec::with_capacity(num_rows);
+        let mut matched_build_indexes = Vec::with_capacity(num_rows);
+        let mut unmatched_probe_indexes = Vec::new();
+        for key_idx in 0..num_rows {
+            let probe_value = unsafe { probe_column.index_unchecked(key_idx) };
+            let mut closest = None;
+            if probe_value != ScalarRef::Null {
+                let key = unsafe { keys.key_unchecked(key_idx) };
+                let ptr = unsafe { *pointers.get_unchecked(key_idx) };
+                // The first row matching the key identifies the key in the sorted rows cache.
+                let (match_count, _) = hash_table.next_probe(key, ptr, build_indexes_ptr, 0, 1);
+                if match_count > 0 {
+                    let first = build_indexes[0];
+                    let cache_key = (first.chunk_index, first.row_index);
+                    let sorted_rows = match asof_sorted_rows.entry(cache_key) {
+                        Entry::Occupied(entry) => entry.into_mut(),
+                        Entry::Vacant(entry) => entry.insert(self.sort_asof_build_rows(
+                            hash_table,
+                            key,
+                            ptr,
+                            build_indexes,
+                            max_block_size,
+                            asof_columns,
+                        )),
+                    };
+                    closest = find_closest(op, &probe_value, sorted_rows, asof_columns);
+                }
+            }
+            match closest {
+                Some(row_ptr) => {
+                    matched_probe_indexes.push(key_idx as u32);
+                    matched_build_indexes.push(row_ptr);
+                }
+                None if LEFT_ASOF => unmatched_probe_indexes.push(key_idx as u32),
+                None => {}
+            }
+        }
+
+        if self.hash_join_state.interrupt.load(Ordering::Relaxed) {
+            return Err(ErrorCode::AbortedQuery(
+                "Aborted query, because the server is shutting down or the query was killed.",
+            ));
+        }
+
+        let mut result_blocks = vec![];
+        let matched_num_rows = matched_probe_indexes.len();
+        if matched_num_rows > 0 {
+            let probe_block = if probe_state.generation_state.is_probe_projected {
+                Some(DataBlock::take(input, &matched_probe_indexes)?)
+            } else {
+                None
+            };
+            let build_block = if build_state.generation_state.is_build_projected {
+                let build_block = self.hash_join_state.row_space.gather(
+                    &matched_build_indexes,
+                    &build_state.generation_state.build_columns,
+                    &build_state.generation_state.build_columns_data_type,
+                    &build_state.generation_state.build_num_rows,
+                )?;
+                if LEFT_ASOF {
+                    // For left asof join, wrap nullable for build block.
+                    let nullable_columns = build_block
+                        .columns()
+                        .iter()
+                        .map(|c| {
+                            wrap_true_validity(
+                                c,
+                                matched_num_rows,
+                                &probe_state.generation_state.true_validity,
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    Some(DataBlock::new(nullable_columns, matched_num_rows))
+                } else {
+                    Some(build_block)
+                }
+            } else {
+                None
+            };
+            result_blocks.push(self.merge_eq_block(probe_block, build_block, matched_num_rows));
+        }
+
+        if !unmatched_probe_indexes.is_empty() {
+            result_blocks.push(self.process_left_or_full_join_null_block(
+                unmatched_probe_indexes.len(),
+                input,
+                &unmatched_probe_indexes,
+                &mut probe_state.generation_state,
+                &build_state.generation_state,
+            )?);
+        }
+
+        probe_state.process_state = None;
+        Ok(result_blocks)
+    }
+
+    /// Collect the build rows with the same keys and a non-null asof build key, sorted by
+    /// the asof build key.
+    fn sort_asof_build_rows<H: HashJoinHashtableLike>(
+        &self,
+        hash_table: &H,
+        key: &H::Key,
+        mut ptr: u64,
+        build_indexes: &mut [RowPtr],
+        max_block_size: usize,
+        asof_columns: &[Option<Column>],
+    ) -> Vec<RowPtr> {
+        let build_indexes_ptr = build_indexes.as_mut_ptr();
+        let mut rows = Vec::new();
+        loop {
+            let (match_count, next_ptr) =
+                hash_table.next_probe(key, ptr, build_indexes_ptr, 0, max_block_size);
+            for row_ptr in build_indexes[0..match_count].iter() {
+                let build_value = unsafe { asof_build_value(asof_columns, row_ptr) };
+                if build_value != ScalarRef::Null {
+                    rows.push((*row_ptr, build_value));
+                }
+            }
+            if next_ptr == 0 {
+                break;
+            }
+            ptr = next_ptr;
+        }
+        rows.sort_by(|(_, left), (_, right)| left.partial_cmp(right).unwrap_or(CmpOrdering::Equal));
+        rows.into_iter().map(|(row_ptr, _)| row_ptr).collect()
+    }
+}
+
+/// # Safety
+///
+/// The chunk of `row_ptr` must have been evaluated when building the hash table.
+#[inline]
+unsafe fn asof_build_value<'a>(
+    asof_columns: &'a [Option<Column>],
+    row_ptr: &RowPtr,
+) -> ScalarRef<'a> {
+    // Safe to unwrap, the column is evaluated when building the hash table.
+    asof_columns
+        .get_unchecked(row_ptr.chunk_index as usize)
+        .as_ref()
+        .unwrap()
+        .index_unchecked(row_ptr.row_index as usize)
+}
+
+/// Binary search the build rows sorted by the asof build key for the closest one
+/// satisfying `probe_key op build_key`.
+fn find_closest(
+    op: ComparisonOp,
+    probe_value: &ScalarRef,
+    sorted_rows: &[RowPtr],
+    asof_columns: &[Option<Column>],
+) -> Option<RowPtr> {
+    let partition = |inclusive: bool| {
+        sorted_rows.partition_point(|row_ptr| {
+            let build_value = unsafe { asof_build_value(asof_columns, row_ptr) };
+            match inclusive {
+                true => build_value <= *probe_value,
+                false => build_value < *probe_value,
+            }
+        })
+    };
+    match op {
+        // The largest build key less than (or equal to) the probe key.
+        ComparisonOp::GT | ComparisonOp::GTE => {
+            let end = partition(op == ComparisonOp::GTE);
+            end.checked_sub(1).map(|idx| sorted_rows[idx])
+        }
+        // The smallest build key greater than (or equal to) the probe key.
+        ComparisonOp::LT | ComparisonOp::LTE => {
+            let start = partition(op == ComparisonOp::LT);
+            sorted_rows.get(start).copied()
+        }
+        ComparisonOp::Equal | ComparisonOp::NotEqual => None,
+    }
+}
@@ -357,7 +357,7 @@ impl HashJoinProbeState {
 
     #[inline]
     #[allow(clippy::too_many_arguments)]
-    fn process_left_or_full_join_null_block(
+    pub(crate) fn process_left_or_full_join_null_block(
         &self,
         unmatched_idx: usize,
         input: &DataBlock,
@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use bigbytesdb_common_column::bitmap::Bitmap;
 use bigbytesdb_common_expression::filter::FilterExecutor;
+use bigbytesdb_common_expression::Column;
 use bigbytesdb_common_expression::DataBlock;
 use bigbytesdb_common_expression::Expr;
 use bigbytesdb_common_expression::FunctionContext;
@@ -73,6 +76,12 @@ pub struct ProbeState {
     pub(crate) probe_unmatched_indexes_count: usize,
 
     pub(crate) filter_executor: Option<FilterExecutor>,
+    // The probe key of asof join match condition for the current input.
+    pub(crate) asof_probe_column: Option<Column>,
+    // The build rows of each key sorted by the build key of asof join match condition, keyed
+    // by the first row matching the key. It's filled lazily and owned by the probe processor,
+    // so it's never locked and its memory is charged to the query like the other probe states.
+    pub(crate) asof_sorted_rows: HashMap<(u32, u32), Vec<RowPtr>>,
 }
 
 impl ProbeState {
@@ -148,6 +157,8 @@ impl ProbeState {
             probe_unmatched_indexes_count: 0,
             with_conjunction,
             filter_executor,
+            asof_probe_column: None,
+            asof_sorted_rows: HashMap::new(),
         }
     }
 
@@ -156,6 +167,8 @@ impl ProbeState {
     pub fn reset(&mut self) {
         self.num_keys = 1;
         self.num_keys_hash_matched = 1;
+        // The hash table is rebuilt for the next round.
+        self.asof_sorted_rows.clear();
     }
 }
 
@@ -116,6 +116,8 @@ impl HashJoinProbeState {
                     self.right_mark_join_with_conjunct(probe_state, keys, hash_table)
                 }
             }
+            JoinType::Asof => self.asof_join::<_, false>(probe_state, keys, hash_table),
+            JoinType::LeftAsof => self.asof_join::<_, true>(probe_state, keys, hash_table),
             _ => Err(ErrorCode::Unimplemented(format!(
                 "{} is unimplemented",
                 self.hash_join_state.hash_join_desc.join_type
@@ -40,7 +40,7 @@ pub fn get_hashes(
     if from_build
         && matches!(
             join_type,
-            JoinType::Left | JoinType::LeftSingle | JoinType::Full
+            JoinType::Left | JoinType::LeftSingle | JoinType::Full | JoinType::LeftAsof
         )
     {
         wrap_nullable_block(&mut block);
@@ -650,6 +650,7 @@ impl TransformHashJoinProbe {
                 | JoinType::LeftAnti
                 | JoinType::RightMark
                 | JoinType::Full
+                | JoinType::LeftAsof
         )
     }
 }
@@ -239,6 +239,7 @@ impl PhysicalPlanReplacer for Fragmenter {
             broadcast: plan.broadcast,
             single_to_inner: plan.single_to_inner.clone(),
             build_side_cache_info: plan.build_side_cache_info.clone(),
+            asof_condition: plan.asof_condition.clone(),
         }))
     }
 
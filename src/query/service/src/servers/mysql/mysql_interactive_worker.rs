@@ -47,6 +47,7 @@ use opensrv_mysql::StatementMetaWriter;
 use rand::RngCore;
 use uuid::Uuid;
 
+use crate::history::SystemHistory;
 use crate::interpreters::interpreter_plan_sql;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
@@ -117,7 +118,22 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
         let info = CertifiedInfo::create(&username, auth_data, &client_addr);
 
         let authenticate = self.base.authenticate(salt, info);
-        match authenticate.await {
+        let res = authenticate.await;
+        let failure = match &res {
+            Ok(true) => None,
+            Ok(false) => Some(ErrorCode::AuthenticateFailure("wrong password")),
+            Err(failure) => Some(failure.clone()),
+        };
+        SystemHistory::instance().append_login(
+            &self.base.session.get_type(),
+            &self.base.session.get_current_tenant(),
+            "mysql_native_password",
+            &username,
+            client_addr.split(':').next().unwrap_or_default(),
+            failure.as_ref(),
+        );
+
+        match res {
             Ok(res) => res,
             Err(failure) => {
                 error!(
@@ -24,6 +24,8 @@ mod session_mgr_status;
 mod session_privilege_mgr;
 mod session_status;
 mod session_type;
+mod tenant_quota_mgr;
+mod workload_group_mgr;
 
 pub use bigbytesdb_common_catalog::table_context::TableContext;
 pub use query_affect::QueryAffect;
@@ -43,3 +45,9 @@ pub use session_mgr_status::SessionManagerStatus;
 pub use session_privilege_mgr::SessionPrivilegeManager;
 pub use session_status::SessionStatus;
 pub use session_type::SessionType;
+pub use tenant_quota_mgr::TenantQuotaManager;
+pub use tenant_quota_mgr::TenantQuotaPermit;
+pub use tenant_quota_mgr::TenantUsage;
+pub use workload_group_mgr::WorkloadGroupPermit;
+pub use workload_group_mgr::WorkloadGroupResource;
+pub use workload_group_mgr::WorkloadGroupResourceManager;
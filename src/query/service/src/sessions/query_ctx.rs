@@ -56,6 +56,7 @@ use bigbytesdb_common_catalog::table_args::TableArgs;
 use bigbytesdb_common_catalog::table_context::ContextError;
 use bigbytesdb_common_catalog::table_context::FilteredCopyFiles;
 use bigbytesdb_common_catalog::table_context::StageAttachment;
+use bigbytesdb_common_catalog::table_context::WorkloadGroupStatus;
 use bigbytesdb_common_config::GlobalConfig;
 use bigbytesdb_common_config::BIGBYTESDB_COMMIT_VERSION;
 use bigbytesdb_common_exception::ErrorCode;
@@ -135,6 +136,7 @@ use crate::sessions::QueryContextShared;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
+use crate::sessions::WorkloadGroupResourceManager;
 use crate::sql::binder::get_storage_params_from_options;
 use crate::storages::Table;
 
@@ -1007,6 +1009,10 @@ impl TableContext for QueryContext {
             .collect::<Vec<_>>()
     }
 
+    fn get_workload_groups_status(&self) -> Vec<WorkloadGroupStatus> {
+        WorkloadGroupResourceManager::instance().status(&self.get_tenant())
+    }
+
     // Get Stage Attachment.
     fn get_stage_attachment(&self) -> Option<StageAttachment> {
         self.shared.get_stage_attachment()
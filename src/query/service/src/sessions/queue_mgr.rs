@@ -53,6 +53,10 @@ use tokio::sync::Semaphore;
 use tokio::time::error::Elapsed;
 
 use crate::sessions::QueryContext;
+use crate::sessions::TenantQuotaPermit;
+use crate::sessions::WorkloadGroupPermit;
+use crate::sessions::WorkloadGroupResource;
+use crate::sessions::WorkloadGroupResourceManager;
 
 pub trait QueueData: Send + Sync + 'static {
     type Key: Send + Sync + Eq + Hash + Display + Clone + 'static;
@@ -65,6 +69,11 @@ pub trait QueueData: Send + Sync + 'static {
 
     fn need_acquire_to_queue(&self) -> bool;
 
+    /// The workload group to acquire a running slot of before the global one.
+    fn workload_group(&self) -> Option<Arc<WorkloadGroupResource>> {
+        None
+    }
+
     fn enter_wait_pending(&self) {}
 
     fn exit_wait_pending(&self, _wait_time: Duration) {}
@@ -138,22 +147,31 @@ impl<Data: QueueData> QueueManager<Data> {
                 self.length()
             );
 
+            let data = Arc::new(data);
+            let start_time = SystemTime::now();
+            let workload_group_permit = match data.workload_group() {
+                None => None,
+                Some(workload_group) => {
+                    Some(self.acquire_workload_group(&data, workload_group).await?)
+                }
+            };
+
             let timeout = data.timeout();
             let future = AcquireQueueFuture::create(
-                Arc::new(data),
+                data,
                 tokio::time::timeout(timeout, self.semaphore.clone().acquire_owned()),
                 self.clone(),
             );
-            let start_time = SystemTime::now();
 
             return match future.await {
-                Ok(v) => {
+                Ok(mut v) => {
                     info!("finished acquiring from queue, length: {}", self.length());
 
                     inc_session_running_acquired_queries();
                     record_session_queue_acquire_duration_ms(
                         start_time.elapsed().unwrap_or_default(),
                     );
+                    v.workload_group_permit = workload_group_permit;
                     Ok(v)
                 }
                 Err(e) => {
@@ -176,6 +194,61 @@ impl<Data: QueueData> QueueManager<Data> {
         Ok(AcquireQueueGuard::create(None))
     }
 
+    /// Acquire a running slot of the workload group, waiting in the queue of the group
+    /// if all the slots are taken. The query is rejected if the queue of the group is full.
+    async fn acquire_workload_group(
+        self: &Arc<Self>,
+        data: &Arc<Data>,
+        workload_group: Arc<WorkloadGroupResource>,
+    ) -> Result<WorkloadGroupPermit> {
+        workload_group.record_acquire_start();
+        let query_id = data.get_key().to_string();
+        if let Ok(permit) = workload_group.semaphore().try_acquire_owned() {
+            return Ok(WorkloadGroupPermit::create(
+                workload_group,
+                query_id,
+                permit,
+            ));
+        }
+
+        let _queued_guard = workload_group.enter_queue()?;
+        info!(
+            "preparing to acquire from the queue of workload group {}",
+            workload_group.name()
+        );
+
+        let timeout = workload_group
+            .queued_timeout()
+            .unwrap_or_else(|| data.timeout());
+        let future = AcquireQueueFuture::create(
+            data.clone(),
+            tokio::time::timeout(timeout, workload_group.semaphore().acquire_owned()),
+            self.clone(),
+        );
+
+        match future.await {
+            Ok(mut guard) => match guard.permit.take() {
+                Some(permit) => Ok(WorkloadGroupPermit::create(
+                    workload_group,
+                    query_id,
+                    permit,
+                )),
+                None => Err(ErrorCode::Internal(
+                    "acquired workload group queue without permit",
+                )),
+            },
+            Err(cause) => {
+                if cause.code() == ErrorCode::TIMEOUT {
+                    workload_group.record_acquire_timeout();
+                }
+                Err(cause.add_message_back(format!(
+                    " (while queuing in workload group {})",
+                    workload_group.name()
+                )))
+            }
+        }
+    }
+
     pub(crate) fn add_entity(&self, inner: Inner<Data>) -> Data::Key {
         inner.data.enter_wait_pending();
 
@@ -210,6 +283,10 @@ impl<Data: QueueData> QueueManager<Data> {
 pub struct AcquireQueueGuard {
     #[allow(dead_code)]
     permit: Option<OwnedSemaphorePermit>,
+    #[allow(dead_code)]
+    workload_group_permit: Option<WorkloadGroupPermit>,
+    #[allow(dead_code)]
+    tenant_quota_permit: Option<TenantQuotaPermit>,
 }
 
 impl Drop for AcquireQueueGuard {
@@ -222,7 +299,16 @@ impl Drop for AcquireQueueGuard {
 
 impl AcquireQueueGuard {
     pub fn create(permit: Option<OwnedSemaphorePermit>) -> Self {
-        AcquireQueueGuard { permit }
+        AcquireQueueGuard {
+            permit,
+            workload_group_permit: None,
+            tenant_quota_permit: None,
+        }
+    }
+
+    pub fn with_tenant_quota_permit(mut self, permit: Option<TenantQuotaPermit>) -> Self {
+        self.tenant_quota_permit = permit;
+        self
     }
 }
 
@@ -322,6 +408,7 @@ pub struct QueryEntry {
     pub user_info: UserInfo,
     pub timeout: Duration,
     pub need_acquire_to_queue: bool,
+    pub workload_group: Option<Arc<WorkloadGroupResource>>,
 }
 
 impl QueryEntry {
@@ -342,9 +429,21 @@ impl QueryEntry {
                 0 => Duration::from_secs(60 * 60 * 24 * 365 * 35),
                 timeout => Duration::from_secs(timeout),
             },
+            workload_group: None,
         })
     }
 
+    /// Resolve the workload group of the query if it needs to be queued.
+    #[async_backtrace::framed]
+    pub async fn with_workload_group(mut self) -> Result<QueryEntry> {
+        if self.need_acquire_to_queue {
+            self.workload_group = WorkloadGroupResourceManager::instance()
+                .resolve(&self.ctx)
+                .await?;
+        }
+        Ok(self)
+    }
+
     pub fn create(
         ctx: &Arc<QueryContext>,
         plan: &Plan,
@@ -407,6 +506,7 @@ impl QueryEntry {
             // DDL: Heavy actions.
             Plan::OptimizePurge(_)
             | Plan::OptimizeCompactSegment(_)
+            | Plan::OptimizeRefreshNgramIndex(_)
             | Plan::OptimizeCompactBlock { .. }
             | Plan::VacuumTable(_)
             | Plan::VacuumTemporaryFiles(_)
@@ -461,6 +561,10 @@ impl QueueData for QueryEntry {
         self.need_acquire_to_queue
     }
 
+    fn workload_group(&self) -> Option<Arc<WorkloadGroupResource>> {
+        self.workload_group.clone()
+    }
+
     fn enter_wait_pending(&self) {
         self.ctx.set_status_info("resources scheduling");
     }
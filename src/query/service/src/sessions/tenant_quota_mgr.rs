@@ -0,0 +1,502 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use chrono::Utc;
+use bigbytesdb_common_base::base::tokio::sync::Notify;
+use bigbytesdb_common_base::base::tokio::time::sleep;
+use bigbytesdb_common_base::base::GlobalInstance;
+use bigbytesdb_common_base::runtime::GlobalIORuntime;
+use bigbytesdb_common_base::runtime::TrySpawn;
+use bigbytesdb_common_catalog::table_context::TableContext;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_meta_app::schema::TableStatistics;
+use bigbytesdb_common_meta_app::tenant::Tenant;
+use bigbytesdb_common_meta_app::tenant::TenantQuota;
+use bigbytesdb_common_meta_types::MatchSeq;
+use bigbytesdb_common_users::UserApiProvider;
+use bigbytesdb_enterprise_resources_management::ResourcesManagement;
+use futures::future::select;
+use futures::future::Either;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::catalogs::CatalogManager;
+use crate::sessions::QueryContext;
+
+const QUOTA_CACHE_TTL: Duration = Duration::from_secs(10);
+const SCANNED_BYTES_CACHE_TTL: Duration = Duration::from_secs(10);
+const RUNNING_QUERIES_CACHE_TTL: Duration = Duration::from_secs(5);
+const STORAGE_USAGE_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+const SCANNED_BYTES_FLUSH_INTERVAL: Duration = Duration::from_secs(10);
+const RUNNING_QUERY_TTL: Duration = Duration::from_secs(60);
+const RUNNING_QUERY_REFRESH_INTERVAL: Duration = Duration::from_secs(20);
+
+/// The current usage of the resources limited by [`TenantQuota`].
+#[derive(Clone, Debug, Default)]
+pub struct TenantUsage {
+    pub storage_bytes: u64,
+    pub running_queries: u64,
+    pub scanned_bytes_this_month: u64,
+}
+
+/// The queries of a tenant counted against `max_concurrent_queries`.
+struct RunningQueries {
+    // The queries running on the other nodes, listed from the meta service.
+    others: Option<(Instant, u64)>,
+    // The queries admitted on this node and not finished yet.
+    local: Arc<AtomicU64>,
+}
+
+/// Enforces the resource quotas of the tenants: storage bytes, concurrent queries
+/// and monthly scanned bytes.
+///
+/// The count quotas (databases, tables, stages, users and warehouses) are checked by
+/// the interpreters creating the objects.
+pub struct TenantQuotaManager {
+    quotas: Mutex<HashMap<String, (Instant, TenantQuota)>>,
+    // The bytes of the tables of the tenants that have committed on this node, refreshed
+    // from the table statistics in background and adjusted by the commits in between.
+    storage_usage: Mutex<HashMap<Tenant, Option<u64>>>,
+    // Wake up the background refresh when a tenant is tracked for the first time.
+    storage_usage_notify: Notify,
+    // The scanned bytes flushed to the meta service, by tenant and month.
+    scanned_bytes: Mutex<HashMap<(Tenant, String), (Instant, u64)>>,
+    // The scanned bytes not yet flushed to the meta service, by tenant and month.
+    pending_scanned_bytes: Mutex<HashMap<(Tenant, String), u64>>,
+    running_queries: Mutex<HashMap<String, RunningQueries>>,
+}
+
+impl TenantQuotaManager {
+    pub fn init() -> Result<()> {
+        let manager = Arc::new(TenantQuotaManager {
+            quotas: Mutex::new(HashMap::new()),
+            storage_usage: Mutex::new(HashMap::new()),
+            storage_usage_notify: Notify::new(),
+            scanned_bytes: Mutex::new(HashMap::new()),
+            pending_scanned_bytes: Mutex::new(HashMap::new()),
+            running_queries: Mutex::new(HashMap::new()),
+        });
+
+        let flusher = manager.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            loop {
+                sleep(SCANNED_BYTES_FLUSH_INTERVAL).await;
+                flusher.flush_scanned_bytes().await;
+            }
+        });
+
+        let refresher = manager.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            loop {
+                let notified = Box::pin(refresher.storage_usage_notify.notified());
+                let interval = Box::pin(sleep(STORAGE_USAGE_REFRESH_INTERVAL));
+                select(notified, interval).await;
+
+                let tenants = refresher
+                    .storage_usage
+                    .lock()
+                    .keys()
+                    .cloned()
+                    .collect::<Vec<_>>();
+                for tenant in tenants {
+                    if let Err(cause) = refresher.refresh_storage_usage(&tenant).await {
+                        warn!(
+                            "failed to refresh the storage usage of tenant {}: {}",
+                            tenant.tenant_name(),
+                            cause
+                        );
+                    }
+                }
+            }
+        });
+
+        GlobalInstance::set(manager);
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<TenantQuotaManager> {
+        GlobalInstance::get()
+    }
+
+    fn current_month() -> String {
+        Utc::now().format("%Y-%m").to_string()
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_quota(&self, tenant: &Tenant) -> Result<TenantQuota> {
+        if let Some((at, quota)) = self.quotas.lock().get(tenant.tenant_name()) {
+            if at.elapsed() < QUOTA_CACHE_TTL {
+                return Ok(quota.clone());
+            }
+        }
+
+        self.load_quota(tenant).await
+    }
+
+    #[async_backtrace::framed]
+    async fn load_quota(&self, tenant: &Tenant) -> Result<TenantQuota> {
+        let quota = UserApiProvider::instance()
+            .tenant_quota_api(tenant)
+            .get_quota(MatchSeq::GE(0))
+            .await?
+            .data;
+        self.quotas.lock().insert(
+            tenant.tenant_name().to_string(),
+            (Instant::now(), quota.clone()),
+        );
+        Ok(quota)
+    }
+
+    /// Returns the bytes of the table data and indexes of the tenant, from the snapshot
+    /// statistics of the tables in the default catalog.
+    #[async_backtrace::framed]
+    pub async fn get_storage_usage(&self, tenant: &Tenant) -> Result<u64> {
+        if let Some(Some(bytes)) = self.storage_usage.lock().get(tenant) {
+            return Ok(*bytes);
+        }
+        self.refresh_storage_usage(tenant).await
+    }
+
+    /// Reloads the quota and the storage usage of the tenant from the meta service.
+    #[async_backtrace::framed]
+    pub async fn refresh_storage_usage(&self, tenant: &Tenant) -> Result<u64> {
+        self.load_quota(tenant).await?;
+
+        let catalog = CatalogManager::instance().get_default_catalog(Default::default())?;
+        let mut bytes = 0;
+        for database in catalog.list_databases(tenant).await? {
+            for table in database.list_tables().await? {
+                bytes += Self::storage_bytes(&table.get_table_info().meta.statistics);
+            }
+        }
+
+        self.storage_usage
+            .lock()
+            .insert(tenant.clone(), Some(bytes));
+        Ok(bytes)
+    }
+
+    pub fn storage_bytes(statistics: &TableStatistics) -> u64 {
+        statistics.compressed_data_bytes + statistics.index_data_bytes
+    }
+
+    /// Checks that committing `growth` more bytes of tables keeps the tenant within
+    /// `max_storage_bytes`. Commits that do not grow the storage are always allowed,
+    /// so that the tenant can free space once the quota is exceeded.
+    ///
+    /// It is on the commit path, thus only the cached quota and usage are used.
+    /// A tenant is tracked and refreshed in background since its first commit on this
+    /// node, the commits before its usage is loaded are allowed.
+    pub fn check_storage(&self, tenant: &Tenant, growth: i64) -> Result<()> {
+        if growth <= 0 {
+            return Ok(());
+        }
+
+        let usage = {
+            let mut storage_usage = self.storage_usage.lock();
+            match storage_usage.get(tenant) {
+                Some(usage) => *usage,
+                None => {
+                    storage_usage.insert(tenant.clone(), None);
+                    self.storage_usage_notify.notify_one();
+                    None
+                }
+            }
+        };
+        let Some(usage) = usage else {
+            return Ok(());
+        };
+
+        let max_storage_bytes = match self.quotas.lock().get(tenant.tenant_name()) {
+            Some((_, quota)) => quota.max_storage_bytes,
+            None => return Ok(()),
+        };
+        if max_storage_bytes == 0 {
+            return Ok(());
+        }
+
+        if usage.saturating_add(growth as u64) > max_storage_bytes {
+            return Err(ErrorCode::TenantQuotaExceeded(format!(
+                "Max storage bytes quota exceeded: tenant '{}' uses {} bytes, committing {} more bytes exceeds max_storage_bytes {}",
+                tenant.tenant_name(),
+                usage,
+                growth,
+                max_storage_bytes
+            )));
+        }
+        Ok(())
+    }
+
+    /// Adjusts the cached storage usage of the tenant after a commit.
+    pub fn add_storage_usage(&self, tenant: &Tenant, growth: i64) {
+        if let Some(Some(bytes)) = self.storage_usage.lock().get_mut(tenant) {
+            *bytes = bytes.saturating_add_signed(growth);
+        }
+    }
+
+    /// Records the bytes scanned by a finished query, flushed to the meta service in background.
+    pub fn record_scanned_bytes(&self, tenant: &Tenant, bytes: u64) {
+        if bytes == 0 {
+            return;
+        }
+
+        let key = (tenant.clone(), Self::current_month());
+        *self.pending_scanned_bytes.lock().entry(key).or_default() += bytes;
+    }
+
+    #[async_backtrace::framed]
+    async fn flush_scanned_bytes(&self) {
+        let pending = std::mem::take(&mut *self.pending_scanned_bytes.lock());
+        for ((tenant, month), bytes) in pending {
+            let quota_api = UserApiProvider::instance().tenant_quota_api(&tenant);
+            match quota_api.add_scanned_bytes(&month, bytes).await {
+                Ok(flushed) => {
+                    self.scanned_bytes
+                        .lock()
+                        .insert((tenant, month), (Instant::now(), flushed));
+                }
+                Err(cause) => {
+                    warn!(
+                        "failed to flush {} scanned bytes of tenant {}: {}",
+                        bytes,
+                        tenant.tenant_name(),
+                        cause
+                    );
+                    // Keep them to retry in the next round.
+                    *self
+                        .pending_scanned_bytes
+                        .lock()
+                        .entry((tenant, month))
+                        .or_default() += bytes;
+                }
+            }
+        }
+    }
+
+    /// Returns the bytes scanned by the tenant this month, including the ones not flushed yet.
+    #[async_backtrace::framed]
+    pub async fn get_scanned_bytes_this_month(&self, tenant: &Tenant) -> Result<u64> {
+        let key = (tenant.clone(), Self::current_month());
+        let cached = match self.scanned_bytes.lock().get(&key) {
+            Some((at, bytes)) if at.elapsed() < SCANNED_BYTES_CACHE_TTL => Some(*bytes),
+            _ => None,
+        };
+        let flushed = match cached {
+            Some(bytes) => bytes,
+            None => {
+                let bytes = UserApiProvider::instance()
+                    .tenant_quota_api(tenant)
+                    .get_scanned_bytes(&key.1)
+                    .await?;
+                self.scanned_bytes
+                    .lock()
+                    .insert(key.clone(), (Instant::now(), bytes));
+                bytes
+            }
+        };
+        let pending = self
+            .pending_scanned_bytes
+            .lock()
+            .get(&key)
+            .copied()
+            .unwrap_or_default();
+        Ok(flushed + pending)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_usage(&self, tenant: &Tenant) -> Result<TenantUsage> {
+        let running_queries = UserApiProvider::instance()
+            .tenant_quota_api(tenant)
+            .list_running_queries()
+            .await?
+            .len() as u64;
+        Ok(TenantUsage {
+            storage_bytes: self.get_storage_usage(tenant).await?,
+            running_queries,
+            scanned_bytes_this_month: self.get_scanned_bytes_this_month(tenant).await?,
+        })
+    }
+
+    /// Returns the number of the running queries of the tenant on the other nodes and the
+    /// counter of the ones admitted on this node. The queries on the other nodes are listed
+    /// at most once every few seconds.
+    #[async_backtrace::framed]
+    async fn get_running_queries(
+        &self,
+        tenant: &Tenant,
+        node_id: &str,
+    ) -> Result<(u64, Arc<AtomicU64>)> {
+        let (others, local) = {
+            let mut running_queries = self.running_queries.lock();
+            let running = running_queries
+                .entry(tenant.tenant_name().to_string())
+                .or_insert_with(|| RunningQueries {
+                    others: None,
+                    local: Arc::new(AtomicU64::new(0)),
+                });
+            let others = match running.others {
+                Some((at, others)) if at.elapsed() < RUNNING_QUERIES_CACHE_TTL => Some(others),
+                _ => None,
+            };
+            (others, running.local.clone())
+        };
+
+        let others = match others {
+            Some(others) => others,
+            None => {
+                let others = UserApiProvider::instance()
+                    .tenant_quota_api(tenant)
+                    .list_running_queries()
+                    .await?
+                    .iter()
+                    .filter(|(_, query)| query.node_id != node_id)
+                    .count() as u64;
+                if let Some(running) = self.running_queries.lock().get_mut(tenant.tenant_name()) {
+                    running.others = Some((Instant::now(), others));
+                }
+                others
+            }
+        };
+
+        Ok((others, local))
+    }
+
+    /// Admits a query to run, checking the monthly scanned bytes and the concurrent queries
+    /// of the tenant.
+    ///
+    /// If `max_concurrent_queries` is set, the query is registered in the meta service
+    /// until the returned permit is dropped. The queries of the other nodes are cached for
+    /// a few seconds, thus the queries admitted concurrently on different nodes may go beyond
+    /// the quota by a few.
+    #[async_backtrace::framed]
+    pub async fn admit(&self, ctx: &Arc<QueryContext>) -> Result<Option<TenantQuotaPermit>> {
+        let tenant = ctx.get_tenant();
+        let quota = self.get_quota(&tenant).await?;
+
+        if quota.max_scanned_bytes_per_month > 0 {
+            let scanned = self.get_scanned_bytes_this_month(&tenant).await?;
+            if scanned >= quota.max_scanned_bytes_per_month {
+                return Err(ErrorCode::TenantQuotaExceeded(format!(
+                    "Max scanned bytes per month quota exceeded: tenant '{}' scanned {} bytes this month, max_scanned_bytes_per_month is {}",
+                    tenant.tenant_name(),
+                    scanned,
+                    quota.max_scanned_bytes_per_month
+                )));
+            }
+        }
+
+        if quota.max_concurrent_queries == 0 {
+            return Ok(None);
+        }
+
+        let query_id = ctx.get_id();
+        let node_id = ctx.get_cluster().local_id.clone();
+        let (others, local) = self.get_running_queries(&tenant, &node_id).await?;
+        // Reserve the slot on this node atomically, so the queries admitted concurrently
+        // on this node never go beyond the quota.
+        let max_local = (quota.max_concurrent_queries as u64).saturating_sub(others);
+        if let Err(running) = local.fetch_update(Ordering::AcqRel, Ordering::Acquire, |running| {
+            (running < max_local).then_some(running + 1)
+        }) {
+            return Err(ErrorCode::TenantQuotaExceeded(format!(
+                "Max concurrent queries quota exceeded: tenant '{}' is running {} queries, max_concurrent_queries is {}",
+                tenant.tenant_name(),
+                others + running,
+                quota.max_concurrent_queries
+            )));
+        }
+
+        // The query is registered in background, it is counted by `local` on this node.
+        let quota_api = UserApiProvider::instance().tenant_quota_api(&tenant);
+        let shutdown = Arc::new(Notify::new());
+        GlobalIORuntime::instance().spawn({
+            let shutdown = shutdown.clone();
+            async move {
+                let mut notified = Box::pin(shutdown.notified());
+                let mut refresh = Box::pin(sleep(Duration::ZERO));
+                loop {
+                    match select(notified, refresh).await {
+                        Either::Left((_, _)) => break,
+                        Either::Right((_, new_notified)) => {
+                            notified = new_notified;
+                            refresh = Box::pin(sleep(RUNNING_QUERY_REFRESH_INTERVAL));
+                            if let Err(cause) = quota_api
+                                .upsert_running_query(&query_id, &node_id, RUNNING_QUERY_TTL)
+                                .await
+                            {
+                                warn!("failed to refresh running query {}: {}", query_id, cause);
+                            }
+                        }
+                    }
+                }
+
+                if let Err(cause) = quota_api.remove_running_query(&query_id).await {
+                    // It expires after the ttl anyway.
+                    warn!("failed to remove running query {}: {}", query_id, cause);
+                }
+                info!("running query {} released the tenant quota", query_id);
+            }
+        });
+
+        Ok(Some(TenantQuotaPermit { shutdown, local }))
+    }
+
+    /// Checks that the warehouses of the tenant and `pending` more ones to create are within
+    /// `max_warehouses`.
+    #[async_backtrace::framed]
+    pub async fn check_warehouses(&self, tenant: &Tenant, pending: usize) -> Result<()> {
+        let quota = self.load_quota(tenant).await?;
+        if quota.max_warehouses == 0 {
+            return Ok(());
+        }
+
+        let warehouses = GlobalInstance::get::<Arc<dyn ResourcesManagement>>()
+            .list_warehouses()
+            .await?
+            .len();
+        if warehouses + pending <= quota.max_warehouses as usize {
+            return Ok(());
+        }
+
+        Err(ErrorCode::TenantQuotaExceeded(format!(
+            "Max warehouses quota exceeded: tenant '{}' has {} warehouses, max_warehouses is {}",
+            tenant.tenant_name(),
+            warehouses,
+            quota.max_warehouses
+        )))
+    }
+}
+
+/// A running slot of the `max_concurrent_queries` quota, released on drop.
+pub struct TenantQuotaPermit {
+    shutdown: Arc<Notify>,
+    local: Arc<AtomicU64>,
+}
+
+impl Drop for TenantQuotaPermit {
+    fn drop(&mut self) {
+        self.local.fetch_sub(1, Ordering::AcqRel);
+        // `notify_one` keeps the permit if the task is not waiting yet.
+        self.shutdown.notify_one();
+    }
+}
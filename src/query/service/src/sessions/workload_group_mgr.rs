@@ -0,0 +1,387 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use bigbytesdb_common_base::base::GlobalInstance;
+use bigbytesdb_common_base::runtime::MemStat;
+use bigbytesdb_common_catalog::table_context::TableContext;
+use bigbytesdb_common_catalog::table_context::WorkloadGroupStatus;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_meta_app::principal::WorkloadGroup;
+use bigbytesdb_common_meta_app::tenant::Tenant;
+use bigbytesdb_common_users::RoleCacheManager;
+use bigbytesdb_common_users::UserApiProvider;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+use tokio::sync::OwnedSemaphorePermit;
+use tokio::sync::Semaphore;
+
+use crate::sessions::QueryContext;
+
+const UNLIMITED_PERMITS: usize = usize::MAX >> 4;
+const WORKLOAD_GROUP_CACHE_TTL: Duration = Duration::from_secs(10);
+
+fn concurrency_permits(max_concurrency: u64) -> usize {
+    match max_concurrency {
+        0 => UNLIMITED_PERMITS,
+        v => std::cmp::min(v as usize, UNLIMITED_PERMITS),
+    }
+}
+
+/// The runtime resources of a workload group on the local node.
+///
+/// The definition is reloaded from the meta service at most every `WORKLOAD_GROUP_CACHE_TTL`,
+/// so `ALTER WORKLOAD GROUP` takes effect without restarting: immediately on the node
+/// that executes it, and within the cache TTL on the other nodes.
+pub struct WorkloadGroupResource {
+    tenant: String,
+    name: String,
+    definition: RwLock<WorkloadGroup>,
+    semaphore: Arc<Semaphore>,
+    // Permits that could not be forgotten when max_concurrency was reduced,
+    // they are forgotten when the running queries release them.
+    pending_forget_permits: Mutex<usize>,
+    running_queries: Mutex<HashSet<String>>,
+    queued_queries: AtomicU64,
+    total_queries: AtomicU64,
+    rejected_queries: AtomicU64,
+    timeout_queries: AtomicU64,
+    mem_stat: Arc<MemStat>,
+}
+
+impl WorkloadGroupResource {
+    fn create(tenant: &Tenant, definition: WorkloadGroup) -> Arc<WorkloadGroupResource> {
+        let mem_stat = MemStat::create(format!("WorkloadGroupMemStat-{}", definition.name));
+        mem_stat.set_limit(definition.max_memory_usage as i64);
+
+        Arc::new(WorkloadGroupResource {
+            tenant: tenant.tenant_name().to_string(),
+            name: definition.name.clone(),
+            semaphore: Arc::new(Semaphore::new(concurrency_permits(
+                definition.max_concurrency,
+            ))),
+            definition: RwLock::new(definition),
+            pending_forget_permits: Mutex::new(0),
+            running_queries: Mutex::new(HashSet::new()),
+            queued_queries: AtomicU64::new(0),
+            total_queries: AtomicU64::new(0),
+            rejected_queries: AtomicU64::new(0),
+            timeout_queries: AtomicU64::new(0),
+            mem_stat,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn mem_stat(&self) -> Arc<MemStat> {
+        self.mem_stat.clone()
+    }
+
+    pub fn cpu_weight(&self) -> u64 {
+        self.definition.read().cpu_weight
+    }
+
+    /// The timeout of waiting for a running slot, None means using the one of the query.
+    pub fn queued_timeout(&self) -> Option<Duration> {
+        match self.definition.read().queued_timeout_secs {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
+        }
+    }
+
+    pub fn semaphore(&self) -> Arc<Semaphore> {
+        self.semaphore.clone()
+    }
+
+    pub fn running_queries(&self) -> usize {
+        self.running_queries.lock().len()
+    }
+
+    fn update(&self, definition: WorkloadGroup) {
+        let mut current = self.definition.write();
+        if *current == definition {
+            return;
+        }
+
+        let old_permits = concurrency_permits(current.max_concurrency);
+        let new_permits = concurrency_permits(definition.max_concurrency);
+        if new_permits > old_permits {
+            let mut pending = self.pending_forget_permits.lock();
+            let cancelled = std::cmp::min(*pending, new_permits - old_permits);
+            *pending -= cancelled;
+            self.semaphore
+                .add_permits(new_permits - old_permits - cancelled);
+        } else if new_permits < old_permits {
+            let forgotten = self.semaphore.forget_permits(old_permits - new_permits);
+            *self.pending_forget_permits.lock() += old_permits - new_permits - forgotten;
+        }
+
+        self.mem_stat.set_limit(definition.max_memory_usage as i64);
+        info!(
+            "workload group {} of tenant {} updated: {:?}",
+            self.name, self.tenant, definition
+        );
+        *current = definition;
+    }
+
+    /// Enter the waiting queue of the group, fails if the queue is full.
+    pub fn enter_queue(self: &Arc<Self>) -> Result<WorkloadGroupQueuedGuard> {
+        let max_queued = self.definition.read().max_queued;
+        let queued = self.queued_queries.fetch_add(1, Ordering::SeqCst);
+        if max_queued != 0 && queued >= max_queued {
+            self.queued_queries.fetch_sub(1, Ordering::SeqCst);
+            self.rejected_queries.fetch_add(1, Ordering::Relaxed);
+            return Err(ErrorCode::WorkloadGroupQueueFull(format!(
+                "The queue of workload group {} is full, max_queued: {}",
+                self.name, max_queued
+            )));
+        }
+        Ok(WorkloadGroupQueuedGuard {
+            group: self.clone(),
+        })
+    }
+
+    pub fn record_acquire_start(&self) {
+        self.total_queries.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_acquire_timeout(&self) {
+        self.timeout_queries.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn status(&self) -> WorkloadGroupStatus {
+        WorkloadGroupStatus {
+            name: self.name.clone(),
+            running_queries: self.running_queries() as u64,
+            queued_queries: self.queued_queries.load(Ordering::Relaxed),
+            total_queries: self.total_queries.load(Ordering::Relaxed),
+            rejected_queries: self.rejected_queries.load(Ordering::Relaxed),
+            timeout_queries: self.timeout_queries.load(Ordering::Relaxed),
+            memory_usage: self.mem_stat.get_memory_usage(),
+        }
+    }
+}
+
+pub struct WorkloadGroupQueuedGuard {
+    group: Arc<WorkloadGroupResource>,
+}
+
+impl Drop for WorkloadGroupQueuedGuard {
+    fn drop(&mut self) {
+        self.group.queued_queries.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// A running slot of a workload group, released when the query finishes.
+pub struct WorkloadGroupPermit {
+    group: Arc<WorkloadGroupResource>,
+    query_id: String,
+    permit: Option<OwnedSemaphorePermit>,
+}
+
+impl WorkloadGroupPermit {
+    pub fn create(
+        group: Arc<WorkloadGroupResource>,
+        query_id: String,
+        permit: OwnedSemaphorePermit,
+    ) -> WorkloadGroupPermit {
+        group.running_queries.lock().insert(query_id.clone());
+        WorkloadGroupPermit {
+            group,
+            query_id,
+            permit: Some(permit),
+        }
+    }
+}
+
+impl Drop for WorkloadGroupPermit {
+    fn drop(&mut self) {
+        self.group.running_queries.lock().remove(&self.query_id);
+        if let Some(permit) = self.permit.take() {
+            let mut pending = self.group.pending_forget_permits.lock();
+            if *pending > 0 {
+                *pending -= 1;
+                permit.forget();
+            }
+        }
+    }
+}
+
+pub struct WorkloadGroupResourceManager {
+    // (tenant, group name) -> resource
+    groups: RwLock<HashMap<(String, String), Arc<WorkloadGroupResource>>>,
+    // (tenant, group name) -> (loaded at, definition), None if the group is not found.
+    definitions: Mutex<HashMap<(String, String), (Instant, Option<WorkloadGroup>)>>,
+}
+
+impl WorkloadGroupResourceManager {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Self::create());
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<WorkloadGroupResourceManager> {
+        GlobalInstance::get()
+    }
+
+    pub fn create() -> Arc<WorkloadGroupResourceManager> {
+        Arc::new(WorkloadGroupResourceManager {
+            groups: RwLock::new(HashMap::new()),
+            definitions: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Resolve the workload group of the query.
+    ///
+    /// The workload group of the user takes precedence over the one of the current role.
+    /// A group that is not found is ignored, so that dropping a group never blocks queries.
+    #[async_backtrace::framed]
+    pub async fn resolve(
+        &self,
+        ctx: &Arc<QueryContext>,
+    ) -> Result<Option<Arc<WorkloadGroupResource>>> {
+        let tenant = ctx.get_tenant();
+        let user = ctx.get_current_user()?;
+        let name = match user.option.workload_group() {
+            Some(name) => Some(name.clone()),
+            None => match ctx.get_current_role() {
+                None => None,
+                Some(role) => match RoleCacheManager::instance()
+                    .find_role(&tenant, &role.name)
+                    .await?
+                {
+                    Some(role) => role.workload_group,
+                    None => role.workload_group,
+                },
+            },
+        };
+
+        let Some(name) = name else {
+            return Ok(None);
+        };
+
+        let Some(definition) = self.get_definition(&tenant, &name).await? else {
+            warn!(
+                "workload group {} of user {} is not found, ignore it",
+                name,
+                user.identity().display()
+            );
+            return Ok(None);
+        };
+
+        Ok(Some(self.get_or_create(&tenant, definition)))
+    }
+
+    #[async_backtrace::framed]
+    async fn get_definition(&self, tenant: &Tenant, name: &str) -> Result<Option<WorkloadGroup>> {
+        let key = (tenant.tenant_name().to_string(), name.to_string());
+        if let Some((at, definition)) = self.definitions.lock().get(&key) {
+            if at.elapsed() < WORKLOAD_GROUP_CACHE_TTL {
+                return Ok(definition.clone());
+            }
+        }
+
+        let definition = match UserApiProvider::instance()
+            .get_workload_group(tenant, name)
+            .await
+        {
+            Ok(definition) => Some(definition),
+            Err(cause) if cause.code() == ErrorCode::UNKNOWN_WORKLOAD_GROUP => None,
+            Err(cause) => return Err(cause),
+        };
+        self.definitions
+            .lock()
+            .insert(key, (Instant::now(), definition.clone()));
+        Ok(definition)
+    }
+
+    /// Drops the cached definition of the group, so that the next query reloads it.
+    pub fn invalidate(&self, tenant: &Tenant, name: &str) {
+        self.definitions
+            .lock()
+            .remove(&(tenant.tenant_name().to_string(), name.to_string()));
+    }
+
+    fn get_or_create(
+        &self,
+        tenant: &Tenant,
+        definition: WorkloadGroup,
+    ) -> Arc<WorkloadGroupResource> {
+        let key = (tenant.tenant_name().to_string(), definition.name.clone());
+        if let Some(group) = self.groups.read().get(&key) {
+            group.update(definition);
+            return group.clone();
+        }
+
+        let mut groups = self.groups.write();
+        match groups.get(&key) {
+            Some(group) => {
+                group.update(definition);
+                group.clone()
+            }
+            None => {
+                let group = WorkloadGroupResource::create(tenant, definition);
+                groups.insert(key, group.clone());
+                group
+            }
+        }
+    }
+
+    /// The workload group that the running query holds a slot of.
+    pub fn get_query_workload_group(&self, query_id: &str) -> Option<Arc<WorkloadGroupResource>> {
+        self.groups
+            .read()
+            .values()
+            .find(|group| group.running_queries.lock().contains(query_id))
+            .cloned()
+    }
+
+    /// The share of the executor threads of the group,
+    /// i.e. its cpu weight divided by the sum of the weights of the groups with running queries.
+    pub fn cpu_share(&self, group: &Arc<WorkloadGroupResource>) -> f64 {
+        let weight = group.cpu_weight();
+        let mut total_weight = weight;
+        for other in self.groups.read().values() {
+            if !Arc::ptr_eq(other, group) && other.running_queries() > 0 {
+                total_weight += other.cpu_weight();
+            }
+        }
+
+        match total_weight {
+            0 => 1.0,
+            total_weight => weight as f64 / total_weight as f64,
+        }
+    }
+
+    pub fn status(&self, tenant: &Tenant) -> Vec<WorkloadGroupStatus> {
+        self.groups
+            .read()
+            .iter()
+            .filter(|((group_tenant, _), _)| group_tenant == tenant.tenant_name())
+            .map(|(_, group)| group.status())
+            .collect()
+    }
+}
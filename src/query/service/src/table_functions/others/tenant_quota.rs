@@ -16,6 +16,7 @@ use std::any::Any;
 use std::sync::Arc;
 
 use chrono::DateTime;
+use bigbytesdb_common_base::base::GlobalInstance;
 use bigbytesdb_common_catalog::plan::DataSourcePlan;
 use bigbytesdb_common_catalog::plan::PartStatistics;
 use bigbytesdb_common_catalog::plan::Partitions;
@@ -28,6 +29,7 @@ use bigbytesdb_common_exception::Result;
 use bigbytesdb_common_expression::types::DataType;
 use bigbytesdb_common_expression::types::NumberDataType;
 use bigbytesdb_common_expression::types::UInt32Type;
+use bigbytesdb_common_expression::types::UInt64Type;
 use bigbytesdb_common_expression::types::ValueType;
 use bigbytesdb_common_expression::BlockEntry;
 use bigbytesdb_common_expression::DataBlock;
@@ -52,8 +54,12 @@ use bigbytesdb_common_pipeline_sources::AsyncSource;
 use bigbytesdb_common_pipeline_sources::AsyncSourcer;
 use bigbytesdb_common_storages_factory::Table;
 use bigbytesdb_common_users::UserApiProvider;
+use bigbytesdb_enterprise_resources_management::ResourcesManagement;
 use fastrace::func_name;
 
+use crate::sessions::TenantQuotaManager;
+use crate::sessions::TenantUsage;
+
 pub struct TenantQuotaTable {
     table_info: TableInfo,
     args: Vec<String>,
@@ -75,6 +81,36 @@ impl TenantQuotaTable {
                 "max_files_per_stage",
                 TableDataType::Number(NumberDataType::UInt32),
             ),
+            TableField::new("max_users", TableDataType::Number(NumberDataType::UInt32)),
+            TableField::new(
+                "max_storage_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "max_concurrent_queries",
+                TableDataType::Number(NumberDataType::UInt32),
+            ),
+            TableField::new(
+                "max_warehouses",
+                TableDataType::Number(NumberDataType::UInt32),
+            ),
+            TableField::new(
+                "max_scanned_bytes_per_month",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "storage_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "running_queries",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("warehouses", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "scanned_bytes_this_month",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
         ])
     }
 
@@ -183,28 +219,38 @@ impl TenantQuotaSource {
 }
 
 impl TenantQuotaSource {
-    fn to_block(&self, quota: &TenantQuota) -> Result<DataBlock> {
-        Ok(DataBlock::new(
+    fn to_block(&self, quota: &TenantQuota, usage: &TenantUsage, warehouses: u64) -> DataBlock {
+        let u32_entry = |v: u32| {
+            BlockEntry::new(
+                DataType::Number(NumberDataType::UInt32),
+                Value::Scalar(UInt32Type::upcast_scalar(v)),
+            )
+        };
+        let u64_entry = |v: u64| {
+            BlockEntry::new(
+                DataType::Number(NumberDataType::UInt64),
+                Value::Scalar(UInt64Type::upcast_scalar(v)),
+            )
+        };
+
+        DataBlock::new(
             vec![
-                BlockEntry::new(
-                    DataType::Number(NumberDataType::UInt32),
-                    Value::Scalar(UInt32Type::upcast_scalar(quota.max_databases)),
-                ),
-                BlockEntry::new(
-                    DataType::Number(NumberDataType::UInt32),
-                    Value::Scalar(UInt32Type::upcast_scalar(quota.max_tables_per_database)),
-                ),
-                BlockEntry::new(
-                    DataType::Number(NumberDataType::UInt32),
-                    Value::Scalar(UInt32Type::upcast_scalar(quota.max_stages)),
-                ),
-                BlockEntry::new(
-                    DataType::Number(NumberDataType::UInt32),
-                    Value::Scalar(UInt32Type::upcast_scalar(quota.max_files_per_stage)),
-                ),
+                u32_entry(quota.max_databases),
+                u32_entry(quota.max_tables_per_database),
+                u32_entry(quota.max_stages),
+                u32_entry(quota.max_files_per_stage),
+                u32_entry(quota.max_users),
+                u64_entry(quota.max_storage_bytes),
+                u32_entry(quota.max_concurrent_queries),
+                u32_entry(quota.max_warehouses),
+                u64_entry(quota.max_scanned_bytes_per_month),
+                u64_entry(usage.storage_bytes),
+                u64_entry(usage.running_queries),
+                u64_entry(warehouses),
+                u64_entry(usage.scanned_bytes_this_month),
             ],
             1,
-        ))
+        )
     }
 }
 
@@ -214,6 +260,13 @@ impl TenantQuotaSource {
 /// max_tables_per_database: u32
 /// max_stages: u32
 /// max_files_per_stage: u32
+/// max_users: u32
+/// max_storage_bytes: u64
+/// max_concurrent_queries: u32
+/// max_warehouses: u32
+/// max_scanned_bytes_per_month: u64
+///
+/// The current usage of the tenant is returned along with the quota.
 #[async_trait::async_trait]
 impl AsyncSource for TenantQuotaSource {
     const NAME: &'static str = "tenant_quota";
@@ -242,8 +295,18 @@ impl AsyncSource for TenantQuotaSource {
         let res = quota_api.get_quota(MatchSeq::GE(0)).await?;
         let mut quota = res.data;
 
+        let usage = TenantQuotaManager::instance().get_usage(&tenant).await?;
+        // Warehouses can only be listed with the enterprise edition.
+        let warehouses = match GlobalInstance::get::<Arc<dyn ResourcesManagement>>()
+            .list_warehouses()
+            .await
+        {
+            Ok(warehouses) => warehouses.len() as u64,
+            Err(_) => 0,
+        };
+
         if args.len() <= 1 {
-            return Ok(Some(self.to_block(&quota)?));
+            return Ok(Some(self.to_block(&quota, &usage, warehouses)));
         };
 
         quota.max_databases = args[1].as_str().parse::<u32>()?;
@@ -256,12 +319,28 @@ impl AsyncSource for TenantQuotaSource {
         if let Some(max_files_per_stage) = args.get(4) {
             quota.max_files_per_stage = max_files_per_stage.as_str().parse::<u32>()?
         };
+        if let Some(max_users) = args.get(5) {
+            quota.max_users = max_users.as_str().parse::<u32>()?;
+        };
+        if let Some(max_storage_bytes) = args.get(6) {
+            quota.max_storage_bytes = max_storage_bytes.as_str().parse::<u64>()?;
+        };
+        if let Some(max_concurrent_queries) = args.get(7) {
+            quota.max_concurrent_queries = max_concurrent_queries.as_str().parse::<u32>()?;
+        };
+        if let Some(max_warehouses) = args.get(8) {
+            quota.max_warehouses = max_warehouses.as_str().parse::<u32>()?;
+        };
+        if let Some(max_scanned_bytes_per_month) = args.get(9) {
+            quota.max_scanned_bytes_per_month =
+                max_scanned_bytes_per_month.as_str().parse::<u64>()?;
+        };
 
         quota_api
             .set_quota(&quota, MatchSeq::Exact(res.seq))
             .await?;
 
-        Ok(Some(self.to_block(&quota)?))
+        Ok(Some(self.to_block(&quota, &usage, warehouses)))
     }
 }
 
@@ -116,6 +116,7 @@ impl<'a> BlockWriter<'a> {
             location.1,
             block,
             bloom_columns_map,
+            &[],
         )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
@@ -0,0 +1,127 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigbytesdb_common_base::base::tokio;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_meta_app::tenant::TenantQuota;
+use bigbytesdb_common_meta_types::MatchSeq;
+use bigbytesdb_common_users::UserApiProvider;
+use bigbytesdb_query::sessions::TableContext;
+use bigbytesdb_query::sessions::TenantQuotaManager;
+use bigbytesdb_query::test_kits::TestFixture;
+use futures::future::join_all;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_check_storage() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let tenant = fixture.default_tenant();
+    let manager = TenantQuotaManager::instance();
+
+    let quota = TenantQuota {
+        max_storage_bytes: 100,
+        ..Default::default()
+    };
+    UserApiProvider::instance()
+        .tenant_quota_api(&tenant)
+        .set_quota(&quota, MatchSeq::GE(0))
+        .await?;
+
+    // The usage is not loaded yet, the commit is allowed.
+    manager.check_storage(&tenant, 1000)?;
+
+    let usage = manager.refresh_storage_usage(&tenant).await?;
+    assert!(usage < 100);
+
+    let err = manager.check_storage(&tenant, 1000).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::TENANT_QUOTA_EXCEEDED);
+    manager.check_storage(&tenant, (100 - usage) as i64)?;
+
+    // A commit that frees space is always allowed.
+    manager.add_storage_usage(&tenant, 1000);
+    manager.check_storage(&tenant, -10)?;
+    let err = manager.check_storage(&tenant, 1).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::TENANT_QUOTA_EXCEEDED);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_admit() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let tenant = fixture.default_tenant();
+    let manager = TenantQuotaManager::instance();
+
+    let quota = TenantQuota {
+        max_concurrent_queries: 1,
+        max_scanned_bytes_per_month: 100,
+        ..Default::default()
+    };
+    UserApiProvider::instance()
+        .tenant_quota_api(&tenant)
+        .set_quota(&quota, MatchSeq::GE(0))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let permit = manager.admit(&ctx).await?;
+    assert!(permit.is_some());
+
+    let ctx = fixture.new_query_ctx().await?;
+    let err = manager.admit(&ctx).await.err().unwrap();
+    assert_eq!(err.code(), ErrorCode::TENANT_QUOTA_EXCEEDED);
+
+    drop(permit);
+    let permit = manager.admit(&ctx).await?;
+    assert!(permit.is_some());
+    drop(permit);
+
+    manager.record_scanned_bytes(&ctx.get_tenant(), 100);
+    let err = manager.admit(&ctx).await.err().unwrap();
+    assert_eq!(err.code(), ErrorCode::TENANT_QUOTA_EXCEEDED);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_admit_concurrently() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let tenant = fixture.default_tenant();
+    let manager = TenantQuotaManager::instance();
+
+    let quota = TenantQuota {
+        max_concurrent_queries: 2,
+        ..Default::default()
+    };
+    UserApiProvider::instance()
+        .tenant_quota_api(&tenant)
+        .set_quota(&quota, MatchSeq::GE(0))
+        .await?;
+
+    let mut ctxs = vec![];
+    for _ in 0..8 {
+        ctxs.push(fixture.new_query_ctx().await?);
+    }
+    // The queries admitted concurrently on one node never go beyond the quota.
+    let results = join_all(ctxs.iter().map(|ctx| manager.admit(ctx))).await;
+    let mut permits = vec![];
+    for result in results {
+        match result {
+            Ok(permit) => permits.push(permit),
+            Err(err) => assert_eq!(err.code(), ErrorCode::TENANT_QUOTA_EXCEEDED),
+        }
+    }
+    assert_eq!(permits.len(), 2);
+
+    Ok(())
+}
@@ -336,6 +336,8 @@ fn build_test_segment_info(
         inverted_index_size: None,
         compression: Compression::Lz4,
         create_on: Some(Utc::now()),
+        spatial_stats: HashMap::new(),
+        partition: None,
     };
 
     let block_metas = (0..num_blocks_per_seg)
@@ -351,6 +353,7 @@ fn build_test_segment_info(
         index_size: 0,
         col_stats: col_stats.clone(),
         cluster_stats: None,
+        partition: None,
     };
 
     Ok(SegmentInfo::new(block_metas, statistics))
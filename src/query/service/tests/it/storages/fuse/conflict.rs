@@ -97,6 +97,7 @@ fn test_resolvable_delete_conflict() {
         index_size: 6,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let mut latest_snapshot = TableSnapshot::new_empty_snapshot(TableSchema::default(), None);
@@ -115,6 +116,7 @@ fn test_resolvable_delete_conflict() {
         index_size: 9,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let removed_statistics = Statistics {
@@ -126,6 +128,7 @@ fn test_resolvable_delete_conflict() {
         index_size: 5,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let merged_statistics = Statistics {
@@ -137,6 +140,7 @@ fn test_resolvable_delete_conflict() {
         index_size: 8,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let ctx = ConflictResolveContext::ModifiedSegmentExistsInLatest(SnapshotChanges {
@@ -173,6 +177,7 @@ fn test_resolvable_delete_conflict() {
         index_size: 12,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
     assert_eq!(actual, expected);
 }
@@ -204,6 +209,7 @@ fn test_resolvable_replace_conflict() {
         index_size: 6,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let mut latest_snapshot = TableSnapshot::new_empty_snapshot(TableSchema::default(), None);
@@ -222,6 +228,7 @@ fn test_resolvable_replace_conflict() {
         index_size: 9,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let removed_statistics = Statistics {
@@ -233,6 +240,7 @@ fn test_resolvable_replace_conflict() {
         index_size: 5,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let merged_statistics = Statistics {
@@ -244,6 +252,7 @@ fn test_resolvable_replace_conflict() {
         index_size: 8,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let ctx = ConflictResolveContext::ModifiedSegmentExistsInLatest(SnapshotChanges {
@@ -285,6 +294,7 @@ fn test_resolvable_replace_conflict() {
         index_size: 12,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
     assert_eq!(actual, expected);
 }
@@ -70,6 +70,7 @@ async fn apply_block_pruning(
         schema,
         push_down,
         bloom_index_cols,
+        vec![],
         None,
         FuseStorageFormat::Parquet,
     )?
@@ -81,6 +81,7 @@ async fn apply_snapshot_pruning(
         schema,
         push_down,
         bloom_index_cols,
+        vec![],
         None,
         FuseStorageFormat::Parquet,
     )?);
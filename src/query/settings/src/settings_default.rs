@@ -485,6 +485,20 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_plan_baseline", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables replaying the plan baseline pinned for the query.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_materialized_view_rewrite", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables rewriting queries to read from the materialized views defined by them. A query is rewritten only if its text, with tables qualified and hints removed, is the same as the query of the view.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_query_result_cache", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables caching query results to improve performance for identical queries.",
@@ -685,6 +699,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: None,
                 }),
+                ("insert_overwrite_partition", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_owned()),
+                    desc: "The partition predicate that the rows written by INSERT OVERWRITE ... PARTITION must match, set internally.",
+                    mode: SettingMode::Write,
+                    scope: SettingScope::Both,
+                    range: None,
+                }),
                 ("enable_distributed_copy_into", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables distributed execution for the 'COPY INTO'.",
@@ -1046,6 +1067,20 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_vector_index", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables pruning blocks with vector index for top-k distance queries.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("vector_index_ef_search", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(64),
+                    desc: "Sets the size of the candidate list when searching the vector index, a larger value gives a better recall.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(1..=10000)),
+                }),
                 ("max_vacuum_temp_files_after_query", DefaultSettingValue {
                     value: UserSettingValue::UInt64(u64::MAX),
                     desc: "The maximum temp files will be removed after query. please enable vacuum feature. disable if 0",
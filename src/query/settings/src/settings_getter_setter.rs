@@ -176,6 +176,14 @@ impl Settings {
         Ok(self.try_get_u64("enable_auto_fix_missing_bloom_index")? != 0)
     }
 
+    pub fn get_enable_vector_index(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_vector_index")? != 0)
+    }
+
+    pub fn get_vector_index_ef_search(&self) -> Result<u64> {
+        self.try_get_u64("vector_index_ef_search")
+    }
+
     // Get max_block_size.
     pub fn get_max_block_size(&self) -> Result<u64> {
         self.try_get_u64("max_block_size")
@@ -420,6 +428,14 @@ impl Settings {
         Ok(self.try_get_u64("enable_planner_cache")? != 0)
     }
 
+    pub fn get_enable_plan_baseline(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_plan_baseline")? != 0)
+    }
+
+    pub fn get_enable_materialized_view_rewrite(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_materialized_view_rewrite")? != 0)
+    }
+
     pub fn get_enable_experimental_procedure(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_experimental_procedure")? != 0)
     }
@@ -556,6 +572,19 @@ impl Settings {
         self.unchecked_set_setting("deduplicate_label".to_string(), val)
     }
 
+    pub fn get_insert_overwrite_partition(&self) -> Result<Option<String>> {
+        let predicate = self.try_get_string("insert_overwrite_partition")?;
+        if predicate.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(predicate))
+        }
+    }
+
+    pub fn set_insert_overwrite_partition(&self, val: String) -> Result<()> {
+        self.set_setting("insert_overwrite_partition".to_string(), val)
+    }
+
     pub fn get_enable_distributed_copy(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_distributed_copy_into")? != 0)
     }
@@ -69,6 +69,7 @@ use crate::planner::Metadata;
 use crate::planner::MetadataRef;
 use crate::planner::DUMMY_TABLE_INDEX;
 use crate::plans::CacheSource;
+use crate::plans::ComparisonOp;
 
 impl PhysicalPlan {
     pub fn format(
@@ -1446,6 +1447,29 @@ fn hash_join_to_format_tree(
         FormatTreeNode::new(format!("filters: [{filters}]")),
     ];
 
+    if let Some(condition) = &plan.asof_condition {
+        let op = match condition.op {
+            ComparisonOp::GT => ">",
+            ComparisonOp::GTE => ">=",
+            ComparisonOp::LT => "<",
+            ComparisonOp::LTE => "<=",
+            ComparisonOp::Equal => "=",
+            ComparisonOp::NotEqual => "<>",
+        };
+        children.push(FormatTreeNode::new(format!(
+            "match condition: {} {} {}",
+            condition
+                .probe_key
+                .as_expr(&BUILTIN_FUNCTIONS)
+                .sql_display(),
+            op,
+            condition
+                .build_key
+                .as_expr(&BUILTIN_FUNCTIONS)
+                .sql_display()
+        )));
+    }
+
     if let Some((cache_index, column_map)) = &plan.build_side_cache_info {
         let mut column_indexes = column_map.keys().collect::<Vec<_>>();
         column_indexes.sort();
@@ -1575,6 +1599,30 @@ fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<S
         );
     }
 
+    // vector index pruning status.
+    if info.pruning_stats.blocks_vector_index_pruning_before > 0 {
+        if !blocks_pruning_description.is_empty() {
+            blocks_pruning_description += ", ";
+        }
+        blocks_pruning_description += &format!(
+            "vector pruning: {} to {}",
+            info.pruning_stats.blocks_vector_index_pruning_before,
+            info.pruning_stats.blocks_vector_index_pruning_after
+        );
+    }
+
+    // spatial index pruning status.
+    if info.pruning_stats.blocks_spatial_pruning_before > 0 {
+        if !blocks_pruning_description.is_empty() {
+            blocks_pruning_description += ", ";
+        }
+        blocks_pruning_description += &format!(
+            "spatial pruning: {} to {}",
+            info.pruning_stats.blocks_spatial_pruning_before,
+            info.pruning_stats.blocks_spatial_pruning_after
+        );
+    }
+
     // Combine segment pruning and blocks pruning descriptions if any
     if info.pruning_stats.segments_range_pruning_before > 0
         || !blocks_pruning_description.is_empty()
@@ -38,6 +38,7 @@ use crate::executor::PhysicalPlanBuilder;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
+use crate::plans::ComparisonOp;
 use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::ColumnEntry;
@@ -46,6 +47,14 @@ use crate::MetadataRef;
 use crate::ScalarExpr;
 use crate::TypeCheck;
 
+/// The match condition of asof join: `probe_key op build_key`.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AsofMatchCondition {
+    pub probe_key: RemoteExpr,
+    pub build_key: RemoteExpr,
+    pub op: ComparisonOp,
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct HashJoin {
     // A unique id of operator in a `PhysicalPlan` tree, only used for display.
@@ -91,6 +100,9 @@ pub struct HashJoin {
     // Hash join build side cache information for ExpressionScan, which includes the cache index and
     // a HashMap for mapping the column indexes to the BlockEntry indexes in DataBlock.
     pub build_side_cache_info: Option<(usize, HashMap<IndexType, usize>)>,
+
+    // The match condition for asof join.
+    pub asof_condition: Option<AsofMatchCondition>,
 }
 
 impl HashJoin {
@@ -173,7 +185,7 @@ impl PhysicalPlanBuilder {
         }
 
         let build_schema = match join.join_type {
-            JoinType::Left | JoinType::LeftSingle | JoinType::Full => {
+            JoinType::Left | JoinType::LeftSingle | JoinType::Full | JoinType::LeftAsof => {
                 let build_schema = build_side.output_schema()?;
                 // Wrap nullable type for columns in build side.
                 let build_schema = DataSchemaRefExt::create(
@@ -346,6 +358,56 @@ impl PhysicalPlanBuilder {
                 .push(left_expr_for_runtime_filter.map(|(expr, idx)| (expr.as_remote_expr(), idx)));
         }
 
+        let asof_condition = join
+            .asof_condition
+            .as_ref()
+            .map(|condition| {
+                let probe_expr = condition
+                    .left
+                    .type_check(probe_schema.as_ref())?
+                    .project_column_ref(|index| probe_schema.index_of(&index.to_string()).unwrap());
+                let build_expr = condition
+                    .right
+                    .type_check(build_schema.as_ref())?
+                    .project_column_ref(|index| build_schema.index_of(&index.to_string()).unwrap());
+                let common_ty = common_super_type(
+                    probe_expr.data_type().clone(),
+                    build_expr.data_type().clone(),
+                    &BUILTIN_FUNCTIONS.default_cast_rules,
+                )
+                .ok_or_else(|| {
+                    ErrorCode::IllegalDataType(format!(
+                        "Cannot find common type for asof match condition {:?} and {:?}",
+                        probe_expr.data_type(),
+                        build_expr.data_type()
+                    ))
+                })?;
+                let probe_expr = check_cast(
+                    probe_expr.span(),
+                    false,
+                    probe_expr,
+                    &common_ty,
+                    &BUILTIN_FUNCTIONS,
+                )?;
+                let build_expr = check_cast(
+                    build_expr.span(),
+                    false,
+                    build_expr,
+                    &common_ty,
+                    &BUILTIN_FUNCTIONS,
+                )?;
+                let (probe_expr, _) =
+                    ConstantFolder::fold(&probe_expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                let (build_expr, _) =
+                    ConstantFolder::fold(&build_expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                Ok::<_, ErrorCode>(AsofMatchCondition {
+                    probe_key: probe_expr.as_remote_expr(),
+                    build_key: build_expr.as_remote_expr(),
+                    op: condition.op,
+                })
+            })
+            .transpose()?;
+
         let mut cache_column_map = HashMap::new();
         let cached_column = if let Some(cache_info) = &join.build_side_cache_info {
             cache_info.columns.clone().into_iter().collect()
@@ -426,7 +488,9 @@ impl PhysicalPlanBuilder {
             | JoinType::LeftSingle
             | JoinType::Right
             | JoinType::RightSingle
-            | JoinType::Full => {
+            | JoinType::Full
+            | JoinType::Asof
+            | JoinType::LeftAsof => {
                 probe_fields.extend(build_fields);
                 probe_fields
             }
@@ -544,6 +608,7 @@ impl PhysicalPlanBuilder {
             )
             .await?,
             build_side_cache_info,
+            asof_condition,
         }))
     }
 }
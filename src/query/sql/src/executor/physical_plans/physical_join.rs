@@ -26,6 +26,7 @@ use crate::optimizer::SExpr;
 use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::ColumnSet;
+use crate::JoinMethodHint;
 use crate::ScalarExpr;
 
 pub enum PhysicalJoinType {
@@ -34,9 +35,16 @@ pub enum PhysicalJoinType {
     RangeJoin(Vec<ScalarExpr>, Vec<ScalarExpr>),
 }
 
-// Choose physical join type by join conditions
-pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
-    if !join.equi_conditions.is_empty() {
+// Choose physical join type by join conditions and the join method hint
+pub fn physical_join(
+    join: &Join,
+    s_expr: &SExpr,
+    join_method: Option<JoinMethodHint>,
+) -> Result<PhysicalJoinType> {
+    if !join.equi_conditions.is_empty()
+        || join.join_type.is_asof_join()
+        || join_method == Some(JoinMethodHint::Hash)
+    {
         // Contain equi condition, use hash join
         return Ok(PhysicalJoinType::Hash);
     }
@@ -49,8 +57,9 @@ pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
     let left_rel_expr = RelExpr::with_s_expr(s_expr.child(0)?);
     let right_rel_expr = RelExpr::with_s_expr(s_expr.child(1)?);
     let right_stat_info = right_rel_expr.derive_cardinality()?;
-    if matches!(right_stat_info.statistics.precise_cardinality, Some(1))
-        || right_stat_info.cardinality == 1.0
+    if join_method.is_none()
+        && (matches!(right_stat_info.statistics.precise_cardinality, Some(1))
+            || right_stat_info.cardinality == 1.0)
     {
         // If the output rows of build side is equal to 1, we use CROSS JOIN + FILTER instead of RANGE JOIN.
         return Ok(PhysicalJoinType::Hash);
@@ -130,6 +139,10 @@ impl PhysicalPlanBuilder {
             .fold(required.clone(), |acc, v| {
                 acc.union(&v.used_columns()).cloned().collect()
             });
+        if let Some(asof_condition) = &join.asof_condition {
+            others_required.extend(asof_condition.left.used_columns());
+            others_required.extend(asof_condition.right.used_columns());
+        }
         if let Some(cache_info) = &join.build_side_cache_info {
             for column in &cache_info.columns {
                 others_required.insert(*column);
@@ -160,7 +173,16 @@ impl PhysicalPlanBuilder {
 
         // 2. Build physical plan.
         // Choose physical join type by join conditions
-        let physical_join = physical_join(join, s_expr)?;
+        let join_method = self.metadata.read().plan_hints().join_method;
+        let physical_join = physical_join(join, s_expr, join_method)?;
+        if join_method == Some(JoinMethodHint::Merge)
+            && matches!(physical_join, PhysicalJoinType::Hash)
+        {
+            self.metadata.write().plan_hints_mut().add_ignored(
+                "MERGE_JOIN".to_string(),
+                "merge join requires an inner join with range conditions only",
+            );
+        }
         match physical_join {
             PhysicalJoinType::Hash => {
                 self.build_hash_join(
@@ -28,6 +28,7 @@ use bigbytesdb_common_exception::Result;
 use bigbytesdb_common_expression::types::DataType;
 use bigbytesdb_common_expression::TableSchema;
 use bigbytesdb_common_expression::ROW_ID_COL_NAME;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_TTL;
 
 use crate::binder::util::TableIdentifier;
 use crate::binder::Binder;
@@ -39,6 +40,7 @@ use crate::optimizer::SExpr;
 use crate::optimizer::SubqueryRewriter;
 use crate::plans::BoundColumnRef;
 use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::MutationSource;
 use crate::plans::RelOperator;
 use crate::plans::SubqueryExpr;
@@ -198,9 +200,31 @@ impl MutationExpression {
                     .ok_or_else(|| ErrorCode::Internal("Can't get target table index"))?;
 
                 // If the filter is a simple expression, change the mutation strategy to MutationStrategy::Direct.
-                let (mutation_strategy, filter) =
+                let (mutation_strategy, mut filter) =
                     binder.process_filter(&mut bind_context, filter)?;
 
+                // The ttl filter of the target table is dropped with the scan by a direct
+                // mutation, so expired rows must be excluded by the filter of the update.
+                // A direct delete may remove expired rows, which `OPTIMIZE TABLE ... EXPIRE`
+                // relies on.
+                if mutation_strategy == MutationStrategy::Direct
+                    && mutation_type == MutationType::Update
+                {
+                    if let Some(ttl) = target_table.options().get(OPT_KEY_TTL) {
+                        let ttl_predicate = binder.bind_ttl_predicate(&mut bind_context, ttl)?;
+                        filter = Some(match filter {
+                            Some(filter) => FunctionCall {
+                                span: None,
+                                func_name: "and".to_string(),
+                                params: vec![],
+                                arguments: vec![filter, ttl_predicate],
+                            }
+                            .into(),
+                            None => ttl_predicate,
+                        });
+                    }
+                }
+
                 // Build bind result according to mutation strategy.
                 if mutation_strategy == MutationStrategy::Direct {
                     let mut truncate_table = false;
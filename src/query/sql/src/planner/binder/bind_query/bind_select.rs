@@ -71,6 +71,7 @@ impl Binder {
                     hints, e
                 );
             }
+            self.bind_plan_hints(hints);
         }
 
         // whether allow rewrite virtual column and pushdown
@@ -38,9 +38,12 @@ use crate::optimizer::SubqueryRewriter;
 use crate::planner::binder::scalar::ScalarBinder;
 use crate::planner::binder::Binder;
 use crate::planner::semantic::NameResolutionContext;
+use crate::plans::AsofCondition;
 use crate::plans::BoundColumnRef;
+use crate::plans::ComparisonOp;
 use crate::plans::EvalScalar;
 use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::HashJoinBuildCacheInfo;
 use crate::plans::Join;
 use crate::plans::JoinEquiCondition;
@@ -57,6 +60,7 @@ pub struct JoinConditions {
     pub(crate) right_conditions: Vec<ScalarExpr>,
     pub(crate) non_equi_conditions: Vec<ScalarExpr>,
     pub(crate) other_conditions: Vec<ScalarExpr>,
+    pub(crate) asof_condition: Option<AsofCondition>,
 }
 
 impl Binder {
@@ -235,7 +239,7 @@ impl Binder {
         right_column_bindings: &mut Vec<ColumnBinding>,
     ) {
         match join_op {
-            JoinOperator::LeftOuter => {
+            JoinOperator::LeftOuter | JoinOperator::LeftAsof => {
                 self.replace_column_binding(right_derived_scalars, right_column_bindings);
             }
             JoinOperator::RightOuter => {
@@ -288,7 +292,7 @@ impl Binder {
         right_column_bindings: &mut Vec<ColumnBinding>,
     ) {
         match join_op {
-            JoinOperator::LeftOuter | JoinOperator::FullOuter => {
+            JoinOperator::LeftOuter | JoinOperator::FullOuter | JoinOperator::LeftAsof => {
                 for column in right_column_bindings {
                     if !column.data_type.is_nullable_or_null() {
                         column.data_type = Box::new(column.data_type.wrap_nullable());
@@ -321,6 +325,7 @@ impl Binder {
         let mut right_join_conditions: Vec<ScalarExpr> = vec![];
         let mut non_equi_conditions: Vec<ScalarExpr> = vec![];
         let mut other_conditions: Vec<ScalarExpr> = vec![];
+        let mut match_condition: Option<ScalarExpr> = None;
         let mut join_condition_resolver = JoinConditionResolver::new(
             self.ctx.clone(),
             &self.name_resolution_ctx,
@@ -337,14 +342,45 @@ impl Binder {
             &mut right_join_conditions,
             &mut non_equi_conditions,
             &mut other_conditions,
+            &mut match_condition,
             join_op,
         )?;
 
+        let asof_condition = match join_op {
+            JoinOperator::Asof | JoinOperator::LeftAsof => {
+                if left_join_conditions.is_empty() {
+                    return Err(ErrorCode::SemanticError(
+                        "ASOF JOIN requires at least one equality condition between the two tables"
+                            .to_string(),
+                    ));
+                }
+                // With `MATCH_CONDITION`, the match condition is given explicitly,
+                // otherwise it is the only non-equi condition in the `ON` clause.
+                let match_condition = match match_condition {
+                    Some(match_condition) if non_equi_conditions.is_empty() => match_condition,
+                    None if non_equi_conditions.len() == 1 => non_equi_conditions.remove(0),
+                    _ => {
+                        return Err(ErrorCode::SemanticError(
+                            "ASOF JOIN requires exactly one inequality condition between the two tables"
+                                .to_string(),
+                        ));
+                    }
+                };
+                Some(asof_condition(
+                    match_condition,
+                    left_column_bindings,
+                    right_column_bindings,
+                )?)
+            }
+            _ => None,
+        };
+
         Ok(JoinConditions {
             left_conditions: left_join_conditions,
             right_conditions: right_join_conditions,
             non_equi_conditions,
             other_conditions,
+            asof_condition,
         })
     }
 
@@ -361,6 +397,7 @@ impl Binder {
         let mut right_conditions = join_conditions.right_conditions;
         let mut non_equi_conditions = join_conditions.non_equi_conditions;
         let other_conditions = join_conditions.other_conditions;
+        let asof_condition = join_conditions.asof_condition;
 
         if join_type == JoinType::Cross
             && (!left_conditions.is_empty() || !right_conditions.is_empty())
@@ -413,6 +450,11 @@ impl Binder {
             // If there are cache indexes used in the expression scan context, we swap the left and right child
             // to make left child as the build side.
             if !self.expression_scan_context.used_cache_indexes.is_empty() {
+                if asof_condition.is_some() {
+                    return Err(ErrorCode::SemanticError(
+                        "ASOF JOIN does not support lateral subquery on the right side".to_string(),
+                    ));
+                }
                 (
                     right_child,
                     left_child,
@@ -443,6 +485,8 @@ impl Binder {
             is_lateral,
             single_to_inner: None,
             build_side_cache_info,
+            asof_condition,
+            distribution_hint: None,
         };
         Ok(SExpr::create_binary(
             Arc::new(logical_join.into()),
@@ -478,12 +522,16 @@ impl Binder {
                     | JoinType::LeftSemi
                     | JoinType::LeftAnti
                     | JoinType::RightSemi
-                    | JoinType::RightAnti => {
+                    | JoinType::RightAnti
+                    | JoinType::Asof => {
                         need_push_down = true;
                         left_push_down.push(predicate.clone());
                         right_push_down.push(predicate.clone());
                     }
-                    JoinType::Left | JoinType::LeftSingle | JoinType::RightMark => {
+                    JoinType::Left
+                    | JoinType::LeftSingle
+                    | JoinType::RightMark
+                    | JoinType::LeftAsof => {
                         need_push_down = true;
                         right_push_down.push(predicate.clone());
                     }
@@ -560,6 +608,22 @@ impl Binder {
                     "cross join should not contain join conditions".to_string(),
                 ));
             }
+            JoinOperator::Asof | JoinOperator::LeftAsof
+                if matches!(
+                    join_condition,
+                    JoinCondition::None | JoinCondition::Natural | JoinCondition::Using(_)
+                ) =>
+            {
+                return Err(ErrorCode::SemanticError(
+                    "asof join should contain a match condition".to_string(),
+                ));
+            }
+            JoinOperator::Asof | JoinOperator::LeftAsof => (),
+            _ if matches!(join_condition, JoinCondition::Match { .. }) => {
+                return Err(ErrorCode::SemanticError(
+                    "MATCH_CONDITION can only be used with ASOF JOIN".to_string(),
+                ));
+            }
             _ => (),
         };
 
@@ -651,6 +715,7 @@ impl<'a> JoinConditionResolver<'a> {
         right_join_conditions: &mut Vec<ScalarExpr>,
         non_equi_conditions: &mut Vec<ScalarExpr>,
         other_join_conditions: &mut Vec<ScalarExpr>,
+        match_condition: &mut Option<ScalarExpr>,
         join_op: &JoinOperator,
     ) -> Result<()> {
         match &self.join_condition {
@@ -694,6 +759,29 @@ impl<'a> JoinConditionResolver<'a> {
                     join_op,
                 )?
             }
+            JoinCondition::Match {
+                match_condition: condition,
+                on,
+            } => {
+                if let Some(on) = on {
+                    self.resolve_on(
+                        on,
+                        left_join_conditions,
+                        right_join_conditions,
+                        non_equi_conditions,
+                        other_join_conditions,
+                    )?;
+                } else {
+                    bind_join_columns(
+                        self.left_column_bindings,
+                        self.right_column_bindings,
+                        self.join_context,
+                    );
+                }
+                let condition = self.bind_predicate(condition)?;
+                self.check_join_allowed_scalar_expr(&vec![condition.clone()])?;
+                *match_condition = Some(condition);
+            }
             JoinCondition::None => {
                 bind_join_columns(
                     self.left_column_bindings,
@@ -913,6 +1001,24 @@ impl<'a> JoinConditionResolver<'a> {
         Ok(false)
     }
 
+    fn bind_predicate(&self, predicate: &Expr) -> Result<ScalarExpr> {
+        let mut join_context = (*self.join_context).clone();
+        bind_join_columns(
+            self.left_column_bindings,
+            self.right_column_bindings,
+            &mut join_context,
+        );
+        let mut scalar_binder = ScalarBinder::new(
+            &mut join_context,
+            self.ctx.clone(),
+            self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (predicate, _) = scalar_binder.bind(predicate)?;
+        Ok(predicate)
+    }
+
     fn add_other_conditions(
         &self,
         predicate: &Expr,
@@ -935,7 +1041,7 @@ impl<'a> JoinConditionResolver<'a> {
         let predicate_used_columns = predicate.used_columns();
         let (left_columns, right_columns) = self.left_right_columns()?;
         match self.join_op {
-            JoinOperator::LeftOuter => {
+            JoinOperator::LeftOuter | JoinOperator::LeftAsof => {
                 if predicate_used_columns.is_subset(&right_columns) {
                     other_join_conditions.push(predicate);
                     return Ok(true);
@@ -947,7 +1053,7 @@ impl<'a> JoinConditionResolver<'a> {
                     return Ok(true);
                 }
             }
-            JoinOperator::Inner => {
+            JoinOperator::Inner | JoinOperator::Asof => {
                 if predicate_used_columns.is_subset(&left_columns)
                     || predicate_used_columns.is_subset(&right_columns)
                 {
@@ -1003,6 +1109,60 @@ fn join_type(join_type: &JoinOperator) -> JoinType {
         JoinOperator::RightSemi => JoinType::RightSemi,
         JoinOperator::LeftAnti => JoinType::LeftAnti,
         JoinOperator::RightAnti => JoinType::RightAnti,
+        JoinOperator::Asof => JoinType::Asof,
+        JoinOperator::LeftAsof => JoinType::LeftAsof,
+    }
+}
+
+// Split the match condition of asof join into `left op right`.
+fn asof_condition(
+    match_condition: ScalarExpr,
+    left_column_bindings: &[ColumnBinding],
+    right_column_bindings: &[ColumnBinding],
+) -> Result<AsofCondition> {
+    let span = match_condition.span();
+    let error = || {
+        ErrorCode::SemanticError(
+            "ASOF JOIN match condition must be a comparison of the form `left >|>=|<|<= right`, each side referring to one table only"
+                .to_string(),
+        )
+        .set_span(span)
+    };
+    let ScalarExpr::FunctionCall(FunctionCall {
+        func_name,
+        arguments,
+        ..
+    }) = &match_condition
+    else {
+        return Err(error());
+    };
+    let op = match ComparisonOp::try_from_func_name(func_name) {
+        Some(
+            op @ (ComparisonOp::GT | ComparisonOp::GTE | ComparisonOp::LT | ComparisonOp::LTE),
+        ) if arguments.len() == 2 => op,
+        _ => return Err(error()),
+    };
+    let left_columns: ColumnSet = left_column_bindings.iter().map(|c| c.index).collect();
+    let right_columns: ColumnSet = right_column_bindings.iter().map(|c| c.index).collect();
+    let (lhs, rhs) = (&arguments[0], &arguments[1]);
+    let (lhs_columns, rhs_columns) = (lhs.used_columns(), rhs.used_columns());
+    if lhs_columns.is_empty() || rhs_columns.is_empty() {
+        return Err(error());
+    }
+    if lhs_columns.is_subset(&left_columns) && rhs_columns.is_subset(&right_columns) {
+        Ok(AsofCondition {
+            left: lhs.clone(),
+            right: rhs.clone(),
+            op,
+        })
+    } else if lhs_columns.is_subset(&right_columns) && rhs_columns.is_subset(&left_columns) {
+        Ok(AsofCondition {
+            left: rhs.clone(),
+            right: lhs.clone(),
+            op: op.reverse(),
+        })
+    } else {
+        Err(error())
     }
 }
 
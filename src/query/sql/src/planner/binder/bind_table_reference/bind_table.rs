@@ -37,6 +37,7 @@ use bigbytesdb_common_meta_app::schema::VirtualField;
 use bigbytesdb_common_meta_types::MetaId;
 use bigbytesdb_common_storages_view::view_table::QUERY;
 use bigbytesdb_storages_common_table_meta::table::get_change_type;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_TTL;
 
 use crate::binder::util::TableIdentifier;
 use crate::binder::Binder;
@@ -292,7 +293,7 @@ impl Binder {
                     cte_suffix_name,
                 );
 
-                let (s_expr, mut bind_context) = self.bind_base_table(
+                let (mut s_expr, mut bind_context) = self.bind_base_table(
                     bind_context,
                     database.as_str(),
                     table_index,
@@ -312,6 +313,12 @@ impl Binder {
                     table_name.as_str(),
                 )?;
 
+                if let Some(ttl) = table_meta.options().get(OPT_KEY_TTL)
+                    && !bind_context.planning_agg_index
+                {
+                    s_expr = self.bind_ttl_filter(&mut bind_context, ttl, s_expr)?;
+                }
+
                 Ok((s_expr, bind_context))
             }
         }
@@ -21,6 +21,8 @@ use std::time::Instant;
 use chrono_tz::Tz;
 use bigbytesdb_common_ast::ast::Hint;
 use bigbytesdb_common_ast::ast::Identifier;
+use bigbytesdb_common_ast::ast::LeadingItem;
+use bigbytesdb_common_ast::ast::PlanHint;
 use bigbytesdb_common_ast::ast::Settings;
 use bigbytesdb_common_ast::ast::Statement;
 use bigbytesdb_common_ast::parser::parse_sql;
@@ -80,8 +82,11 @@ use crate::plans::UseDatabasePlan;
 use crate::plans::Visitor;
 use crate::BindContext;
 use crate::ColumnBinding;
+use crate::JoinMethodHint;
+use crate::JoinOrderHint;
 use crate::MetadataRef;
 use crate::NameResolutionContext;
+use crate::PlanHints;
 use crate::ScalarExpr;
 use crate::TypeChecker;
 use crate::Visibility;
@@ -319,9 +324,9 @@ impl<'a> Binder {
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
             Statement::DropIndex(stmt) => self.bind_drop_index(stmt).await?,
             Statement::RefreshIndex(stmt) => self.bind_refresh_index(bind_context, stmt).await?,
-            Statement::CreateInvertedIndex(stmt) => self.bind_create_inverted_index(bind_context, stmt).await?,
-            Statement::DropInvertedIndex(stmt) => self.bind_drop_inverted_index(bind_context, stmt).await?,
-            Statement::RefreshInvertedIndex(stmt) => self.bind_refresh_inverted_index(bind_context, stmt).await?,
+            Statement::CreateTableIndex(stmt) => self.bind_create_table_index(bind_context, stmt).await?,
+            Statement::DropTableIndex(stmt) => self.bind_drop_table_index(bind_context, stmt).await?,
+            Statement::RefreshTableIndex(stmt) => self.bind_refresh_table_index(bind_context, stmt).await?,
 
             // Virtual Columns
             Statement::CreateVirtualColumn(stmt) => self.bind_create_virtual_column(stmt).await?,
@@ -365,6 +370,7 @@ impl<'a> Binder {
                 if_exists: *if_exists,
                 role_name: role_name.to_string(),
             })),
+            Statement::AlterRole(stmt) => self.bind_alter_role(stmt).await?,
 
             // Stages
             Statement::ShowStages => self.bind_rewrite_to_query(bind_context, "SELECT name, stage_type, number_of_files, creator, created_on, comment FROM system.stages ORDER BY name", RewriteKind::ShowStages).await?,
@@ -555,6 +561,14 @@ impl<'a> Binder {
                 self.bind_desc_password_policy(stmt).await?
             }
             Statement::ShowPasswordPolicies { show_options } => self.bind_show_password_policies(bind_context, show_options).await?,
+            Statement::CreatePlanBaseline(stmt) => {
+                self.bind_create_plan_baseline(bind_context, stmt).await?
+            }
+            Statement::DropPlanBaseline(stmt) => self.bind_drop_plan_baseline(stmt).await?,
+            Statement::CreateWorkloadGroup(stmt) => self.bind_create_workload_group(stmt).await?,
+            Statement::AlterWorkloadGroup(stmt) => self.bind_alter_workload_group(stmt).await?,
+            Statement::DropWorkloadGroup(stmt) => self.bind_drop_workload_group(stmt).await?,
+            Statement::ShowWorkloadGroups => self.bind_rewrite_to_query(bind_context, "SELECT name, max_concurrency, max_queued, queued_timeout, max_memory_usage, cpu_weight, running, queued FROM system.workload_groups ORDER BY name", RewriteKind::ShowWorkloadGroups).await?,
             Statement::CreateTask(stmt) => {
                 self.bind_create_task(stmt).await?
             }
@@ -583,6 +597,17 @@ impl<'a> Binder {
             // Dynamic Table
             Statement::CreateDynamicTable(stmt) => self.bind_create_dynamic_table(stmt).await?,
 
+            // Materialized Views
+            Statement::CreateMaterializedView(stmt) => {
+                self.bind_create_materialized_view(stmt).await?
+            }
+            Statement::RefreshMaterializedView(stmt) => {
+                self.bind_refresh_materialized_view(stmt).await?
+            }
+            Statement::DropMaterializedView(stmt) => {
+                self.bind_drop_materialized_view(stmt).await?
+            }
+
             Statement::CreatePipe(_) => {
                 todo!()
             }
@@ -742,6 +767,17 @@ impl<'a> Binder {
             .set_batch_settings(&hint_settings, true)
     }
 
+    /// Record the optimizer hints into metadata, a hint conflicting with
+    /// a previous one is ignored.
+    pub(crate) fn bind_plan_hints(&mut self, hints: &Hint) {
+        let mut metadata = self.metadata.write();
+        bind_plan_hints(
+            metadata.plan_hints_mut(),
+            &hints.plan_hints,
+            &self.name_resolution_ctx,
+        );
+    }
+
     pub fn set_bind_recursive_cte(&mut self, val: bool) {
         self.bind_recursive_cte = val;
     }
@@ -1107,3 +1143,69 @@ async fn try_purge_files(ctx: Arc<dyn TableContext>, stage_info: &StageInfo, fil
         metrics_inc_copy_purge_files_cost_milliseconds(elapsed.as_millis() as u32);
     }
 }
+
+/// Record the optimizer hints into `plan_hints`, a hint conflicting with
+/// a previous one is ignored.
+pub(crate) fn bind_plan_hints(
+    plan_hints: &mut PlanHints,
+    hints: &[PlanHint],
+    name_resolution_ctx: &NameResolutionContext,
+) {
+    for hint in hints.iter() {
+        match hint {
+            PlanHint::Leading(items) => {
+                if plan_hints.leading.is_some() {
+                    plan_hints.add_ignored(hint.to_string(), "conflicts with another LEADING");
+                    continue;
+                }
+                plan_hints.leading = items
+                    .iter()
+                    .map(|item| bind_leading_item(item, name_resolution_ctx))
+                    .reduce(|left, right| JoinOrderHint::Join(Box::new(left), Box::new(right)));
+            }
+            PlanHint::Broadcast(table) | PlanHint::Shuffle(table) => {
+                let table = normalize_identifier(table, name_resolution_ctx).name;
+                if plan_hints.broadcast.contains(&table) || plan_hints.shuffle.contains(&table) {
+                    plan_hints.add_ignored(
+                        hint.to_string(),
+                        "conflicts with another BROADCAST or SHUFFLE",
+                    );
+                } else if matches!(hint, PlanHint::Broadcast(_)) {
+                    plan_hints.broadcast.push(table);
+                } else {
+                    plan_hints.shuffle.push(table);
+                }
+            }
+            PlanHint::HashJoin | PlanHint::MergeJoin => {
+                let method = if matches!(hint, PlanHint::HashJoin) {
+                    JoinMethodHint::Hash
+                } else {
+                    JoinMethodHint::Merge
+                };
+                match plan_hints.join_method {
+                    Some(join_method) if join_method != method => {
+                        plan_hints
+                            .add_ignored(hint.to_string(), "conflicts with another join method");
+                    }
+                    _ => plan_hints.join_method = Some(method),
+                }
+            }
+            PlanHint::NoDecorrelate => plan_hints.no_decorrelate = true,
+        }
+    }
+}
+
+fn bind_leading_item(
+    item: &LeadingItem,
+    name_resolution_ctx: &NameResolutionContext,
+) -> JoinOrderHint {
+    match item {
+        LeadingItem::Table(table) => {
+            JoinOrderHint::Table(normalize_identifier(table, name_resolution_ctx).name)
+        }
+        LeadingItem::Join(left, right) => JoinOrderHint::Join(
+            Box::new(bind_leading_item(left, name_resolution_ctx)),
+            Box::new(bind_leading_item(right, name_resolution_ctx)),
+        ),
+    }
+}
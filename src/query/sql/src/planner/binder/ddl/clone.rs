@@ -0,0 +1,131 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigbytesdb_common_ast::ast::CreateDatabaseStmt;
+use bigbytesdb_common_ast::ast::CreateTableSource;
+use bigbytesdb_common_ast::ast::CreateTableStmt;
+use bigbytesdb_common_ast::ast::DatabaseRef;
+use bigbytesdb_common_ast::ast::Engine;
+use bigbytesdb_common_ast::ast::TableType;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+
+use crate::normalize_identifier;
+use crate::plans::CloneDatabasePlan;
+use crate::plans::CloneTablePlan;
+use crate::plans::CreateDatabasePlan;
+use crate::plans::Plan;
+use crate::BindContext;
+use crate::Binder;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_clone_table(
+        &mut self,
+        stmt: &CreateTableStmt,
+    ) -> Result<Plan> {
+        let CreateTableStmt {
+            create_option,
+            catalog,
+            database,
+            table,
+            source,
+            table_options,
+            cluster_by,
+            as_query,
+            table_type,
+            engine,
+            uri_location,
+        } = stmt;
+
+        let Some(CreateTableSource::Clone {
+            catalog: source_catalog,
+            database: source_database,
+            table: source_table,
+            travel_point,
+        }) = source
+        else {
+            return Err(ErrorCode::Internal(
+                "Logical error, CLONE table statement without a source table",
+            ));
+        };
+
+        // A clone takes its definition from the source table.
+        if as_query.is_some()
+            || uri_location.is_some()
+            || cluster_by.is_some()
+            || !table_options.is_empty()
+            || !matches!(engine, None | Some(Engine::Fuse))
+            || *table_type != TableType::Normal
+        {
+            return Err(ErrorCode::BadArguments(
+                "Incorrect CREATE query: CREATE TABLE ... CLONE does not accept engine, location, cluster key, options, table type or AS section",
+            ));
+        }
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let (source_catalog, source_database, source_table) =
+            self.normalize_object_identifier_triple(source_catalog, source_database, source_table);
+
+        let navigation = if let Some(point) = travel_point {
+            let mut bind_context = BindContext::new();
+            Some(self.resolve_data_travel_point(&mut bind_context, point)?)
+        } else {
+            None
+        };
+
+        Ok(Plan::CloneTable(Box::new(CloneTablePlan {
+            create_option: create_option.clone().into(),
+            tenant: self.ctx.get_tenant(),
+            catalog,
+            database,
+            table,
+            source_catalog,
+            source_database,
+            source_table,
+            navigation,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_clone_database(
+        &self,
+        stmt: &CreateDatabaseStmt,
+        create_database: CreateDatabasePlan,
+    ) -> Result<Plan> {
+        let Some(DatabaseRef { catalog, database }) = &stmt.clone_from else {
+            return Err(ErrorCode::Internal(
+                "Logical error, CLONE database statement without a source database",
+            ));
+        };
+
+        let source_catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let source_database = normalize_identifier(database, &self.name_resolution_ctx).name;
+        if source_catalog != create_database.catalog {
+            return Err(ErrorCode::BadArguments(
+                "CREATE DATABASE ... CLONE can not clone a database of another catalog",
+            ));
+        }
+
+        Ok(Plan::CloneDatabase(Box::new(CloneDatabasePlan {
+            create_database,
+            source_catalog,
+            source_database,
+        })))
+    }
+}
@@ -253,6 +253,7 @@ impl Binder {
             database: DatabaseRef { catalog, database },
             engine,
             options,
+            clone_from,
         } = stmt;
 
         let tenant = self.ctx.get_tenant();
@@ -264,13 +265,17 @@ impl Binder {
 
         let meta = self.database_meta(engine, options)?;
 
-        Ok(Plan::CreateDatabase(Box::new(CreateDatabasePlan {
+        let plan = CreateDatabasePlan {
             create_option: create_option.clone().into(),
             tenant,
             catalog,
             database,
             meta,
-        })))
+        };
+        if clone_from.is_some() {
+            return self.bind_clone_database(stmt, plan).await;
+        }
+        Ok(Plan::CreateDatabase(Box::new(plan)))
     }
 
     fn database_meta(
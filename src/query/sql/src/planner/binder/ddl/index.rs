@@ -18,21 +18,23 @@ use std::collections::HashSet;
 use std::sync::LazyLock;
 
 use bigbytesdb_common_ast::ast::CreateIndexStmt;
-use bigbytesdb_common_ast::ast::CreateInvertedIndexStmt;
+use bigbytesdb_common_ast::ast::CreateTableIndexStmt;
 use bigbytesdb_common_ast::ast::DropIndexStmt;
-use bigbytesdb_common_ast::ast::DropInvertedIndexStmt;
+use bigbytesdb_common_ast::ast::DropTableIndexStmt;
 use bigbytesdb_common_ast::ast::ExplainKind;
 use bigbytesdb_common_ast::ast::Identifier;
 use bigbytesdb_common_ast::ast::Query;
 use bigbytesdb_common_ast::ast::RefreshIndexStmt;
-use bigbytesdb_common_ast::ast::RefreshInvertedIndexStmt;
+use bigbytesdb_common_ast::ast::RefreshTableIndexStmt;
 use bigbytesdb_common_ast::ast::SetExpr;
 use bigbytesdb_common_ast::ast::Statement;
+use bigbytesdb_common_ast::ast::TableIndexType as AstTableIndexType;
 use bigbytesdb_common_ast::ast::TableReference;
 use bigbytesdb_common_ast::parser::parse_sql;
 use bigbytesdb_common_ast::parser::tokenize_sql;
 use bigbytesdb_common_exception::ErrorCode;
 use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::types::NumberDataType;
 use bigbytesdb_common_expression::ColumnId;
 use bigbytesdb_common_expression::TableDataType;
 use bigbytesdb_common_expression::TableSchemaRef;
@@ -41,6 +43,8 @@ use bigbytesdb_common_license::license_manager::LicenseManagerSwitch;
 use bigbytesdb_common_meta_app::schema::GetIndexReq;
 use bigbytesdb_common_meta_app::schema::IndexMeta;
 use bigbytesdb_common_meta_app::schema::IndexNameIdent;
+use bigbytesdb_common_meta_app::schema::TableIndexType;
+use bigbytesdb_common_meta_app::schema::TableInfo;
 use bigbytesdb_storages_common_table_meta::meta::Location;
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
@@ -100,6 +104,36 @@ fn is_valid_index_record_values<S: AsRef<str>>(opt_val: S) -> bool {
     INDEX_RECORD_VALUES.contains(opt_val.as_ref())
 }
 
+// upper bound of the vector index options `m` and `ef_construction`
+const MAX_VECTOR_INDEX_PARAM: u64 = 1000;
+
+fn table_index_type(index_type: &AstTableIndexType) -> Result<TableIndexType> {
+    match index_type {
+        AstTableIndexType::Inverted => Ok(TableIndexType::Inverted),
+        AstTableIndexType::Vector => Ok(TableIndexType::Vector),
+        AstTableIndexType::Aggregating => Err(ErrorCode::UnsupportedIndex(
+            "Aggregating index can not be created on table columns",
+        )),
+    }
+}
+
+// The index type in the statement must match the type of the existing index.
+fn check_table_index_type(
+    table_info: &TableInfo,
+    index_name: &str,
+    index_type: TableIndexType,
+) -> Result<()> {
+    match table_info.meta.indexes.get(index_name) {
+        Some(index) if index.index_type != index_type => Err(ErrorCode::UnsupportedIndex(format!(
+            "Index {} is a {} index, not a {} index",
+            index_name,
+            index.index_type.to_string().to_lowercase(),
+            index_type.to_string().to_lowercase()
+        ))),
+        _ => Ok(()),
+    }
+}
+
 impl Binder {
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_query_index(
@@ -410,12 +444,13 @@ impl Binder {
     }
 
     #[async_backtrace::framed]
-    pub(in crate::planner::binder) async fn bind_create_inverted_index(
+    pub(in crate::planner::binder) async fn bind_create_table_index(
         &mut self,
         _bind_context: &mut BindContext,
-        stmt: &CreateInvertedIndexStmt,
+        stmt: &CreateTableIndexStmt,
     ) -> Result<Plan> {
-        let CreateInvertedIndexStmt {
+        let CreateTableIndexStmt {
+            index_type,
             create_option,
             index_name,
             catalog,
@@ -426,40 +461,53 @@ impl Binder {
             index_options,
         } = stmt;
 
+        let index_type = table_index_type(index_type)?;
         let (catalog, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
 
         let table = self.ctx.get_table(&catalog, &database, &table).await?;
 
+        let index_kind = index_type.to_string().to_lowercase();
         if table.is_read_only() {
             return Err(ErrorCode::UnsupportedIndex(format!(
-                "Table {} is read-only, creating inverted index not allowed",
-                table.name()
+                "Table {} is read-only, creating {} index not allowed",
+                table.name(),
+                index_kind
             )));
         }
 
         if !table.support_index() {
             return Err(ErrorCode::UnsupportedIndex(format!(
-                "Table engine {} does not support create inverted index",
-                table.engine()
+                "Table engine {} does not support create {} index",
+                table.engine(),
+                index_kind
             )));
         }
         if table.is_temp() {
             return Err(ErrorCode::UnsupportedIndex(format!(
-                "Table {} is temporary table, creating inverted index not allowed",
-                table.name()
+                "Table {} is temporary table, creating {} index not allowed",
+                table.name(),
+                index_kind
             )));
         }
         let table_schema = table.schema();
         let table_id = table.get_id();
         let index_name = self.normalize_object_identifier(index_name);
-        let column_ids = self
-            .validate_inverted_index_columns(table_schema, columns)
-            .await?;
-        let index_options = self.validate_inverted_index_options(index_options).await?;
+        let (column_ids, index_options) = match index_type {
+            TableIndexType::Inverted => (
+                self.validate_inverted_index_columns(table_schema, columns)
+                    .await?,
+                self.validate_inverted_index_options(index_options).await?,
+            ),
+            TableIndexType::Vector => (
+                self.validate_vector_index_columns(table_schema, columns)?,
+                self.validate_vector_index_options(index_options)?,
+            ),
+        };
 
         let plan = CreateTableIndexPlan {
             create_option: create_option.clone().into(),
+            index_type,
             catalog,
             index_name,
             column_ids,
@@ -558,13 +606,84 @@ impl Binder {
         Ok(options)
     }
 
+    pub(in crate::planner::binder) fn validate_vector_index_columns(
+        &self,
+        table_schema: TableSchemaRef,
+        columns: &[Identifier],
+    ) -> Result<Vec<ColumnId>> {
+        if columns.len() != 1 {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Vector index must be created on exactly one column, but got {}",
+                columns.len()
+            )));
+        }
+        let column = &columns[0];
+        let Ok(field) = table_schema.field_with_name(&column.name) else {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Table does not have column {}",
+                column
+            )));
+        };
+        let is_float32_array = match field.data_type.remove_nullable() {
+            TableDataType::Array(inner) => *inner == TableDataType::Number(NumberDataType::Float32),
+            _ => false,
+        };
+        if !is_float32_array {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Vector index currently only support Array(Float32) type, but the type of column {} is {}",
+                column, field.data_type
+            )));
+        }
+        Ok(vec![field.column_id])
+    }
+
+    pub(in crate::planner::binder) fn validate_vector_index_options(
+        &self,
+        index_options: &BTreeMap<String, String>,
+    ) -> Result<BTreeMap<String, String>> {
+        let mut options = BTreeMap::new();
+        options.insert("distance".to_string(), "cosine".to_string());
+        for (opt, val) in index_options.iter() {
+            let key = opt.to_lowercase();
+            let value = val.to_lowercase();
+            match key.as_str() {
+                "distance" => {
+                    if value != "cosine" && value != "l2" {
+                        return Err(ErrorCode::IndexOptionInvalid(format!(
+                            "value `{value}` is invalid index distance, must be `cosine` or `l2`",
+                        )));
+                    }
+                    options.insert(key, value);
+                }
+                "m" | "ef_construction" => {
+                    let valid = value
+                        .parse::<u64>()
+                        .is_ok_and(|v| (2..=MAX_VECTOR_INDEX_PARAM).contains(&v));
+                    if !valid {
+                        return Err(ErrorCode::IndexOptionInvalid(format!(
+                            "value `{value}` is invalid index {key}, must be an integer between 2 and {MAX_VECTOR_INDEX_PARAM}",
+                        )));
+                    }
+                    options.insert(key, value);
+                }
+                _ => {
+                    return Err(ErrorCode::IndexOptionInvalid(format!(
+                        "index option `{key}` is invalid key for create vector index statement",
+                    )));
+                }
+            }
+        }
+        Ok(options)
+    }
+
     #[async_backtrace::framed]
-    pub(in crate::planner::binder) async fn bind_drop_inverted_index(
+    pub(in crate::planner::binder) async fn bind_drop_table_index(
         &mut self,
         _bind_context: &mut BindContext,
-        stmt: &DropInvertedIndexStmt,
+        stmt: &DropTableIndexStmt,
     ) -> Result<Plan> {
-        let DropInvertedIndexStmt {
+        let DropTableIndexStmt {
+            index_type,
             if_exists,
             index_name,
             catalog,
@@ -575,17 +694,21 @@ impl Binder {
         let (catalog, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
 
+        let index_type = table_index_type(index_type)?;
         let table = self.ctx.get_table(&catalog, &database, &table).await?;
         if !table.support_index() {
             return Err(ErrorCode::UnsupportedIndex(format!(
-                "Table engine {} does not support create inverted index",
-                table.engine()
+                "Table engine {} does not support drop {} index",
+                table.engine(),
+                index_type.to_string().to_lowercase()
             )));
         }
         let table_id = table.get_id();
         let index_name = self.normalize_object_identifier(index_name);
+        check_table_index_type(table.get_table_info(), &index_name, index_type)?;
 
         let plan = DropTableIndexPlan {
+            index_type,
             if_exists: *if_exists,
             catalog,
             index_name,
@@ -595,12 +718,13 @@ impl Binder {
     }
 
     #[async_backtrace::framed]
-    pub(in crate::planner::binder) async fn bind_refresh_inverted_index(
+    pub(in crate::planner::binder) async fn bind_refresh_table_index(
         &mut self,
         _bind_context: &mut BindContext,
-        stmt: &RefreshInvertedIndexStmt,
+        stmt: &RefreshTableIndexStmt,
     ) -> Result<Plan> {
-        let RefreshInvertedIndexStmt {
+        let RefreshTableIndexStmt {
+            index_type,
             index_name,
             catalog,
             database,
@@ -612,6 +736,10 @@ impl Binder {
             self.normalize_object_identifier_triple(catalog, database, table);
         let index_name = self.normalize_object_identifier(index_name);
 
+        let index_type = table_index_type(index_type)?;
+        let table = self.ctx.get_table(&catalog, &database, &table).await?;
+        check_table_index_type(table.get_table_info(), &index_name, index_type)?;
+
         let plan = RefreshTableIndexPlan {
             catalog,
             database,
@@ -0,0 +1,227 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use bigbytesdb_common_ast::ast::CreateMaterializedViewStmt;
+use bigbytesdb_common_ast::ast::CreateTableStmt;
+use bigbytesdb_common_ast::ast::DropMaterializedViewStmt;
+use bigbytesdb_common_ast::ast::Engine;
+use bigbytesdb_common_ast::ast::RefreshMaterializedViewStmt;
+use bigbytesdb_common_ast::ast::RefreshMode;
+use bigbytesdb_common_ast::ast::TableReference;
+use bigbytesdb_common_ast::ast::TableType;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_MAX_STALENESS;
+use derive_visitor::DriveMut;
+
+use crate::incremental_refresh;
+use crate::materialized_view_query;
+use crate::normalize_identifier;
+use crate::plans::CreateMaterializedViewPlan;
+use crate::plans::DropTablePlan;
+use crate::plans::Plan;
+use crate::plans::RefreshMaterializedViewPlan;
+use crate::table_references;
+use crate::Binder;
+use crate::ViewRewriter;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_materialized_view(
+        &mut self,
+        stmt: &CreateMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let CreateMaterializedViewStmt {
+            create_option,
+            catalog,
+            database,
+            view,
+            max_staleness,
+            refresh_mode,
+            query,
+        } = stmt;
+
+        let (catalog_name, database_name, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+
+        // The tables of the query are resolved in the database of the view.
+        let mut query = *query.clone();
+        query.drive_mut(&mut ViewRewriter {
+            current_database: database_name.clone(),
+        });
+
+        for table_ref in table_references(&query) {
+            let TableReference::Table {
+                catalog,
+                database,
+                table,
+                temporal,
+                ..
+            } = &table_ref
+            else {
+                continue;
+            };
+            if temporal.is_some() {
+                return Err(ErrorCode::IllegalMaterializedView(format!(
+                    "materialized view {view_name} can't read the table {table} at a time travel point"
+                )));
+            }
+            let catalog = catalog
+                .as_ref()
+                .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+                .unwrap_or_else(|| self.ctx.get_current_catalog());
+            let database = database
+                .as_ref()
+                .map(|database| normalize_identifier(database, &self.name_resolution_ctx).name)
+                .unwrap_or_else(|| database_name.clone());
+            let table_name = normalize_identifier(table, &self.name_resolution_ctx).name;
+            let table = self.ctx.get_table(&catalog, &database, &table_name).await?;
+            // The refresh state records the snapshots of the base tables.
+            if table.engine() != "FUSE" {
+                return Err(ErrorCode::IllegalMaterializedView(format!(
+                    "materialized view {view_name} can only read FUSE tables, but {database}.{table_name} is {}",
+                    table.engine()
+                )));
+            }
+        }
+
+        if *refresh_mode == RefreshMode::Incremental && incremental_refresh(&query).is_none() {
+            return Err(ErrorCode::IllegalMaterializedView(format!(
+                "materialized view {view_name} can't be refreshed incrementally, \
+                only the queries joining tables by inner joins without subqueries, \
+                and aggregating by count, sum, min and max are supported"
+            )));
+        }
+
+        let create_table_stmt = CreateTableStmt {
+            create_option: create_option.clone(),
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: view.clone(),
+            source: None,
+            engine: Some(Engine::Fuse),
+            uri_location: None,
+            cluster_by: None,
+            table_options: BTreeMap::new(),
+            as_query: Some(Box::new(query.clone())),
+            table_type: TableType::Normal,
+        };
+        let Plan::CreateTable(mut create_table) =
+            self.bind_create_table(&create_table_stmt).await?
+        else {
+            unreachable!()
+        };
+        // The view is filled by a full refresh after it is created.
+        create_table.as_select = None;
+        create_table.options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_QUERY.to_string(),
+            materialized_view_query(&query, &database_name),
+        );
+        create_table.options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE.to_string(),
+            refresh_mode.to_string().to_lowercase(),
+        );
+        if let Some(max_staleness) = max_staleness {
+            create_table
+                .options
+                .insert(OPT_KEY_MAX_STALENESS.to_string(), max_staleness.to_string());
+        }
+
+        Ok(Plan::CreateMaterializedView(Box::new(
+            CreateMaterializedViewPlan {
+                create_table: *create_table,
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_materialized_view(
+        &mut self,
+        stmt: &RefreshMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let RefreshMaterializedViewStmt {
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let (catalog, database, view) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        self.check_materialized_view(&catalog, &database, &view)
+            .await?;
+
+        Ok(Plan::RefreshMaterializedView(Box::new(
+            RefreshMaterializedViewPlan {
+                catalog,
+                database,
+                view,
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_materialized_view(
+        &mut self,
+        stmt: &DropMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let DropMaterializedViewStmt {
+            if_exists,
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let (catalog, database, view) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        match self
+            .check_materialized_view(&catalog, &database, &view)
+            .await
+        {
+            Ok(_) => {}
+            Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE && *if_exists => {}
+            Err(e) => return Err(e),
+        }
+
+        Ok(Plan::DropTable(Box::new(DropTablePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            catalog,
+            database,
+            table: view,
+            all: false,
+        })))
+    }
+
+    async fn check_materialized_view(
+        &self,
+        catalog: &str,
+        database: &str,
+        view: &str,
+    ) -> Result<()> {
+        let table = self.ctx.get_table(catalog, database, view).await?;
+        if !table
+            .options()
+            .contains_key(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+        {
+            return Err(ErrorCode::IllegalMaterializedView(format!(
+                "{database}.{view} is not a materialized view"
+            )));
+        }
+        Ok(())
+    }
+}
@@ -14,6 +14,7 @@
 
 mod account;
 mod catalog;
+mod clone;
 mod column;
 mod connection;
 mod data_mask;
@@ -21,9 +22,11 @@ mod database;
 mod dictionary;
 mod dynamic_table;
 mod index;
+mod materialized_view;
 mod network_policy;
 mod notification;
 mod password_policy;
+mod plan_baseline;
 mod procedure;
 mod role;
 mod sequence;
@@ -34,3 +37,4 @@ mod task;
 mod view;
 mod virtual_column;
 mod warehouse;
+mod workload_group;
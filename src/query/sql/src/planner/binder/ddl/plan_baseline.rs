@@ -0,0 +1,81 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigbytesdb_common_ast::ast::CreatePlanBaselineStmt;
+use bigbytesdb_common_ast::ast::DropPlanBaselineStmt;
+use bigbytesdb_common_ast::ast::Statement;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+
+use crate::normalize_identifier;
+use crate::planner::plan_baseline::may_join;
+use crate::plans::CreatePlanBaselinePlan;
+use crate::plans::DropPlanBaselinePlan;
+use crate::plans::Plan;
+use crate::query_fingerprint;
+use crate::BindContext;
+use crate::Binder;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_plan_baseline(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &CreatePlanBaselineStmt,
+    ) -> Result<Plan> {
+        let CreatePlanBaselineStmt {
+            create_option,
+            name,
+            query,
+        } = stmt;
+
+        // The plan baseline only pins the joins of the query.
+        if !may_join(query) {
+            return Err(ErrorCode::SemanticError(
+                "CREATE PLAN BASELINE requires a query joining tables",
+            )
+            .set_span(query.span));
+        }
+
+        let fingerprint = query_fingerprint(query, &self.ctx.get_current_database());
+        let plan = self
+            .bind_statement(bind_context, &Statement::Query(query.clone()))
+            .await?;
+
+        let plan = CreatePlanBaselinePlan {
+            create_option: create_option.clone().into(),
+            tenant: self.ctx.get_tenant(),
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+            fingerprint,
+            query: query.to_string(),
+            plan: Box::new(plan),
+        };
+        Ok(Plan::CreatePlanBaseline(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_plan_baseline(
+        &mut self,
+        stmt: &DropPlanBaselineStmt,
+    ) -> Result<Plan> {
+        let DropPlanBaselineStmt { if_exists, name } = stmt;
+
+        let plan = DropPlanBaselinePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+        };
+        Ok(Plan::DropPlanBaseline(Box::new(plan)))
+    }
+}
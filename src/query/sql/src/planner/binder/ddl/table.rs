@@ -34,7 +34,6 @@ use bigbytesdb_common_ast::ast::Engine;
 use bigbytesdb_common_ast::ast::ExistsTableStmt;
 use bigbytesdb_common_ast::ast::Expr;
 use bigbytesdb_common_ast::ast::Identifier;
-use bigbytesdb_common_ast::ast::InvertedIndexDefinition;
 use bigbytesdb_common_ast::ast::ModifyColumnAction;
 use bigbytesdb_common_ast::ast::OptimizeTableAction as AstOptimizeTableAction;
 use bigbytesdb_common_ast::ast::OptimizeTableStmt;
@@ -46,6 +45,8 @@ use bigbytesdb_common_ast::ast::ShowLimit;
 use bigbytesdb_common_ast::ast::ShowTablesStatusStmt;
 use bigbytesdb_common_ast::ast::ShowTablesStmt;
 use bigbytesdb_common_ast::ast::Statement;
+use bigbytesdb_common_ast::ast::TableIndexDefinition;
+use bigbytesdb_common_ast::ast::TableIndexType as AstTableIndexType;
 use bigbytesdb_common_ast::ast::TableReference;
 use bigbytesdb_common_ast::ast::TableType;
 use bigbytesdb_common_ast::ast::TruncateTableStmt;
@@ -84,6 +85,7 @@ use bigbytesdb_common_license::license::Feature;
 use bigbytesdb_common_license::license_manager::LicenseManagerSwitch;
 use bigbytesdb_common_meta_app::schema::CreateOption;
 use bigbytesdb_common_meta_app::schema::TableIndex;
+use bigbytesdb_common_meta_app::schema::TableIndexType;
 use bigbytesdb_common_meta_app::storage::StorageParams;
 use bigbytesdb_common_storage::check_operator;
 use bigbytesdb_common_storage::init_operator;
@@ -121,8 +123,10 @@ use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CreateSnapshotTagPlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
+use crate::plans::DropSnapshotTagPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
@@ -133,6 +137,7 @@ use crate::plans::ModifyTableCommentPlan;
 use crate::plans::OptimizeCompactBlock;
 use crate::plans::OptimizeCompactSegmentPlan;
 use crate::plans::OptimizePurgePlan;
+use crate::plans::OptimizeRefreshNgramIndexPlan;
 use crate::plans::Plan;
 use crate::plans::Recluster;
 use crate::plans::RelOperator;
@@ -435,6 +440,10 @@ impl Binder {
         &mut self,
         stmt: &CreateTableStmt,
     ) -> Result<Plan> {
+        if let Some(CreateTableSource::Clone { .. }) = &stmt.source {
+            return self.bind_clone_table(stmt).await;
+        }
+
         let CreateTableStmt {
             create_option,
             catalog,
@@ -690,7 +699,7 @@ impl Binder {
             }
         } else if inverted_indexes.is_some() {
             return Err(ErrorCode::UnsupportedIndex(format!(
-                "Table engine {} does not support create inverted index",
+                "Table engine {} does not support create table index",
                 engine
             )));
         }
@@ -1067,6 +1076,46 @@ impl Binder {
                     table,
                 })))
             }
+            AlterTableAction::CreateTag {
+                name,
+                point,
+                retain_days,
+            } => {
+                let navigation = point
+                    .as_ref()
+                    .map(|point| self.resolve_data_travel_point(bind_context, point))
+                    .transpose()?;
+                Ok(Plan::CreateSnapshotTag(Box::new(CreateSnapshotTagPlan {
+                    catalog,
+                    database,
+                    table,
+                    tag: self.normalize_object_identifier(name),
+                    navigation,
+                    retain_days: *retain_days,
+                })))
+            }
+            AlterTableAction::DropTag { name } => {
+                Ok(Plan::DropSnapshotTag(Box::new(DropSnapshotTagPlan {
+                    catalog,
+                    database,
+                    table,
+                    tag: self.normalize_object_identifier(name),
+                })))
+            }
+            AlterTableAction::DropPartition { partition }
+            | AlterTableAction::TruncatePartition { partition } => {
+                let TableReference::Table {
+                    catalog,
+                    database,
+                    table,
+                    ..
+                } = table_reference
+                else {
+                    unreachable!()
+                };
+                self.bind_delete_partition(bind_context, catalog, database, table, partition)
+                    .await
+            }
         }
     }
 
@@ -1347,6 +1396,17 @@ impl Binder {
                     }))
                 }
             },
+            AstOptimizeTableAction::Expire => {
+                self.bind_expire_table(bind_context, &stmt.catalog, &stmt.database, &stmt.table)
+                    .await?
+            }
+            AstOptimizeTableAction::RefreshNgramIndex => {
+                Plan::OptimizeRefreshNgramIndex(Box::new(OptimizeRefreshNgramIndexPlan {
+                    catalog,
+                    database,
+                    table,
+                }))
+            }
         };
 
         Ok(plan)
@@ -1627,37 +1687,52 @@ impl Binder {
     }
 
     #[async_backtrace::framed]
-    async fn analyze_inverted_indexes(
+    async fn analyze_table_indexes(
         &self,
         table_schema: TableSchemaRef,
-        inverted_index_defs: &[InvertedIndexDefinition],
+        table_index_defs: &[TableIndexDefinition],
     ) -> Result<BTreeMap<String, TableIndex>> {
-        let mut inverted_indexes = BTreeMap::new();
-        for inverted_index_def in inverted_index_defs {
-            let name = self.normalize_object_identifier(&inverted_index_def.index_name);
-            if inverted_indexes.contains_key(&name) {
+        let mut table_indexes = BTreeMap::new();
+        for table_index_def in table_index_defs {
+            let name = self.normalize_object_identifier(&table_index_def.index_name);
+            if table_indexes.contains_key(&name) {
                 return Err(ErrorCode::BadArguments(format!(
-                    "Duplicated inverted index name: {}",
+                    "Duplicated index name: {}",
                     name
                 )));
             }
-            let column_ids = self
-                .validate_inverted_index_columns(table_schema.clone(), &inverted_index_def.columns)
-                .await?;
-            let options = self
-                .validate_inverted_index_options(&inverted_index_def.index_options)
-                .await?;
+            let (index_type, column_ids, options) = match table_index_def.index_type {
+                AstTableIndexType::Vector => (
+                    TableIndexType::Vector,
+                    self.validate_vector_index_columns(
+                        table_schema.clone(),
+                        &table_index_def.columns,
+                    )?,
+                    self.validate_vector_index_options(&table_index_def.index_options)?,
+                ),
+                _ => (
+                    TableIndexType::Inverted,
+                    self.validate_inverted_index_columns(
+                        table_schema.clone(),
+                        &table_index_def.columns,
+                    )
+                    .await?,
+                    self.validate_inverted_index_options(&table_index_def.index_options)
+                        .await?,
+                ),
+            };
 
-            let inverted_index = TableIndex {
+            let table_index = TableIndex {
+                index_type,
                 name: name.clone(),
                 column_ids,
-                sync_creation: inverted_index_def.sync_creation,
+                sync_creation: table_index_def.sync_creation,
                 version: Uuid::new_v4().simple().to_string(),
                 options,
             };
-            inverted_indexes.insert(name, inverted_index);
+            table_indexes.insert(name, table_index);
         }
-        Ok(inverted_indexes)
+        Ok(table_indexes)
     }
 
     #[async_backtrace::framed]
@@ -1670,12 +1745,12 @@ impl Binder {
         Option<BTreeMap<String, TableIndex>>,
     )> {
         match source {
-            CreateTableSource::Columns(columns, inverted_index_defs) => {
+            CreateTableSource::Columns(columns, table_index_defs) => {
                 let (schema, comments) =
                     self.analyze_create_table_schema_by_columns(columns).await?;
-                let inverted_indexes = if let Some(inverted_index_defs) = inverted_index_defs {
+                let inverted_indexes = if let Some(table_index_defs) = table_index_defs {
                     let inverted_indexes = self
-                        .analyze_inverted_indexes(schema.clone(), inverted_index_defs)
+                        .analyze_table_indexes(schema.clone(), table_index_defs)
                         .await?;
                     Some(inverted_indexes)
                 } else {
@@ -1706,6 +1781,9 @@ impl Binder {
                     Ok((table.schema(), table.field_comments().clone(), None))
                 }
             }
+            CreateTableSource::Clone { .. } => Err(ErrorCode::BadArguments(
+                "CLONE is only supported by CREATE TABLE",
+            )),
         }
     }
 
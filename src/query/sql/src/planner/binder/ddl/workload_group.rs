@@ -0,0 +1,144 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use bigbytesdb_common_ast::ast::AlterRoleStmt;
+use bigbytesdb_common_ast::ast::AlterWorkloadGroupStmt;
+use bigbytesdb_common_ast::ast::CreateWorkloadGroupStmt;
+use bigbytesdb_common_ast::ast::DropWorkloadGroupStmt;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+
+use crate::plans::AlterRolePlan;
+use crate::plans::AlterWorkloadGroupPlan;
+use crate::plans::CreateWorkloadGroupPlan;
+use crate::plans::DropWorkloadGroupPlan;
+use crate::plans::Plan;
+use crate::plans::WorkloadGroupOptions;
+use crate::Binder;
+
+const MAX_CPU_WEIGHT: u64 = 10000;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_workload_group(
+        &mut self,
+        stmt: &CreateWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let CreateWorkloadGroupStmt {
+            create_option,
+            name,
+            options,
+        } = stmt;
+
+        let plan = CreateWorkloadGroupPlan {
+            create_option: create_option.clone().into(),
+            tenant: self.ctx.get_tenant(),
+            name: name.clone(),
+            options: parse_workload_group_options(options)?,
+        };
+        Ok(Plan::CreateWorkloadGroup(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_workload_group(
+        &mut self,
+        stmt: &AlterWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let AlterWorkloadGroupStmt {
+            if_exists,
+            name,
+            options,
+        } = stmt;
+
+        let plan = AlterWorkloadGroupPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.clone(),
+            options: parse_workload_group_options(options)?,
+        };
+        Ok(Plan::AlterWorkloadGroup(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_workload_group(
+        &mut self,
+        stmt: &DropWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let DropWorkloadGroupStmt { if_exists, name } = stmt;
+
+        let plan = DropWorkloadGroupPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.clone(),
+        };
+        Ok(Plan::DropWorkloadGroup(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_role(
+        &mut self,
+        stmt: &AlterRoleStmt,
+    ) -> Result<Plan> {
+        let AlterRoleStmt {
+            if_exists,
+            role_name,
+            workload_group,
+        } = stmt;
+
+        let plan = AlterRolePlan {
+            if_exists: *if_exists,
+            role_name: role_name.clone(),
+            workload_group: workload_group.clone(),
+        };
+        Ok(Plan::AlterRole(Box::new(plan)))
+    }
+}
+
+fn parse_workload_group_options(
+    options: &BTreeMap<String, String>,
+) -> Result<WorkloadGroupOptions> {
+    let mut res = WorkloadGroupOptions::default();
+    for (key, value) in options {
+        let v = value.parse::<u64>().map_err(|_| {
+            ErrorCode::SemanticError(format!(
+                "invalid value '{}' of workload group option {}, expect an unsigned integer",
+                value, key
+            ))
+        })?;
+        match key.as_str() {
+            "max_concurrency" => res.max_concurrency = Some(v),
+            "max_queued" => res.max_queued = Some(v),
+            "queued_timeout" => res.queued_timeout_secs = Some(v),
+            "max_memory_usage" => res.max_memory_usage = Some(v),
+            "cpu_weight" => {
+                if v == 0 || v > MAX_CPU_WEIGHT {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "cpu_weight of workload group must be between 1 and {}, but got {}",
+                        MAX_CPU_WEIGHT, v
+                    )));
+                }
+                res.cpu_weight = Some(v);
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "unknown workload group option {}, expect one of max_concurrency, max_queued, queued_timeout, max_memory_usage, cpu_weight",
+                    key
+                )));
+            }
+        }
+    }
+    Ok(res)
+}
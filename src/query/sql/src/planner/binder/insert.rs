@@ -81,9 +81,14 @@ impl Binder {
             columns,
             source,
             overwrite,
+            partition,
             ..
         } = stmt;
 
+        if let Some(partition) = partition {
+            return self.bind_insert_overwrite_partition(stmt, partition).await;
+        }
+
         self.init_cte(bind_context, with)?;
 
         let table_identifier = TableIdentifier::new(self, catalog, database, table, &None);
@@ -35,6 +35,7 @@ mod insert_multi_table;
 mod internal_column_factory;
 mod kill;
 mod location;
+mod partition;
 mod presign;
 mod project;
 mod project_set;
@@ -52,6 +53,7 @@ mod stream_column_factory;
 mod system;
 mod table;
 mod table_args;
+mod ttl;
 mod udf;
 mod util;
 mod window;
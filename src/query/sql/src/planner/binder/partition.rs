@@ -0,0 +1,145 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigbytesdb_common_ast::ast::DeleteStmt;
+use bigbytesdb_common_ast::ast::Expr;
+use bigbytesdb_common_ast::ast::Identifier;
+use bigbytesdb_common_ast::ast::InsertStmt;
+use bigbytesdb_common_ast::ast::TableReference;
+use bigbytesdb_common_ast::parser::parse_expr;
+use bigbytesdb_common_ast::parser::tokenize_sql;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
+
+use crate::binder::Binder;
+use crate::plans::InsertOverwritePartitionPlan;
+use crate::plans::Plan;
+use crate::BindContext;
+
+impl Binder {
+    /// `ALTER TABLE t DROP PARTITION (value)` and `ALTER TABLE t TRUNCATE PARTITION (value)`
+    /// are bound as a `DELETE` of the rows of the partition. Blocks never span partitions,
+    /// so the blocks of the partition are dropped without being read.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_delete_partition(
+        &mut self,
+        bind_context: &mut BindContext,
+        catalog: &Option<Identifier>,
+        database: &Option<Identifier>,
+        table: &Identifier,
+        partition: &Expr,
+    ) -> Result<Plan> {
+        let predicate = self
+            .partition_predicate(catalog, database, table, partition)
+            .await?;
+        let tokens = tokenize_sql(&predicate)?;
+        let selection = parse_expr(&tokens, self.dialect)?;
+
+        let stmt = DeleteStmt {
+            hints: None,
+            table: TableReference::Table {
+                span: None,
+                catalog: catalog.clone(),
+                database: database.clone(),
+                table: table.clone(),
+                alias: None,
+                temporal: None,
+                with_options: None,
+                pivot: None,
+                unpivot: None,
+                sample: None,
+            },
+            selection: Some(selection),
+            with: None,
+        };
+        self.bind_delete(bind_context, &stmt).await
+    }
+
+    /// `INSERT OVERWRITE t PARTITION (value) ...` deletes the rows of the partition and
+    /// inserts the new ones in one transaction, the new rows are checked to belong to the
+    /// partition while they are written.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_insert_overwrite_partition(
+        &mut self,
+        stmt: &InsertStmt,
+        partition: &Expr,
+    ) -> Result<Plan> {
+        if !stmt.overwrite {
+            return Err(ErrorCode::BadArguments(
+                "PARTITION can only be used with INSERT OVERWRITE",
+            ));
+        }
+        let InsertStmt {
+            catalog,
+            database,
+            table,
+            ..
+        } = stmt;
+        let predicate = self
+            .partition_predicate(catalog, database, table, partition)
+            .await?;
+        let table_ref = catalog
+            .iter()
+            .chain(database)
+            .chain(Some(table))
+            .map(|ident| ident.to_string())
+            .collect::<Vec<_>>()
+            .join(".");
+
+        let insert = InsertStmt {
+            overwrite: false,
+            partition: None,
+            ..stmt.clone()
+        };
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        Ok(Plan::InsertOverwritePartition(Box::new(
+            InsertOverwritePartitionPlan {
+                catalog,
+                database,
+                table,
+                delete: format!("DELETE FROM {table_ref} WHERE {predicate}"),
+                insert: insert.to_string(),
+                predicate,
+            },
+        )))
+    }
+
+    /// Returns `(partition_by) IS NOT DISTINCT FROM (value)`, so that the `NULL` partition
+    /// can be matched too. Fails if the table is not partitioned.
+    async fn partition_predicate(
+        &self,
+        catalog: &Option<Identifier>,
+        database: &Option<Identifier>,
+        table: &Identifier,
+        partition: &Expr,
+    ) -> Result<String> {
+        let (catalog_name, database_name, table_name) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let table_meta = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        let Some(partition_by) = table_meta.options().get(OPT_KEY_PARTITION_BY) else {
+            return Err(ErrorCode::BadArguments(format!(
+                "table {}.{} is not partitioned",
+                database_name, table_name
+            )));
+        };
+        Ok(format!(
+            "({partition_by}) IS NOT DISTINCT FROM ({partition})"
+        ))
+    }
+}
@@ -393,6 +393,8 @@ impl Binder {
             right_conditions,
             non_equi_conditions: vec![],
             other_conditions: vec![],
+            asof_condition: None,
+            distribution_hint: None,
         };
         let s_expr = self.bind_join_with_type(
             join_type,
@@ -560,6 +560,9 @@ impl Binder {
                     Utc.timestamp_nanos(micros * 1000),
                 ))
             }
+            TimeTravelPoint::Tag(name) => {
+                Ok(NavigationPoint::Tag(self.normalize_object_identifier(name)))
+            }
             TimeTravelPoint::Stream {
                 catalog,
                 database,
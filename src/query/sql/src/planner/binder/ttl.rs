@@ -0,0 +1,121 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bigbytesdb_common_ast::ast::DeleteStmt;
+use bigbytesdb_common_ast::ast::Identifier;
+use bigbytesdb_common_ast::ast::TableReference;
+use bigbytesdb_common_ast::parser::parse_expr;
+use bigbytesdb_common_ast::parser::tokenize_sql;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_TTL;
+
+use crate::binder::Binder;
+use crate::binder::ScalarBinder;
+use crate::optimizer::SExpr;
+use crate::plans::Filter;
+use crate::plans::Plan;
+use crate::BindContext;
+use crate::ScalarExpr;
+
+impl Binder {
+    /// Hide the rows of a table with a `ttl` option that have already expired,
+    /// they stay in storage until `OPTIMIZE TABLE ... EXPIRE` removes them.
+    pub(crate) fn bind_ttl_filter(
+        &mut self,
+        bind_context: &mut BindContext,
+        ttl: &str,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let filter = Filter {
+            predicates: vec![self.bind_ttl_predicate(bind_context, ttl)?],
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(s_expr),
+        ))
+    }
+
+    /// The predicate of the rows that have not expired yet.
+    pub(crate) fn bind_ttl_predicate(
+        &mut self,
+        bind_context: &mut BindContext,
+        ttl: &str,
+    ) -> Result<ScalarExpr> {
+        let predicate = format!("({ttl}) > now() OR ({ttl}) IS NULL");
+        let tokens = tokenize_sql(&predicate)?;
+        let ast = parse_expr(&tokens, self.dialect)?;
+
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (scalar, _) = scalar_binder.bind(&ast)?;
+        Ok(scalar)
+    }
+
+    /// `OPTIMIZE TABLE t EXPIRE` is bound as a `DELETE` of the expired rows.
+    /// The predicate has no subquery, so the mutation is done directly on the
+    /// blocks: blocks whose min/max show that every row has expired are dropped
+    /// without being read, and only partially expired blocks are rewritten.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_expire_table(
+        &mut self,
+        bind_context: &mut BindContext,
+        catalog: &Option<Identifier>,
+        database: &Option<Identifier>,
+        table: &Identifier,
+    ) -> Result<Plan> {
+        let (catalog_name, database_name, table_name) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let table_meta = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        let Some(ttl) = table_meta.options().get(OPT_KEY_TTL) else {
+            return Err(ErrorCode::BadArguments(format!(
+                "table {}.{} has no ttl option, nothing to expire",
+                database_name, table_name
+            )));
+        };
+
+        let predicate = format!("({ttl}) <= now()");
+        let tokens = tokenize_sql(&predicate)?;
+        let selection = parse_expr(&tokens, self.dialect)?;
+
+        let stmt = DeleteStmt {
+            hints: None,
+            table: TableReference::Table {
+                span: None,
+                catalog: catalog.clone(),
+                database: database.clone(),
+                table: table.clone(),
+                alias: None,
+                temporal: None,
+                with_options: None,
+                pivot: None,
+                unpivot: None,
+                sample: None,
+            },
+            selection: Some(selection),
+            with: None,
+        };
+        self.bind_delete(bind_context, &stmt).await
+    }
+}
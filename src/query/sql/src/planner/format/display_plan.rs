@@ -52,12 +52,14 @@ impl Plan {
             // Databases
             Plan::ShowCreateDatabase(_) => Ok("ShowCreateDatabase".to_string()),
             Plan::CreateDatabase(_) => Ok("CreateDatabase".to_string()),
+            Plan::CloneDatabase(_) => Ok("CloneDatabase".to_string()),
             Plan::DropDatabase(_) => Ok("DropDatabase".to_string()),
             Plan::UndropDatabase(_) => Ok("UndropDatabase".to_string()),
             Plan::RenameDatabase(_) => Ok("RenameDatabase".to_string()),
 
             // Tables
             Plan::CreateTable(create_table) => format_create_table(create_table),
+            Plan::CloneTable(_) => Ok("CloneTable".to_string()),
             Plan::ShowCreateTable(_) => Ok("ShowCreateTable".to_string()),
             Plan::DropTable(_) => Ok("DropTable".to_string()),
             Plan::UndropTable(_) => Ok("UndropTable".to_string()),
@@ -76,6 +78,7 @@ impl Plan {
             Plan::TruncateTable(_) => Ok("TruncateTable".to_string()),
             Plan::OptimizePurge(_) => Ok("OptimizePurge".to_string()),
             Plan::OptimizeCompactSegment(_) => Ok("OptimizeCompactSegment".to_string()),
+            Plan::OptimizeRefreshNgramIndex(_) => Ok("OptimizeRefreshNgramIndex".to_string()),
             Plan::OptimizeCompactBlock { .. } => Ok("OptimizeCompactBlock".to_string()),
             Plan::VacuumTable(_) => Ok("VacuumTable".to_string()),
             Plan::VacuumDropTable(_) => Ok("VacuumDropTable".to_string()),
@@ -96,6 +99,10 @@ impl Plan {
             // Dynamic Tables
             Plan::CreateDynamicTable(_) => Ok("CreateDynamicTable".to_string()),
 
+            // Materialized Views
+            Plan::CreateMaterializedView(_) => Ok("CreateMaterializedView".to_string()),
+            Plan::RefreshMaterializedView(_) => Ok("RefreshMaterializedView".to_string()),
+
             // Indexes
             Plan::CreateIndex(_) => Ok("CreateIndex".to_string()),
             Plan::DropIndex(_) => Ok("DropIndex".to_string()),
@@ -113,6 +120,7 @@ impl Plan {
             // Insert
             Plan::Insert(_) => Ok("Insert".to_string()),
             Plan::InsertMultiTable(_) => Ok("InsertMultiTable".to_string()),
+            Plan::InsertOverwritePartition(_) => Ok("InsertOverwritePartition".to_string()),
             Plan::Replace(_) => Ok("Replace".to_string()),
             Plan::DataMutation { s_expr, .. } => format_merge_into(s_expr),
 
@@ -140,6 +148,7 @@ impl Plan {
             Plan::DescUser(_) => Ok("DescUser".to_string()),
             Plan::CreateRole(_) => Ok("CreateRole".to_string()),
             Plan::DropRole(_) => Ok("DropRole".to_string()),
+            Plan::AlterRole(_) => Ok("AlterRole".to_string()),
             Plan::Presign(_) => Ok("Presign".to_string()),
 
             Plan::Set(_) => Ok("Set".to_string()),
@@ -151,6 +160,8 @@ impl Plan {
 
             Plan::ShowRoles(_) => Ok("ShowRoles".to_string()),
             Plan::RevertTable(_) => Ok("RevertTable".to_string()),
+            Plan::CreateSnapshotTag(_) => Ok("CreateSnapshotTag".to_string()),
+            Plan::DropSnapshotTag(_) => Ok("DropSnapshotTag".to_string()),
 
             // data mask
             Plan::CreateDatamaskPolicy(_) => Ok("CreateDatamaskPolicy".to_string()),
@@ -170,6 +181,13 @@ impl Plan {
             Plan::DropPasswordPolicy(_) => Ok("DropPasswordPolicy".to_string()),
             Plan::DescPasswordPolicy(_) => Ok("DescPasswordPolicy".to_string()),
 
+            // plan baseline
+            Plan::CreatePlanBaseline(_) => Ok("CreatePlanBaseline".to_string()),
+            Plan::DropPlanBaseline(_) => Ok("DropPlanBaseline".to_string()),
+            Plan::CreateWorkloadGroup(_) => Ok("CreateWorkloadGroup".to_string()),
+            Plan::AlterWorkloadGroup(_) => Ok("AlterWorkloadGroup".to_string()),
+            Plan::DropWorkloadGroup(_) => Ok("DropWorkloadGroup".to_string()),
+
             // task
             Plan::CreateTask(_) => Ok("CreateTask".to_string()),
             Plan::DropTask(_) => Ok("DropTask".to_string()),
@@ -0,0 +1,634 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::time::Duration;
+use std::time::Instant;
+
+use chrono::Utc;
+use bigbytesdb_common_ast::ast::quote::display_ident;
+use bigbytesdb_common_ast::ast::Expr;
+use bigbytesdb_common_ast::ast::FunctionCall;
+use bigbytesdb_common_ast::ast::GroupBy;
+use bigbytesdb_common_ast::ast::JoinOperator;
+use bigbytesdb_common_ast::ast::Literal;
+use bigbytesdb_common_ast::ast::Query;
+use bigbytesdb_common_ast::ast::SelectStmt;
+use bigbytesdb_common_ast::ast::SelectTarget;
+use bigbytesdb_common_ast::ast::SetExpr;
+use bigbytesdb_common_ast::ast::Statement;
+use bigbytesdb_common_ast::ast::TableReference;
+use bigbytesdb_common_ast::parser::parse_sql;
+use bigbytesdb_common_ast::parser::tokenize_sql;
+use bigbytesdb_common_ast::parser::Dialect;
+use bigbytesdb_common_catalog::catalog::Catalog;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::FunctionKind;
+use bigbytesdb_common_functions::aggregates::AggregateFunctionFactory;
+use bigbytesdb_common_functions::BUILTIN_FUNCTIONS;
+use bigbytesdb_common_meta_app::schema::TableInfo;
+use bigbytesdb_common_meta_app::tenant::Tenant;
+use bigbytesdb_storages_common_cache::CacheAccessor;
+use bigbytesdb_storages_common_cache::CacheValue;
+use bigbytesdb_storages_common_cache::InMemoryLruCache;
+use bigbytesdb_storages_common_table_meta::table::MaterializedViewRefreshState;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESH_STATE;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_MAX_STALENESS;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+use derive_visitor::Visitor;
+use derive_visitor::VisitorMut;
+use log::warn;
+
+use crate::normalize_identifier;
+use crate::NameResolutionContext;
+use crate::Planner;
+use crate::ViewRewriter;
+
+// The materialized views created or dropped on other nodes are seen after this.
+const MATERIALIZED_VIEW_DEFS_TTL: Duration = Duration::from_secs(10);
+
+static MATERIALIZED_VIEW_DEFS: LazyLock<InMemoryLruCache<MaterializedViewDefs>> =
+    LazyLock::new(|| {
+        InMemoryLruCache::with_items_capacity("materialized_view_defs".to_string(), 1024)
+    });
+
+/// The materialized views of a database, by the canonical text of their queries.
+struct MaterializedViewDefs {
+    loaded_at: Instant,
+    db_id: u64,
+    views: HashMap<String, String>,
+}
+
+impl From<MaterializedViewDefs> for CacheValue<MaterializedViewDefs> {
+    fn from(val: MaterializedViewDefs) -> Self {
+        let mem_bytes = val.views.iter().map(|(q, t)| q.len() + t.len()).sum();
+        CacheValue::new(val, mem_bytes)
+    }
+}
+
+impl Planner {
+    /// Rewrite the queries of the statement to read from the materialized views defined
+    /// by them, if the views are fresh and visible to the current user.
+    ///
+    /// A query is only matched with a materialized view if their canonical texts are the
+    /// same, see [`materialized_view_query`]; queries that are equivalent but written
+    /// differently, or that only read a subset of the view, are not rewritten.
+    ///
+    /// The rewrite never fails the query, the statement is kept as is on errors.
+    #[async_backtrace::framed]
+    pub async fn rewrite_materialized_views(&self, stmt: &Statement) -> Option<Statement> {
+        match self.try_rewrite_materialized_views(stmt).await {
+            Ok(rewritten) => rewritten,
+            Err(cause) => {
+                warn!(
+                    "skip rewriting the query with materialized views: {}",
+                    cause
+                );
+                None
+            }
+        }
+    }
+
+    async fn try_rewrite_materialized_views(&self, stmt: &Statement) -> Result<Option<Statement>> {
+        let query = match stmt {
+            Statement::Query(query) => query,
+            Statement::Explain { query, .. } => match query.as_ref() {
+                Statement::Query(query) => query,
+                _ => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+        let settings = self.ctx.get_settings();
+        if !settings.get_enable_materialized_view_rewrite()? {
+            return Ok(None);
+        }
+
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let current_catalog = self.ctx.get_current_catalog();
+        let current_database = self.ctx.get_current_database();
+
+        // The materialized views are looked up in the databases of the tables read by the query.
+        let mut databases = BTreeSet::new();
+        for table_ref in table_references(&qualify_query(query, &current_database)) {
+            if let TableReference::Table {
+                catalog, database, ..
+            } = table_ref
+            {
+                let catalog = catalog
+                    .as_ref()
+                    .map(|catalog| normalize_identifier(catalog, &name_resolution_ctx).name)
+                    .unwrap_or_else(|| current_catalog.clone());
+                let database = database
+                    .as_ref()
+                    .map(|database| normalize_identifier(database, &name_resolution_ctx).name)
+                    .unwrap_or_else(|| current_database.clone());
+                databases.insert((catalog, database));
+            }
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let mut candidates = HashMap::new();
+        for (catalog_name, database) in databases {
+            let Ok(catalog) = self.ctx.get_catalog(&catalog_name).await else {
+                continue;
+            };
+            // Unknown databases are reported by the binder.
+            let Ok(defs) =
+                materialized_view_defs(&catalog, &tenant, &catalog_name, &database).await
+            else {
+                continue;
+            };
+            for (view_query, table_name) in defs.views.iter() {
+                candidates.insert(
+                    view_query.clone(),
+                    (
+                        catalog.clone(),
+                        catalog_name.clone(),
+                        database.clone(),
+                        defs.db_id,
+                        table_name.clone(),
+                    ),
+                );
+            }
+        }
+        if candidates.is_empty() {
+            return Ok(None);
+        }
+
+        #[derive(Visitor)]
+        #[visitor(Query(enter))]
+        struct QueryCollector {
+            database: String,
+            queries: Vec<String>,
+        }
+
+        impl QueryCollector {
+            fn enter_query(&mut self, query: &Query) {
+                self.queries
+                    .push(materialized_view_query(query, &self.database));
+            }
+        }
+
+        let mut collector = QueryCollector {
+            database: current_database.clone(),
+            queries: vec![],
+        };
+        query.drive(&mut collector);
+
+        let dialect = settings.get_sql_dialect()?;
+        let visibility_checker = self.ctx.get_visibility_checker(false).await?;
+        let mut replacements = HashMap::new();
+        for view_query in collector.queries {
+            let Some((catalog, catalog_name, database, db_id, table_name)) =
+                candidates.get(&view_query)
+            else {
+                continue;
+            };
+            if replacements.contains_key(&view_query) {
+                continue;
+            }
+            // The cached view may be replaced or dropped since, its current meta is read.
+            let Ok(table) = catalog.get_table(&tenant, database, table_name).await else {
+                continue;
+            };
+            let table_info = table.get_table_info();
+            if table_info.options().get(OPT_KEY_MATERIALIZED_VIEW_QUERY) != Some(&view_query) {
+                continue;
+            }
+            if !visibility_checker.check_table_visibility(
+                catalog_name,
+                database,
+                &table_info.name,
+                *db_id,
+                table_info.ident.table_id,
+            ) || !is_materialized_view_fresh(catalog, table_info).await?
+            {
+                continue;
+            }
+            let sql = format!(
+                "SELECT * FROM {}.{}.{}",
+                display_ident(catalog_name, true, dialect),
+                display_ident(database, true, dialect),
+                display_ident(&table_info.name, true, dialect)
+            );
+            replacements.insert(view_query, parse_query(&sql, dialect)?);
+        }
+        if replacements.is_empty() {
+            return Ok(None);
+        }
+
+        #[derive(VisitorMut)]
+        #[visitor(Query(enter))]
+        struct QueryReplacer {
+            database: String,
+            replacements: HashMap<String, Query>,
+        }
+
+        impl QueryReplacer {
+            fn enter_query(&mut self, query: &mut Query) {
+                if let Some(replacement) = self
+                    .replacements
+                    .get(&materialized_view_query(query, &self.database))
+                {
+                    *query = replacement.clone();
+                }
+            }
+        }
+
+        let mut stmt = stmt.clone();
+        stmt.drive_mut(&mut QueryReplacer {
+            database: current_database,
+            replacements,
+        });
+        Ok(Some(stmt))
+    }
+}
+
+/// Returns the materialized views of the database, cached for [`MATERIALIZED_VIEW_DEFS_TTL`].
+async fn materialized_view_defs(
+    catalog: &Arc<dyn Catalog>,
+    tenant: &Tenant,
+    catalog_name: &str,
+    database: &str,
+) -> Result<Arc<MaterializedViewDefs>> {
+    let key = format!("{}/{catalog_name}/{database}", tenant.tenant_name());
+    let cache = LazyLock::force(&MATERIALIZED_VIEW_DEFS);
+    if let Some(defs) = cache.get(&key) {
+        if defs.loaded_at.elapsed() < MATERIALIZED_VIEW_DEFS_TTL {
+            return Ok(defs);
+        }
+    }
+
+    let db = catalog.get_database(tenant, database).await?;
+    let mut views = HashMap::new();
+    for table in catalog.list_tables(tenant, database).await? {
+        let table_info = table.get_table_info();
+        if let Some(view_query) = table_info.options().get(OPT_KEY_MATERIALIZED_VIEW_QUERY) {
+            views.insert(view_query.clone(), table_info.name.clone());
+        }
+    }
+    let defs = MaterializedViewDefs {
+        loaded_at: Instant::now(),
+        db_id: db.get_db_info().database_id.db_id,
+        views,
+    };
+    Ok(cache.insert(key, defs))
+}
+
+/// Drops the cached materialized views of the database, so that a view created
+/// on this node is used by the queries right away.
+pub fn invalidate_materialized_view_defs(tenant: &Tenant, catalog_name: &str, database: &str) {
+    let key = format!("{}/{catalog_name}/{database}", tenant.tenant_name());
+    LazyLock::force(&MATERIALIZED_VIEW_DEFS).evict(&key);
+}
+
+/// The canonical text of the query of a materialized view, the unqualified tables are
+/// resolved in `database` and the hints are removed. A query is rewritten to read from
+/// a materialized view if their canonical texts are the same.
+pub fn materialized_view_query(query: &Query, database: &str) -> String {
+    #[derive(VisitorMut)]
+    #[visitor(SelectStmt(enter))]
+    struct HintRemover;
+
+    impl HintRemover {
+        fn enter_select_stmt(&mut self, stmt: &mut SelectStmt) {
+            stmt.hints = None;
+        }
+    }
+
+    let mut query = qualify_query(query, database);
+    query.drive_mut(&mut HintRemover);
+    query.to_string()
+}
+
+fn qualify_query(query: &Query, database: &str) -> Query {
+    let mut query = query.clone();
+    query.drive_mut(&mut ViewRewriter {
+        current_database: database.to_string(),
+    });
+    query
+}
+
+fn parse_query(sql: &str, dialect: Dialect) -> Result<Query> {
+    let tokens = tokenize_sql(sql)?;
+    match parse_sql(&tokens, dialect)?.0 {
+        Statement::Query(query) => Ok(*query),
+        _ => Err(ErrorCode::Internal(format!("expect a query, got: {sql}"))),
+    }
+}
+
+/// Check if the data of the materialized view can be served, see
+/// [`MaterializedViewRefreshState::is_fresh`].
+#[async_backtrace::framed]
+pub async fn is_materialized_view_fresh(
+    catalog: &Arc<dyn Catalog>,
+    table_info: &TableInfo,
+) -> Result<bool> {
+    let options = table_info.options();
+    let Some(state) = materialized_view_refresh_state(table_info)? else {
+        return Ok(false);
+    };
+    let max_staleness = options
+        .get(OPT_KEY_MAX_STALENESS)
+        .and_then(|secs| secs.parse::<u64>().ok());
+
+    let mut current_locations = HashMap::with_capacity(state.sources.len());
+    for source in &state.sources {
+        if let Some(meta) = catalog.get_table_meta_by_id(source.table_id).await? {
+            if meta.data.drop_on.is_none() {
+                current_locations.insert(
+                    source.table_id,
+                    meta.data.options.get(OPT_KEY_SNAPSHOT_LOCATION).cloned(),
+                );
+            }
+        }
+    }
+    Ok(state.is_fresh(
+        options.get(OPT_KEY_SNAPSHOT_LOCATION),
+        max_staleness,
+        Utc::now(),
+        &current_locations,
+    ))
+}
+
+/// The state recorded by the last refresh of the materialized view.
+pub fn materialized_view_refresh_state(
+    table_info: &TableInfo,
+) -> Result<Option<MaterializedViewRefreshState>> {
+    match table_info
+        .options()
+        .get(OPT_KEY_MATERIALIZED_VIEW_REFRESH_STATE)
+    {
+        Some(state) => serde_json::from_str(state).map(Some).map_err(|e| {
+            ErrorCode::IllegalMaterializedView(format!(
+                "invalid refresh state of materialized view {}: {e}",
+                table_info.name
+            ))
+        }),
+        None => Ok(None),
+    }
+}
+
+/// How a materialized view is refreshed incrementally by the rows appended to the base tables.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IncrementalRefresh {
+    /// The rows derived from the appended rows are appended to the view.
+    Append,
+    /// The rows derived from the appended rows are aggregated with the rows of the view,
+    /// grouped by the select items of `None`, the other select items are merged by the
+    /// aggregate functions.
+    Merge(Vec<Option<&'static str>>),
+}
+
+/// Check if the materialized view of the query can be refreshed incrementally.
+///
+/// The query must be a single SELECT over tables joined by inner or cross joins, with
+/// no subqueries, and either no aggregation or the aggregation is grouped by plain
+/// select items and only uses `count`, `sum`, `min` and `max`.
+pub fn incremental_refresh(query: &Query) -> Option<IncrementalRefresh> {
+    if query.with.is_some()
+        || !query.order_by.is_empty()
+        || !query.limit.is_empty()
+        || query.offset.is_some()
+    {
+        return None;
+    }
+    let SetExpr::Select(select) = &query.body else {
+        return None;
+    };
+    if select.distinct
+        || select.top_n.is_some()
+        || select.having.is_some()
+        || select.window_list.is_some()
+        || select.qualify.is_some()
+        || select.from.is_empty()
+        || !select.from.iter().all(is_inner_join_of_tables)
+    {
+        return None;
+    }
+
+    #[derive(Visitor)]
+    #[visitor(Query(enter), FunctionCall(enter), Expr(enter))]
+    struct QueryChecker {
+        queries: usize,
+        has_aggregate: bool,
+        not_support: bool,
+    }
+
+    impl QueryChecker {
+        fn enter_query(&mut self, _query: &Query) {
+            self.queries += 1;
+        }
+
+        fn enter_function_call(&mut self, func: &FunctionCall) {
+            let name = func.name.name.to_lowercase();
+            if func.window.is_some() {
+                self.not_support = true;
+            } else if AggregateFunctionFactory::instance().contains(&name) {
+                self.has_aggregate = true;
+            } else if let Some(property) = BUILTIN_FUNCTIONS.get_property(&name) {
+                // The refreshed rows must be the same as the rows computed in a full refresh.
+                if property.kind == FunctionKind::SRF || property.non_deterministic {
+                    self.not_support = true;
+                }
+            } else {
+                self.not_support = true;
+            }
+        }
+
+        fn enter_expr(&mut self, expr: &Expr) {
+            if let Expr::CountAll { window, .. } = expr {
+                self.has_aggregate = true;
+                self.not_support |= window.is_some();
+            }
+        }
+    }
+
+    let mut checker = QueryChecker {
+        queries: 0,
+        has_aggregate: false,
+        not_support: false,
+    };
+    query.drive(&mut checker);
+    if checker.queries > 1 || checker.not_support {
+        return None;
+    }
+    if !checker.has_aggregate && select.group_by.is_none() {
+        return Some(IncrementalRefresh::Append);
+    }
+
+    let mut merge_functions = Vec::with_capacity(select.select_list.len());
+    for target in &select.select_list {
+        let SelectTarget::AliasedExpr { expr, .. } = target else {
+            return None;
+        };
+        let merge_function = match expr.as_ref() {
+            Expr::CountAll { .. } => Some("sum"),
+            Expr::FunctionCall { func, .. } => match func.name.name.to_lowercase().as_str() {
+                _ if func.distinct || func.args.len() != 1 || !func.params.is_empty() => None,
+                "count" | "sum" => Some("sum"),
+                "min" => Some("min"),
+                "max" => Some("max"),
+                _ => None,
+            },
+            _ => None,
+        };
+        if merge_function.is_none() && contains_aggregate(expr) {
+            return None;
+        }
+        merge_functions.push(merge_function);
+    }
+
+    // Each group key must be a select item, so the rows can be grouped again.
+    let group_keys = match &select.group_by {
+        None => vec![],
+        Some(GroupBy::Normal(exprs)) => exprs.iter().collect(),
+        Some(_) => return None,
+    };
+    for key in group_keys {
+        let matched =
+            select
+                .select_list
+                .iter()
+                .enumerate()
+                .any(|(i, target)| match (key, target) {
+                    (_, SelectTarget::StarColumns { .. }) => false,
+                    (_, _) if merge_functions[i].is_some() => false,
+                    (
+                        Expr::Literal {
+                            value: Literal::UInt64(pos),
+                            ..
+                        },
+                        _,
+                    ) => *pos as usize == i + 1,
+                    (Expr::ColumnRef { column, .. }, SelectTarget::AliasedExpr { expr, alias }) => {
+                        alias.as_ref().is_some_and(|alias| {
+                            column.database.is_none()
+                                && column.table.is_none()
+                                && column.column.to_string() == alias.to_string()
+                        }) || expr.to_string() == key.to_string()
+                    }
+                    (_, SelectTarget::AliasedExpr { expr, .. }) => {
+                        expr.to_string() == key.to_string()
+                    }
+                });
+        if !matched {
+            return None;
+        }
+    }
+    Some(IncrementalRefresh::Merge(merge_functions))
+}
+
+fn is_inner_join_of_tables(table_ref: &TableReference) -> bool {
+    match table_ref {
+        TableReference::Table {
+            temporal,
+            pivot,
+            unpivot,
+            sample,
+            ..
+        } => temporal.is_none() && pivot.is_none() && unpivot.is_none() && sample.is_none(),
+        TableReference::Join { join, .. } => {
+            matches!(join.op, JoinOperator::Inner | JoinOperator::CrossJoin)
+                && is_inner_join_of_tables(&join.left)
+                && is_inner_join_of_tables(&join.right)
+        }
+        _ => false,
+    }
+}
+
+fn contains_aggregate(expr: &Expr) -> bool {
+    #[derive(Visitor)]
+    #[visitor(FunctionCall(enter), Expr(enter))]
+    struct AggregateFinder {
+        found: bool,
+    }
+
+    impl AggregateFinder {
+        fn enter_function_call(&mut self, func: &FunctionCall) {
+            self.found |=
+                AggregateFunctionFactory::instance().contains(func.name.name.to_lowercase());
+        }
+
+        fn enter_expr(&mut self, expr: &Expr) {
+            self.found |= matches!(expr, Expr::CountAll { .. });
+        }
+    }
+
+    let mut finder = AggregateFinder { found: false };
+    expr.drive(&mut finder);
+    finder.found
+}
+
+/// The tables read by the query, in the order of their occurrences.
+pub fn table_references(query: &Query) -> Vec<TableReference> {
+    #[derive(Visitor)]
+    #[visitor(TableReference(exit))]
+    struct TableCollector {
+        tables: Vec<TableReference>,
+    }
+
+    impl TableCollector {
+        fn exit_table_reference(&mut self, table_ref: &TableReference) {
+            if matches!(table_ref, TableReference::Table { .. }) {
+                self.tables.push(table_ref.clone());
+            }
+        }
+    }
+
+    let mut collector = TableCollector { tables: vec![] };
+    query.drive(&mut collector);
+    collector.tables
+}
+
+/// Replace the tables read by the query, in the order of [`table_references`].
+pub fn replace_table_references(query: &mut Query, replacements: Vec<TableReference>) {
+    #[derive(VisitorMut)]
+    #[visitor(TableReference(exit))]
+    struct TableReplacer {
+        replacements: std::vec::IntoIter<TableReference>,
+    }
+
+    impl TableReplacer {
+        fn exit_table_reference(&mut self, table_ref: &mut TableReference) {
+            if matches!(table_ref, TableReference::Table { .. }) {
+                if let Some(replacement) = self.replacements.next() {
+                    *table_ref = replacement;
+                }
+            }
+        }
+    }
+
+    query.drive_mut(&mut TableReplacer {
+        replacements: replacements.into_iter(),
+    });
+}
+
+/// Parse a table reference, e.g. `t AT (SNAPSHOT => '...')`.
+pub fn parse_table_reference(sql: &str, dialect: Dialect) -> Result<TableReference> {
+    let query = parse_query(&format!("SELECT * FROM {sql}"), dialect)?;
+    match query.body {
+        SetExpr::Select(mut select) if select.from.len() == 1 => Ok(select.from.remove(0)),
+        _ => Err(ErrorCode::Internal(format!(
+            "expect a table reference, got: {sql}"
+        ))),
+    }
+}
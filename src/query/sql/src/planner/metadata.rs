@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::fmt::Debug;
+use std::fmt::Display;
 use std::fmt::Formatter;
 use std::sync::Arc;
 
@@ -79,6 +80,8 @@ pub struct Metadata {
     next_scan_id: usize,
     /// Mappings from base column index to scan id.
     base_column_scan_id: HashMap<IndexType, usize>,
+    /// Optimizer hints of the query.
+    plan_hints: PlanHints,
 }
 
 impl Metadata {
@@ -490,6 +493,24 @@ impl Metadata {
         self.base_column_scan_id.get(&column_index).cloned()
     }
 
+    pub fn plan_hints(&self) -> &PlanHints {
+        &self.plan_hints
+    }
+
+    pub fn plan_hints_mut(&mut self) -> &mut PlanHints {
+        &mut self.plan_hints
+    }
+
+    /// Get the indexes of the tables named `name` in plan hints, the alias of a table
+    /// is used if it has one.
+    pub fn table_indexes_by_hint_name(&self, name: &str) -> Vec<IndexType> {
+        self.tables
+            .iter()
+            .filter(|table| table.alias_name().as_deref().unwrap_or(table.name()) == name)
+            .map(|table| table.index())
+            .collect()
+    }
+
     fn remove_cte_suffix(mut table_name: String, cte_suffix_name: Option<String>) -> String {
         if let Some(suffix) = cte_suffix_name {
             if table_name.ends_with(&suffix) {
@@ -500,6 +521,79 @@ impl Metadata {
     }
 }
 
+/// Join method required by the `HASH_JOIN` or `MERGE_JOIN` hint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JoinMethodHint {
+    Hash,
+    Merge,
+}
+
+/// Join order required by the `LEADING` hint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JoinOrderHint {
+    Table(String),
+    Join(Box<JoinOrderHint>, Box<JoinOrderHint>),
+}
+
+impl JoinOrderHint {
+    /// Display the operands of `LEADING`, the joins on the left side are flattened,
+    /// e.g. `((t1 t2) t3)` is displayed as `t1 t2 t3`.
+    pub fn display_operands(&self) -> String {
+        match self {
+            JoinOrderHint::Join(left, right) => format!("{} {right}", left.display_operands()),
+            JoinOrderHint::Table(table) => table.clone(),
+        }
+    }
+}
+
+impl Display for JoinOrderHint {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            JoinOrderHint::Table(table) => write!(f, "{table}"),
+            JoinOrderHint::Join(left, right) => write!(f, "({left} {right})"),
+        }
+    }
+}
+
+/// Optimizer hints of a query, written as `/*+ ... */` after `SELECT`.
+/// Table names are normalized.
+#[derive(Clone, Debug, Default)]
+pub struct PlanHints {
+    /// Join order of the `LEADING` hint.
+    pub leading: Option<JoinOrderHint>,
+    /// Whether the `LEADING` hint has been applied by join reorder.
+    pub leading_applied: bool,
+    /// Tables of the `BROADCAST` hints.
+    pub broadcast: Vec<String>,
+    /// Tables of the `SHUFFLE` hints.
+    pub shuffle: Vec<String>,
+    pub join_method: Option<JoinMethodHint>,
+    /// Keep correlated `EXISTS` subqueries from being merged into semi joins.
+    pub no_decorrelate: bool,
+    /// Hints that are not honored and the reasons, they are reported by EXPLAIN.
+    pub ignored: Vec<String>,
+    /// Name of the plan baseline that the hints are taken from.
+    pub plan_baseline: Option<String>,
+}
+
+impl PlanHints {
+    pub fn leading_hint(&self) -> String {
+        let operands = self
+            .leading
+            .as_ref()
+            .map(|leading| leading.display_operands())
+            .unwrap_or_default();
+        format!("LEADING({operands})")
+    }
+
+    pub fn add_ignored(&mut self, hint: String, reason: &str) {
+        let ignored = format!("{hint}: {reason}");
+        if !self.ignored.contains(&ignored) {
+            self.ignored.push(ignored);
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct TableEntry {
     catalog: String,
@@ -23,7 +23,9 @@ mod semantic;
 pub mod binder;
 pub mod dataframe;
 mod expression_parser;
+mod materialized_view;
 pub mod optimizer;
+pub(crate) mod plan_baseline;
 mod planner_cache;
 pub mod plans;
 mod stream_column;
@@ -41,7 +43,18 @@ pub use binder::Visibility;
 pub use bloom_index::BloomIndexColumns;
 pub use expression_parser::*;
 pub use format::format_scalar;
+pub use materialized_view::incremental_refresh;
+pub use materialized_view::invalidate_materialized_view_defs;
+pub use materialized_view::is_materialized_view_fresh;
+pub use materialized_view::materialized_view_query;
+pub use materialized_view::materialized_view_refresh_state;
+pub use materialized_view::parse_table_reference;
+pub use materialized_view::replace_table_references;
+pub use materialized_view::table_references;
+pub use materialized_view::IncrementalRefresh;
 pub use metadata::*;
+pub use plan_baseline::capture_plan_hints;
+pub use plan_baseline::query_fingerprint;
 pub use planner::get_query_kind;
 pub use planner::PlanExtras;
 pub use planner::Planner;
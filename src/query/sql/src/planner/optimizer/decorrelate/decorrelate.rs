@@ -201,6 +201,8 @@ impl SubqueryRewriter {
             is_lateral: false,
             single_to_inner: None,
             build_side_cache_info: None,
+            asof_condition: None,
+            distribution_hint: None,
         };
 
         // Rewrite plan to semi-join.
@@ -295,6 +297,8 @@ impl SubqueryRewriter {
                     is_lateral: false,
                     single_to_inner: None,
                     build_side_cache_info: None,
+                    asof_condition: None,
+                    distribution_hint: None,
                 };
                 let s_expr = SExpr::create_binary(
                     Arc::new(join_plan.into()),
@@ -304,7 +308,10 @@ impl SubqueryRewriter {
                 Ok((s_expr, UnnestResult::SingleJoin))
             }
             SubqueryType::Exists | SubqueryType::NotExists => {
-                if is_conjunctive_predicate {
+                // The `NO_DECORRELATE` hint keeps the subquery as a whole instead of merging
+                // its correlated predicates into a semi join with the outer query.
+                let no_decorrelate = self.metadata.read().plan_hints().no_decorrelate;
+                if is_conjunctive_predicate && !no_decorrelate {
                     if let Some(result) = self.try_decorrelate_simple_subquery(left, subquery)? {
                         return Ok((result, UnnestResult::SimpleJoin { output_index: None }));
                     }
@@ -349,6 +356,8 @@ impl SubqueryRewriter {
                     is_lateral: false,
                     single_to_inner: None,
                     build_side_cache_info: None,
+                    asof_condition: None,
+                    distribution_hint: None,
                 };
                 let s_expr = SExpr::create_binary(
                     Arc::new(join_plan.into()),
@@ -418,6 +427,8 @@ impl SubqueryRewriter {
                     is_lateral: false,
                     single_to_inner: None,
                     build_side_cache_info: None,
+                    asof_condition: None,
+                    distribution_hint: None,
                 }
                 .into();
                 Ok((
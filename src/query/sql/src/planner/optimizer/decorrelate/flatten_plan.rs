@@ -165,6 +165,8 @@ impl SubqueryRewriter {
                 is_lateral: false,
                 single_to_inner: None,
                 build_side_cache_info: None,
+                asof_condition: None,
+                distribution_hint: None,
             }
             .into();
 
@@ -523,6 +525,8 @@ impl SubqueryRewriter {
                     is_lateral: false,
                     single_to_inner: None,
                     build_side_cache_info: None,
+                    asof_condition: None,
+                    distribution_hint: None,
                 }
                 .into(),
             ),
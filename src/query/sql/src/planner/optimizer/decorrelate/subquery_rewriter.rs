@@ -458,6 +458,8 @@ impl SubqueryRewriter {
                     is_lateral: false,
                     single_to_inner: None,
                     build_side_cache_info: None,
+                    asof_condition: None,
+                    distribution_hint: None,
                 }
                 .into();
                 let s_expr = SExpr::create_binary(
@@ -573,6 +575,8 @@ impl SubqueryRewriter {
                     is_lateral: false,
                     single_to_inner: None,
                     build_side_cache_info: None,
+                    asof_condition: None,
+                    distribution_hint: None,
                 }
                 .into();
                 Ok((
@@ -648,6 +652,8 @@ impl SubqueryRewriter {
                     is_lateral: false,
                     single_to_inner: None,
                     build_side_cache_info: None,
+                    asof_condition: None,
+                    distribution_hint: None,
                 }
                 .into();
                 let s_expr = SExpr::create_binary(
@@ -37,6 +37,8 @@ use crate::plans::Filter;
 use crate::plans::JoinType;
 use crate::plans::RelOperator;
 use crate::IndexType;
+use crate::JoinOrderHint;
+use crate::Metadata;
 use crate::MetadataRef;
 use crate::ScalarExpr;
 
@@ -398,16 +400,100 @@ impl DPhpy {
             self.dp_table.insert(nodes, join);
         }
 
+        // The relations in `LEADING` hint are joined first in the given order,
+        // then the other relations are added by the greedy algorithm.
+        if let Some(leading_joins) = self.leading_joins() {
+            let leading_relations = self.join_leading_relations(&leading_joins).await?;
+            let mut join_relations = vec![leading_relations.clone()];
+            for idx in (0..self.join_relations.len()).filter(|idx| !leading_relations.contains(idx))
+            {
+                join_relations.push(self.relation_set_tree.get_relation_set_by_index(idx)?);
+            }
+            self.join_reorder_by_greedy(join_relations).await?;
+            self.metadata().write().plan_hints_mut().leading_applied = true;
+            return Ok(());
+        }
+
         // First, try to use dynamic programming to find the optimal join order.
         if !self.join_reorder_by_dphyp().await? {
             // When DPhpy takes too much time during join ordering, it is necessary to exit the dynamic programming algorithm
             // and switch to a greedy algorithm to minimizes the overall query time.
-            self.join_reorder_by_greedy().await?;
+            let join_relations = (0..self.join_relations.len())
+                .map(|idx| self.relation_set_tree.get_relation_set_by_index(idx))
+                .collect::<Result<Vec<_>>>()?;
+            self.join_reorder_by_greedy(join_relations).await?;
         }
 
         Ok(())
     }
 
+    // Map the `LEADING` hint to the joins of relations, in the order they are performed.
+    // Returns None if the hint can't be applied to the relations of this join tree.
+    fn leading_joins(&self) -> Option<Vec<(Vec<IndexType>, Vec<IndexType>)>> {
+        let metadata = self.opt_ctx.metadata.read();
+        let leading = metadata.plan_hints().leading.as_ref()?;
+        let mut joins = vec![];
+        self.collect_leading_joins(&metadata, leading, &mut joins)?;
+        if joins.is_empty() {
+            return None;
+        }
+        Some(joins)
+    }
+
+    // Collect the joins of the hint, returns the relations joined by the hint.
+    fn collect_leading_joins(
+        &self,
+        metadata: &Metadata,
+        leading: &JoinOrderHint,
+        joins: &mut Vec<(Vec<IndexType>, Vec<IndexType>)>,
+    ) -> Option<Vec<IndexType>> {
+        match leading {
+            JoinOrderHint::Table(table) => {
+                let mut relation = None;
+                for table_index in metadata.table_indexes_by_hint_name(table) {
+                    if let Some(idx) = self.table_index_map.get(&table_index) {
+                        // The name is ambiguous if it refers to more than one relation.
+                        if relation.is_some_and(|relation| relation != *idx) {
+                            return None;
+                        }
+                        relation = Some(*idx);
+                    }
+                }
+                Some(vec![relation?])
+            }
+            JoinOrderHint::Join(left, right) => {
+                let left = self.collect_leading_joins(metadata, left, joins)?;
+                let right = self.collect_leading_joins(metadata, right, joins)?;
+                // A relation can't be joined twice.
+                if left.iter().any(|relation| right.contains(relation)) {
+                    return None;
+                }
+                joins.push((left.clone(), right.clone()));
+                Some(left.into_iter().chain(right).collect())
+            }
+        }
+    }
+
+    // Perform the joins of `LEADING` hint, a cross join is used if two relations are not connected.
+    async fn join_leading_relations(
+        &mut self,
+        joins: &[(Vec<IndexType>, Vec<IndexType>)],
+    ) -> Result<Vec<IndexType>> {
+        let mut joined = vec![];
+        for (left, right) in joins.iter() {
+            let left = self
+                .relation_set_tree
+                .get_relation_set(&left.iter().copied().collect())?;
+            let right = self
+                .relation_set_tree
+                .get_relation_set(&right.iter().copied().collect())?;
+            let join_conditions = self.query_graph.is_connected(&left, &right)?;
+            self.emit_csg_cmp(&left, &right, join_conditions).await?;
+            joined = union(&left, &right);
+        }
+        Ok(joined)
+    }
+
     // Join reorder by dynamic programming algorithm.
     async fn join_reorder_by_dphyp(&mut self) -> Result<bool> {
         // Choose all nodes as enumeration start node once (desc order)
@@ -430,14 +516,14 @@ impl DPhpy {
     }
 
     // Join reorder by greedy algorithm.
-    async fn join_reorder_by_greedy(&mut self) -> Result<bool> {
+    async fn join_reorder_by_greedy(
+        &mut self,
+        mut join_relations: Vec<Vec<IndexType>>,
+    ) -> Result<bool> {
         // The Greedy Operator Ordering starts with a single relation and iteratively adds the relation that minimizes the cost of the join.
         // the algorithm terminates when all relations have been added, the cost of a join is the sum of the cardinalities of the node involved
         // in the tree, the algorithm is not guaranteed to find the optimal join tree, it is guaranteed to find it in polynomial time.
-        // Create join relations list, all relations have been inserted into dp_table in func `join_reorder`.
-        let mut join_relations = (0..self.join_relations.len())
-            .map(|idx| self.relation_set_tree.get_relation_set_by_index(idx))
-            .collect::<Result<Vec<_>>>()?;
+        // All relations in `join_relations` have been inserted into dp_table.
         // When all relations have been added, the algorithm terminates.
         while join_relations.len() > 1 {
             // The cost is the sum of the cardinalities of the node involved in the tree.
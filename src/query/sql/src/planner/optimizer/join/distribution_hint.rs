@@ -0,0 +1,192 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use bigbytesdb_common_exception::Result;
+
+use crate::optimizer::SExpr;
+use crate::plans::Join;
+use crate::plans::JoinDistributionHint;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::IndexType;
+use crate::MetadataRef;
+
+// The DistributionHintOptimizer marks the joins required by `BROADCAST` and `SHUFFLE` hints.
+// A hint applies to the join whose child only reads the hinted table, for `BROADCAST` the
+// table is moved to the build side if the join can be commuted.
+pub struct DistributionHintOptimizer {
+    metadata: MetadataRef,
+}
+
+impl DistributionHintOptimizer {
+    pub fn new(metadata: MetadataRef) -> Self {
+        DistributionHintOptimizer { metadata }
+    }
+
+    pub fn run(self, s_expr: &SExpr) -> Result<SExpr> {
+        let plan_hints = self.metadata.read().plan_hints().clone();
+        let hints = plan_hints
+            .broadcast
+            .iter()
+            .map(|table| (table, JoinDistributionHint::Broadcast))
+            .chain(
+                plan_hints
+                    .shuffle
+                    .iter()
+                    .map(|table| (table, JoinDistributionHint::Shuffle)),
+            );
+
+        let mut s_expr = s_expr.clone();
+        for (table, hint) in hints {
+            let hint_text = match hint {
+                JoinDistributionHint::Broadcast => format!("BROADCAST({table})"),
+                JoinDistributionHint::Shuffle => format!("SHUFFLE({table})"),
+            };
+            let tables = self.metadata.read().table_indexes_by_hint_name(table);
+            if tables.is_empty() {
+                self.metadata
+                    .write()
+                    .plan_hints_mut()
+                    .add_ignored(hint_text, "table not found");
+                continue;
+            }
+
+            let mut outcome = None;
+            s_expr = Self::apply_hint(&s_expr, &tables, hint, &mut outcome)?;
+            let reason = match outcome {
+                Some(Ok(())) => continue,
+                Some(Err(reason)) => reason,
+                None => "table is not joined",
+            };
+            self.metadata
+                .write()
+                .plan_hints_mut()
+                .add_ignored(hint_text, reason);
+        }
+        Ok(s_expr)
+    }
+
+    #[recursive::recursive]
+    fn apply_hint(
+        s_expr: &SExpr,
+        tables: &[IndexType],
+        hint: JoinDistributionHint,
+        outcome: &mut Option<std::result::Result<(), &'static str>>,
+    ) -> Result<SExpr> {
+        if let RelOperator::Join(join) = s_expr.plan.as_ref() {
+            let left_only = Self::only_reads(s_expr.child(0)?, tables);
+            let right_only = Self::only_reads(s_expr.child(1)?, tables);
+            if left_only || right_only {
+                let mut join = join.clone();
+                let mut children = [s_expr.child(0)?.clone(), s_expr.child(1)?.clone()];
+                *outcome = Some(match hint {
+                    JoinDistributionHint::Broadcast if right_only => {
+                        if Self::can_broadcast(&join) {
+                            Ok(())
+                        } else {
+                            Err("join type does not support broadcast")
+                        }
+                    }
+                    JoinDistributionHint::Broadcast => {
+                        if join.build_side_cache_info.is_none()
+                            && !join.join_type.is_asof_join()
+                            && matches!(
+                                join.join_type,
+                                JoinType::Inner
+                                    | JoinType::Cross
+                                    | JoinType::Right
+                                    | JoinType::RightSemi
+                                    | JoinType::RightAnti
+                                    | JoinType::RightSingle
+                            )
+                        {
+                            // Move the table to the build side.
+                            for condition in join.equi_conditions.iter_mut() {
+                                (condition.left, condition.right) =
+                                    (condition.right.clone(), condition.left.clone());
+                            }
+                            join.join_type = join.join_type.opposite();
+                            children.swap(0, 1);
+                            if Self::can_broadcast(&join) {
+                                Ok(())
+                            } else {
+                                Err("join type does not support broadcast")
+                            }
+                        } else {
+                            Err("table is on the probe side and the join can not be commuted")
+                        }
+                    }
+                    JoinDistributionHint::Shuffle => {
+                        if join.equi_conditions.is_empty() {
+                            Err("join has no equi-condition")
+                        } else {
+                            Ok(())
+                        }
+                    }
+                });
+                if let Some(Ok(())) = outcome {
+                    join.distribution_hint = Some(hint);
+                    return Ok(SExpr::create_binary(
+                        Arc::new(join.into()),
+                        Arc::new(children[0].clone()),
+                        Arc::new(children[1].clone()),
+                    ));
+                }
+                return Ok(s_expr.clone());
+            }
+        }
+
+        let mut children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            if outcome.is_some() {
+                children.push(Arc::new(child.clone()));
+            } else {
+                children.push(Arc::new(Self::apply_hint(child, tables, hint, outcome)?));
+            }
+        }
+        Ok(s_expr.replace_children(children))
+    }
+
+    // Check if the plan only reads the given tables.
+    fn only_reads(s_expr: &SExpr, tables: &[IndexType]) -> bool {
+        let mut scanned = HashSet::new();
+        Self::collect_scanned_tables(s_expr, &mut scanned);
+        !scanned.is_empty() && scanned.iter().all(|table| tables.contains(table))
+    }
+
+    #[recursive::recursive]
+    fn collect_scanned_tables(s_expr: &SExpr, scanned: &mut HashSet<IndexType>) {
+        if let RelOperator::Scan(scan) = s_expr.plan.as_ref() {
+            scanned.insert(scan.table_index);
+        }
+        for child in s_expr.children() {
+            Self::collect_scanned_tables(child, scanned);
+        }
+    }
+
+    fn can_broadcast(join: &Join) -> bool {
+        !matches!(
+            join.join_type,
+            JoinType::Right
+                | JoinType::Full
+                | JoinType::RightAnti
+                | JoinType::RightSemi
+                | JoinType::LeftMark
+                | JoinType::RightSingle
+        ) && !(join.equi_conditions.is_empty() && !join.non_equi_conditions.is_empty())
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod distribution_hint;
 mod single_to_inner;
 
+pub use distribution_hint::DistributionHintOptimizer;
 pub use single_to_inner::SingleToInnerOptimizer;
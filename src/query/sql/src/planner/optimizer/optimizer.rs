@@ -37,6 +37,7 @@ use crate::optimizer::distributed::SortAndLimitPushDownOptimizer;
 use crate::optimizer::filter::DeduplicateJoinConditionOptimizer;
 use crate::optimizer::filter::PullUpFilterOptimizer;
 use crate::optimizer::hyper_dp::DPhpy;
+use crate::optimizer::join::DistributionHintOptimizer;
 use crate::optimizer::join::SingleToInnerOptimizer;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::statistics::CollectStatisticsOptimizer;
@@ -283,6 +284,10 @@ pub async fn optimize(mut opt_ctx: OptimizerContext, plan: Plan) -> Result<Plan>
             graphical,
             plan: Box::new(Box::pin(optimize(opt_ctx, *plan)).await?),
         }),
+        Plan::CreatePlanBaseline(mut plan) => {
+            plan.plan = Box::new(Box::pin(optimize(opt_ctx, *plan.plan)).await?);
+            Ok(Plan::CreatePlanBaseline(plan))
+        }
         Plan::CopyIntoLocation(CopyIntoLocationPlan {
             stage,
             path,
@@ -427,10 +432,45 @@ pub async fn optimize_query(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -
         }
     }
 
+    let plan_hints = opt_ctx.metadata.read().plan_hints().clone();
+    if plan_hints.leading.is_some() && !plan_hints.leading_applied {
+        let reason = if opt_ctx.enable_dphyp && opt_ctx.enable_join_reorder {
+            "tables can not be reordered together"
+        } else {
+            "join reorder is disabled"
+        };
+        opt_ctx
+            .metadata
+            .write()
+            .plan_hints_mut()
+            .add_ignored(plan_hints.leading_hint(), reason);
+    }
+
     // After join reorder, Convert some single join to inner join.
     s_expr = SingleToInnerOptimizer::new().run(&s_expr)?;
     // Deduplicate join conditions.
     s_expr = DeduplicateJoinConditionOptimizer::new().run(&s_expr)?;
+    // Mark the joins required by `BROADCAST` and `SHUFFLE` hints.
+    if opt_ctx.enable_distributed_optimization {
+        s_expr = DistributionHintOptimizer::new(opt_ctx.metadata.clone()).run(&s_expr)?;
+    } else {
+        let mut metadata = opt_ctx.metadata.write();
+        let hints = plan_hints
+            .broadcast
+            .iter()
+            .map(|table| format!("BROADCAST({table})"))
+            .chain(
+                plan_hints
+                    .shuffle
+                    .iter()
+                    .map(|table| format!("SHUFFLE({table})")),
+            );
+        for hint in hints {
+            metadata
+                .plan_hints_mut()
+                .add_ignored(hint, "distributed optimization is disabled");
+        }
+    }
 
     let mut cascades = CascadesOptimizer::new(opt_ctx.clone(), dphyp_optimized)?;
 
@@ -158,7 +158,10 @@ pub fn try_push_down_filter_join(s_expr: &SExpr, metadata: MetadataRef) -> Resul
                 }
             }
             JoinPredicate::Right(_) => {
-                if matches!(
+                if join.join_type.is_asof_join() {
+                    // Filtering the build side changes which row is the closest match.
+                    original_predicates.push(predicate);
+                } else if matches!(
                     join.join_type,
                     JoinType::Left | JoinType::LeftSingle | JoinType::Full
                 ) {
@@ -214,7 +217,7 @@ pub fn try_push_down_filter_join(s_expr: &SExpr, metadata: MetadataRef) -> Resul
         }
         join.equi_conditions.clear();
         match join.join_type {
-            JoinType::Left | JoinType::LeftSingle => {
+            JoinType::Left | JoinType::LeftSingle | JoinType::LeftAsof => {
                 push_down_predicates.extend(left_push_down);
                 left_push_down = vec![];
             }
@@ -15,16 +15,25 @@
 use std::cmp;
 use std::sync::Arc;
 
+use bigbytesdb_common_catalog::plan::VectorIndexInfo;
 use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::types::NumberColumn;
+use bigbytesdb_common_expression::Column;
+use bigbytesdb_common_expression::Scalar;
+use bigbytesdb_common_meta_app::schema::TableIndexType;
 
 use crate::optimizer::extract::Matcher;
 use crate::optimizer::rule::Rule;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
+use crate::plans::EvalScalar;
 use crate::plans::RelOp;
 use crate::plans::RelOperator;
+use crate::plans::Scan;
 use crate::plans::Sort;
+use crate::MetadataRef;
+use crate::ScalarExpr;
 
 /// Input:  Sort
 ///           \
@@ -34,15 +43,21 @@ use crate::plans::Sort;
 ///         Sort
 ///           \
 ///           Scan(padding order_by and limit)
+///
+/// If the sort is `ORDER BY cosine_distance(col, <const>) LIMIT k` (or `l2_distance`)
+/// and the table has a vector index on `col` with the same distance,
+/// the vector index is also pushed down to prune blocks.
 pub struct RulePushDownSortScan {
     id: RuleID,
     matchers: Vec<Matcher>,
+    metadata: MetadataRef,
 }
 
 impl RulePushDownSortScan {
-    pub fn new() -> Self {
+    pub fn new(metadata: MetadataRef) -> Self {
         Self {
             id: RuleID::PushDownSortScan,
+            metadata,
             matchers: vec![
                 Matcher::MatchOp {
                     op_type: RelOp::Sort,
@@ -66,6 +81,71 @@ impl RulePushDownSortScan {
     }
 }
 
+impl RulePushDownSortScan {
+    fn try_push_down_vector_index(
+        &self,
+        sort: &Sort,
+        eval_scalar: &EvalScalar,
+        scan: &Scan,
+    ) -> Option<VectorIndexInfo> {
+        // The index only helps to find the nearest rows of the whole table.
+        let limit = sort.limit?;
+        if scan.push_down_predicates.is_some() || scan.prewhere.is_some() {
+            return None;
+        }
+        let sort_item = sort.items.first()?;
+        if !sort_item.asc {
+            return None;
+        }
+        let item = eval_scalar
+            .items
+            .iter()
+            .find(|item| item.index == sort_item.index)?;
+        let ScalarExpr::FunctionCall(func) = &item.scalar else {
+            return None;
+        };
+        let distance = match func.func_name.as_str() {
+            "cosine_distance" => "cosine",
+            "l2_distance" => "l2",
+            _ => return None,
+        };
+        let (column, query_values) = match func.arguments.as_slice() {
+            [ScalarExpr::BoundColumnRef(column), ScalarExpr::ConstantExpr(constant)]
+            | [ScalarExpr::ConstantExpr(constant), ScalarExpr::BoundColumnRef(column)] => {
+                match &constant.value {
+                    Scalar::Array(Column::Number(NumberColumn::Float32(values))) => {
+                        (&column.column, values.to_vec())
+                    }
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+        if column.table_index != Some(scan.table_index) || column.virtual_expr.is_some() {
+            return None;
+        }
+
+        let metadata = self.metadata.read();
+        let table = metadata.table(scan.table_index).table();
+        let table_info = table.get_table_info();
+        let column_id = table_info.schema().column_id_of(&column.column_name).ok()?;
+        let index = table_info.meta.indexes.values().find(|index| {
+            index.index_type == TableIndexType::Vector
+                && index.column_ids == [column_id]
+                && index.options.get("distance").map(|v| v.as_str()) == Some(distance)
+        })?;
+
+        Some(VectorIndexInfo {
+            index_name: index.name.clone(),
+            index_version: index.version.clone(),
+            column_name: column.column_name.clone(),
+            func_name: func.func_name.clone(),
+            query_values,
+            limit,
+        })
+    }
+}
+
 impl Rule for RulePushDownSortScan {
     fn id(&self) -> RuleID {
         self.id
@@ -76,9 +156,12 @@ impl Rule for RulePushDownSortScan {
         let child = s_expr.child(0)?;
         let mut get = match child.plan() {
             RelOperator::Scan(scan) => scan.clone(),
-            RelOperator::EvalScalar(_) => {
-                let child = child.child(0)?;
-                child.plan().clone().try_into()?
+            RelOperator::EvalScalar(eval_scalar) => {
+                let mut get: Scan = child.child(0)?.plan().clone().try_into()?;
+                if get.vector_index.is_none() {
+                    get.vector_index = self.try_push_down_vector_index(&sort, eval_scalar, &get);
+                }
+                get
             }
             _ => unreachable!(),
         };
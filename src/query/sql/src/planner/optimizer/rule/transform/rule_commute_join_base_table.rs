@@ -63,6 +63,10 @@ impl Rule for RuleCommuteJoinBaseTable {
         if left_child.plan.rel_op() == RelOp::Join || right_child.plan.rel_op() == RelOp::Join {
             return Ok(());
         }
+        // Skip if the build side is required by a hint.
+        if join.distribution_hint.is_some() {
+            return Ok(());
+        }
 
         match join.join_type {
             JoinType::Inner
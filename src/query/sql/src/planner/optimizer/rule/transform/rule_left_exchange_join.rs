@@ -102,9 +102,11 @@ impl Rule for RuleLeftExchangeJoin {
         let t2 = s_expr.child(0)?.child(1)?;
         let t3 = s_expr.child(1)?;
 
-        // Ensure inner joins or cross joins.
+        // Ensure inner joins or cross joins without distribution hints.
         if !matches!(join1.join_type, JoinType::Inner | JoinType::Cross)
             || !matches!(join2.join_type, JoinType::Inner | JoinType::Cross)
+            || join1.distribution_hint.is_some()
+            || join2.distribution_hint.is_some()
         {
             return Ok(());
         }
@@ -0,0 +1,258 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigbytesdb_common_ast::ast::quote::ident_needs_quote;
+use bigbytesdb_common_ast::ast::Identifier;
+use bigbytesdb_common_ast::ast::LeadingItem;
+use bigbytesdb_common_ast::ast::Literal;
+use bigbytesdb_common_ast::ast::PlanHint;
+use bigbytesdb_common_ast::ast::Query;
+use bigbytesdb_common_ast::ast::SelectStmt;
+use bigbytesdb_common_ast::ast::Statement;
+use bigbytesdb_common_ast::ast::TableReference;
+use bigbytesdb_common_ast::parser::parse_plan_hints;
+use bigbytesdb_common_ast::parser::tokenize_sql;
+use bigbytesdb_common_ast::parser::Dialect;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_meta_app::principal::PlanBaseline;
+use bigbytesdb_common_users::UserApiProvider;
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+use derive_visitor::Visitor;
+use derive_visitor::VisitorMut;
+use itertools::Itertools;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::binder::bind_plan_hints;
+use crate::optimizer::SExpr;
+use crate::plans::Exchange;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::IndexType;
+use crate::JoinOrderHint;
+use crate::Metadata;
+use crate::MetadataRef;
+use crate::NameResolutionContext;
+use crate::PlanHints;
+use crate::Planner;
+
+impl Planner {
+    /// Get the plan baseline pinned for the query of the statement.
+    #[async_backtrace::framed]
+    pub async fn get_plan_baseline(&self, stmt: &Statement) -> Result<Option<PlanBaseline>> {
+        let query = match stmt {
+            Statement::Query(query) => query,
+            Statement::Explain { query, .. } => match query.as_ref() {
+                Statement::Query(query) => query,
+                _ => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+        // The plan baseline only pins the joins, a query reading one table is not looked up.
+        if !self.ctx.get_settings().get_enable_plan_baseline()? || !may_join(query) {
+            return Ok(None);
+        }
+
+        let fingerprint = query_fingerprint(query, &self.ctx.get_current_database());
+        UserApiProvider::instance()
+            .get_plan_baseline_by_fingerprint(&self.ctx.get_tenant(), &fingerprint)
+            .await
+    }
+}
+
+/// Fingerprint of a query to match plan baselines, literals and hints are removed,
+/// so the queries only differing in them share the same plan baseline.
+pub fn query_fingerprint(query: &Query, database: &str) -> String {
+    #[derive(VisitorMut)]
+    #[visitor(Literal(enter), SelectStmt(enter))]
+    struct QueryNormalizer;
+
+    impl QueryNormalizer {
+        fn enter_literal(&mut self, literal: &mut Literal) {
+            *literal = Literal::Null;
+        }
+
+        fn enter_select_stmt(&mut self, stmt: &mut SelectStmt) {
+            stmt.hints = None;
+        }
+    }
+
+    let mut query = query.clone();
+    query.drive_mut(&mut QueryNormalizer);
+    // Unqualified tables are resolved in the current database.
+    format!("{:x}", Sha256::digest(format!("{database}: {query}")))
+}
+
+// Check if the query reads more than one relation.
+pub(crate) fn may_join(query: &Query) -> bool {
+    #[derive(Visitor)]
+    #[visitor(TableReference(enter))]
+    struct RelationCounter {
+        count: usize,
+    }
+
+    impl RelationCounter {
+        fn enter_table_reference(&mut self, table_ref: &TableReference) {
+            if !matches!(table_ref, TableReference::Join { .. }) {
+                self.count += 1;
+            }
+        }
+    }
+
+    let mut counter = RelationCounter { count: 0 };
+    query.drive(&mut counter);
+    counter.count > 1
+}
+
+/// Replace the optimizer hints of the query with the hints of the plan baseline.
+pub fn apply_plan_baseline(
+    metadata: &MetadataRef,
+    plan_baseline: &PlanBaseline,
+    name_resolution_ctx: &NameResolutionContext,
+) -> Result<()> {
+    let tokens = tokenize_sql(&plan_baseline.plan_hints)?;
+    let hints = parse_plan_hints(&tokens, Dialect::PostgreSQL)?;
+    let mut plan_hints = PlanHints {
+        plan_baseline: Some(plan_baseline.name.clone()),
+        ..Default::default()
+    };
+    bind_plan_hints(&mut plan_hints, &hints, name_resolution_ctx);
+    *metadata.write().plan_hints_mut() = plan_hints;
+    Ok(())
+}
+
+/// Capture the optimizer hints reproducing the join order and the distribution of
+/// the joins in the optimized plan, e.g. `LEADING(t1 t2 (t3 t4)) BROADCAST(t4)`.
+pub fn capture_plan_hints(s_expr: &SExpr, metadata: &Metadata) -> Result<String> {
+    let mut hints = vec![];
+    if let Some(join_order) = capture_join_order(s_expr, metadata)? {
+        hints.push(PlanHint::Leading(leading_items(&join_order)));
+    }
+    capture_distribution(s_expr, metadata, &mut hints)?;
+    Ok(hints.iter().join(" "))
+}
+
+// Find the first join tree that can be reordered and whose relations are all tables.
+#[recursive::recursive]
+fn capture_join_order(s_expr: &SExpr, metadata: &Metadata) -> Result<Option<JoinOrderHint>> {
+    if is_reorderable_join(s_expr) {
+        if let Some(join_order) = join_order(s_expr, metadata)? {
+            return Ok(Some(join_order));
+        }
+    }
+    for child in s_expr.children() {
+        if let Some(join_order) = capture_join_order(child, metadata)? {
+            return Ok(Some(join_order));
+        }
+    }
+    Ok(None)
+}
+
+#[recursive::recursive]
+fn join_order(s_expr: &SExpr, metadata: &Metadata) -> Result<Option<JoinOrderHint>> {
+    if !is_reorderable_join(s_expr) {
+        return Ok(hint_table_name(s_expr, metadata).map(JoinOrderHint::Table));
+    }
+    let left = join_order(s_expr.child(0)?, metadata)?;
+    let right = join_order(s_expr.child(1)?, metadata)?;
+    Ok(left
+        .zip(right)
+        .map(|(left, right)| JoinOrderHint::Join(Box::new(left), Box::new(right))))
+}
+
+fn is_reorderable_join(s_expr: &SExpr) -> bool {
+    matches!(
+        s_expr.plan(),
+        RelOperator::Join(join)
+            if matches!(join.join_type, JoinType::Inner | JoinType::Cross)
+                && join.build_side_cache_info.is_none()
+    )
+}
+
+#[recursive::recursive]
+fn capture_distribution(
+    s_expr: &SExpr,
+    metadata: &Metadata,
+    hints: &mut Vec<PlanHint>,
+) -> Result<()> {
+    if let RelOperator::Join(_) = s_expr.plan() {
+        let (left, right) = (s_expr.child(0)?, s_expr.child(1)?);
+        let hint = match (left.plan(), right.plan()) {
+            (_, RelOperator::Exchange(Exchange::Broadcast)) => {
+                hint_table_name(right, metadata).map(|table| PlanHint::Broadcast(ident(&table)))
+            }
+            (
+                RelOperator::Exchange(Exchange::Hash(_)),
+                RelOperator::Exchange(Exchange::Hash(_)),
+            ) => hint_table_name(right, metadata)
+                .or_else(|| hint_table_name(left, metadata))
+                .map(|table| PlanHint::Shuffle(ident(&table))),
+            _ => None,
+        };
+        hints.extend(hint);
+    }
+    for child in s_expr.children() {
+        capture_distribution(child, metadata, hints)?;
+    }
+    Ok(())
+}
+
+// The name referring to the only table read by the plan in hints.
+fn hint_table_name(s_expr: &SExpr, metadata: &Metadata) -> Option<String> {
+    let table = metadata.table(scanned_table(s_expr)?);
+    let name = table
+        .alias_name()
+        .clone()
+        .unwrap_or_else(|| table.name().to_string());
+    // The name must refer to exactly one table of the query.
+    (metadata.table_indexes_by_hint_name(&name).len() == 1).then_some(name)
+}
+
+#[recursive::recursive]
+fn scanned_table(s_expr: &SExpr) -> Option<IndexType> {
+    match s_expr.plan() {
+        RelOperator::Scan(scan) => Some(scan.table_index),
+        _ if s_expr.arity() == 1 => scanned_table(s_expr.child(0).ok()?),
+        _ => None,
+    }
+}
+
+// The joins on the left side are flattened, e.g. `((t1 t2) t3)` is `LEADING(t1 t2 t3)`.
+fn leading_items(join_order: &JoinOrderHint) -> Vec<LeadingItem> {
+    match join_order {
+        JoinOrderHint::Join(left, right) => {
+            let mut items = leading_items(left);
+            items.push(leading_item(right));
+            items
+        }
+        JoinOrderHint::Table(_) => vec![leading_item(join_order)],
+    }
+}
+
+fn leading_item(join_order: &JoinOrderHint) -> LeadingItem {
+    match join_order {
+        JoinOrderHint::Table(table) => LeadingItem::Table(ident(table)),
+        JoinOrderHint::Join(left, right) => {
+            LeadingItem::Join(Box::new(leading_item(left)), Box::new(leading_item(right)))
+        }
+    }
+}
+
+// The hints are parsed in PostgreSQL dialect when the plan baseline is replayed.
+fn ident(name: &str) -> Identifier {
+    let quote =
+        (name.chars().any(|c| c.is_ascii_uppercase()) || ident_needs_quote(name)).then_some('"');
+    Identifier::from_name_with_quoted(None, name, quote)
+}
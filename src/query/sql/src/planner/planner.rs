@@ -37,6 +37,7 @@ use log::info;
 use log::warn;
 use parking_lot::RwLock;
 
+use super::plan_baseline::apply_plan_baseline;
 use super::semantic::AggregateRewriter;
 use super::semantic::DistinctToGroupBy;
 use crate::optimizer::optimize;
@@ -224,10 +225,22 @@ impl Planner {
     pub async fn plan_stmt(&mut self, stmt: &Statement, attach_query: bool) -> Result<Plan> {
         let start = Instant::now();
         let query_kind = get_query_kind(stmt);
+        // The query string attached to the context is kept as the original statement.
+        let origin_stmt = stmt;
+        let rewritten_stmt = self.rewrite_materialized_views(stmt).await;
+        let stmt = rewritten_stmt.as_ref().unwrap_or(stmt);
         let settings = self.ctx.get_settings();
         // Step 3: Bind AST with catalog, and generate a pure logical SExpr
         let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
         let mut enable_planner_cache = self.ctx.get_settings().get_enable_planner_cache()?;
+        // The plan baseline is looked up before the planner cache and the replayed plans
+        // are not cached, so creating or dropping a plan baseline takes effect immediately
+        // on this node and on the others once their cached plan baselines are reloaded.
+        // The lookup is only done for the join queries if `enable_plan_baseline` is on.
+        let plan_baseline = self.get_plan_baseline(stmt).await?;
+        if plan_baseline.is_some() {
+            enable_planner_cache = false;
+        }
         let planner_cache_key = if enable_planner_cache {
             Some(Self::planner_cache_key(&stmt.to_string()))
         } else {
@@ -244,7 +257,8 @@ impl Planner {
                 info!("logical plan from cache, time used: {:?}", start.elapsed());
                 if attach_query {
                     // update for clickhouse handler
-                    self.ctx.attach_query_str(query_kind, stmt.to_mask_sql());
+                    self.ctx
+                        .attach_query_str(query_kind, origin_stmt.to_mask_sql());
                 }
                 return Ok(plan.plan);
             }
@@ -255,19 +269,24 @@ impl Planner {
         let binder = Binder::new(
             self.ctx.clone(),
             CatalogManager::instance(),
-            name_resolution_ctx,
+            name_resolution_ctx.clone(),
             metadata.clone(),
         )
         .with_subquery_executor(self.query_executor.clone());
 
         // must attach before bind, because ParquetRSTable::create used it.
         if attach_query {
-            self.ctx.attach_query_str(query_kind, stmt.to_mask_sql());
+            self.ctx
+                .attach_query_str(query_kind, origin_stmt.to_mask_sql());
         }
         let plan = binder.bind(stmt).await?;
+        if let Some(plan_baseline) = &plan_baseline {
+            apply_plan_baseline(&metadata, plan_baseline, &name_resolution_ctx)?;
+        }
         // attach again to avoid the query kind is overwritten by the subquery
         if attach_query {
-            self.ctx.attach_query_str(query_kind, stmt.to_mask_sql());
+            self.ctx
+                .attach_query_str(query_kind, origin_stmt.to_mask_sql());
         }
 
         // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
@@ -0,0 +1,43 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigbytesdb_common_catalog::table::NavigationPoint;
+use bigbytesdb_common_meta_app::schema::CreateOption;
+use bigbytesdb_common_meta_app::tenant::Tenant;
+
+use crate::plans::CreateDatabasePlan;
+
+/// Create a table sharing the segments and blocks of a Fuse table,
+/// optionally as of a point of the source table's history.
+#[derive(Clone, Debug)]
+pub struct CloneTablePlan {
+    pub create_option: CreateOption,
+    pub tenant: Tenant,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+
+    pub source_catalog: String,
+    pub source_database: String,
+    pub source_table: String,
+    pub navigation: Option<NavigationPoint>,
+}
+
+/// Create a database holding a clone of every table and view of the source database.
+#[derive(Clone, Debug)]
+pub struct CloneDatabasePlan {
+    pub create_database: CreateDatabasePlan,
+    pub source_catalog: String,
+    pub source_database: String,
+}
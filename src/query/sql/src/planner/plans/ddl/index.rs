@@ -14,10 +14,11 @@
 
 use std::collections::BTreeMap;
 
-use bigbytesdb_common_ast::ast::TableIndexType;
+use bigbytesdb_common_ast::ast::TableIndexType as AstTableIndexType;
 use bigbytesdb_common_expression::ColumnId;
 use bigbytesdb_common_meta_app::schema::CreateOption;
 use bigbytesdb_common_meta_app::schema::IndexMeta;
+use bigbytesdb_common_meta_app::schema::TableIndexType;
 use bigbytesdb_common_meta_app::schema::TableInfo;
 use bigbytesdb_common_meta_types::MetaId;
 use bigbytesdb_storages_common_table_meta::meta::Location;
@@ -27,7 +28,7 @@ use crate::plans::Plan;
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateIndexPlan {
     pub create_option: CreateOption,
-    pub index_type: TableIndexType,
+    pub index_type: AstTableIndexType,
     pub index_name: String,
     pub original_query: String,
     pub query: String,
@@ -57,6 +58,7 @@ pub struct RefreshIndexPlan {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateTableIndexPlan {
     pub create_option: CreateOption,
+    pub index_type: TableIndexType,
     pub catalog: String,
     pub index_name: String,
     pub column_ids: Vec<ColumnId>,
@@ -68,6 +70,7 @@ pub struct CreateTableIndexPlan {
 /// Drop.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DropTableIndexPlan {
+    pub index_type: TableIndexType,
     pub if_exists: bool,
     pub catalog: String,
     pub index_name: String,
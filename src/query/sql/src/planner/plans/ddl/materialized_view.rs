@@ -0,0 +1,31 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::plans::CreateTablePlan;
+
+/// Create a materialized view.
+///
+/// The view is stored as a Fuse table, `create_table` carries its schema and the
+/// materialized view options. The initial data is filled by a full refresh.
+#[derive(Clone, Debug)]
+pub struct CreateMaterializedViewPlan {
+    pub create_table: CreateTablePlan,
+}
+
+#[derive(Clone, Debug)]
+pub struct RefreshMaterializedViewPlan {
+    pub catalog: String,
+    pub database: String,
+    pub view: String,
+}
@@ -14,15 +14,19 @@
 
 mod account;
 mod catalog;
+mod clone;
 mod connection;
 mod database;
 mod dictionary;
 mod dynamic_table;
 mod file_format;
 mod index;
+mod materialized_view;
 mod notification;
+mod plan_baseline;
 mod procedure;
 mod sequence;
+mod snapshot_tag;
 mod stage;
 mod stream;
 mod table;
@@ -31,18 +35,23 @@ mod udf;
 mod view;
 mod virtual_column;
 mod warehouse;
+mod workload_group;
 
 pub use account::*;
 pub use catalog::*;
+pub use clone::*;
 pub use connection::*;
 pub use database::*;
 pub use dictionary::*;
 pub use dynamic_table::*;
 pub use file_format::*;
 pub use index::*;
+pub use materialized_view::*;
 pub use notification::*;
+pub use plan_baseline::*;
 pub use procedure::*;
 pub use sequence::*;
+pub use snapshot_tag::*;
 pub use stage::*;
 pub use stream::*;
 pub use table::*;
@@ -51,3 +60,4 @@ pub use udf::*;
 pub use view::*;
 pub use virtual_column::*;
 pub use warehouse::*;
+pub use workload_group::*;
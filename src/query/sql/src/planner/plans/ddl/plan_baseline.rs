@@ -0,0 +1,37 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigbytesdb_common_meta_app::schema::CreateOption;
+use bigbytesdb_common_meta_app::tenant::Tenant;
+
+use crate::plans::Plan;
+
+#[derive(Clone, Debug)]
+pub struct CreatePlanBaselinePlan {
+    pub create_option: CreateOption,
+    pub tenant: Tenant,
+    pub name: String,
+    pub fingerprint: String,
+    /// The query pinned by the plan baseline.
+    pub query: String,
+    /// Plan of the query, the plan baseline is captured from it after optimization.
+    pub plan: Box<Plan>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropPlanBaselinePlan {
+    pub if_exists: bool,
+    pub tenant: Tenant,
+    pub name: String,
+}
@@ -0,0 +1,34 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigbytesdb_common_catalog::table::NavigationPoint;
+
+/// Pin a snapshot of a Fuse table under a name, the current one if no point is given.
+#[derive(Clone, Debug)]
+pub struct CreateSnapshotTagPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub tag: String,
+    pub navigation: Option<NavigationPoint>,
+    pub retain_days: Option<u64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct DropSnapshotTagPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub tag: String,
+}
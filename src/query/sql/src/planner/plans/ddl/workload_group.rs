@@ -0,0 +1,70 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigbytesdb_common_meta_app::principal::WorkloadGroup;
+use bigbytesdb_common_meta_app::schema::CreateOption;
+use bigbytesdb_common_meta_app::tenant::Tenant;
+
+/// The workload group options set by `CREATE` or `ALTER WORKLOAD GROUP`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WorkloadGroupOptions {
+    pub max_concurrency: Option<u64>,
+    pub max_queued: Option<u64>,
+    pub queued_timeout_secs: Option<u64>,
+    pub max_memory_usage: Option<u64>,
+    pub cpu_weight: Option<u64>,
+}
+
+impl WorkloadGroupOptions {
+    pub fn apply(&self, workload_group: &mut WorkloadGroup) {
+        if let Some(v) = self.max_concurrency {
+            workload_group.max_concurrency = v;
+        }
+        if let Some(v) = self.max_queued {
+            workload_group.max_queued = v;
+        }
+        if let Some(v) = self.queued_timeout_secs {
+            workload_group.queued_timeout_secs = v;
+        }
+        if let Some(v) = self.max_memory_usage {
+            workload_group.max_memory_usage = v;
+        }
+        if let Some(v) = self.cpu_weight {
+            workload_group.cpu_weight = v;
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateWorkloadGroupPlan {
+    pub create_option: CreateOption,
+    pub tenant: Tenant,
+    pub name: String,
+    pub options: WorkloadGroupOptions,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterWorkloadGroupPlan {
+    pub if_exists: bool,
+    pub tenant: Tenant,
+    pub name: String,
+    pub options: WorkloadGroupOptions,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropWorkloadGroupPlan {
+    pub if_exists: bool,
+    pub tenant: Tenant,
+    pub name: String,
+}
@@ -0,0 +1,28 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// `INSERT OVERWRITE t PARTITION (value) ...` replaces the rows of a single partition,
+/// by running the statements below in one transaction.
+#[derive(Clone, Debug)]
+pub struct InsertOverwritePartitionPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    /// Deletes the rows of the partition.
+    pub delete: String,
+    /// Inserts the new rows of the partition.
+    pub insert: String,
+    /// The predicate of the partition, the inserted rows must match it.
+    pub predicate: String,
+}
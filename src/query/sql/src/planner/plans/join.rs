@@ -38,6 +38,7 @@ use crate::optimizer::RequiredProperty;
 use crate::optimizer::StatInfo;
 use crate::optimizer::Statistics;
 use crate::optimizer::UniformSampleSet;
+use crate::plans::ComparisonOp;
 use crate::plans::Operator;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
@@ -62,6 +63,11 @@ pub enum JoinType {
     /// Single Join is a special kind of join that is used to process correlated scalar subquery.
     LeftSingle,
     RightSingle,
+    /// Asof Join matches each left row with the closest right row that satisfies
+    /// the equi conditions and the match condition, see [`AsofCondition`].
+    Asof,
+    /// Same as Asof Join, but keeps the left rows without any match.
+    LeftAsof,
 }
 
 impl JoinType {
@@ -95,6 +101,10 @@ impl JoinType {
     pub fn is_mark_join(&self) -> bool {
         matches!(self, JoinType::LeftMark | JoinType::RightMark)
     }
+
+    pub fn is_asof_join(&self) -> bool {
+        matches!(self, JoinType::Asof | JoinType::LeftAsof)
+    }
 }
 
 impl Display for JoinType {
@@ -139,6 +149,12 @@ impl Display for JoinType {
             JoinType::RightSingle => {
                 write!(f, "RIGHT SINGLE")
             }
+            JoinType::Asof => {
+                write!(f, "ASOF")
+            }
+            JoinType::LeftAsof => {
+                write!(f, "LEFT ASOF")
+            }
         }
     }
 }
@@ -179,6 +195,24 @@ impl JoinEquiCondition {
     }
 }
 
+/// The match condition of an asof join, `left op right`, where `op` is one of
+/// `>`, `>=`, `<` and `<=`. For each left row, only the right row with the
+/// closest `right` value satisfying the condition is joined.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AsofCondition {
+    pub left: ScalarExpr,
+    pub right: ScalarExpr,
+    pub op: ComparisonOp,
+}
+
+/// Distribution of a join required by the `BROADCAST` or `SHUFFLE` hint,
+/// `Broadcast` means the build side is broadcast to all nodes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum JoinDistributionHint {
+    Broadcast,
+    Shuffle,
+}
+
 /// Join operator. We will choose hash join by default.
 /// In the case that using hash join, the right child
 /// is always the build side, and the left child is always
@@ -200,6 +234,10 @@ pub struct Join {
     pub single_to_inner: Option<JoinType>,
     // Cache info for ExpressionScan.
     pub build_side_cache_info: Option<HashJoinBuildCacheInfo>,
+    // Match condition for asof join.
+    pub asof_condition: Option<AsofCondition>,
+    // Distribution required by the `BROADCAST` or `SHUFFLE` hint.
+    pub distribution_hint: Option<JoinDistributionHint>,
 }
 
 impl Default for Join {
@@ -214,6 +252,8 @@ impl Default for Join {
             is_lateral: false,
             single_to_inner: None,
             build_side_cache_info: None,
+            asof_condition: None,
+            distribution_hint: None,
         }
     }
 }
@@ -237,6 +277,10 @@ impl Join {
                 .cloned()
                 .collect();
         }
+        if let Some(condition) = &self.asof_condition {
+            used_columns.extend(condition.left.used_columns());
+            used_columns.extend(condition.right.used_columns());
+        }
         Ok(used_columns)
     }
 
@@ -439,9 +483,14 @@ impl Join {
                     + f64::max(right_cardinality, inner_join_cardinality)
                     - inner_join_cardinality
             }
-            JoinType::LeftSemi => f64::min(left_cardinality, inner_join_cardinality),
+            JoinType::LeftSemi | JoinType::Asof => {
+                f64::min(left_cardinality, inner_join_cardinality)
+            }
             JoinType::RightSemi => f64::min(right_cardinality, inner_join_cardinality),
-            JoinType::LeftSingle | JoinType::RightMark | JoinType::LeftAnti => left_cardinality,
+            JoinType::LeftSingle
+            | JoinType::RightMark
+            | JoinType::LeftAnti
+            | JoinType::LeftAsof => left_cardinality,
             JoinType::RightSingle | JoinType::LeftMark | JoinType::RightAnti => right_cardinality,
         };
         // Derive column statistics
@@ -586,15 +635,26 @@ impl Operator for Join {
             return Ok(required);
         }
 
+        if self.distribution_hint == Some(JoinDistributionHint::Broadcast) {
+            if child_index == 1 {
+                required.distribution = Distribution::Broadcast;
+            } else {
+                required.distribution = Distribution::Any;
+            }
+            return Ok(required);
+        }
+
         // Try to use broadcast join
-        if !matches!(
-            self.join_type,
-            JoinType::Right
-                | JoinType::Full
-                | JoinType::RightAnti
-                | JoinType::RightSemi
-                | JoinType::LeftMark
-        ) {
+        if self.distribution_hint.is_none()
+            && !matches!(
+                self.join_type,
+                JoinType::Right
+                    | JoinType::Full
+                    | JoinType::RightAnti
+                    | JoinType::RightSemi
+                    | JoinType::LeftMark
+            )
+        {
             let settings = ctx.get_settings();
             let left_stat_info = rel_expr.derive_cardinality_child(0)?;
             let right_stat_info = rel_expr.derive_cardinality_child(1)?;
@@ -649,7 +709,15 @@ impl Operator for Join {
         let mut children_required = vec![];
 
         let settings = ctx.get_settings();
-        if self.join_type != JoinType::Cross && !settings.get_enforce_broadcast_join()? {
+        let (allow_shuffle, allow_broadcast) = match self.distribution_hint {
+            Some(JoinDistributionHint::Broadcast) => (false, true),
+            Some(JoinDistributionHint::Shuffle) => (true, false),
+            None => (
+                !settings.get_enforce_broadcast_join()?,
+                !settings.get_enforce_shuffle_join()?,
+            ),
+        };
+        if self.join_type != JoinType::Cross && allow_shuffle {
             // (Hash, Hash)
             children_required.extend(self.equi_conditions.iter().map(|condition| {
                 vec![
@@ -671,7 +739,7 @@ impl Operator for Join {
                 | JoinType::RightSemi
                 | JoinType::LeftMark
                 | JoinType::RightSingle
-        ) && !settings.get_enforce_shuffle_join()?
+        ) && allow_broadcast
         {
             // (Any, Broadcast)
             let left_distribution = Distribution::Any;
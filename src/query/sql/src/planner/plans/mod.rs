@@ -28,6 +28,7 @@ mod expression_scan;
 mod filter;
 mod insert;
 mod insert_multi_table;
+mod insert_partition;
 mod join;
 mod kill;
 mod limit;
@@ -69,6 +70,7 @@ pub use expression_scan::*;
 pub use filter::*;
 pub use insert::*;
 pub use insert_multi_table::*;
+pub use insert_partition::InsertOverwritePartitionPlan;
 pub use join::*;
 pub use kill::KillPlan;
 pub use limit::*;
@@ -35,6 +35,13 @@ pub struct OptimizeCompactSegmentPlan {
     pub num_segment_limit: Option<usize>,
 }
 
+#[derive(Clone, Debug)]
+pub struct OptimizeRefreshNgramIndexPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct OptimizeCompactBlock {
     pub catalog: String,
@@ -37,15 +37,19 @@ use crate::plans::AddWarehouseClusterPlan;
 use crate::plans::AlterNetworkPolicyPlan;
 use crate::plans::AlterNotificationPlan;
 use crate::plans::AlterPasswordPolicyPlan;
+use crate::plans::AlterRolePlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AlterTaskPlan;
 use crate::plans::AlterUDFPlan;
 use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
 use crate::plans::AlterVirtualColumnPlan;
+use crate::plans::AlterWorkloadGroupPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::AssignWarehouseNodesPlan;
 use crate::plans::CallProcedurePlan;
+use crate::plans::CloneDatabasePlan;
+use crate::plans::CloneTablePlan;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
 use crate::plans::CreateCatalogPlan;
@@ -55,12 +59,15 @@ use crate::plans::CreateDatamaskPolicyPlan;
 use crate::plans::CreateDynamicTablePlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateIndexPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateNetworkPolicyPlan;
 use crate::plans::CreateNotificationPlan;
 use crate::plans::CreatePasswordPolicyPlan;
+use crate::plans::CreatePlanBaselinePlan;
 use crate::plans::CreateProcedurePlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::CreateSequencePlan;
+use crate::plans::CreateSnapshotTagPlan;
 use crate::plans::CreateStagePlan;
 use crate::plans::CreateStreamPlan;
 use crate::plans::CreateTableIndexPlan;
@@ -71,6 +78,7 @@ use crate::plans::CreateUserPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::CreateVirtualColumnPlan;
 use crate::plans::CreateWarehousePlan;
+use crate::plans::CreateWorkloadGroupPlan;
 use crate::plans::DescConnectionPlan;
 use crate::plans::DescDatamaskPolicyPlan;
 use crate::plans::DescNetworkPolicyPlan;
@@ -90,9 +98,11 @@ use crate::plans::DropIndexPlan;
 use crate::plans::DropNetworkPolicyPlan;
 use crate::plans::DropNotificationPlan;
 use crate::plans::DropPasswordPolicyPlan;
+use crate::plans::DropPlanBaselinePlan;
 use crate::plans::DropProcedurePlan;
 use crate::plans::DropRolePlan;
 use crate::plans::DropSequencePlan;
+use crate::plans::DropSnapshotTagPlan;
 use crate::plans::DropStagePlan;
 use crate::plans::DropStreamPlan;
 use crate::plans::DropTableClusterKeyPlan;
@@ -106,6 +116,7 @@ use crate::plans::DropViewPlan;
 use crate::plans::DropVirtualColumnPlan;
 use crate::plans::DropWarehouseClusterPlan;
 use crate::plans::DropWarehousePlan;
+use crate::plans::DropWorkloadGroupPlan;
 use crate::plans::Exchange;
 use crate::plans::ExecuteImmediatePlan;
 use crate::plans::ExecuteTaskPlan;
@@ -114,14 +125,17 @@ use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
 use crate::plans::Insert;
 use crate::plans::InsertMultiTable;
+use crate::plans::InsertOverwritePartitionPlan;
 use crate::plans::InspectWarehousePlan;
 use crate::plans::KillPlan;
 use crate::plans::ModifyTableColumnPlan;
 use crate::plans::ModifyTableCommentPlan;
 use crate::plans::OptimizeCompactSegmentPlan;
 use crate::plans::OptimizePurgePlan;
+use crate::plans::OptimizeRefreshNgramIndexPlan;
 use crate::plans::PresignPlan;
 use crate::plans::RefreshIndexPlan;
+use crate::plans::RefreshMaterializedViewPlan;
 use crate::plans::RefreshTableIndexPlan;
 use crate::plans::RefreshVirtualColumnPlan;
 use crate::plans::RelOperator;
@@ -224,6 +238,7 @@ pub enum Plan {
     // Databases
     ShowCreateDatabase(Box<ShowCreateDatabasePlan>),
     CreateDatabase(Box<CreateDatabasePlan>),
+    CloneDatabase(Box<CloneDatabasePlan>),
     DropDatabase(Box<DropDatabasePlan>),
     UndropDatabase(Box<UndropDatabasePlan>),
     RenameDatabase(Box<RenameDatabasePlan>),
@@ -233,6 +248,7 @@ pub enum Plan {
     ShowCreateTable(Box<ShowCreateTablePlan>),
     DescribeTable(Box<DescribeTablePlan>),
     CreateTable(Box<CreateTablePlan>),
+    CloneTable(Box<CloneTablePlan>),
     DropTable(Box<DropTablePlan>),
     UndropTable(Box<UndropTablePlan>),
     RenameTable(Box<RenameTablePlan>),
@@ -249,6 +265,8 @@ pub enum Plan {
         is_final: bool,
     },
     RevertTable(Box<RevertTablePlan>),
+    CreateSnapshotTag(Box<CreateSnapshotTagPlan>),
+    DropSnapshotTag(Box<DropSnapshotTagPlan>),
     TruncateTable(Box<TruncateTablePlan>),
     VacuumTable(Box<VacuumTablePlan>),
     VacuumDropTable(Box<VacuumDropTablePlan>),
@@ -261,6 +279,7 @@ pub enum Plan {
     // Optimize
     OptimizePurge(Box<OptimizePurgePlan>),
     OptimizeCompactSegment(Box<OptimizeCompactSegmentPlan>),
+    OptimizeRefreshNgramIndex(Box<OptimizeRefreshNgramIndexPlan>),
     OptimizeCompactBlock {
         s_expr: Box<SExpr>,
         need_purge: bool,
@@ -269,6 +288,7 @@ pub enum Plan {
     // Insert
     Insert(Box<Insert>),
     InsertMultiTable(Box<InsertMultiTable>),
+    InsertOverwritePartition(Box<InsertOverwritePartitionPlan>),
     Replace(Box<Replace>),
     DataMutation {
         s_expr: Box<SExpr>,
@@ -318,6 +338,7 @@ pub enum Plan {
     ShowRoles(Box<ShowRolesPlan>),
     CreateRole(Box<CreateRolePlan>),
     DropRole(Box<DropRolePlan>),
+    AlterRole(Box<AlterRolePlan>),
     GrantRole(Box<GrantRolePlan>),
     GrantPriv(Box<GrantPrivilegePlan>),
     RevokePriv(Box<RevokePrivilegePlan>),
@@ -369,6 +390,15 @@ pub enum Plan {
     DropPasswordPolicy(Box<DropPasswordPolicyPlan>),
     DescPasswordPolicy(Box<DescPasswordPolicyPlan>),
 
+    // Plan baseline
+    CreatePlanBaseline(Box<CreatePlanBaselinePlan>),
+    DropPlanBaseline(Box<DropPlanBaselinePlan>),
+
+    // Workload group
+    CreateWorkloadGroup(Box<CreateWorkloadGroupPlan>),
+    AlterWorkloadGroup(Box<AlterWorkloadGroupPlan>),
+    DropWorkloadGroup(Box<DropWorkloadGroupPlan>),
+
     // Task
     CreateTask(Box<CreateTaskPlan>),
     AlterTask(Box<AlterTaskPlan>),
@@ -379,6 +409,10 @@ pub enum Plan {
 
     CreateDynamicTable(Box<CreateDynamicTablePlan>),
 
+    // Materialized views
+    CreateMaterializedView(Box<CreateMaterializedViewPlan>),
+    RefreshMaterializedView(Box<RefreshMaterializedViewPlan>),
+
     // Txn
     Begin,
     Commit,
@@ -442,6 +476,7 @@ pub enum RewriteKind {
     ListStage,
     ShowRoles,
     ShowPasswordPolicies,
+    ShowWorkloadGroups,
     ShowGrants,
 
     Call,
@@ -460,11 +495,12 @@ impl Plan {
             | Plan::ExplainAnalyze { .. }
             | Plan::ExplainAst { .. }
             | Plan::ExplainSyntax { .. } => QueryKind::Explain,
-            Plan::Insert(_) => QueryKind::Insert,
+            Plan::Insert(_) | Plan::InsertOverwritePartition(_) => QueryKind::Insert,
             Plan::Replace(_)
             | Plan::DataMutation { .. }
             | Plan::OptimizePurge(_)
             | Plan::OptimizeCompactSegment(_)
+            | Plan::OptimizeRefreshNgramIndex(_)
             | Plan::OptimizeCompactBlock { .. } => QueryKind::Update,
             _ => QueryKind::Other,
         }
@@ -100,6 +100,7 @@ use bigbytesdb_common_meta_app::schema::dictionary_name_ident::DictionaryNameIde
 use bigbytesdb_common_meta_app::schema::DictionaryIdentity;
 use bigbytesdb_common_meta_app::schema::GetSequenceReq;
 use bigbytesdb_common_meta_app::schema::SequenceIdent;
+use bigbytesdb_common_meta_app::schema::TableIndexType;
 use bigbytesdb_common_storage::init_stage_operator;
 use bigbytesdb_common_users::UserApiProvider;
 use derive_visitor::Drive;
@@ -1747,6 +1748,27 @@ impl<'a> TypeChecker<'a> {
             params
         };
 
+        // Convert the k and counters of approx_top_k to params
+        let params = if func_name.eq_ignore_ascii_case("approx_top_k")
+            && arguments.len() > 1
+            && params.is_empty()
+        {
+            arguments[1..]
+                .iter()
+                .map(|arg| {
+                    let value: u64 = check_number(
+                        None,
+                        &FunctionContext::default(),
+                        &arg.as_expr()?,
+                        &BUILTIN_FUNCTIONS,
+                    )?;
+                    Ok(Scalar::Number(NumberScalar::UInt64(value)))
+                })
+                .collect::<Result<Vec<_>>>()?
+        } else {
+            params
+        };
+
         // Rewrite `xxx(distinct)` to `xxx_distinct(...)`
         let (func_name, distinct) = if func_name.eq_ignore_ascii_case("count") && distinct {
             ("count_distinct", false)
@@ -2525,9 +2547,10 @@ impl<'a> TypeChecker<'a> {
         let mut index_schema = None;
         let mut index_options = BTreeMap::new();
         for table_index in table_indexes.values() {
-            if column_ids
-                .iter()
-                .all(|id| table_index.column_ids.contains(id))
+            if table_index.index_type == TableIndexType::Inverted
+                && column_ids
+                    .iter()
+                    .all(|id| table_index.column_ids.contains(id))
             {
                 index_name = table_index.name.clone();
                 index_version = table_index.version.clone();
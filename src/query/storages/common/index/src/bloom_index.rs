@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -22,7 +23,6 @@ use bigbytesdb_common_exception::ErrorCode;
 use bigbytesdb_common_exception::Result;
 use bigbytesdb_common_expression::converts::datavalues::scalar_to_datavalue;
 use bigbytesdb_common_expression::eval_function;
-use bigbytesdb_common_expression::types::boolean::BooleanDomain;
 use bigbytesdb_common_expression::types::nullable::NullableDomain;
 use bigbytesdb_common_expression::types::AnyType;
 use bigbytesdb_common_expression::types::Bitmap;
@@ -32,6 +32,8 @@ use bigbytesdb_common_expression::types::MapType;
 use bigbytesdb_common_expression::types::NullableType;
 use bigbytesdb_common_expression::types::Number;
 use bigbytesdb_common_expression::types::NumberDataType;
+use bigbytesdb_common_expression::types::NumberScalar;
+use bigbytesdb_common_expression::types::StringType;
 use bigbytesdb_common_expression::types::UInt64Type;
 use bigbytesdb_common_expression::types::ValueType;
 use bigbytesdb_common_expression::BlockEntry;
@@ -42,6 +44,7 @@ use bigbytesdb_common_expression::DataBlock;
 use bigbytesdb_common_expression::Domain;
 use bigbytesdb_common_expression::Expr;
 use bigbytesdb_common_expression::FieldIndex;
+use bigbytesdb_common_expression::FromData;
 use bigbytesdb_common_expression::FunctionContext;
 use bigbytesdb_common_expression::Scalar;
 use bigbytesdb_common_expression::ScalarRef;
@@ -159,6 +162,28 @@ pub enum FilterEvalResult {
     Uncertain,
 }
 
+/// NgramArgs describes an n-gram filter of a string column.
+///
+/// The filter is built over the distinct lower-cased n-grams of the column values,
+/// so that `LIKE '%pattern%'` and substring predicates can be checked against it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NgramArgs {
+    /// The index of the column in the source block.
+    pub index: FieldIndex,
+    pub field: TableField,
+    pub gram_size: usize,
+}
+
+impl NgramArgs {
+    pub fn new(index: FieldIndex, field: TableField, gram_size: usize) -> Self {
+        Self {
+            index,
+            field,
+            gram_size,
+        }
+    }
+}
+
 impl BloomIndex {
     /// Load a filter directly from the source table's schema and the corresponding filter parquet file.
     #[fastrace::trace]
@@ -185,6 +210,7 @@ impl BloomIndex {
         version: u64,
         block: &DataBlock,
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_args: &[NgramArgs],
     ) -> Result<Option<Self>> {
         // TODO refactor :
         // if only current version is allowed, just use the current version
@@ -303,6 +329,34 @@ impl BloomIndex {
             filters.push(Arc::new(filter));
         }
 
+        for arg in ngram_args {
+            let column = match &block.get_by_offset(arg.index).value {
+                Value::Scalar(_) => continue,
+                Value::Column(c) => c.clone(),
+            };
+
+            let grams = Self::column_ngrams(&column, arg.gram_size);
+            if grams.is_empty() {
+                continue;
+            }
+            let gram_column = StringType::from_data(grams);
+            let digests = Self::calculate_column_digest(
+                &func_ctx,
+                &gram_column,
+                &DataType::String,
+                &DataType::Number(NumberDataType::UInt64),
+            )?;
+            let digests = UInt64Type::try_downcast_column(&digests).unwrap();
+
+            let mut filter_builder = Xor8Builder::create();
+            filter_builder.add_digests(digests.deref());
+            let filter = filter_builder.build()?;
+
+            let filter_name = Self::build_ngram_filter_column_name(&arg.field, arg.gram_size);
+            filter_fields.push(TableField::new(&filter_name, TableDataType::Binary));
+            filters.push(Arc::new(filter));
+        }
+
         if filter_fields.is_empty() {
             return Ok(None);
         }
@@ -339,6 +393,7 @@ impl BloomIndex {
         &self,
         mut expr: Expr<String>,
         scalar_map: &HashMap<Scalar, u64>,
+        ngram_args: &[NgramArgs],
         column_stats: &StatisticsOfColumns,
         data_schema: TableSchemaRef,
     ) -> Result<FilterEvalResult> {
@@ -360,26 +415,13 @@ impl BloomIndex {
                     let new_col_name = format!("__bloom_column_{}_{}", col_name, new_col_id);
                     new_col_id += 1;
 
-                    let bool_domain = Domain::Boolean(BooleanDomain {
-                        has_false: true,
-                        has_true: false,
-                    });
-                    let new_domain = if return_type.is_nullable() {
-                        // generate `has_null` based on the `null_count` in column statistics.
-                        let has_null = match data_schema.column_id_of(col_name) {
-                            Ok(col_id) => match column_stats.get(&col_id) {
-                                Some(stat) => stat.null_count > 0,
-                                None => true,
-                            },
-                            Err(_) => true,
-                        };
-                        Domain::Nullable(NullableDomain {
-                            has_null,
-                            value: Some(Box::new(bool_domain)),
-                        })
-                    } else {
-                        bool_domain
-                    };
+                    let new_domain = Self::nullable_domain(
+                        Scalar::Boolean(false),
+                        col_name,
+                        return_type,
+                        column_stats,
+                        &data_schema,
+                    );
                     domains.insert(new_col_name.clone(), new_domain);
 
                     Ok(Some(Expr::ColumnRef {
@@ -394,6 +436,46 @@ impl BloomIndex {
             },
         )?;
 
+        if !ngram_args.is_empty() {
+            visit_expr_column_contains_constant(
+                &mut expr,
+                &mut |span, col_name, substrings, missing_value, return_type| {
+                    let Some(arg) = ngram_args.iter().find(|arg| arg.field.name() == col_name)
+                    else {
+                        return Ok(None);
+                    };
+
+                    // If any n-gram of the substrings is absent from the block,
+                    // the substrings can't be contained by any value of the column.
+                    // We rewrite the expression to a new column with the domain of
+                    // the result of a failed match.
+                    if self.find_ngrams(arg, substrings, scalar_map)? == FilterEvalResult::MustFalse
+                    {
+                        let new_col_name = format!("__ngram_column_{}_{}", col_name, new_col_id);
+                        new_col_id += 1;
+
+                        let new_domain = Self::nullable_domain(
+                            missing_value,
+                            col_name,
+                            return_type,
+                            column_stats,
+                            &data_schema,
+                        );
+                        domains.insert(new_col_name.clone(), new_domain);
+
+                        Ok(Some(Expr::ColumnRef {
+                            span,
+                            id: new_col_name.clone(),
+                            data_type: return_type.clone(),
+                            display_name: new_col_name,
+                        }))
+                    } else {
+                        Ok(None)
+                    }
+                },
+            )?;
+        }
+
         let (new_expr, _) =
             ConstantFolder::fold_with_domain(&expr, &domains, &self.func_ctx, &BUILTIN_FUNCTIONS);
 
@@ -406,6 +488,34 @@ impl BloomIndex {
         }
     }
 
+    /// Build the domain of a rewritten expression which can only evaluate to `value`,
+    /// or to NULL if the column may contain nulls.
+    fn nullable_domain(
+        value: Scalar,
+        col_name: &str,
+        return_type: &DataType,
+        column_stats: &StatisticsOfColumns,
+        data_schema: &TableSchemaRef,
+    ) -> Domain {
+        let domain = value.as_ref().domain(&return_type.remove_nullable());
+        if return_type.is_nullable() {
+            // generate `has_null` based on the `null_count` in column statistics.
+            let has_null = match data_schema.column_id_of(col_name) {
+                Ok(col_id) => match column_stats.get(&col_id) {
+                    Some(stat) => stat.null_count > 0,
+                    None => true,
+                },
+                Err(_) => true,
+            };
+            Domain::Nullable(NullableDomain {
+                has_null,
+                value: Some(Box::new(domain)),
+            })
+        } else {
+            domain
+        }
+    }
+
     /// calculate digest for column
     pub fn calculate_column_digest(
         func_ctx: &FunctionContext,
@@ -491,6 +601,86 @@ impl BloomIndex {
         Ok(cols)
     }
 
+    /// Find all columns with a n-gram filter that match the pattern of
+    /// `col LIKE <constant>` or a substring search of a constant in the expression,
+    /// returns the n-grams that should be looked up in the filters.
+    pub fn find_ngram_columns(
+        expr: &Expr<String>,
+        ngram_args: &[NgramArgs],
+    ) -> Result<Vec<(NgramArgs, Vec<String>)>> {
+        let mut cols = Vec::new();
+        visit_expr_column_contains_constant(
+            &mut expr.clone(),
+            &mut |_, col_name, substrings, _, _| {
+                if let Some(arg) = ngram_args.iter().find(|arg| arg.field.name() == col_name) {
+                    let grams = Self::substring_ngrams(substrings, arg.gram_size);
+                    if !grams.is_empty() {
+                        cols.push((arg.clone(), grams));
+                    }
+                }
+                Ok(None)
+            },
+        )?;
+        Ok(cols)
+    }
+
+    /// The n-gram filter of a column will be stored with field name 'Ngram{gram_size}(column_id)'.
+    ///
+    /// The gram size is part of the name, so that filters built with a different
+    /// gram size are never consulted after the table option is changed.
+    pub fn build_ngram_filter_column_name(field: &TableField, gram_size: usize) -> String {
+        format!("Ngram{}({})", gram_size, field.column_id())
+    }
+
+    /// Collect the distinct lower-cased n-grams of a string column.
+    fn column_ngrams(column: &Column, gram_size: usize) -> Vec<String> {
+        let mut grams = HashSet::new();
+        if let Column::String(column) = column.remove_nullable() {
+            for value in column.iter() {
+                collect_ngrams(&lowercase(value), gram_size, &mut grams);
+            }
+        }
+        grams.into_iter().collect()
+    }
+
+    /// Collect the distinct lower-cased n-grams of the substrings being searched.
+    ///
+    /// Substrings shorter than `gram_size` have no n-grams, and can't be used for pruning.
+    pub fn substring_ngrams(substrings: &[String], gram_size: usize) -> Vec<String> {
+        let mut grams = HashSet::new();
+        for substring in substrings {
+            collect_ngrams(&lowercase(substring), gram_size, &mut grams);
+        }
+        grams.into_iter().collect()
+    }
+
+    fn find_ngrams(
+        &self,
+        arg: &NgramArgs,
+        substrings: &[String],
+        scalar_map: &HashMap<Scalar, u64>,
+    ) -> Result<FilterEvalResult> {
+        let filter_column = Self::build_ngram_filter_column_name(&arg.field, arg.gram_size);
+        if !self.filter_schema.has_field(&filter_column) {
+            // The column doesn't have a n-gram filter, e.g. the block is written
+            // before the n-gram index is configured.
+            return Ok(FilterEvalResult::Uncertain);
+        }
+
+        let idx = self.filter_schema.index_of(&filter_column)?;
+        let filter = &self.filters[idx];
+
+        for gram in Self::substring_ngrams(substrings, arg.gram_size) {
+            let contains = scalar_map
+                .get(&Scalar::String(gram))
+                .map_or(true, |digest| filter.contains_digest(*digest));
+            if !contains {
+                return Ok(FilterEvalResult::MustFalse);
+            }
+        }
+        Ok(FilterEvalResult::Uncertain)
+    }
+
     /// For every applicable column, we will create a filter.
     /// The filter will be stored with field name 'Bloom(column_name)'
     pub fn build_filter_column_name(version: u64, field: &TableField) -> Result<String> {
@@ -545,6 +735,10 @@ impl BloomIndex {
         Xor8Filter::supported_type(&data_type)
     }
 
+    pub fn supported_ngram_type(data_type: &TableDataType) -> bool {
+        data_type.remove_nullable() == TableDataType::String
+    }
+
     /// Checks if the average length of a string column exceeds 256 bytes.
     /// If it does, the bloom index for the column will not be established.
     fn check_large_string(column: &Column) -> bool {
@@ -686,6 +880,144 @@ fn visit_expr_column_eq_constant(
     Ok(())
 }
 
+fn visit_expr_column_contains_constant(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(Span, &str, &[String], Scalar, &DataType) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    // Find patterns like `Column LIKE <constant>`, `position(<constant>, Column)`,
+    // `locate(<constant>, Column)` or `instr(Column, <constant>)`.
+    // The visitor is given the literal substrings that any matched value must contain,
+    // and the result of the expression if the substrings are not contained.
+    if let Expr::FunctionCall {
+        span,
+        id,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        let matched = match (id.name().as_ref(), args.as_slice()) {
+            (
+                "like",
+                [Expr::ColumnRef {
+                    id: col_name,
+                    data_type,
+                    ..
+                }, Expr::Constant {
+                    scalar: Scalar::String(pattern),
+                    ..
+                }],
+            ) => Some((
+                col_name,
+                data_type,
+                like_pattern_literals(pattern),
+                Scalar::Boolean(false),
+            )),
+            (
+                "position" | "locate",
+                [Expr::Constant {
+                    scalar: Scalar::String(substring),
+                    ..
+                }, Expr::ColumnRef {
+                    id: col_name,
+                    data_type,
+                    ..
+                }, ..],
+            )
+            | (
+                "instr",
+                [Expr::ColumnRef {
+                    id: col_name,
+                    data_type,
+                    ..
+                }, Expr::Constant {
+                    scalar: Scalar::String(substring),
+                    ..
+                }],
+            ) => Some((
+                col_name,
+                data_type,
+                vec![substring.clone()],
+                Scalar::Number(NumberScalar::UInt64(0)),
+            )),
+            _ => None,
+        };
+
+        if let Some((col_name, data_type, substrings, missing_value)) = matched {
+            if data_type.remove_nullable() == DataType::String && !substrings.is_empty() {
+                if let Some(new_expr) =
+                    visitor(*span, col_name, &substrings, missing_value, return_type)?
+                {
+                    *expr = new_expr;
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_column_contains_constant(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_column_contains_constant(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+/// Split a LIKE pattern into the literal substrings between the wildcards.
+fn like_pattern_literals(pattern: &str) -> Vec<String> {
+    let mut literals = vec![];
+    let mut current = String::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    current.push(escaped);
+                }
+            }
+            '%' | '_' => {
+                if !current.is_empty() {
+                    literals.push(std::mem::take(&mut current));
+                }
+            }
+            _ => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        literals.push(current);
+    }
+    literals
+}
+
+/// Collect the n-grams of a string, in unit of chars.
+// Lower-case the chars one by one. `str::to_lowercase` maps 'Σ' to 'ς' at the end of
+// a word, so a value and a substring cut out of it could get different n-grams.
+fn lowercase(value: &str) -> String {
+    value.chars().flat_map(char::to_lowercase).collect()
+}
+
+fn collect_ngrams(value: &str, gram_size: usize, grams: &mut HashSet<String>) {
+    if gram_size == 0 {
+        return;
+    }
+    let offsets = value
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(value.len()))
+        .collect::<Vec<_>>();
+    for window in offsets.windows(gram_size + 1) {
+        grams.insert(value[window[0]..window[gram_size]].to_string());
+    }
+}
+
 fn visit_map_column(
     span: Span,
     args: &[Expr<String>],
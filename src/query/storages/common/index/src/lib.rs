@@ -21,10 +21,12 @@ mod index;
 mod inverted_index;
 mod page_index;
 mod range_index;
+mod spatial_index;
 
 pub use bloom_index::BloomIndex;
 pub use bloom_index::BloomIndexMeta;
 pub use bloom_index::FilterEvalResult;
+pub use bloom_index::NgramArgs;
 pub use index::Index;
 pub use inverted_index::extract_component_fields;
 pub use inverted_index::extract_fsts;
@@ -36,3 +38,4 @@ pub use inverted_index::TermReader;
 pub use page_index::PageIndex;
 pub use range_index::statistics_to_domain;
 pub use range_index::RangeIndex;
+pub use spatial_index::SpatialIndex;
@@ -0,0 +1,266 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigbytesdb_common_expression::types::DataType;
+use bigbytesdb_common_expression::types::NumberScalar;
+use bigbytesdb_common_expression::ColumnId;
+use bigbytesdb_common_expression::Expr;
+use bigbytesdb_common_expression::Scalar;
+use bigbytesdb_common_expression::TableSchemaRef;
+use bigbytesdb_common_io::ewkb_bounding_rect;
+use bigbytesdb_storages_common_table_meta::meta::SpatialStatistics;
+use bigbytesdb_storages_common_table_meta::meta::SpatialStatisticsOfColumns;
+
+use crate::Index;
+
+/// Prunes blocks by the bounding boxes of their Geometry columns.
+///
+/// Supported predicates are `st_intersects`, `st_contains`, `st_within` and `st_dwithin`
+/// between a Geometry column and a constant geometry, combined with `AND` and `OR`.
+#[derive(Clone, Debug)]
+pub struct SpatialIndex {
+    condition: SpatialCondition,
+}
+
+#[derive(Clone, Debug)]
+enum SpatialCondition {
+    /// The bounding box of the column must intersect `bbox`.
+    Intersects {
+        column_id: ColumnId,
+        bbox: SpatialStatistics,
+    },
+    /// The bounding box of the column must contain `bbox`.
+    Contains {
+        column_id: ColumnId,
+        bbox: SpatialStatistics,
+    },
+    And(Vec<SpatialCondition>),
+    Or(Vec<SpatialCondition>),
+    /// A predicate that can not be used to prune blocks.
+    Unknown,
+}
+
+impl SpatialIndex {
+    /// Returns `None` if the filter has no predicate usable by the spatial index.
+    pub fn try_create(expr: &Expr<String>, schema: &TableSchemaRef) -> Option<Self> {
+        let condition = SpatialCondition::from_expr(expr, schema);
+        if condition.is_unknown() {
+            return None;
+        }
+        Some(Self { condition })
+    }
+
+    /// Returns false if the block can be skipped (false positive allowed).
+    pub fn should_keep(&self, stats: &SpatialStatisticsOfColumns) -> bool {
+        self.condition.eval(stats)
+    }
+}
+
+impl SpatialCondition {
+    fn from_expr(expr: &Expr<String>, schema: &TableSchemaRef) -> Self {
+        let Expr::FunctionCall { id, args, .. } = Self::unwrap_cast(expr) else {
+            return SpatialCondition::Unknown;
+        };
+        let name = id.name();
+        match name.as_ref() {
+            // A filter on a nullable predicate is wrapped in `is_true`, a block whose
+            // predicate can not be true can be skipped all the same.
+            "is_true" => Self::from_expr(&args[0], schema),
+            "and" | "and_filters" => {
+                let conditions = args
+                    .iter()
+                    .map(|arg| Self::from_expr(arg, schema))
+                    .filter(|condition| !condition.is_unknown())
+                    .collect::<Vec<_>>();
+                if conditions.is_empty() {
+                    SpatialCondition::Unknown
+                } else {
+                    SpatialCondition::And(conditions)
+                }
+            }
+            "or" => {
+                let conditions = args
+                    .iter()
+                    .map(|arg| Self::from_expr(arg, schema))
+                    .collect::<Vec<_>>();
+                if conditions.iter().any(|condition| condition.is_unknown()) {
+                    SpatialCondition::Unknown
+                } else {
+                    SpatialCondition::Or(conditions)
+                }
+            }
+            "st_intersects" => {
+                match Self::symmetric_column_and_constant(&args[0], &args[1], schema) {
+                    Some((column_id, bbox)) => SpatialCondition::Intersects { column_id, bbox },
+                    None => SpatialCondition::Unknown,
+                }
+            }
+            // If the column is inside the constant, it must intersect the box of the constant.
+            // If the column contains the constant, its box must contain the box of the constant.
+            "st_contains" | "st_within" => {
+                let (inner, outer) = if name.as_ref() == "st_contains" {
+                    (&args[1], &args[0])
+                } else {
+                    (&args[0], &args[1])
+                };
+                if let Some((column_id, bbox)) = Self::column_and_constant(inner, outer, schema) {
+                    SpatialCondition::Intersects { column_id, bbox }
+                } else if let Some((column_id, bbox)) =
+                    Self::column_and_constant(outer, inner, schema)
+                {
+                    SpatialCondition::Contains { column_id, bbox }
+                } else {
+                    SpatialCondition::Unknown
+                }
+            }
+            "st_dwithin" => {
+                let distance = match &args[2] {
+                    Expr::Constant {
+                        scalar: Scalar::Number(NumberScalar::Float64(distance)),
+                        ..
+                    } if distance.0 >= 0.0 => distance.0,
+                    _ => return SpatialCondition::Unknown,
+                };
+                match Self::symmetric_column_and_constant(&args[0], &args[1], schema) {
+                    Some((column_id, bbox)) => SpatialCondition::Intersects {
+                        column_id,
+                        bbox: bbox.expand(distance),
+                    },
+                    None => SpatialCondition::Unknown,
+                }
+            }
+            _ => SpatialCondition::Unknown,
+        }
+    }
+
+    /// Matches a Geometry column and a constant geometry in either order.
+    fn symmetric_column_and_constant(
+        lhs: &Expr<String>,
+        rhs: &Expr<String>,
+        schema: &TableSchemaRef,
+    ) -> Option<(ColumnId, SpatialStatistics)> {
+        Self::column_and_constant(lhs, rhs, schema)
+            .or_else(|| Self::column_and_constant(rhs, lhs, schema))
+    }
+
+    /// Matches a Geometry column `column` and a constant geometry `constant`,
+    /// returns the column id and the bounding box of the constant.
+    fn column_and_constant(
+        column: &Expr<String>,
+        constant: &Expr<String>,
+        schema: &TableSchemaRef,
+    ) -> Option<(ColumnId, SpatialStatistics)> {
+        match (Self::unwrap_cast(column), Self::unwrap_cast(constant)) {
+            (
+                Expr::ColumnRef { id, data_type, .. },
+                Expr::Constant {
+                    scalar: Scalar::Geometry(ewkb),
+                    ..
+                },
+            ) if SpatialIndex::supported_type(data_type) => {
+                let field = schema.field_with_name(id).ok()?;
+                let rect = ewkb_bounding_rect(ewkb).ok()??;
+                let (min, max) = (rect.min(), rect.max());
+                Some((
+                    field.column_id(),
+                    SpatialStatistics::new(min.x, min.y, max.x, max.y),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Strips the casts that only change the nullability of the expression.
+    fn unwrap_cast(expr: &Expr<String>) -> &Expr<String> {
+        match expr {
+            Expr::Cast {
+                expr: inner,
+                dest_type,
+                ..
+            } if dest_type.remove_nullable() == inner.data_type().remove_nullable() => {
+                Self::unwrap_cast(inner)
+            }
+            _ => expr,
+        }
+    }
+
+    fn is_unknown(&self) -> bool {
+        matches!(self, SpatialCondition::Unknown)
+    }
+
+    fn eval(&self, stats: &SpatialStatisticsOfColumns) -> bool {
+        match self {
+            SpatialCondition::Intersects { column_id, bbox } => stats
+                .get(column_id)
+                .map_or(true, |stat| stat.intersects(bbox)),
+            SpatialCondition::Contains { column_id, bbox } => stats
+                .get(column_id)
+                .map_or(true, |stat| stat.contains(bbox)),
+            SpatialCondition::And(conditions) => conditions.iter().all(|c| c.eval(stats)),
+            SpatialCondition::Or(conditions) => conditions.iter().any(|c| c.eval(stats)),
+            SpatialCondition::Unknown => true,
+        }
+    }
+}
+
+impl Index for SpatialIndex {
+    fn supported_type(data_type: &DataType) -> bool {
+        data_type.remove_nullable() == DataType::Geometry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spatial_statistics_relations() {
+        let block = SpatialStatistics::new(0.0, 0.0, 10.0, 10.0);
+        assert!(block.intersects(&SpatialStatistics::new(5.0, 5.0, 20.0, 20.0)));
+        assert!(block.intersects(&SpatialStatistics::new(10.0, 10.0, 20.0, 20.0)));
+        assert!(!block.intersects(&SpatialStatistics::new(11.0, 0.0, 20.0, 10.0)));
+        assert!(block
+            .expand(1.0)
+            .intersects(&SpatialStatistics::new(11.0, 0.0, 20.0, 10.0)));
+
+        assert!(block.contains(&SpatialStatistics::new(1.0, 1.0, 2.0, 2.0)));
+        assert!(!block.contains(&SpatialStatistics::new(5.0, 5.0, 20.0, 20.0)));
+    }
+
+    #[test]
+    fn test_spatial_condition_eval() {
+        let mut stats = SpatialStatisticsOfColumns::new();
+        stats.insert(0, SpatialStatistics::new(0.0, 0.0, 10.0, 10.0));
+
+        let inside = SpatialCondition::Intersects {
+            column_id: 0,
+            bbox: SpatialStatistics::new(1.0, 1.0, 2.0, 2.0),
+        };
+        let outside = SpatialCondition::Intersects {
+            column_id: 0,
+            bbox: SpatialStatistics::new(20.0, 20.0, 30.0, 30.0),
+        };
+        let no_stats = SpatialCondition::Intersects {
+            column_id: 1,
+            bbox: SpatialStatistics::new(20.0, 20.0, 30.0, 30.0),
+        };
+
+        assert!(inside.eval(&stats));
+        assert!(!outside.eval(&stats));
+        assert!(no_stats.eval(&stats));
+        assert!(!SpatialCondition::And(vec![inside.clone(), outside.clone()]).eval(&stats));
+        assert!(SpatialCondition::Or(vec![inside, outside.clone()]).eval(&stats));
+        assert!(!SpatialCondition::Or(vec![outside.clone(), outside]).eval(&stats));
+    }
+}
@@ -49,6 +49,7 @@ use bigbytesdb_storages_common_index::filters::Xor8Filter;
 use bigbytesdb_storages_common_index::BloomIndex;
 use bigbytesdb_storages_common_index::FilterEvalResult;
 use bigbytesdb_storages_common_index::Index;
+use bigbytesdb_storages_common_index::NgramArgs;
 use bigbytesdb_storages_common_table_meta::meta::StatisticsOfColumns;
 use bigbytesdb_storages_common_table_meta::meta::Versioned;
 
@@ -154,6 +155,7 @@ fn test_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &block,
         bloom_columns,
+        &[],
     )?
     .unwrap();
 
@@ -326,6 +328,7 @@ fn test_specify_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &block,
         bloom_columns,
+        &[],
     )?
     .unwrap();
 
@@ -366,6 +369,7 @@ fn test_string_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &block,
         bloom_columns,
+        &[],
     )?
     .unwrap();
 
@@ -384,6 +388,120 @@ fn test_string_bloom_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ngram_bloom_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("0", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new("1", TableDataType::String),
+    ]));
+
+    let blocks = [DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2]),
+        StringType::from_data(vec!["Request error-1234 occurred", "ok"]),
+    ])];
+    let block = DataBlock::concat(&blocks)?;
+
+    let ngram_args = vec![NgramArgs::new(1, schema.field(1).clone(), 3)];
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        LatestBloom::VERSION,
+        &block,
+        BTreeMap::new(),
+        &ngram_args,
+    )?
+    .unwrap();
+
+    let like = |pattern: &str| {
+        let expr = check_function(
+            None,
+            "like",
+            &[],
+            &[string_column_ref("1"), string_constant(pattern)],
+            &BUILTIN_FUNCTIONS,
+        )
+        .unwrap();
+        eval_ngram_index(&index, expr, &ngram_args, schema.clone())
+    };
+
+    assert_eq!(FilterEvalResult::Uncertain, like("%error-1234%"));
+    assert_eq!(FilterEvalResult::Uncertain, like("%ERROR-12%occurred"));
+    assert_eq!(FilterEvalResult::MustFalse, like("%warn%"));
+    assert_eq!(FilterEvalResult::MustFalse, like("%error-5678%"));
+    // Literals shorter than the gram size can't be used for pruning.
+    assert_eq!(FilterEvalResult::Uncertain, like("%zz%"));
+
+    let position = check_function(
+        None,
+        "position",
+        &[],
+        &[string_constant("timeout"), string_column_ref("1")],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+    let expr = check_function(
+        None,
+        "gt",
+        &[],
+        &[position, Expr::Constant {
+            span: None,
+            scalar: Scalar::Number(NumberScalar::UInt64(0)),
+            data_type: DataType::Number(NumberDataType::UInt64),
+        }],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_ngram_index(&index, expr, &ngram_args, schema.clone())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_ngram_bloom_filter_final_sigma() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("0", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new("1", TableDataType::String),
+    ]));
+
+    let block = DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2]),
+        StringType::from_data(vec!["ΛΟΓΟΣ", "ΟΔΟΣΑ"]),
+    ]);
+
+    let ngram_args = vec![NgramArgs::new(1, schema.field(1).clone(), 3)];
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        LatestBloom::VERSION,
+        &block,
+        BTreeMap::new(),
+        &ngram_args,
+    )?
+    .unwrap();
+
+    let like = |pattern: &str| {
+        let expr = check_function(
+            None,
+            "like",
+            &[],
+            &[string_column_ref("1"), string_constant(pattern)],
+            &BUILTIN_FUNCTIONS,
+        )
+        .unwrap();
+        eval_ngram_index(&index, expr, &ngram_args, schema.clone())
+    };
+
+    // 'Σ' is lower-cased the same at the end of a value and in the middle of a substring.
+    assert_eq!(FilterEvalResult::Uncertain, like("%ΛΟΓΟΣ%"));
+    assert_eq!(FilterEvalResult::Uncertain, like("%ΟΓΟΣ"));
+    // 'Σ' is lower-cased the same in the middle of a value and at the end of a substring.
+    assert_eq!(FilterEvalResult::Uncertain, like("%ΟΔΟΣ%"));
+    assert_eq!(FilterEvalResult::MustFalse, like("%ΛΟΓΟΙ%"));
+
+    Ok(())
+}
+
 fn eval_index(
     index: &BloomIndex,
     col_name: &str,
@@ -425,7 +543,7 @@ fn eval_index(
     }
     let column_stats = StatisticsOfColumns::new();
     index
-        .apply(expr, &scalar_map, &column_stats, schema)
+        .apply(expr, &scalar_map, &[], &column_stats, schema)
         .unwrap()
 }
 
@@ -486,10 +604,51 @@ fn eval_map_index(
     }
     let column_stats = StatisticsOfColumns::new();
     index
-        .apply(expr, &scalar_map, &column_stats, schema)
+        .apply(expr, &scalar_map, &[], &column_stats, schema)
         .unwrap()
 }
 
+fn eval_ngram_index(
+    index: &BloomIndex,
+    expr: Expr<String>,
+    ngram_args: &[NgramArgs],
+    schema: Arc<TableSchema>,
+) -> FilterEvalResult {
+    let func_ctx = FunctionContext::default();
+    let ngram_cols = BloomIndex::find_ngram_columns(&expr, ngram_args).unwrap();
+
+    let mut scalar_map = HashMap::<Scalar, u64>::new();
+    for (_, grams) in ngram_cols.iter() {
+        for gram in grams {
+            let scalar = Scalar::String(gram.clone());
+            let digest =
+                BloomIndex::calculate_scalar_digest(&func_ctx, &scalar, &DataType::String).unwrap();
+            scalar_map.insert(scalar, digest);
+        }
+    }
+    let column_stats = StatisticsOfColumns::new();
+    index
+        .apply(expr, &scalar_map, ngram_args, &column_stats, schema)
+        .unwrap()
+}
+
+fn string_column_ref(col_name: &str) -> Expr<String> {
+    Expr::ColumnRef {
+        span: None,
+        id: col_name.to_string(),
+        data_type: DataType::String,
+        display_name: col_name.to_string(),
+    }
+}
+
+fn string_constant(value: &str) -> Expr<String> {
+    Expr::Constant {
+        span: None,
+        scalar: Scalar::String(value.to_string()),
+        data_type: DataType::String,
+    }
+}
+
 fn bloom_columns_map(
     schema: TableSchemaRef,
     cols: Vec<FieldIndex>,
@@ -18,6 +18,7 @@ pub use v2::ClusterStatistics;
 pub use v2::ColumnMeta;
 pub use v2::ColumnStatistics;
 pub use v2::MetaHLL;
+pub use v2::SpatialStatistics;
 pub use v2::Statistics;
 pub use v3::TableSnapshotStatistics;
 pub use v4::CompactSegmentInfo;
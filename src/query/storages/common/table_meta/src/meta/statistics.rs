@@ -19,12 +19,14 @@ use bigbytesdb_common_expression::types::DataType;
 use bigbytesdb_common_expression::ColumnId;
 
 use crate::meta::ColumnStatistics;
+use crate::meta::SpatialStatistics;
 
 pub type FormatVersion = u64;
 pub type SnapshotId = Uuid;
 pub type Location = (String, FormatVersion);
 pub type ClusterKey = (u32, String);
 pub type StatisticsOfColumns = HashMap<ColumnId, ColumnStatistics>;
+pub type SpatialStatisticsOfColumns = HashMap<ColumnId, SpatialStatistics>;
 
 // Assigned to executors, describes that which blocks of given segment, an executor should take care of
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq)]
@@ -23,6 +23,7 @@ pub use segment::SegmentInfo;
 pub use snapshot::TableSnapshot;
 pub use statistics::ClusterStatistics;
 pub use statistics::ColumnStatistics;
+pub use statistics::SpatialStatistics;
 pub use statistics::Statistics;
 pub use table_snapshot_statistics::MetaHLL;
 pub use table_snapshot_statistics::TableSnapshotStatistics;
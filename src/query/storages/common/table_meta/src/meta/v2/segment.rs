@@ -21,6 +21,7 @@ use chrono::Utc;
 use bigbytesdb_common_expression::BlockMetaInfo;
 use bigbytesdb_common_expression::BlockMetaInfoDowncast;
 use bigbytesdb_common_expression::ColumnId;
+use bigbytesdb_common_expression::Scalar;
 use bigbytesdb_common_expression::TableField;
 use bigbytesdb_common_native::ColumnMeta as NativeColumnMeta;
 use enum_as_inner::EnumAsInner;
@@ -34,6 +35,7 @@ use crate::meta::ColumnStatistics;
 use crate::meta::Compression;
 use crate::meta::FormatVersion;
 use crate::meta::Location;
+use crate::meta::SpatialStatisticsOfColumns;
 use crate::meta::Statistics;
 use crate::meta::Versioned;
 
@@ -82,6 +84,19 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// bounding boxes of Geometry columns
+    #[serde(default)]
+    pub spatial_stats: SpatialStatisticsOfColumns,
+
+    /// value of the `PARTITION BY` expression shared by all the rows,
+    /// None if the table is not partitioned or the rows belong to different partitions
+    #[serde(
+        default,
+        serialize_with = "crate::meta::v2::statistics::serialize_index_scalar_option",
+        deserialize_with = "crate::meta::v2::statistics::deserialize_index_scalar_option"
+    )]
+    pub partition: Option<Scalar>,
 }
 
 impl BlockMeta {
@@ -113,6 +128,8 @@ impl BlockMeta {
             inverted_index_size,
             compression,
             create_on,
+            spatial_stats: SpatialStatisticsOfColumns::new(),
+            partition: None,
         }
     }
 
@@ -254,6 +271,8 @@ impl BlockMeta {
             compression: Compression::Lz4,
             inverted_index_size: None,
             create_on: None,
+            spatial_stats: SpatialStatisticsOfColumns::new(),
+            partition: None,
         }
     }
 
@@ -278,6 +297,8 @@ impl BlockMeta {
             compression: s.compression,
             inverted_index_size: None,
             create_on: None,
+            spatial_stats: SpatialStatisticsOfColumns::new(),
+            partition: None,
         }
     }
 }
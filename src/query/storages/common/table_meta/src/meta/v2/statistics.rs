@@ -19,6 +19,7 @@ use std::marker::PhantomData;
 use bigbytesdb_common_expression::converts::datavalues::from_scalar;
 use bigbytesdb_common_expression::converts::meta::IndexScalar;
 use bigbytesdb_common_expression::types::DataType;
+use bigbytesdb_common_expression::types::F64;
 use bigbytesdb_common_expression::ColumnId;
 use bigbytesdb_common_expression::Scalar;
 use bigbytesdb_common_expression::TableDataType;
@@ -69,6 +70,16 @@ pub struct ClusterStatistics {
     pub pages: Option<Vec<Scalar>>,
 }
 
+/// Bounding box of the non-empty values of a Geometry column,
+/// used to prune blocks by spatial predicates.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpatialStatistics {
+    pub min_x: F64,
+    pub min_y: F64,
+    pub max_x: F64,
+    pub max_y: F64,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct Statistics {
     pub row_count: u64,
@@ -82,6 +93,14 @@ pub struct Statistics {
     #[serde(deserialize_with = "crate::meta::v2::statistics::deserialize_col_stats")]
     pub col_stats: HashMap<ColumnId, ColumnStatistics>,
     pub cluster_stats: Option<ClusterStatistics>,
+
+    /// partition shared by all the blocks, see [crate::meta::BlockMeta::partition]
+    #[serde(
+        default,
+        serialize_with = "serialize_index_scalar_option",
+        deserialize_with = "deserialize_index_scalar_option"
+    )]
+    pub partition: Option<Scalar>,
 }
 
 // conversions from old meta data
@@ -144,6 +163,49 @@ impl ColumnStatistics {
     }
 }
 
+impl SpatialStatistics {
+    pub fn new(min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Self {
+        Self {
+            min_x: min_x.into(),
+            min_y: min_y.into(),
+            max_x: max_x.into(),
+            max_y: max_y.into(),
+        }
+    }
+
+    /// Extends the box so that it also covers `other`.
+    pub fn merge(&mut self, other: &SpatialStatistics) {
+        self.min_x = self.min_x.min(other.min_x);
+        self.min_y = self.min_y.min(other.min_y);
+        self.max_x = self.max_x.max(other.max_x);
+        self.max_y = self.max_y.max(other.max_y);
+    }
+
+    /// Grows the box by `distance` in every direction.
+    pub fn expand(&self, distance: f64) -> SpatialStatistics {
+        SpatialStatistics::new(
+            *self.min_x - distance,
+            *self.min_y - distance,
+            *self.max_x + distance,
+            *self.max_y + distance,
+        )
+    }
+
+    pub fn intersects(&self, other: &SpatialStatistics) -> bool {
+        self.min_x <= other.max_x
+            && other.min_x <= self.max_x
+            && self.min_y <= other.max_y
+            && other.min_y <= self.max_y
+    }
+
+    pub fn contains(&self, other: &SpatialStatistics) -> bool {
+        self.min_x <= other.min_x
+            && other.max_x <= self.max_x
+            && self.min_y <= other.min_y
+            && other.max_y <= self.max_y
+    }
+}
+
 impl ClusterStatistics {
     pub fn new(
         cluster_key_id: u32,
@@ -238,6 +300,7 @@ impl Statistics {
             index_size: v0.index_size,
             col_stats,
             cluster_stats: None,
+            partition: None,
         }
     }
 }
@@ -314,6 +377,32 @@ where D: serde::Deserializer<'de> {
         .collect::<Result<Vec<_>, _>>()
 }
 
+pub(crate) fn serialize_index_scalar_option<S>(
+    scalar: &Option<Scalar>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match scalar {
+        Some(scalar) => serialize_index_scalar(scalar, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+pub(crate) fn deserialize_index_scalar_option<'de, D>(
+    deserializer: D,
+) -> Result<Option<Scalar>, D::Error>
+where D: serde::Deserializer<'de> {
+    <Option<IndexScalar> as serde::Deserialize>::deserialize(deserializer)?
+        .map(|index_scalar| {
+            Scalar::try_from(index_scalar).map_err(|e| {
+                D::Error::custom(format!("Failed to convert IndexScalar to Scalar: {:?}", e))
+            })
+        })
+        .transpose()
+}
+
 fn serialize_index_scalar_option_vec<S>(
     scalars: &Option<Vec<Scalar>>,
     serializer: S,
@@ -64,6 +64,8 @@ impl From<BlockMeta> for crate::meta::BlockMeta {
             inverted_index_size: None,
             compression: value.compression.into(),
             create_on: None,
+            spatial_stats: Default::default(),
+            partition: None,
         }
     }
 }
@@ -0,0 +1,41 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Clone table options.
+//
+// A clone shares the segments and blocks of the table it was cloned from, both
+// sides record the lineage so that gc never purges files that are still referenced.
+
+/// Ids of the tables whose files may be referenced by the snapshots of this table.
+pub const OPT_KEY_CLONE_SOURCES: &str = "clone_sources";
+/// Ids of the tables whose snapshots may reference the files of this table.
+pub const OPT_KEY_CLONE_DESCENDANTS: &str = "clone_descendants";
+
+/// Parse the comma separated table ids kept by the clone lineage options.
+pub fn parse_clone_table_ids(value: Option<&String>) -> Vec<u64> {
+    value
+        .map(|v| {
+            v.split(',')
+                .filter_map(|id| id.trim().parse::<u64>().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub fn format_clone_table_ids(ids: &[u64]) -> String {
+    ids.iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -0,0 +1,84 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+pub const OPT_KEY_MATERIALIZED_VIEW_QUERY: &str = "materialized_view_query";
+pub const OPT_KEY_MAX_STALENESS: &str = "max_staleness";
+pub const OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE: &str = "materialized_view_refresh_mode";
+pub const OPT_KEY_MATERIALIZED_VIEW_REFRESH_STATE: &str = "materialized_view_refresh_state";
+
+pub const REFRESH_MODE_AUTO: &str = "auto";
+pub const REFRESH_MODE_FULL: &str = "full";
+pub const REFRESH_MODE_INCREMENTAL: &str = "incremental";
+
+/// The state recorded by the last refresh of a materialized view.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MaterializedViewRefreshState {
+    /// The snapshot of the materialized view written by the refresh, the view is
+    /// modified out of refreshes if it is not the current snapshot.
+    pub snapshot_location: Option<String>,
+    pub refreshed_on: DateTime<Utc>,
+    /// `full` or `incremental`.
+    pub refresh_mode: String,
+    /// The snapshots of the base tables the view is refreshed to.
+    pub sources: Vec<MaterializedViewSource>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MaterializedViewSource {
+    pub table_id: u64,
+    pub snapshot_id: Option<String>,
+    pub snapshot_location: Option<String>,
+}
+
+impl MaterializedViewRefreshState {
+    pub fn source(&self, table_id: u64) -> Option<&MaterializedViewSource> {
+        self.sources
+            .iter()
+            .find(|source| source.table_id == table_id)
+    }
+
+    /// Check if the data of the materialized view can be served.
+    ///
+    /// The view must not be modified since the refresh, and either the last refresh is
+    /// within `max_staleness` seconds, or none of the base tables changed since then.
+    /// `current_locations` are the current snapshot locations of the base tables by id,
+    /// a missing base table is never fresh.
+    pub fn is_fresh(
+        &self,
+        view_snapshot_location: Option<&String>,
+        max_staleness: Option<u64>,
+        now: DateTime<Utc>,
+        current_locations: &HashMap<u64, Option<String>>,
+    ) -> bool {
+        if self.snapshot_location.as_ref() != view_snapshot_location {
+            return false;
+        }
+        if let Some(max_staleness) = max_staleness {
+            let elapsed = now.signed_duration_since(self.refreshed_on).num_seconds();
+            if elapsed <= max_staleness as i64 {
+                return true;
+            }
+        }
+        self.sources.iter().all(|source| {
+            current_locations
+                .get(&source.table_id)
+                .is_some_and(|location| location == &source.snapshot_location)
+        })
+    }
+}
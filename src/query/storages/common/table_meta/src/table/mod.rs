@@ -12,14 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod clone_keys;
 mod dynamic_table_keys;
+mod materialized_view_keys;
 mod stream_keys;
 mod table_compression;
 mod table_keys;
 mod table_prefix;
+mod tag_keys;
 
+pub use clone_keys::*;
 pub use dynamic_table_keys::*;
+pub use materialized_view_keys::*;
 pub use stream_keys::*;
 pub use table_compression::TableCompression;
 pub use table_keys::*;
 pub use table_prefix::*;
+pub use tag_keys::*;
@@ -16,6 +16,15 @@ use std::collections::HashSet;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::sync::LazyLock;
+
+use crate::table::OPT_KEY_CLONE_DESCENDANTS;
+use crate::table::OPT_KEY_CLONE_SOURCES;
+use crate::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use crate::table::OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE;
+use crate::table::OPT_KEY_MATERIALIZED_VIEW_REFRESH_STATE;
+use crate::table::OPT_KEY_MAX_STALENESS;
+use crate::table::OPT_KEY_SNAPSHOT_TAGS;
+
 pub const OPT_KEY_DATABASE_ID: &str = "database_id";
 pub const OPT_KEY_STORAGE_PREFIX: &str = "storage_prefix";
 pub const OPT_KEY_TEMP_PREFIX: &str = "temp_prefix";
@@ -28,6 +37,14 @@ pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
 pub const OPT_KEY_CHANGE_TRACKING_BEGIN_VER: &str = "begin_version";
+/// Expression of the time after which a row expires, rows whose ttl is NULL never expire.
+pub const OPT_KEY_TTL: &str = "ttl";
+/// Expression whose value decides the partition of a row, a block never spans partitions.
+pub const OPT_KEY_PARTITION_BY: &str = "partition_by";
+/// String columns that have a n-gram filter, which prunes blocks for LIKE and substring searches.
+pub const OPT_KEY_NGRAM_INDEX_COLUMNS: &str = "ngram_index_columns";
+/// Number of chars of each n-gram in the n-gram filters.
+pub const OPT_KEY_NGRAM_SIZE: &str = "ngram_size";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
@@ -65,6 +82,13 @@ pub static RESERVED_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MAX_STALENESS);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESH_STATE);
+    r.insert(OPT_KEY_CLONE_SOURCES);
+    r.insert(OPT_KEY_CLONE_DESCENDANTS);
+    r.insert(OPT_KEY_SNAPSHOT_TAGS);
     r
 });
 
@@ -76,6 +100,13 @@ pub static INTERNAL_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     r.insert(OPT_KEY_ENGINE_META);
     r.insert(OPT_KEY_CHANGE_TRACKING_BEGIN_VER);
     r.insert(OPT_KEY_TEMP_PREFIX);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MAX_STALENESS);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESH_STATE);
+    r.insert(OPT_KEY_CLONE_SOURCES);
+    r.insert(OPT_KEY_CLONE_DESCENDANTS);
+    r.insert(OPT_KEY_SNAPSHOT_TAGS);
     r
 });
 
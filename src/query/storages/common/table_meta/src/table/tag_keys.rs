@@ -0,0 +1,54 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Snapshot tag table options.
+//
+// A tag pins a snapshot of the table by name, gc keeps the tagged snapshot and
+// everything it references until the tag is dropped or expires.
+
+use std::collections::BTreeMap;
+
+use chrono::DateTime;
+use chrono::Utc;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+
+/// Tags of the table, stored as a json object keyed by tag name.
+pub const OPT_KEY_SNAPSHOT_TAGS: &str = "snapshot_tags";
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SnapshotTag {
+    pub snapshot_location: String,
+    pub created_on: DateTime<Utc>,
+    pub expire_at: Option<DateTime<Utc>>,
+}
+
+impl SnapshotTag {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expire_at.is_some_and(|expire_at| expire_at <= now)
+    }
+}
+
+pub fn parse_snapshot_tags(value: Option<&String>) -> Result<BTreeMap<String, SnapshotTag>> {
+    match value {
+        None => Ok(BTreeMap::new()),
+        Some(v) => serde_json::from_str(v).map_err(|e| {
+            ErrorCode::Internal(format!("invalid {OPT_KEY_SNAPSHOT_TAGS} '{v}': {e}"))
+        }),
+    }
+}
+
+pub fn format_snapshot_tags(tags: &BTreeMap<String, SnapshotTag>) -> Result<String> {
+    Ok(serde_json::to_string(tags)?)
+}
@@ -35,5 +35,7 @@ pub const DEFAULT_BLOCK_PER_SEGMENT: usize = 1000;
 pub const DEFAULT_ROW_PER_PAGE: usize = 131072;
 pub const DEFAULT_ROW_PER_PAGE_FOR_BLOCKING: usize = 2048;
 pub const DEFAULT_ROW_PER_INDEX: usize = 100000;
+pub const DEFAULT_NGRAM_SIZE: usize = 3;
+pub const MAX_NGRAM_SIZE: usize = 8;
 
 pub const DEFAULT_AVG_DEPTH_THRESHOLD: f64 = 0.001;
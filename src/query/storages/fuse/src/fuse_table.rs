@@ -49,13 +49,16 @@ use bigbytesdb_common_exception::Result;
 use bigbytesdb_common_expression::types::DataType;
 use bigbytesdb_common_expression::BlockThresholds;
 use bigbytesdb_common_expression::ColumnId;
+use bigbytesdb_common_expression::Expr;
 use bigbytesdb_common_expression::RemoteExpr;
 use bigbytesdb_common_expression::TableSchema;
+use bigbytesdb_common_expression::TableSchemaRef;
 use bigbytesdb_common_expression::ORIGIN_BLOCK_ID_COL_NAME;
 use bigbytesdb_common_expression::ORIGIN_BLOCK_ROW_NUM_COL_NAME;
 use bigbytesdb_common_expression::ORIGIN_VERSION_COL_NAME;
 use bigbytesdb_common_expression::ROW_VERSION_COL_NAME;
 use bigbytesdb_common_expression::SEARCH_SCORE_COLUMN_ID;
+use bigbytesdb_common_functions::BUILTIN_FUNCTIONS;
 use bigbytesdb_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use bigbytesdb_common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use bigbytesdb_common_meta_app::schema::DatabaseType;
@@ -65,12 +68,15 @@ use bigbytesdb_common_meta_app::schema::UpsertTableCopiedFileReq;
 use bigbytesdb_common_pipeline_core::Pipeline;
 use bigbytesdb_common_sql::binder::STREAM_COLUMN_FACTORY;
 use bigbytesdb_common_sql::parse_cluster_keys;
+use bigbytesdb_common_sql::parse_exprs;
 use bigbytesdb_common_sql::BloomIndexColumns;
 use bigbytesdb_common_storage::init_operator;
 use bigbytesdb_common_storage::DataOperator;
 use bigbytesdb_common_storage::StorageMetrics;
 use bigbytesdb_common_storage::StorageMetricsLayer;
 use bigbytesdb_storages_common_cache::LoadParams;
+use bigbytesdb_storages_common_index::BloomIndex;
+use bigbytesdb_storages_common_index::NgramArgs;
 use bigbytesdb_storages_common_io::Files;
 use bigbytesdb_storages_common_table_meta::meta::parse_storage_prefix;
 use bigbytesdb_storages_common_table_meta::meta::ClusterKey;
@@ -86,6 +92,9 @@ use bigbytesdb_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_NGRAM_SIZE;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION_FIXED_FLAG;
 use bigbytesdb_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
@@ -107,12 +116,15 @@ use crate::io::WriteSettings;
 use crate::operations::ChangesDesc;
 use crate::operations::TruncateMode;
 use crate::statistics::reduce_block_statistics;
+use crate::statistics::resolve_columns;
+use crate::statistics::PartitionGenerator;
 use crate::statistics::Trim;
 use crate::FuseStorageFormat;
 use crate::NavigationPoint;
 use crate::Table;
 use crate::TableStatistics;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
+use crate::DEFAULT_NGRAM_SIZE;
 use crate::DEFAULT_ROW_PER_PAGE;
 use crate::DEFAULT_ROW_PER_PAGE_FOR_BLOCKING;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
@@ -426,10 +438,73 @@ impl FuseTable {
         cluster_keys
     }
 
+    /// The `PARTITION BY` expression of the table, columns are referenced by name.
+    pub fn partition_by(&self, ctx: Arc<dyn TableContext>) -> Result<Option<RemoteExpr<String>>> {
+        let Some(partition_by) = self.table_info.options().get(OPT_KEY_PARTITION_BY) else {
+            return Ok(None);
+        };
+        let table_meta = Arc::new(self.clone());
+        let exprs = parse_exprs(ctx, table_meta.clone(), partition_by)?;
+        Ok(exprs.first().map(|expr| {
+            expr.project_column_ref(|index| table_meta.schema().field(*index).name().to_string())
+                .as_remote_expr()
+        }))
+    }
+
+    pub fn partition_gen(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        source_schema: &TableSchema,
+    ) -> Result<PartitionGenerator> {
+        let Some(partition_by) = self.partition_by(ctx.clone())? else {
+            return Ok(PartitionGenerator::default());
+        };
+        let expr = resolve_columns(partition_by.as_expr(&BUILTIN_FUNCTIONS), source_schema)?;
+        Ok(PartitionGenerator::new(expr, ctx.get_function_context()?))
+    }
+
+    /// The predicate that the rows written by `INSERT OVERWRITE ... PARTITION` must match,
+    /// None if the query is not an `INSERT OVERWRITE ... PARTITION` of a partitioned table.
+    pub fn partition_check(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        source_schema: &TableSchema,
+    ) -> Result<Option<Expr>> {
+        let Some(predicate) = ctx.get_settings().get_insert_overwrite_partition()? else {
+            return Ok(None);
+        };
+        if !self.table_info.options().contains_key(OPT_KEY_PARTITION_BY) {
+            return Ok(None);
+        }
+        let table_meta = Arc::new(self.clone());
+        let exprs = parse_exprs(ctx, table_meta.clone(), &predicate)?;
+        let Some(expr) = exprs.first() else {
+            return Ok(None);
+        };
+        let expr =
+            expr.project_column_ref(|index| table_meta.schema().field(*index).name().to_string());
+        Ok(Some(resolve_columns(expr, source_schema)?))
+    }
+
     pub fn bloom_index_cols(&self) -> BloomIndexColumns {
         self.bloom_index_cols.clone()
     }
 
+    /// The n-gram filters to build for blocks of `schema`, empty if no n-gram index is configured.
+    pub fn ngram_args(&self, schema: TableSchemaRef) -> Result<Vec<NgramArgs>> {
+        let Some(columns) = self.table_info.options().get(OPT_KEY_NGRAM_INDEX_COLUMNS) else {
+            return Ok(vec![]);
+        };
+        let gram_size = self.get_option(OPT_KEY_NGRAM_SIZE, DEFAULT_NGRAM_SIZE);
+        let fields = columns
+            .parse::<BloomIndexColumns>()?
+            .bloom_index_fields(schema, BloomIndex::supported_ngram_type)?;
+        Ok(fields
+            .into_iter()
+            .map(|(index, field)| NgramArgs::new(index, field, gram_size))
+            .collect())
+    }
+
     // Check if table is attached.
     pub fn is_table_attached(table_meta_options: &BTreeMap<String, String>) -> bool {
         table_meta_options
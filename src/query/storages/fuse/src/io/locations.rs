@@ -16,6 +16,7 @@ use std::marker::PhantomData;
 
 use bigbytesdb_common_exception::Result;
 use bigbytesdb_common_expression::DataBlock;
+use bigbytesdb_common_vector::HnswIndex;
 use bigbytesdb_storages_common_table_meta::meta::trim_vacuum2_object_prefix;
 use bigbytesdb_storages_common_table_meta::meta::Location;
 use bigbytesdb_storages_common_table_meta::meta::SegmentInfo;
@@ -234,6 +235,28 @@ impl TableMetaLocationGenerator {
             InvertedIndexFile::VERSION,
         )
     }
+
+    pub fn gen_vector_index_location_from_block_location(
+        loc: &str,
+        index_name: &str,
+        index_version: &str,
+    ) -> String {
+        let splits = loc.split('/').collect::<Vec<_>>();
+        let len = splits.len();
+        let prefix = splits[..len - 2].join("/");
+        let block_name = trim_vacuum2_object_prefix(splits[len - 1]);
+        let id: String = block_name.chars().take(32).collect();
+        let short_ver: String = index_version.chars().take(7).collect();
+        format!(
+            "{}/{}/{}/{}/{}_v{}.vindex",
+            prefix,
+            FUSE_TBL_INVERTED_INDEX_PREFIX,
+            index_name,
+            short_ver,
+            id,
+            HnswIndex::VERSION,
+        )
+    }
 }
 
 trait SnapshotLocationCreator {
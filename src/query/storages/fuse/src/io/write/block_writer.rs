@@ -31,6 +31,7 @@ use bigbytesdb_common_expression::TableField;
 use bigbytesdb_common_expression::TableSchemaRef;
 use bigbytesdb_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use bigbytesdb_common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
+use bigbytesdb_common_meta_app::schema::TableIndexType;
 use bigbytesdb_common_meta_app::schema::TableMeta;
 use bigbytesdb_common_metrics::storage::metrics_inc_block_index_write_milliseconds;
 use bigbytesdb_common_metrics::storage::metrics_inc_block_index_write_nums;
@@ -43,6 +44,7 @@ use bigbytesdb_common_metrics::storage::metrics_inc_block_write_nums;
 use bigbytesdb_common_native::write::NativeWriter;
 use bigbytesdb_storages_common_blocks::blocks_to_parquet;
 use bigbytesdb_storages_common_index::BloomIndex;
+use bigbytesdb_storages_common_index::NgramArgs;
 use bigbytesdb_storages_common_io::ReadSettings;
 use bigbytesdb_storages_common_table_meta::meta::BlockMeta;
 use bigbytesdb_storages_common_table_meta::meta::ClusterStatistics;
@@ -56,9 +58,13 @@ use crate::io::write::WriteSettings;
 use crate::io::BlockReader;
 use crate::io::InvertedIndexWriter;
 use crate::io::TableMetaLocationGenerator;
+use crate::io::VectorIndexBuilder;
+use crate::io::VectorIndexState;
 use crate::operations::column_parquet_metas;
 use crate::statistics::gen_columns_statistics;
+use crate::statistics::gen_spatial_statistics;
 use crate::statistics::ClusterStatsGenerator;
+use crate::statistics::PartitionGenerator;
 use crate::FuseStorageFormat;
 
 pub fn serialize_block(
@@ -138,6 +144,7 @@ pub struct BloomIndexBuilder {
     pub table_dal: Operator,
     pub storage_format: FuseStorageFormat,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub ngram_args: Vec<NgramArgs>,
 }
 
 impl BloomIndexBuilder {
@@ -151,6 +158,7 @@ impl BloomIndexBuilder {
             bloom_location.1,
             block,
             self.bloom_columns_map.clone(),
+            &self.ngram_args,
         )?;
 
         match maybe_bloom_index {
@@ -166,11 +174,21 @@ impl BloomIndexBuilder {
         &self,
         block_meta: &BlockMeta,
     ) -> Result<Option<(BloomIndexState, BloomIndex)>> {
-        let ctx = self.table_ctx.clone();
-
         // the caller should not pass a block meta without a bloom index location here.
         assert!(block_meta.bloom_filter_index_location.is_some());
 
+        let data_block = self.read_block(block_meta).await?;
+
+        self.bloom_index_state_from_data_block(
+            &data_block,
+            block_meta.bloom_filter_index_location.clone().unwrap(),
+        )
+    }
+
+    /// Read the data of the block, for re-building its bloom index.
+    pub async fn read_block(&self, block_meta: &BlockMeta) -> Result<DataBlock> {
+        let ctx = self.table_ctx.clone();
+
         let projection =
             Projection::Columns((0..self.table_schema.fields().len()).collect::<Vec<usize>>());
 
@@ -186,14 +204,9 @@ impl BloomIndexBuilder {
 
         let settings = ReadSettings::from_ctx(&self.table_ctx)?;
 
-        let data_block = block_reader
+        block_reader
             .read_by_meta(&settings, block_meta, &self.storage_format)
-            .await?;
-
-        self.bloom_index_state_from_data_block(
-            &data_block,
-            block_meta.bloom_filter_index_location.clone().unwrap(),
-        )
+            .await
     }
 }
 
@@ -223,6 +236,7 @@ impl BloomIndexState {
         block: &DataBlock,
         location: Location,
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_args: &[NgramArgs],
     ) -> Result<Option<Self>> {
         // write index
         let maybe_bloom_index = BloomIndex::try_create(
@@ -230,6 +244,7 @@ impl BloomIndexState {
             location.1,
             block,
             bloom_columns_map,
+            ngram_args,
         )?;
         if let Some(bloom_index) = maybe_bloom_index {
             Ok(Some(Self::from_bloom_index(&bloom_index, location)?))
@@ -250,7 +265,7 @@ pub struct InvertedIndexBuilder {
 pub fn create_inverted_index_builders(table_meta: &TableMeta) -> Vec<InvertedIndexBuilder> {
     let mut inverted_index_builders = Vec::with_capacity(table_meta.indexes.len());
     for index in table_meta.indexes.values() {
-        if !index.sync_creation {
+        if !index.sync_creation || index.index_type != TableIndexType::Inverted {
             continue;
         }
         let mut index_fields = Vec::with_capacity(index.column_ids.len());
@@ -332,6 +347,7 @@ pub struct BlockSerialization {
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
     pub inverted_index_states: Vec<InvertedIndexState>,
+    pub vector_index_states: Vec<VectorIndexState>,
 }
 
 #[derive(Clone)]
@@ -341,8 +357,11 @@ pub struct BlockBuilder {
     pub source_schema: TableSchemaRef,
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
+    pub partition_gen: PartitionGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub ngram_args: Vec<NgramArgs>,
     pub inverted_index_builders: Vec<InvertedIndexBuilder>,
+    pub vector_index_builders: Vec<VectorIndexBuilder>,
 }
 
 impl BlockBuilder {
@@ -358,6 +377,7 @@ impl BlockBuilder {
             &data_block,
             bloom_index_location,
             self.bloom_columns_map.clone(),
+            &self.ngram_args,
         )?;
         let column_distinct_count = bloom_index_state
             .as_ref()
@@ -374,10 +394,23 @@ impl BlockBuilder {
             inverted_index_states.push(inverted_index_state);
         }
 
+        let mut vector_index_states = Vec::with_capacity(self.vector_index_builders.len());
+        for vector_index_builder in &self.vector_index_builders {
+            let vector_index_state = VectorIndexState::try_create(
+                &self.source_schema,
+                &data_block,
+                &block_location,
+                vector_index_builder,
+            )?;
+            vector_index_states.push(vector_index_state);
+        }
+
         let row_count = data_block.num_rows() as u64;
         let block_size = data_block.memory_size() as u64;
         let col_stats =
             gen_columns_statistics(&data_block, column_distinct_count, &self.source_schema)?;
+        let spatial_stats = gen_spatial_statistics(&data_block, &self.source_schema)?;
+        let partition = self.partition_gen.gen_partition(&data_block)?;
 
         let mut buffer = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
         let col_metas = serialize_block(
@@ -409,6 +442,8 @@ impl BlockBuilder {
             compression: self.write_settings.table_compression.into(),
             inverted_index_size,
             create_on: Some(Utc::now()),
+            spatial_stats,
+            partition,
         };
 
         let serialized = BlockSerialization {
@@ -417,6 +452,7 @@ impl BlockBuilder {
             block_meta,
             bloom_index_state,
             inverted_index_states,
+            vector_index_states,
         };
         Ok(serialized)
     }
@@ -431,6 +467,7 @@ impl BlockWriter {
         Self::write_down_data_block(dal, serialized.block_raw_data, &block_meta.location.0).await?;
         Self::write_down_bloom_index_state(dal, serialized.bloom_index_state).await?;
         Self::write_down_inverted_index_state(dal, serialized.inverted_index_states).await?;
+        Self::write_down_vector_index_state(dal, serialized.vector_index_states).await?;
 
         Ok(block_meta)
     }
@@ -485,4 +522,14 @@ impl BlockWriter {
         }
         Ok(())
     }
+
+    pub async fn write_down_vector_index_state(
+        dal: &Operator,
+        vector_index_states: Vec<VectorIndexState>,
+    ) -> Result<()> {
+        for vector_index_state in vector_index_states {
+            write_data(vector_index_state.data, dal, &vector_index_state.location.0).await?;
+        }
+        Ok(())
+    }
 }
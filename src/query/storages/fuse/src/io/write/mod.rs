@@ -15,6 +15,7 @@
 mod block_writer;
 mod inverted_index_writer;
 mod meta_writer;
+mod vector_index_writer;
 mod write_settings;
 
 pub(crate) use block_writer::create_inverted_index_builders;
@@ -32,4 +33,7 @@ pub(crate) use inverted_index_writer::create_tokenizer_manager;
 pub use inverted_index_writer::InvertedIndexWriter;
 pub use meta_writer::CachedMetaWriter;
 pub use meta_writer::MetaWriter;
+pub(crate) use vector_index_writer::create_vector_index_builders;
+pub use vector_index_writer::VectorIndexBuilder;
+pub use vector_index_writer::VectorIndexState;
 pub use write_settings::WriteSettings;
@@ -0,0 +1,159 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::types::ArrayType;
+use bigbytesdb_common_expression::types::Float32Type;
+use bigbytesdb_common_expression::types::ValueType;
+use bigbytesdb_common_expression::Column;
+use bigbytesdb_common_expression::DataBlock;
+use bigbytesdb_common_expression::TableField;
+use bigbytesdb_common_expression::TableSchemaRef;
+use bigbytesdb_common_meta_app::schema::TableIndex;
+use bigbytesdb_common_meta_app::schema::TableIndexType;
+use bigbytesdb_common_meta_app::schema::TableMeta;
+use bigbytesdb_common_vector::DistanceType;
+use bigbytesdb_common_vector::HnswIndex;
+use bigbytesdb_storages_common_table_meta::meta::Location;
+
+use crate::io::TableMetaLocationGenerator;
+
+const DEFAULT_M: usize = 16;
+const DEFAULT_EF_CONSTRUCTION: usize = 100;
+
+/// Builds the HNSW index of one vector index column for each block.
+#[derive(Clone)]
+pub struct VectorIndexBuilder {
+    pub(crate) name: String,
+    pub(crate) version: String,
+    pub(crate) field: TableField,
+    pub(crate) distance_type: DistanceType,
+    pub(crate) m: usize,
+    pub(crate) ef_construction: usize,
+}
+
+impl VectorIndexBuilder {
+    /// Returns None if the index is not a vector index or its column no longer exists.
+    pub fn try_create(table_meta: &TableMeta, index: &TableIndex) -> Option<Self> {
+        if index.index_type != TableIndexType::Vector || index.column_ids.len() != 1 {
+            return None;
+        }
+        let field = table_meta
+            .schema
+            .fields
+            .iter()
+            .find(|f| f.column_id() == index.column_ids[0])?
+            .clone();
+        let distance_type = index
+            .options
+            .get("distance")
+            .and_then(|v| DistanceType::from_option(v))
+            .unwrap_or(DistanceType::Cosine);
+        let option_usize = |key: &str, default: usize| {
+            index
+                .options
+                .get(key)
+                .and_then(|v| v.parse::<usize>().ok())
+                .unwrap_or(default)
+        };
+
+        Some(Self {
+            name: index.name.clone(),
+            version: index.version.clone(),
+            field,
+            distance_type,
+            m: option_usize("m", DEFAULT_M),
+            ef_construction: option_usize("ef_construction", DEFAULT_EF_CONSTRUCTION),
+        })
+    }
+
+    pub fn index_location(&self, block_location: &str) -> String {
+        TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+            block_location,
+            &self.name,
+            &self.version,
+        )
+    }
+
+    /// Builds the index of the column, the row numbers of the block are used as the ids
+    /// of the vectors. NULL values and vectors whose dimension differs from the first
+    /// vector are skipped, so the index may have fewer vectors than the block rows.
+    pub fn build(&self, column: &Column) -> Result<HnswIndex> {
+        let (column, validity) = match column {
+            Column::Nullable(c) => (&c.column, Some(&c.validity)),
+            c => (c, None),
+        };
+        let Some(array_column) = ArrayType::<Float32Type>::try_downcast_column(column) else {
+            return Err(ErrorCode::StorageOther(format!(
+                "vector index column {} must be Array(Float32), but got {}",
+                self.field.name(),
+                column.data_type()
+            )));
+        };
+
+        let mut index: Option<HnswIndex> = None;
+        for (row, value) in array_column.iter().enumerate() {
+            if validity.is_some_and(|v| !v.get_bit(row)) {
+                continue;
+            }
+            let vector = value.iter().map(|v| v.0).collect::<Vec<_>>();
+            let index = index.get_or_insert_with(|| {
+                HnswIndex::new(
+                    self.distance_type,
+                    vector.len(),
+                    self.m,
+                    self.ef_construction,
+                )
+            });
+            if vector.len() == index.dimension() {
+                index.insert(row as u32, &vector)?;
+            }
+        }
+        Ok(index
+            .unwrap_or_else(|| HnswIndex::new(self.distance_type, 0, self.m, self.ef_construction)))
+    }
+}
+
+pub(crate) fn create_vector_index_builders(table_meta: &TableMeta) -> Vec<VectorIndexBuilder> {
+    table_meta
+        .indexes
+        .values()
+        .filter(|index| index.sync_creation)
+        .filter_map(|index| VectorIndexBuilder::try_create(table_meta, index))
+        .collect()
+}
+
+pub struct VectorIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) location: Location,
+}
+
+impl VectorIndexState {
+    pub fn try_create(
+        source_schema: &TableSchemaRef,
+        block: &DataBlock,
+        block_location: &Location,
+        vector_index_builder: &VectorIndexBuilder,
+    ) -> Result<Self> {
+        let field_index = source_schema.index_of(vector_index_builder.field.name())?;
+        let column = block.get_by_offset(field_index).to_column(block.num_rows());
+        let index = vector_index_builder.build(&column)?;
+
+        Ok(Self {
+            data: index.to_bytes(),
+            location: (vector_index_builder.index_location(&block_location.0), 0),
+        })
+    }
+}
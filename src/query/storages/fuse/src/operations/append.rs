@@ -36,6 +36,7 @@ use bigbytesdb_common_sql::executor::physical_plans::MutationKind;
 use bigbytesdb_storages_common_table_meta::table::ClusterType;
 
 use crate::operations::common::TransformSerializeBlock;
+use crate::operations::common::TransformSplitPartition;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
 
@@ -48,6 +49,16 @@ impl FuseTable {
         let block_thresholds = self.get_block_thresholds();
         build_compact_block_pipeline(pipeline, block_thresholds)?;
 
+        let source_schema = self.schema().remove_virtual_computed_fields();
+        let partition_gen = self
+            .partition_gen(ctx.clone(), &source_schema)?
+            .with_check(self.partition_check(ctx.clone(), &source_schema)?);
+        if partition_gen.is_partitioned() {
+            pipeline.add_accumulating_transformer(|| {
+                TransformSplitPartition::create(partition_gen.clone())
+            });
+        }
+
         let schema = DataSchema::from(self.schema()).into();
         let cluster_stats_gen =
             self.cluster_gen_for_append(ctx.clone(), pipeline, block_thresholds, Some(schema))?;
@@ -306,6 +306,7 @@ impl FuseTable {
             cluster_key_meta,
             cluster_keys,
             bloom_index_cols,
+            self.ngram_args(table_schema.clone())?,
             None,
             self.get_storage_format(),
         )?;
@@ -0,0 +1,248 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use bigbytesdb_common_catalog::table::Table;
+use bigbytesdb_common_catalog::table_context::TableContext;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_meta_app::schema::TableIdent;
+use bigbytesdb_common_meta_app::schema::TableInfo;
+use bigbytesdb_common_meta_app::schema::UpdateTableMetaReq;
+use bigbytesdb_common_meta_types::MatchSeq;
+use bigbytesdb_storages_common_table_meta::meta::parse_storage_prefix;
+use bigbytesdb_storages_common_table_meta::meta::TableSnapshot;
+use bigbytesdb_storages_common_table_meta::table::format_clone_table_ids;
+use bigbytesdb_storages_common_table_meta::table::parse_clone_table_ids;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_CLONE_DESCENDANTS;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_CLONE_SOURCES;
+use futures::future::try_join_all;
+use log::info;
+use uuid::Uuid;
+
+use crate::io::SnapshotsIO;
+use crate::operations::RetainedLocations;
+use crate::FuseTable;
+use crate::FUSE_TBL_SNAPSHOT_PREFIX;
+
+const MAX_REGISTER_CLONE_RETRIES: usize = 10;
+
+impl FuseTable {
+    /// Make the snapshot of `source` the first snapshot of this newly created table.
+    ///
+    /// The segments and blocks are shared rather than copied, the clone diverges
+    /// from the source on its first write.
+    #[async_backtrace::framed]
+    pub async fn do_clone_from(&self, ctx: &dyn TableContext, source: &FuseTable) -> Result<()> {
+        let Some(source_snapshot) = source.read_table_snapshot().await? else {
+            return Ok(());
+        };
+
+        // the ancestors must know about the clone before it references their files
+        self.register_clone_lineage(ctx).await?;
+
+        let mut snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            Some(self.table_info.ident.seq),
+            &None,
+            None,
+            source_snapshot.schema.clone(),
+            source_snapshot.summary.clone(),
+            source_snapshot.segments.clone(),
+            None,
+        );
+        snapshot.cluster_key_meta = source_snapshot.cluster_key_meta.clone();
+
+        // keep the column ids and the cluster key id the blocks were written with
+        let mut table_info = self.table_info.clone();
+        table_info.meta.schema = Arc::new(source_snapshot.schema.clone());
+        table_info.meta.cluster_key = source.table_info.meta.cluster_key.clone();
+        table_info.meta.cluster_key_seq = source.table_info.meta.cluster_key_seq;
+
+        FuseTable::commit_to_meta_server(
+            ctx,
+            &table_info,
+            &self.meta_location_generator,
+            snapshot,
+            None,
+            &None,
+            &self.operator,
+        )
+        .await
+    }
+
+    /// Append this table to the descendants of every table listed in its `clone_sources`.
+    #[async_backtrace::framed]
+    async fn register_clone_lineage(&self, ctx: &dyn TableContext) -> Result<()> {
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let clone_id = self.get_id();
+        let ancestors = parse_clone_table_ids(self.table_info.options().get(OPT_KEY_CLONE_SOURCES));
+        for ancestor_id in ancestors {
+            let mut retries = 0;
+            loop {
+                let Some(seq_meta) = catalog.get_table_meta_by_id(ancestor_id).await? else {
+                    // the ancestor has been vacuumed, none of its files is left to protect
+                    break;
+                };
+                let mut meta = seq_meta.data;
+                let mut descendants =
+                    parse_clone_table_ids(meta.options.get(OPT_KEY_CLONE_DESCENDANTS));
+                if descendants.contains(&clone_id) {
+                    break;
+                }
+                descendants.push(clone_id);
+                meta.options.insert(
+                    OPT_KEY_CLONE_DESCENDANTS.to_string(),
+                    format_clone_table_ids(&descendants),
+                );
+
+                let req = UpdateTableMetaReq {
+                    table_id: ancestor_id,
+                    seq: MatchSeq::Exact(seq_meta.seq),
+                    new_table_meta: meta.clone(),
+                };
+                let table_info = TableInfo {
+                    ident: TableIdent::new(ancestor_id, seq_meta.seq),
+                    meta,
+                    ..Default::default()
+                };
+                match catalog.update_single_table_meta(req, &table_info).await {
+                    Ok(_) => break,
+                    Err(e)
+                        if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED
+                            && retries < MAX_REGISTER_CLONE_RETRIES =>
+                    {
+                        retries += 1;
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Add the files of this table that are referenced by any snapshot of its live clones.
+    ///
+    /// A clone counts as live until its meta data is vacuumed, so that an undropped clone
+    /// still finds all of its files.
+    #[async_backtrace::framed]
+    pub async fn collect_clone_referenced_locations(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        retained: &mut RetainedLocations,
+    ) -> Result<()> {
+        let descendants =
+            parse_clone_table_ids(self.table_info.options().get(OPT_KEY_CLONE_DESCENDANTS));
+        if descendants.is_empty() {
+            return Ok(());
+        }
+
+        let prefix = format!("{}/", self.meta_location_generator.prefix());
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let mut snapshot_files = vec![];
+        for clone_id in descendants {
+            let Some(seq_meta) = catalog.get_table_meta_by_id(clone_id).await? else {
+                continue;
+            };
+            let storage_prefix = parse_storage_prefix(&seq_meta.data.options, clone_id)?;
+            snapshot_files.extend(
+                SnapshotsIO::list_files(
+                    self.get_operator(),
+                    &format!("{}/{}/", storage_prefix, FUSE_TBL_SNAPSHOT_PREFIX),
+                    None,
+                )
+                .await?,
+            );
+        }
+
+        // The snapshots of the clones mostly share their segments, collect them first so
+        // that every segment is read only once in this gc run.
+        let mut segments = HashSet::new();
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+        for chunk in snapshot_files.chunks(chunk_size) {
+            if let Err(err) = ctx.check_aborting() {
+                return Err(err.with_context("failed to read snapshots of clones"));
+            }
+            let reads = chunk.iter().map(|location| {
+                let operator = self.get_operator();
+                async move {
+                    match SnapshotsIO::read_snapshot(location.clone(), operator).await {
+                        Ok((snapshot, _)) => Ok(snapshot.segments.clone()),
+                        // concurrent gc of the clone, the snapshot no longer references anything
+                        Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => Ok(vec![]),
+                        Err(e) => Err(e),
+                    }
+                }
+            });
+            for snapshot_segments in try_join_all(reads).await? {
+                segments.extend(snapshot_segments);
+            }
+        }
+        let segments = Vec::from_iter(segments);
+        let locations = self
+            .get_block_locations(ctx.clone(), &segments, false, true)
+            .await?;
+
+        let (num_snapshots, num_segments, num_blocks) = (
+            snapshot_files.len(),
+            segments.len(),
+            locations.block_location.len(),
+        );
+        retained.segments.extend(
+            segments
+                .into_iter()
+                .map(|(location, _)| location)
+                .filter(|location| location.starts_with(&prefix)),
+        );
+        retained.blocks.extend(
+            locations
+                .block_location
+                .into_iter()
+                .filter(|location| location.starts_with(&prefix)),
+        );
+        retained.blooms.extend(
+            locations
+                .bloom_location
+                .into_iter()
+                .filter(|location| location.starts_with(&prefix)),
+        );
+
+        info!(
+            "gc: files referenced by clones of table {}, snapshots: {}, segments: {}, blocks: {}",
+            self.table_info.desc, num_snapshots, num_segments, num_blocks,
+        );
+        Ok(())
+    }
+
+    /// Returns the ids of the clones of this table whose meta data has not been vacuumed.
+    #[async_backtrace::framed]
+    pub async fn live_clone_ids(&self, ctx: &dyn TableContext) -> Result<Vec<u64>> {
+        let descendants =
+            parse_clone_table_ids(self.table_info.options().get(OPT_KEY_CLONE_DESCENDANTS));
+        if descendants.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let mut live = vec![];
+        for clone_id in descendants {
+            if catalog.get_table_meta_by_id(clone_id).await?.is_some() {
+                live.push(clone_id);
+            }
+        }
+        Ok(live)
+    }
+}
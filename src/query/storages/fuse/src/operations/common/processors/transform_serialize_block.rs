@@ -36,6 +36,7 @@ use bigbytesdb_storages_common_index::BloomIndex;
 use opendal::Operator;
 
 use crate::io::create_inverted_index_builders;
+use crate::io::create_vector_index_builders;
 use crate::io::BlockBuilder;
 use crate::io::BlockSerialization;
 use crate::io::BlockWriter;
@@ -127,8 +128,11 @@ impl TransformSerializeBlock {
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
+        let ngram_args = table.ngram_args(source_schema.clone())?;
 
         let inverted_index_builders = create_inverted_index_builders(&table.table_info.meta);
+        let vector_index_builders = create_vector_index_builders(&table.table_info.meta);
+        let partition_gen = table.partition_gen(ctx.clone(), &source_schema)?;
 
         let block_builder = BlockBuilder {
             ctx,
@@ -136,8 +140,11 @@ impl TransformSerializeBlock {
             source_schema,
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
+            partition_gen,
             bloom_columns_map,
+            ngram_args,
             inverted_index_builders,
+            vector_index_builders,
         };
         Ok(TransformSerializeBlock {
             state: State::Consume,
@@ -0,0 +1,38 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::DataBlock;
+use bigbytesdb_common_pipeline_transforms::processors::AccumulatingTransform;
+
+use crate::statistics::PartitionGenerator;
+
+/// Splits the blocks of a partitioned table, so that no written block spans partitions.
+pub struct TransformSplitPartition {
+    partition_gen: PartitionGenerator,
+}
+
+impl TransformSplitPartition {
+    pub fn create(partition_gen: PartitionGenerator) -> Self {
+        TransformSplitPartition { partition_gen }
+    }
+}
+
+impl AccumulatingTransform for TransformSplitPartition {
+    const NAME: &'static str = "TransformSplitPartition";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        self.partition_gen.split(data)
+    }
+}
@@ -23,6 +23,7 @@ use bigbytesdb_common_exception::ErrorCode;
 use bigbytesdb_common_exception::Result;
 use bigbytesdb_common_meta_app::schema::ListIndexesByIdReq;
 use bigbytesdb_common_meta_app::schema::TableIndex;
+use bigbytesdb_common_meta_app::schema::TableIndexType;
 use bigbytesdb_storages_common_cache::CacheAccessor;
 use bigbytesdb_storages_common_cache::CachedObject;
 use bigbytesdb_storages_common_cache::LoadParams;
@@ -35,6 +36,8 @@ use bigbytesdb_storages_common_table_meta::meta::Location;
 use bigbytesdb_storages_common_table_meta::meta::SegmentInfo;
 use bigbytesdb_storages_common_table_meta::meta::TableSnapshot;
 use bigbytesdb_storages_common_table_meta::meta::TableSnapshotStatistics;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_CLONE_DESCENDANTS;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_CLONE_SOURCES;
 use log::error;
 use log::info;
 use log::warn;
@@ -117,6 +120,9 @@ impl FuseTable {
 
         let inverted_indexes = &self.table_info.meta.indexes;
 
+        // Files shared with clones or pinned by tags must survive the purge.
+        let retained = self.retained_locations(ctx).await?;
+
         // 2. Read snapshot fields by chunk size.
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
         for chunk in snapshot_files.chunks(chunk_size).rev() {
@@ -162,17 +168,19 @@ impl FuseTable {
                     if purged_snapshot_count >= purged_snapshot_limit {
                         break;
                     }
-                    snapshots_to_be_purged.insert(loc);
-                    purged_snapshot_count += 1;
+                    if retained.is_purgeable(&loc) {
+                        snapshots_to_be_purged.insert(loc);
+                        purged_snapshot_count += 1;
+                    }
                 }
 
                 let diff: HashSet<_> = s.segments.difference(&base_segments).cloned().collect();
                 segments_to_be_purged.extend(diff);
 
-                if s.table_statistics_location.is_some()
-                    && s.table_statistics_location != base_ts_location_opt
-                {
-                    ts_to_be_purged.insert(s.table_statistics_location.unwrap());
+                if let Some(ts) = s.table_statistics_location {
+                    if Some(&ts) != base_ts_location_opt.as_ref() && retained.is_purgeable(&ts) {
+                        ts_to_be_purged.insert(ts);
+                    }
                 }
             }
 
@@ -195,6 +203,7 @@ impl FuseTable {
                         ctx,
                         &mut dry_run_purge_files,
                         &root_snapshot_info.referenced_locations,
+                        &retained,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -210,6 +219,7 @@ impl FuseTable {
                         ctx,
                         counter,
                         &root_snapshot_info.referenced_locations,
+                        &retained,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -236,14 +246,18 @@ impl FuseTable {
                     if purged_snapshot_count >= purged_snapshot_limit {
                         break;
                     }
-                    snapshots_to_be_purged.insert(loc);
-                    purged_snapshot_count += 1;
+                    if retained.is_purgeable(&loc) {
+                        snapshots_to_be_purged.insert(loc);
+                        purged_snapshot_count += 1;
+                    }
                 }
 
                 segments_to_be_purged.extend(s.segments);
 
-                if s.table_statistics_location.is_some() {
-                    ts_to_be_purged.insert(s.table_statistics_location.unwrap());
+                if let Some(ts) = s.table_statistics_location {
+                    if retained.is_purgeable(&ts) {
+                        ts_to_be_purged.insert(ts);
+                    }
                 }
             }
             if dry_run {
@@ -251,6 +265,7 @@ impl FuseTable {
                     ctx,
                     &mut dry_run_purge_files,
                     &root_snapshot_info.referenced_locations,
+                    &retained,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -262,6 +277,7 @@ impl FuseTable {
                     ctx,
                     counter,
                     &root_snapshot_info.referenced_locations,
+                    &retained,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -284,6 +300,7 @@ impl FuseTable {
                 root_snapshot_info.snapshot_lite,
                 root_snapshot_info.referenced_locations,
                 root_snapshot_info.snapshot_location,
+                &retained,
                 &table_agg_index_ids,
                 inverted_indexes,
             )
@@ -349,6 +366,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         purge_files: &mut Vec<String>,
         locations_referenced_by_root: &LocationTuple,
+        retained: &RetainedLocations,
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
@@ -364,7 +382,9 @@ impl FuseTable {
                 .await?;
 
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || !retained.is_purgeable(loc)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string());
@@ -378,13 +398,20 @@ impl FuseTable {
             }
 
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || !retained.is_purgeable(loc)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string())
             }
 
-            purge_files.extend(chunk.iter().map(|loc| loc.0.clone()));
+            purge_files.extend(
+                chunk
+                    .iter()
+                    .filter(|loc| retained.is_purgeable(&loc.0))
+                    .map(|loc| loc.0.clone()),
+            );
         }
         purge_files.extend(ts_to_be_purged.iter().map(|loc| loc.to_string()));
         purge_files.extend(snapshots_to_be_purged.iter().map(|loc| loc.to_string()));
@@ -398,6 +425,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         locations_referenced_by_root: &LocationTuple,
+        retained: &RetainedLocations,
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
@@ -418,7 +446,9 @@ impl FuseTable {
             let mut agg_indexes_to_be_purged = HashSet::new();
             let mut inverted_indexes_to_be_purged = HashSet::new();
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || !retained.is_purgeable(loc)
+                {
                     continue;
                 }
                 blocks_to_be_purged.insert(loc.to_string());
@@ -431,19 +461,15 @@ impl FuseTable {
                 }
 
                 for idx in inverted_indexes.values() {
-                    inverted_indexes_to_be_purged.insert(
-                        TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
-                            loc,
-                            idx.name.as_str(),
-                            idx.version.as_str(),
-                        ),
-                    );
+                    inverted_indexes_to_be_purged.insert(table_index_location(loc, idx));
                 }
             }
 
             let mut blooms_to_be_purged = HashSet::new();
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || !retained.is_purgeable(loc)
+                {
                     continue;
                 }
                 blooms_to_be_purged.insert(loc.to_string());
@@ -452,6 +478,7 @@ impl FuseTable {
             let segment_locations_to_be_purged = HashSet::from_iter(
                 chunk
                     .iter()
+                    .filter(|loc| retained.is_purgeable(&loc.0))
                     .map(|loc| loc.0.clone())
                     .collect::<Vec<String>>(),
             );
@@ -484,13 +511,15 @@ impl FuseTable {
             .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn purge_root_snapshot(
         &self,
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         root_snapshot: Arc<SnapshotLiteExtended>,
-        root_location_tuple: LocationTuple,
+        mut root_location_tuple: LocationTuple,
         root_snapshot_location: String,
+        retained: &RetainedLocations,
         table_agg_index_ids: &[u64],
         inverted_indexes: &BTreeMap<String, TableIndex>,
     ) -> Result<()> {
@@ -498,9 +527,16 @@ impl FuseTable {
             root_snapshot
                 .segments
                 .iter()
+                .filter(|loc| retained.is_purgeable(&loc.0))
                 .map(|loc| loc.0.clone())
                 .collect::<Vec<_>>(),
         );
+        root_location_tuple
+            .block_location
+            .retain(|loc| retained.is_purgeable(loc));
+        root_location_tuple
+            .bloom_location
+            .retain(|loc| retained.is_purgeable(loc));
 
         let mut agg_indexes_to_be_purged = HashSet::new();
         let mut inverted_indexes_to_be_purged = HashSet::new();
@@ -517,15 +553,12 @@ impl FuseTable {
         // such as, different versions of same (in the sense of name) inverted index.
         // we do not handle this one block multiple inverted indexes case now.
         for idx in inverted_indexes.values() {
-            inverted_indexes_to_be_purged.extend(root_location_tuple.block_location.iter().map(
-                |loc| {
-                    TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
-                        loc,
-                        idx.name.as_str(),
-                        idx.version.as_str(),
-                    )
-                },
-            ));
+            inverted_indexes_to_be_purged.extend(
+                root_location_tuple
+                    .block_location
+                    .iter()
+                    .map(|loc| table_index_location(loc, idx)),
+            );
         }
 
         self.purge_block_segments(
@@ -541,15 +574,16 @@ impl FuseTable {
 
         let mut ts_to_be_purged = HashSet::new();
         if let Some(ts) = root_snapshot.table_statistics_location.clone() {
-            ts_to_be_purged.insert(ts);
+            if retained.is_purgeable(&ts) {
+                ts_to_be_purged.insert(ts);
+            }
         }
-        self.purge_ts_snapshots(
-            ctx,
-            counter,
-            ts_to_be_purged,
-            HashSet::from([root_snapshot_location]),
-        )
-        .await
+        let mut snapshots_to_be_purged = HashSet::new();
+        if retained.is_purgeable(&root_snapshot_location) {
+            snapshots_to_be_purged.insert(root_snapshot_location);
+        }
+        self.purge_ts_snapshots(ctx, counter, ts_to_be_purged, snapshots_to_be_purged)
+            .await
     }
 
     async fn purge_block_segments(
@@ -740,6 +774,27 @@ impl FuseTable {
         })
     }
 
+    /// Collect the files that gc must keep although no snapshot in the purge range needs them.
+    #[async_backtrace::framed]
+    pub async fn retained_locations(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+    ) -> Result<RetainedLocations> {
+        let options = self.table_info.options();
+        let mut retained = RetainedLocations::default();
+        if options.contains_key(OPT_KEY_CLONE_SOURCES)
+            || options.contains_key(OPT_KEY_CLONE_DESCENDANTS)
+        {
+            // a clone must never purge the files it shares with its source
+            retained.prefix = format!("{}/", self.meta_location_generator.prefix());
+        }
+        self.collect_clone_referenced_locations(ctx, &mut retained)
+            .await?;
+        self.collect_tag_referenced_locations(ctx, &mut retained)
+            .await?;
+        Ok(retained)
+    }
+
     pub async fn list_snapshot_files(&self) -> Result<Vec<String>> {
         let prefix = format!(
             "{}/{}/",
@@ -750,6 +805,31 @@ impl FuseTable {
     }
 }
 
+/// Files under the storage prefix of a table that gc must keep.
+///
+/// A clone only owns the files under its own storage prefix, the files it shares with
+/// its descendants and the files of tagged snapshots stay alive while they are referenced.
+#[derive(Default)]
+pub struct RetainedLocations {
+    prefix: String,
+    pub snapshots: HashSet<String>,
+    pub table_statistics: HashSet<String>,
+    pub segments: HashSet<String>,
+    pub blocks: HashSet<String>,
+    pub blooms: HashSet<String>,
+}
+
+impl RetainedLocations {
+    pub fn is_purgeable(&self, location: &str) -> bool {
+        location.starts_with(&self.prefix)
+            && !self.snapshots.contains(location)
+            && !self.table_statistics.contains(location)
+            && !self.segments.contains(location)
+            && !self.blocks.contains(location)
+            && !self.blooms.contains(location)
+    }
+}
+
 struct RootSnapshotInfo {
     snapshot_location: String,
     referenced_locations: LocationTuple,
@@ -807,3 +887,23 @@ impl PurgeCounter {
         }
     }
 }
+
+// The location of the index file of the table index (inverted or vector) accompanying the block.
+fn table_index_location(block_location: &str, index: &TableIndex) -> String {
+    match index.index_type {
+        TableIndexType::Inverted => {
+            TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                block_location,
+                &index.name,
+                &index.version,
+            )
+        }
+        TableIndexType::Vector => {
+            TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                block_location,
+                &index.name,
+                &index.version,
+            )
+        }
+    }
+}
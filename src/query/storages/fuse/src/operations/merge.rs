@@ -26,6 +26,7 @@ use bigbytesdb_storages_common_table_meta::meta::Location;
 use super::merge_into::MatchedAggregator;
 use super::mutation::SegmentIndex;
 use crate::io::create_inverted_index_builders;
+use crate::io::create_vector_index_builders;
 use crate::io::BlockBuilder;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
@@ -90,7 +91,10 @@ impl FuseTable {
         let bloom_columns_map = self
             .bloom_index_cols()
             .bloom_index_fields(new_schema.clone(), BloomIndex::supported_type)?;
+        let ngram_args = self.ngram_args(new_schema.clone())?;
         let inverted_index_builders = create_inverted_index_builders(&self.table_info.meta);
+        let vector_index_builders = create_vector_index_builders(&self.table_info.meta);
+        let partition_gen = self.partition_gen(ctx.clone(), &new_schema)?;
 
         let block_builder = BlockBuilder {
             ctx: ctx.clone(),
@@ -98,8 +102,11 @@ impl FuseTable {
             source_schema: new_schema,
             write_settings: self.get_write_settings(),
             cluster_stats_gen,
+            partition_gen,
             bloom_columns_map,
+            ngram_args,
             inverted_index_builders,
+            vector_index_builders,
         };
         let aggregator = MatchedAggregator::create(
             ctx,
@@ -16,6 +16,7 @@ mod agg_index_sink;
 mod analyze;
 mod append;
 mod changes;
+mod clone;
 mod commit;
 mod common;
 mod compact;
@@ -26,6 +27,7 @@ mod merge_into;
 mod mutation;
 mod mutation_source;
 mod navigate;
+mod ngram_index;
 mod read;
 mod read_data;
 mod read_partitions;
@@ -33,14 +35,17 @@ mod recluster;
 mod replace;
 mod replace_into;
 mod revert;
+mod tag;
 mod truncate;
 mod util;
+mod vector_index;
 
 pub use agg_index_sink::AggIndexSink;
 pub use analyze::HistogramInfoSink;
 pub use changes::ChangesDesc;
 pub use common::*;
 pub use compact::CompactOptions;
+pub use gc::RetainedLocations;
 pub use merge_into::*;
 pub use mutation::*;
 pub use mutation_source::*;
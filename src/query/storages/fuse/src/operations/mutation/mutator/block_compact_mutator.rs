@@ -550,8 +550,23 @@ impl CompactTaskBuilder {
             });
         }
 
+        if blocks.iter().any(|v| v.partition.is_some()) {
+            // Blocks of different partitions cannot be compacted together,
+            // the sort is stable so the cluster order within a partition is kept.
+            blocks.sort_by(|a, b| a.partition.cmp(&b.partition));
+        }
+
         let mut tasks = VecDeque::new();
         for block in blocks.iter() {
+            if self
+                .blocks
+                .last()
+                .is_some_and(|v| v.partition != block.partition)
+            {
+                let blocks = self.take_blocks();
+                latest_flag = self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, blocks);
+                block_idx += 1;
+            }
             let (unchanged, need_take) = self.add(block, self.thresholds);
             if need_take {
                 let blocks = self.take_blocks();
@@ -583,11 +598,14 @@ impl CompactTaskBuilder {
                         acc.1 += x.block_size as usize;
                         acc
                     });
-                if self.thresholds.check_for_compact(total_rows, total_size) {
+                let same_partition = blocks
+                    .last()
+                    .map_or(true, |v| v.partition == tail[0].partition);
+                if same_partition && self.thresholds.check_for_compact(total_rows, total_size) {
                     blocks.extend(tail);
                     self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, blocks);
                 } else {
-                    // blocks > 2N
+                    // blocks > 2N or different partitions
                     self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, blocks);
                     self.build_task(&mut tasks, &mut unchanged_blocks, block_idx + 1, tail);
                 }
@@ -51,6 +51,7 @@ use crate::operations::mutation::MutationPartInfo;
 use crate::operations::mutation::MutationSource;
 use crate::pruning::create_segment_location_vector;
 use crate::pruning::FusePruner;
+use crate::pruning::PartitionPruner;
 use crate::FuseLazyPartInfo;
 use crate::FuseTable;
 use crate::SegmentLocation;
@@ -235,10 +236,14 @@ impl FuseTable {
             self.schema_with_stream(),
             &push_down,
             self.bloom_index_cols(),
+            self.ngram_args(self.schema_with_stream())?,
             None,
             self.get_storage_format(),
         )?;
+        let partition_by = self.partition_by(ctx.clone())?;
+        pruner.set_partition_pruner(partition_by.clone())?;
 
+        let mut inverse_partition_pruner = None;
         if let Some(inverse) = filters.map(|f| f.inverted_filter) {
             // now the `block_metas` refers to the blocks that need to be deleted completely or partially.
             //
@@ -253,12 +258,20 @@ impl FuseTable {
             let inverse = inverse.as_expr(&BUILTIN_FUNCTIONS);
             let func_ctx = ctx.get_function_context()?;
             let range_index = RangeIndex::try_create(
-                func_ctx,
+                func_ctx.clone(),
                 &inverse,
                 self.table_info.schema(),
                 StatisticsOfColumns::default(), // TODO default values
             )?;
             pruner.set_inverse_range_index(range_index);
+            // blocks of a partition which does not satisfy the inverse filter are deleted completely.
+            inverse_partition_pruner = partition_by.and_then(|partition_by| {
+                PartitionPruner::try_create(
+                    func_ctx,
+                    &inverse,
+                    &partition_by.as_expr(&BUILTIN_FUNCTIONS),
+                )
+            });
         }
 
         let block_metas = if is_delete {
@@ -272,7 +285,11 @@ impl FuseTable {
         if !block_metas.is_empty() {
             if let Some(range_index) = pruner.get_inverse_range_index() {
                 for (block_meta_idx, block_meta) in &block_metas {
-                    if !range_index.should_keep(&block_meta.as_ref().col_stats, None) {
+                    if !range_index.should_keep(&block_meta.as_ref().col_stats, None)
+                        || inverse_partition_pruner
+                            .as_ref()
+                            .is_some_and(|pruner| !pruner.should_keep(&block_meta.partition))
+                    {
                         // this block should be deleted completely
                         whole_block_deletions
                             .insert((block_meta_idx.segment_idx, block_meta_idx.block_idx));
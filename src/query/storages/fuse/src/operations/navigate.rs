@@ -60,6 +60,7 @@ impl FuseTable {
                     .await
             }
             NavigationPoint::StreamInfo(info) => self.navigate_to_stream(info).await,
+            NavigationPoint::Tag(name) => self.navigate_to_tag(name).await,
         }
     }
 
@@ -241,6 +242,11 @@ impl FuseTable {
                     .await
             }
             Some(NavigationPoint::StreamInfo(info)) => self.list_by_stream(info, time_point).await,
+            Some(NavigationPoint::Tag(name)) => {
+                let snapshot_loc = self.tagged_snapshot_location(&name)?;
+                self.list_by_snapshot_location(snapshot_loc, time_point)
+                    .await
+            }
             None => self.list_by_time_point(time_point).await,
         }?;
 
@@ -330,7 +336,16 @@ impl FuseTable {
                 ErrorCode::TableHistoricalDataNotFound("No historical data found at given point")
             })?
             .parse::<String>()?;
+        self.list_by_snapshot_location(snapshot_loc, retention_point)
+            .await
+    }
 
+    #[async_backtrace::framed]
+    pub async fn list_by_snapshot_location(
+        &self,
+        snapshot_loc: String,
+        retention_point: DateTime<Utc>,
+    ) -> Result<(String, Vec<String>)> {
         let mut found = false;
         let prefix = format!(
             "{}/{}/",
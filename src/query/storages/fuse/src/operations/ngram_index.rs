@@ -0,0 +1,170 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bigbytesdb_common_catalog::table::Table;
+use bigbytesdb_common_catalog::table_context::TableContext;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_storages_common_cache::LoadParams;
+use bigbytesdb_storages_common_index::BloomIndex;
+use bigbytesdb_storages_common_index::NgramArgs;
+use bigbytesdb_storages_common_table_meta::meta::BlockMeta;
+use bigbytesdb_storages_common_table_meta::meta::SegmentInfo;
+use bigbytesdb_storages_common_table_meta::meta::Versioned;
+use log::info;
+use uuid::Uuid;
+
+use crate::io::BlockWriter;
+use crate::io::BloomIndexBuilder;
+use crate::io::CachedMetaWriter;
+use crate::io::MetaReaders;
+use crate::io::SegmentsIO;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Re-build the bloom index of the blocks that miss any of the n-gram filters
+    /// configured by `ngram_index_columns`, e.g. blocks written before the option is set.
+    ///
+    /// Bloom index files are immutable, so the re-built ones are written to new locations,
+    /// and the segments referring to them are rewritten and committed as a mutation.
+    #[async_backtrace::framed]
+    pub async fn do_refresh_ngram_index(&self, ctx: Arc<dyn TableContext>) -> Result<()> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(());
+        };
+
+        let schema = Arc::new(self.schema().remove_virtual_computed_fields());
+        let ngram_args = self.ngram_args(schema.clone())?;
+        if ngram_args.is_empty() {
+            return Ok(());
+        }
+
+        let bloom_columns_map = self
+            .bloom_index_cols()
+            .bloom_index_fields(schema.clone(), BloomIndex::supported_type)?;
+        let bloom_index_builder = BloomIndexBuilder {
+            table_ctx: ctx.clone(),
+            table_schema: schema.clone(),
+            table_dal: self.operator.clone(),
+            storage_format: self.storage_format,
+            bloom_columns_map,
+            ngram_args: ngram_args.clone(),
+        };
+
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), schema);
+        let segments = segments_io
+            .read_segments::<SegmentInfo>(&snapshot.segments, false)
+            .await?;
+
+        let mut summary = snapshot.summary.clone();
+        let mut segment_locations = Vec::with_capacity(snapshot.segments.len());
+        let mut num_refreshed_blocks = 0;
+        for (location, segment) in snapshot.segments.iter().zip(segments) {
+            let segment = segment?;
+            let mut segment_summary = segment.summary.clone();
+            let mut blocks = Vec::with_capacity(segment.blocks.len());
+            let mut segment_changed = false;
+            for block_meta in &segment.blocks {
+                if self.has_ngram_filters(block_meta, &ngram_args).await? {
+                    blocks.push(block_meta.clone());
+                    continue;
+                }
+
+                let data_block = bloom_index_builder.read_block(block_meta).await?;
+                let bloom_index_location = self
+                    .meta_location_generator
+                    .block_bloom_index_location(&Uuid::new_v4());
+                let Some((bloom_index_state, _)) = bloom_index_builder
+                    .bloom_index_state_from_data_block(&data_block, bloom_index_location)?
+                else {
+                    blocks.push(block_meta.clone());
+                    continue;
+                };
+
+                let mut new_block_meta = block_meta.as_ref().clone();
+                new_block_meta.bloom_filter_index_location =
+                    Some(bloom_index_state.location.clone());
+                new_block_meta.bloom_filter_index_size = bloom_index_state.size;
+                BlockWriter::write_down_bloom_index_state(&self.operator, Some(bloom_index_state))
+                    .await?;
+
+                for stats in [&mut segment_summary, &mut summary] {
+                    stats.index_size = stats
+                        .index_size
+                        .saturating_sub(block_meta.bloom_filter_index_size)
+                        + new_block_meta.bloom_filter_index_size;
+                }
+                blocks.push(Arc::new(new_block_meta));
+                segment_changed = true;
+                num_refreshed_blocks += 1;
+            }
+
+            if segment_changed {
+                let new_segment = SegmentInfo::new(blocks, segment_summary);
+                let new_location = self.meta_location_generator.gen_segment_info_location();
+                new_segment
+                    .write_meta_through_cache(&self.operator, &new_location)
+                    .await?;
+                segment_locations.push((new_location, SegmentInfo::VERSION));
+            } else {
+                segment_locations.push(location.clone());
+            }
+        }
+
+        if num_refreshed_blocks == 0 {
+            return Ok(());
+        }
+        info!(
+            "refreshed ngram index of {} blocks of table {}",
+            num_refreshed_blocks, self.table_info.desc
+        );
+
+        self.commit_mutation(&ctx, snapshot, &segment_locations, summary, None)
+            .await
+    }
+
+    /// Returns true if the bloom index of the block contains the n-gram filters of all
+    /// the n-gram index columns the block has.
+    async fn has_ngram_filters(
+        &self,
+        block_meta: &BlockMeta,
+        ngram_args: &[NgramArgs],
+    ) -> Result<bool> {
+        let filter_names = ngram_args
+            .iter()
+            .filter(|arg| block_meta.col_metas.contains_key(&arg.field.column_id()))
+            .map(|arg| BloomIndex::build_ngram_filter_column_name(&arg.field, arg.gram_size))
+            .collect::<Vec<_>>();
+        if filter_names.is_empty() {
+            return Ok(true);
+        }
+
+        let Some((path, _)) = &block_meta.bloom_filter_index_location else {
+            return Ok(false);
+        };
+        let reader = MetaReaders::bloom_index_meta_reader(self.operator.clone());
+        let index_meta = reader
+            .read(&LoadParams {
+                location: path.clone(),
+                len_hint: Some(block_meta.bloom_filter_index_size),
+                ver: 0,
+                put_cache: true,
+            })
+            .await?;
+        Ok(filter_names
+            .iter()
+            .all(|name| index_meta.columns.iter().any(|(column, _)| column == name)))
+    }
+}
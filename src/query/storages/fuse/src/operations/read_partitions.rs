@@ -128,7 +128,12 @@ impl FuseTable {
                     nodes_num = cluster.nodes.len();
                 }
 
-                if !dry_run && segment_len > nodes_num && distributed_pruning {
+                // The vector index pruner picks the nearest vectors among all the blocks,
+                // so the blocks can not be pruned lazily by segments.
+                let has_vector_index = push_downs
+                    .as_ref()
+                    .is_some_and(|p| p.vector_index.is_some());
+                if !dry_run && segment_len > nodes_num && distributed_pruning && !has_vector_index {
                     let mut segments = Vec::with_capacity(segment_locs.len());
                     for (idx, segment_location) in segment_locs.into_iter().enumerate() {
                         segments.push(FuseLazyPartInfo::create(idx, segment_location))
@@ -469,6 +474,7 @@ impl FuseTable {
         table_schema: TableSchemaRef,
         dal: Operator,
     ) -> Result<FusePruner> {
+        let ngram_args = self.ngram_args(table_schema.clone())?;
         let bloom_index_builder = if ctx
             .get_settings()
             .get_enable_auto_fix_missing_bloom_index()?
@@ -485,12 +491,13 @@ impl FuseTable {
                 table_dal: dal.clone(),
                 storage_format,
                 bloom_columns_map,
+                ngram_args: ngram_args.clone(),
             })
         } else {
             None
         };
 
-        let pruner =
+        let mut pruner =
             if !self.is_native() || self.cluster_type().is_none_or(|v| v != ClusterType::Linear) {
                 FusePruner::create(
                     &ctx,
@@ -498,6 +505,7 @@ impl FuseTable {
                     table_schema.clone(),
                     &push_downs,
                     self.bloom_index_cols(),
+                    ngram_args,
                     bloom_index_builder,
                     self.get_storage_format(),
                 )?
@@ -512,10 +520,12 @@ impl FuseTable {
                     self.cluster_key_meta.clone(),
                     cluster_keys,
                     self.bloom_index_cols(),
+                    ngram_args,
                     bloom_index_builder,
                     self.get_storage_format(),
                 )?
             };
+        pruner.set_partition_pruner(self.partition_by(ctx)?)?;
         Ok(pruner)
     }
 
@@ -252,6 +252,7 @@ impl FuseTable {
             None,
             vec![],
             BloomIndexColumns::None,
+            vec![],
             max_concurrency,
             bloom_index_builder,
             storage_format,
@@ -0,0 +1,104 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use bigbytesdb_common_catalog::table_context::TableContext;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_storages_common_table_meta::table::parse_snapshot_tags;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_TAGS;
+use log::info;
+use log::warn;
+
+use crate::io::SnapshotsIO;
+use crate::operations::RetainedLocations;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Returns the location of the snapshot pinned by the tag `name`.
+    pub fn tagged_snapshot_location(&self, name: &str) -> Result<String> {
+        let tags = parse_snapshot_tags(self.table_info.options().get(OPT_KEY_SNAPSHOT_TAGS))?;
+        match tags.get(name) {
+            Some(tag) if !tag.is_expired(Utc::now()) => Ok(tag.snapshot_location.clone()),
+            _ => Err(ErrorCode::UnknownSnapshotTag(format!(
+                "Unknown tag '{}' of table '{}'",
+                name, self.table_info.desc
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn navigate_to_tag(&self, name: &str) -> Result<Arc<FuseTable>> {
+        let location = self.tagged_snapshot_location(name)?;
+        let (snapshot, format_version) =
+            SnapshotsIO::read_snapshot(location, self.get_operator()).await?;
+        self.load_table_by_snapshot(snapshot.as_ref(), format_version)
+    }
+
+    /// Add the snapshots pinned by the unexpired tags of this table and the files they reference.
+    #[async_backtrace::framed]
+    pub async fn collect_tag_referenced_locations(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        retained: &mut RetainedLocations,
+    ) -> Result<()> {
+        let tags = parse_snapshot_tags(self.table_info.options().get(OPT_KEY_SNAPSHOT_TAGS))?;
+        if tags.is_empty() {
+            return Ok(());
+        }
+
+        let now = Utc::now();
+        let mut segments = Vec::new();
+        for (name, tag) in tags.iter().filter(|(_, tag)| !tag.is_expired(now)) {
+            let location = tag.snapshot_location.clone();
+            match SnapshotsIO::read_snapshot(location.clone(), self.get_operator()).await {
+                Ok((snapshot, _)) => {
+                    segments.extend(snapshot.segments.iter().cloned());
+                    retained
+                        .table_statistics
+                        .extend(snapshot.table_statistics_location.clone());
+                }
+                Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => {
+                    warn!(
+                        "gc: snapshot {} of tag {} of table {} not found",
+                        location, name, self.table_info.desc
+                    );
+                }
+                Err(e) => return Err(e),
+            }
+            retained.snapshots.insert(location);
+        }
+        segments.sort();
+        segments.dedup();
+
+        let locations = self
+            .get_block_locations(ctx.clone(), &segments, false, true)
+            .await?;
+        info!(
+            "gc: files pinned by tags of table {}, snapshots: {}, segments: {}, blocks: {}",
+            self.table_info.desc,
+            retained.snapshots.len(),
+            segments.len(),
+            locations.block_location.len()
+        );
+        retained
+            .segments
+            .extend(segments.into_iter().map(|(location, _)| location));
+        retained.blocks.extend(locations.block_location);
+        retained.blooms.extend(locations.bloom_location);
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bigbytesdb_common_catalog::plan::Projection;
+use bigbytesdb_common_catalog::table::Table;
+use bigbytesdb_common_catalog::table_context::TableContext;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_meta_app::schema::TableIndex;
+use bigbytesdb_storages_common_cache::LoadParams;
+use bigbytesdb_storages_common_io::ReadSettings;
+use bigbytesdb_storages_common_table_meta::meta::Location;
+use log::info;
+
+use crate::io::write_data;
+use crate::io::MetaReaders;
+use crate::io::VectorIndexBuilder;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Build the HNSW index files of the blocks that do not have one yet,
+    /// e.g. blocks written before an async vector index is created.
+    ///
+    /// Index files are keyed by block location, so no new snapshot is committed.
+    #[async_backtrace::framed]
+    pub async fn do_refresh_vector_index(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        index: &TableIndex,
+        segment_locs: Option<Vec<Location>>,
+    ) -> Result<()> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(());
+        };
+        let Some(builder) = VectorIndexBuilder::try_create(&self.table_info.meta, index) else {
+            return Err(ErrorCode::RefreshIndexError(format!(
+                "Vector index {} is invalid",
+                index.name
+            )));
+        };
+
+        // If no segment locations are specified, iterates through all segments
+        let segment_locs = if let Some(segment_locs) = segment_locs {
+            segment_locs
+                .into_iter()
+                .filter(|s| snapshot.segments.contains(s))
+                .collect()
+        } else {
+            snapshot.segments.clone()
+        };
+        if segment_locs.is_empty() {
+            return Ok(());
+        }
+
+        let table_schema = self.schema();
+        let field_index = table_schema.index_of(builder.field.name())?;
+        let block_reader = self.create_block_reader(
+            ctx.clone(),
+            Projection::Columns(vec![field_index]),
+            false,
+            false,
+            false,
+        )?;
+        let segment_reader = MetaReaders::segment_info_reader(self.get_operator(), table_schema);
+        let settings = ReadSettings::from_ctx(&ctx)?;
+        let storage_format = self.get_write_settings().storage_format;
+        let operator = self.get_operator_ref();
+
+        let mut num_refreshed_blocks = 0;
+        for (segment_loc, ver) in &segment_locs {
+            let segment_info = segment_reader
+                .read(&LoadParams {
+                    location: segment_loc.to_string(),
+                    len_hint: None,
+                    ver: *ver,
+                    put_cache: false,
+                })
+                .await?;
+
+            for block_meta in segment_info.block_metas()? {
+                let index_location = builder.index_location(&block_meta.location.0);
+                // only generate vector index if it is not exist.
+                if operator.stat(&index_location).await.is_ok() {
+                    continue;
+                }
+                let block = block_reader
+                    .read_by_meta(&settings, &block_meta, &storage_format)
+                    .await?;
+                let column = block.get_by_offset(0).to_column(block.num_rows());
+                let vector_index = builder.build(&column)?;
+                write_data(vector_index.to_bytes(), operator, &index_location).await?;
+                num_refreshed_blocks += 1;
+            }
+        }
+
+        info!(
+            "refreshed vector index {} of {} blocks of table {}",
+            index.name, num_refreshed_blocks, self.table_info.desc
+        );
+        Ok(())
+    }
+}
@@ -25,12 +25,14 @@ use bigbytesdb_common_exception::Result;
 use bigbytesdb_common_expression::types::F32;
 use bigbytesdb_common_expression::BLOCK_NAME_COL_NAME;
 use bigbytesdb_common_metrics::storage::*;
+use bigbytesdb_storages_common_index::SpatialIndex;
 use bigbytesdb_storages_common_pruner::BlockMetaIndex;
 use bigbytesdb_storages_common_pruner::VirtualBlockMetaIndex;
 use bigbytesdb_storages_common_table_meta::meta::BlockMeta;
 use futures_util::future;
 
 use super::SegmentLocation;
+use crate::pruning::FusePruningStatistics;
 use crate::pruning::PruningContext;
 
 pub struct BlockPruner {
@@ -100,6 +102,8 @@ impl BlockPruner {
         let pruning_semaphore = &self.pruning_ctx.pruning_semaphore;
         let limit_pruner = self.pruning_ctx.limit_pruner.clone();
         let range_pruner = self.pruning_ctx.range_pruner.clone();
+        let spatial_pruner = self.pruning_ctx.spatial_pruner.clone();
+        let partition_pruner = self.pruning_ctx.partition_pruner.clone();
         let page_pruner = self.pruning_ctx.page_pruner.clone();
         let bloom_pruner = self.pruning_ctx.bloom_pruner.clone();
         let inverted_index_pruner = self.pruning_ctx.inverted_index_pruner.clone();
@@ -131,8 +135,11 @@ impl BlockPruner {
                     BlockPruneResult::new(block_idx, block_meta.location.0.clone());
                 let block_meta = block_meta.clone();
                 let row_count = block_meta.row_count;
-                prune_result.keep =
-                    range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas));
+                prune_result.keep = range_pruner
+                    .should_keep(&block_meta.col_stats, Some(&block_meta.col_metas))
+                    && partition_pruner
+                        .as_ref()
+                        .map_or(true, |pruner| pruner.should_keep(&block_meta.partition));
                 if prune_result.keep {
                     // Perf.
                     {
@@ -142,6 +149,10 @@ impl BlockPruner {
                         pruning_stats.set_blocks_range_pruning_after(1);
                     }
 
+                    prune_result.keep =
+                        spatial_should_keep(spatial_pruner.as_deref(), &pruning_stats, &block_meta);
+                }
+                if prune_result.keep {
                     // not pruned by block zone map index,
                     let bloom_pruner = bloom_pruner.clone();
                     let limit_pruner = limit_pruner.clone();
@@ -310,6 +321,8 @@ impl BlockPruner {
         let pruning_stats = self.pruning_ctx.pruning_stats.clone();
         let limit_pruner = self.pruning_ctx.limit_pruner.clone();
         let range_pruner = self.pruning_ctx.range_pruner.clone();
+        let spatial_pruner = self.pruning_ctx.spatial_pruner.clone();
+        let partition_pruner = self.pruning_ctx.partition_pruner.clone();
         let page_pruner = self.pruning_ctx.page_pruner.clone();
 
         let start = Instant::now();
@@ -330,9 +343,11 @@ impl BlockPruner {
                 break;
             }
             let row_count = block_meta.row_count;
-            if range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas))
-                && limit_pruner.within_limit(row_count)
-            {
+            let keep = range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas))
+                && partition_pruner
+                    .as_ref()
+                    .map_or(true, |pruner| pruner.should_keep(&block_meta.partition));
+            if keep {
                 // Perf.
                 {
                     metrics_inc_blocks_range_pruning_after(1);
@@ -340,7 +355,11 @@ impl BlockPruner {
 
                     pruning_stats.set_blocks_range_pruning_after(1);
                 }
-
+            }
+            if keep
+                && spatial_should_keep(spatial_pruner.as_deref(), &pruning_stats, &block_meta)
+                && limit_pruner.within_limit(row_count)
+            {
                 let (keep, range) = page_pruner.should_keep(&block_meta.cluster_stats);
                 if keep {
                     result.push((
@@ -371,6 +390,23 @@ impl BlockPruner {
     }
 }
 
+/// Returns false if the spatial index prunes the block, counts the blocks it checks.
+fn spatial_should_keep(
+    spatial_pruner: Option<&SpatialIndex>,
+    pruning_stats: &FusePruningStatistics,
+    block_meta: &BlockMeta,
+) -> bool {
+    let Some(pruner) = spatial_pruner else {
+        return true;
+    };
+    pruning_stats.set_blocks_spatial_pruning_before(1);
+    let keep = pruner.should_keep(&block_meta.spatial_stats);
+    if keep {
+        pruning_stats.set_blocks_spatial_pruning_after(1);
+    }
+    keep
+}
+
 // result of block pruning
 struct BlockPruneResult {
     // the block index in segment
@@ -19,6 +19,7 @@ use std::sync::Arc;
 
 use bigbytesdb_common_exception::ErrorCode;
 use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::types::DataType;
 use bigbytesdb_common_expression::ColumnId;
 use bigbytesdb_common_expression::Expr;
 use bigbytesdb_common_expression::FunctionContext;
@@ -30,6 +31,7 @@ use bigbytesdb_common_sql::BloomIndexColumns;
 use bigbytesdb_storages_common_index::filters::BlockFilter;
 use bigbytesdb_storages_common_index::BloomIndex;
 use bigbytesdb_storages_common_index::FilterEvalResult;
+use bigbytesdb_storages_common_index::NgramArgs;
 use bigbytesdb_storages_common_table_meta::meta::BlockMeta;
 use bigbytesdb_storages_common_table_meta::meta::Location;
 use bigbytesdb_storages_common_table_meta::meta::StatisticsOfColumns;
@@ -60,6 +62,9 @@ pub struct BloomPrunerCreator {
     /// indices that should be loaded from filter block
     index_fields: Vec<TableField>,
 
+    /// n-gram filters that should be loaded from filter block
+    ngram_args: Vec<NgramArgs>,
+
     /// the expression that would be evaluate
     filter_expression: Expr<String>,
 
@@ -83,6 +88,7 @@ impl BloomPrunerCreator {
         dal: Operator,
         filter_expr: Option<&Expr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_args: Vec<NgramArgs>,
         bloom_index_builder: Option<BloomIndexBuilder>,
     ) -> Result<Option<Arc<dyn BloomPruner + Send + Sync>>> {
         if let Some(expr) = filter_expr {
@@ -90,8 +96,9 @@ impl BloomPrunerCreator {
                 bloom_index_cols.bloom_index_fields(schema.clone(), BloomIndex::supported_type)?;
             let bloom_column_fields = bloom_columns_map.values().cloned().collect::<Vec<_>>();
             let point_query_cols = BloomIndex::find_eq_columns(expr, bloom_column_fields)?;
+            let ngram_query_cols = BloomIndex::find_ngram_columns(expr, &ngram_args)?;
 
-            if !point_query_cols.is_empty() {
+            if !point_query_cols.is_empty() || !ngram_query_cols.is_empty() {
                 // convert to filter column names
                 let mut filter_fields = Vec::with_capacity(point_query_cols.len());
                 let mut scalar_map = HashMap::<Scalar, u64>::new();
@@ -103,9 +110,27 @@ impl BloomPrunerCreator {
                     }
                 }
 
+                let mut ngram_filters = Vec::with_capacity(ngram_query_cols.len());
+                for (arg, grams) in ngram_query_cols.into_iter() {
+                    for gram in grams {
+                        if let Entry::Vacant(e) = scalar_map.entry(Scalar::String(gram)) {
+                            let digest = BloomIndex::calculate_scalar_digest(
+                                &func_ctx,
+                                e.key(),
+                                &DataType::String,
+                            )?;
+                            e.insert(digest);
+                        }
+                    }
+                    if !ngram_filters.contains(&arg) {
+                        ngram_filters.push(arg);
+                    }
+                }
+
                 let creator = BloomPrunerCreator {
                     func_ctx,
                     index_fields: filter_fields,
+                    ngram_args: ngram_filters,
                     filter_expression: expr.clone(),
                     scalar_map,
                     dal,
@@ -131,8 +156,8 @@ impl BloomPrunerCreator {
         let version = index_location.1;
 
         // filter out columns that no longer exist in the indexed block
-        let index_columns = self.index_fields.iter().try_fold(
-            Vec::with_capacity(self.index_fields.len()),
+        let mut index_columns = self.index_fields.iter().try_fold(
+            Vec::with_capacity(self.index_fields.len() + self.ngram_args.len()),
             |mut acc, field| {
                 if column_ids_of_indexed_block.contains(&field.column_id()) {
                     acc.push(BloomIndex::build_filter_column_name(version, field)?);
@@ -140,6 +165,14 @@ impl BloomPrunerCreator {
                 Ok::<_, ErrorCode>(acc)
             },
         )?;
+        for arg in &self.ngram_args {
+            if column_ids_of_indexed_block.contains(&arg.field.column_id()) {
+                index_columns.push(BloomIndex::build_ngram_filter_column_name(
+                    &arg.field,
+                    arg.gram_size,
+                ));
+            }
+        }
 
         // load the relevant index columns
         let maybe_filter = index_location
@@ -194,6 +227,7 @@ impl BloomPrunerCreator {
             .apply(
                 self.filter_expression.clone(),
                 &self.scalar_map,
+                &self.ngram_args,
                 column_stats,
                 self.data_schema.clone(),
             )? != FilterEvalResult::MustFalse),
@@ -31,7 +31,9 @@ use bigbytesdb_common_sql::BloomIndexColumns;
 use bigbytesdb_storages_common_cache::CacheAccessor;
 use bigbytesdb_storages_common_cache::CacheManager;
 use bigbytesdb_storages_common_cache::SegmentBlockMetasCache;
+use bigbytesdb_storages_common_index::NgramArgs;
 use bigbytesdb_storages_common_index::RangeIndex;
+use bigbytesdb_storages_common_index::SpatialIndex;
 use bigbytesdb_storages_common_pruner::BlockMetaIndex;
 use bigbytesdb_storages_common_pruner::InternalColumnPruner;
 use bigbytesdb_storages_common_pruner::Limiter;
@@ -62,7 +64,9 @@ use crate::pruning::BloomPruner;
 use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
 use crate::pruning::InvertedIndexPruner;
+use crate::pruning::PartitionPruner;
 use crate::pruning::SegmentLocation;
+use crate::pruning::VectorIndexPruner;
 use crate::pruning::VirtualColumnPruner;
 use crate::FuseStorageFormat;
 
@@ -79,7 +83,10 @@ pub struct PruningContext {
     pub bloom_pruner: Option<Arc<dyn BloomPruner + Send + Sync>>,
     pub page_pruner: Arc<dyn PagePruner + Send + Sync>,
     pub internal_column_pruner: Option<Arc<InternalColumnPruner>>,
+    pub spatial_pruner: Option<Arc<SpatialIndex>>,
+    pub partition_pruner: Option<Arc<PartitionPruner>>,
     pub inverted_index_pruner: Option<Arc<InvertedIndexPruner>>,
+    pub vector_index_pruner: Option<Arc<VectorIndexPruner>>,
     pub virtual_column_pruner: Option<Arc<VirtualColumnPruner>>,
 
     pub pruning_stats: Arc<FusePruningStatistics>,
@@ -95,6 +102,7 @@ impl PruningContext {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_args: Vec<NgramArgs>,
         max_concurrency: usize,
         bloom_index_builder: Option<BloomIndexBuilder>,
         storage_format: FuseStorageFormat,
@@ -149,6 +157,7 @@ impl PruningContext {
             dal.clone(),
             filter_expr.as_ref(),
             bloom_index_cols,
+            ngram_args,
             bloom_index_builder,
         )?;
 
@@ -161,9 +170,18 @@ impl PruningContext {
             cluster_keys,
         )?;
 
+        // Spatial pruner, used to prune blocks by the bounding boxes of Geometry columns.
+        let spatial_pruner = filter_expr
+            .as_ref()
+            .and_then(|expr| SpatialIndex::try_create(expr, &table_schema))
+            .map(Arc::new);
+
         // inverted index pruner, used to search matched rows in block
         let inverted_index_pruner = InvertedIndexPruner::try_create(ctx, dal.clone(), push_down)?;
 
+        // vector index pruner, used to keep the blocks holding the nearest vectors
+        let vector_index_pruner = VectorIndexPruner::try_create(ctx, dal.clone(), push_down)?;
+
         // virtual column pruner, used to read virtual column metas and ignore source columns.
         let virtual_column_pruner =
             VirtualColumnPruner::try_create(dal.clone(), push_down, storage_format)?;
@@ -194,7 +212,10 @@ impl PruningContext {
             bloom_pruner,
             page_pruner,
             internal_column_pruner,
+            spatial_pruner,
+            partition_pruner: None,
             inverted_index_pruner,
+            vector_index_pruner,
             virtual_column_pruner,
             pruning_stats,
         });
@@ -220,6 +241,7 @@ impl FusePruner {
         table_schema: TableSchemaRef,
         push_down: &Option<PushDownInfo>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_args: Vec<NgramArgs>,
         bloom_index_builder: Option<BloomIndexBuilder>,
         storage_format: FuseStorageFormat,
     ) -> Result<Self> {
@@ -231,6 +253,7 @@ impl FusePruner {
             None,
             vec![],
             bloom_index_cols,
+            ngram_args,
             bloom_index_builder,
             storage_format,
         )
@@ -245,6 +268,7 @@ impl FusePruner {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_args: Vec<NgramArgs>,
         bloom_index_builder: Option<BloomIndexBuilder>,
         storage_format: FuseStorageFormat,
     ) -> Result<Self> {
@@ -271,6 +295,7 @@ impl FusePruner {
             cluster_key_meta,
             cluster_keys,
             bloom_index_cols,
+            ngram_args,
             max_concurrency,
             bloom_index_builder,
             storage_format,
@@ -304,7 +329,7 @@ impl FusePruner {
     }
 
     // Pruning chain:
-    // segment pruner -> block pruner -> topn pruner
+    // segment pruner -> block pruner -> vector index pruner -> topn pruner
     #[async_backtrace::framed]
     pub async fn pruning(
         &mut self,
@@ -438,6 +463,7 @@ impl FusePruner {
         } else {
             // Todo:: for now, all operation (contains other mutation other than delete, like select,update etc.)
             // will get here, we can prevent other mutations like update and so on.
+            let metas = self.vector_index_pruning(metas).await?;
             // TopN pruner.
             self.topn_pruning(metas)
         }
@@ -508,9 +534,24 @@ impl FusePruner {
             let res = worker?;
             metas.extend(res);
         }
+        let metas = self.vector_index_pruning(metas).await?;
         self.topn_pruning(metas)
     }
 
+    // vector index pruner:
+    // if there are ordering by a vector distance + limit clause, keep the blocks
+    // holding the nearest vectors
+    #[async_backtrace::framed]
+    async fn vector_index_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        match &self.pruning_ctx.vector_index_pruner {
+            Some(pruner) => pruner.prune(metas, &self.pruning_ctx.pruning_stats).await,
+            None => Ok(metas),
+        }
+    }
+
     // topn pruner:
     // if there are ordering + limit clause and no filters, use topn pruner
     fn topn_pruning(
@@ -551,6 +592,14 @@ impl FusePruner {
         let blocks_inverted_index_pruning_after =
             stats.get_blocks_inverted_index_pruning_after() as usize;
 
+        let blocks_vector_index_pruning_before =
+            stats.get_blocks_vector_index_pruning_before() as usize;
+        let blocks_vector_index_pruning_after =
+            stats.get_blocks_vector_index_pruning_after() as usize;
+
+        let blocks_spatial_pruning_before = stats.get_blocks_spatial_pruning_before() as usize;
+        let blocks_spatial_pruning_after = stats.get_blocks_spatial_pruning_after() as usize;
+
         bigbytesdb_common_catalog::plan::PruningStatistics {
             segments_range_pruning_before,
             segments_range_pruning_after,
@@ -560,9 +609,33 @@ impl FusePruner {
             blocks_bloom_pruning_after,
             blocks_inverted_index_pruning_before,
             blocks_inverted_index_pruning_after,
+            blocks_vector_index_pruning_before,
+            blocks_vector_index_pruning_after,
+            blocks_spatial_pruning_before,
+            blocks_spatial_pruning_after,
         }
     }
 
+    /// Prunes segments and blocks by their partition, must be called before pruning.
+    pub fn set_partition_pruner(&mut self, partition_by: Option<RemoteExpr<String>>) -> Result<()> {
+        let filter = self
+            .push_down
+            .as_ref()
+            .and_then(|p| p.filters.as_ref())
+            .map(|f| f.filter.as_expr(&BUILTIN_FUNCTIONS));
+        let (Some(filter), Some(partition_by)) = (filter, partition_by) else {
+            return Ok(());
+        };
+        let func_ctx = self.pruning_ctx.ctx.get_function_context()?;
+        let partition_by = partition_by.as_expr(&BUILTIN_FUNCTIONS);
+        let pruning_ctx = Arc::get_mut(&mut self.pruning_ctx).ok_or_else(|| {
+            ErrorCode::Internal("the partition pruner must be set before pruning starts")
+        })?;
+        pruning_ctx.partition_pruner =
+            PartitionPruner::try_create(func_ctx, &filter, &partition_by).map(Arc::new);
+        Ok(())
+    }
+
     pub fn set_inverse_range_index(&mut self, index: RangeIndex) {
         self.inverse_range_index = Some(index)
     }
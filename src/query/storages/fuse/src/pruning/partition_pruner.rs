@@ -0,0 +1,165 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigbytesdb_common_expression::ConstantFolder;
+use bigbytesdb_common_expression::Expr;
+use bigbytesdb_common_expression::FunctionContext;
+use bigbytesdb_common_expression::Scalar;
+use bigbytesdb_common_functions::BUILTIN_FUNCTIONS;
+
+/// Prunes segments and blocks by the partition recorded in their metadata.
+///
+/// The `PARTITION BY` expression is replaced by the partition of the segment or block
+/// wherever it appears in the filter, the segment or block is skipped if the filter
+/// then folds to `false`.
+pub struct PartitionPruner {
+    func_ctx: FunctionContext,
+    filter: Expr<String>,
+    partition_by: Expr<String>,
+}
+
+impl PartitionPruner {
+    /// Returns `None` if the filter does not use the `PARTITION BY` expression.
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        filter: &Expr<String>,
+        partition_by: &Expr<String>,
+    ) -> Option<Self> {
+        let mut found = false;
+        replace_partition_expr(filter, partition_by, &Scalar::Null, &mut found);
+        found.then(|| Self {
+            func_ctx,
+            filter: filter.clone(),
+            partition_by: partition_by.clone(),
+        })
+    }
+
+    /// Returns false if the segment or block can be skipped.
+    pub fn should_keep(&self, partition: &Option<Scalar>) -> bool {
+        let Some(partition) = partition else {
+            return true;
+        };
+        let mut found = false;
+        let expr = replace_partition_expr(&self.filter, &self.partition_by, partition, &mut found);
+        let (folded, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        !matches!(folded, Expr::Constant {
+            scalar: Scalar::Boolean(false),
+            ..
+        })
+    }
+}
+
+fn replace_partition_expr(
+    expr: &Expr<String>,
+    partition_by: &Expr<String>,
+    partition: &Scalar,
+    found: &mut bool,
+) -> Expr<String> {
+    if is_same_expr(expr, partition_by) {
+        *found = true;
+        return Expr::Constant {
+            span: None,
+            scalar: partition.clone(),
+            data_type: partition_by.data_type().clone(),
+        };
+    }
+    match expr {
+        Expr::Cast {
+            span,
+            is_try,
+            expr,
+            dest_type,
+        } => Expr::Cast {
+            span: *span,
+            is_try: *is_try,
+            expr: Box::new(replace_partition_expr(expr, partition_by, partition, found)),
+            dest_type: dest_type.clone(),
+        },
+        Expr::FunctionCall {
+            span,
+            id,
+            function,
+            generics,
+            args,
+            return_type,
+        } => Expr::FunctionCall {
+            span: *span,
+            id: id.clone(),
+            function: function.clone(),
+            generics: generics.clone(),
+            args: args
+                .iter()
+                .map(|arg| replace_partition_expr(arg, partition_by, partition, found))
+                .collect(),
+            return_type: return_type.clone(),
+        },
+        _ => expr.clone(),
+    }
+}
+
+/// Compares two expressions, ignoring spans and display names.
+fn is_same_expr(l: &Expr<String>, r: &Expr<String>) -> bool {
+    match (l, r) {
+        (Expr::Constant { scalar: l, .. }, Expr::Constant { scalar: r, .. }) => l == r,
+        (
+            Expr::ColumnRef {
+                id: l_id,
+                data_type: l_type,
+                ..
+            },
+            Expr::ColumnRef {
+                id: r_id,
+                data_type: r_type,
+                ..
+            },
+        ) => l_id == r_id && l_type == r_type,
+        (
+            Expr::Cast {
+                is_try: l_try,
+                expr: l_expr,
+                dest_type: l_type,
+                ..
+            },
+            Expr::Cast {
+                is_try: r_try,
+                expr: r_expr,
+                dest_type: r_type,
+                ..
+            },
+        ) => l_try == r_try && l_type == r_type && is_same_expr(l_expr, r_expr),
+        (
+            Expr::FunctionCall {
+                id: l_id,
+                generics: l_generics,
+                args: l_args,
+                ..
+            },
+            Expr::FunctionCall {
+                id: r_id,
+                generics: r_generics,
+                args: r_args,
+                ..
+            },
+        ) => {
+            l_id == r_id
+                && l_generics == r_generics
+                && l_args.len() == r_args.len()
+                && l_args
+                    .iter()
+                    .zip(r_args.iter())
+                    .all(|(l, r)| is_same_expr(l, r))
+        }
+        _ => false,
+    }
+}
@@ -32,6 +32,14 @@ pub struct FusePruningStatistics {
     /// Block inverted index filter pruning stats.
     pub blocks_inverted_index_pruning_before: AtomicU64,
     pub blocks_inverted_index_pruning_after: AtomicU64,
+
+    /// Block vector index pruning stats.
+    pub blocks_vector_index_pruning_before: AtomicU64,
+    pub blocks_vector_index_pruning_after: AtomicU64,
+
+    /// Block spatial index pruning stats.
+    pub blocks_spatial_pruning_before: AtomicU64,
+    pub blocks_spatial_pruning_after: AtomicU64,
 }
 
 impl FusePruningStatistics {
@@ -108,4 +116,42 @@ impl FusePruningStatistics {
         self.blocks_inverted_index_pruning_after
             .load(Ordering::Relaxed)
     }
+
+    pub fn set_blocks_vector_index_pruning_before(&self, v: u64) {
+        self.blocks_vector_index_pruning_before
+            .fetch_add(v, Ordering::Relaxed);
+    }
+
+    pub fn get_blocks_vector_index_pruning_before(&self) -> u64 {
+        self.blocks_vector_index_pruning_before
+            .load(Ordering::Relaxed)
+    }
+
+    pub fn set_blocks_vector_index_pruning_after(&self, v: u64) {
+        self.blocks_vector_index_pruning_after
+            .fetch_add(v, Ordering::Relaxed);
+    }
+
+    pub fn get_blocks_vector_index_pruning_after(&self) -> u64 {
+        self.blocks_vector_index_pruning_after
+            .load(Ordering::Relaxed)
+    }
+
+    pub fn set_blocks_spatial_pruning_before(&self, v: u64) {
+        self.blocks_spatial_pruning_before
+            .fetch_add(v, Ordering::Relaxed);
+    }
+
+    pub fn get_blocks_spatial_pruning_before(&self) -> u64 {
+        self.blocks_spatial_pruning_before.load(Ordering::Relaxed)
+    }
+
+    pub fn set_blocks_spatial_pruning_after(&self, v: u64) {
+        self.blocks_spatial_pruning_after
+            .fetch_add(v, Ordering::Relaxed);
+    }
+
+    pub fn get_blocks_spatial_pruning_after(&self) -> u64 {
+        self.blocks_spatial_pruning_after.load(Ordering::Relaxed)
+    }
 }
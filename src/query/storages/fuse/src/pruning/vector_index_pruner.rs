@@ -0,0 +1,154 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bigbytesdb_common_catalog::plan::PushDownInfo;
+use bigbytesdb_common_catalog::plan::VectorIndexInfo;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_vector::HnswIndex;
+use bigbytesdb_storages_common_pruner::BlockMetaIndex;
+use bigbytesdb_storages_common_table_meta::meta::BlockMeta;
+use futures::StreamExt;
+use log::info;
+use opendal::Operator;
+
+use crate::io::TableMetaLocationGenerator;
+use crate::pruning::FusePruningStatistics;
+use crate::TableContext;
+
+// Each block file has a corresponding HNSW index file.
+// The index of each block is searched for the `limit` nearest vectors of the query,
+// and only the blocks holding one of the global `limit` nearest vectors are kept.
+// The kept blocks are read and sorted by the exact distance as usual,
+// so the index only affects which blocks are read, not the result order.
+//
+// Blocks without a complete index (e.g. written before an async index is refreshed,
+// or containing NULL values) can not be pruned and are always kept.
+//
+// The index is built per block rather than per segment: blocks are the unit that is
+// pruned and read, a segment index would have to be rebuilt whenever compaction or a
+// mutation replaces any of its blocks, and the row ids of a block index stay valid
+// as long as the block itself.
+pub struct VectorIndexPruner {
+    dal: Operator,
+    info: VectorIndexInfo,
+    query: Vec<f32>,
+    ef_search: usize,
+    max_concurrency: usize,
+}
+
+impl VectorIndexPruner {
+    pub fn try_create(
+        ctx: &Arc<dyn TableContext>,
+        dal: Operator,
+        push_down: &Option<PushDownInfo>,
+    ) -> Result<Option<Arc<VectorIndexPruner>>> {
+        let Some(info) = push_down.as_ref().and_then(|p| p.vector_index.as_ref()) else {
+            return Ok(None);
+        };
+        let settings = ctx.get_settings();
+        if !settings.get_enable_vector_index()? || info.limit == 0 {
+            return Ok(None);
+        }
+        let ef_search = std::cmp::max(settings.get_vector_index_ef_search()? as usize, info.limit);
+        let max_concurrency = std::cmp::max(settings.get_max_storage_io_requests()? as usize, 10);
+
+        Ok(Some(Arc::new(VectorIndexPruner {
+            dal,
+            info: info.clone(),
+            query: info.query_values.iter().map(|v| v.0).collect(),
+            ef_search,
+            max_concurrency,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn prune(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+        pruning_stats: &FusePruningStatistics,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let blocks_before = metas.len();
+        let mut keep = vec![false; metas.len()];
+        // (distance, position of the block in metas)
+        let mut candidates = Vec::new();
+
+        // The indexes are loaded and searched concurrently, bounded like the other pruners.
+        let results = futures::stream::iter(metas.iter().enumerate())
+            .map(|(pos, (_, block_meta))| async move { (pos, self.search_block(block_meta).await) })
+            .buffer_unordered(self.max_concurrency)
+            .collect::<Vec<_>>()
+            .await;
+        for (pos, distances) in results {
+            match distances {
+                Some(distances) => {
+                    candidates.extend(distances.into_iter().map(|distance| (distance, pos)));
+                }
+                None => keep[pos] = true,
+            }
+        }
+
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+        for (_, pos) in candidates.into_iter().take(self.info.limit) {
+            keep[pos] = true;
+        }
+
+        let res = metas
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(meta, keep)| keep.then_some(meta))
+            .collect::<Vec<_>>();
+
+        pruning_stats.set_blocks_vector_index_pruning_before(blocks_before as u64);
+        pruning_stats.set_blocks_vector_index_pruning_after(res.len() as u64);
+        Ok(res)
+    }
+
+    // Returns the distances of the nearest vectors in the block,
+    // or None if the block has no complete index and must be kept.
+    async fn search_block(&self, block_meta: &BlockMeta) -> Option<Vec<f32>> {
+        let index_location =
+            TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                &block_meta.location.0,
+                &self.info.index_name,
+                &self.info.index_version,
+            );
+        let index = self.load_index(&index_location).await?;
+        if index.len() < block_meta.row_count as usize {
+            return None;
+        }
+        let res = index
+            .search(&self.query, self.info.limit, self.ef_search)
+            .ok()?;
+        Some(res.into_iter().map(|(_, distance)| distance).collect())
+    }
+
+    async fn load_index(&self, location: &str) -> Option<HnswIndex> {
+        let buffer = match self.dal.read(location).await {
+            Ok(buffer) => buffer,
+            Err(e) => {
+                info!("vector index file {} can not be read: {}", location, e);
+                return None;
+            }
+        };
+        match HnswIndex::from_bytes(&buffer.to_vec()) {
+            Ok(index) => Some(index),
+            Err(e) => {
+                info!("vector index file {} is invalid: {}", location, e);
+                None
+            }
+        }
+    }
+}
@@ -15,7 +15,9 @@
 pub mod accumulator;
 mod cluster_statistics;
 mod column_statistic;
+mod partition_statistics;
 pub mod reducers;
+mod spatial_statistic;
 
 pub use accumulator::StatisticsAccumulator;
 pub use cluster_statistics::sort_by_cluster_stats;
@@ -28,7 +30,10 @@ pub use column_statistic::traverse;
 pub use column_statistic::Trim;
 pub use column_statistic::STATS_REPLACEMENT_CHAR;
 pub use column_statistic::STATS_STRING_PREFIX_LEN;
+pub use partition_statistics::resolve_columns;
+pub use partition_statistics::PartitionGenerator;
 pub use reducers::merge_statistics;
 pub use reducers::reduce_block_metas;
 pub use reducers::reduce_block_statistics;
 pub use reducers::reduce_cluster_statistics;
+pub use spatial_statistic::gen_spatial_statistics;
@@ -0,0 +1,145 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::types::AnyType;
+use bigbytesdb_common_expression::types::BooleanType;
+use bigbytesdb_common_expression::DataBlock;
+use bigbytesdb_common_expression::Evaluator;
+use bigbytesdb_common_expression::Expr;
+use bigbytesdb_common_expression::FunctionContext;
+use bigbytesdb_common_expression::Scalar;
+use bigbytesdb_common_expression::TableSchema;
+use bigbytesdb_common_expression::Value;
+use bigbytesdb_common_functions::BUILTIN_FUNCTIONS;
+
+/// Evaluates the `PARTITION BY` expression of a table on the blocks to be written.
+#[derive(Clone, Default)]
+pub struct PartitionGenerator {
+    expr: Option<Expr>,
+    // The predicate of `INSERT OVERWRITE ... PARTITION`, the rows must match it.
+    check: Option<Expr>,
+    func_ctx: FunctionContext,
+}
+
+impl PartitionGenerator {
+    pub fn new(expr: Expr, func_ctx: FunctionContext) -> Self {
+        Self {
+            expr: Some(expr),
+            check: None,
+            func_ctx,
+        }
+    }
+
+    pub fn with_check(mut self, check: Option<Expr>) -> Self {
+        self.check = check;
+        self
+    }
+
+    pub fn is_partitioned(&self) -> bool {
+        self.expr.is_some()
+    }
+
+    /// Returns the partition of the block, None if the rows belong to different partitions.
+    pub fn gen_partition(&self, data_block: &DataBlock) -> Result<Option<Scalar>> {
+        let Some(expr) = &self.expr else {
+            return Ok(None);
+        };
+        match self.eval(expr, data_block)? {
+            Value::Scalar(scalar) => Ok(Some(scalar)),
+            Value::Column(column) => {
+                let mut iter = column.iter();
+                let Some(first) = iter.next() else {
+                    return Ok(None);
+                };
+                if iter.all(|v| v == first) {
+                    Ok(Some(first.to_owned()))
+                } else {
+                    Ok(None)
+                }
+            }
+        }
+    }
+
+    /// Fails if any row of the block does not match the check predicate.
+    pub fn check(&self, data_block: &DataBlock) -> Result<()> {
+        let Some(check) = &self.check else {
+            return Ok(());
+        };
+        let mismatched = match self.eval(check, data_block)?.try_downcast::<BooleanType>() {
+            Some(Value::Scalar(true)) => 0,
+            Some(Value::Scalar(false)) => data_block.num_rows(),
+            Some(Value::Column(bitmap)) => bitmap.null_count(),
+            None => {
+                return Err(ErrorCode::Internal(format!(
+                    "partition check {} is not a boolean",
+                    check.sql_display()
+                )));
+            }
+        };
+        if mismatched > 0 {
+            return Err(ErrorCode::BadArguments(format!(
+                "{} of the inserted rows do not belong to the overwritten partition",
+                mismatched
+            )));
+        }
+        Ok(())
+    }
+
+    /// Splits the block so that each part belongs to a single partition.
+    pub fn split(&self, data_block: DataBlock) -> Result<Vec<DataBlock>> {
+        self.check(&data_block)?;
+        let Some(expr) = &self.expr else {
+            return Ok(vec![data_block]);
+        };
+        let Value::Column(column) = self.eval(expr, &data_block)? else {
+            return Ok(vec![data_block]);
+        };
+
+        let mut partitions = BTreeMap::new();
+        let indices = column
+            .iter()
+            .map(|v| {
+                let next = partitions.len() as u32;
+                *partitions.entry(v.to_owned()).or_insert(next)
+            })
+            .collect::<Vec<_>>();
+        if partitions.len() <= 1 {
+            return Ok(vec![data_block]);
+        }
+        data_block.scatter(&indices, partitions.len())
+    }
+
+    fn eval(&self, expr: &Expr, data_block: &DataBlock) -> Result<Value<AnyType>> {
+        let evaluator = Evaluator::new(data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        evaluator.run(expr)
+    }
+}
+
+/// Resolves the columns of an expression by name in the schema of the written blocks.
+pub fn resolve_columns(expr: Expr<String>, schema: &TableSchema) -> Result<Expr> {
+    let indices = expr
+        .column_refs()
+        .into_keys()
+        .map(|name| {
+            let index = schema.index_of(&name)?;
+            Ok((name, index))
+        })
+        .collect::<Result<HashMap<_, _>>>()?;
+    Ok(expr.project_column_ref(|name| indices[name]))
+}
@@ -129,6 +129,15 @@ pub fn reduce_cluster_statistics<T: Borrow<Option<ClusterStatistics>>>(
     ))
 }
 
+/// The partition is kept only if all the inputs belong to the same one.
+pub fn reduce_partition<T: Borrow<Option<Scalar>>>(partitions: &[T]) -> Option<Scalar> {
+    let (first, rest) = partitions.split_first()?;
+    let first = first.borrow().as_ref()?;
+    rest.iter()
+        .all(|p| p.borrow().as_ref() == Some(first))
+        .then(|| first.clone())
+}
+
 pub fn merge_statistics(
     mut l: Statistics,
     r: &Statistics,
@@ -146,12 +155,14 @@ pub fn merge_statistics_mut(
     if l.row_count == 0 {
         l.col_stats = r.col_stats.clone();
         l.cluster_stats = r.cluster_stats.clone();
+        l.partition = r.partition.clone();
     } else {
         l.col_stats = reduce_block_statistics(&[&l.col_stats, &r.col_stats]);
         l.cluster_stats = reduce_cluster_statistics(
             &[&l.cluster_stats, &r.cluster_stats],
             default_cluster_key_id,
         );
+        l.partition = reduce_partition(&[&l.partition, &r.partition]);
     }
 
     l.row_count += r.row_count;
@@ -202,6 +213,7 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
     let len = block_metas.len();
     let mut col_stats = Vec::with_capacity(len);
     let mut cluster_stats = Vec::with_capacity(len);
+    let mut partitions = Vec::with_capacity(len);
 
     block_metas.iter().for_each(|b| {
         let b = b.borrow();
@@ -218,6 +230,7 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
         }
         col_stats.push(&b.col_stats);
         cluster_stats.push(&b.cluster_stats);
+        partitions.push(&b.partition);
     });
 
     let merged_col_stats = reduce_block_statistics(&col_stats);
@@ -232,5 +245,6 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
         index_size,
         col_stats: merged_col_stats,
         cluster_stats: merged_cluster_stats,
+        partition: reduce_partition(&partitions),
     }
 }
@@ -0,0 +1,62 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::types::DataType;
+use bigbytesdb_common_expression::DataBlock;
+use bigbytesdb_common_expression::ScalarRef;
+use bigbytesdb_common_expression::TableSchemaRef;
+use bigbytesdb_common_io::ewkb_bounding_rect;
+use bigbytesdb_storages_common_table_meta::meta::SpatialStatistics;
+use bigbytesdb_storages_common_table_meta::meta::SpatialStatisticsOfColumns;
+
+/// Generates the bounding box of each top-level Geometry column in the block.
+///
+/// Columns without any non-empty value have no statistics,
+/// blocks are never pruned by the spatial index for such columns.
+pub fn gen_spatial_statistics(
+    data_block: &DataBlock,
+    schema: &TableSchemaRef,
+) -> Result<SpatialStatisticsOfColumns> {
+    let mut statistics = SpatialStatisticsOfColumns::new();
+    let rows = data_block.num_rows();
+    for (idx, field) in schema.fields().iter().enumerate() {
+        let data_type: DataType = field.data_type().into();
+        if data_type.remove_nullable() != DataType::Geometry {
+            continue;
+        }
+
+        let column = data_block.get_by_offset(idx).to_column(rows);
+        let mut bbox: Option<SpatialStatistics> = None;
+        for value in column.iter() {
+            let ScalarRef::Geometry(ewkb) = value else {
+                continue;
+            };
+            let Some(rect) = ewkb_bounding_rect(ewkb)? else {
+                continue;
+            };
+            let (min, max) = (rect.min(), rect.max());
+            let value_bbox = SpatialStatistics::new(min.x, min.y, max.x, max.y);
+            match bbox.as_mut() {
+                Some(bbox) => bbox.merge(&value_bbox),
+                None => bbox = Some(value_bbox),
+            }
+        }
+
+        if let Some(bbox) = bbox {
+            statistics.insert(field.column_id(), bbox);
+        }
+    }
+    Ok(statistics)
+}
@@ -59,7 +59,8 @@ impl ResultCacheMetaManager {
 
     #[async_backtrace::framed]
     pub async fn get(&self, key: String) -> Result<Option<ResultCacheValue>> {
-        let raw = self.inner.get_kv(&key).await?;
+        // A stale entry is fine, it is checked against the partitions the query reads.
+        let raw = self.inner.stale().get_kv(&key).await?;
         match raw {
             None => Ok(None),
             Some(SeqV { data, .. }) => {
@@ -71,7 +72,7 @@ impl ResultCacheMetaManager {
 
     #[async_backtrace::framed]
     pub async fn list(&self, prefix: &str) -> Result<Vec<ResultCacheValue>> {
-        let result = self.inner.prefix_list_kv(prefix).await?;
+        let result = self.inner.stale().prefix_list_kv(prefix).await?;
 
         let mut r = vec![];
         for (_key, val) in result {
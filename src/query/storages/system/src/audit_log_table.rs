@@ -0,0 +1,132 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::types::number::NumberScalar;
+use bigbytesdb_common_expression::types::NumberDataType;
+use bigbytesdb_common_expression::ColumnBuilder;
+use bigbytesdb_common_expression::Scalar;
+use bigbytesdb_common_expression::TableDataType;
+use bigbytesdb_common_expression::TableField;
+use bigbytesdb_common_expression::TableSchemaRef;
+use bigbytesdb_common_expression::TableSchemaRefExt;
+use serde::Serialize;
+
+use crate::query_log_table::datetime_str;
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// A DDL or privilege change, with the state of the changed object before and after it.
+#[derive(Clone, Serialize)]
+pub struct AuditLogElement {
+    #[serde(serialize_with = "datetime_str")]
+    pub event_time: i64,
+    pub tenant_id: String,
+    pub cluster_id: String,
+    pub node_id: String,
+    pub query_id: String,
+    pub user_name: String,
+    pub client_ip: String,
+    // Such as `CREATE TABLE` or `GRANT`.
+    pub action: String,
+    // Such as `TABLE`, `USER` or `DATA MASK`.
+    pub object_type: String,
+    pub object_name: String,
+    pub object_id: Option<u64>,
+    pub statement: String,
+    // The json of the object before the change, `None` if it did not exist.
+    pub old_value: Option<String>,
+    // The json of the object after the change, `None` if it does not exist any more.
+    pub new_value: Option<String>,
+}
+
+impl SystemLogElement for AuditLogElement {
+    const TABLE_NAME: &'static str = "audit_log";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("tenant_id", TableDataType::String),
+            TableField::new("cluster_id", TableDataType::String),
+            TableField::new("node_id", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("user_name", TableDataType::String),
+            TableField::new("client_ip", TableDataType::String),
+            TableField::new("action", TableDataType::String),
+            TableField::new("object_type", TableDataType::String),
+            TableField::new("object_name", TableDataType::String),
+            TableField::new(
+                "object_id",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            TableField::new("statement", TableDataType::String),
+            TableField::new(
+                "old_value",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "new_value",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.event_time).as_ref());
+        for value in [
+            &self.tenant_id,
+            &self.cluster_id,
+            &self.node_id,
+            &self.query_id,
+            &self.user_name,
+            &self.client_ip,
+            &self.action,
+            &self.object_type,
+            &self.object_name,
+        ] {
+            columns
+                .next()
+                .unwrap()
+                .push(Scalar::String(value.clone()).as_ref());
+        }
+        columns.next().unwrap().push(
+            self.object_id
+                .map(|id| Scalar::Number(NumberScalar::UInt64(id)))
+                .unwrap_or(Scalar::Null)
+                .as_ref(),
+        );
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.statement.clone()).as_ref());
+        for value in [&self.old_value, &self.new_value] {
+            columns.next().unwrap().push(
+                value
+                    .clone()
+                    .map(Scalar::String)
+                    .unwrap_or(Scalar::Null)
+                    .as_ref(),
+            );
+        }
+        Ok(())
+    }
+}
+
+pub type AuditLogQueue = SystemLogQueue<AuditLogElement>;
+pub type AuditLogTable = SystemLogTable<AuditLogElement>;
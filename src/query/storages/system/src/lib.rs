@@ -20,6 +20,7 @@
 
 extern crate core;
 
+mod audit_log_table;
 mod background_jobs_table;
 mod background_tasks_table;
 mod backtrace_table;
@@ -43,11 +44,13 @@ mod log_queue;
 mod malloc_stats_table;
 #[cfg(feature = "jemalloc")]
 mod malloc_stats_totals_table;
+mod materialized_views_table;
 mod metrics_table;
 mod notification_history_table;
 mod notifications_table;
 mod one_table;
 mod password_policies_table;
+mod plan_baselines_table;
 mod procedures_table;
 mod processes_table;
 mod queries_profiling;
@@ -68,7 +71,11 @@ mod user_functions_table;
 mod users_table;
 mod util;
 mod virtual_columns_table;
+mod workload_groups_table;
 
+pub use audit_log_table::AuditLogElement;
+pub use audit_log_table::AuditLogQueue;
+pub use audit_log_table::AuditLogTable;
 pub use background_jobs_table::BackgroundJobTable;
 pub use background_tasks_table::BackgroundTaskTable;
 pub use backtrace_table::BacktraceTable;
@@ -98,12 +105,14 @@ pub use log_queue::SystemLogTable;
 pub use malloc_stats_table::MallocStatsTable;
 #[cfg(feature = "jemalloc")]
 pub use malloc_stats_totals_table::MallocStatsTotalsTable;
+pub use materialized_views_table::MaterializedViewsTable;
 pub use metrics_table::MetricsTable;
 pub use notification_history_table::NotificationHistoryTable;
 pub use notifications_table::parse_notifications_to_datablock;
 pub use notifications_table::NotificationsTable;
 pub use one_table::OneTable;
 pub use password_policies_table::PasswordPoliciesTable;
+pub use plan_baselines_table::PlanBaselinesTable;
 pub use procedures_table::ProceduresTable;
 pub use processes_table::ProcessesTable;
 pub use queries_profiling::ProfilesLogElement;
@@ -136,3 +145,4 @@ pub use temporary_tables_table::TemporaryTablesTable;
 pub use user_functions_table::UserFunctionsTable;
 pub use users_table::UsersTable;
 pub use virtual_columns_table::VirtualColumnsTable;
+pub use workload_groups_table::WorkloadGroupsTable;
@@ -0,0 +1,189 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bigbytesdb_common_catalog::catalog::CATALOG_DEFAULT;
+use bigbytesdb_common_catalog::plan::PushDownInfo;
+use bigbytesdb_common_catalog::table::Table;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::types::BooleanType;
+use bigbytesdb_common_expression::types::NumberDataType;
+use bigbytesdb_common_expression::types::StringType;
+use bigbytesdb_common_expression::types::TimestampType;
+use bigbytesdb_common_expression::types::UInt64Type;
+use bigbytesdb_common_expression::DataBlock;
+use bigbytesdb_common_expression::FromData;
+use bigbytesdb_common_expression::TableDataType;
+use bigbytesdb_common_expression::TableField;
+use bigbytesdb_common_expression::TableSchemaRefExt;
+use bigbytesdb_common_meta_app::schema::TableIdent;
+use bigbytesdb_common_meta_app::schema::TableInfo;
+use bigbytesdb_common_meta_app::schema::TableMeta;
+use bigbytesdb_common_sql::is_materialized_view_fresh;
+use bigbytesdb_common_sql::materialized_view_refresh_state;
+use bigbytesdb_common_storages_fuse::TableContext;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE;
+use bigbytesdb_storages_common_table_meta::table::OPT_KEY_MAX_STALENESS;
+use bigbytesdb_storages_common_table_meta::table::REFRESH_MODE_AUTO;
+use log::warn;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct MaterializedViewsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for MaterializedViewsTable {
+    const NAME: &'static str = "system.materialized_views";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let visibility_checker = ctx.get_visibility_checker(false).await?;
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+        let ctl_name = catalog.name();
+
+        let mut databases = vec![];
+        let mut names = vec![];
+        let mut queries = vec![];
+        let mut refresh_modes = vec![];
+        let mut max_stalenesses = vec![];
+        let mut last_refresh_modes = vec![];
+        let mut refreshed_ons = vec![];
+        let mut is_freshes = vec![];
+
+        for db in catalog.list_databases(&tenant).await? {
+            let db_id = db.get_db_info().database_id.db_id;
+            let db_name = db.name();
+            if !visibility_checker.check_database_visibility(&ctl_name, db_name, db_id) {
+                continue;
+            }
+            let tables = match catalog.list_tables(&tenant, db_name).await {
+                Ok(tables) => tables,
+                Err(err) => {
+                    let msg = format!("Failed to list tables in database: {}, {}", db_name, err);
+                    warn!("{}", msg);
+                    ctx.push_warning(msg);
+                    continue;
+                }
+            };
+            for table in tables {
+                let table_info = table.get_table_info();
+                let options = table_info.options();
+                let Some(query) = options.get(OPT_KEY_MATERIALIZED_VIEW_QUERY) else {
+                    continue;
+                };
+                if !visibility_checker.check_table_visibility(
+                    &ctl_name,
+                    db_name,
+                    table.name(),
+                    db_id,
+                    table.get_id(),
+                ) {
+                    continue;
+                }
+
+                let state = materialized_view_refresh_state(table_info)?;
+                databases.push(db_name.to_string());
+                names.push(table.name().to_string());
+                queries.push(query.clone());
+                refresh_modes.push(
+                    options
+                        .get(OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE)
+                        .map(String::as_str)
+                        .unwrap_or(REFRESH_MODE_AUTO)
+                        .to_uppercase(),
+                );
+                max_stalenesses.push(
+                    options
+                        .get(OPT_KEY_MAX_STALENESS)
+                        .and_then(|secs| secs.parse::<u64>().ok()),
+                );
+                last_refresh_modes.push(
+                    state
+                        .as_ref()
+                        .map(|state| state.refresh_mode.to_uppercase()),
+                );
+                refreshed_ons.push(
+                    state
+                        .as_ref()
+                        .map(|state| state.refreshed_on.timestamp_micros()),
+                );
+                is_freshes.push(is_materialized_view_fresh(&catalog, table_info).await?);
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(databases),
+            StringType::from_data(names),
+            StringType::from_data(queries),
+            StringType::from_data(refresh_modes),
+            UInt64Type::from_opt_data(max_stalenesses),
+            StringType::from_opt_data(last_refresh_modes),
+            TimestampType::from_opt_data(refreshed_ons),
+            BooleanType::from_data(is_freshes),
+        ]))
+    }
+}
+
+impl MaterializedViewsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("database", TableDataType::String),
+            TableField::new("name", TableDataType::String),
+            TableField::new("query", TableDataType::String),
+            TableField::new("refresh_mode", TableDataType::String),
+            TableField::new(
+                "max_staleness",
+                TableDataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+            TableField::new(
+                "last_refresh_mode",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "refreshed_on",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+            TableField::new("is_fresh", TableDataType::Boolean),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'materialized_views'".to_string(),
+            name: "materialized_views".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemMaterializedViews".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bigbytesdb_common_catalog::plan::PushDownInfo;
+use bigbytesdb_common_catalog::table::Table;
+use bigbytesdb_common_catalog::table_context::TableContext;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::types::StringType;
+use bigbytesdb_common_expression::types::TimestampType;
+use bigbytesdb_common_expression::utils::FromData;
+use bigbytesdb_common_expression::DataBlock;
+use bigbytesdb_common_expression::TableDataType;
+use bigbytesdb_common_expression::TableField;
+use bigbytesdb_common_expression::TableSchemaRefExt;
+use bigbytesdb_common_meta_app::schema::TableIdent;
+use bigbytesdb_common_meta_app::schema::TableInfo;
+use bigbytesdb_common_meta_app::schema::TableMeta;
+use bigbytesdb_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct PlanBaselinesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for PlanBaselinesTable {
+    const NAME: &'static str = "system.plan_baselines";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let plan_baselines = UserApiProvider::instance()
+            .get_plan_baselines(&tenant)
+            .await?;
+
+        let mut names = Vec::with_capacity(plan_baselines.len());
+        let mut fingerprints = Vec::with_capacity(plan_baselines.len());
+        let mut queries = Vec::with_capacity(plan_baselines.len());
+        let mut plan_hints = Vec::with_capacity(plan_baselines.len());
+        let mut created_on_columns = Vec::with_capacity(plan_baselines.len());
+        for plan_baseline in plan_baselines {
+            names.push(plan_baseline.name);
+            fingerprints.push(plan_baseline.fingerprint);
+            queries.push(plan_baseline.query);
+            plan_hints.push(plan_baseline.plan_hints);
+            created_on_columns.push(plan_baseline.created_on.timestamp_micros());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(fingerprints),
+            StringType::from_data(queries),
+            StringType::from_data(plan_hints),
+            TimestampType::from_data(created_on_columns),
+        ]))
+    }
+}
+
+impl PlanBaselinesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("fingerprint", TableDataType::String),
+            TableField::new("query", TableDataType::String),
+            TableField::new("plan_hints", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'plan_baselines'".to_string(),
+            name: "plan_baselines".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPlanBaselines".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(PlanBaselinesTable { table_info })
+    }
+}
@@ -73,7 +73,7 @@ where S: Serializer {
     s.serialize_str(t.format("%Y-%m-%d").to_string().as_str())
 }
 
-fn datetime_str<S>(dt: &i64, s: S) -> std::result::Result<S::Ok, S::Error>
+pub(crate) fn datetime_str<S>(dt: &i64, s: S) -> std::result::Result<S::Ok, S::Error>
 where S: Serializer {
     let t = DateTime::from_timestamp(
         dt / 1_000_000,
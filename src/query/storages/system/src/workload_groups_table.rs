@@ -0,0 +1,173 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use bigbytesdb_common_catalog::plan::PushDownInfo;
+use bigbytesdb_common_catalog::table::Table;
+use bigbytesdb_common_catalog::table_context::TableContext;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_expression::types::NumberDataType;
+use bigbytesdb_common_expression::types::StringType;
+use bigbytesdb_common_expression::types::TimestampType;
+use bigbytesdb_common_expression::types::UInt64Type;
+use bigbytesdb_common_expression::utils::FromData;
+use bigbytesdb_common_expression::DataBlock;
+use bigbytesdb_common_expression::TableDataType;
+use bigbytesdb_common_expression::TableField;
+use bigbytesdb_common_expression::TableSchemaRefExt;
+use bigbytesdb_common_meta_app::schema::TableIdent;
+use bigbytesdb_common_meta_app::schema::TableInfo;
+use bigbytesdb_common_meta_app::schema::TableMeta;
+use bigbytesdb_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct WorkloadGroupsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for WorkloadGroupsTable {
+    const NAME: &'static str = "system.workload_groups";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let workload_groups = UserApiProvider::instance()
+            .get_workload_groups(&tenant)
+            .await?;
+        // The runtime statistics of the groups on the local node.
+        let status = ctx
+            .get_workload_groups_status()
+            .into_iter()
+            .map(|status| (status.name.clone(), status))
+            .collect::<HashMap<_, _>>();
+
+        let len = workload_groups.len();
+        let mut names = Vec::with_capacity(len);
+        let mut max_concurrency = Vec::with_capacity(len);
+        let mut max_queued = Vec::with_capacity(len);
+        let mut queued_timeout = Vec::with_capacity(len);
+        let mut max_memory_usage = Vec::with_capacity(len);
+        let mut cpu_weight = Vec::with_capacity(len);
+        let mut running = Vec::with_capacity(len);
+        let mut queued = Vec::with_capacity(len);
+        let mut total_queries = Vec::with_capacity(len);
+        let mut rejected_queries = Vec::with_capacity(len);
+        let mut timeout_queries = Vec::with_capacity(len);
+        let mut memory_usage = Vec::with_capacity(len);
+        let mut created_on_columns = Vec::with_capacity(len);
+        let mut updated_on_columns = Vec::with_capacity(len);
+        for workload_group in workload_groups {
+            let status = status.get(&workload_group.name);
+            running.push(status.map_or(0, |s| s.running_queries));
+            queued.push(status.map_or(0, |s| s.queued_queries));
+            total_queries.push(status.map_or(0, |s| s.total_queries));
+            rejected_queries.push(status.map_or(0, |s| s.rejected_queries));
+            timeout_queries.push(status.map_or(0, |s| s.timeout_queries));
+            memory_usage.push(status.map_or(0, |s| s.memory_usage.max(0) as u64));
+
+            names.push(workload_group.name);
+            max_concurrency.push(workload_group.max_concurrency);
+            max_queued.push(workload_group.max_queued);
+            queued_timeout.push(workload_group.queued_timeout_secs);
+            max_memory_usage.push(workload_group.max_memory_usage);
+            cpu_weight.push(workload_group.cpu_weight);
+            created_on_columns.push(workload_group.created_on.timestamp_micros());
+            updated_on_columns.push(workload_group.updated_on.timestamp_micros());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            UInt64Type::from_data(max_concurrency),
+            UInt64Type::from_data(max_queued),
+            UInt64Type::from_data(queued_timeout),
+            UInt64Type::from_data(max_memory_usage),
+            UInt64Type::from_data(cpu_weight),
+            UInt64Type::from_data(running),
+            UInt64Type::from_data(queued),
+            UInt64Type::from_data(total_queries),
+            UInt64Type::from_data(rejected_queries),
+            UInt64Type::from_data(timeout_queries),
+            UInt64Type::from_data(memory_usage),
+            TimestampType::from_data(created_on_columns),
+            TimestampType::from_data(updated_on_columns),
+        ]))
+    }
+}
+
+impl WorkloadGroupsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new(
+                "max_concurrency",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("max_queued", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "queued_timeout",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "max_memory_usage",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("cpu_weight", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("running", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("queued", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "total_queries",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "rejected_queries",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "timeout_queries",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "memory_usage",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("updated_on", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'workload_groups'".to_string(),
+            name: "workload_groups".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemWorkloadGroups".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(WorkloadGroupsTable { table_info })
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_meta_app::principal::PlanBaseline;
+use bigbytesdb_common_meta_app::schema::CreateOption;
+use bigbytesdb_common_meta_app::tenant::Tenant;
+
+use crate::UserApiProvider;
+
+/// How long the cached plan baselines of a tenant are served before reloading them,
+/// the plan baselines changed by the other query nodes are seen after at most this long.
+const PLAN_BASELINE_CACHE_TTL: Duration = Duration::from_secs(15);
+
+/// The plan baselines of a tenant keyed by the query fingerprint.
+pub(crate) struct CachedPlanBaselines {
+    plan_baselines: HashMap<String, PlanBaseline>,
+    cached_at: Instant,
+}
+
+/// plan baseline operations.
+impl UserApiProvider {
+    // Add a new plan baseline.
+    #[async_backtrace::framed]
+    pub async fn add_plan_baseline(
+        &self,
+        tenant: &Tenant,
+        plan_baseline: PlanBaseline,
+        create_option: &CreateOption,
+    ) -> Result<()> {
+        let plan_baseline_api = self.plan_baseline_api(tenant);
+        let res = plan_baseline_api.add(plan_baseline, create_option).await;
+        self.invalidate_plan_baselines(tenant);
+        res
+    }
+
+    // Get all the plan baselines of the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_plan_baselines(&self, tenant: &Tenant) -> Result<Vec<PlanBaseline>> {
+        let plan_baseline_api = self.plan_baseline_api(tenant);
+        plan_baseline_api
+            .list()
+            .await
+            .map_err(|e| e.add_message_back(" (while get plan baselines)"))
+    }
+
+    // Get the plan baseline pinned for the query fingerprint.
+    //
+    // Every query that may join looks it up, so it is served from the plan baselines of
+    // the tenant cached for `PLAN_BASELINE_CACHE_TTL` instead of reading the meta.
+    #[async_backtrace::framed]
+    pub async fn get_plan_baseline_by_fingerprint(
+        &self,
+        tenant: &Tenant,
+        fingerprint: &str,
+    ) -> Result<Option<PlanBaseline>> {
+        {
+            let cache = self.plan_baseline_cache.read();
+            if let Some(cached) = cache.get(tenant) {
+                if cached.cached_at.elapsed() < PLAN_BASELINE_CACHE_TTL {
+                    return Ok(cached.plan_baselines.get(fingerprint).cloned());
+                }
+            }
+        }
+
+        // A plan baseline created or dropped during the reload invalidates what is loaded.
+        let version = self.plan_baseline_cache_version.load(Ordering::Acquire);
+        let cached_at = Instant::now();
+        let plan_baselines = self
+            .get_plan_baselines(tenant)
+            .await?
+            .into_iter()
+            .map(|plan_baseline| (plan_baseline.fingerprint.clone(), plan_baseline))
+            .collect::<HashMap<_, _>>();
+        let plan_baseline = plan_baselines.get(fingerprint).cloned();
+        let mut cache = self.plan_baseline_cache.write();
+        if self.plan_baseline_cache_version.load(Ordering::Acquire) == version {
+            cache.insert(tenant.clone(), CachedPlanBaselines {
+                plan_baselines,
+                cached_at,
+            });
+        }
+        Ok(plan_baseline)
+    }
+
+    // Drop a plan baseline by name.
+    #[async_backtrace::framed]
+    pub async fn drop_plan_baseline(
+        &self,
+        tenant: &Tenant,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let plan_baseline_api = self.plan_baseline_api(tenant);
+        let res = plan_baseline_api.remove(name).await;
+        self.invalidate_plan_baselines(tenant);
+        match res {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_PLAN_BASELINE {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop plan baseline)"))
+                }
+            }
+        }
+    }
+
+    // Drop the cached plan baselines of the tenant, they are reloaded on the next lookup.
+    fn invalidate_plan_baselines(&self, tenant: &Tenant) {
+        let mut cache = self.plan_baseline_cache.write();
+        self.plan_baseline_cache_version
+            .fetch_add(1, Ordering::AcqRel);
+        cache.remove(tenant);
+    }
+}
@@ -296,6 +296,27 @@ impl UserApiProvider {
             .map_err(|e| e.add_message_back("(while revoke role from role)"))
     }
 
+    // Set or unset the workload group of a role.
+    #[async_backtrace::framed]
+    pub async fn set_role_workload_group(
+        &self,
+        tenant: &Tenant,
+        role: &String,
+        workload_group: Option<String>,
+    ) -> Result<Option<u64>> {
+        if let Some(name) = &workload_group {
+            self.get_workload_group(tenant, name).await?;
+        }
+        let client = self.role_api(tenant);
+        client
+            .update_role_with(role, MatchSeq::GE(1), move |ri: &mut RoleInfo| {
+                ri.update_role_time();
+                ri.workload_group = workload_group;
+            })
+            .await
+            .map_err(|e| e.add_message_back("(while set role workload group)"))
+    }
+
     // Drop a role by name
     #[async_backtrace::framed]
     pub async fn drop_role(&self, tenant: &Tenant, role: String, if_exists: bool) -> Result<()> {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 
 use bigbytesdb_common_base::base::GlobalInstance;
@@ -25,6 +26,7 @@ use bigbytesdb_common_management::ConnectionMgr;
 use bigbytesdb_common_management::FileFormatMgr;
 use bigbytesdb_common_management::NetworkPolicyMgr;
 use bigbytesdb_common_management::PasswordPolicyMgr;
+use bigbytesdb_common_management::PlanBaselineMgr;
 use bigbytesdb_common_management::ProcedureMgr;
 use bigbytesdb_common_management::QuotaApi;
 use bigbytesdb_common_management::QuotaMgr;
@@ -35,6 +37,7 @@ use bigbytesdb_common_management::StageApi;
 use bigbytesdb_common_management::StageMgr;
 use bigbytesdb_common_management::UserApi;
 use bigbytesdb_common_management::UserMgr;
+use bigbytesdb_common_management::WorkloadGroupMgr;
 use bigbytesdb_common_meta_app::principal::AuthInfo;
 use bigbytesdb_common_meta_app::principal::RoleInfo;
 use bigbytesdb_common_meta_app::principal::UserDefinedFunction;
@@ -45,14 +48,18 @@ use bigbytesdb_common_meta_store::MetaStore;
 use bigbytesdb_common_meta_store::MetaStoreProvider;
 use bigbytesdb_common_meta_types::MatchSeq;
 use bigbytesdb_common_meta_types::MetaError;
+use parking_lot::RwLock;
 
 use crate::builtin::BuiltIn;
+use crate::plan_baseline::CachedPlanBaselines;
 use crate::BUILTIN_ROLE_PUBLIC;
 
 pub struct UserApiProvider {
     meta: MetaStore,
     client: Arc<dyn kvapi::KVApi<Error = MetaError> + Send + Sync>,
     builtin: BuiltIn,
+    pub(crate) plan_baseline_cache: RwLock<HashMap<Tenant, CachedPlanBaselines>>,
+    pub(crate) plan_baseline_cache_version: AtomicU64,
 }
 
 impl UserApiProvider {
@@ -84,6 +91,8 @@ impl UserApiProvider {
             meta: client.clone(),
             client: client.arc(),
             builtin,
+            plan_baseline_cache: RwLock::new(HashMap::new()),
+            plan_baseline_cache_version: AtomicU64::new(0),
         };
 
         // init built-in role
@@ -166,6 +175,14 @@ impl UserApiProvider {
         PasswordPolicyMgr::create(self.client.clone(), tenant)
     }
 
+    pub fn plan_baseline_api(&self, tenant: &Tenant) -> PlanBaselineMgr {
+        PlanBaselineMgr::create(self.client.clone(), tenant)
+    }
+
+    pub fn workload_group_api(&self, tenant: &Tenant) -> WorkloadGroupMgr {
+        WorkloadGroupMgr::create(self.client.clone(), tenant)
+    }
+
     pub fn client_session_api(&self, tenant: &Tenant) -> ClientSessionMgr {
         ClientSessionMgr::create(self.client.clone(), tenant)
     }
@@ -177,6 +177,9 @@ impl UserApiProvider {
                 )));
             }
         }
+        if let Some(name) = user_info.option.workload_group() {
+            self.get_workload_group(tenant, name).await?;
+        }
         if self.get_configured_user(&user_info.name).is_some() {
             return Err(ErrorCode::UserAlreadyExists(format!(
                 "Same name with configured user `{}`",
@@ -347,6 +350,9 @@ impl UserApiProvider {
                     )));
                 }
             }
+            if let Some(name) = user_option.workload_group() {
+                self.get_workload_group(tenant, name).await?;
+            }
         }
         if self.get_configured_user(&user.username).is_some() {
             return Err(ErrorCode::UserAlreadyExists(format!(
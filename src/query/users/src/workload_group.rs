@@ -0,0 +1,138 @@
+// Copyright 2024 Digitrans Inc
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use bigbytesdb_common_exception::ErrorCode;
+use bigbytesdb_common_exception::Result;
+use bigbytesdb_common_meta_api::crud::CrudError;
+use bigbytesdb_common_meta_app::principal::WorkloadGroup;
+use bigbytesdb_common_meta_app::schema::CreateOption;
+use bigbytesdb_common_meta_app::tenant::Tenant;
+use bigbytesdb_common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// workload group operations.
+impl UserApiProvider {
+    // Add a new workload group.
+    #[async_backtrace::framed]
+    pub async fn add_workload_group(
+        &self,
+        tenant: &Tenant,
+        workload_group: WorkloadGroup,
+        create_option: &CreateOption,
+    ) -> Result<()> {
+        let workload_group_api = self.workload_group_api(tenant);
+        workload_group_api
+            .add(workload_group, create_option)
+            .await?;
+        Ok(())
+    }
+
+    // Update a workload group with `update`, returns None if it does not exist and `if_exists` is set.
+    #[async_backtrace::framed]
+    pub async fn update_workload_group(
+        &self,
+        tenant: &Tenant,
+        name: &str,
+        if_exists: bool,
+        update: impl FnOnce(&mut WorkloadGroup) -> Result<()>,
+    ) -> Result<Option<u64>> {
+        let workload_group_api = self.workload_group_api(tenant);
+        let seq_workload_group = match workload_group_api.get(name, MatchSeq::GE(0)).await {
+            Ok(seq_workload_group) => seq_workload_group,
+            Err(CrudError::Business(_)) if if_exists => return Ok(None),
+            Err(e) => {
+                return Err(ErrorCode::from(e).add_message_back(" (while alter workload group)"));
+            }
+        };
+
+        let seq = seq_workload_group.seq;
+        let mut workload_group = seq_workload_group.data;
+        update(&mut workload_group)?;
+        workload_group.updated_on = Utc::now();
+
+        match workload_group_api
+            .update(workload_group, MatchSeq::Exact(seq))
+            .await
+        {
+            Ok(res) => Ok(Some(res)),
+            Err(e) => Err(ErrorCode::from(e).add_message_back(" (while alter workload group)")),
+        }
+    }
+
+    // Get a workload group by name.
+    #[async_backtrace::framed]
+    pub async fn get_workload_group(&self, tenant: &Tenant, name: &str) -> Result<WorkloadGroup> {
+        let workload_group_api = self.workload_group_api(tenant);
+        let seq_workload_group = workload_group_api
+            .get(name, MatchSeq::GE(0))
+            .await
+            .map_err(|e| ErrorCode::from(e).add_message_back(" (while get workload group)"))?;
+        Ok(seq_workload_group.data)
+    }
+
+    // Get all the workload groups of the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_workload_groups(&self, tenant: &Tenant) -> Result<Vec<WorkloadGroup>> {
+        let workload_group_api = self.workload_group_api(tenant);
+        match workload_group_api.list().await {
+            Err(e) => Err(ErrorCode::from(e).add_message_back(" (while get workload groups)")),
+            Ok(workload_groups) => Ok(workload_groups),
+        }
+    }
+
+    // Drop a workload group by name.
+    #[async_backtrace::framed]
+    pub async fn drop_workload_group(
+        &self,
+        tenant: &Tenant,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let user_infos = self.get_users(tenant).await?;
+        if let Some(user_info) = user_infos
+            .iter()
+            .find(|u| u.option.workload_group().map(|v| v.as_str()) == Some(name))
+        {
+            return Err(ErrorCode::WorkloadGroupIsUsed(format!(
+                "workload group `{}` is used by user `{}`",
+                name, user_info.name
+            )));
+        }
+        let role_infos = self.get_roles(tenant).await?;
+        if let Some(role_info) = role_infos
+            .iter()
+            .find(|r| r.workload_group.as_deref() == Some(name))
+        {
+            return Err(ErrorCode::WorkloadGroupIsUsed(format!(
+                "workload group `{}` is used by role `{}`",
+                name, role_info.name
+            )));
+        }
+
+        let workload_group_api = self.workload_group_api(tenant);
+        match workload_group_api.remove(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                let e = ErrorCode::from(e);
+                if if_exists && e.code() == ErrorCode::UNKNOWN_WORKLOAD_GROUP {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop workload group)"))
+                }
+            }
+        }
+    }
+}
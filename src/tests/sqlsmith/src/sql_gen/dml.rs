@@ -92,6 +92,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                 source,
                 // TODO
                 overwrite: false,
+                partition: None,
             };
             insert_stmts.push(insert_stmt);
         }
@@ -318,7 +319,10 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                 };
                 hints_list.push(hint);
             }
-            Some(Hint { hints_list })
+            Some(Hint {
+                hints_list,
+                plan_hints: vec![],
+            })
         } else {
             None
         }
@@ -526,6 +530,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                 columns,
                 source,
                 overwrite: false,
+                partition: None,
             })
         } else {
             None
@@ -145,3 +145,57 @@ cat $grpc_exported |
     ./target/${BUILD_PROFILE}/bigbytesdb-metactl import --raft-dir "$meta_dir"  \
     && { echo " === expect error when importing incompatible header"; exit 1; } \
     || echo " === error is expected. OK";
+
+
+echo " === "
+echo " === 6. Test key-value subcommands against a running metasrv"
+echo " === "
+
+rm -rf "$meta_dir"
+
+echo " === start a single node bigbytesdb-meta"
+./target/${BUILD_PROFILE}/bigbytesdb-meta --single --raft-dir "$meta_dir" --log-file-level=debug &
+METASRV_PID=$!
+echo $METASRV_PID
+sleep 10
+
+metactl_kv () {
+    ./target/${BUILD_PROFILE}/bigbytesdb-metactl "$@" --grpc-api-address "localhost:9191"
+}
+
+expect_output () {
+    local want="$1"
+    local got="$2"
+    if echo "$got" | grep -Fq "$want"; then
+        echo " === found: $want"
+    else
+        echo " === expect: $want"
+        echo " === got: $got"
+        exit 1
+    fi
+}
+
+echo " === upsert, get and mget"
+metactl_kv upsert --key "kv_test/a" --value "foo"
+metactl_kv upsert --key "kv_test/b" --value "bar"
+expect_output "kv_test/a: seq=1 value=foo" "$(metactl_kv get --key "kv_test/a")"
+expect_output "kv_test/b: seq=2 value=bar" "$(metactl_kv mget --keys "kv_test/a" "kv_test/b")"
+expect_output "kv_test/c: <not found>" "$(metactl_kv mget --keys "kv_test/a" "kv_test/c")"
+
+echo " === upsert with a mismatched seq"
+expect_output "not changed" "$(metactl_kv upsert --key "kv_test/a" --value "foo2" --seq 5)"
+
+echo " === list"
+expect_output "kv_test/b: seq=2 value=bar" "$(metactl_kv list --prefix "kv_test/")"
+
+echo " === txn"
+metactl_kv txn --txn '{"operations":[],"condition":[],"if_then":[{"request":{"Put":{"key":"kv_test/c","value":[98,97,122],"prev_value":true}}}],"else_then":[]}'
+expect_output "kv_test/c: seq=3 value=baz" "$(metactl_kv get --key "kv_test/c")"
+
+echo " === delete"
+metactl_kv delete --key "kv_test/a"
+expect_output "kv_test/a: <not found>" "$(metactl_kv get --key "kv_test/a")"
+
+kill $METASRV_PID
+
+sleep 3